@@ -0,0 +1,120 @@
+//! Buffer list publishing benchmark.
+//!
+//! Pushes buffer lists of small RTP-sized buffers through zenohsink, once
+//! publishing every buffer as its own sample and once with `batch-lists`,
+//! and prints the throughput of both runs as seen by a zenohsrc receiver.
+//!
+//! Usage: cargo run --release --example batch_lists [lists] [buffers-per-list] [buffer-size]
+
+use anyhow::Error;
+use gst::prelude::*;
+use gstzenoh::zenohsink::ZenohSink;
+use gstzenoh::zenohsrc::ZenohSrc;
+use std::thread;
+use std::time::{Duration, Instant};
+use zenoh::Wait;
+
+struct RunResult {
+    elapsed: Duration,
+    buffers_received: u64,
+    bytes_sent: u64,
+}
+
+fn run(
+    session: &zenoh::Session,
+    batch_lists: bool,
+    lists: usize,
+    buffers_per_list: usize,
+    buffer_size: usize,
+) -> Result<RunResult, Error> {
+    let key_expr = format!("gst/example/batch-lists/{}", batch_lists);
+
+    let receiver = gst::Pipeline::new();
+    let zenohsrc = ZenohSrc::builder(&key_expr)
+        .session(session.clone())
+        .receive_timeout_ms(50)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()?;
+    receiver.add_many([zenohsrc.upcast_ref(), &fakesink])?;
+    zenohsrc.link(&fakesink)?;
+    receiver.set_state(gst::State::Playing)?;
+    thread::sleep(Duration::from_millis(500));
+
+    let sender = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .block(true)
+        .build();
+    let zenohsink = ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .reliability("reliable")
+        .batch_lists(batch_lists)
+        .build();
+    sender.add_many([appsrc.upcast_ref(), zenohsink.upcast_ref()])?;
+    appsrc.link(&zenohsink)?;
+    sender.set_state(gst::State::Playing)?;
+
+    let expected = (lists * buffers_per_list) as u64;
+    let start = Instant::now();
+    for _ in 0..lists {
+        let mut list = gst::BufferList::new_sized(buffers_per_list);
+        {
+            let list_mut = list.get_mut().unwrap();
+            for _ in 0..buffers_per_list {
+                list_mut.add(gst::Buffer::from_slice(vec![0u8; buffer_size]));
+            }
+        }
+        appsrc.push_buffer_list(list)?;
+    }
+    appsrc.end_of_stream()?;
+
+    // Done once the receiver has every buffer, or gives up after 10 s of loss
+    while zenohsrc.messages_received() < expected && start.elapsed() < Duration::from_secs(10) {
+        thread::sleep(Duration::from_millis(1));
+    }
+    let result = RunResult {
+        elapsed: start.elapsed(),
+        buffers_received: zenohsrc.messages_received(),
+        bytes_sent: zenohsink.bytes_sent(),
+    };
+
+    sender.set_state(gst::State::Null)?;
+    receiver.set_state(gst::State::Null)?;
+    Ok(result)
+}
+
+fn main() -> Result<(), Error> {
+    gst::init()?;
+    gstzenoh::plugin_register_static()?;
+
+    let mut args = std::env::args().skip(1).map(|arg| arg.parse::<usize>());
+    let lists = args.next().transpose()?.unwrap_or(2000);
+    let buffers_per_list = args.next().transpose()?.unwrap_or(32);
+    let buffer_size = args.next().transpose()?.unwrap_or(1200);
+
+    println!(
+        "Publishing {} lists of {} buffers of {} bytes",
+        lists, buffers_per_list, buffer_size
+    );
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    for batch_lists in [false, true] {
+        let result = run(&session, batch_lists, lists, buffers_per_list, buffer_size)?;
+        let seconds = result.elapsed.as_secs_f64();
+        println!(
+            "batch-lists={:<5}  {:>8.1} ms  {:>10.0} buffers/s  {:>8.1} MB/s  ({} buffers received)",
+            batch_lists,
+            seconds * 1000.0,
+            result.buffers_received as f64 / seconds,
+            result.bytes_sent as f64 / seconds / 1_000_000.0,
+            result.buffers_received
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,421 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Conversion of received Zenoh payloads into GStreamer buffers
+//!
+//! Zenoh hands out payloads as reference-counted `ZBytes`. When such a
+//! payload is a single contiguous slice, the buffer memory can point straight
+//! at it: the memory owns a clone of the `ZBytes` and keeps it alive until
+//! GStreamer releases the buffer. Fragmented payloads (e.g. large messages
+//! reassembled from several network batches) are copied into one allocation.
+//!
+//! Copied payloads can go into the buffers of a [`PayloadPool`] rather than
+//! a fresh allocation per sample, which spares the allocator at high rates,
+//! or into the buffers of a pool downstream proposed, for memory it controls
+//! (e.g. DMA buffers of a hardware decoder).
+//!
+//! Batches published by zenohsink's `batch-lists` and `aggregate-window-ms`
+//! are split back into their buffers by [`split_batch`], without copying the
+//! memory.
+
+use gst::prelude::*;
+use zenoh::bytes::ZBytes;
+
+use crate::metadata::BatchPartTiming;
+
+/// A `ZBytes` known to consist of exactly one slice.
+///
+/// Wrapped by `gst::Buffer::from_slice()`, which keeps it alive as long as
+/// the memory is referenced.
+struct ContiguousPayload(ZBytes);
+
+impl AsRef<[u8]> for ContiguousPayload {
+    fn as_ref(&self) -> &[u8] {
+        self.0.slices().next().unwrap_or_default()
+    }
+}
+
+/// Returns whether `payload` can back a buffer without copying.
+pub(crate) fn is_contiguous(payload: &ZBytes) -> bool {
+    payload.slices().count() == 1
+}
+
+/// Creates a buffer holding `payload`.
+///
+/// With `zero_copy` enabled and a contiguous payload, the buffer wraps the
+/// payload memory (read-only; downstream writers get a copy on map).
+/// Otherwise the payload is copied into a buffer of `pool` when one is free,
+/// or into a newly allocated buffer. An empty payload gives a buffer without
+/// memory.
+pub(crate) fn buffer_from_payload(
+    payload: &ZBytes,
+    zero_copy: bool,
+    pool: Option<&mut PayloadPool>,
+) -> gst::Buffer {
+    if payload.is_empty() {
+        gst::Buffer::new()
+    } else if zero_copy && is_contiguous(payload) {
+        gst::Buffer::from_slice(ContiguousPayload(payload.clone()))
+    } else if let Some(buffer) = pool.and_then(|pool| pool.copy_payload(payload)) {
+        buffer
+    } else {
+        gst::Buffer::from_mut_slice(payload.to_bytes().into_owned())
+    }
+}
+
+/// Copies `payload` into a buffer of `pool`, a pool proposed by downstream
+/// whose buffers hold `size` bytes.
+///
+/// Returns `None` for an empty payload, one larger than `size`, or when no
+/// buffer is free, for the caller to fall back on [`buffer_from_payload`].
+pub(crate) fn copy_into_pool(
+    pool: &gst::BufferPool,
+    size: usize,
+    payload: &ZBytes,
+) -> Option<gst::Buffer> {
+    if payload.is_empty() || payload.len() > size {
+        return None;
+    }
+    copy_payload_into(pool, payload)
+}
+
+/// Copies `payload` into a buffer of `pool`, resized to the payload size.
+/// The pool buffers must be large enough.
+fn copy_payload_into(pool: &gst::BufferPool, payload: &ZBytes) -> Option<gst::Buffer> {
+    // Never wait for a buffer to come back: falling back to an allocation
+    // beats stalling the stream
+    let params = gst::BufferPoolAcquireParams::with_flags(gst::BufferPoolAcquireFlags::DONTWAIT);
+    let mut buffer = pool.acquire_buffer(Some(&params)).ok()?;
+    {
+        let buffer_mut = buffer.get_mut()?;
+        buffer_mut.set_size(payload.len());
+        let mut offset = 0;
+        for slice in payload.slices() {
+            buffer_mut.copy_from_slice(offset, slice).ok()?;
+            offset += slice.len();
+        }
+    }
+    Some(buffer)
+}
+
+/// A buffer pool for copied payloads, sized for the largest payload seen.
+///
+/// The pool is created with the first payload and replaced by a bigger one
+/// when a payload doesn't fit its buffers, so a stream of varying sizes
+/// settles on buffers holding its largest frames. Buffers of a replaced pool
+/// are freed once downstream releases them.
+pub(crate) struct PayloadPool {
+    /// Buffers allocated when the pool is (re)created
+    min_buffers: u32,
+    /// Buffers the pool may hand out at once (0 = unlimited)
+    max_buffers: u32,
+    /// The active pool and the size of its buffers
+    pool: Option<(gst::BufferPool, usize)>,
+}
+
+impl PayloadPool {
+    pub(crate) fn new(min_buffers: u32, max_buffers: u32) -> Self {
+        Self {
+            min_buffers,
+            max_buffers,
+            pool: None,
+        }
+    }
+
+    /// Size of the pool buffers, 0 before the first payload.
+    pub(crate) fn buffer_size(&self) -> usize {
+        self.pool.as_ref().map_or(0, |(_, size)| *size)
+    }
+
+    /// Copies `payload` into a pool buffer, resized to the payload size.
+    ///
+    /// Returns `None` when all `max_buffers` are in use or the pool can't be
+    /// configured for the payload size, for the caller to allocate instead.
+    pub(crate) fn copy_payload(&mut self, payload: &ZBytes) -> Option<gst::Buffer> {
+        let len = payload.len();
+        if len > self.buffer_size() {
+            self.replace(len)?;
+        }
+        let (pool, _) = self.pool.as_ref()?;
+        copy_payload_into(pool, payload)
+    }
+
+    /// Replaces the pool by one whose buffers hold `size` bytes.
+    fn replace(&mut self, size: usize) -> Option<()> {
+        self.deactivate();
+
+        let pool = gst::BufferPool::new();
+        let mut config = pool.config();
+        config.set_params(None, u32::try_from(size).ok()?, self.min_buffers, self.max_buffers);
+        pool.set_config(config).ok()?;
+        pool.set_active(true).ok()?;
+        self.pool = Some((pool, size));
+        Some(())
+    }
+
+    fn deactivate(&mut self) {
+        if let Some((pool, _)) = self.pool.take() {
+            let _ = pool.set_active(false);
+        }
+    }
+}
+
+impl Drop for PayloadPool {
+    fn drop(&mut self) {
+        self.deactivate();
+    }
+}
+
+/// Splits a batched payload into the buffers it was made of, given their
+/// sizes from the `gst.batch` metadata.
+///
+/// The buffers share the memory of `buffer` and carry its metas, PTS and DTS;
+/// only the first one keeps its flags. With the `gst.batch-timing` of the
+/// batch, each buffer takes its own timestamps, duration and flags instead,
+/// the first one keeping the DISCONT flag of `buffer`. Returns `None` when the
+/// sizes don't add up to the buffer size.
+pub(crate) fn split_batch(
+    buffer: &gst::Buffer,
+    sizes: &[usize],
+    timing: Option<&[BatchPartTiming]>,
+) -> Option<gst::BufferList> {
+    if sizes.iter().sum::<usize>() != buffer.size() {
+        return None;
+    }
+
+    // Timing that doesn't match the sizes is ignored
+    let timing = timing.filter(|timing| timing.len() == sizes.len());
+
+    let mut list = gst::BufferList::new_sized(sizes.len());
+    let list_mut = list.get_mut().expect("newly created list");
+    let mut offset = 0;
+    for (i, &size) in sizes.iter().enumerate() {
+        let flags = if i == 0 {
+            gst::BufferCopyFlags::METADATA | gst::BufferCopyFlags::MEMORY
+        } else {
+            gst::BufferCopyFlags::META | gst::BufferCopyFlags::MEMORY
+        };
+        let mut part = buffer.copy_region(flags, offset..offset + size).ok()?;
+        {
+            let part_mut = part.make_mut();
+            part_mut.set_pts(buffer.pts());
+            part_mut.set_dts(buffer.dts());
+            if let Some(part_timing) = timing.map(|timing| &timing[i]) {
+                if part_timing.pts.is_some() {
+                    part_mut.set_pts(part_timing.pts);
+                }
+                if part_timing.dts.is_some() {
+                    part_mut.set_dts(part_timing.dts);
+                }
+                part_mut.set_duration(part_timing.duration);
+                let mut flags = part_timing.flags;
+                if i == 0 {
+                    flags |= buffer.flags() & gst::BufferFlags::DISCONT;
+                }
+                part_mut.set_flags(flags);
+            }
+        }
+        list_mut.add(part);
+        offset += size;
+    }
+    Some(list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contiguous_payload_is_wrapped() {
+        gst::init().unwrap();
+
+        let payload = ZBytes::from(vec![1u8, 2, 3, 4]);
+        assert!(is_contiguous(&payload));
+
+        let buffer = buffer_from_payload(&payload, true, None);
+        let map = buffer.map_readable().unwrap();
+        assert_eq!(map.as_slice(), &[1, 2, 3, 4]);
+        // The buffer points at the payload memory rather than a copy
+        assert_eq!(
+            map.as_slice().as_ptr(),
+            payload.slices().next().unwrap().as_ptr()
+        );
+    }
+
+    #[test]
+    fn test_fragmented_payload_is_copied() {
+        gst::init().unwrap();
+
+        let mut writer = ZBytes::writer();
+        writer.append(ZBytes::from(vec![1u8, 2]));
+        writer.append(ZBytes::from(vec![3u8, 4]));
+        let payload = writer.finish();
+        assert!(!is_contiguous(&payload));
+
+        let buffer = buffer_from_payload(&payload, true, None);
+        let map = buffer.map_readable().unwrap();
+        assert_eq!(map.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_empty_payload() {
+        gst::init().unwrap();
+
+        let payload = ZBytes::new();
+        let mut pool = PayloadPool::new(1, 0);
+        for buffer in [
+            buffer_from_payload(&payload, true, None),
+            buffer_from_payload(&payload, false, Some(&mut pool)),
+        ] {
+            assert_eq!(buffer.size(), 0);
+            assert!(buffer.map_readable().unwrap().is_empty());
+        }
+        // No pool is created for nothing to copy
+        assert_eq!(pool.buffer_size(), 0);
+    }
+
+    #[test]
+    fn test_split_batch() {
+        gst::init().unwrap();
+
+        let mut buffer = gst::Buffer::from_slice(vec![1u8, 2, 3, 4, 5, 6]);
+        buffer.get_mut().unwrap().set_pts(gst::ClockTime::from_mseconds(40));
+
+        let list = split_batch(&buffer, &[1, 0, 5], None).expect("sizes match");
+        assert_eq!(list.len(), 3);
+        let parts: Vec<Vec<u8>> = list
+            .iter()
+            .map(|part| part.map_readable().unwrap().to_vec())
+            .collect();
+        assert_eq!(parts, [vec![1], vec![], vec![2, 3, 4, 5, 6]]);
+        assert!(list.iter().all(|part| part.pts() == buffer.pts()));
+
+        assert!(split_batch(&buffer, &[1, 2], None).is_none());
+        assert!(split_batch(&buffer, &[4, 4], None).is_none());
+    }
+
+    #[test]
+    fn test_split_batch_timing() {
+        gst::init().unwrap();
+
+        let mut buffer = gst::Buffer::from_slice(vec![1u8, 2, 3]);
+        {
+            let buffer_mut = buffer.get_mut().unwrap();
+            buffer_mut.set_pts(gst::ClockTime::from_mseconds(40));
+            buffer_mut.set_flags(gst::BufferFlags::DISCONT);
+        }
+        let timing = [
+            BatchPartTiming {
+                pts: None,
+                dts: None,
+                duration: Some(gst::ClockTime::from_mseconds(20)),
+                flags: gst::BufferFlags::empty(),
+            },
+            BatchPartTiming {
+                pts: Some(gst::ClockTime::from_mseconds(60)),
+                dts: Some(gst::ClockTime::from_mseconds(50)),
+                duration: Some(gst::ClockTime::from_mseconds(20)),
+                flags: gst::BufferFlags::DELTA_UNIT,
+            },
+        ];
+
+        let list = split_batch(&buffer, &[1, 2], Some(&timing)).expect("sizes match");
+        let first = list.get(0).unwrap();
+        assert_eq!(first.pts(), buffer.pts());
+        assert_eq!(first.duration(), Some(gst::ClockTime::from_mseconds(20)));
+        assert_eq!(first.flags(), gst::BufferFlags::DISCONT);
+        let second = list.get(1).unwrap();
+        assert_eq!(second.pts(), Some(gst::ClockTime::from_mseconds(60)));
+        assert_eq!(second.dts(), Some(gst::ClockTime::from_mseconds(50)));
+        assert_eq!(second.flags(), gst::BufferFlags::DELTA_UNIT);
+
+        // Timing for another number of buffers is ignored
+        let list = split_batch(&buffer, &[1, 2], Some(&timing[..1])).expect("sizes match");
+        assert!(list.iter().all(|part| part.pts() == buffer.pts()));
+    }
+
+    #[test]
+    fn test_copy_when_zero_copy_disabled() {
+        gst::init().unwrap();
+
+        let payload = ZBytes::from(vec![5u8; 16]);
+        let buffer = buffer_from_payload(&payload, false, None);
+        let map = buffer.map_readable().unwrap();
+        assert_eq!(map.as_slice(), &[5u8; 16]);
+        assert_ne!(
+            map.as_slice().as_ptr(),
+            payload.slices().next().unwrap().as_ptr()
+        );
+    }
+
+    #[test]
+    fn test_pool_buffers_are_reused() {
+        gst::init().unwrap();
+
+        let mut pool = PayloadPool::new(1, 1);
+        let buffer = buffer_from_payload(&ZBytes::from(vec![1u8; 64]), false, Some(&mut pool));
+        assert_eq!(pool.buffer_size(), 64);
+        let ptr = buffer.map_readable().unwrap().as_slice().as_ptr();
+        drop(buffer);
+
+        // A smaller payload gets the released buffer, resized
+        let buffer = buffer_from_payload(&ZBytes::from(vec![2u8; 16]), false, Some(&mut pool));
+        let map = buffer.map_readable().unwrap();
+        assert_eq!(map.as_slice(), &[2u8; 16]);
+        assert_eq!(map.as_slice().as_ptr(), ptr);
+        assert_eq!(pool.buffer_size(), 64);
+    }
+
+    #[test]
+    fn test_pool_grows_for_larger_payloads() {
+        gst::init().unwrap();
+
+        let mut pool = PayloadPool::new(1, 0);
+        let small = buffer_from_payload(&ZBytes::from(vec![1u8; 16]), false, Some(&mut pool));
+        let large = buffer_from_payload(&ZBytes::from(vec![2u8; 256]), false, Some(&mut pool));
+        assert_eq!(pool.buffer_size(), 256);
+        // The buffer of the replaced pool stays valid
+        assert_eq!(small.map_readable().unwrap().as_slice(), &[1u8; 16]);
+        assert_eq!(large.map_readable().unwrap().as_slice(), &[2u8; 256]);
+    }
+
+    #[test]
+    fn test_exhausted_pool_falls_back_to_allocation() {
+        gst::init().unwrap();
+
+        let mut pool = PayloadPool::new(1, 1);
+        let mut writer = ZBytes::writer();
+        writer.append(ZBytes::from(vec![1u8, 2]));
+        writer.append(ZBytes::from(vec![3u8, 4]));
+        let payload = writer.finish();
+
+        // Fragmented payloads are copied into the pool even with zero-copy
+        let first = buffer_from_payload(&payload, true, Some(&mut pool));
+        assert!(pool.copy_payload(&payload).is_none());
+        let second = buffer_from_payload(&payload, true, Some(&mut pool));
+        assert_eq!(first.map_readable().unwrap().as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(second.map_readable().unwrap().as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_copy_into_downstream_pool() {
+        gst::init().unwrap();
+
+        let pool = gst::BufferPool::new();
+        let mut config = pool.config();
+        config.set_params(None, 64, 1, 1);
+        pool.set_config(config).unwrap();
+        pool.set_active(true).unwrap();
+
+        let payload = ZBytes::from(vec![1u8; 16]);
+        let buffer = copy_into_pool(&pool, 64, &payload).unwrap();
+        assert_eq!(buffer.map_readable().unwrap().as_slice(), &[1u8; 16]);
+        assert!(buffer.peek_memory(0).maxsize() >= 64);
+
+        // The only buffer is in use, and larger payloads don't fit anyway
+        assert!(copy_into_pool(&pool, 64, &payload).is_none());
+        drop(buffer);
+        assert!(copy_into_pool(&pool, 64, &ZBytes::from(vec![1u8; 65])).is_none());
+        assert!(copy_into_pool(&pool, 64, &payload).is_some());
+        pool.set_active(false).unwrap();
+    }
+}
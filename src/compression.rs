@@ -7,12 +7,25 @@
 //! - `compression-zstd`: Zstandard compression (recommended for general use)
 //! - `compression-lz4`: LZ4 compression (fastest, lower compression ratio)
 //! - `compression-gzip`: Gzip compression (widely compatible)
+//! - `compression-brotli`: Brotli compression (best ratio for text-like payloads)
+//! - `compression-snappy`: Snappy compression (very fast, ignores the level)
 //!
 //! Each compression algorithm can be individually enabled or all can be enabled with the
 //! `compression` feature.
+//!
+//! Receivers advertise the algorithms they decompress with liveliness tokens on
+//! `<key-expr>/compression/<algorithm>/<id>`; zenohsink's `compression-negotiate`
+//! only compresses with an algorithm every one of them advertises. Receivers that
+//! advertise nothing (built without compression, older versions, other subscribers)
+//! can't be seen, so a mix of those with capable receivers isn't detected.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 use gst::glib;
 use thiserror::Error;
+use zenoh::Wait;
 
 /// Compression algorithm selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, glib::Enum, Default)]
@@ -37,6 +50,16 @@ pub enum CompressionType {
     #[cfg(feature = "compression-gzip")]
     #[enum_value(name = "Gzip", nick = "gzip")]
     Gzip,
+
+    /// Brotli compression (requires compression-brotli feature)
+    #[cfg(feature = "compression-brotli")]
+    #[enum_value(name = "Brotli", nick = "brotli")]
+    Brotli,
+
+    /// Snappy compression (requires compression-snappy feature)
+    #[cfg(feature = "compression-snappy")]
+    #[enum_value(name = "Snappy", nick = "snappy")]
+    Snappy,
 }
 
 impl CompressionType {
@@ -50,6 +73,10 @@ impl CompressionType {
             Self::Lz4 => "lz4",
             #[cfg(feature = "compression-gzip")]
             Self::Gzip => "gzip",
+            #[cfg(feature = "compression-brotli")]
+            Self::Brotli => "brotli",
+            #[cfg(feature = "compression-snappy")]
+            Self::Snappy => "snappy",
         }
     }
 
@@ -63,6 +90,10 @@ impl CompressionType {
             "lz4" => Some(Self::Lz4),
             #[cfg(feature = "compression-gzip")]
             "gzip" => Some(Self::Gzip),
+            #[cfg(feature = "compression-brotli")]
+            "brotli" => Some(Self::Brotli),
+            #[cfg(feature = "compression-snappy")]
+            "snappy" => Some(Self::Snappy),
             _ => None,
         }
     }
@@ -139,10 +170,33 @@ pub fn compress(
                 .map_err(|e| CompressionError::CompressionFailed(e.to_string()))
         }
 
+        #[cfg(feature = "compression-brotli")]
+        CompressionType::Brotli => {
+            use std::io::Write;
+
+            // Brotli quality goes from 0 to 11; levels 1-9 map directly onto it.
+            // 22 is the default window size (log2 of 4 MiB).
+            let mut encoder = brotli::CompressorWriter::new(Vec::new(), 4096, level as u32, 22);
+            encoder
+                .write_all(data)
+                .map_err(|e| CompressionError::CompressionFailed(e.to_string()))?;
+            Ok(encoder.into_inner())
+        }
+
+        #[cfg(feature = "compression-snappy")]
+        CompressionType::Snappy => {
+            // Snappy has no compression levels
+            snap::raw::Encoder::new()
+                .compress_vec(data)
+                .map_err(|e| CompressionError::CompressionFailed(e.to_string()))
+        }
+
         #[cfg(not(any(
             feature = "compression-zstd",
             feature = "compression-lz4",
-            feature = "compression-gzip"
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
         )))]
         _ => Err(CompressionError::UnsupportedType(format!(
             "{:?}",
@@ -163,40 +217,80 @@ pub fn decompress(
     data: &[u8],
     compression_type: CompressionType,
 ) -> Result<Vec<u8>, CompressionError> {
+    decompress_with_limit(data, compression_type, 0)
+}
+
+/// Decompress data using the specified algorithm, failing when the output
+/// would exceed `max_size` bytes (0 = unlimited)
+///
+/// Receivers pass their `max-buffer-size`, so a small payload that expands
+/// enormously is refused before it is fully inflated.
+pub fn decompress_with_limit(
+    data: &[u8],
+    compression_type: CompressionType,
+    max_size: u64,
+) -> Result<Vec<u8>, CompressionError> {
+    let too_large = || {
+        CompressionError::DecompressionFailed(format!(
+            "decompressed size exceeds {} bytes",
+            max_size
+        ))
+    };
+
     match compression_type {
-        CompressionType::None => Ok(data.to_vec()),
+        CompressionType::None => {
+            if max_size > 0 && data.len() as u64 > max_size {
+                return Err(too_large());
+            }
+            Ok(data.to_vec())
+        }
 
         #[cfg(feature = "compression-zstd")]
         CompressionType::Zstd => {
-            zstd::decode_all(data).map_err(|e| CompressionError::DecompressionFailed(e.to_string()))
+            let decoder = zstd::stream::read::Decoder::new(data)
+                .map_err(|e| CompressionError::DecompressionFailed(e.to_string()))?;
+            read_with_limit(decoder, max_size)
         }
 
         #[cfg(feature = "compression-lz4")]
         CompressionType::Lz4 => {
             // LZ4 needs to know the decompressed size, but we don't store it
-            // We'll use a reasonable max size (16MB) for decompression
+            // We'll use a reasonable max size (16MB) for decompression, or the
+            // limit when lower; larger output fails to decompress
             const MAX_DECOMPRESSED_SIZE: i32 = 16 * 1024 * 1024;
-            lz4::block::decompress(data, Some(MAX_DECOMPRESSED_SIZE))
+            let capacity = match i32::try_from(max_size) {
+                Ok(limit) if limit > 0 => limit.min(MAX_DECOMPRESSED_SIZE),
+                _ => MAX_DECOMPRESSED_SIZE,
+            };
+            lz4::block::decompress(data, Some(capacity))
                 .map_err(|e| CompressionError::DecompressionFailed(e.to_string()))
         }
 
         #[cfg(feature = "compression-gzip")]
-        CompressionType::Gzip => {
-            use flate2::read::GzDecoder;
-            use std::io::Read;
+        CompressionType::Gzip => read_with_limit(flate2::read::GzDecoder::new(data), max_size),
 
-            let mut decoder = GzDecoder::new(data);
-            let mut decompressed = Vec::new();
-            decoder
-                .read_to_end(&mut decompressed)
+        #[cfg(feature = "compression-brotli")]
+        CompressionType::Brotli => read_with_limit(brotli::Decompressor::new(data, 4096), max_size),
+
+        #[cfg(feature = "compression-snappy")]
+        CompressionType::Snappy => {
+            // The decompressed length comes first in the data
+            let len = snap::raw::decompress_len(data)
                 .map_err(|e| CompressionError::DecompressionFailed(e.to_string()))?;
-            Ok(decompressed)
+            if max_size > 0 && len as u64 > max_size {
+                return Err(too_large());
+            }
+            snap::raw::Decoder::new()
+                .decompress_vec(data)
+                .map_err(|e| CompressionError::DecompressionFailed(e.to_string()))
         }
 
         #[cfg(not(any(
             feature = "compression-zstd",
             feature = "compression-lz4",
-            feature = "compression-gzip"
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
         )))]
         _ => Err(CompressionError::UnsupportedType(format!(
             "{:?}",
@@ -205,6 +299,153 @@ pub fn decompress(
     }
 }
 
+/// Reads `decoder` to the end, failing as soon as the output exceeds
+/// `max_size` bytes (0 = unlimited).
+#[cfg(any(
+    feature = "compression-zstd",
+    feature = "compression-gzip",
+    feature = "compression-brotli"
+))]
+fn read_with_limit(
+    decoder: impl std::io::Read,
+    max_size: u64,
+) -> Result<Vec<u8>, CompressionError> {
+    use std::io::Read;
+
+    let mut decompressed = Vec::new();
+    let read = if max_size > 0 {
+        // One byte past the limit tells a payload of exactly max_size bytes apart
+        decoder.take(max_size + 1).read_to_end(&mut decompressed)
+    } else {
+        decoder.read_to_end(&mut decompressed)
+    };
+    read.map_err(|e| CompressionError::DecompressionFailed(e.to_string()))?;
+    if max_size > 0 && decompressed.len() as u64 > max_size {
+        return Err(CompressionError::DecompressionFailed(format!(
+            "decompressed size exceeds {} bytes",
+            max_size
+        )));
+    }
+    Ok(decompressed)
+}
+
+/// Makes the liveliness tokens of each receiver unique within the process
+static NEXT_RECEIVER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the algorithms this build compresses and decompresses, `None` excluded.
+fn supported_algorithms() -> impl Iterator<Item = CompressionType> {
+    ["zstd", "lz4", "gzip", "brotli", "snappy"]
+        .into_iter()
+        .filter_map(CompressionType::from_metadata_value)
+}
+
+/// Advertises the algorithms this build decompresses to the zenohsinks
+/// publishing on `key_expr`, with one liveliness token per algorithm on
+/// `<key_expr>/compression/<algorithm>/<id>`.
+///
+/// The advertisement is withdrawn when the tokens are dropped or the session closes.
+pub(crate) fn advertise(
+    session: &zenoh::Session,
+    key_expr: &str,
+) -> Result<Vec<zenoh::liveliness::LivelinessToken>, zenoh::Error> {
+    let caps_key = crate::metadata::compression_caps_key_expr(key_expr);
+    let id = format!("{}-{}", session.zid(), NEXT_RECEIVER_ID.fetch_add(1, Ordering::Relaxed));
+    supported_algorithms()
+        .map(|algorithm| {
+            let token_key = format!("{}/{}/{}", caps_key, algorithm.to_metadata_value(), id);
+            session.liveliness().declare_token(token_key).wait()
+        })
+        .collect()
+}
+
+/// Returns the algorithm a liveliness token declared by [`advertise`]
+/// stands for, if this build supports it.
+fn advertised_algorithm(token_key: &str) -> Option<CompressionType> {
+    let mut chunks = token_key.rsplit('/').skip(1);
+    CompressionType::from_metadata_value(chunks.next()?)
+        .filter(|algorithm| *algorithm != CompressionType::None)
+}
+
+/// The algorithms advertised by the receivers of a zenohsink's key
+/// expressions (`compression-negotiate`), followed through their liveliness
+/// tokens as receivers come and go.
+pub(crate) struct CompressionPeers {
+    /// Algorithm of every advertising token alive, by token key (`None` for
+    /// algorithms this build doesn't know, which still identify a receiver)
+    tokens: Arc<Mutex<HashMap<String, Option<CompressionType>>>>,
+    subscribers: Vec<zenoh::pubsub::Subscriber<()>>,
+}
+
+impl CompressionPeers {
+    /// Follows the algorithms advertised for each of `key_exprs`, including
+    /// those advertised before.
+    pub(crate) fn watch<'a>(
+        session: &zenoh::Session,
+        key_exprs: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, zenoh::Error> {
+        let tokens = Arc::new(Mutex::new(HashMap::new()));
+        let subscribers = key_exprs
+            .into_iter()
+            .map(|key_expr| {
+                let tokens = tokens.clone();
+                let caps_key = crate::metadata::compression_caps_key_expr(key_expr);
+                session
+                    .liveliness()
+                    .declare_subscriber(format!("{}/*/*", caps_key))
+                    .history(true)
+                    .callback(move |sample| {
+                        let token_key = sample.key_expr().to_string();
+                        let mut tokens = tokens.lock().unwrap();
+                        match sample.kind() {
+                            zenoh::sample::SampleKind::Put => {
+                                let algorithm = advertised_algorithm(&token_key);
+                                tokens.insert(token_key, algorithm);
+                            }
+                            zenoh::sample::SampleKind::Delete => {
+                                tokens.remove(&token_key);
+                            }
+                        }
+                    })
+                    .wait()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            tokens,
+            subscribers,
+        })
+    }
+
+    /// Returns whether there are advertising receivers and every one of them
+    /// decompresses `compression`.
+    ///
+    /// Receivers are told apart by the `<id>` of their tokens. Subscribers that
+    /// advertise nothing aren't accounted for.
+    pub(crate) fn supports(&self, compression: CompressionType) -> bool {
+        let tokens = self.tokens.lock().unwrap();
+        let mut receivers: HashMap<&str, bool> = HashMap::new();
+        for (token_key, algorithm) in tokens.iter() {
+            let receiver = token_key.rsplit('/').next().unwrap_or_default();
+            *receivers.entry(receiver).or_default() |= *algorithm == Some(compression);
+        }
+        !receivers.is_empty() && receivers.values().all(|supported| *supported)
+    }
+
+    /// Undeclares the liveliness subscribers.
+    pub(crate) fn release(self) {
+        for subscriber in self.subscribers {
+            let key_expr = subscriber.key_expr().to_string();
+            if let Err(e) = subscriber.undeclare().wait() {
+                gst::warning!(
+                    gst::CAT_DEFAULT,
+                    "Failed to undeclare compression subscriber on '{}': {}",
+                    key_expr,
+                    e
+                );
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +485,24 @@ mod tests {
             );
         }
 
+        #[cfg(feature = "compression-brotli")]
+        {
+            assert_eq!(CompressionType::Brotli.to_metadata_value(), "brotli");
+            assert_eq!(
+                CompressionType::from_metadata_value("brotli"),
+                Some(CompressionType::Brotli)
+            );
+        }
+
+        #[cfg(feature = "compression-snappy")]
+        {
+            assert_eq!(CompressionType::Snappy.to_metadata_value(), "snappy");
+            assert_eq!(
+                CompressionType::from_metadata_value("snappy"),
+                Some(CompressionType::Snappy)
+            );
+        }
+
         assert_eq!(CompressionType::from_metadata_value("invalid"), None);
     }
 
@@ -257,6 +516,24 @@ mod tests {
         assert_eq!(decompressed, data);
     }
 
+    #[test]
+    fn test_decompress_with_limit() {
+        // Highly compressible: a few bytes on the wire for a large output
+        let data = vec![0u8; 1024 * 1024];
+        let algorithms = std::iter::once(CompressionType::None).chain(supported_algorithms());
+        for compression_type in algorithms {
+            let compressed = compress(&data, compression_type, 5).unwrap();
+
+            let result = decompress_with_limit(&compressed, compression_type, 64 * 1024);
+            assert!(result.is_err(), "{:?} output should be capped", compression_type);
+
+            let decompressed =
+                decompress_with_limit(&compressed, compression_type, data.len() as u64).unwrap();
+            assert_eq!(decompressed.len(), data.len());
+            assert_eq!(decompress_with_limit(&compressed, compression_type, 0).unwrap(), data);
+        }
+    }
+
     #[test]
     fn test_invalid_level() {
         let data = b"test";
@@ -300,6 +577,29 @@ mod tests {
         assert_eq!(decompressed, data);
     }
 
+    #[cfg(feature = "compression-brotli")]
+    #[test]
+    fn test_brotli_compression() {
+        let data = b"This is a test string that should compress well with repeated patterns repeated patterns";
+        let compressed = compress(data, CompressionType::Brotli, 5).unwrap();
+
+        // Compressed data should be smaller
+        assert!(compressed.len() < data.len());
+
+        let decompressed = decompress(&compressed, CompressionType::Brotli).unwrap();
+        assert_eq!(decompressed, data);
+    }
+
+    #[cfg(feature = "compression-snappy")]
+    #[test]
+    fn test_snappy_compression() {
+        let data = b"This is a test string that should compress well with repeated patterns repeated patterns";
+        let compressed = compress(data, CompressionType::Snappy, 5).unwrap();
+
+        let decompressed = decompress(&compressed, CompressionType::Snappy).unwrap();
+        assert_eq!(decompressed, data);
+    }
+
     #[cfg(feature = "compression-zstd")]
     #[test]
     fn test_compression_levels() {
@@ -315,4 +615,42 @@ mod tests {
         // Higher compression level should generally produce smaller output
         // (not guaranteed for small data, but likely for this test)
     }
+
+    #[test]
+    fn test_advertised_algorithm() {
+        for algorithm in supported_algorithms() {
+            let token_key = format!("demo/**/compression/{}/zid-0", algorithm.to_metadata_value());
+            assert_eq!(advertised_algorithm(&token_key), Some(algorithm));
+        }
+        assert_eq!(advertised_algorithm("demo/compression/none/zid-0"), None);
+        assert_eq!(advertised_algorithm("demo/compression/xz/zid-0"), None);
+        assert_eq!(advertised_algorithm("zid-0"), None);
+    }
+
+    #[test]
+    fn test_peers_support_requires_every_receiver() {
+        let Some(algorithm) = supported_algorithms().next() else {
+            return;
+        };
+        let peers = CompressionPeers {
+            tokens: Arc::default(),
+            subscribers: Vec::new(),
+        };
+        let advertise = |algorithm: &str, id: &str| {
+            let token_key = format!("demo/compression/{}/{}", algorithm, id);
+            let advertised = advertised_algorithm(&token_key);
+            peers.tokens.lock().unwrap().insert(token_key, advertised);
+        };
+
+        assert!(!peers.supports(algorithm));
+        advertise(algorithm.to_metadata_value(), "zid-0");
+        advertise("xz", "zid-0");
+        assert!(peers.supports(algorithm));
+
+        // A second receiver only decompressing an algorithm unknown here
+        advertise("xz", "zid-1");
+        assert!(!peers.supports(algorithm));
+        advertise(algorithm.to_metadata_value(), "zid-1");
+        assert!(peers.supports(algorithm));
+    }
 }
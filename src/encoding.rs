@@ -0,0 +1,225 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Mapping of Zenoh encodings to GStreamer caps
+//!
+//! Zenoh-native publishers don't send `gst.caps` metadata but often set the
+//! sample encoding to a MIME type such as `video/h264`. With
+//! `use-encoding-caps` enabled, zenohsrc looks the encoding up here and sets
+//! the resulting caps on its src pad. Encodings without a mapping leave the
+//! pad caps untouched (`ANY`).
+//!
+//! The other way around, zenohsink's `auto-encoding` publishes with the
+//! encoding of its caps ([`encoding_for_caps`]), so native Zenoh consumers can
+//! tell streams apart by encoding.
+//!
+//! The built-in table covers common media types; applications can add or
+//! override entries with [`register_encoding_caps`]:
+//!
+//! ```no_run
+//! gst::init().unwrap();
+//! gstzenoh::encoding::register_encoding_caps(
+//!     "application/x-my-telemetry",
+//!     gst::Caps::builder("application/x-telemetry").build(),
+//! );
+//! ```
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{LazyLock, Mutex};
+
+/// Built-in encoding → caps table (caps in their string form)
+const BUILTIN: &[(&str, &str)] = &[
+    ("video/h264", "video/x-h264"),
+    ("video/h265", "video/x-h265"),
+    ("video/vp8", "video/x-vp8"),
+    ("video/vp9", "video/x-vp9"),
+    ("video/av1", "video/x-av1"),
+    ("video/ogg", "application/ogg"),
+    ("image/jpeg", "image/jpeg"),
+    ("image/png", "image/png"),
+    ("image/gif", "image/gif"),
+    ("image/bmp", "image/bmp"),
+    ("image/webp", "image/webp"),
+    ("audio/aac", "audio/mpeg, mpegversion=(int)4"),
+    ("audio/mpeg", "audio/mpeg, mpegversion=(int)1"),
+    ("audio/flac", "audio/x-flac"),
+    ("audio/opus", "audio/x-opus"),
+    ("audio/vorbis", "audio/x-vorbis"),
+    ("audio/ogg", "application/ogg"),
+    ("text/plain", "text/x-raw, format=(string)utf8"),
+];
+
+/// Entries added at runtime; they take precedence over the built-in table
+static CUSTOM: LazyLock<Mutex<HashMap<String, gst::Caps>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Strips the schema suffix (`video/h264;annexb` → `video/h264`)
+fn base_encoding(encoding: &str) -> &str {
+    encoding.split(';').next().unwrap_or(encoding).trim()
+}
+
+/// Adds or replaces the caps used for a Zenoh encoding.
+///
+/// `encoding` is matched without its schema suffix. The caps must be fixed,
+/// since they are set on the pad as is.
+pub fn register_encoding_caps(encoding: &str, caps: gst::Caps) {
+    CUSTOM
+        .lock()
+        .unwrap()
+        .insert(base_encoding(encoding).to_string(), caps);
+}
+
+/// Returns the caps for a Zenoh encoding, or `None` if it has no mapping.
+pub fn caps_for_encoding(encoding: &str) -> Option<gst::Caps> {
+    let encoding = base_encoding(encoding);
+
+    if let Some(caps) = CUSTOM.lock().unwrap().get(encoding) {
+        return Some(caps.clone());
+    }
+
+    BUILTIN
+        .iter()
+        .find(|(name, _)| *name == encoding)
+        .and_then(|(_, caps)| gst::Caps::from_str(caps).ok())
+}
+
+/// Returns the Zenoh encoding for caps, or `None` if no mapping covers them.
+///
+/// An entry matches when `caps` are a subset of its caps: `video/x-h264,
+/// stream-format=avc` gives `video/h264`, while `audio/mpeg` needs its
+/// `mpegversion` to tell `audio/aac` from `audio/mpeg`. Registered entries are
+/// tried first; of the built-in entries sharing caps (Ogg), the first wins.
+pub fn encoding_for_caps(caps: &gst::CapsRef) -> Option<String> {
+    if caps.is_empty() {
+        return None;
+    }
+
+    if let Some((encoding, _)) = CUSTOM
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(_, custom)| caps.is_subset(custom))
+    {
+        return Some(encoding.clone());
+    }
+
+    BUILTIN
+        .iter()
+        .find(|(_, builtin)| {
+            gst::Caps::from_str(builtin).is_ok_and(|builtin| caps.is_subset(&builtin))
+        })
+        .map(|(encoding, _)| encoding.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_mappings() {
+        gst::init().unwrap();
+
+        for (encoding, expected) in [
+            ("video/h264", "video/x-h264"),
+            ("video/h265", "video/x-h265"),
+            ("image/jpeg", "image/jpeg"),
+            ("audio/opus", "audio/x-opus"),
+        ] {
+            let caps = caps_for_encoding(encoding).unwrap();
+            assert_eq!(caps.structure(0).unwrap().name(), expected);
+        }
+
+        let aac = caps_for_encoding("audio/aac").unwrap();
+        assert_eq!(
+            aac.structure(0).unwrap().get::<i32>("mpegversion").unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_builtin_caps_are_fixed() {
+        gst::init().unwrap();
+
+        for (encoding, _) in BUILTIN {
+            let caps = caps_for_encoding(encoding)
+                .unwrap_or_else(|| panic!("invalid caps for {}", encoding));
+            assert!(caps.is_fixed(), "caps for {} are not fixed", encoding);
+        }
+    }
+
+    #[test]
+    fn test_schema_is_ignored() {
+        gst::init().unwrap();
+
+        let caps = caps_for_encoding("video/h264;annexb").unwrap();
+        assert_eq!(caps.structure(0).unwrap().name(), "video/x-h264");
+    }
+
+    #[test]
+    fn test_unknown_encoding() {
+        gst::init().unwrap();
+
+        assert!(caps_for_encoding("zenoh/bytes").is_none());
+        assert!(caps_for_encoding("application/x-unknown").is_none());
+    }
+
+    #[test]
+    fn test_encoding_for_caps() {
+        gst::init().unwrap();
+
+        for (caps, expected) in [
+            ("video/x-h264, stream-format=(string)avc, alignment=(string)au", "video/h264"),
+            ("video/x-h265", "video/h265"),
+            ("image/jpeg, width=(int)640, height=(int)480", "image/jpeg"),
+            ("audio/mpeg, mpegversion=(int)4, stream-format=(string)adts", "audio/aac"),
+            ("audio/mpeg, mpegversion=(int)1, layer=(int)3", "audio/mpeg"),
+            ("audio/x-opus, channels=(int)2", "audio/opus"),
+            ("text/x-raw, format=(string)utf8", "text/plain"),
+        ] {
+            let caps = gst::Caps::from_str(caps).unwrap();
+            assert_eq!(encoding_for_caps(&caps).as_deref(), Some(expected), "{}", caps);
+        }
+
+        for caps in ["video/x-raw, format=(string)I420", "audio/mpeg", "ANY", "EMPTY"] {
+            let caps = gst::Caps::from_str(caps).unwrap();
+            assert!(encoding_for_caps(&caps).is_none(), "{}", caps);
+        }
+    }
+
+    #[test]
+    fn test_encoding_caps_roundtrip() {
+        gst::init().unwrap();
+
+        for (encoding, _) in BUILTIN {
+            let caps = caps_for_encoding(encoding).unwrap();
+            let back = encoding_for_caps(&caps)
+                .unwrap_or_else(|| panic!("no encoding for the caps of {}", encoding));
+            assert_eq!(caps_for_encoding(&back), Some(caps), "{} → {}", encoding, back);
+        }
+    }
+
+    #[test]
+    fn test_registered_mapping_overrides_builtin() {
+        gst::init().unwrap();
+
+        register_encoding_caps(
+            "application/x-test-telemetry",
+            gst::Caps::builder("application/x-telemetry").build(),
+        );
+        assert_eq!(
+            caps_for_encoding("application/x-test-telemetry;v2")
+                .unwrap()
+                .structure(0)
+                .unwrap()
+                .name(),
+            "application/x-telemetry"
+        );
+
+        register_encoding_caps(
+            "image/webp",
+            gst::Caps::builder("image/webp").field("test", true).build(),
+        );
+        let webp = caps_for_encoding("image/webp").unwrap();
+        assert!(webp.structure(0).unwrap().get::<bool>("test").unwrap());
+    }
+}
@@ -1,7 +1,11 @@
 //! Error handling for the Zenoh GStreamer plugin
 
+use gst::prelude::*;
 use thiserror::Error;
 
+/// Name of the element message structure posted for recoverable errors
+pub(crate) const ZENOH_ERROR_MESSAGE: &str = "zenoh-error";
+
 /// Custom error type for Zenoh operations
 #[derive(Debug, Error)]
 pub enum ZenohError {
@@ -20,6 +24,18 @@ pub enum ZenohError {
         #[source]
         source: zenoh::Error,
     },
+
+    /// Received data compressed with an algorithm this build can't decompress
+    #[error("Received {algorithm}-compressed data but this build lacks compression-{algorithm}")]
+    UnsupportedCompression { algorithm: String },
+
+    /// No router or peer became reachable within the connection timeout
+    #[error("No Zenoh router or peer reachable within {timeout_ms} ms")]
+    NotConnected { timeout_ms: u64 },
+
+    /// No matching subscriber appeared within the subscriber timeout
+    #[error("No matching Zenoh subscriber within {timeout_ms} ms")]
+    NoSubscribers { timeout_ms: u64 },
 }
 
 /// Extension trait to convert errors to GStreamer error messages
@@ -60,6 +76,34 @@ impl ErrorHandling for ZenohError {
                     ]
                 )
             }
+            ZenohError::UnsupportedCompression { algorithm } => {
+                gst::error_msg!(
+                    gst::StreamError::Decode,
+                    [
+                        "Received {}-compressed data but this build lacks compression-{}. Rebuild with that feature or disable compression on the sender.",
+                        algorithm,
+                        algorithm
+                    ]
+                )
+            }
+            ZenohError::NotConnected { timeout_ms } => {
+                gst::error_msg!(
+                    gst::ResourceError::OpenWrite,
+                    [
+                        "No Zenoh router or peer reachable within {} ms. Check network connectivity and the connect endpoints of the Zenoh configuration.",
+                        timeout_ms
+                    ]
+                )
+            }
+            ZenohError::NoSubscribers { timeout_ms } => {
+                gst::error_msg!(
+                    gst::ResourceError::OpenWrite,
+                    [
+                        "No matching Zenoh subscriber within {} ms. Check that the receivers are running and subscribe to a matching key expression.",
+                        timeout_ms
+                    ]
+                )
+            }
         }
     }
 }
@@ -76,6 +120,30 @@ impl FlowErrorHandling for ZenohError {
             ZenohError::Init(_) => gst::FlowError::NotNegotiated,
             ZenohError::KeyExpr { .. } => gst::FlowError::NotNegotiated,
             ZenohError::Publish { .. } => gst::FlowError::Error,
+            ZenohError::UnsupportedCompression { .. } => gst::FlowError::NotSupported,
+            ZenohError::NotConnected { .. } => gst::FlowError::Error,
+            ZenohError::NoSubscribers { .. } => gst::FlowError::Error,
         }
     }
 }
+
+/// Posts a `zenoh-error` element message on the bus for a recoverable failure.
+///
+/// Used where a publish or receive fails but the element keeps running, so that
+/// applications can observe transient errors (for retry or alerting logic)
+/// without parsing debug logs. Fatal errors still go through `element_error!`.
+///
+/// The message structure carries two string fields:
+/// - `error`: human-readable description of the failure
+/// - `key-expr`: the key expression the failure relates to
+pub(crate) fn post_error_message(
+    element: &gst::Element,
+    key_expr: &str,
+    error: &dyn std::fmt::Display,
+) {
+    let s = gst::Structure::builder(ZENOH_ERROR_MESSAGE)
+        .field("error", error.to_string())
+        .field("key-expr", key_expr)
+        .build();
+    let _ = element.post_message(gst::message::Element::builder(s).src(element).build());
+}
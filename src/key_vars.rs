@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! `${name}` placeholder substitution in key expressions
+//!
+//! A key expression such as `cameras/${device-id}/video` is expanded with the
+//! fields of a `gst::Structure` (e.g. `vars, device-id=front`) before the
+//! key is declared. `$$` produces a literal `$`; any other `$` is kept as is,
+//! so Zenoh's `$*` sub-chunk wildcard needs no escaping.
+
+/// Expands the `${name}` placeholders of `template` with the fields of `vars`.
+///
+/// String fields are inserted verbatim, other types in their serialized form.
+/// Returns the name of the first placeholder that has no matching field (or
+/// is unterminated) as the error.
+pub(crate) fn expand(template: &str, vars: Option<&gst::StructureRef>) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        if let Some(after) = rest.strip_prefix("$$") {
+            out.push('$');
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("${") {
+            let Some(end) = after.find('}') else {
+                return Err(rest.to_string());
+            };
+            let name = &after[..end];
+            let unresolved = || format!("${{{}}}", name);
+            let value = vars
+                .and_then(|vars| vars.value(name).ok())
+                .ok_or_else(unresolved)?;
+            match value.get::<String>() {
+                Ok(s) => out.push_str(&s),
+                Err(_) => out.push_str(&value.serialize().map_err(|_| unresolved())?),
+            }
+            rest = &after[end + 1..];
+        } else {
+            out.push('$');
+            rest = &rest[1..];
+        }
+    }
+    out.push_str(rest);
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> gst::Structure {
+        gst::Structure::builder("vars")
+            .field("device-id", "front")
+            .field("index", 3i32)
+            .build()
+    }
+
+    #[test]
+    fn test_expand_placeholders() {
+        gst::init().unwrap();
+
+        let vars = vars();
+        assert_eq!(
+            expand("cameras/${device-id}/video", Some(&vars)).unwrap(),
+            "cameras/front/video"
+        );
+        assert_eq!(
+            expand("cam${index}/${device-id}", Some(&vars)).unwrap(),
+            "cam3/front"
+        );
+        assert_eq!(expand("plain/key", None).unwrap(), "plain/key");
+    }
+
+    #[test]
+    fn test_expand_escapes() {
+        gst::init().unwrap();
+
+        let vars = vars();
+        assert_eq!(
+            expand("a/$${device-id}", Some(&vars)).unwrap(),
+            "a/${device-id}"
+        );
+        // Zenoh's sub-chunk wildcard passes through untouched
+        assert_eq!(expand("a/b$*/c", None).unwrap(), "a/b$*/c");
+    }
+
+    #[test]
+    fn test_expand_unresolved() {
+        gst::init().unwrap();
+
+        let vars = vars();
+        assert_eq!(
+            expand("cameras/${site}/video", Some(&vars)).unwrap_err(),
+            "${site}"
+        );
+        assert_eq!(
+            expand("cameras/${device-id}", None).unwrap_err(),
+            "${device-id}"
+        );
+        assert_eq!(expand("cameras/${oops", Some(&vars)).unwrap_err(), "${oops");
+    }
+}
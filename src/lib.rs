@@ -11,6 +11,10 @@
 //! - [`zenohsink`]: Publishes GStreamer buffers to Zenoh networks
 //! - [`zenohsrc`]: Subscribes to Zenoh data and delivers it to GStreamer pipelines
 //! - [`zenohdemux`]: Demultiplexes Zenoh streams by key expression, creating dynamic pads
+//! - [`zenohqueryablesrc`]: Answers Zenoh queries with the latest pipeline buffer
+//! - [`zenohget`]: Queries Zenoh once and pushes the replies, then EOS
+//! - [`zenohrecord`]: Records buffers with their timing and caps to a file
+//! - [`zenohreplay`]: Replays a `zenohrecord` recording with the recorded timing
 //!
 //! ## Features
 //!
@@ -19,7 +23,7 @@
 //! - **Session Sharing**: Efficient resource management across multiple elements
 //! - **Thread Safety**: Safe concurrent access to all components
 //! - **Error Recovery**: Comprehensive error handling and network resilience
-//! - **Optional Compression**: zstd, lz4, and gzip support via feature flags
+//! - **Optional Compression**: zstd, lz4, gzip, brotli, and snappy support via feature flags
 //!
 //! ## Quick Start (gst-launch)
 //!
@@ -179,33 +183,68 @@
 //! [`zenohsink`]: zenohsink
 //! [`zenohsrc`]: zenohsrc
 //! [`zenohdemux`]: zenohdemux
+//! [`zenohqueryablesrc`]: zenohqueryablesrc
+//! [`zenohget`]: zenohget
+//! [`zenohrecord`]: zenohrecord
+//! [`zenohreplay`]: zenohreplay
 
 use gst::glib;
 
+pub(crate) mod buffer;
+pub mod encoding;
 mod error;
+pub(crate) mod key_vars;
 pub mod metadata;
+#[cfg(feature = "metrics-http")]
+pub(crate) mod metrics;
+pub mod qos;
+pub(crate) mod recording;
+pub(crate) mod reorder;
+pub(crate) mod routing;
 pub(crate) mod session;
+pub(crate) mod stats;
+pub(crate) mod throttle;
+pub(crate) mod uri;
 pub mod utils;
 pub mod zenohdemux;
+pub mod zenohget;
+pub mod zenohqueryablesrc;
+pub mod zenohrecord;
+pub mod zenohreplay;
 pub mod zenohsink;
 pub mod zenohsrc;
 
 // Re-export main types at crate root for convenience
+pub use metadata::CapsChannel;
+pub use qos::{ZenohConsolidation, ZenohExpressCongestionControl, ZenohLocality, ZenohQueryTarget};
+pub use session::open_session_blocking;
 pub use zenohdemux::{PadNaming, ZenohDemux, ZenohDemuxBuilder};
+pub use zenohget::{ZenohGet, ZenohGetBuilder};
+pub use zenohqueryablesrc::{ZenohQueryableSrc, ZenohQueryableSrcBuilder};
+pub use zenohrecord::ZenohRecord;
+pub use zenohreplay::{ZenohReplay, ZenohReplayBuilder};
 pub use zenohsink::{ZenohSink, ZenohSinkBuilder};
-pub use zenohsrc::{ZenohSrc, ZenohSrcBuilder};
+pub use zenohsrc::{SampleKindFilter, TimestampMode, ZenohSrc, ZenohSrcBuilder};
 
 #[cfg(any(
     feature = "compression-zstd",
     feature = "compression-lz4",
-    feature = "compression-gzip"
+    feature = "compression-gzip",
+    feature = "compression-brotli",
+    feature = "compression-snappy"
 ))]
 pub mod compression;
 
 fn plugin_init(plugin: &gst::Plugin) -> Result<(), glib::BoolError> {
+    metadata::ZenohKeyMeta::register();
+    metadata::ZenohUserMeta::register();
     zenohsink::register(plugin)?;
     zenohsrc::register(plugin)?;
     zenohdemux::register(plugin)?;
+    zenohqueryablesrc::register(plugin)?;
+    zenohget::register(plugin)?;
+    zenohrecord::register(plugin)?;
+    zenohreplay::register(plugin)?;
     Ok(())
 }
 
@@ -7,6 +7,10 @@
 
 use std::collections::HashMap;
 use std::str::FromStr;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use gst::glib;
 use zenoh::bytes::ZBytes;
 
 /// Metadata keys used in Zenoh attachments
@@ -15,6 +19,8 @@ pub mod keys {
     pub const CAPS: &str = "gst.caps";
     /// Custom user-defined metadata prefix
     pub const USER_PREFIX: &str = "user.";
+    /// Custom user-defined binary metadata prefix (values are base64-encoded)
+    pub const USER_BINARY_PREFIX: &str = "user-bin.";
     /// Metadata format version
     pub const VERSION: &str = "gst.version";
     /// Compression algorithm used (if any)
@@ -33,10 +39,268 @@ pub mod keys {
     pub const FLAGS: &str = "gst.flags";
     /// Zenoh key expression the sample was received on
     pub const KEY_EXPR: &str = "zenoh.key-expr";
+    /// Per-publisher sequence number, incremented for every published buffer
+    pub const SEQNUM: &str = "gst.seqnum";
+    /// Segment rate and base time in nanoseconds (`<rate>,<base>`)
+    pub const SEGMENT: &str = "gst.segment";
+    /// Sizes of the buffers concatenated in a batched sample (comma-separated)
+    pub const BATCH: &str = "gst.batch";
+    /// Timing and flags of each buffer of a batch (`;`-separated, see
+    /// [`BatchPartTiming`](super::BatchPartTiming))
+    pub const BATCH_TIMING: &str = "gst.batch-timing";
+    /// Stream tags, a serialized `GstTagList` limited to [`TRANSMITTED_TAGS`](super::TRANSMITTED_TAGS)
+    pub const TAGS: &str = "gst.tags";
+    /// Stream id of the upstream stream-start event
+    pub const STREAM_ID: &str = "gst.stream-id";
+    /// Group id of the upstream stream-start event, when it has one
+    pub const GROUP_ID: &str = "gst.group-id";
+}
+
+/// Tags zenohsink forwards from upstream tag events; other tags are dropped.
+///
+/// Descriptive and codec tags that stay valid across the network. Tags such
+/// as images or timestamps tied to the sender's timeline are left out.
+pub const TRANSMITTED_TAGS: &[&str] = &[
+    "title",
+    "artist",
+    "album",
+    "comment",
+    "language-code",
+    "language-name",
+    "codec",
+    "audio-codec",
+    "video-codec",
+    "subtitle-codec",
+    "container-format",
+    "encoder",
+    "bitrate",
+    "nominal-bitrate",
+];
+
+/// Last key segment of the queryable zenohsink declares next to each publisher
+/// to serve the current caps of the stream
+pub const CAPS_QUERY_SUFFIX: &str = "caps";
+
+/// Returns the key expression on which the caps of the stream published on
+/// `key_expr` can be queried, e.g. `demo/video` gives `demo/video/caps`.
+///
+/// Replies carry the caps serialized as a string. Wildcards are kept, so
+/// `demo/**` queries the caps of every stream below `demo`.
+pub fn caps_query_key_expr(key_expr: &str) -> String {
+    format!("{}/{}", key_expr.trim_end_matches('/'), CAPS_QUERY_SUFFIX)
+}
+
+/// Last key segment of the sidecar key zenohsink publishes forwarded events on
+pub const EVENTS_SUFFIX: &str = "events";
+
+/// Returns the key expression on which zenohsink's `forward-events` sends the
+/// events of the stream published on `key_expr`, e.g. `demo/video` gives
+/// `demo/video/events`.
+pub fn events_key_expr(key_expr: &str) -> String {
+    format!("{}/{}", key_expr.trim_end_matches('/'), EVENTS_SUFFIX)
+}
+
+/// Last key segment of the sidecar key zenohsrc sends force-key-unit requests on
+pub const FKU_SUFFIX: &str = "fku";
+
+/// Returns the key expression on which zenohsrc's `enable-fku` requests a key
+/// unit from the publishers of `key_expr`, e.g. `demo/video` gives
+/// `demo/video/fku`.
+pub fn fku_key_expr(key_expr: &str) -> String {
+    format!("{}/{}", key_expr.trim_end_matches('/'), FKU_SUFFIX)
+}
+
+/// Key segment below which receivers advertise the compression algorithms
+/// they decompress, for zenohsink's `compression-negotiate`
+pub const COMPRESSION_CAPS_SUFFIX: &str = "compression";
+
+/// Returns the key expression below which the receivers of `key_expr`
+/// declare a liveliness token per algorithm they decompress, e.g.
+/// `demo/video` gives `demo/video/compression`, and a receiver supporting zstd
+/// declares `demo/video/compression/zstd/<id>`.
+pub fn compression_caps_key_expr(key_expr: &str) -> String {
+    format!("{}/{}", key_expr.trim_end_matches('/'), COMPRESSION_CAPS_SUFFIX)
+}
+
+/// Name of the structure of upstream force-key-unit events
+const FORCE_KEY_UNIT: &str = "GstForceKeyUnit";
+
+/// Serializes an upstream force-key-unit event relayed with `enable-fku`, or
+/// returns `None` for any other event.
+///
+/// The payload is the event structure, serialized.
+pub(crate) fn serialize_force_key_unit(event: &gst::EventRef) -> Option<String> {
+    if event.type_() != gst::EventType::CustomUpstream {
+        return None;
+    }
+    let structure = event.structure()?;
+    (structure.name() == FORCE_KEY_UNIT).then(|| structure.to_string())
+}
+
+/// Parses a force-key-unit request serialized by [`serialize_force_key_unit`]
+/// into an upstream event.
+///
+/// The running time is cleared: it is the one of the requesting pipeline, so
+/// the key unit is requested as soon as possible instead.
+pub(crate) fn deserialize_force_key_unit(payload: &str) -> Option<gst::Event> {
+    let mut structure = gst::Structure::from_str(payload).ok()?;
+    if structure.name() != FORCE_KEY_UNIT {
+        return None;
+    }
+    structure.set("running-time", gst::ClockTime::NONE);
+    Some(gst::event::CustomUpstream::new(structure))
+}
+
+/// Serializes an event forwarded with `forward-events`, or returns `None` for
+/// the event types that aren't forwarded (only EOS and custom downstream
+/// events are).
+///
+/// The payload is a serialized structure named after the event type; a custom
+/// event carries its own structure, serialized, in the `structure` field.
+pub(crate) fn serialize_event(event: &gst::EventRef) -> Option<String> {
+    let serialized = match event.view() {
+        gst::EventView::Eos(_) => gst::Structure::new_empty("eos"),
+        gst::EventView::CustomDownstream(_) => gst::Structure::builder("custom-downstream")
+            .field("structure", event.structure()?.to_string())
+            .build(),
+        _ => return None,
+    };
+    Some(serialized.to_string())
+}
+
+/// Parses an event serialized by [`serialize_event`].
+pub(crate) fn deserialize_event(payload: &str) -> Option<gst::Event> {
+    let serialized = gst::Structure::from_str(payload).ok()?;
+    match serialized.name().as_str() {
+        "eos" => Some(gst::event::Eos::new()),
+        "custom-downstream" => {
+            let structure = serialized.get::<String>("structure").ok()?;
+            Some(gst::event::CustomDownstream::new(structure.parse().ok()?))
+        }
+        _ => None,
+    }
+}
+
+/// Where zenohsink sends the caps of the stream, and where zenohsrc expects them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "GstZenohCapsChannel")]
+#[repr(u32)]
+pub enum CapsChannel {
+    /// In the attachment of the data samples
+    #[default]
+    #[enum_value(name = "Attachment", nick = "attachment")]
+    Attachment = 0,
+    /// Published on the sidecar key [`caps_query_key_expr`], leaving the data
+    /// samples without caps metadata
+    #[enum_value(name = "Sidecar Key", nick = "sidecar-key")]
+    SidecarKey = 1,
+}
+
+/// Current metadata format version (1.1 adds buffer timing support, 1.2 the
+/// segment, 1.3 batches, 1.4 tags, 1.5 the stream identity, 1.6 the timing
+/// of batched buffers)
+pub const METADATA_VERSION: &str = "1.6";
+
+/// Timing and flags of one buffer of a batch
+///
+/// Serialized as `<pts>/<dts>/<duration>/<flags>`, timestamps in nanoseconds
+/// and left empty when unset, flags as in `gst.flags`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchPartTiming {
+    /// Presentation timestamp
+    pub pts: Option<gst::ClockTime>,
+    /// Decoding timestamp
+    pub dts: Option<gst::ClockTime>,
+    /// Duration
+    pub duration: Option<gst::ClockTime>,
+    /// Buffer flags
+    pub flags: gst::BufferFlags,
+}
+
+impl BatchPartTiming {
+    /// Returns the timing and flags of `buffer`
+    pub fn from_buffer(buffer: &gst::BufferRef) -> Self {
+        Self {
+            pts: buffer.pts(),
+            dts: buffer.dts(),
+            duration: buffer.duration(),
+            flags: buffer.flags(),
+        }
+    }
+
+    fn serialize(&self) -> String {
+        let nseconds = |time: Option<gst::ClockTime>| {
+            time.map(|time| time.nseconds().to_string()).unwrap_or_default()
+        };
+        format!(
+            "{}/{}/{}/{}",
+            nseconds(self.pts),
+            nseconds(self.dts),
+            nseconds(self.duration),
+            flags_to_string(self.flags)
+        )
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let nseconds = |field: &str| match field {
+            "" => Some(None),
+            ns => ns.parse::<u64>().ok().map(|ns| Some(gst::ClockTime::from_nseconds(ns))),
+        };
+        let mut fields = s.split('/');
+        let timing = Self {
+            pts: nseconds(fields.next()?)?,
+            dts: nseconds(fields.next()?)?,
+            duration: nseconds(fields.next()?)?,
+            flags: string_to_flags(fields.next()?),
+        };
+        fields.next().is_none().then_some(timing)
+    }
+}
+
+/// Stream id and group id of a stream-start event, carried across Zenoh so
+/// receivers start their stream with the identity of the upstream one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamIdentity {
+    /// Stream id, kept as is
+    pub stream_id: String,
+    /// Group id on the sender, only meaningful to tell its groups apart
+    pub group_id: Option<u32>,
+}
+
+impl StreamIdentity {
+    /// Returns the identity of a stream-start event.
+    pub fn from_event(event: &gst::event::StreamStart) -> Self {
+        Self {
+            stream_id: event.stream_id().to_string(),
+            // Read from the structure: group ids have no public numeric value
+            group_id: event
+                .structure()
+                .and_then(|s| s.get::<u32>("group-id").ok())
+                .filter(|&group_id| group_id != 0),
+        }
+    }
 }
 
-/// Current metadata format version (1.1 adds buffer timing support)
-pub const METADATA_VERSION: &str = "1.1";
+/// Maps the group ids of received [`StreamIdentity`]s to local ones, so the
+/// streams a sender grouped together stay grouped on the receiver.
+///
+/// Group ids are only unique within a process, hence the mapping.
+#[derive(Debug, Default)]
+pub(crate) struct StreamGroups(HashMap<u32, gst::GroupId>);
+
+impl StreamGroups {
+    /// Returns the stream-start event to push for a received identity.
+    pub(crate) fn stream_start(&mut self, identity: &StreamIdentity) -> gst::Event {
+        let builder = gst::event::StreamStart::builder(&identity.stream_id);
+        match identity.group_id {
+            Some(group_id) => {
+                let local = *self.0.entry(group_id).or_insert_with(gst::GroupId::next);
+                builder.group_id(local).build()
+            }
+            None => builder.build(),
+        }
+    }
+}
 
 /// Builder for creating Zenoh attachments with GStreamer metadata
 #[derive(Debug, Default)]
@@ -49,7 +313,14 @@ pub struct MetadataBuilder {
     offset_end: Option<u64>,
     flags: Option<gst::BufferFlags>,
     key_expr: Option<String>,
+    seqnum: Option<u64>,
+    segment: Option<(f64, gst::ClockTime)>,
+    batch: Option<Vec<usize>>,
+    batch_timing: Option<Vec<BatchPartTiming>>,
+    tags: Option<gst::TagList>,
+    stream_identity: Option<StreamIdentity>,
     user_metadata: HashMap<String, String>,
+    user_metadata_bytes: HashMap<String, Vec<u8>>,
 }
 
 impl MetadataBuilder {
@@ -117,12 +388,90 @@ impl MetadataBuilder {
         self
     }
 
+    /// Set the sequence number, used by receivers to detect lost and reordered samples
+    pub fn seqnum(mut self, seqnum: u64) -> Self {
+        self.seqnum = Some(seqnum);
+        self
+    }
+
+    /// Set the segment rate and base time, so the receiver can restore them
+    ///
+    /// Only these two fields are transmitted: start, stop and position are
+    /// tied to the sender's timeline.
+    pub fn segment(mut self, segment: &gst::FormattedSegment<gst::ClockTime>) -> Self {
+        self.segment = Some((segment.rate(), segment.base().unwrap_or(gst::ClockTime::ZERO)));
+        self
+    }
+
+    /// Mark the payload as a batch: the concatenation of buffers of the given sizes
+    ///
+    /// Receivers split the payload back into one buffer per size.
+    pub fn batch(mut self, sizes: &[usize]) -> Self {
+        self.batch = Some(sizes.to_vec());
+        self
+    }
+
+    /// Set the timing and flags of the buffers of a batch, in batch order
+    ///
+    /// Receivers apply them to the buffers they split the batch into.
+    pub fn batch_timing(mut self, timing: &[BatchPartTiming]) -> Self {
+        self.batch_timing = Some(timing.to_vec());
+        self
+    }
+
+    /// Set the stream tags to transmit
+    ///
+    /// Only the tags listed in [`TRANSMITTED_TAGS`] are kept; nothing is sent
+    /// when none of them is set.
+    pub fn tags(mut self, tags: &gst::TagList) -> Self {
+        self.tags = transmitted_tags(tags);
+        self
+    }
+
+    /// Set the identity of the stream, from its stream-start event
+    pub fn stream_identity(mut self, identity: &StreamIdentity) -> Self {
+        self.stream_identity = Some(identity.clone());
+        self
+    }
+
     /// Add custom user metadata
     pub fn user_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.user_metadata.insert(key.into(), value.into());
         self
     }
 
+    /// Add custom binary user metadata (e.g. a protobuf descriptor)
+    ///
+    /// The value is base64-encoded in the attachment and read back with
+    /// [`MetadataParser::get_user_metadata_bytes`]. Binary and text user
+    /// metadata live in separate namespaces.
+    pub fn user_metadata_bytes(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.user_metadata_bytes.insert(key.into(), value.into());
+        self
+    }
+
+    /// Add every field of `fields` as user metadata
+    ///
+    /// String fields are added verbatim, `GBytes` fields as binary user metadata
+    /// and other types in their serialized form (e.g. `7` for an int). Fields
+    /// that can't be serialized are skipped.
+    pub fn user_metadata_fields(mut self, fields: &gst::StructureRef) -> Self {
+        for (key, value) in fields.iter() {
+            if let Ok(text) = value.get::<String>() {
+                self = self.user_metadata(key.as_str(), text);
+            } else if let Ok(bytes) = value.get::<gst::glib::Bytes>() {
+                self = self.user_metadata_bytes(key.as_str(), bytes.to_vec());
+            } else if let Ok(serialized) = value.serialize() {
+                self = self.user_metadata(key.as_str(), serialized.as_str());
+            }
+        }
+        self
+    }
+
     /// Build the attachment as ZBytes
     ///
     /// The attachment is encoded as a simple key-value format:
@@ -130,6 +479,10 @@ impl MetadataBuilder {
     /// - Caps are serialized using their string representation
     /// - Timestamps are serialized as nanoseconds
     /// - Flags are serialized as comma-separated names
+    /// - The segment is serialized as `<rate>,<base nanoseconds>`
+    /// - Batch buffer sizes are serialized as comma-separated byte counts
+    /// - Batch buffer timing is serialized as `;`-separated [`BatchPartTiming`]s
+    /// - Tags are serialized using their string representation
     pub fn build(self) -> Option<ZBytes> {
         let mut parts = Vec::new();
 
@@ -174,6 +527,37 @@ impl MetadataBuilder {
             parts.push(format!("{}={}", keys::KEY_EXPR, key_expr_escaped));
         }
 
+        if let Some(seqnum) = self.seqnum {
+            parts.push(format!("{}={}", keys::SEQNUM, seqnum));
+        }
+
+        if let Some((rate, base)) = self.segment {
+            parts.push(format!("{}={},{}", keys::SEGMENT, rate, base.nseconds()));
+        }
+
+        if let Some(sizes) = self.batch {
+            let sizes: Vec<String> = sizes.iter().map(|size| size.to_string()).collect();
+            parts.push(format!("{}={}", keys::BATCH, sizes.join(",")));
+        }
+
+        if let Some(timing) = self.batch_timing {
+            let timing: Vec<String> = timing.iter().map(BatchPartTiming::serialize).collect();
+            parts.push(format!("{}={}", keys::BATCH_TIMING, timing.join(";")));
+        }
+
+        if let Some(tags) = self.tags {
+            let tags_escaped = tags.to_string().replace('\n', "\\n");
+            parts.push(format!("{}={}", keys::TAGS, tags_escaped));
+        }
+
+        if let Some(identity) = self.stream_identity {
+            let stream_id_escaped = identity.stream_id.replace('\n', "\\n");
+            parts.push(format!("{}={}", keys::STREAM_ID, stream_id_escaped));
+            if let Some(group_id) = identity.group_id {
+                parts.push(format!("{}={}", keys::GROUP_ID, group_id));
+            }
+        }
+
         // Add user metadata
         for (key, value) in self.user_metadata {
             let full_key = if key.starts_with(keys::USER_PREFIX) {
@@ -186,6 +570,11 @@ impl MetadataBuilder {
             parts.push(format!("{}={}", full_key, value_escaped));
         }
 
+        // Add binary user metadata (base64 needs no escaping)
+        for (key, value) in self.user_metadata_bytes {
+            parts.push(format!("{}{}={}", keys::USER_BINARY_PREFIX, key, BASE64.encode(value)));
+        }
+
         if parts.is_empty() {
             None
         } else {
@@ -195,67 +584,194 @@ impl MetadataBuilder {
     }
 }
 
+/// Names of the buffer flags carried in `gst.flags`.
+///
+/// Every stable `gst::BufferFlags` variant except `TAG_MEMORY`, which only
+/// describes the sender's memory and means nothing to the receiver.
+const BUFFER_FLAG_NAMES: &[(gst::BufferFlags, &str)] = &[
+    (gst::BufferFlags::LIVE, "live"),
+    (gst::BufferFlags::DISCONT, "discont"),
+    (gst::BufferFlags::DELTA_UNIT, "delta"),
+    (gst::BufferFlags::HEADER, "header"),
+    (gst::BufferFlags::GAP, "gap"),
+    (gst::BufferFlags::DROPPABLE, "droppable"),
+    (gst::BufferFlags::MARKER, "marker"),
+    (gst::BufferFlags::CORRUPTED, "corrupted"),
+    (gst::BufferFlags::NON_DROPPABLE, "non-droppable"),
+    (gst::BufferFlags::DECODE_ONLY, "decode-only"),
+    (gst::BufferFlags::RESYNC, "resync"),
+    (gst::BufferFlags::SYNC_AFTER, "sync-after"),
+];
+
 /// Convert GStreamer buffer flags to a comma-separated string
 fn flags_to_string(flags: gst::BufferFlags) -> String {
-    let mut parts = Vec::new();
+    BUFFER_FLAG_NAMES
+        .iter()
+        .filter(|(flag, _)| flags.contains(*flag))
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>()
+        .join(",")
+}
 
-    if flags.contains(gst::BufferFlags::LIVE) {
-        parts.push("live");
-    }
-    if flags.contains(gst::BufferFlags::DISCONT) {
-        parts.push("discont");
-    }
-    if flags.contains(gst::BufferFlags::DELTA_UNIT) {
-        parts.push("delta");
-    }
-    if flags.contains(gst::BufferFlags::HEADER) {
-        parts.push("header");
-    }
-    if flags.contains(gst::BufferFlags::GAP) {
-        parts.push("gap");
-    }
-    if flags.contains(gst::BufferFlags::DROPPABLE) {
-        parts.push("droppable");
+/// Parse a comma-separated string back to GStreamer buffer flags
+fn string_to_flags(s: &str) -> gst::BufferFlags {
+    // Unknown flags are ignored for forward compatibility
+    s.split(',')
+        .filter_map(|part| {
+            let part = part.trim();
+            BUFFER_FLAG_NAMES.iter().find(|(_, name)| *name == part)
+        })
+        .fold(gst::BufferFlags::empty(), |flags, (flag, _)| flags | *flag)
+}
+
+/// Returns the [`TRANSMITTED_TAGS`] of `tags`, or `None` when it has none of them.
+pub(crate) fn transmitted_tags(tags: &gst::TagListRef) -> Option<gst::TagList> {
+    let unsupported: Vec<String> = tags
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .filter(|name| !TRANSMITTED_TAGS.contains(&name.as_str()))
+        .collect();
+    let mut filtered = tags.copy();
+    {
+        let filtered = filtered.make_mut();
+        for name in unsupported {
+            filtered.remove_generic(name.as_str());
+        }
     }
-    if flags.contains(gst::BufferFlags::MARKER) {
-        parts.push("marker");
+    (!filtered.is_empty()).then_some(filtered)
+}
+
+/// Returns the compression algorithm of a payload if this build can't decompress it.
+///
+/// Receivers check this before using a payload so that data compressed with an
+/// algorithm whose feature isn't compiled in is reported instead of being
+/// delivered downstream as if it were raw.
+pub(crate) fn unsupported_compression(metadata: &MetadataParser) -> Option<&str> {
+    let algorithm = metadata.compression()?;
+
+    #[cfg(any(
+        feature = "compression-zstd",
+        feature = "compression-lz4",
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
+    ))]
+    if crate::compression::CompressionType::from_metadata_value(algorithm).is_some() {
+        return None;
     }
-    if flags.contains(gst::BufferFlags::CORRUPTED) {
-        parts.push("corrupted");
+
+    Some(algorithm)
+}
+
+/// Custom buffer meta carrying the Zenoh key expression a buffer was received on
+///
+/// zenohdemux attaches it to every buffer it pushes, so applications can map
+/// buffers back to their exact key even when pad names are lossy (e.g.
+/// `PadNaming::Hash`). It is a `GstCustomMeta` named [`ZenohKeyMeta::NAME`]
+/// whose structure holds a `key-expr` string field, so it can also be read from
+/// C or Python.
+#[derive(Debug)]
+pub struct ZenohKeyMeta;
+
+impl ZenohKeyMeta {
+    /// Registered name of the custom meta
+    pub const NAME: &'static str = "GstZenohKeyMeta";
+    /// Structure field holding the key expression
+    const FIELD: &'static str = "key-expr";
+
+    /// Registers the meta with GStreamer. Done at plugin initialization;
+    /// calling it again is harmless.
+    pub fn register() {
+        if !gst::meta::CustomMeta::is_registered(Self::NAME) {
+            gst::meta::CustomMeta::register(Self::NAME, &[]);
+        }
     }
-    if flags.contains(gst::BufferFlags::NON_DROPPABLE) {
-        parts.push("non-droppable");
+
+    /// Attaches `key_expr` to `buffer`
+    pub fn add(buffer: &mut gst::BufferRef, key_expr: &str) -> Result<(), gst::glib::BoolError> {
+        let mut meta = gst::meta::CustomMeta::add(buffer, Self::NAME)?;
+        meta.mut_structure().set(Self::FIELD, key_expr);
+        Ok(())
     }
 
-    parts.join(",")
+    /// Returns the key expression attached to `buffer`, if any
+    pub fn key_expr(buffer: &gst::BufferRef) -> Option<String> {
+        let meta = gst::meta::CustomMeta::from_buffer(buffer, Self::NAME).ok()?;
+        meta.structure().get::<String>(Self::FIELD).ok()
+    }
 }
 
-/// Parse a comma-separated string back to GStreamer buffer flags
-fn string_to_flags(s: &str) -> gst::BufferFlags {
-    let mut flags = gst::BufferFlags::empty();
+/// Custom buffer meta carrying the user metadata a buffer was published with
+///
+/// zenohsrc and zenohdemux attach it to buffers whose attachment had user
+/// metadata, so application-defined fields can be read downstream (e.g. in an
+/// appsink). It is a `GstCustomMeta` named [`ZenohUserMeta::NAME`] whose
+/// structure has one field per entry, named as in the attachment: text values
+/// as `user.<key>` strings and binary values as `user-bin.<key>` `GBytes`, so
+/// it can also be read from C or Python.
+#[derive(Debug)]
+pub struct ZenohUserMeta;
+
+impl ZenohUserMeta {
+    /// Registered name of the custom meta
+    pub const NAME: &'static str = "GstZenohUserMeta";
+
+    /// Registers the meta with GStreamer. Done at plugin initialization;
+    /// calling it again is harmless.
+    pub fn register() {
+        if !gst::meta::CustomMeta::is_registered(Self::NAME) {
+            gst::meta::CustomMeta::register(Self::NAME, &[]);
+        }
+    }
+
+    /// Attaches the text and binary user metadata of `metadata` to `buffer`.
+    ///
+    /// Does nothing when `metadata` has no user metadata.
+    pub fn add(
+        buffer: &mut gst::BufferRef,
+        metadata: &MetadataParser,
+    ) -> Result<(), gst::glib::BoolError> {
+        if metadata.user_metadata.is_empty() && metadata.user_metadata_bytes.is_empty() {
+            return Ok(());
+        }
 
-    for part in s.split(',') {
-        match part.trim() {
-            "live" => flags |= gst::BufferFlags::LIVE,
-            "discont" => flags |= gst::BufferFlags::DISCONT,
-            "delta" => flags |= gst::BufferFlags::DELTA_UNIT,
-            "header" => flags |= gst::BufferFlags::HEADER,
-            "gap" => flags |= gst::BufferFlags::GAP,
-            "droppable" => flags |= gst::BufferFlags::DROPPABLE,
-            "marker" => flags |= gst::BufferFlags::MARKER,
-            "corrupted" => flags |= gst::BufferFlags::CORRUPTED,
-            "non-droppable" => flags |= gst::BufferFlags::NON_DROPPABLE,
-            _ => {} // Ignore unknown flags for forward compatibility
+        let mut meta = gst::meta::CustomMeta::add(buffer, Self::NAME)?;
+        let structure = meta.mut_structure();
+        for (key, value) in &metadata.user_metadata {
+            structure.set(format!("{}{}", keys::USER_PREFIX, key), value.as_str());
+        }
+        for (key, value) in &metadata.user_metadata_bytes {
+            structure.set(
+                format!("{}{}", keys::USER_BINARY_PREFIX, key),
+                gst::glib::Bytes::from(value),
+            );
         }
+        Ok(())
+    }
+
+    /// Returns the text user metadata `key` attached to `buffer`, if any
+    pub fn user_metadata(buffer: &gst::BufferRef, key: &str) -> Option<String> {
+        let meta = gst::meta::CustomMeta::from_buffer(buffer, Self::NAME).ok()?;
+        meta.structure()
+            .get::<String>(format!("{}{}", keys::USER_PREFIX, key))
+            .ok()
     }
 
-    flags
+    /// Returns the binary user metadata `key` attached to `buffer`, if any
+    pub fn user_metadata_bytes(buffer: &gst::BufferRef, key: &str) -> Option<Vec<u8>> {
+        let meta = gst::meta::CustomMeta::from_buffer(buffer, Self::NAME).ok()?;
+        meta.structure()
+            .get::<gst::glib::Bytes>(format!("{}{}", keys::USER_BINARY_PREFIX, key))
+            .ok()
+            .map(|bytes| bytes.to_vec())
+    }
 }
 
 /// Parse metadata from a Zenoh attachment
 #[derive(Debug, Default)]
 pub struct MetadataParser {
     caps: Option<gst::Caps>,
+    caps_error: Option<String>,
     pts: Option<gst::ClockTime>,
     dts: Option<gst::ClockTime>,
     duration: Option<gst::ClockTime>,
@@ -263,7 +779,15 @@ pub struct MetadataParser {
     offset_end: Option<u64>,
     flags: Option<gst::BufferFlags>,
     key_expr: Option<String>,
+    seqnum: Option<u64>,
+    segment: Option<(f64, gst::ClockTime)>,
+    batch: Option<Vec<usize>>,
+    batch_timing: Option<Vec<BatchPartTiming>>,
+    tags: Option<gst::TagList>,
+    stream_id: Option<String>,
+    group_id: Option<u32>,
     user_metadata: HashMap<String, String>,
+    user_metadata_bytes: HashMap<String, Vec<u8>>,
     version: Option<String>,
 }
 
@@ -293,11 +817,13 @@ impl MetadataParser {
                     parser.version = Some(value_unescaped);
                 }
                 keys::CAPS => {
-                    // Parse caps from string
+                    // Malformed caps don't invalidate the rest of the metadata;
+                    // receivers report them and keep the payload
                     match gst::Caps::from_str(&value_unescaped) {
                         Ok(caps) => parser.caps = Some(caps),
                         Err(_) => {
-                            return Err(format!("Failed to parse caps '{}'", value_unescaped));
+                            parser.caps_error =
+                                Some(format!("Failed to parse caps '{}'", value_unescaped));
                         }
                     }
                 }
@@ -332,6 +858,50 @@ impl MetadataParser {
                 keys::KEY_EXPR => {
                     parser.key_expr = Some(value_unescaped);
                 }
+                keys::SEQNUM => {
+                    if let Ok(seqnum) = value_unescaped.parse::<u64>() {
+                        parser.seqnum = Some(seqnum);
+                    }
+                }
+                keys::SEGMENT => {
+                    // Malformed values and rates a segment can't hold are skipped
+                    if let Some((rate, base)) = value_unescaped.split_once(',')
+                        && let Ok(rate) = rate.parse::<f64>()
+                        && let Ok(base) = base.parse::<u64>()
+                        && rate.is_normal()
+                    {
+                        parser.segment = Some((rate, gst::ClockTime::from_nseconds(base)));
+                    }
+                }
+                keys::BATCH => {
+                    // A batch with any malformed size is skipped as a whole
+                    parser.batch = value_unescaped
+                        .split(',')
+                        .map(|size| size.parse::<usize>().ok())
+                        .collect();
+                }
+                keys::BATCH_TIMING => {
+                    // Likewise, any malformed entry discards the batch timing
+                    parser.batch_timing =
+                        value_unescaped.split(';').map(BatchPartTiming::parse).collect();
+                }
+                keys::TAGS => {
+                    // Malformed tags are skipped, like the other optional entries
+                    parser.tags = gst::TagList::from_str(&value_unescaped).ok();
+                }
+                keys::STREAM_ID => {
+                    parser.stream_id = Some(value_unescaped);
+                }
+                keys::GROUP_ID => {
+                    parser.group_id = value_unescaped.parse::<u32>().ok().filter(|&id| id != 0);
+                }
+                k if k.starts_with(keys::USER_BINARY_PREFIX) => {
+                    // Values that aren't valid base64 are skipped
+                    if let Ok(bytes) = BASE64.decode(value) {
+                        let user_key = k.trim_start_matches(keys::USER_BINARY_PREFIX);
+                        parser.user_metadata_bytes.insert(user_key.to_string(), bytes);
+                    }
+                }
                 k if k.starts_with(keys::USER_PREFIX) => {
                     let user_key = k.trim_start_matches(keys::USER_PREFIX);
                     parser
@@ -352,6 +922,13 @@ impl MetadataParser {
         self.caps.as_ref()
     }
 
+    /// Get the error of a caps entry that couldn't be parsed, if any
+    ///
+    /// The rest of the metadata is still parsed; [`caps`](Self::caps) is `None`.
+    pub fn caps_error(&self) -> Option<&str> {
+        self.caps_error.as_deref()
+    }
+
     /// Get the presentation timestamp
     pub fn pts(&self) -> Option<gst::ClockTime> {
         self.pts
@@ -387,6 +964,46 @@ impl MetadataParser {
         self.key_expr.as_deref()
     }
 
+    /// Get the sequence number
+    pub fn seqnum(&self) -> Option<u64> {
+        self.seqnum
+    }
+
+    /// Get a TIME segment carrying the transmitted rate and base time
+    ///
+    /// The other fields keep their defaults; see [`MetadataBuilder::segment`].
+    pub fn segment(&self) -> Option<gst::FormattedSegment<gst::ClockTime>> {
+        self.segment.map(|(rate, base)| {
+            let mut segment = gst::FormattedSegment::<gst::ClockTime>::new();
+            segment.set_rate(rate);
+            segment.set_base(base);
+            segment
+        })
+    }
+
+    /// Get the sizes of the buffers making up a batched payload
+    pub fn batch(&self) -> Option<&[usize]> {
+        self.batch.as_deref()
+    }
+
+    /// Get the timing and flags of the buffers making up a batched payload
+    pub fn batch_timing(&self) -> Option<&[BatchPartTiming]> {
+        self.batch_timing.as_deref()
+    }
+
+    /// Get the stream tags
+    pub fn tags(&self) -> Option<&gst::TagList> {
+        self.tags.as_ref()
+    }
+
+    /// Get the identity of the stream, when the stream id was sent
+    pub fn stream_identity(&self) -> Option<StreamIdentity> {
+        Some(StreamIdentity {
+            stream_id: self.stream_id.clone()?,
+            group_id: self.group_id,
+        })
+    }
+
     /// Get the metadata format version
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
@@ -402,6 +1019,26 @@ impl MetadataParser {
         self.user_metadata.get(key).map(|s| s.as_str())
     }
 
+    /// Get all binary user metadata
+    pub fn user_metadata_bytes(&self) -> &HashMap<String, Vec<u8>> {
+        &self.user_metadata_bytes
+    }
+
+    /// Get a specific binary user metadata value
+    pub fn get_user_metadata_bytes(&self, key: &str) -> Option<&[u8]> {
+        self.user_metadata_bytes.get(key).map(|v| v.as_slice())
+    }
+
+    /// Get the compression algorithm the payload was compressed with, if any
+    ///
+    /// Returns the raw `gst.compression` value (e.g. "zstd"), so it is
+    /// available even in builds without any compression feature. "none" is
+    /// reported as `None`.
+    pub fn compression(&self) -> Option<&str> {
+        self.get_user_metadata(keys::COMPRESSION)
+            .filter(|algorithm| *algorithm != "none")
+    }
+
     /// Apply parsed buffer timing to a mutable buffer
     ///
     /// This sets PTS, DTS, duration, offset, offset_end, and flags on the buffer
@@ -460,6 +1097,51 @@ mod tests {
         assert_eq!(structure.get::<i32>("width").unwrap(), 1920);
     }
 
+    #[test]
+    fn test_metadata_compression_marker() {
+        let zbytes = MetadataBuilder::new()
+            .user_metadata(keys::COMPRESSION, "zstd")
+            .build()
+            .expect("Failed to build");
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert_eq!(parser.compression(), Some("zstd"));
+
+        // "none" and a missing marker both mean uncompressed
+        let zbytes = MetadataBuilder::new()
+            .user_metadata(keys::COMPRESSION, "none")
+            .build()
+            .expect("Failed to build");
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert_eq!(parser.compression(), None);
+        assert_eq!(unsupported_compression(&parser), None);
+
+        let zbytes = MetadataBuilder::new().build().expect("Failed to build");
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert_eq!(parser.compression(), None);
+    }
+
+    #[test]
+    fn test_unsupported_compression() {
+        // An algorithm no build knows about is always unsupported
+        let zbytes = MetadataBuilder::new()
+            .user_metadata(keys::COMPRESSION, "xz")
+            .build()
+            .expect("Failed to build");
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert_eq!(unsupported_compression(&parser), Some("xz"));
+
+        let zbytes = MetadataBuilder::new()
+            .user_metadata(keys::COMPRESSION, "zstd")
+            .build()
+            .expect("Failed to build");
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        if cfg!(feature = "compression-zstd") {
+            assert_eq!(unsupported_compression(&parser), None);
+        } else {
+            assert_eq!(unsupported_compression(&parser), Some("zstd"));
+        }
+    }
+
     #[test]
     fn test_metadata_builder_empty() {
         let zbytes = MetadataBuilder::new().build();
@@ -501,6 +1183,38 @@ mod tests {
         assert_eq!(parser.get_user_metadata("key2"), Some("value2"));
     }
 
+    #[test]
+    fn test_metadata_user_bytes_round_trip() {
+        // Not valid UTF-8, and contains bytes the text format would mangle
+        let descriptor = vec![0x00, 0xff, 0xfe, b'\n', b'=', 0x80, 0x0a, 0xc3];
+        assert!(std::str::from_utf8(&descriptor).is_err());
+
+        let zbytes = MetadataBuilder::new()
+            .user_metadata("name", "camera")
+            .user_metadata_bytes("descriptor", descriptor.clone())
+            .user_metadata_bytes("empty", Vec::new())
+            .build()
+            .expect("Failed to build");
+
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert_eq!(parser.get_user_metadata_bytes("descriptor"), Some(descriptor.as_slice()));
+        assert_eq!(parser.get_user_metadata_bytes("empty"), Some(&[][..]));
+        assert_eq!(parser.user_metadata_bytes().len(), 2);
+
+        // Text and binary namespaces don't mix
+        assert_eq!(parser.get_user_metadata("name"), Some("camera"));
+        assert_eq!(parser.get_user_metadata("descriptor"), None);
+        assert_eq!(parser.get_user_metadata_bytes("name"), None);
+    }
+
+    #[test]
+    fn test_metadata_user_bytes_invalid_base64_skipped() {
+        let zbytes = ZBytes::from(b"gst.version=1.1\nuser-bin.blob=not base64!".to_vec());
+
+        let parser = MetadataParser::parse(&zbytes).expect("Should parse despite invalid base64");
+        assert_eq!(parser.get_user_metadata_bytes("blob"), None);
+    }
+
     #[test]
     fn test_metadata_newline_escaping() {
         gst::init().unwrap();
@@ -625,6 +1339,173 @@ mod tests {
         assert!(buffer.flags().contains(gst::BufferFlags::DISCONT));
     }
 
+    #[test]
+    fn test_segment_round_trip() {
+        gst::init().unwrap();
+
+        let mut segment = gst::FormattedSegment::<gst::ClockTime>::new();
+        segment.set_rate(2.5);
+        segment.set_base(gst::ClockTime::from_seconds(7));
+        segment.set_start(gst::ClockTime::from_seconds(3));
+
+        let zbytes = MetadataBuilder::new()
+            .segment(&segment)
+            .build()
+            .expect("Failed to build");
+
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        let parsed = parser.segment().expect("Should have a segment");
+
+        assert_eq!(parsed.rate(), 2.5);
+        assert_eq!(parsed.base(), Some(gst::ClockTime::from_seconds(7)));
+        // Only rate and base are transmitted
+        assert_eq!(parsed.start(), Some(gst::ClockTime::ZERO));
+    }
+
+    #[test]
+    fn test_segment_invalid_skipped() {
+        for value in ["0,1000", "fast,1000", "2.0", "2.0,-5"] {
+            let zbytes = ZBytes::from(format!("{}={}", keys::SEGMENT, value).into_bytes());
+            let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+            assert!(parser.segment().is_none(), "'{}' should be skipped", value);
+        }
+    }
+
+    #[test]
+    fn test_batch_round_trip() {
+        let zbytes = MetadataBuilder::new()
+            .batch(&[1200, 0, 64])
+            .build()
+            .expect("Failed to build");
+
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert_eq!(parser.batch(), Some(&[1200, 0, 64][..]));
+
+        for value in ["", "12,x", "12,,3", "-1"] {
+            let zbytes = ZBytes::from(format!("{}={}", keys::BATCH, value).into_bytes());
+            let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+            assert!(parser.batch().is_none(), "'{}' should be skipped", value);
+        }
+    }
+
+    #[test]
+    fn test_batch_timing_round_trip() {
+        gst::init().unwrap();
+
+        let timing = [
+            BatchPartTiming {
+                pts: Some(gst::ClockTime::from_mseconds(40)),
+                dts: Some(gst::ClockTime::from_mseconds(20)),
+                duration: Some(gst::ClockTime::from_mseconds(20)),
+                flags: gst::BufferFlags::empty(),
+            },
+            BatchPartTiming {
+                pts: Some(gst::ClockTime::from_mseconds(60)),
+                dts: None,
+                duration: None,
+                flags: gst::BufferFlags::DELTA_UNIT | gst::BufferFlags::MARKER,
+            },
+        ];
+        let zbytes = MetadataBuilder::new()
+            .batch(&[10, 20])
+            .batch_timing(&timing)
+            .build()
+            .expect("Failed to build");
+
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert_eq!(parser.batch_timing(), Some(&timing[..]));
+
+        for value in ["", "1/2/3", "1/2/3/delta/4", "x//3/;1///", "-1///"] {
+            let zbytes = ZBytes::from(format!("{}={}", keys::BATCH_TIMING, value).into_bytes());
+            let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+            assert!(parser.batch_timing().is_none(), "'{}' should be skipped", value);
+        }
+    }
+
+    #[test]
+    fn test_tags_round_trip() {
+        gst::init().unwrap();
+
+        let mut tags = gst::TagList::new();
+        {
+            let tags = tags.get_mut().unwrap();
+            tags.add::<gst::tags::Title>(&"Commentary", gst::TagMergeMode::Append);
+            tags.add::<gst::tags::LanguageCode>(&"fr", gst::TagMergeMode::Append);
+            tags.add::<gst::tags::Bitrate>(&128_000, gst::TagMergeMode::Append);
+            // Not transmitted
+            tags.add::<gst::tags::TrackNumber>(&3, gst::TagMergeMode::Append);
+        }
+
+        let zbytes = MetadataBuilder::new()
+            .tags(&tags)
+            .build()
+            .expect("Failed to build");
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        let parsed = parser.tags().expect("Should have tags");
+
+        assert_eq!(parsed.get::<gst::tags::Title>().unwrap().get(), "Commentary");
+        assert_eq!(parsed.get::<gst::tags::LanguageCode>().unwrap().get(), "fr");
+        assert_eq!(parsed.get::<gst::tags::Bitrate>().unwrap().get(), 128_000);
+        assert!(parsed.get::<gst::tags::TrackNumber>().is_none());
+
+        // Nothing left to send
+        let mut untransmitted = gst::TagList::new();
+        untransmitted
+            .get_mut()
+            .unwrap()
+            .add::<gst::tags::TrackNumber>(&3, gst::TagMergeMode::Append);
+        let zbytes = MetadataBuilder::new()
+            .tags(&untransmitted)
+            .build()
+            .expect("Failed to build");
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert!(parser.tags().is_none());
+    }
+
+    #[test]
+    fn test_stream_identity_round_trip() {
+        gst::init().unwrap();
+
+        let group_id = gst::GroupId::next();
+        let event = gst::event::StreamStart::builder("upstream/video")
+            .group_id(group_id)
+            .build();
+        let gst::EventView::StreamStart(stream_start) = event.view() else {
+            unreachable!();
+        };
+        let identity = StreamIdentity::from_event(stream_start);
+        assert_eq!(identity.stream_id, "upstream/video");
+        assert!(identity.group_id.is_some());
+
+        let zbytes = MetadataBuilder::new()
+            .stream_identity(&identity)
+            .build()
+            .expect("Failed to build");
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert_eq!(parser.stream_identity(), Some(identity.clone()));
+
+        // Streams of one sender group share a local group
+        let mut groups = StreamGroups::default();
+        let first = groups.stream_start(&identity);
+        let second = groups.stream_start(&StreamIdentity {
+            stream_id: "upstream/audio".to_string(),
+            ..identity
+        });
+        let group = |event: &gst::Event| match event.view() {
+            gst::EventView::StreamStart(stream_start) => stream_start.group_id(),
+            _ => unreachable!(),
+        };
+        assert!(group(&first).is_some());
+        assert_eq!(group(&first), group(&second));
+
+        // No group id without one upstream
+        let event = gst::event::StreamStart::new("ungrouped");
+        let gst::EventView::StreamStart(stream_start) = event.view() else {
+            unreachable!();
+        };
+        assert_eq!(StreamIdentity::from_event(stream_start).group_id, None);
+    }
+
     #[test]
     fn test_flags_serialization() {
         // Test all supported flags
@@ -636,10 +1517,14 @@ mod tests {
             | gst::BufferFlags::DROPPABLE
             | gst::BufferFlags::MARKER
             | gst::BufferFlags::CORRUPTED
-            | gst::BufferFlags::NON_DROPPABLE;
+            | gst::BufferFlags::NON_DROPPABLE
+            | gst::BufferFlags::DECODE_ONLY
+            | gst::BufferFlags::RESYNC
+            | gst::BufferFlags::SYNC_AFTER;
 
-        let flags_str = flags_to_string(all_flags);
+        let flags_str = flags_to_string(all_flags | gst::BufferFlags::TAG_MEMORY);
         let parsed_flags = string_to_flags(&flags_str);
+        assert_eq!(parsed_flags, all_flags, "TAG_MEMORY isn't sent: {}", flags_str);
 
         assert!(parsed_flags.contains(gst::BufferFlags::LIVE));
         assert!(parsed_flags.contains(gst::BufferFlags::DISCONT));
@@ -750,13 +1635,34 @@ mod tests {
     fn test_malformed_metadata_invalid_caps() {
         gst::init().unwrap();
 
-        // Invalid caps string
-        let invalid_caps = "gst.version=1.0\ngst.caps=not_a_valid_caps!!!";
+        // Invalid caps string: reported, the other entries are kept
+        let invalid_caps = "gst.version=1.0\ngst.caps=not_a_valid_caps!!!\ngst.pts=1000";
         let zbytes = ZBytes::from(invalid_caps.as_bytes().to_vec());
 
-        let result = MetadataParser::parse(&zbytes);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("Failed to parse caps"));
+        let parser = MetadataParser::parse(&zbytes).expect("Should parse despite invalid caps");
+        assert!(parser.caps().is_none());
+        assert!(parser.caps_error().unwrap().contains("Failed to parse caps"));
+        assert_eq!(parser.pts(), Some(gst::ClockTime::from_nseconds(1000)));
+    }
+
+    #[test]
+    fn test_user_metadata_fields() {
+        gst::init().unwrap();
+
+        let fields = gst::Structure::builder("user")
+            .field("producer", "camera-07")
+            .field("site", 3i32)
+            .field("key", gst::glib::Bytes::from_static(&[1, 2, 3]))
+            .build();
+        let zbytes = MetadataBuilder::new()
+            .user_metadata_fields(&fields)
+            .build()
+            .unwrap();
+
+        let parser = MetadataParser::parse(&zbytes).expect("Failed to parse");
+        assert_eq!(parser.user_metadata().get("producer").unwrap(), "camera-07");
+        assert_eq!(parser.user_metadata().get("site").unwrap(), "3");
+        assert_eq!(parser.user_metadata_bytes().get("key").unwrap(), &vec![1, 2, 3]);
     }
 
     #[test]
@@ -796,6 +1702,60 @@ mod tests {
         assert!(parser.pts().is_none());
     }
 
+    #[test]
+    fn test_key_meta_round_trip() {
+        gst::init().unwrap();
+        ZenohKeyMeta::register();
+        // Registering twice is a no-op
+        ZenohKeyMeta::register();
+
+        let mut buffer = gst::Buffer::with_size(4).unwrap();
+        assert_eq!(ZenohKeyMeta::key_expr(&buffer), None);
+
+        ZenohKeyMeta::add(buffer.get_mut().unwrap(), "camera/front").unwrap();
+        assert_eq!(
+            ZenohKeyMeta::key_expr(&buffer).as_deref(),
+            Some("camera/front")
+        );
+
+        // The meta survives a deep copy
+        let copy = buffer.copy_deep().unwrap();
+        assert_eq!(ZenohKeyMeta::key_expr(&copy).as_deref(), Some("camera/front"));
+    }
+
+    #[test]
+    fn test_user_meta_round_trip() {
+        gst::init().unwrap();
+        ZenohUserMeta::register();
+
+        let attachment = MetadataBuilder::new()
+            .user_metadata("sensor-id", "imu-42")
+            .user_metadata_bytes("sensor-id", vec![0u8, 1, 2])
+            .build()
+            .unwrap();
+        let parser = MetadataParser::parse(&attachment).unwrap();
+
+        let mut buffer = gst::Buffer::with_size(4).unwrap();
+        assert_eq!(ZenohUserMeta::user_metadata(&buffer, "sensor-id"), None);
+
+        ZenohUserMeta::add(buffer.get_mut().unwrap(), &parser).unwrap();
+        assert_eq!(
+            ZenohUserMeta::user_metadata(&buffer, "sensor-id").as_deref(),
+            Some("imu-42")
+        );
+        // Text and binary values don't collide
+        assert_eq!(
+            ZenohUserMeta::user_metadata_bytes(&buffer, "sensor-id"),
+            Some(vec![0u8, 1, 2])
+        );
+        assert_eq!(ZenohUserMeta::user_metadata(&buffer, "missing"), None);
+
+        // Nothing is attached without user metadata
+        let mut buffer = gst::Buffer::with_size(4).unwrap();
+        ZenohUserMeta::add(buffer.get_mut().unwrap(), &MetadataParser::default()).unwrap();
+        assert!(gst::meta::CustomMeta::from_buffer(&buffer, ZenohUserMeta::NAME).is_err());
+    }
+
     #[test]
     fn test_unknown_flags_ignored() {
         // Unknown flags should be ignored
@@ -807,4 +1767,63 @@ mod tests {
         assert!(parsed.contains(gst::BufferFlags::DISCONT));
         // Unknown flags are silently ignored (no panic)
     }
+
+    #[test]
+    fn test_event_serialization() {
+        gst::init().unwrap();
+
+        assert_eq!(events_key_expr("demo/video/"), "demo/video/events");
+
+        let payload = serialize_event(&gst::event::Eos::new()).unwrap();
+        let event = deserialize_event(&payload).unwrap();
+        assert_eq!(event.type_(), gst::EventType::Eos);
+
+        let structure = gst::Structure::builder("scene-change")
+            .field("camera", "front, left")
+            .field("index", 7i32)
+            .build();
+        let payload =
+            serialize_event(&gst::event::CustomDownstream::new(structure.clone())).unwrap();
+        let event = deserialize_event(&payload).unwrap();
+        assert_eq!(event.type_(), gst::EventType::CustomDownstream);
+        assert_eq!(event.structure(), Some(structure.as_ref()));
+
+        // Other events aren't forwarded
+        assert!(serialize_event(&gst::event::FlushStop::new(true)).is_none());
+        assert!(deserialize_event("flush-stop").is_none());
+        assert!(deserialize_event("not a structure, =").is_none());
+    }
+
+    #[test]
+    fn test_force_key_unit_serialization() {
+        gst::init().unwrap();
+
+        assert_eq!(fku_key_expr("demo/video"), "demo/video/fku");
+
+        let structure = gst::Structure::builder("GstForceKeyUnit")
+            .field("running-time", gst::ClockTime::from_seconds(3))
+            .field("all-headers", true)
+            .field("count", 2u32)
+            .build();
+        let payload =
+            serialize_force_key_unit(&gst::event::CustomUpstream::new(structure)).unwrap();
+        let event = deserialize_force_key_unit(&payload).unwrap();
+        assert_eq!(event.type_(), gst::EventType::CustomUpstream);
+        let structure = event.structure().unwrap();
+        assert_eq!(structure.name(), "GstForceKeyUnit");
+        assert!(structure.get::<bool>("all-headers").unwrap());
+        assert_eq!(structure.get::<u32>("count").unwrap(), 2);
+        assert_eq!(
+            structure
+                .get::<Option<gst::ClockTime>>("running-time")
+                .unwrap(),
+            None,
+            "the running time of the requester doesn't apply to the publisher"
+        );
+
+        // Other upstream events aren't relayed
+        let other = gst::event::CustomUpstream::new(gst::Structure::new_empty("other"));
+        assert!(serialize_force_key_unit(&other).is_none());
+        assert!(deserialize_force_key_unit("other").is_none());
+    }
 }
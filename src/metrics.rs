@@ -0,0 +1,248 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Prometheus endpoint for element statistics (`metrics-http` feature)
+//!
+//! An element with a non-zero `metrics-port` serves its statistics on
+//! `http://<metrics-address>:<port>/metrics` in the Prometheus text format, from
+//! a background thread running between `start()` and `stop()`. The endpoint
+//! listens on the loopback interface unless `metrics-address` says otherwise.
+//! The metrics are generated from the `stats-json` snapshot, so every statistic
+//! is exported under its property name (`bytes-sent` becomes
+//! `gstzenoh_bytes_sent`) with `element` and `factory` labels, as a counter when
+//! it only ever grows and as a gauge otherwise.
+//!
+//! The server is a minimal HTTP/1.1 responder on top of `std::net`, answering
+//! one request per connection, so the feature pulls in no dependency.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use gst::prelude::*;
+
+static CAT: LazyLock<gst::DebugCategory> = LazyLock::new(|| {
+    gst::DebugCategory::new(
+        "zenohmetrics",
+        gst::DebugColorFlags::empty(),
+        Some("Zenoh metrics endpoint"),
+    )
+});
+
+/// How often the accept loop checks whether it should stop
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Connections that don't send a request within this time are dropped
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Default `metrics-address`: only reachable from the local host
+pub(crate) const DEFAULT_METRICS_ADDRESS: &str = "127.0.0.1";
+
+/// Statistics that only ever grow besides the `bytes-*`, `messages-*`,
+/// `attachment-bytes-*` and `dropped-*` ones, exported as counters
+const COUNTERS: &[&str] = &[
+    "dropped",
+    "errors",
+    "metadata-errors",
+    "samples-reordered",
+    "pads-created",
+    "pads-rejected",
+    "rate-limited-ms",
+];
+
+/// Thread serving the statistics of one element, stopped when dropped.
+pub(crate) struct MetricsServer {
+    stopping: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MetricsServer {
+    /// Serves `stats_json()` (the `stats-json` snapshot) on `address`:`port`.
+    fn start(
+        element: &gst::Element,
+        address: &str,
+        port: u16,
+        stats_json: impl Fn() -> String + Send + 'static,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind((address, port))?;
+        // Non-blocking accept, so the thread notices when it must stop
+        listener.set_nonblocking(true)?;
+
+        let factory = element.factory().map(|factory| factory.name().to_string());
+        let labels = format!(
+            "element=\"{}\",factory=\"{}\"",
+            escape_label(&element.name()),
+            escape_label(factory.as_deref().unwrap_or_default())
+        );
+
+        let stopping = Arc::new(AtomicBool::new(false));
+        let thread_stopping = stopping.clone();
+        let thread = thread::Builder::new()
+            .name("zenoh-metrics".into())
+            .spawn(move || {
+                while !thread_stopping.load(Ordering::SeqCst) {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            if let Err(e) = respond(stream, || render(&stats_json(), &labels)) {
+                                gst::debug!(CAT, "Metrics request failed: {}", e);
+                            }
+                        }
+                        Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                            thread::sleep(POLL_INTERVAL);
+                        }
+                        Err(e) => {
+                            gst::warning!(CAT, "Failed to accept metrics connection: {}", e);
+                            thread::sleep(POLL_INTERVAL);
+                        }
+                    }
+                }
+            })?;
+
+        gst::info!(CAT, obj = element, "Serving metrics on {}:{}", address, port);
+        Ok(Self {
+            stopping,
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for MetricsServer {
+    fn drop(&mut self) {
+        self.stopping.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Starts the endpoint of `metrics-address` and `metrics-port` for `element`.
+///
+/// Returns `None` when `port` is 0 (disabled) or the address can't be bound, in
+/// which case a warning is posted and the element keeps running without it.
+pub(crate) fn serve(
+    element: &gst::Element,
+    address: &str,
+    port: u32,
+    stats_json: impl Fn() -> String + Send + 'static,
+) -> Option<MetricsServer> {
+    if port == 0 {
+        return None;
+    }
+    let port = u16::try_from(port).expect("metrics-port is limited to 65535");
+    match MetricsServer::start(element, address, port, stats_json) {
+        Ok(server) => Some(server),
+        Err(e) => {
+            gst::element_warning!(
+                element,
+                gst::ResourceError::OpenWrite,
+                ["Failed to serve metrics on {}:{}: {}", address, port, e]
+            );
+            None
+        }
+    }
+}
+
+/// Answers one HTTP request: the metrics on `GET /metrics`, 404 otherwise.
+fn respond(stream: TcpStream, metrics: impl FnOnce() -> String) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Skip the headers, up to the empty line
+    let mut header = String::new();
+    loop {
+        header.clear();
+        if reader.read_line(&mut header)? == 0 || header.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let path = parts.nth(1).map(|target| target.split('?').next().unwrap_or_default());
+    let (status, body) = match (request_line.starts_with("GET "), path) {
+        (true, Some("/metrics")) => ("200 OK", metrics()),
+        _ => ("404 Not Found", String::new()),
+    };
+
+    let mut stream = reader.into_inner();
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+/// Converts a `stats-json` object into the Prometheus text format, one counter
+/// or gauge per numeric statistic.
+fn render(stats_json: &str, labels: &str) -> String {
+    let Ok(serde_json::Value::Object(stats)) = serde_json::from_str(stats_json) else {
+        return String::new();
+    };
+
+    let mut text = String::new();
+    for (key, value) in stats {
+        let serde_json::Value::Number(value) = value else {
+            continue;
+        };
+        let name = format!("gstzenoh_{}", key.replace('-', "_"));
+        let kind = if is_counter(&key) { "counter" } else { "gauge" };
+        text.push_str(&format!("# TYPE {} {}\n{}{{{}}} {}\n", name, kind, name, labels, value));
+    }
+    text
+}
+
+/// Whether the statistic `key` only ever grows while the element runs.
+fn is_counter(key: &str) -> bool {
+    ["bytes-", "messages-", "attachment-bytes-", "dropped-"]
+        .iter()
+        .any(|prefix| key.starts_with(prefix))
+        || COUNTERS.contains(&key)
+}
+
+/// Escapes a Prometheus label value.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_prometheus_text() {
+        let text = render(
+            r#"{"bytes-sent":1024,"message-rate":2.5,"state":"x"}"#,
+            r#"element="sink0",factory="zenohsink""#,
+        );
+        assert_eq!(
+            text,
+            "# TYPE gstzenoh_bytes_sent counter\n\
+             gstzenoh_bytes_sent{element=\"sink0\",factory=\"zenohsink\"} 1024\n\
+             # TYPE gstzenoh_message_rate gauge\n\
+             gstzenoh_message_rate{element=\"sink0\",factory=\"zenohsink\"} 2.5\n"
+        );
+        assert!(render("not json", "").is_empty());
+        assert_eq!(escape_label("a\"b\\c"), "a\\\"b\\\\c");
+    }
+
+    #[test]
+    fn test_counter_types() {
+        for key in ["bytes-received", "messages-sent", "errors", "dropped", "dropped-oversize"] {
+            assert!(is_counter(key), "{} is a counter", key);
+        }
+        for key in ["bitrate", "message-rate", "latency-ms", "samples-lost", "compression-ratio"] {
+            assert!(!is_counter(key), "{} is a gauge", key);
+        }
+    }
+}
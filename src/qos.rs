@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! QoS enums shared by several elements
+//!
+//! These are `glib::Enum` wrappers around Zenoh types so they can be exposed
+//! as GObject enum properties (and set by nick from `gst-launch`). They map
+//! one-to-one to the Zenoh types through `From`.
+
+use gst::glib;
+use zenoh::qos::CongestionControl;
+use zenoh::query::{ConsolidationMode, QueryTarget};
+use zenoh::sample::Locality;
+
+/// Which queryables a query is routed to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "GstZenohQueryTarget")]
+#[repr(u32)]
+pub enum ZenohQueryTarget {
+    /// The nearest complete queryable if any, otherwise all matching queryables
+    #[default]
+    #[enum_value(name = "Best Matching", nick = "best-matching")]
+    BestMatching = 0,
+    /// All matching queryables
+    #[enum_value(name = "All", nick = "all")]
+    All = 1,
+    /// All matching queryables that are complete for the key expression
+    #[enum_value(name = "All Complete", nick = "all-complete")]
+    AllComplete = 2,
+}
+
+impl From<ZenohQueryTarget> for QueryTarget {
+    fn from(target: ZenohQueryTarget) -> Self {
+        match target {
+            ZenohQueryTarget::BestMatching => QueryTarget::BestMatching,
+            ZenohQueryTarget::All => QueryTarget::All,
+            ZenohQueryTarget::AllComplete => QueryTarget::AllComplete,
+        }
+    }
+}
+
+/// How replies for the same key expression are consolidated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "GstZenohConsolidation")]
+#[repr(u32)]
+pub enum ZenohConsolidation {
+    /// Let Zenoh pick the mode depending on the selector
+    #[default]
+    #[enum_value(name = "Auto", nick = "auto")]
+    Auto = 0,
+    /// Deliver every reply, possibly with duplicates
+    #[enum_value(name = "None", nick = "none")]
+    None = 1,
+    /// Drop replies older than one already delivered for the same key
+    #[enum_value(name = "Monotonic", nick = "monotonic")]
+    Monotonic = 2,
+    /// Only deliver the latest reply per key, once the query completes
+    #[enum_value(name = "Latest", nick = "latest")]
+    Latest = 3,
+}
+
+impl From<ZenohConsolidation> for ConsolidationMode {
+    fn from(consolidation: ZenohConsolidation) -> Self {
+        match consolidation {
+            ZenohConsolidation::Auto => ConsolidationMode::Auto,
+            ZenohConsolidation::None => ConsolidationMode::None,
+            ZenohConsolidation::Monotonic => ConsolidationMode::Monotonic,
+            ZenohConsolidation::Latest => ConsolidationMode::Latest,
+        }
+    }
+}
+
+/// Whether data is exchanged with the local session, remote peers, or both
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "GstZenohLocality")]
+#[repr(u32)]
+pub enum ZenohLocality {
+    /// Local session and remote peers
+    #[default]
+    #[enum_value(name = "Any", nick = "any")]
+    Any = 0,
+    /// Only entities declared on the same Zenoh session
+    #[enum_value(name = "Session Local", nick = "session-local")]
+    SessionLocal = 1,
+    /// Only entities on other sessions (prevents local echo)
+    #[enum_value(name = "Remote", nick = "remote")]
+    Remote = 2,
+}
+
+impl From<ZenohLocality> for Locality {
+    fn from(locality: ZenohLocality) -> Self {
+        match locality {
+            ZenohLocality::Any => Locality::Any,
+            ZenohLocality::SessionLocal => Locality::SessionLocal,
+            ZenohLocality::Remote => Locality::Remote,
+        }
+    }
+}
+
+/// Congestion control applied to express publications
+///
+/// Zenoh has a single congestion control per publisher; this selects the one
+/// used while express mode is on, or keeps the regular one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "GstZenohExpressCongestionControl")]
+#[repr(u32)]
+pub enum ZenohExpressCongestionControl {
+    /// Same as the regular congestion control
+    #[default]
+    #[enum_value(name = "Inherit", nick = "inherit")]
+    Inherit = 0,
+    /// Wait for room in the transmission queue
+    #[enum_value(name = "Block", nick = "block")]
+    Block = 1,
+    /// Drop the message when the transmission queue is full
+    #[enum_value(name = "Drop", nick = "drop")]
+    Drop = 2,
+}
+
+impl ZenohExpressCongestionControl {
+    /// Returns the congestion control to use, `regular` when inherited.
+    pub fn resolve(self, regular: CongestionControl) -> CongestionControl {
+        match self {
+            ZenohExpressCongestionControl::Inherit => regular,
+            ZenohExpressCongestionControl::Block => CongestionControl::Block,
+            ZenohExpressCongestionControl::Drop => CongestionControl::Drop,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_target_conversion() {
+        assert_eq!(
+            QueryTarget::from(ZenohQueryTarget::BestMatching),
+            QueryTarget::BestMatching
+        );
+        assert_eq!(QueryTarget::from(ZenohQueryTarget::All), QueryTarget::All);
+        assert_eq!(
+            QueryTarget::from(ZenohQueryTarget::AllComplete),
+            QueryTarget::AllComplete
+        );
+        assert_eq!(
+            QueryTarget::from(ZenohQueryTarget::default()),
+            QueryTarget::default()
+        );
+    }
+
+    #[test]
+    fn test_consolidation_conversion() {
+        assert_eq!(
+            ConsolidationMode::from(ZenohConsolidation::Auto),
+            ConsolidationMode::Auto
+        );
+        assert_eq!(
+            ConsolidationMode::from(ZenohConsolidation::None),
+            ConsolidationMode::None
+        );
+        assert_eq!(
+            ConsolidationMode::from(ZenohConsolidation::Monotonic),
+            ConsolidationMode::Monotonic
+        );
+        assert_eq!(
+            ConsolidationMode::from(ZenohConsolidation::Latest),
+            ConsolidationMode::Latest
+        );
+        assert_eq!(
+            ConsolidationMode::from(ZenohConsolidation::default()),
+            ConsolidationMode::default()
+        );
+    }
+
+    #[test]
+    fn test_locality_conversion() {
+        assert_eq!(Locality::from(ZenohLocality::Any), Locality::Any);
+        assert_eq!(Locality::from(ZenohLocality::SessionLocal), Locality::SessionLocal);
+        assert_eq!(Locality::from(ZenohLocality::Remote), Locality::Remote);
+        assert_eq!(Locality::from(ZenohLocality::default()), Locality::default());
+    }
+    #[test]
+    fn test_express_congestion_control_resolve() {
+        let inherit = ZenohExpressCongestionControl::default();
+        assert_eq!(inherit.resolve(CongestionControl::Block), CongestionControl::Block);
+        assert_eq!(inherit.resolve(CongestionControl::Drop), CongestionControl::Drop);
+        assert_eq!(
+            ZenohExpressCongestionControl::Drop.resolve(CongestionControl::Block),
+            CongestionControl::Drop
+        );
+        assert_eq!(
+            ZenohExpressCongestionControl::Block.resolve(CongestionControl::Drop),
+            CongestionControl::Block
+        );
+    }
+}
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Recording file format shared by zenohrecord and zenohreplay
+//!
+//! A recording starts with an 8 byte magic, followed by one record per
+//! buffer, all integers little-endian:
+//!
+//! - arrival time: `u64`, nanoseconds since the first record
+//! - attachment: `u32` length and bytes, in the format zenohsink attaches to
+//!   samples (caps when they changed, buffer timing)
+//! - payload: `u32` length and bytes
+//!
+//! Records are appended as buffers arrive, so a recording cut short (killed
+//! recorder, full disk) is readable up to its last complete record.
+
+use std::io::{self, Read, Write};
+
+/// File magic, the last byte being the format version
+pub(crate) const MAGIC: &[u8; 8] = b"GSTZREC\x01";
+
+/// Recorded buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Record {
+    /// Nanoseconds since the first record
+    pub(crate) time: u64,
+    /// Metadata attachment, empty when the buffer had none
+    pub(crate) attachment: Vec<u8>,
+    pub(crate) payload: Vec<u8>,
+}
+
+/// Writes the magic starting a recording.
+pub(crate) fn write_header(writer: &mut impl Write) -> io::Result<()> {
+    writer.write_all(MAGIC)
+}
+
+/// Reads and checks the magic starting a recording.
+pub(crate) fn read_header(reader: &mut impl Read) -> io::Result<()> {
+    let mut magic = [0u8; MAGIC.len()];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not a zenohrecord recording",
+        ));
+    }
+    Ok(())
+}
+
+fn write_chunk(writer: &mut impl Write, data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "record larger than 4 GiB"))?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(data)
+}
+
+fn read_chunk(reader: &mut impl Read) -> io::Result<Vec<u8>> {
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len) as usize;
+
+    // Not preallocated, so a corrupted length fails on EOF instead of allocating
+    let mut data = Vec::new();
+    reader.take(len as u64).read_to_end(&mut data)?;
+    if data.len() != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(data)
+}
+
+impl Record {
+    /// Appends the record, returning the number of bytes written.
+    pub(crate) fn write(&self, writer: &mut impl Write) -> io::Result<u64> {
+        writer.write_all(&self.time.to_le_bytes())?;
+        write_chunk(writer, &self.attachment)?;
+        write_chunk(writer, &self.payload)?;
+        Ok((8 + 4 + self.attachment.len() + 4 + self.payload.len()) as u64)
+    }
+
+    /// Reads the next record, `None` at the end of the recording.
+    ///
+    /// A record cut short fails with [`io::ErrorKind::UnexpectedEof`].
+    pub(crate) fn read(reader: &mut impl Read) -> io::Result<Option<Self>> {
+        let mut time = [0u8; 8];
+        let mut filled = 0;
+        while filled < time.len() {
+            match reader.read(&mut time[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(Some(Self {
+            time: u64::from_le_bytes(time),
+            attachment: read_chunk(reader)?,
+            payload: read_chunk(reader)?,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(time: u64, attachment: &[u8], payload: &[u8]) -> Record {
+        Record {
+            time,
+            attachment: attachment.to_vec(),
+            payload: payload.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let records = [record(0, b"gst.caps=foo", b"first"), record(40_000_000, b"", b"")];
+
+        let mut file = Vec::new();
+        write_header(&mut file).unwrap();
+        for record in &records {
+            let written = record.write(&mut file).unwrap();
+            assert_eq!(written, (16 + record.attachment.len() + record.payload.len()) as u64);
+        }
+
+        let mut reader = file.as_slice();
+        read_header(&mut reader).unwrap();
+        for expected in &records {
+            assert_eq!(Record::read(&mut reader).unwrap().as_ref(), Some(expected));
+        }
+        assert!(Record::read(&mut reader).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_truncated_and_invalid() {
+        let mut file = Vec::new();
+        write_header(&mut file).unwrap();
+        record(1, b"", b"payload").write(&mut file).unwrap();
+
+        // Cut inside the payload, then inside the time
+        for len in [file.len() - 1, MAGIC.len() + 3] {
+            let mut reader = &file[..len];
+            read_header(&mut reader).unwrap();
+            let err = Record::read(&mut reader).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        }
+
+        let err = read_header(&mut b"GSTZREC\x02".as_slice()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Sample reordering for zenohsrc's `reorder-window`
+//!
+//! Samples carrying the sequence numbers of zenohsink's `sequence-numbers` are
+//! held until the ones before them arrived, then released in order. A missing
+//! number is given up on once `reorder-window` samples are held behind it, or
+//! once a held sample waited `reorder-timeout-ms`; when it shows up afterwards
+//! it is too late and the caller drops it.
+//!
+//! Sequence numbers are per publisher key, so each key expression is
+//! reordered on its own, like the `lost` / `reordered` statistics.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Samples of one key expression.
+#[derive(Debug)]
+struct Stream<T> {
+    /// Next sequence number to release
+    next: u64,
+    /// Samples waiting for the ones before them, with their arrival time
+    held: BTreeMap<u64, (T, Instant)>,
+}
+
+/// Reorders items by per-key sequence number within a bounded window.
+#[derive(Debug)]
+pub(crate) struct ReorderBuffer<T> {
+    /// Samples held per key expression before a missing one is skipped
+    window: usize,
+    /// Longest a sample is held, `None` for no limit
+    timeout: Option<Duration>,
+    streams: HashMap<String, Stream<T>>,
+    /// Samples released in order, waiting to be output
+    ready: VecDeque<T>,
+}
+
+impl<T> ReorderBuffer<T> {
+    /// Creates a buffer holding up to `window` samples per key (at least one).
+    pub(crate) fn new(window: usize, timeout: Option<Duration>) -> Self {
+        Self {
+            window: window.max(1),
+            timeout,
+            streams: HashMap::new(),
+            ready: VecDeque::new(),
+        }
+    }
+
+    /// Adds `item`, received on `key_expr` with `seqnum`. Returns it back when
+    /// it arrived too late: its number was already released or skipped.
+    ///
+    /// The first number of a key, and 0 (a restarted publisher), start its
+    /// sequence; samples held from before a restart are released first.
+    pub(crate) fn push(
+        &mut self,
+        key_expr: &str,
+        seqnum: u64,
+        item: T,
+        now: Instant,
+    ) -> Option<T> {
+        let Some(stream) = self.streams.get_mut(key_expr) else {
+            self.streams.insert(
+                key_expr.to_string(),
+                Stream {
+                    next: seqnum,
+                    held: BTreeMap::from([(seqnum, (item, now))]),
+                },
+            );
+            self.release(now);
+            return None;
+        };
+
+        if seqnum == 0 && stream.next > 0 {
+            let held = std::mem::take(&mut stream.held);
+            self.ready.extend(held.into_values().map(|(item, _)| item));
+            stream.next = 0;
+        }
+        if seqnum < stream.next || stream.held.contains_key(&seqnum) {
+            return Some(item);
+        }
+        stream.held.insert(seqnum, (item, now));
+        self.release(now);
+        None
+    }
+
+    /// Returns the next sample in order, if any can be released by `now`.
+    pub(crate) fn pop(&mut self, now: Instant) -> Option<T> {
+        self.release(now);
+        self.ready.pop_front()
+    }
+
+    /// Returns the next sample in order, giving up on every missing one: at
+    /// the end of the stream, the held samples go out without waiting.
+    pub(crate) fn pop_held(&mut self) -> Option<T> {
+        for stream in self.streams.values_mut() {
+            if let Some((&last, _)) = stream.held.last_key_value() {
+                stream.next = last + 1;
+            }
+            let held = std::mem::take(&mut stream.held);
+            self.ready.extend(held.into_values().map(|(item, _)| item));
+        }
+        self.ready.pop_front()
+    }
+
+    /// Returns when a held sample times out, so the missing ones before it
+    /// are skipped: the caller shouldn't wait for new samples past it.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        let timeout = self.timeout?;
+        self.streams
+            .values()
+            .flat_map(|stream| stream.held.values())
+            .map(|(_, arrival)| *arrival + timeout)
+            .min()
+    }
+
+    /// Moves the samples that can go out to the ready queue: the consecutive
+    /// ones from the next number of each key, skipping a gap once the window
+    /// is full or a held sample timed out.
+    fn release(&mut self, now: Instant) {
+        for stream in self.streams.values_mut() {
+            while let Some((&first, _)) = stream.held.first_key_value() {
+                let give_up = stream.held.len() > self.window
+                    || self.timeout.is_some_and(|timeout| {
+                        stream
+                            .held
+                            .values()
+                            .any(|(_, arrival)| now.saturating_duration_since(*arrival) >= timeout)
+                    });
+                if first != stream.next && !give_up {
+                    break;
+                }
+                let (item, _) = stream.held.remove(&first).expect("first held sample");
+                stream.next = first + 1;
+                self.ready.push_back(item);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(reorder: &mut ReorderBuffer<u64>, now: Instant) -> Vec<u64> {
+        std::iter::from_fn(|| reorder.pop(now)).collect()
+    }
+
+    #[test]
+    fn test_reorder_within_window() {
+        let now = Instant::now();
+        let mut reorder = ReorderBuffer::new(4, None);
+        for seqnum in [0, 2, 1, 4, 3, 5] {
+            assert!(reorder.push("key", seqnum, seqnum, now).is_none());
+        }
+        assert_eq!(drain(&mut reorder, now), [0, 1, 2, 3, 4, 5]);
+
+        // Already released
+        assert_eq!(reorder.push("key", 3, 3, now), Some(3));
+    }
+
+    #[test]
+    fn test_gap_skipped_when_window_full() {
+        let now = Instant::now();
+        let mut reorder = ReorderBuffer::new(2, None);
+        for seqnum in [10, 12, 13] {
+            assert!(reorder.push("key", seqnum, seqnum, now).is_none());
+        }
+        assert_eq!(drain(&mut reorder, now), [10]);
+        // A third sample behind the missing 11 gives up on it
+        assert!(reorder.push("key", 14, 14, now).is_none());
+        assert_eq!(drain(&mut reorder, now), [12, 13, 14]);
+        assert_eq!(reorder.push("key", 11, 11, now), Some(11));
+    }
+
+    #[test]
+    fn test_gap_skipped_on_timeout() {
+        let start = Instant::now();
+        let timeout = Duration::from_millis(50);
+        let mut reorder = ReorderBuffer::new(8, Some(timeout));
+        assert!(reorder.push("key", 1, 1, start).is_none());
+        assert!(reorder.push("key", 3, 3, start).is_none());
+        assert_eq!(drain(&mut reorder, start), [1]);
+        assert_eq!(reorder.deadline(), Some(start + timeout));
+        assert_eq!(drain(&mut reorder, start + timeout), [3]);
+        assert_eq!(reorder.deadline(), None);
+    }
+
+    #[test]
+    fn test_keys_and_restart() {
+        let now = Instant::now();
+        let mut reorder = ReorderBuffer::new(4, None);
+        assert!(reorder.push("a", 5, 5, now).is_none());
+        assert!(reorder.push("b", 7, 7, now).is_none());
+        assert!(reorder.push("a", 7, 7, now).is_none());
+        assert_eq!(drain(&mut reorder, now), [5, 7]);
+
+        // The held 7 of "a" goes out with the restart
+        assert!(reorder.push("a", 0, 0, now).is_none());
+        assert_eq!(drain(&mut reorder, now), [7, 0]);
+    }
+
+    #[test]
+    fn test_pop_held() {
+        let now = Instant::now();
+        let mut reorder = ReorderBuffer::new(8, None);
+        for seqnum in [1, 3, 6] {
+            assert!(reorder.push("key", seqnum, seqnum, now).is_none());
+        }
+        assert_eq!(drain(&mut reorder, now), [1]);
+
+        let held: Vec<u64> = std::iter::from_fn(|| reorder.pop_held()).collect();
+        assert_eq!(held, [3, 6]);
+        // The missing ones were given up on
+        assert_eq!(reorder.push("key", 4, 4, now), Some(4));
+    }
+}
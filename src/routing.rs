@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Content-routed publishing for zenohsink's `key-suffix-from-meta`
+//!
+//! Buffers carrying a [`ZenohKeyMeta`](crate::metadata::ZenohKeyMeta) are
+//! published on `<key-expr>/<suffix>`. The publishers of these keys are
+//! declared on first use and kept in a bounded cache, so a stream routed to a
+//! few hot keys doesn't re-declare them for every buffer, while one fanning
+//! out to many keys can't hold an unbounded number of declarations.
+
+/// Joins `base` and the per-buffer `suffix` with a single `/`.
+pub(crate) fn routed_key_expr(base: &str, suffix: &str) -> String {
+    format!("{}/{}", base.trim_end_matches('/'), suffix.trim_start_matches('/'))
+}
+
+/// Least-recently-used cache keyed by key expression suffix.
+///
+/// Linear lookups are fine for the few dozen entries it is meant to hold.
+pub(crate) struct LruCache<V> {
+    capacity: usize,
+    /// Entries from least to most recently used
+    entries: Vec<(String, V)>,
+}
+
+impl<V> LruCache<V> {
+    /// Creates a cache holding at most `capacity` entries (at least one).
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the value of `key`, marking it as the most recently used.
+    pub(crate) fn get(&mut self, key: &str) -> Option<&V> {
+        let index = self.entries.iter().position(|(k, _)| k == key)?;
+        let entry = self.entries.remove(index);
+        self.entries.push(entry);
+        self.entries.last().map(|(_, value)| value)
+    }
+
+    /// Inserts `value` as the most recently used entry, for a `key` that
+    /// [`get`](Self::get) didn't find. Returns the least recently used entry
+    /// when it had to be evicted to make room.
+    pub(crate) fn insert(&mut self, key: String, value: V) -> Option<(String, V)> {
+        let evicted = (self.entries.len() >= self.capacity).then(|| self.entries.remove(0));
+        self.entries.push((key, value));
+        evicted
+    }
+
+    /// Removes every entry.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the number of entries.
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Consumes the cache, returning its values.
+    pub(crate) fn into_values(self) -> impl Iterator<Item = V> {
+        self.entries.into_iter().map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routed_key_expr() {
+        assert_eq!(routed_key_expr("detections", "person"), "detections/person");
+        assert_eq!(routed_key_expr("detections/", "/cam/1"), "detections/cam/1");
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut cache = LruCache::new(2);
+        assert!(cache.insert("a".into(), 1).is_none());
+        assert!(cache.insert("b".into(), 2).is_none());
+
+        // Using "a" makes "b" the least recently used
+        assert_eq!(cache.get("a"), Some(&1));
+        assert_eq!(cache.insert("c".into(), 3), Some(("b".to_string(), 2)));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("b").is_none());
+        assert_eq!(cache.get("c"), Some(&3));
+
+        cache.clear();
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_lru_minimum_capacity() {
+        let mut cache = LruCache::new(0);
+        assert!(cache.insert("a".into(), 1).is_none());
+        assert_eq!(cache.insert("b".into(), 2), Some(("a".to_string(), 1)));
+    }
+}
@@ -16,6 +16,10 @@
 //!   audiotestsrc ! zenohsink key-expr=demo/audio session-group=main
 //! ```
 //!
+//! The session of a group is closed when the last element using it stops.
+//! With `session-group-linger-ms`, it stays open that long first, so elements
+//! briefly overlapping (e.g. a pipeline restarting) reuse it.
+//!
 //! ### Using shared session in Rust
 //!
 //! ```ignore
@@ -34,19 +38,315 @@
 //!     .session(session)
 //!     .build();
 //! ```
+//!
+//! ### From async code
+//!
+//! `zenoh::open(config).wait()` must not be called from a tokio runtime's
+//! thread (it panics on a current-thread runtime). Open the session with
+//! `.await` and pass it to the builders, or use [`open_session_blocking`]
+//! from synchronous code that may run inside a runtime:
+//!
+//! ```ignore
+//! let session = zenoh::open(zenoh::Config::default()).await?;
+//! let sink = ZenohSink::builder("demo/video").session(session).build();
+//! ```
 
 use std::collections::HashMap;
-use std::sync::{LazyLock, Mutex};
+use std::path::Path;
+use std::sync::{LazyLock, Mutex, mpsc};
+use std::time::{Duration, Instant};
 
+use gst::prelude::*;
 use zenoh::Wait;
 
+/// Wrapper to handle both owned and shared Zenoh sessions.
+///
+/// This allows the plugin to either create its own session or use
+/// a shared session provided externally, enabling session reuse
+/// across multiple GStreamer elements.
+///
+/// Note: `zenoh::Session` is internally Arc-based and Clone, so the
+/// distinction between Owned and Shared is mainly for documentation
+/// purposes - both variants use the same underlying type.
+pub(crate) enum SessionWrapper {
+    /// Element created this session (will be dropped when element stops)
+    Owned(zenoh::Session),
+    /// Element is using a shared session (may outlive this element)
+    Shared(zenoh::Session),
+    /// Element is using a `session-group` session, released when dropped
+    Group(GroupSession),
+}
+
+impl SessionWrapper {
+    /// Get a reference to the underlying Zenoh session
+    pub(crate) fn as_session(&self) -> &zenoh::Session {
+        match self {
+            SessionWrapper::Owned(session) => session,
+            SessionWrapper::Shared(session) => session,
+            SessionWrapper::Group(group) => group.session(),
+        }
+    }
+
+    /// Whether the session is shared with others rather than owned by the element
+    pub(crate) fn is_shared(&self) -> bool {
+        !matches!(self, SessionWrapper::Owned(_))
+    }
+}
+
+/// Options the elements use to build the configuration of the sessions they open.
+#[derive(Clone)]
+pub(crate) struct SessionConfig {
+    /// Optional path to a Zenoh configuration file (empty = default config)
+    pub(crate) config_file: Option<String>,
+    /// Inline configuration (JSON5), used instead of `config_file` when set
+    pub(crate) config_json5: Option<String>,
+    /// Multicast scouting; disabled for networks where multicast doesn't work
+    pub(crate) scouting: bool,
+    /// Client mode without multicast scouting, connecting only to the configured endpoints
+    pub(crate) endpoints_only: bool,
+    /// CA certificate used to verify TLS routers and peers
+    pub(crate) tls_root_ca: Option<String>,
+    /// Client certificate presented on TLS links (mTLS, with `tls_client_key`)
+    pub(crate) tls_client_cert: Option<String>,
+    /// Private key of `tls_client_cert`
+    pub(crate) tls_client_key: Option<String>,
+    /// User name for usrpwd authentication (with `password`)
+    pub(crate) username: Option<String>,
+    /// Password for usrpwd authentication; never logged
+    pub(crate) password: Option<String>,
+}
+
+impl std::fmt::Debug for SessionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionConfig")
+            .field("config_file", &self.config_file)
+            .field("config_json5", &self.config_json5)
+            .field("scouting", &self.scouting)
+            .field("endpoints_only", &self.endpoints_only)
+            .field("tls_root_ca", &self.tls_root_ca)
+            .field("tls_client_cert", &self.tls_client_cert)
+            .field("tls_client_key", &self.tls_client_key)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            config_file: None,
+            config_json5: None,
+            scouting: true,
+            endpoints_only: false,
+            tls_root_ca: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+            username: None,
+            password: None,
+        }
+    }
+}
+
+/// Returns the value of an optional string property, treating "" as unset.
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|value| !value.is_empty())
+}
+
+/// Quotes `value` as a JSON5 string for `zenoh::Config::insert_json5`.
+fn json5_string(value: &str) -> Result<String, zenoh::Error> {
+    Ok(serde_json::to_string(value)?)
+}
+
+/// Switches `config` to client mode for `endpoints-only`, failing when it has
+/// no endpoint for a client to connect to.
+fn apply_endpoints_only(config: &mut zenoh::Config) -> Result<(), zenoh::Error> {
+    config.insert_json5("mode", "\"client\"")?;
+
+    // Endpoints are either one list or a list per mode
+    let endpoints: serde_json::Value =
+        serde_json::from_str(&config.get_json("connect/endpoints")?)?;
+    let client_endpoints = match endpoints {
+        serde_json::Value::Object(ref per_mode) => per_mode.get("client"),
+        ref list => Some(list),
+    };
+    if !client_endpoints
+        .and_then(|endpoints| endpoints.as_array())
+        .is_some_and(|endpoints| !endpoints.is_empty())
+    {
+        return Err("endpoints-only requires at least one connect endpoint, e.g. \
+                    config-json5='{connect: {endpoints: [\"tcp/192.168.1.10:7447\"]}}'"
+            .into());
+    }
+    Ok(())
+}
+
+impl SessionConfig {
+    /// Loads the inline configuration, the configuration file or the default
+    /// configuration, in that order of precedence, and applies the overrides
+    /// on top of it.
+    pub(crate) fn build(&self) -> Result<zenoh::Config, zenoh::Error> {
+        let mut config = if let Some(json5) = non_empty(&self.config_json5) {
+            zenoh::Config::from_json5(json5)
+                .map_err(|e| format!("Invalid config-json5: {}", e))?
+        } else {
+            match self.config_file.as_deref() {
+                Some(path) if !path.is_empty() => zenoh::Config::from_file(path)?,
+                _ => zenoh::Config::default(),
+            }
+        };
+        if !self.scouting || self.endpoints_only {
+            config.insert_json5("scouting/multicast/enabled", "false")?;
+        }
+        if self.endpoints_only {
+            apply_endpoints_only(&mut config)?;
+        }
+        self.apply_tls(&mut config)?;
+        self.apply_auth(&mut config)?;
+        Ok(config)
+    }
+
+    /// Sets `transport/auth/usrpwd` from the username/password properties,
+    /// keeping the `dictionary_file` of the configuration file.
+    fn apply_auth(&self, config: &mut zenoh::Config) -> Result<(), zenoh::Error> {
+        let (user, password) = match (non_empty(&self.username), non_empty(&self.password)) {
+            (Some(user), Some(password)) => (user, password),
+            (None, None) => return Ok(()),
+            _ => return Err("username and password must be set together".into()),
+        };
+
+        // Zenoh validates user and password as a pair, so the whole section
+        // is inserted at once.
+        let mut usrpwd: serde_json::Value =
+            serde_json::from_str(&config.get_json("transport/auth/usrpwd")?)?;
+        usrpwd["user"] = user.into();
+        usrpwd["password"] = password.into();
+        config.insert_json5("transport/auth/usrpwd", &usrpwd.to_string())?;
+        Ok(())
+    }
+
+    /// Sets the `transport/link/tls` entries from the TLS properties, overriding
+    /// the configuration file.
+    ///
+    /// Fails, naming the properties involved, when a file doesn't exist or when
+    /// only half of the client certificate/key pair is set.
+    fn apply_tls(&self, config: &mut zenoh::Config) -> Result<(), zenoh::Error> {
+        let files = [
+            ("tls-root-ca", non_empty(&self.tls_root_ca)),
+            ("tls-client-cert", non_empty(&self.tls_client_cert)),
+            ("tls-client-key", non_empty(&self.tls_client_key)),
+        ];
+        let missing: Vec<String> = files
+            .iter()
+            .filter_map(|(name, path)| {
+                let path = (*path)?;
+                (!Path::new(path).is_file()).then(|| format!("{}={}", name, path))
+            })
+            .collect();
+        if !missing.is_empty() {
+            return Err(format!("TLS file not found: {}", missing.join(", ")).into());
+        }
+
+        let [(_, root_ca), (_, client_cert), (_, client_key)] = files;
+        if let Some(path) = root_ca {
+            config.insert_json5("transport/link/tls/root_ca_certificate", &json5_string(path)?)?;
+        }
+        match (client_cert, client_key) {
+            (Some(cert), Some(key)) => {
+                config.insert_json5(
+                    "transport/link/tls/connect_certificate",
+                    &json5_string(cert)?,
+                )?;
+                config.insert_json5(
+                    "transport/link/tls/connect_private_key",
+                    &json5_string(key)?,
+                )?;
+                config.insert_json5("transport/link/tls/enable_mtls", "true")?;
+            }
+            (None, None) => {}
+            _ => return Err("tls-client-cert and tls-client-key must be set together".into()),
+        }
+        Ok(())
+    }
+
+    /// Builds the configuration and opens a new session with it.
+    pub(crate) fn open(&self) -> Result<zenoh::Session, zenoh::Error> {
+        open_session_blocking(self.build()?)
+    }
+}
+
+/// Opens a Zenoh session from synchronous code, even inside a tokio runtime.
+///
+/// `zenoh::open(config).wait()` panics when called from a task of a tokio
+/// current-thread runtime, and can stall the worker it runs on otherwise.
+/// The session is opened on a separate thread, outside of any runtime, while
+/// the caller blocks. The elements open their sessions this way.
+///
+/// Async applications should rather open their session with
+/// `zenoh::open(config).await` and hand it to the elements with the
+/// builders' `session()`.
+pub fn open_session_blocking(config: zenoh::Config) -> Result<zenoh::Session, zenoh::Error> {
+    wait_off_runtime(|| zenoh::open(config).wait())
+        .unwrap_or_else(|| Err("thread opening the Zenoh session panicked".into()))
+}
+
+/// Runs a blocking Zenoh call on a separate thread while the caller blocks.
+///
+/// The `wait()` of the Zenoh operations that resolve a future (opening and
+/// closing a session, `matching_status()`, `routers_zid()` / `peers_zid()`)
+/// goes through Zenoh's runtime, which panics on the thread of a tokio
+/// current-thread runtime. Every such call of the elements goes through here,
+/// so they can be driven from async code. Returns `None` if `f` panicked.
+pub(crate) fn wait_off_runtime<T: Send>(f: impl FnOnce() -> T + Send) -> Option<T> {
+    std::thread::scope(|scope| scope.spawn(f).join().ok())
+}
+
+/// Default of the elements' `session-group-linger-ms`
+pub(crate) const DEFAULT_SESSION_GROUP_LINGER_MS: u32 = 0;
+
+/// Maximum of the elements' `session-group-linger-ms` (10 minutes)
+pub(crate) const MAX_SESSION_GROUP_LINGER_MS: u32 = 600_000;
+
+/// A shared session of the registry and the number of elements using it.
+struct Group {
+    session: zenoh::Session,
+    users: usize,
+    /// Incremented whenever the last user leaves, so that a lingering close
+    /// is called off when the group was used and left again meanwhile
+    releases: u64,
+}
+
 /// Global registry of shared sessions by group name.
 ///
 /// Sessions are stored directly since `zenoh::Session` is already Arc-based
 /// internally and supports Clone.
-static SESSION_REGISTRY: LazyLock<Mutex<HashMap<String, zenoh::Session>>> =
+static SESSION_REGISTRY: LazyLock<Mutex<HashMap<String, Group>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
 
+/// An element's use of a `session-group` session.
+///
+/// Dropping it releases the session. When the last element of the group
+/// releases it, the session is closed, after the `linger` of that element
+/// unless another element joins the group meanwhile.
+pub(crate) struct GroupSession {
+    group: String,
+    session: zenoh::Session,
+    linger: Duration,
+}
+
+impl GroupSession {
+    /// Get a reference to the shared Zenoh session
+    pub(crate) fn session(&self) -> &zenoh::Session {
+        &self.session
+    }
+}
+
+impl Drop for GroupSession {
+    fn drop(&mut self) {
+        release_session(&self.group, self.linger);
+    }
+}
+
 /// Get or create a shared session for a named group.
 ///
 /// This is used internally by elements when the `session-group` property is set.
@@ -56,65 +356,455 @@ static SESSION_REGISTRY: LazyLock<Mutex<HashMap<String, zenoh::Session>>> =
 /// # Arguments
 ///
 /// * `group` - The session group name
-/// * `config_path` - Optional path to a Zenoh configuration file
+/// * `config` - Configuration used if the session has to be created
+/// * `linger` - How long the session stays open once this element was the
+///   last one to release it (`session-group-linger-ms`)
 ///
 /// # Returns
 ///
-/// A `zenoh::Session` that may be shared with other elements in the same group.
+/// A [`GroupSession`] that may be shared with other elements in the same group,
+/// counted as a user of the session until dropped.
 ///
 /// # Note
 ///
-/// If a session already exists for the group, the `config_path` is ignored and
+/// If a session already exists for the group, the `config` is ignored and
 /// the existing session is returned. This means the first element to start with
 /// a given group name determines the configuration for that group.
 pub(crate) fn get_or_create_session(
     group: &str,
-    config_path: Option<&str>,
-) -> Result<zenoh::Session, zenoh::Error> {
+    config: &SessionConfig,
+    linger: Duration,
+) -> Result<GroupSession, zenoh::Error> {
     let mut registry = SESSION_REGISTRY.lock().unwrap();
 
-    // Check if session already exists for this group
-    if let Some(session) = registry.get(group) {
+    // Check if session already exists for this group, maybe lingering
+    let session = if let Some(entry) = registry.get_mut(group) {
+        entry.users += 1;
         // Session is Arc-based, clone is cheap
-        return Ok(session.clone());
-    }
+        entry.session.clone()
+    } else {
+        let session = config.open()?;
+        let entry = Group {
+            session: session.clone(),
+            users: 1,
+            releases: 0,
+        };
+        registry.insert(group.to_string(), entry);
+        session
+    };
 
-    // Create new session
-    let config = match config_path {
-        Some(path) => zenoh::Config::from_file(path)?,
-        None => zenoh::Config::default(),
+    Ok(GroupSession {
+        group: group.to_string(),
+        session,
+        linger,
+    })
+}
+
+/// Releases a user of `group`. When it was the last one, the session is
+/// closed right away, or after `linger` unless the group is used again.
+fn release_session(group: &str, linger: Duration) {
+    let mut registry = SESSION_REGISTRY.lock().unwrap();
+    let Some(entry) = registry.get_mut(group) else {
+        return;
     };
-    let session = zenoh::open(config).wait()?;
+    entry.users -= 1;
+    if entry.users > 0 {
+        return;
+    }
+    entry.releases += 1;
+
+    if linger.is_zero() {
+        let entry = registry.remove(group).expect("group checked above");
+        drop(registry);
+        close_session(group, &entry.session);
+        return;
+    }
+
+    let releases = entry.releases;
+    let group = group.to_string();
+    std::thread::Builder::new()
+        .name("zenoh-session-linger".into())
+        .spawn(move || {
+            std::thread::sleep(linger);
+            let mut registry = SESSION_REGISTRY.lock().unwrap();
+            let unused = registry
+                .get(&group)
+                .is_some_and(|entry| entry.users == 0 && entry.releases == releases);
+            if unused {
+                let entry = registry.remove(&group).expect("group checked above");
+                drop(registry);
+                close_session(&group, &entry.session);
+            }
+        })
+        .expect("Failed to spawn session linger thread");
+}
+
+/// Closes the session of `group`, now that no element uses it.
+fn close_session(group: &str, session: &zenoh::Session) {
+    gst::debug!(gst::CAT_DEFAULT, "Closing the session of group '{}'", group);
+    let closed = wait_off_runtime(|| session.close().wait());
+    if let Some(Err(e)) = closed {
+        gst::warning!(gst::CAT_DEFAULT, "Failed to close the session of group '{}': {}", group, e);
+    }
+}
+
+/// Returns whether `group` has a session in the registry, in use or lingering.
+#[cfg(test)]
+fn has_session(group: &str) -> bool {
+    SESSION_REGISTRY.lock().unwrap().contains_key(group)
+}
+
+/// Lists the routers and peers `session` is currently connected to.
+///
+/// Entries are formatted as `router:<zid>` or `peer:<zid>`, routers first.
+/// Used by the `peers` property of the elements for field diagnostics.
+pub(crate) fn connected_peers(session: &zenoh::Session) -> Vec<String> {
+    wait_off_runtime(|| {
+        let info = session.info();
+        let routers = info.routers_zid().wait().map(|zid| format!("router:{}", zid));
+        let peers = info.peers_zid().wait().map(|zid| format!("peer:{}", zid));
+        routers.chain(peers).collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Returns whether `session` is connected to at least one router or peer.
+pub(crate) fn is_connected(session: &zenoh::Session) -> bool {
+    wait_off_runtime(|| {
+        let info = session.info();
+        info.routers_zid().wait().next().is_some() || info.peers_zid().wait().next().is_some()
+    })
+    .unwrap_or(false)
+}
+
+/// How often [`wait_for_connection`] re-checks the session
+const CONNECTION_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Waits up to `timeout` for `session` to be connected to at least one router or peer.
+///
+/// Returns whether a connection was seen. Used by zenohsink's `wait-for-connection-ms`
+/// so that data isn't published before the session has joined the network.
+pub(crate) fn wait_for_connection(session: &zenoh::Session, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if is_connected(session) {
+            return true;
+        }
+        let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+            return false;
+        };
+        std::thread::sleep(remaining.min(CONNECTION_POLL_INTERVAL));
+    }
+}
+
+/// Default of the elements' `connectivity-poll-ms`
+pub(crate) const DEFAULT_CONNECTIVITY_POLL_MS: u32 = 1000;
+
+/// Thread watching whether a session is connected to any router or peer, for
+/// the `session-disconnected` and `session-reconnected` signals of an element.
+///
+/// Zenoh has no connectivity callback, so the session is polled every
+/// `connectivity-poll-ms`. The state at start is taken as is; afterwards
+/// `session-disconnected` is emitted when the last router or peer is gone and
+/// `session-reconnected` when one is back, from the monitor thread. The
+/// signals only notify: the element keeps running either way.
+///
+/// Dropping the monitor wakes the thread up and ends it.
+pub(crate) struct ConnectivityMonitor {
+    /// Dropped to stop the thread
+    _stop: mpsc::Sender<()>,
+}
+
+impl ConnectivityMonitor {
+    /// Starts monitoring `session` for `element`, or returns `None` when
+    /// `poll_ms` is 0.
+    pub(crate) fn start(
+        session: &zenoh::Session,
+        element: &gst::Element,
+        poll_ms: u32,
+    ) -> Option<Self> {
+        if poll_ms == 0 {
+            return None;
+        }
 
-    // Store in registry
-    registry.insert(group.to_string(), session.clone());
+        let interval = Duration::from_millis(poll_ms.into());
+        let session = session.clone();
+        let element = element.downgrade();
+        let mut connected = is_connected(&session);
+        let (stop, stopped) = mpsc::channel::<()>();
+        std::thread::Builder::new()
+            .name("zenoh-connectivity".into())
+            .spawn(move || {
+                while let Err(mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                    if is_connected(&session) == connected {
+                        continue;
+                    }
+                    connected = !connected;
+                    let Some(element) = element.upgrade() else {
+                        break;
+                    };
+                    let signal = if connected {
+                        "session-reconnected"
+                    } else {
+                        "session-disconnected"
+                    };
+                    gst::info!(gst::CAT_DEFAULT, obj = element, "Emitting {}", signal);
+                    element.emit_by_name::<()>(signal, &[]);
+                }
+            })
+            .expect("Failed to spawn connectivity monitor thread");
 
-    Ok(session)
+        Some(Self { _stop: stop })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn group_session(group: &str, linger: Duration) -> GroupSession {
+        get_or_create_session(group, &SessionConfig::default(), linger)
+            .expect("Failed to create session")
+    }
+
     #[test]
     fn test_session_group_reuse() {
-        let session1 =
-            get_or_create_session("test-reuse-group", None).expect("Failed to create session");
-        let session2 =
-            get_or_create_session("test-reuse-group", None).expect("Failed to get session");
+        let session1 = group_session("test-reuse-group", Duration::ZERO);
+        let session2 = group_session("test-reuse-group", Duration::ZERO);
 
         // Should be the same session (same zid)
-        assert_eq!(session1.zid(), session2.zid());
+        assert_eq!(session1.session().zid(), session2.session().zid());
     }
 
     #[test]
     fn test_different_groups_different_sessions() {
-        let session1 =
-            get_or_create_session("test-group-x", None).expect("Failed to create session");
-        let session2 =
-            get_or_create_session("test-group-y", None).expect("Failed to create session");
+        let session1 = group_session("test-group-x", Duration::ZERO);
+        let session2 = group_session("test-group-y", Duration::ZERO);
 
         // Should be different sessions
-        assert_ne!(session1.zid(), session2.zid());
+        assert_ne!(session1.session().zid(), session2.session().zid());
+    }
+
+    #[test]
+    fn test_session_group_closed_by_last_user() {
+        let session1 = group_session("test-close-group", Duration::ZERO);
+        let session2 = group_session("test-close-group", Duration::ZERO);
+        let session = session1.session().clone();
+
+        drop(session1);
+        assert!(!session.is_closed(), "Still used by the second element");
+        drop(session2);
+        assert!(session.is_closed());
+        assert!(!has_session("test-close-group"));
+    }
+
+    #[test]
+    fn test_session_group_linger() {
+        let linger = Duration::from_millis(200);
+        let session1 = group_session("test-linger-group", linger);
+        let zid = session1.session().zid();
+        drop(session1);
+
+        // Joining within the linger reuses the session
+        let session2 = group_session("test-linger-group", linger);
+        assert_eq!(session2.session().zid(), zid);
+        let session = session2.session().clone();
+        drop(session2);
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while has_session("test-linger-group") && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        assert!(!has_session("test-linger-group"));
+        assert!(session.is_closed());
+    }
+
+    #[test]
+    fn test_scouting_disabled_config() {
+        let config = SessionConfig {
+            scouting: false,
+            ..Default::default()
+        }
+        .build()
+        .expect("Failed to build config");
+
+        assert_eq!(config.get_json("scouting/multicast/enabled").unwrap(), "false");
+    }
+
+    #[test]
+    fn test_endpoints_only_config() {
+        let config = SessionConfig {
+            config_json5: Some(r#"{ connect: { endpoints: ["tcp/10.0.0.1:7447"] } }"#.into()),
+            endpoints_only: true,
+            ..Default::default()
+        }
+        .build()
+        .unwrap();
+        assert_eq!(config.get_json("mode").unwrap(), "\"client\"");
+        assert_eq!(config.get_json("scouting/multicast/enabled").unwrap(), "false");
+
+        // Per-mode endpoints need a client entry
+        let per_mode = r#"{ connect: { endpoints: { client: ["tcp/10.0.0.1:7447"] } } }"#;
+        let config = SessionConfig {
+            config_json5: Some(per_mode.into()),
+            endpoints_only: true,
+            ..Default::default()
+        };
+        assert!(config.build().is_ok());
+    }
+
+    #[test]
+    fn test_endpoints_only_requires_endpoints() {
+        for json5 in [None, Some(r#"{ connect: { endpoints: { peer: ["tcp/10.0.0.1:7447"] } } }"#)] {
+            let err = SessionConfig {
+                config_json5: json5.map(Into::into),
+                endpoints_only: true,
+                ..Default::default()
+            }
+            .build()
+            .unwrap_err();
+            assert!(err.to_string().contains("endpoints-only"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_inline_json5_config() {
+        let json5 = r#"{ mode: "client", connect: { endpoints: ["tcp/10.0.0.1:7447"] } }"#;
+        let config = SessionConfig {
+            config_file: Some("/nonexistent/zenoh.json5".into()),
+            config_json5: Some(json5.into()),
+            scouting: false,
+            ..Default::default()
+        }
+        .build()
+        .expect("Inline config takes precedence over the file");
+
+        assert_eq!(config.get_json("mode").unwrap(), "\"client\"");
+        assert!(config.get_json("connect/endpoints").unwrap().contains("tcp/10.0.0.1:7447"));
+        // Property overrides still apply on top
+        assert_eq!(config.get_json("scouting/multicast/enabled").unwrap(), "false");
+
+        let err = SessionConfig {
+            config_json5: Some("{ mode: ".into()),
+            ..Default::default()
+        }
+        .build()
+        .expect_err("Malformed JSON5 should fail");
+        assert!(err.to_string().starts_with("Invalid config-json5: "), "{}", err);
+    }
+
+    #[test]
+    fn test_tls_config() {
+        let dir = std::env::temp_dir();
+        let paths: Vec<String> = ["ca.pem", "client.pem", "client.key"]
+            .iter()
+            .map(|name| {
+                let path = dir.join(format!("gstzenoh-tls-{}-{}", std::process::id(), name));
+                std::fs::write(&path, "test").unwrap();
+                path.to_string_lossy().into_owned()
+            })
+            .collect();
+
+        let config = SessionConfig {
+            tls_root_ca: Some(paths[0].clone()),
+            tls_client_cert: Some(paths[1].clone()),
+            tls_client_key: Some(paths[2].clone()),
+            ..Default::default()
+        }
+        .build()
+        .expect("Failed to build config");
+
+        let get = |key: &str| config.get_json(&format!("transport/link/tls/{}", key)).unwrap();
+        assert_eq!(get("root_ca_certificate"), json5_string(&paths[0]).unwrap());
+        assert_eq!(get("connect_certificate"), json5_string(&paths[1]).unwrap());
+        assert_eq!(get("connect_private_key"), json5_string(&paths[2]).unwrap());
+        assert_eq!(get("enable_mtls"), "true");
+
+        for path in paths {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    #[test]
+    fn test_tls_missing_file() {
+        let err = SessionConfig {
+            tls_root_ca: Some("/nonexistent/ca.pem".into()),
+            ..Default::default()
+        }
+        .build()
+        .expect_err("Missing CA file should fail");
+
+        assert_eq!(err.to_string(), "TLS file not found: tls-root-ca=/nonexistent/ca.pem");
+    }
+
+    #[test]
+    fn test_tls_client_cert_without_key() {
+        let cert =
+            std::env::temp_dir().join(format!("gstzenoh-tls-{}-lone.pem", std::process::id()));
+        std::fs::write(&cert, "test").unwrap();
+
+        let result = SessionConfig {
+            tls_client_cert: Some(cert.to_string_lossy().into_owned()),
+            ..Default::default()
+        }
+        .build();
+        let _ = std::fs::remove_file(&cert);
+
+        assert!(result.is_err(), "A client certificate needs its key");
+    }
+
+    #[test]
+    fn test_usrpwd_config() {
+        let config = SessionConfig {
+            username: Some("alice".into()),
+            password: Some("s3cr3t".into()),
+            ..Default::default()
+        };
+        assert!(
+            !format!("{:?}", config).contains("s3cr3t"),
+            "Password must not appear in debug output"
+        );
+
+        let config = config.build().expect("Failed to build config");
+        assert_eq!(config.get_json("transport/auth/usrpwd/user").unwrap(), "\"alice\"");
+        assert_eq!(config.get_json("transport/auth/usrpwd/password").unwrap(), "\"s3cr3t\"");
+    }
+
+    #[test]
+    fn test_usrpwd_requires_both() {
+        let result = SessionConfig {
+            username: Some("alice".into()),
+            ..Default::default()
+        }
+        .build();
+
+        assert!(result.is_err(), "A username needs a password");
+    }
+
+    #[test]
+    fn test_wait_for_connection_times_out_when_isolated() {
+        let session = SessionConfig {
+            scouting: false,
+            ..Default::default()
+        }
+        .open()
+        .expect("Failed to open session");
+
+        let start = Instant::now();
+        assert!(!wait_for_connection(&session, Duration::from_millis(100)));
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_wait_for_connection_sees_peer() {
+        let session1 = zenoh::open(zenoh::Config::default())
+            .wait()
+            .expect("Failed to open session");
+        let session2 = zenoh::open(zenoh::Config::default())
+            .wait()
+            .expect("Failed to open session");
+
+        assert!(wait_for_connection(&session1, Duration::from_secs(5)));
+        assert!(wait_for_connection(&session2, Duration::from_secs(5)));
     }
 }
@@ -0,0 +1,475 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Shared statistics helpers for gst-plugin-zenoh elements
+//!
+//! Cumulative counters (bytes, messages, errors) live in each element's own
+//! `Statistics` struct. This module provides the pieces that are identical
+//! across elements, such as the sliding-window throughput tracker, the
+//! idle-stream detection behind `no-data-timeout-ms`, the sequence number
+//! checks behind `samples-lost` / `samples-reordered` and the QoS of received
+//! samples behind `rx-priority` / `rx-express`.
+
+use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use gst::prelude::*;
+
+/// Name of the element message posted when a stream goes silent
+pub(crate) const STREAM_IDLE_MESSAGE: &str = "zenoh-stream-idle";
+
+/// Name of the element message posted when data arrives again after an idle period
+pub(crate) const STREAM_RESUMED_MESSAGE: &str = "zenoh-stream-resumed";
+
+/// Length of the sliding window used for rate computation.
+const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Number of buckets the window is split into.
+///
+/// Using fixed buckets keeps memory bounded regardless of message rate,
+/// at the cost of a 1/`RATE_BUCKETS` granularity on the window edge.
+const RATE_BUCKETS: usize = 10;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct RateBucket {
+    /// Absolute bucket number since the tracker origin
+    index: u64,
+    bytes: u64,
+    messages: u64,
+}
+
+/// Throughput tracker computing bitrate and message rate over a sliding
+/// one-second window.
+///
+/// Reports 0 once no data has been recorded for a full window.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateTracker {
+    /// Instant of the first recorded sample; bucket indices are relative to it
+    origin: Option<Instant>,
+    buckets: [RateBucket; RATE_BUCKETS],
+}
+
+impl RateTracker {
+    fn bucket_index(origin: Instant, now: Instant) -> u64 {
+        let bucket_len = RATE_WINDOW.as_nanos() / RATE_BUCKETS as u128;
+        (now.saturating_duration_since(origin).as_nanos() / bucket_len) as u64
+    }
+
+    /// Records `messages` messages totalling `bytes` bytes at the current time.
+    pub(crate) fn record(&mut self, bytes: u64, messages: u64) {
+        self.record_at(Instant::now(), bytes, messages);
+    }
+
+    pub(crate) fn record_at(&mut self, now: Instant, bytes: u64, messages: u64) {
+        let origin = *self.origin.get_or_insert(now);
+        let index = Self::bucket_index(origin, now);
+        let bucket = &mut self.buckets[(index % RATE_BUCKETS as u64) as usize];
+        if bucket.index != index {
+            // Bucket slot is being reused for a new time slice
+            *bucket = RateBucket {
+                index,
+                bytes: 0,
+                messages: 0,
+            };
+        }
+        bucket.bytes += bytes;
+        bucket.messages += messages;
+    }
+
+    /// Sums bytes and messages over the buckets still inside the window.
+    fn window_totals(&self, now: Instant) -> (u64, u64) {
+        let Some(origin) = self.origin else {
+            return (0, 0);
+        };
+        let current = Self::bucket_index(origin, now);
+        self.buckets
+            .iter()
+            .filter(|b| b.index <= current && b.index + RATE_BUCKETS as u64 > current)
+            .fold((0, 0), |(bytes, messages), b| {
+                (bytes + b.bytes, messages + b.messages)
+            })
+    }
+
+    /// Returns the bitrate in bits per second over the last window.
+    pub(crate) fn bitrate(&self) -> u64 {
+        self.bitrate_at(Instant::now())
+    }
+
+    pub(crate) fn bitrate_at(&self, now: Instant) -> u64 {
+        let (bytes, _) = self.window_totals(now);
+        (bytes as f64 * 8.0 / RATE_WINDOW.as_secs_f64()) as u64
+    }
+
+    /// Returns the message rate in messages per second over the last window.
+    pub(crate) fn message_rate(&self) -> f64 {
+        self.message_rate_at(Instant::now())
+    }
+
+    pub(crate) fn message_rate_at(&self, now: Instant) -> f64 {
+        let (_, messages) = self.window_totals(now);
+        messages as f64 / RATE_WINDOW.as_secs_f64()
+    }
+}
+
+/// Detects a stream going silent for longer than a timeout.
+///
+/// Has no timer of its own: the receive loops feed it every sample and poll it
+/// whenever a receive times out, so detection is as precise as their receive
+/// timeout.
+#[derive(Debug, Clone)]
+pub(crate) struct IdleTracker {
+    /// Last time data arrived (or the tracker was created)
+    last_data: Instant,
+    /// Whether the current silence has already been reported
+    idle: bool,
+}
+
+impl Default for IdleTracker {
+    fn default() -> Self {
+        Self::new_at(Instant::now())
+    }
+}
+
+impl IdleTracker {
+    pub(crate) fn new_at(now: Instant) -> Self {
+        Self {
+            last_data: now,
+            idle: false,
+        }
+    }
+
+    /// Records incoming data. Returns how long the stream was silent if it had
+    /// been reported idle, i.e. it just resumed.
+    pub(crate) fn record_data(&mut self) -> Option<Duration> {
+        self.record_data_at(Instant::now())
+    }
+
+    pub(crate) fn record_data_at(&mut self, now: Instant) -> Option<Duration> {
+        let silence = now.saturating_duration_since(self.last_data);
+        self.last_data = now;
+        std::mem::replace(&mut self.idle, false).then_some(silence)
+    }
+
+    /// Returns how long the stream has been silent if that just exceeded
+    /// `timeout`. Each idle period is reported once; a zero `timeout` disables
+    /// detection.
+    pub(crate) fn check(&mut self, timeout: Duration) -> Option<Duration> {
+        self.check_at(Instant::now(), timeout)
+    }
+
+    pub(crate) fn check_at(&mut self, now: Instant, timeout: Duration) -> Option<Duration> {
+        if timeout.is_zero() || self.idle {
+            return None;
+        }
+        let silence = now.saturating_duration_since(self.last_data);
+        if silence < timeout {
+            return None;
+        }
+        self.idle = true;
+        Some(silence)
+    }
+}
+
+/// Detects lost and reordered samples from the `gst.seqnum` sequence numbers
+/// zenohsink attaches with `sequence-numbers=true`.
+///
+/// Sequence numbers are tracked per key expression, so wildcard subscriptions
+/// covering several publishers are handled. A gap counts the skipped numbers
+/// as lost; when one of them arrives afterwards it moves from lost to
+/// reordered. Any other number below the expected one is a duplicate and is
+/// ignored. Sequence number 0 marks a restarted publisher and resets the
+/// expectation for its key without counting anything.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SequenceTracker {
+    /// Sequence state per key expression
+    keys: HashMap<String, KeySequence>,
+    lost: u64,
+    reordered: u64,
+}
+
+/// Gaps remembered per key. Numbers of older gaps that show up late are
+/// taken for duplicates and stay counted as lost.
+const MAX_TRACKED_GAPS: usize = 64;
+
+/// Sequence state of one key expression
+#[derive(Debug, Clone, Default)]
+struct KeySequence {
+    /// Next expected sequence number
+    expected: u64,
+    /// Numbers skipped by the most recent gaps that haven't arrived yet, oldest first
+    missing: VecDeque<Range<u64>>,
+}
+
+impl KeySequence {
+    /// Removes `seqnum` from the missing numbers. Returns whether it was missing.
+    fn take_missing(&mut self, seqnum: u64) -> bool {
+        let Some(index) = self.missing.iter().position(|gap| gap.contains(&seqnum)) else {
+            return false;
+        };
+        let gap = self.missing.remove(index).unwrap();
+        if seqnum + 1 < gap.end {
+            self.missing.insert(index, seqnum + 1..gap.end);
+        }
+        if gap.start < seqnum {
+            self.missing.insert(index, gap.start..seqnum);
+        }
+        self.truncate_missing();
+        true
+    }
+
+    /// Forgets the oldest gaps past `MAX_TRACKED_GAPS`.
+    fn truncate_missing(&mut self) {
+        while self.missing.len() > MAX_TRACKED_GAPS {
+            self.missing.pop_front();
+        }
+    }
+}
+
+impl SequenceTracker {
+    /// Records a sample carrying `seqnum` received on `key_expr`. Returns
+    /// whether it follows a gap, some numbers before it having been skipped.
+    pub(crate) fn record(&mut self, key_expr: &str, seqnum: u64) -> bool {
+        let Some(key) = self.keys.get_mut(key_expr) else {
+            let key = KeySequence {
+                expected: seqnum + 1,
+                ..Default::default()
+            };
+            self.keys.insert(key_expr.to_string(), key);
+            return false;
+        };
+        if seqnum >= key.expected {
+            let skipped = seqnum - key.expected;
+            if skipped > 0 {
+                self.lost += skipped;
+                key.missing.push_back(key.expected..seqnum);
+                key.truncate_missing();
+            }
+            key.expected = seqnum + 1;
+            return skipped > 0;
+        } else if seqnum == 0 {
+            *key = KeySequence {
+                expected: 1,
+                ..Default::default()
+            };
+        } else if key.take_missing(seqnum) {
+            self.lost -= 1;
+            self.reordered += 1;
+        }
+        false
+    }
+
+    /// Samples skipped by a sequence number gap that never arrived.
+    pub(crate) fn lost(&self) -> u64 {
+        self.lost
+    }
+
+    /// Samples that arrived after a later sequence number.
+    pub(crate) fn reordered(&self) -> u64 {
+        self.reordered
+    }
+}
+
+/// QoS carried by the most recent sample, behind `rx-priority` / `rx-express`.
+///
+/// Lets receivers check that they observe the QoS the publisher was
+/// configured with. Priority 0 means no sample was received yet. Zenoh only
+/// exposes the reliability of a sample through its unstable API, so it isn't
+/// tracked.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ReceivedQos {
+    priority: u8,
+    express: bool,
+}
+
+impl ReceivedQos {
+    /// Records the QoS of a received sample.
+    pub(crate) fn record(&mut self, sample: &zenoh::sample::Sample) {
+        self.priority = sample.priority() as u8;
+        self.express = sample.express();
+    }
+
+    /// Priority of the last sample (1=RealTime to 7=Background), 0 before any sample.
+    pub(crate) fn priority(&self) -> u32 {
+        self.priority as u32
+    }
+
+    /// Whether the last sample was published with express.
+    pub(crate) fn express(&self) -> bool {
+        self.express
+    }
+}
+
+/// Posts a `zenoh-stream-idle` or `zenoh-stream-resumed` element message.
+///
+/// The message structure carries:
+/// - `key-expr`: the subscribed key expression
+/// - `idle-ms`: how long no data arrived so far (idle) or in total (resumed)
+pub(crate) fn post_idle_message(
+    element: &gst::Element,
+    name: &str,
+    key_expr: &str,
+    silence: Duration,
+) {
+    let s = gst::Structure::builder(name)
+        .field("key-expr", key_expr)
+        .field("idle-ms", silence.as_millis() as u64)
+        .build();
+    let _ = element.post_message(gst::message::Element::builder(s).src(element).build());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_tracker_idle_is_zero() {
+        let tracker = RateTracker::default();
+        assert_eq!(tracker.bitrate(), 0);
+        assert_eq!(tracker.message_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_rate_tracker_steady_stream() {
+        let mut tracker = RateTracker::default();
+        let start = Instant::now();
+
+        // 100 messages of 1000 bytes spread evenly over one second
+        for i in 0..100 {
+            tracker.record_at(start + Duration::from_millis(i * 10), 1000, 1);
+        }
+        let now = start + Duration::from_millis(995);
+
+        // 100_000 bytes/s = 800_000 bits/s
+        let bitrate = tracker.bitrate_at(now);
+        assert!(
+            (780_000..=820_000).contains(&bitrate),
+            "unexpected bitrate {}",
+            bitrate
+        );
+        let rate = tracker.message_rate_at(now);
+        assert!((rate - 100.0).abs() <= 2.0, "unexpected rate {}", rate);
+    }
+
+    #[test]
+    fn test_rate_tracker_window_slides() {
+        let mut tracker = RateTracker::default();
+        let start = Instant::now();
+
+        tracker.record_at(start, 5000, 5);
+        tracker.record_at(start + Duration::from_millis(600), 1000, 1);
+
+        // First sample has left the window, second is still inside
+        let now = start + Duration::from_millis(1200);
+        assert_eq!(tracker.bitrate_at(now), 8000);
+        assert_eq!(tracker.message_rate_at(now), 1.0);
+    }
+
+    #[test]
+    fn test_rate_tracker_resets_when_idle() {
+        let mut tracker = RateTracker::default();
+        let start = Instant::now();
+
+        tracker.record_at(start, 1_000_000, 10);
+        assert!(tracker.bitrate_at(start) > 0);
+
+        let later = start + Duration::from_secs(5);
+        assert_eq!(tracker.bitrate_at(later), 0);
+        assert_eq!(tracker.message_rate_at(later), 0.0);
+
+        // Recording again after a long gap only counts the new data
+        tracker.record_at(later, 100, 1);
+        assert_eq!(tracker.bitrate_at(later), 800);
+        assert_eq!(tracker.message_rate_at(later), 1.0);
+    }
+
+    #[test]
+    fn test_idle_tracker_reports_once() {
+        let start = Instant::now();
+        let mut tracker = IdleTracker::new_at(start);
+        let timeout = Duration::from_millis(500);
+
+        assert_eq!(tracker.check_at(start + Duration::from_millis(400), timeout), None);
+        assert_eq!(
+            tracker.check_at(start + Duration::from_millis(600), timeout),
+            Some(Duration::from_millis(600))
+        );
+        // Still idle, but already reported
+        assert_eq!(tracker.check_at(start + Duration::from_secs(2), timeout), None);
+
+        // Data resumes after 3s of silence, and a new idle period can start
+        let resumed = start + Duration::from_secs(3);
+        assert_eq!(tracker.record_data_at(resumed), Some(Duration::from_secs(3)));
+        assert_eq!(tracker.record_data_at(resumed), None);
+        assert_eq!(tracker.check_at(resumed + Duration::from_millis(100), timeout), None);
+        assert!(tracker.check_at(resumed + timeout, timeout).is_some());
+    }
+
+    #[test]
+    fn test_sequence_tracker_gaps_and_reordering() {
+        let mut tracker = SequenceTracker::default();
+
+        for seqnum in [0, 1, 2, 5, 6] {
+            assert_eq!(tracker.record("demo/a", seqnum), seqnum == 5);
+        }
+        assert_eq!(tracker.lost(), 2);
+        assert_eq!(tracker.reordered(), 0);
+
+        // 3 shows up late: it was reordered, not lost
+        assert!(!tracker.record("demo/a", 3));
+        assert_eq!(tracker.lost(), 1);
+        assert_eq!(tracker.reordered(), 1);
+
+        // Duplicates of numbers already received change nothing
+        for seqnum in [3, 6, 1] {
+            assert!(!tracker.record("demo/a", seqnum));
+        }
+        assert_eq!(tracker.lost(), 1);
+        assert_eq!(tracker.reordered(), 1);
+
+        // Other keys are tracked independently, starting anywhere
+        tracker.record("demo/b", 40);
+        tracker.record("demo/b", 41);
+        assert_eq!(tracker.lost(), 1);
+
+        // A restarted publisher begins again at 0
+        tracker.record("demo/a", 0);
+        tracker.record("demo/a", 1);
+        assert_eq!(tracker.lost(), 1);
+        assert_eq!(tracker.reordered(), 1);
+    }
+
+    #[test]
+    fn test_sequence_tracker_split_gap() {
+        let mut tracker = SequenceTracker::default();
+
+        tracker.record("demo/a", 10);
+        assert!(tracker.record("demo/a", 20));
+        assert_eq!(tracker.lost(), 9);
+
+        // Late numbers from the middle of the gap are found once each
+        for seqnum in [15, 15, 11, 19, 11] {
+            tracker.record("demo/a", seqnum);
+        }
+        assert_eq!(tracker.lost(), 6);
+        assert_eq!(tracker.reordered(), 3);
+
+        // Only the most recent gaps are remembered
+        for gap in 0..MAX_TRACKED_GAPS as u64 {
+            tracker.record("demo/a", 22 + gap * 2);
+        }
+        let lost = tracker.lost();
+        tracker.record("demo/a", 12);
+        assert_eq!(tracker.lost(), lost, "the first gap was forgotten");
+        tracker.record("demo/a", 21);
+        assert_eq!(tracker.lost(), lost - 1);
+    }
+
+    #[test]
+    fn test_idle_tracker_disabled() {
+        let start = Instant::now();
+        let mut tracker = IdleTracker::new_at(start);
+
+        assert_eq!(tracker.check_at(start + Duration::from_secs(60), Duration::ZERO), None);
+        assert_eq!(tracker.record_data_at(start + Duration::from_secs(61)), None);
+    }
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MPL-2.0
+
+//! Egress rate limiting for zenohsink's `max-bitrate`
+//!
+//! A token bucket that may go into debt: a buffer is let through as long as
+//! the bucket isn't empty, and its size is taken afterwards. Buffers larger
+//! than the bucket still pass, and the debt they leave delays (or drops) the
+//! following ones until the average rate is back under the limit.
+
+use std::time::{Duration, Instant};
+
+/// Burst allowed after an idle period, as an amount of time at the limit
+const BURST: Duration = Duration::from_millis(100);
+
+/// Token bucket counting bytes.
+#[derive(Debug, Default)]
+pub(crate) struct TokenBucket {
+    /// Available bytes; negative while in debt
+    tokens: f64,
+    /// Last refill, `None` until first used (the bucket then starts full)
+    last: Option<Instant>,
+}
+
+impl TokenBucket {
+    fn refill(&mut self, max_bitrate: u64, now: Instant) -> f64 {
+        let bytes_per_sec = max_bitrate as f64 / 8.0;
+        let capacity = bytes_per_sec * BURST.as_secs_f64();
+        self.tokens = match self.last {
+            Some(last) => {
+                let elapsed = now.saturating_duration_since(last).as_secs_f64();
+                (self.tokens + elapsed * bytes_per_sec).min(capacity)
+            }
+            None => capacity,
+        };
+        self.last = Some(now);
+        bytes_per_sec
+    }
+
+    /// Returns how long to wait before the next buffer may be sent at
+    /// `max_bitrate` (bits per second), zero if it may be sent now.
+    pub(crate) fn delay(&mut self, max_bitrate: u64) -> Duration {
+        self.delay_at(max_bitrate, Instant::now())
+    }
+
+    pub(crate) fn delay_at(&mut self, max_bitrate: u64, now: Instant) -> Duration {
+        if max_bitrate == 0 {
+            return Duration::ZERO;
+        }
+        let bytes_per_sec = self.refill(max_bitrate, now);
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / bytes_per_sec)
+        }
+    }
+
+    /// Takes `bytes` sent bytes from the bucket.
+    pub(crate) fn consume(&mut self, bytes: u64) {
+        self.tokens -= bytes as f64;
+    }
+
+    /// Forgets the debt and refills the bucket on next use.
+    pub(crate) fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_millis(delay: Duration, millis: u64) {
+        let expected = Duration::from_millis(millis).as_secs_f64();
+        assert!((delay.as_secs_f64() - expected).abs() < 1e-6, "{:?}", delay);
+    }
+
+    #[test]
+    fn test_unlimited() {
+        let mut bucket = TokenBucket::default();
+        bucket.consume(1_000_000);
+        assert_eq!(bucket.delay(0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_debt_delays_next_buffer() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::default();
+
+        // 8000 bit/s = 1000 bytes/s, starting with a 100 byte burst
+        assert_eq!(bucket.delay_at(8000, start), Duration::ZERO);
+        bucket.consume(600);
+        assert_millis(bucket.delay_at(8000, start), 500);
+
+        // Refilled over time, up to the burst
+        let later = start + Duration::from_millis(600);
+        assert_eq!(bucket.delay_at(8000, later), Duration::ZERO);
+        let idle = later + Duration::from_secs(10);
+        assert_eq!(bucket.delay_at(8000, idle), Duration::ZERO);
+        bucket.consume(300);
+        assert_millis(bucket.delay_at(8000, idle), 200);
+
+        bucket.reset();
+        assert_eq!(bucket.delay_at(8000, idle), Duration::ZERO);
+    }
+}
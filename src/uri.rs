@@ -0,0 +1,193 @@
+//! Helpers for the `zenoh:<key-expr>?<param>=<value>&...` URIs of zenohsrc and zenohsink.
+//!
+//! Parameters are named after the element properties. Generated URIs only carry
+//! the properties that differ from their default, so existing URIs keep their form.
+
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use gst::glib;
+
+/// Splits a `zenoh:` URI into its decoded key expression and query parameters.
+pub(crate) fn parse(uri: &str) -> Result<(String, Vec<(String, String)>), glib::Error> {
+    let Some(uri_content) = uri.strip_prefix("zenoh:") else {
+        return Err(glib::Error::new(
+            gst::URIError::BadUri,
+            &format!("Invalid URI scheme, expected 'zenoh:', got: {}", uri),
+        ));
+    };
+
+    // Split into key expression and query parameters
+    let (key_expr, query) = match uri_content.split_once('?') {
+        Some((key_expr, query)) => (key_expr, Some(query)),
+        None => (uri_content, None),
+    };
+
+    if key_expr.is_empty() {
+        return Err(glib::Error::new(gst::URIError::BadUri, "Key expression cannot be empty"));
+    }
+
+    let key_expr = urlencoding::decode(key_expr)
+        .map_err(|e| {
+            glib::Error::new(
+                gst::URIError::BadUri,
+                &format!("Failed to decode key expression: {}", e),
+            )
+        })?
+        .into_owned();
+
+    let mut params = Vec::new();
+    for param in query.into_iter().flat_map(|query| query.split('&')) {
+        // Parameters without a value are ignored
+        let Some((key, value)) = param.split_once('=') else {
+            continue;
+        };
+        let value = urlencoding::decode(value)
+            .map_err(|e| {
+                glib::Error::new(
+                    gst::URIError::BadUri,
+                    &format!("Failed to decode parameter value: {}", e),
+                )
+            })?
+            .into_owned();
+        params.push((key.to_string(), value));
+    }
+
+    Ok((key_expr, params))
+}
+
+/// Builds a `zenoh:` URI, percent-encoding the parameter values.
+pub(crate) struct UriBuilder {
+    uri: String,
+    params: Vec<String>,
+}
+
+impl UriBuilder {
+    pub(crate) fn new(key_expr: &str) -> Self {
+        Self {
+            uri: format!("zenoh:{}", key_expr),
+            params: Vec::new(),
+        }
+    }
+
+    /// Appends `key=value`.
+    pub(crate) fn param(&mut self, key: &str, value: impl Display) {
+        self.params.push(format!("{}={}", key, urlencoding::encode(&value.to_string())));
+    }
+
+    /// Appends `key=value` when `value` differs from `default`.
+    pub(crate) fn non_default<T: PartialEq + Display>(
+        &mut self,
+        key: &str,
+        value: T,
+        default: T,
+    ) {
+        if value != default {
+            self.param(key, value);
+        }
+    }
+
+    pub(crate) fn build(mut self) -> String {
+        if !self.params.is_empty() {
+            self.uri.push('?');
+            self.uri.push_str(&self.params.join("&"));
+        }
+        self.uri
+    }
+}
+
+fn invalid_value(key: &str, value: &str) -> glib::Error {
+    glib::Error::new(gst::URIError::BadUri, &format!("Invalid {} value: {}", key, value))
+}
+
+/// Parses a number (or any `FromStr` value) of parameter `key`.
+pub(crate) fn parse_value<T: FromStr>(key: &str, value: &str) -> Result<T, glib::Error> {
+    value.parse().map_err(|_| invalid_value(key, value))
+}
+
+/// Parses a number of parameter `key` that must lie in `range`, like the property.
+pub(crate) fn parse_in_range<T: FromStr + PartialOrd>(
+    key: &str,
+    value: &str,
+    range: RangeInclusive<T>,
+) -> Result<T, glib::Error> {
+    parse_value(key, value)
+        .ok()
+        .filter(|parsed| range.contains(parsed))
+        .ok_or_else(|| invalid_value(key, value))
+}
+
+/// Parses a boolean written as `true`/`false`, `1`/`0` or `yes`/`no`.
+pub(crate) fn parse_bool(key: &str, value: &str) -> Result<bool, glib::Error> {
+    match value {
+        "true" | "1" | "yes" => Ok(true),
+        "false" | "0" | "no" => Ok(false),
+        _ => Err(invalid_value(key, value)),
+    }
+}
+
+/// Checks that `value` is one of the strings accepted by the property.
+pub(crate) fn parse_choice(
+    key: &str,
+    value: String,
+    choices: &[&str],
+) -> Result<String, glib::Error> {
+    if choices.contains(&value.as_str()) {
+        Ok(value)
+    } else {
+        Err(invalid_value(key, &value))
+    }
+}
+
+/// Parses an enum property value from its nick (e.g. `session-local`).
+pub(crate) fn parse_enum<T>(key: &str, value: &str) -> Result<T, glib::Error>
+where
+    T: glib::StaticType + for<'a> glib::value::FromValue<'a>,
+{
+    glib::EnumClass::with_type(T::static_type())
+        .and_then(|class| class.to_value_by_nick(value))
+        .and_then(|value| value.get::<T>().ok())
+        .ok_or_else(|| invalid_value(key, value))
+}
+
+/// Returns the nick of an enum property value, as accepted by [`parse_enum`].
+pub(crate) fn enum_nick<T: glib::value::ToValue>(value: T) -> String {
+    glib::EnumValue::from_value(&value.to_value())
+        .map(|(_, enum_value)| enum_value.nick().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_build_roundtrip() {
+        let mut builder = UriBuilder::new("demo/video");
+        builder.param("caps", "video/x-raw, format=(string)RGB");
+        builder.non_default("priority", 5, 5);
+        builder.non_default("caps-interval", 0, 1);
+        let uri = builder.build();
+        assert!(!uri.contains("priority"));
+
+        let (key_expr, params) = parse(&uri).unwrap();
+        assert_eq!(key_expr, "demo/video");
+        assert_eq!(
+            params,
+            vec![
+                ("caps".to_string(), "video/x-raw, format=(string)RGB".to_string()),
+                ("caps-interval".to_string(), "0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_values() {
+        assert_eq!(parse_in_range("priority", "7", 1u8..=7).unwrap(), 7);
+        assert!(parse_in_range("priority", "8", 1u8..=7).is_err());
+        assert!(parse_bool("express", "yes").unwrap());
+        assert!(parse_bool("express", "maybe").is_err());
+        assert!(parse_choice("reliability", "lossy".into(), &["best-effort", "reliable"]).is_err());
+    }
+}
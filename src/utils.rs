@@ -1,2 +1,66 @@
-// This file intentionally left minimal as we're using
-// Zenoh's synchronous API with wait() instead of async/await
+// SPDX-License-Identifier: MPL-2.0
+
+//! Helpers shared by the elements and their builders
+
+use gst::glib;
+use gst::prelude::*;
+use zenoh::key_expr::OwnedKeyExpr;
+
+/// Checks that `key_expr` is a valid Zenoh key expression.
+///
+/// The error names the key expression and gives the reason reported by Zenoh,
+/// so it can be shown as is. The elements perform the same check on start.
+pub fn validate_key_expr(key_expr: &str) -> Result<(), String> {
+    OwnedKeyExpr::try_from(key_expr.to_string())
+        .map(|_| ())
+        .map_err(|e| format!("invalid key expression '{}': {}", key_expr, e))
+}
+
+/// Logs the state of `element` and the values of its own properties at INFO,
+/// in one block on `cat`. Used by the `dump-state` action signals.
+///
+/// Listing the properties keeps the dump in sync with the settings and
+/// statistics of each element. Write-only properties (`password`) can't be
+/// read and `stats-json` repeats the statistics, so both are left out.
+pub(crate) fn dump_state(element: &gst::Element, cat: gst::DebugCategory, state: &str) {
+    let mut block = format!("State dump of {}\n  state: {}", element.name(), state);
+    for pspec in element.list_properties() {
+        if pspec.owner_type() != element.type_()
+            || !pspec.flags().contains(glib::ParamFlags::READABLE)
+            || pspec.name() == "stats-json"
+        {
+            continue;
+        }
+        let value = element.property_value(pspec.name());
+        let value = match value.serialize() {
+            Ok(serialized) => serialized.to_string(),
+            Err(_) => format!("{:?}", value),
+        };
+        block.push_str(&format!("\n  {}: {}", pspec.name(), value));
+    }
+    gst::info!(cat, obj = element, "{}", block);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_key_expr_valid() {
+        for key_expr in ["demo/video", "demo/*/video", "demo/**", "a", "cameras/$*-front"] {
+            assert!(
+                validate_key_expr(key_expr).is_ok(),
+                "'{}' should be valid",
+                key_expr
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_key_expr_invalid() {
+        for key_expr in ["", "demo//video", "demo/video/", "/demo", "demo/#bad", "demo/?x"] {
+            let err = validate_key_expr(key_expr).expect_err(key_expr);
+            assert!(err.contains(&format!("'{}'", key_expr)), "{}", err);
+        }
+    }
+}
@@ -1,13 +1,15 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, LazyLock, Mutex};
+use std::sync::{Arc, Condvar, LazyLock, Mutex};
 use std::time::Duration;
 
 use gst::{glib, prelude::*, subclass::prelude::*};
 use zenoh::Wait;
 
-use crate::error::{ErrorHandling, ZenohError};
-use crate::metadata::MetadataParser;
+use crate::error::{ErrorHandling, ZenohError, post_error_message};
+use crate::metadata::{MetadataParser, StreamGroups, StreamIdentity, ZenohKeyMeta, ZenohUserMeta};
+use crate::session::{ConnectivityMonitor, GroupSession, SessionConfig};
+use crate::stats::{IdleTracker, RateTracker, ReceivedQos, SequenceTracker};
 
 // Define debug category for logging
 static CAT: LazyLock<gst::DebugCategory> = LazyLock::new(|| {
@@ -30,7 +32,7 @@ pub enum PadNaming {
     /// Use last segment only: "camera/front" -> "front"
     #[enum_value(name = "Last Segment", nick = "last-segment")]
     LastSegment = 1,
-    /// Use hash of key expression: "camera/front" -> "pad_a1b2c3"
+    /// Use hash of key expression: "camera/front" -> "pad_8108a0"
     #[enum_value(name = "Hash", nick = "hash")]
     Hash = 2,
 }
@@ -41,12 +43,84 @@ struct Statistics {
     bytes_received: u64,
     messages_received: u64,
     pads_created: u64,
+    /// Samples dropped without a pad because `max-pads` was reached
+    pads_rejected: u64,
     errors: u64,
+    /// Buffers dropped because the queue of a pad was full (`per-pad-queue-size`)
+    dropped_queue_full: u64,
+    /// Serialized attachment (metadata) bytes received, on top of `bytes_received`
+    attachment_bytes_received: u64,
+    /// Samples whose metadata was malformed (e.g. unparsable caps), delivered anyway
+    metadata_errors: u64,
+    /// Sliding-window throughput across all pads (bitrate / message-rate)
+    rate: RateTracker,
+    /// Lost / reordered samples according to the publishers' sequence numbers
+    sequence: SequenceTracker,
+    /// QoS of the last sample (`rx-priority` / `rx-express`)
+    rx_qos: ReceivedQos,
+    #[cfg(any(
+        feature = "compression-zstd",
+        feature = "compression-lz4",
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
+    ))]
+    bytes_before_decompression: u64,
+    #[cfg(any(
+        feature = "compression-zstd",
+        feature = "compression-lz4",
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
+    ))]
+    bytes_after_decompression: u64,
+}
+
+impl Statistics {
+    /// Serializes all statistics into a compact JSON object keyed by property name.
+    fn to_json(&self) -> String {
+        #[allow(unused_mut)]
+        let mut json = serde_json::json!({
+            "bytes-received": self.bytes_received,
+            "messages-received": self.messages_received,
+            "errors": self.errors,
+            "pads-created": self.pads_created,
+            "pads-rejected": self.pads_rejected,
+            "dropped-queue-full": self.dropped_queue_full,
+            "attachment-bytes-received": self.attachment_bytes_received,
+            "metadata-errors": self.metadata_errors,
+            "samples-lost": self.sequence.lost(),
+            "samples-reordered": self.sequence.reordered(),
+            "bitrate": self.rate.bitrate(),
+            "message-rate": self.rate.message_rate(),
+            "rx-priority": self.rx_qos.priority(),
+            "rx-express": self.rx_qos.express(),
+        });
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        {
+            json["bytes-before-decompression"] = self.bytes_before_decompression.into();
+            json["bytes-after-decompression"] = self.bytes_after_decompression.into();
+        }
+        json.to_string()
+    }
+}
+
+/// Counters of one pad, reported by `get-streams`
+#[derive(Debug, Default)]
+struct PadStatistics {
+    bytes_received: u64,
+    messages_received: u64,
 }
 
 struct Started {
-    // Keep session alive for the duration of the element
-    _session: zenoh::Session,
+    // Keep session alive for the duration of the element (also used for introspection)
+    session: zenoh::Session,
     // Keep subscriber alive (actual receiving is done in thread with its own subscriber)
     _subscriber:
         zenoh::pubsub::Subscriber<zenoh::handlers::FifoChannelHandler<zenoh::sample::Sample>>,
@@ -54,10 +128,26 @@ struct Started {
     stopping: Arc<AtomicBool>,
     /// Statistics tracking
     stats: Arc<Mutex<Statistics>>,
-    /// Map of key expression -> source pad
-    pads: Arc<Mutex<HashMap<String, gst::Pad>>>,
+    /// Map of pad name -> source pad
+    pads: Arc<Mutex<HashMap<String, DemuxPad>>>,
     /// Receiver thread handle
     thread_handle: Option<std::thread::JoinHandle<()>>,
+    /// Liveliness tokens advertising the algorithms this build decompresses
+    #[cfg(any(
+        feature = "compression-zstd",
+        feature = "compression-lz4",
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
+    ))]
+    _compression_tokens: Vec<zenoh::liveliness::LivelinessToken>,
+    /// Emits the session signals (when `connectivity-poll-ms` is set)
+    _connectivity: Option<ConnectivityMonitor>,
+    /// Prometheus endpoint serving `stats` (when `metrics-port` is set)
+    #[cfg(feature = "metrics-http")]
+    _metrics: Option<crate::metrics::MetricsServer>,
+    /// Releases the `session-group` session when dropped, after everything using it
+    group_session: Option<GroupSession>,
 }
 
 #[derive(Default)]
@@ -67,6 +157,15 @@ enum State {
     Started(Started),
 }
 
+impl State {
+    fn name(&self) -> &'static str {
+        match self {
+            State::Stopped => "Stopped",
+            State::Started(_) => "Started",
+        }
+    }
+}
+
 /// Configuration settings for the ZenohDemux element.
 #[derive(Debug)]
 struct Settings {
@@ -74,12 +173,56 @@ struct Settings {
     key_expr: String,
     /// Optional path to Zenoh configuration file
     config_file: Option<String>,
+    /// Inline Zenoh configuration (JSON5), takes precedence over `config_file`
+    config_json5: Option<String>,
+    /// Multicast scouting for peer discovery (default: true)
+    scouting: bool,
+    /// Client mode connecting only to the configured endpoints (default: false)
+    endpoints_only: bool,
+    /// CA certificate for verifying TLS routers/peers (overrides the config file)
+    tls_root_ca: Option<String>,
+    /// Client certificate for mutual TLS
+    tls_client_cert: Option<String>,
+    /// Private key of the client certificate
+    tls_client_key: Option<String>,
+    /// User name for usrpwd authentication
+    username: Option<String>,
+    /// Password for usrpwd authentication (write-only, never logged)
+    password: Option<String>,
     /// How to name pads from key expressions
     pad_naming: PadNaming,
+    /// Width in bits of the key hash in `hash` pad names
+    hash_bits: u32,
+    /// Prefix removed from keys before deriving pad names (empty = none)
+    strip_prefix: String,
+    /// Pad names of given keys, taking precedence over `pad_naming`
+    pad_name_map: Option<gst::Structure>,
+    /// Push every key on one `src` pad instead of a pad per key (default: false)
+    single_pad: bool,
     /// Receive timeout in milliseconds
     receive_timeout_ms: u64,
     /// Session group name for sharing sessions via property (gst-launch compatible)
     session_group: Option<String>,
+    /// How long the group session stays open after its last element released it
+    session_group_linger_ms: u32,
+    /// Drop samples whose payload exceeds this many bytes (0 = unlimited)
+    max_buffer_size: u64,
+    /// Apply buffer timing metadata (PTS, DTS, duration, flags) from received messages (default: true)
+    apply_buffer_meta: bool,
+    /// Post `zenoh-stream-idle` when no sample arrives for this long (0 = disabled)
+    no_data_timeout_ms: u64,
+    /// Buffers queued per pad and pushed from the pad's own task (0 = push directly)
+    per_pad_queue_size: u32,
+    /// Dynamic pads created at most, samples of further keys being dropped (0 = unlimited)
+    max_pads: u32,
+    /// Session connectivity check interval for the session signals (0 = disabled)
+    connectivity_poll_ms: u32,
+    /// Port of the Prometheus statistics endpoint (0 = disabled)
+    #[cfg(feature = "metrics-http")]
+    metrics_port: u32,
+    /// Address the Prometheus statistics endpoint listens on
+    #[cfg(feature = "metrics-http")]
+    metrics_address: String,
 }
 
 impl Default for Settings {
@@ -87,15 +230,275 @@ impl Default for Settings {
         Self {
             key_expr: String::new(),
             config_file: None,
+            config_json5: None,
+            scouting: true,
+            endpoints_only: false,
+            tls_root_ca: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+            username: None,
+            password: None,
             pad_naming: PadNaming::FullPath,
+            hash_bits: DEFAULT_HASH_BITS,
+            strip_prefix: String::new(),
+            pad_name_map: None,
+            single_pad: false,
             receive_timeout_ms: 100,
             session_group: None,
+            session_group_linger_ms: crate::session::DEFAULT_SESSION_GROUP_LINGER_MS,
+            max_buffer_size: 0,
+            apply_buffer_meta: true,
+            no_data_timeout_ms: 0,
+            per_pad_queue_size: 0,
+            max_pads: 0,
+            connectivity_poll_ms: crate::session::DEFAULT_CONNECTIVITY_POLL_MS,
+            #[cfg(feature = "metrics-http")]
+            metrics_port: 0,
+            #[cfg(feature = "metrics-http")]
+            metrics_address: crate::metrics::DEFAULT_METRICS_ADDRESS.into(),
+        }
+    }
+}
+
+/// Settings the receiver thread works with, captured at start
+#[derive(Debug, Clone)]
+struct ReceiverConfig {
+    pad_naming: PadNaming,
+    hash_bits: u32,
+    strip_prefix: String,
+    /// Pad name of each key of `pad-name-map`
+    pad_name_map: HashMap<String, String>,
+    single_pad: bool,
+    receive_timeout_ms: u64,
+    max_buffer_size: u64,
+    apply_buffer_meta: bool,
+    no_data_timeout_ms: u64,
+    per_pad_queue_size: u32,
+    max_pads: u32,
+}
+
+/// Bounded buffer queue between the receiver thread and the streaming task of one pad.
+///
+/// When the queue is full the oldest buffer is dropped, so a stalled branch only
+/// loses its own data instead of blocking the receiver thread, and with it every
+/// other pad. Serialized events are queued with the buffers to keep their order,
+/// and are never dropped.
+struct PadQueue {
+    capacity: usize,
+    inner: Mutex<PadQueueInner>,
+    cond: Condvar,
+}
+
+/// An entry of a [`PadQueue`]
+enum QueueItem {
+    Buffer(gst::Buffer),
+    Event(gst::Event),
+}
+
+#[derive(Default)]
+struct PadQueueInner {
+    items: VecDeque<QueueItem>,
+    /// Buffers among `items`, which `capacity` applies to
+    buffers: usize,
+    flushing: bool,
+}
+
+impl PadQueue {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(PadQueueInner::default()),
+            cond: Condvar::new(),
+        }
+    }
+
+    /// Queues `buffer`. Returns whether the oldest queued buffer had to be dropped.
+    fn push(&self, buffer: gst::Buffer) -> bool {
+        let mut inner = self.inner.lock().unwrap();
+        let dropped = inner.buffers >= self.capacity;
+        if dropped {
+            let oldest = inner
+                .items
+                .iter()
+                .position(|item| matches!(item, QueueItem::Buffer(_)));
+            if let Some(index) = oldest {
+                inner.items.remove(index);
+                inner.buffers -= 1;
+            }
+        }
+        inner.items.push_back(QueueItem::Buffer(buffer));
+        inner.buffers += 1;
+        self.cond.notify_one();
+        dropped
+    }
+
+    /// Queues `event` behind the buffers already queued.
+    fn push_event(&self, event: gst::Event) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.items.push_back(QueueItem::Event(event));
+        self.cond.notify_one();
+    }
+
+    /// Waits for the next item. Returns `None` once the queue is flushing.
+    fn pop(&self) -> Option<QueueItem> {
+        let mut inner = self.inner.lock().unwrap();
+        loop {
+            if inner.flushing {
+                return None;
+            }
+            if let Some(item) = inner.items.pop_front() {
+                if matches!(item, QueueItem::Buffer(_)) {
+                    inner.buffers -= 1;
+                }
+                return Some(item);
+            }
+            inner = self.cond.wait(inner).unwrap();
+        }
+    }
+
+    /// Discards the queued items and wakes up the streaming task so it can stop.
+    fn set_flushing(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.flushing = true;
+        inner.items.clear();
+        inner.buffers = 0;
+        self.cond.notify_all();
+    }
+}
+
+/// A dynamic source pad, with the queue its streaming task drains when
+/// `per-pad-queue-size` is set.
+#[derive(Clone)]
+struct DemuxPad {
+    pad: gst::Pad,
+    queue: Option<Arc<PadQueue>>,
+    /// Key of the first sample pushed on the pad, which it was created for
+    key_expr: Arc<str>,
+    stats: Arc<Mutex<PadStatistics>>,
+}
+
+impl DemuxPad {
+    /// Wraps `pad`, pushed from the receiver thread.
+    fn new(pad: gst::Pad, key_expr: &str) -> Self {
+        Self {
+            pad,
+            queue: None,
+            key_expr: Arc::from(key_expr),
+            stats: Arc::new(Mutex::new(PadStatistics::default())),
+        }
+    }
+
+    /// Starts the streaming task pushing the queued buffers and events of `pad`.
+    fn with_queue(
+        pad: gst::Pad,
+        key_expr: &str,
+        capacity: usize,
+    ) -> Result<Self, glib::BoolError> {
+        let queue = Arc::new(PadQueue::new(capacity));
+        let task_pad = pad.clone();
+        let task_queue = queue.clone();
+        pad.start_task(move || match task_queue.pop() {
+            Some(QueueItem::Buffer(buffer)) => push_buffer(&task_pad, buffer),
+            Some(QueueItem::Event(event)) => push_event(&task_pad, event),
+            None => {
+                let _ = task_pad.pause_task();
+            }
+        })?;
+        Ok(Self {
+            queue: Some(queue),
+            ..Self::new(pad, key_expr)
+        })
+    }
+
+    /// Sends a serialized event downstream in order with the buffers: behind
+    /// the queued ones with a queue, right away otherwise.
+    fn push_event(&self, event: gst::Event) {
+        match self.queue {
+            Some(ref queue) => queue.push_event(event),
+            None => push_event(&self.pad, event),
+        }
+    }
+
+    /// Flushes the queue and stops the streaming task, if any.
+    fn stop(&self) {
+        if let Some(ref queue) = self.queue {
+            queue.set_flushing();
+            if let Err(e) = self.pad.stop_task() {
+                gst::warning!(CAT, "Failed to stop task of pad {}: {}", self.pad.name(), e);
+            }
         }
     }
 }
 
+/// Pushes `buffer` downstream, logging failures other than flushing.
+fn push_buffer(pad: &gst::Pad, buffer: gst::Buffer) {
+    match pad.push(buffer) {
+        Ok(_) => {}
+        Err(gst::FlowError::Flushing) => {
+            gst::debug!(CAT, "Pad {} is flushing", pad.name());
+        }
+        Err(e) => {
+            gst::warning!(CAT, "Failed to push buffer to pad {}: {:?}", pad.name(), e);
+        }
+    }
+}
+
+/// Pushes `event` downstream, logging when it isn't accepted.
+fn push_event(pad: &gst::Pad, event: gst::Event) {
+    let type_ = event.type_();
+    if !pad.push_event(event) {
+        gst::debug!(CAT, "{:?} event not accepted downstream of pad {}", type_, pad.name());
+    }
+}
+
+/// Longest wait for a sample between checks of the stop flag. Bounds how
+/// long stopping takes, whatever `receive-timeout-ms` is.
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Name of the pad every key is pushed on with `single-pad`
+const SINGLE_PAD_NAME: &str = "src";
+
+/// Width of the key hash in `hash` pad names by default, in bits
+const DEFAULT_HASH_BITS: u32 = 24;
+
+/// Remove `prefix` from the start of `key_expr` before pad naming.
+///
+/// The prefix must end on a segment boundary (`factory/line3` and `factory/line3/`
+/// both turn `factory/line3/camera/front` into `camera/front`) and must leave at least
+/// one segment. Returns `None` when it doesn't match, so the caller can keep the full key.
+fn strip_key_prefix<'a>(key_expr: &'a str, prefix: &str) -> Option<&'a str> {
+    let prefix = prefix.trim_end_matches('/');
+    if prefix.is_empty() {
+        return Some(key_expr);
+    }
+    key_expr
+        .strip_prefix(prefix)?
+        .strip_prefix('/')
+        .filter(|rest| !rest.is_empty())
+}
+
+/// 64-bit FNV-1a hash. Unlike `DefaultHasher`, it is the same with every Rust
+/// version, so `hash` pad names don't change from one build to the next.
+fn fnv1a_64(data: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    data.iter()
+        .fold(OFFSET_BASIS, |hash, &byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
+
+/// Name of a `hash` pad: the hash of `key_expr` xor-folded to `bits` bits, in hex
+fn hash_pad_name(key_expr: &str, bits: u32) -> String {
+    let hash = fnv1a_64(key_expr.as_bytes());
+    let hash = if bits < 64 {
+        ((hash >> bits) ^ hash) & ((1 << bits) - 1)
+    } else {
+        hash
+    };
+    format!("pad_{:0width$x}", hash, width = bits.div_ceil(4) as usize)
+}
+
 /// Convert a key expression to a valid GStreamer pad name
-fn key_expr_to_pad_name(key_expr: &str, naming: PadNaming) -> String {
+fn key_expr_to_pad_name(key_expr: &str, naming: PadNaming, hash_bits: u32) -> String {
     match naming {
         PadNaming::FullPath => {
             // Replace invalid characters with underscores
@@ -113,14 +516,74 @@ fn key_expr_to_pad_name(key_expr: &str, naming: PadNaming) -> String {
                 .replace('*', "wildcard")
                 .replace(' ', "_")
         }
-        PadNaming::Hash => {
-            // Use a hash of the key expression
-            use std::collections::hash_map::DefaultHasher;
-            use std::hash::{Hash, Hasher};
-            let mut hasher = DefaultHasher::new();
-            key_expr.hash(&mut hasher);
-            format!("pad_{:x}", hasher.finish() & 0xFFFFFF)
+        PadNaming::Hash => hash_pad_name(key_expr, hash_bits),
+    }
+}
+
+/// Reads `pad-name-map` into the pad name of each key expression.
+///
+/// The names must be usable from `gst-launch` (`demux.<name>`): letters,
+/// digits, `_` and `-` only, and no two keys may share one.
+fn parse_pad_name_map(map: &gst::Structure) -> Result<HashMap<String, String>, String> {
+    let mut names = HashMap::new();
+    let mut used = std::collections::HashSet::new();
+    for (key_expr, value) in map.iter() {
+        let name = value
+            .get::<String>()
+            .map_err(|_| format!("pad name of '{}' is not a string", key_expr))?;
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid {
+            return Err(format!("'{}' is not a valid pad name (key '{}')", name, key_expr));
+        }
+        if !used.insert(name.clone()) {
+            return Err(format!("pad name '{}' is given to more than one key", name));
+        }
+        names.insert(key_expr.to_string(), name);
+    }
+    Ok(names)
+}
+
+/// Pad names given to keys with `hash` naming, so that keys whose hashes
+/// collide still get pads of their own
+#[derive(Default)]
+struct HashPadNames {
+    /// Pad name of each key
+    names: HashMap<String, String>,
+    /// Key each pad name was given to
+    keys: HashMap<String, String>,
+}
+
+impl HashPadNames {
+    /// Returns the pad name of `key_expr`. When its hash is the name of
+    /// another key's pad, a `_<n>` suffix is appended and a warning logged.
+    fn name(&mut self, key_expr: &str, hash_bits: u32) -> String {
+        if let Some(name) = self.names.get(key_expr) {
+            return name.clone();
+        }
+
+        let hashed = hash_pad_name(key_expr, hash_bits);
+        let mut name = hashed.clone();
+        let mut suffix = 0;
+        while let Some(other) = self.keys.get(&name) {
+            if suffix == 0 {
+                gst::warning!(
+                    CAT,
+                    "Pad name '{}' of '{}' collides with '{}'; raise hash-bits to avoid it",
+                    hashed,
+                    key_expr,
+                    other
+                );
+            }
+            suffix += 1;
+            name = format!("{}_{}", hashed, suffix);
         }
+
+        self.names.insert(key_expr.to_string(), name.clone());
+        self.keys.insert(name.clone(), key_expr.to_string());
+        name
     }
 }
 
@@ -165,7 +628,16 @@ impl ElementImpl for ZenohDemux {
             )
             .unwrap();
 
-            vec![src_template]
+            // The pad every key is pushed on with single-pad, created on start
+            let single_template = gst::PadTemplate::new(
+                "src",
+                gst::PadDirection::Src,
+                gst::PadPresence::Sometimes,
+                &gst::Caps::new_any(),
+            )
+            .unwrap();
+
+            vec![src_template, single_template]
         });
 
         PAD_TEMPLATES.as_ref()
@@ -211,6 +683,36 @@ impl ElementImpl for ZenohDemux {
 }
 
 impl ObjectImpl for ZenohDemux {
+    fn signals() -> &'static [glib::subclass::Signal] {
+        static SIGNALS: LazyLock<Vec<glib::subclass::Signal>> = LazyLock::new(|| {
+            vec![
+                glib::subclass::Signal::builder("session-disconnected").build(),
+                glib::subclass::Signal::builder("session-reconnected").build(),
+                glib::subclass::Signal::builder("dump-state")
+                    .action()
+                    .class_handler(|args| {
+                        let element = args[0]
+                            .get::<super::ZenohDemux>()
+                            .expect("signal arg type checked upstream");
+                        element.imp().dump_state();
+                        None
+                    })
+                    .build(),
+                glib::subclass::Signal::builder("get-streams")
+                    .action()
+                    .return_type::<gst::Array>()
+                    .class_handler(|args| {
+                        let element = args[0]
+                            .get::<super::ZenohDemux>()
+                            .expect("signal arg type checked upstream");
+                        Some(element.imp().streams().to_value())
+                    })
+                    .build(),
+            ]
+        });
+        SIGNALS.as_ref()
+    }
+
     fn properties() -> &'static [glib::ParamSpec] {
         static PROPERTIES: LazyLock<Vec<glib::ParamSpec>> = LazyLock::new(|| {
             vec![
@@ -222,22 +724,144 @@ impl ObjectImpl for ZenohDemux {
                     .nick("Zenoh Configuration")
                     .blurb("Path to Zenoh configuration file (JSON5 format)")
                     .build(),
+                glib::ParamSpecString::builder("config-json5")
+                    .nick("Zenoh Configuration (JSON5)")
+                    .blurb("Inline Zenoh configuration in JSON5 format (e.g. '{mode: \"client\", connect: {endpoints: [\"tcp/10.0.0.1:7447\"]}}'), taking precedence over config; scouting, TLS and authentication properties are applied on top")
+                    .build(),
+                glib::ParamSpecBoolean::builder("scouting")
+                    .nick("Multicast Scouting")
+                    .blurb("Discover peers via multicast scouting; disable on networks where multicast doesn't work and rely on configured endpoints")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecBoolean::builder("endpoints-only")
+                    .nick("Endpoints Only")
+                    .blurb("Connect only to the configured endpoints: client mode with multicast scouting disabled; starting fails when the configuration has no connect endpoint")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecString::builder("tls-root-ca")
+                    .nick("TLS Root CA")
+                    .blurb("Path to the CA certificate (PEM) used to verify TLS routers and peers; overrides transport/link/tls/root_ca_certificate of the config file")
+                    .build(),
+                glib::ParamSpecString::builder("tls-client-cert")
+                    .nick("TLS Client Certificate")
+                    .blurb("Path to the client certificate (PEM) presented on TLS links for mutual TLS; requires tls-client-key")
+                    .build(),
+                glib::ParamSpecString::builder("tls-client-key")
+                    .nick("TLS Client Key")
+                    .blurb("Path to the private key (PEM) of tls-client-cert")
+                    .build(),
+                glib::ParamSpecString::builder("username")
+                    .nick("Username")
+                    .blurb("User name for Zenoh usrpwd authentication; overrides transport/auth/usrpwd/user of the config file, requires password")
+                    .build(),
+                glib::ParamSpecString::builder("password")
+                    .nick("Password")
+                    .blurb("Password for Zenoh usrpwd authentication (write-only)")
+                    .write_only()
+                    .build(),
                 glib::ParamSpecEnum::builder_with_default("pad-naming", PadNaming::FullPath)
                     .nick("Pad Naming Strategy")
                     .blurb("How to derive pad names from key expressions")
                     .build(),
+                glib::ParamSpecUInt::builder("hash-bits")
+                    .nick("Hash Bits")
+                    .blurb("Width in bits of the key hash in pad names with pad-naming=hash; raise it for many keys, keys whose hashes still collide get a '_<n>' suffix")
+                    .default_value(DEFAULT_HASH_BITS)
+                    .minimum(8)
+                    .maximum(64)
+                    .build(),
+                glib::ParamSpecString::builder("strip-prefix")
+                    .nick("Strip Prefix")
+                    .blurb("Prefix removed from each key expression before deriving the pad name, e.g. 'factory/line3/' turns 'factory/line3/camera/front' into 'camera_front'. Keys that don't start with it keep their full name")
+                    .build(),
+                glib::ParamSpecBoxed::builder::<gst::Structure>("pad-name-map")
+                    .nick("Pad Name Map")
+                    .blurb("Pad names of given keys, e.g. 'map, camera/front=front_cam, camera/rear=rear_cam'. Field names are key expressions as received, values pad names (letters, digits, '_' and '-'); other keys are named by pad-naming")
+                    .build(),
+                glib::ParamSpecBoolean::builder("single-pad")
+                    .nick("Single Pad")
+                    .blurb("Push the samples of every key on one 'src' pad, added on start, instead of a pad per key; the source key of each buffer is in its GstZenohKeyMeta. Caps are taken from the first sample carrying them")
+                    .default_value(false)
+                    .build(),
                 glib::ParamSpecUInt64::builder("receive-timeout-ms")
                     .nick("Receive Timeout")
-                    .blurb("Timeout in milliseconds for polling Zenoh subscriber")
+                    .blurb("Timeout in milliseconds for polling Zenoh subscriber; polls are capped at 100 ms so that stopping stays fast")
                     .default_value(100)
                     .minimum(10)
                     .maximum(5000)
                     .build(),
+                glib::ParamSpecBoolean::builder("apply-buffer-meta")
+                    .nick("Apply Buffer Metadata")
+                    .blurb("Apply buffer timing metadata (PTS, DTS, duration, offset, flags) from received messages. When disabled, buffers are left untimed for downstream re-timestamping; caps are still used.")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecUInt64::builder("max-buffer-size")
+                    .nick("Max Buffer Size")
+                    .blurb("Drop samples whose payload is larger than this many bytes instead of allocating a buffer for them; compressed samples that decompress to more fail to decode (0 = unlimited)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt::builder("per-pad-queue-size")
+                    .nick("Per-Pad Queue Size")
+                    .blurb("Buffers queued per pad and pushed from a streaming task of the pad, so a slow or blocked branch doesn't stall the others; the oldest buffer is dropped when full (0 = push directly from the receiver thread)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt::builder("max-pads")
+                    .nick("Max Pads")
+                    .blurb("Create at most this many dynamic pads; samples of further keys are dropped and counted in pads-rejected, a warning being posted the first time (0 = unlimited). Applied on the next start")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt64::builder("no-data-timeout-ms")
+                    .nick("No Data Timeout")
+                    .blurb("Post a zenoh-stream-idle element message when no sample arrives on any key for this many milliseconds, and zenoh-stream-resumed when data returns (0 = disabled)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt::builder("connectivity-poll-ms")
+                    .nick("Connectivity Poll Interval")
+                    .blurb("How often the session is checked for router and peer connections, emitting session-disconnected when the last one is lost and session-reconnected when one is back (0 = disabled)")
+                    .default_value(crate::session::DEFAULT_CONNECTIVITY_POLL_MS)
+                    .maximum(60_000)
+                    .build(),
+                #[cfg(feature = "metrics-http")]
+                glib::ParamSpecUInt::builder("metrics-port")
+                    .nick("Metrics Port")
+                    .blurb("Serve the statistics in Prometheus text format on http://<metrics-address>:<port>/metrics while the element is started (0 = disabled). Applied on the next start")
+                    .maximum(u16::MAX as u32)
+                    .default_value(0)
+                    .build(),
+                #[cfg(feature = "metrics-http")]
+                glib::ParamSpecString::builder("metrics-address")
+                    .nick("Metrics Address")
+                    .blurb("Address the metrics-port endpoint listens on. The default only accepts connections from the local host; use 0.0.0.0 to serve every interface. Applied on the next start")
+                    .default_value(Some(crate::metrics::DEFAULT_METRICS_ADDRESS))
+                    .build(),
                 // Session sharing property
                 glib::ParamSpecString::builder("session-group")
                     .nick("Session Group")
                     .blurb("Name of the session group for sharing Zenoh sessions across elements. Elements with the same group name share a single session.")
                     .build(),
+                glib::ParamSpecUInt::builder("session-group-linger-ms")
+                    .nick("Session Group Linger")
+                    .blurb("Keep the session-group session open this many milliseconds after this element was the last one to release it, so elements briefly overlapping reuse it instead of reopening one (0 = close right away)")
+                    .maximum(crate::session::MAX_SESSION_GROUP_LINGER_MS)
+                    .default_value(crate::session::DEFAULT_SESSION_GROUP_LINGER_MS)
+                    .build(),
+                // Session introspection (read-only)
+                glib::ParamSpecString::builder("session-zid")
+                    .nick("Session ZID")
+                    .blurb("Zenoh ID of the session used by this element (empty when stopped)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecBoolean::builder("session-shared")
+                    .nick("Session Shared")
+                    .blurb("Whether the session comes from session-group rather than opened by this element (false when stopped)")
+                    .default_value(false)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecBoxed::builder::<Vec<String>>("peers")
+                    .nick("Connected Peers")
+                    .blurb("Routers and peers the session is connected to, as 'router:<zid>' or 'peer:<zid>' (empty when stopped)")
+                    .read_only()
+                    .build(),
                 // Statistics (read-only)
                 glib::ParamSpecUInt64::builder("bytes-received")
                     .nick("Bytes Received")
@@ -249,11 +873,98 @@ impl ObjectImpl for ZenohDemux {
                     .blurb("Total messages received since element started")
                     .read_only()
                     .build(),
+                glib::ParamSpecUInt64::builder("errors")
+                    .nick("Errors")
+                    .blurb("Total number of errors encountered (including dropped oversized samples)")
+                    .read_only()
+                    .build(),
                 glib::ParamSpecUInt64::builder("pads-created")
                     .nick("Pads Created")
                     .blurb("Number of dynamic pads created")
                     .read_only()
                     .build(),
+                glib::ParamSpecUInt64::builder("pads-rejected")
+                    .nick("Pads Rejected")
+                    .blurb("Samples dropped because their key had no pad and max-pads pads already existed")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("dropped-queue-full")
+                    .nick("Dropped Queue Full")
+                    .blurb("Buffers dropped because the queue of their pad was full (per-pad-queue-size)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("attachment-bytes-received")
+                    .nick("Attachment Bytes Received")
+                    .blurb("Serialized metadata attachment bytes received (caps, buffer timing, ...), not included in bytes-received")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("metadata-errors")
+                    .nick("Metadata Errors")
+                    .blurb("Samples whose metadata attachment was malformed (e.g. unparsable caps); their payload is still delivered")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("samples-lost")
+                    .nick("Samples Lost")
+                    .blurb("Samples missing from the publishers' sequence numbers, across all keys (requires sequence-numbers on zenohsink)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("samples-reordered")
+                    .nick("Samples Reordered")
+                    .blurb("Samples received after a sample with a higher sequence number on the same key (requires sequence-numbers on zenohsink)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("bitrate")
+                    .nick("Bitrate")
+                    .blurb("Incoming bitrate across all pads in bits per second over the last second (0 when idle)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecDouble::builder("message-rate")
+                    .nick("Message Rate")
+                    .blurb("Incoming messages per second across all pads over the last second (0 when idle)")
+                    .minimum(0.0)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt::builder("rx-priority")
+                    .nick("Received Priority")
+                    .blurb("Priority of the last sample received on any pad (1=RealTime to 7=Background, 0 before any sample)")
+                    .maximum(7)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecBoolean::builder("rx-express")
+                    .nick("Received Express")
+                    .blurb("Whether the last sample received on any pad was published with express (false before any sample)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecString::builder("stats-json")
+                    .nick("Statistics JSON")
+                    .blurb("All statistics above as one compact JSON object keyed by property name, read atomically (for metrics exporters)")
+                    .read_only()
+                    .build(),
+                // Decompression statistics (conditional on features)
+                #[cfg(any(
+                    feature = "compression-zstd",
+                    feature = "compression-lz4",
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
+                ))]
+                glib::ParamSpecUInt64::builder("bytes-before-decompression")
+                    .nick("Bytes Before Decompression")
+                    .blurb("Total compressed bytes received (compressed samples only)")
+                    .read_only()
+                    .build(),
+                #[cfg(any(
+                    feature = "compression-zstd",
+                    feature = "compression-lz4",
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
+                ))]
+                glib::ParamSpecUInt64::builder("bytes-after-decompression")
+                    .nick("Bytes After Decompression")
+                    .blurb("Total bytes those compressed samples expanded to")
+                    .read_only()
+                    .build(),
             ]
         });
 
@@ -272,17 +983,103 @@ impl ObjectImpl for ZenohDemux {
                     .get::<Option<String>>()
                     .expect("type checked upstream");
             }
+            "config-json5" => {
+                settings.config_json5 = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "scouting" => {
+                settings.scouting = value.get::<bool>().expect("type checked upstream");
+            }
+            "endpoints-only" => {
+                settings.endpoints_only = value.get::<bool>().expect("type checked upstream");
+            }
+            "tls-root-ca" => {
+                settings.tls_root_ca = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "tls-client-cert" => {
+                settings.tls_client_cert = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "tls-client-key" => {
+                settings.tls_client_key = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "username" => {
+                settings.username = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "password" => {
+                settings.password = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
             "pad-naming" => {
                 settings.pad_naming = value.get::<PadNaming>().expect("type checked upstream");
             }
+            "hash-bits" => {
+                settings.hash_bits = value.get::<u32>().expect("type checked upstream");
+            }
+            "strip-prefix" => {
+                settings.strip_prefix = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream")
+                    .unwrap_or_default();
+            }
+            "pad-name-map" => {
+                settings.pad_name_map = value
+                    .get::<Option<gst::Structure>>()
+                    .expect("type checked upstream");
+            }
+            "single-pad" => {
+                settings.single_pad = value.get::<bool>().expect("type checked upstream");
+            }
             "receive-timeout-ms" => {
                 settings.receive_timeout_ms = value.get::<u64>().expect("type checked upstream");
             }
+            "apply-buffer-meta" => {
+                settings.apply_buffer_meta = value.get::<bool>().expect("type checked upstream");
+            }
+            "max-buffer-size" => {
+                settings.max_buffer_size = value.get::<u64>().expect("type checked upstream");
+            }
+            "per-pad-queue-size" => {
+                settings.per_pad_queue_size = value.get::<u32>().expect("type checked upstream");
+            }
+            "max-pads" => {
+                settings.max_pads = value.get::<u32>().expect("type checked upstream");
+            }
+            "no-data-timeout-ms" => {
+                settings.no_data_timeout_ms = value.get::<u64>().expect("type checked upstream");
+            }
+            "connectivity-poll-ms" => {
+                settings.connectivity_poll_ms = value.get::<u32>().expect("type checked upstream");
+            }
+            #[cfg(feature = "metrics-http")]
+            "metrics-port" => {
+                settings.metrics_port = value.get::<u32>().expect("type checked upstream");
+            }
+            #[cfg(feature = "metrics-http")]
+            "metrics-address" => {
+                settings.metrics_address = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream")
+                    .unwrap_or_else(|| crate::metrics::DEFAULT_METRICS_ADDRESS.into());
+            }
             "session-group" => {
                 settings.session_group = value
                     .get::<Option<String>>()
                     .expect("type checked upstream");
             }
+            "session-group-linger-ms" => {
+                settings.session_group_linger_ms =
+                    value.get::<u32>().expect("type checked upstream");
+            }
             name => {
                 gst::warning!(CAT, imp = self, "Unknown property: {}", name);
             }
@@ -293,9 +1090,57 @@ impl ObjectImpl for ZenohDemux {
         match pspec.name() {
             "key-expr" => self.settings.lock().unwrap().key_expr.to_value(),
             "config" => self.settings.lock().unwrap().config_file.to_value(),
+            "config-json5" => self.settings.lock().unwrap().config_json5.to_value(),
+            "scouting" => self.settings.lock().unwrap().scouting.to_value(),
+            "endpoints-only" => self.settings.lock().unwrap().endpoints_only.to_value(),
+            "tls-root-ca" => self.settings.lock().unwrap().tls_root_ca.to_value(),
+            "tls-client-cert" => self.settings.lock().unwrap().tls_client_cert.to_value(),
+            "tls-client-key" => self.settings.lock().unwrap().tls_client_key.to_value(),
+            "username" => self.settings.lock().unwrap().username.to_value(),
             "pad-naming" => self.settings.lock().unwrap().pad_naming.to_value(),
+            "hash-bits" => self.settings.lock().unwrap().hash_bits.to_value(),
+            "strip-prefix" => self.settings.lock().unwrap().strip_prefix.to_value(),
+            "pad-name-map" => self.settings.lock().unwrap().pad_name_map.to_value(),
+            "single-pad" => self.settings.lock().unwrap().single_pad.to_value(),
             "receive-timeout-ms" => self.settings.lock().unwrap().receive_timeout_ms.to_value(),
+            "apply-buffer-meta" => self.settings.lock().unwrap().apply_buffer_meta.to_value(),
+            "max-buffer-size" => self.settings.lock().unwrap().max_buffer_size.to_value(),
+            "per-pad-queue-size" => self.settings.lock().unwrap().per_pad_queue_size.to_value(),
+            "max-pads" => self.settings.lock().unwrap().max_pads.to_value(),
+            "no-data-timeout-ms" => self.settings.lock().unwrap().no_data_timeout_ms.to_value(),
+            "connectivity-poll-ms" => self.settings.lock().unwrap().connectivity_poll_ms.to_value(),
+            #[cfg(feature = "metrics-http")]
+            "metrics-port" => self.settings.lock().unwrap().metrics_port.to_value(),
+            #[cfg(feature = "metrics-http")]
+            "metrics-address" => self.settings.lock().unwrap().metrics_address.to_value(),
             "session-group" => self.settings.lock().unwrap().session_group.to_value(),
+            "session-group-linger-ms" => {
+                self.settings.lock().unwrap().session_group_linger_ms.to_value()
+            }
+            "session-zid" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.session.zid().to_string().to_value()
+                } else {
+                    String::new().to_value()
+                }
+            }
+            "session-shared" => {
+                let state = self.state.lock().unwrap();
+                let shared = match *state {
+                    State::Started(ref started) => started.group_session.is_some(),
+                    _ => false,
+                };
+                shared.to_value()
+            }
+            "peers" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    crate::session::connected_peers(&started.session).to_value()
+                } else {
+                    Vec::<String>::new().to_value()
+                }
+            }
             "bytes-received" => {
                 let state = self.state.lock().unwrap();
                 if let State::Started(ref started) = *state {
@@ -312,6 +1157,14 @@ impl ObjectImpl for ZenohDemux {
                     0u64.to_value()
                 }
             }
+            "errors" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().errors.to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
             "pads-created" => {
                 let state = self.state.lock().unwrap();
                 if let State::Started(ref started) = *state {
@@ -320,6 +1173,134 @@ impl ObjectImpl for ZenohDemux {
                     0u64.to_value()
                 }
             }
+            "pads-rejected" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().pads_rejected.to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "dropped-queue-full" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().dropped_queue_full.to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "attachment-bytes-received" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().attachment_bytes_received.to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "metadata-errors" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().metadata_errors.to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "samples-lost" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().sequence.lost().to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "samples-reordered" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().sequence.reordered().to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "bitrate" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().rate.bitrate().to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "message-rate" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().rate.message_rate().to_value()
+                } else {
+                    0f64.to_value()
+                }
+            }
+            "rx-priority" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().rx_qos.priority().to_value()
+                } else {
+                    0u32.to_value()
+                }
+            }
+            "rx-express" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().rx_qos.express().to_value()
+                } else {
+                    false.to_value()
+                }
+            }
+            "stats-json" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().to_json().to_value()
+                } else {
+                    Statistics::default().to_json().to_value()
+                }
+            }
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            "bytes-before-decompression" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started
+                        .stats
+                        .lock()
+                        .unwrap()
+                        .bytes_before_decompression
+                        .to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            "bytes-after-decompression" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started
+                        .stats
+                        .lock()
+                        .unwrap()
+                        .bytes_after_decompression
+                        .to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
             name => {
                 gst::warning!(CAT, imp = self, "Unknown property: {}", name);
                 "".to_value()
@@ -336,6 +1317,42 @@ impl ObjectSubclass for ZenohDemux {
 }
 
 impl ZenohDemux {
+    /// Logs the state, settings and statistics at INFO (`dump-state` signal).
+    fn dump_state(&self) {
+        let state = self.state.lock().unwrap().name();
+        crate::utils::dump_state(self.obj().upcast_ref(), *CAT, state);
+    }
+
+    /// Returns a `stream` structure per pad, sorted by pad name (`get-streams` signal).
+    fn streams(&self) -> gst::Array {
+        let pads = match *self.state.lock().unwrap() {
+            State::Started(ref started) => started.pads.clone(),
+            State::Stopped => return gst::Array::new(std::iter::empty::<gst::Structure>()),
+        };
+        let pads = pads.lock().unwrap();
+        let mut names: Vec<&String> = pads.keys().collect();
+        names.sort();
+
+        gst::Array::new(names.into_iter().map(|name| {
+            let demux_pad = &pads[name];
+            let stats = demux_pad.stats.lock().unwrap();
+            gst::Structure::builder("stream")
+                .field("pad-name", name.as_str())
+                .field("key-expr", &*demux_pad.key_expr)
+                .field("bytes-received", stats.bytes_received)
+                .field("messages-received", stats.messages_received)
+                .build()
+        }))
+    }
+
+    /// Returns the Zenoh session in use, while started.
+    pub(crate) fn session(&self) -> Option<zenoh::Session> {
+        match *self.state.lock().unwrap() {
+            State::Started(ref started) => Some(started.session.clone()),
+            _ => None,
+        }
+    }
+
     fn start(&self) -> Result<(), gst::ErrorMessage> {
         let mut state = self.state.lock().unwrap();
         if matches!(*state, State::Started(_)) {
@@ -344,32 +1361,67 @@ impl ZenohDemux {
 
         let settings = self.settings.lock().unwrap();
         let key_expr = settings.key_expr.clone();
-        let config_file = settings.config_file.clone();
-        let pad_naming = settings.pad_naming;
-        let receive_timeout_ms = settings.receive_timeout_ms;
+        let session_config = SessionConfig {
+            config_file: settings.config_file.clone(),
+            config_json5: settings.config_json5.clone(),
+            scouting: settings.scouting,
+            endpoints_only: settings.endpoints_only,
+            tls_root_ca: settings.tls_root_ca.clone(),
+            tls_client_cert: settings.tls_client_cert.clone(),
+            tls_client_key: settings.tls_client_key.clone(),
+            username: settings.username.clone(),
+            password: settings.password.clone(),
+        };
         let session_group = settings.session_group.clone();
+        let session_group_linger = Duration::from_millis(settings.session_group_linger_ms.into());
+        let pad_name_map = match settings.pad_name_map {
+            Some(ref map) => parse_pad_name_map(map).map_err(|e| {
+                gst::error_msg!(
+                    gst::ResourceError::Settings,
+                    ["Invalid pad-name-map: {}", e]
+                )
+            })?,
+            None => HashMap::new(),
+        };
+        let receiver_config = ReceiverConfig {
+            pad_naming: settings.pad_naming,
+            hash_bits: settings.hash_bits,
+            strip_prefix: settings.strip_prefix.clone(),
+            pad_name_map,
+            single_pad: settings.single_pad,
+            receive_timeout_ms: settings.receive_timeout_ms,
+            max_buffer_size: settings.max_buffer_size,
+            apply_buffer_meta: settings.apply_buffer_meta,
+            no_data_timeout_ms: settings.no_data_timeout_ms,
+            per_pad_queue_size: settings.per_pad_queue_size,
+            max_pads: settings.max_pads,
+        };
+        let connectivity_poll_ms = settings.connectivity_poll_ms;
+        #[cfg(feature = "metrics-http")]
+        let (metrics_address, metrics_port) =
+            (settings.metrics_address.clone(), settings.metrics_port);
         drop(settings);
 
         // Determine session source: session-group (property) > new session
-        let session = if let Some(ref group) = session_group {
+        let (session, group_session) = if let Some(ref group) = session_group {
             // Use session group (gst-launch compatible)
             gst::debug!(CAT, imp = self, "Using session group '{}'", group);
-            crate::session::get_or_create_session(group, config_file.as_deref())
-                .map_err(|e| ZenohError::Init(e).to_error_message())?
+            let group_session =
+                crate::session::get_or_create_session(group, &session_config, session_group_linger)
+                    .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            (group_session.session().clone(), Some(group_session))
         } else {
             // Create a new session
             gst::debug!(CAT, imp = self, "Creating new Zenoh session");
-            let config = match config_file {
-                Some(path) if !path.is_empty() => {
-                    gst::debug!(CAT, imp = self, "Loading Zenoh config from {}", path);
-                    zenoh::Config::from_file(&path)
-                        .map_err(|e| ZenohError::Init(e).to_error_message())?
-                }
-                _ => zenoh::Config::default(),
-            };
-            zenoh::open(config)
-                .wait()
-                .map_err(|e| ZenohError::Init(e).to_error_message())?
+            if let Some(ref path) = session_config.config_file
+                && !path.is_empty()
+            {
+                gst::debug!(CAT, imp = self, "Loading Zenoh config from {}", path);
+            }
+            let session = session_config
+                .open()
+                .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            (session, None)
         };
 
         gst::debug!(
@@ -391,9 +1443,40 @@ impl ZenohDemux {
             .wait()
             .map_err(|e| ZenohError::Init(e).to_error_message())?;
 
+        // Tell the zenohsinks using compression-negotiate which algorithms can
+        // be decompressed here. Without the tokens they just send uncompressed.
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        let compression_tokens = crate::compression::advertise(&session, &key_expr)
+            .unwrap_or_else(|e| {
+                gst::debug!(CAT, imp = self, "Failed to advertise compression: {}", e);
+                Vec::new()
+            });
+
         let stopping = Arc::new(AtomicBool::new(false));
         let stats = Arc::new(Mutex::new(Statistics::default()));
-        let pads: Arc<Mutex<HashMap<String, gst::Pad>>> = Arc::new(Mutex::new(HashMap::new()));
+        let pads: Arc<Mutex<HashMap<String, DemuxPad>>> = Arc::new(Mutex::new(HashMap::new()));
+
+        // The single pad exists before any data, so it can be linked up front.
+        // Added without the state lock, as pad-added handlers may read properties.
+        if receiver_config.single_pad {
+            drop(state);
+            let demux_pad = Self::add_src_pad(
+                &self.obj(),
+                "src",
+                SINGLE_PAD_NAME,
+                &key_expr,
+                &stats,
+                receiver_config.per_pad_queue_size,
+            );
+            pads.lock().unwrap().insert(SINGLE_PAD_NAME.to_string(), demux_pad);
+            state = self.state.lock().unwrap();
+        }
 
         // Clone for the receiver thread
         let stopping_clone = stopping.clone();
@@ -409,18 +1492,40 @@ impl ZenohDemux {
                 stopping_clone,
                 stats_clone,
                 pads_clone,
-                pad_naming,
-                receive_timeout_ms,
+                receiver_config,
             );
         });
 
+        #[cfg(feature = "metrics-http")]
+        let metrics = {
+            let stats = stats.clone();
+            crate::metrics::serve(self.obj().upcast_ref(), &metrics_address, metrics_port, move || {
+                stats.lock().unwrap().to_json()
+            })
+        };
+
+        let connectivity =
+            ConnectivityMonitor::start(&session, self.obj().upcast_ref(), connectivity_poll_ms);
+
         *state = State::Started(Started {
-            _session: session,
+            session,
             _subscriber: subscriber_for_state,
             stopping,
             stats,
             pads,
             thread_handle: Some(thread_handle),
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            _compression_tokens: compression_tokens,
+            _connectivity: connectivity,
+            #[cfg(feature = "metrics-http")]
+            _metrics: metrics,
+            group_session,
         });
 
         gst::debug!(CAT, imp = self, "ZenohDemux started successfully");
@@ -429,6 +1534,7 @@ impl ZenohDemux {
 
     fn stop(&self) {
         let mut state = self.state.lock().unwrap();
+        let mut pads = Vec::new();
         if let State::Started(ref mut started) = *state {
             gst::debug!(CAT, imp = self, "Stopping ZenohDemux");
 
@@ -442,19 +1548,92 @@ impl ZenohDemux {
                 state = self.state.lock().unwrap();
             }
 
-            // Remove all dynamic pads
             if let State::Started(ref started) = *state {
-                let pads = started.pads.lock().unwrap();
-                for (_, pad) in pads.iter() {
-                    let _ = self.obj().remove_pad(pad);
-                }
+                pads.extend(started.pads.lock().unwrap().drain().map(|(_, pad)| pad));
             }
         }
 
         *state = State::Stopped;
+        drop(state);
+
+        // End each stream with EOS before removing its pad, so downstream
+        // branches (queues, sinks) terminate cleanly instead of waiting for
+        // data that will never come. Done without locks, as EOS may block.
+        for demux_pad in pads {
+            demux_pad.stop();
+            if !demux_pad.pad.push_event(gst::event::Eos::new()) {
+                gst::debug!(
+                    CAT,
+                    imp = self,
+                    "EOS not accepted downstream of pad {}",
+                    demux_pad.pad.name()
+                );
+            }
+            if let Err(e) = demux_pad.pad.set_active(false) {
+                gst::warning!(
+                    CAT,
+                    imp = self,
+                    "Failed to deactivate pad {}: {}",
+                    demux_pad.pad.name(),
+                    e
+                );
+            }
+            let _ = self.obj().remove_pad(&demux_pad.pad);
+        }
+
         gst::debug!(CAT, imp = self, "ZenohDemux stopped");
     }
 
+    /// Creates, activates and adds a source pad from template `templ`, sending
+    /// the stream-start and segment events that must precede any data.
+    fn add_src_pad(
+        element: &super::ZenohDemux,
+        templ: &str,
+        pad_name: &str,
+        key_expr: &str,
+        stats: &Mutex<Statistics>,
+        per_pad_queue_size: u32,
+    ) -> DemuxPad {
+        gst::debug!(CAT, "Creating new pad '{}' for key expression '{}'", pad_name, key_expr);
+
+        let templ = element.pad_template(templ).unwrap();
+        let pad = gst::Pad::builder_from_template(&templ)
+            .name(pad_name)
+            .build();
+
+        // Activate the pad
+        pad.set_active(true).unwrap();
+
+        // Add pad to element
+        element.add_pad(&pad).unwrap();
+
+        // Send stream-start event (required before any data)
+        let stream_id = format!("zenohdemux/{}/{}", pad_name, key_expr);
+        pad.push_event(gst::event::StreamStart::new(&stream_id));
+
+        // Send segment event (required before any data)
+        let segment = gst::FormattedSegment::<gst::ClockTime>::new();
+        pad.push_event(gst::event::Segment::new(&segment));
+
+        // Update statistics
+        stats.lock().unwrap().pads_created += 1;
+
+        let queue_size = per_pad_queue_size as usize;
+        if queue_size > 0 {
+            DemuxPad::with_queue(pad.clone(), key_expr, queue_size).unwrap_or_else(|e| {
+                gst::warning!(
+                    CAT,
+                    "Failed to start task of pad {}, pushing directly: {}",
+                    pad_name,
+                    e
+                );
+                DemuxPad::new(pad, key_expr)
+            })
+        } else {
+            DemuxPad::new(pad, key_expr)
+        }
+    }
+
     fn receiver_loop(
         element: super::ZenohDemux,
         subscriber: zenoh::pubsub::Subscriber<
@@ -462,60 +1641,123 @@ impl ZenohDemux {
         >,
         stopping: Arc<AtomicBool>,
         stats: Arc<Mutex<Statistics>>,
-        pads: Arc<Mutex<HashMap<String, gst::Pad>>>,
-        pad_naming: PadNaming,
-        receive_timeout_ms: u64,
+        pads: Arc<Mutex<HashMap<String, DemuxPad>>>,
+        config: ReceiverConfig,
     ) {
         gst::debug!(CAT, "Receiver loop started");
+        let no_data_timeout = Duration::from_millis(config.no_data_timeout_ms);
+        let receive_timeout = Duration::from_millis(config.receive_timeout_ms);
+        let poll_interval = receive_timeout.min(MAX_POLL_INTERVAL);
+        let mut idle = IdleTracker::default();
+        // Warn once per start about keys strip-prefix doesn't apply to
+        let mut warned_prefix_mismatch = false;
+        // Whether max-pads was hit, warned about once per start. Rejected samples are
+        // counted rather than their keys remembered, whose number the sender controls.
+        let mut max_pads_reached = false;
+        // Pads caps were pushed on, which keep the first caps they got
+        let mut pads_with_caps: HashSet<String> = HashSet::new();
+        // Tags last pushed on each pad, so that repeated tags aren't pushed again
+        let mut pad_tags: HashMap<String, gst::TagList> = HashMap::new();
+        // Likewise for the publishers' stream identities, and their groups mapped to local ones
+        let mut pad_identities: HashMap<String, StreamIdentity> = HashMap::new();
+        let mut stream_groups = StreamGroups::default();
+        let mut hash_pad_names = HashPadNames::default();
 
         while !stopping.load(Ordering::SeqCst) {
             // Use recv_timeout to remain responsive to stopping signal
-            match subscriber.recv_timeout(Duration::from_millis(receive_timeout_ms)) {
+            match subscriber.recv_timeout(poll_interval) {
                 Ok(Some(sample)) => {
+                    if let Some(silence) = idle.record_data() {
+                        Self::post_idle_message(
+                            &element,
+                            &subscriber,
+                            crate::stats::STREAM_RESUMED_MESSAGE,
+                            silence,
+                        );
+                    }
+
                     // Get the key expression this sample arrived on
                     let sample_key_expr = sample.key_expr().as_str().to_string();
-                    let pad_name = key_expr_to_pad_name(&sample_key_expr, pad_naming);
-
-                    // Get or create the pad for this key expression
-                    let pad = {
-                        let mut pads_guard = pads.lock().unwrap();
-                        if let Some(pad) = pads_guard.get(&pad_name) {
-                            pad.clone()
-                        } else {
-                            // Create a new pad
-                            gst::debug!(
-                                CAT,
-                                "Creating new pad '{}' for key expression '{}'",
-                                pad_name,
-                                sample_key_expr
-                            );
-
-                            let templ = element.pad_template("src_%s").unwrap();
-                            let pad = gst::Pad::builder_from_template(&templ)
-                                .name(pad_name.as_str())
-                                .build();
-
-                            // Activate the pad
-                            pad.set_active(true).unwrap();
-
-                            // Add pad to element
-                            element.add_pad(&pad).unwrap();
 
-                            // Send stream-start event (required before any data)
-                            let stream_id = format!("zenohdemux/{}/{}", pad_name, sample_key_expr);
-                            pad.push_event(gst::event::StreamStart::new(&stream_id));
-
-                            // Send segment event (required before any data)
-                            let segment = gst::FormattedSegment::<gst::ClockTime>::new();
-                            pad.push_event(gst::event::Segment::new(&segment));
+                    // Refuse oversized samples before creating a pad or allocating
+                    let size = sample.payload().len() as u64;
+                    if config.max_buffer_size > 0 && size > config.max_buffer_size {
+                        stats.lock().unwrap().errors += 1;
+                        gst::warning!(
+                            CAT,
+                            "Dropping {} byte sample on '{}': exceeds max-buffer-size ({})",
+                            size,
+                            sample_key_expr,
+                            config.max_buffer_size
+                        );
+                        continue;
+                    }
 
-                            // Update statistics
-                            stats.lock().unwrap().pads_created += 1;
+                    let pad_name = if config.single_pad {
+                        SINGLE_PAD_NAME.to_string()
+                    } else if let Some(name) = config.pad_name_map.get(&sample_key_expr) {
+                        name.clone()
+                    } else {
+                        let pad_key =
+                            match strip_key_prefix(&sample_key_expr, &config.strip_prefix) {
+                                Some(stripped) => stripped,
+                                None => {
+                                    if !warned_prefix_mismatch {
+                                        warned_prefix_mismatch = true;
+                                        gst::warning!(
+                                            CAT,
+                                            "strip-prefix '{}' does not match '{}', using the full key",
+                                            config.strip_prefix,
+                                            sample_key_expr
+                                        );
+                                    }
+                                    &sample_key_expr
+                                }
+                            };
+                        match config.pad_naming {
+                            PadNaming::Hash => hash_pad_names.name(pad_key, config.hash_bits),
+                            naming => key_expr_to_pad_name(pad_key, naming, config.hash_bits),
+                        }
+                    };
 
-                            pads_guard.insert(pad_name, pad.clone());
-                            pad
+                    // Get or create the pad for this key expression. The pad is
+                    // added without the pads lock, so that pad-added handlers
+                    // can call get-streams.
+                    let (existing, pad_count) = {
+                        let pads = pads.lock().unwrap();
+                        (pads.get(&pad_name).cloned(), pads.len())
+                    };
+                    let demux_pad = match existing {
+                        Some(demux_pad) => demux_pad,
+                        None if config.max_pads > 0 && pad_count >= config.max_pads as usize => {
+                            if !std::mem::replace(&mut max_pads_reached, true) {
+                                gst::element_warning!(
+                                    element,
+                                    gst::ResourceError::NoSpaceLeft,
+                                    [
+                                        "max-pads ({}) reached, ignoring new keys like '{}'",
+                                        config.max_pads,
+                                        sample_key_expr
+                                    ]
+                                );
+                            }
+                            stats.lock().unwrap().pads_rejected += 1;
+                            continue;
+                        }
+                        None => {
+                            let demux_pad = Self::add_src_pad(
+                                &element,
+                                "src_%s",
+                                &pad_name,
+                                &sample_key_expr,
+                                &stats,
+                                config.per_pad_queue_size,
+                            );
+                            pads.lock().unwrap().insert(pad_name, demux_pad.clone());
+                            demux_pad
                         }
                     };
+                    let pad = &demux_pad.pad;
 
                     // Process the sample and create a buffer
                     let payload = sample.payload();
@@ -525,7 +1767,9 @@ impl ZenohDemux {
                     #[cfg(any(
                         feature = "compression-zstd",
                         feature = "compression-lz4",
-                        feature = "compression-gzip"
+                        feature = "compression-gzip",
+                        feature = "compression-brotli",
+                        feature = "compression-snappy"
                     ))]
                     let (final_data, metadata) = if let Some(attachment) = sample.attachment() {
                         match MetadataParser::parse(attachment) {
@@ -539,11 +1783,28 @@ impl ZenohDemux {
                                             comp_str,
                                         )
                                     {
-                                        match crate::compression::decompress(&data, comp_type) {
-                                            Ok(decompressed) => (decompressed, Some(meta)),
+                                        match crate::compression::decompress_with_limit(
+                                            &data,
+                                            comp_type,
+                                            config.max_buffer_size,
+                                        ) {
+                                            Ok(decompressed) => {
+                                                let mut stats_guard = stats.lock().unwrap();
+                                                stats_guard.bytes_before_decompression +=
+                                                    data.len() as u64;
+                                                stats_guard.bytes_after_decompression +=
+                                                    decompressed.len() as u64;
+                                                drop(stats_guard);
+                                                (decompressed, Some(meta))
+                                            }
                                             Err(e) => {
                                                 gst::warning!(CAT, "Decompression failed: {}", e);
                                                 stats.lock().unwrap().errors += 1;
+                                                post_error_message(
+                                                    element.upcast_ref(),
+                                                    &sample_key_expr,
+                                                    &format_args!("Decompression failed: {}", e),
+                                                );
                                                 continue;
                                             }
                                         }
@@ -556,6 +1817,7 @@ impl ZenohDemux {
                             }
                             Err(e) => {
                                 gst::warning!(CAT, "Failed to parse metadata: {}", e);
+                                stats.lock().unwrap().metadata_errors += 1;
                                 (data.to_vec(), None)
                             }
                         }
@@ -566,13 +1828,16 @@ impl ZenohDemux {
                     #[cfg(not(any(
                         feature = "compression-zstd",
                         feature = "compression-lz4",
-                        feature = "compression-gzip"
+                        feature = "compression-gzip",
+                        feature = "compression-brotli",
+                        feature = "compression-snappy"
                     )))]
                     let (final_data, metadata) = if let Some(attachment) = sample.attachment() {
                         match MetadataParser::parse(attachment) {
                             Ok(meta) => (data.to_vec(), Some(meta)),
                             Err(e) => {
                                 gst::warning!(CAT, "Failed to parse metadata: {}", e);
+                                stats.lock().unwrap().metadata_errors += 1;
                                 (data.to_vec(), None)
                             }
                         }
@@ -580,13 +1845,47 @@ impl ZenohDemux {
                         (data.to_vec(), None)
                     };
 
-                    // Create buffer
-                    let mut buffer = match gst::Buffer::with_size(final_data.len()) {
-                        Ok(buf) => buf,
-                        Err(_) => {
-                            gst::warning!(CAT, "Failed to allocate buffer");
-                            stats.lock().unwrap().errors += 1;
-                            continue;
+                    if let Some(e) = metadata.as_ref().and_then(|m| m.caps_error()) {
+                        // The pad is used as is, keeping the caps it has
+                        gst::warning!(
+                            CAT,
+                            "Ignoring malformed caps metadata on '{}': {}",
+                            sample_key_expr,
+                            e
+                        );
+                        stats.lock().unwrap().metadata_errors += 1;
+                    }
+                    if let Some(seqnum) = metadata.as_ref().and_then(|m| m.seqnum()) {
+                        stats.lock().unwrap().sequence.record(&sample_key_expr, seqnum);
+                    }
+
+                    // Data compressed with an algorithm this build can't decode must
+                    // not be pushed downstream as if it were raw
+                    if let Some(algorithm) = metadata
+                        .as_ref()
+                        .and_then(crate::metadata::unsupported_compression)
+                    {
+                        stats.lock().unwrap().errors += 1;
+                        let err = ZenohError::UnsupportedCompression {
+                            algorithm: algorithm.to_string(),
+                        };
+                        gst::warning!(CAT, "{} (key '{}')", err, sample_key_expr);
+                        element.post_error_message(err.to_error_message());
+                        continue;
+                    }
+
+                    // Create buffer. Empty samples (DELETEs, gaps) get one
+                    // without memory, with nothing to copy into it.
+                    let mut buffer = if final_data.is_empty() {
+                        gst::Buffer::new()
+                    } else {
+                        match gst::Buffer::with_size(final_data.len()) {
+                            Ok(buf) => buf,
+                            Err(_) => {
+                                gst::warning!(CAT, "Failed to allocate buffer");
+                                stats.lock().unwrap().errors += 1;
+                                continue;
+                            }
                         }
                     };
 
@@ -600,61 +1899,149 @@ impl ZenohDemux {
                             }
                         };
 
-                        if buffer_ref.copy_from_slice(0, &final_data).is_err() {
+                        if !final_data.is_empty()
+                            && buffer_ref.copy_from_slice(0, &final_data).is_err()
+                        {
                             gst::warning!(CAT, "Failed to copy data to buffer");
                             stats.lock().unwrap().errors += 1;
                             continue;
                         }
 
+                        // Record the exact source key, which the pad name may not preserve
+                        if let Err(e) = ZenohKeyMeta::add(buffer_ref, &sample_key_expr) {
+                            gst::warning!(CAT, "Failed to attach key expression meta: {}", e);
+                        }
+
                         // Apply metadata to buffer
                         if let Some(ref meta) = metadata {
-                            meta.apply_to_buffer(buffer_ref);
-
-                            // Set caps if present
-                            if let Some(caps) = meta.caps() {
-                                // We can't set caps on the buffer directly, but we can
-                                // set them on the pad if needed
-                                if !pad.has_current_caps() {
-                                    pad.push_event(gst::event::Caps::new(caps));
-                                }
+                            if config.apply_buffer_meta {
+                                meta.apply_to_buffer(buffer_ref);
+                            }
+
+                            // Keep application-defined fields for downstream consumers
+                            if let Err(e) = ZenohUserMeta::add(buffer_ref, meta) {
+                                gst::warning!(CAT, "Failed to attach user metadata meta: {}", e);
+                            }
+
+                            // The stream-start goes first: a new stream drops the sticky tags
+                            if let Some(identity) = meta.stream_identity()
+                                && pad_identities.get(pad.name().as_str()) != Some(&identity)
+                            {
+                                demux_pad.push_event(stream_groups.stream_start(&identity));
+                                pad_identities.insert(pad.name().to_string(), identity);
+                                pad_tags.remove(pad.name().as_str());
+                            }
+
+                            // Set caps if present. We can't set caps on the buffer
+                            // directly, but we can set them on the pad if needed. With
+                            // a queue they reach the pad later, so the pads they were
+                            // sent on are remembered.
+                            if let Some(caps) = meta.caps()
+                                && !pad.has_current_caps()
+                                && pads_with_caps.insert(pad.name().to_string())
+                            {
+                                demux_pad.push_event(gst::event::Caps::new(caps));
+                            }
+
+                            if let Some(tags) = meta.tags()
+                                && pad_tags.get(pad.name().as_str()) != Some(tags)
+                            {
+                                pad_tags.insert(pad.name().to_string(), tags.clone());
+                                demux_pad.push_event(gst::event::Tag::new(tags.clone()));
                             }
                         }
                     }
 
+                    // A batch from batch-lists or aggregate-window-ms is pushed as the
+                    // buffers it was made of
+                    let batch = metadata.as_ref().and_then(|m| {
+                        let timing = m.batch_timing().filter(|_| config.apply_buffer_meta);
+                        Some((m.batch()?, timing))
+                    });
+                    let buffers = match batch {
+                        Some((sizes, timing)) => {
+                            match crate::buffer::split_batch(&buffer, sizes, timing) {
+                                Some(list) => list.iter_owned().collect(),
+                                None => {
+                                    gst::warning!(
+                                        CAT,
+                                        "Batch sizes on '{}' don't match the {} byte payload, pushing it as one buffer",
+                                        sample_key_expr,
+                                        final_data.len()
+                                    );
+                                    stats.lock().unwrap().errors += 1;
+                                    vec![buffer]
+                                }
+                            }
+                        }
+                        None => vec![buffer],
+                    };
+
                     // Update statistics
                     {
                         let mut stats_guard = stats.lock().unwrap();
                         stats_guard.bytes_received += final_data.len() as u64;
-                        stats_guard.messages_received += 1;
+                        stats_guard.messages_received += buffers.len() as u64;
+                        stats_guard.attachment_bytes_received +=
+                            sample.attachment().map_or(0, |a| a.len() as u64);
+                        stats_guard.rate.record(final_data.len() as u64, buffers.len() as u64);
+                        stats_guard.rx_qos.record(&sample);
+                        drop(stats_guard);
+
+                        let mut pad_stats = demux_pad.stats.lock().unwrap();
+                        pad_stats.bytes_received += final_data.len() as u64;
+                        pad_stats.messages_received += buffers.len() as u64;
                     }
 
-                    // Push buffer to the pad
-                    match pad.push(buffer) {
-                        Ok(_) => {}
-                        Err(gst::FlowError::Flushing) => {
-                            gst::debug!(CAT, "Pad {} is flushing", pad.name());
-                        }
-                        Err(e) => {
-                            gst::warning!(
-                                CAT,
-                                "Failed to push buffer to pad {}: {:?}",
-                                pad.name(),
-                                e
-                            );
+                    // Push the buffers to the pad, or hand them to the pad's task
+                    for buffer in buffers {
+                        match demux_pad.queue {
+                            Some(ref queue) => {
+                                if queue.push(buffer) {
+                                    gst::debug!(
+                                        CAT,
+                                        "Queue of pad {} is full, dropped the oldest buffer",
+                                        pad.name()
+                                    );
+                                    stats.lock().unwrap().dropped_queue_full += 1;
+                                }
+                            }
+                            None => push_buffer(pad, buffer),
                         }
                     }
                 }
                 Ok(None) => {
-                    // Timeout - continue loop
+                    // Timeout - check for a silent stream and continue loop
+                    if let Some(silence) = idle.check(no_data_timeout) {
+                        Self::post_idle_message(
+                            &element,
+                            &subscriber,
+                            crate::stats::STREAM_IDLE_MESSAGE,
+                            silence,
+                        );
+                    }
                     continue;
                 }
                 Err(e) => {
                     let err_msg = format!("{:?}", e);
                     if err_msg.contains("Timeout") {
+                        if let Some(silence) = idle.check(no_data_timeout) {
+                            Self::post_idle_message(
+                                &element,
+                                &subscriber,
+                                crate::stats::STREAM_IDLE_MESSAGE,
+                                silence,
+                            );
+                        }
                         continue;
                     } else {
                         gst::warning!(CAT, "Subscriber error: {}", e);
                         stats.lock().unwrap().errors += 1;
+                        post_error_message(
+                            element.upcast_ref(),
+                            subscriber.key_expr().as_str(),
+                            &format_args!("Subscriber error: {}", e),
+                        );
                         break;
                     }
                 }
@@ -663,4 +2050,138 @@ impl ZenohDemux {
 
         gst::debug!(CAT, "Receiver loop finished");
     }
+
+    /// Posts a `zenoh-stream-idle` / `zenoh-stream-resumed` message for the
+    /// subscription as a whole (any of its keys delivering data counts).
+    fn post_idle_message(
+        element: &super::ZenohDemux,
+        subscriber: &zenoh::pubsub::Subscriber<
+            zenoh::handlers::FifoChannelHandler<zenoh::sample::Sample>,
+        >,
+        name: &str,
+        silence: Duration,
+    ) {
+        let key_expr = subscriber.key_expr().as_str();
+        gst::info!(CAT, "{} on '{}' after {:?} without data", name, key_expr, silence);
+        crate::stats::post_idle_message(element.upcast_ref(), name, key_expr, silence);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pad_queue_keeps_events_in_order() {
+        gst::init().unwrap();
+
+        let queue = PadQueue::new(2);
+        let buffer = |offset| {
+            let mut buffer = gst::Buffer::new();
+            buffer.get_mut().unwrap().set_offset(offset);
+            buffer
+        };
+        assert!(!queue.push(buffer(0)));
+        queue.push_event(gst::event::Tag::new(gst::TagList::new()));
+        assert!(!queue.push(buffer(1)));
+        // Full: the oldest buffer is dropped, the event is kept
+        assert!(queue.push(buffer(2)));
+
+        assert!(matches!(queue.pop(), Some(QueueItem::Event(_))));
+        for offset in [1, 2] {
+            match queue.pop() {
+                Some(QueueItem::Buffer(buffer)) => assert_eq!(buffer.offset(), offset),
+                _ => panic!("expected buffer {}", offset),
+            }
+        }
+
+        queue.set_flushing();
+        assert!(queue.pop().is_none());
+    }
+
+    #[test]
+    fn test_strip_key_prefix() {
+        let key = "factory/line3/camera/front";
+        assert_eq!(strip_key_prefix(key, ""), Some(key));
+        assert_eq!(strip_key_prefix(key, "factory/line3/"), Some("camera/front"));
+        assert_eq!(strip_key_prefix(key, "factory/line3"), Some("camera/front"));
+        // Must end on a segment boundary and leave something to name the pad after
+        assert_eq!(strip_key_prefix(key, "factory/line"), None);
+        assert_eq!(strip_key_prefix(key, "factory/line3/camera/front"), None);
+        assert_eq!(strip_key_prefix(key, "factory/line4/"), None);
+    }
+
+    #[test]
+    fn test_pad_naming_with_stripped_prefix() {
+        let stripped = strip_key_prefix("factory/line3/camera/front", "factory/line3/").unwrap();
+
+        let name = |key_expr, naming| key_expr_to_pad_name(key_expr, naming, DEFAULT_HASH_BITS);
+        assert_eq!(name(stripped, PadNaming::FullPath), "camera_front");
+        assert_eq!(name(stripped, PadNaming::LastSegment), "front");
+        assert_eq!(name(stripped, PadNaming::Hash), name("camera/front", PadNaming::Hash));
+        assert_ne!(
+            name(stripped, PadNaming::Hash),
+            name("factory/line3/camera/front", PadNaming::Hash)
+        );
+    }
+
+    #[test]
+    fn test_hash_pad_names_are_stable() {
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        for (key_expr, bits_24, bits_64) in [
+            ("camera/front", "pad_8108a0", "pad_f4979120a2102802"),
+            ("camera/rear", "pad_c57b55", "pad_d14832e824f79371"),
+            ("lidar/top", "pad_8efe07", "pad_e8f24e82dec07cd9"),
+        ] {
+            assert_eq!(hash_pad_name(key_expr, 24), bits_24);
+            assert_eq!(hash_pad_name(key_expr, 64), bits_64);
+        }
+        assert_eq!(hash_pad_name("camera/front", 8), "pad_2a");
+    }
+
+    #[test]
+    fn test_hash_pad_name_collisions() {
+        // More keys than 8-bit hashes: some collide and get a suffix
+        let mut names = HashPadNames::default();
+        let keys: Vec<String> = (0..300).map(|i| format!("sensor/{}", i)).collect();
+        let assigned: Vec<String> = keys.iter().map(|key| names.name(key, 8)).collect();
+
+        let unique: std::collections::HashSet<_> = assigned.iter().collect();
+        assert_eq!(unique.len(), keys.len());
+        assert!(assigned.iter().any(|name| name.len() > "pad_xx".len()));
+
+        // Names stay the same for keys seen before
+        for (key, name) in keys.iter().zip(&assigned) {
+            assert_eq!(&names.name(key, 8), name);
+        }
+    }
+
+    #[test]
+    fn test_parse_pad_name_map() {
+        let map = gst::Structure::builder("map")
+            .field("camera/front", "front_cam")
+            .field("camera/rear", "rear-cam")
+            .build();
+        let names = parse_pad_name_map(&map).unwrap();
+        assert_eq!(names.len(), 2);
+        assert_eq!(names["camera/front"], "front_cam");
+        assert_eq!(names["camera/rear"], "rear-cam");
+
+        for invalid in [
+            gst::Structure::builder("map").field("camera/front", "").build(),
+            gst::Structure::builder("map")
+                .field("camera/front", "front cam")
+                .build(),
+            gst::Structure::builder("map")
+                .field("camera/front", "demux.front")
+                .build(),
+            gst::Structure::builder("map").field("camera/front", 1i32).build(),
+            gst::Structure::builder("map")
+                .field("camera/front", "cam")
+                .field("camera/rear", "cam")
+                .build(),
+        ] {
+            assert!(parse_pad_name_map(&invalid).is_err(), "{} should be refused", invalid);
+        }
+    }
 }
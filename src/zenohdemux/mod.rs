@@ -14,7 +14,49 @@
 //!
 //! * `key-expr` - Zenoh key expression for subscribing (supports wildcards like `*` and `**`)
 //! * `config` - Path to Zenoh configuration file (optional)
+//! * `config-json5` - Inline Zenoh configuration in JSON5 (optional, overrides `config`)
+//! * `scouting` - Discover peers via multicast scouting (default: true)
+//!   - Disable on networks where multicast doesn't work; peers are then reached
+//!     through the endpoints of the configuration
+//! * `endpoints-only` - Connect only to the configured endpoints (default: false)
+//!   - Client mode without multicast scouting, for routers on other subnets;
+//!     starting fails when the configuration has no `connect/endpoints`
+//! * `tls-root-ca`, `tls-client-cert`, `tls-client-key` - TLS certificate files (optional)
+//!   - Override the matching `transport/link/tls` keys of the configuration file
+//! * `username`, `password` - Zenoh usrpwd authentication (optional, `password` is write-only)
 //! * `pad-naming` - How to name pads: "full-path", "last-segment", or "hash"
+//! * `hash-bits` - Width of the key hash in `hash` pad names (default: 24, 8-64)
+//!   - Keys whose hashes collide get a `_<n>` suffix (a warning is logged)
+//! * `strip-prefix` - Prefix removed from keys before pad naming (e.g. "factory/line3/")
+//!   - Keys that don't start with it keep their full name (a warning is logged)
+//! * `pad-name-map` - Pad names of given keys, e.g. "map, camera/front=front_cam"
+//!   - Takes precedence over `pad-naming` and `strip-prefix`; other keys are named as usual
+//!   - Names may only use letters, digits, `_` and `-`, once each; starting fails otherwise
+//! * `single-pad` - Push every key on one `src` pad instead of a pad per key (default: false)
+//! * `apply-buffer-meta` - Apply PTS, DTS, duration and flags from the sender (default: true)
+//!   - Disable to leave buffers untimed for downstream re-timestamping; caps are still applied
+//! * `max-buffer-size` - Drop samples larger than this many bytes (default: 0 = unlimited)
+//!   - Also caps the decompressed size of compressed samples
+//! * `per-pad-queue-size` - Buffers queued per pad (default: 0 = push from the receiver thread)
+//!   - Each pad gets its own streaming task, so a slow or blocked branch doesn't
+//!     stall the other pads; when its queue is full the oldest buffer is dropped
+//!     and counted in `dropped-queue-full`
+//! * `max-pads` - Dynamic pads created at most (default: 0 = unlimited)
+//!   - Samples of further keys are dropped and counted in `pads-rejected`;
+//!     a warning message is posted the first time
+//! * `no-data-timeout-ms` - Post `zenoh-stream-idle` when no data arrives for this long (0 = off)
+//!   - Tracks the subscription as a whole: data on any key keeps it alive
+//!   - `zenoh-stream-resumed` is posted when data arrives again
+//! * `connectivity-poll-ms` - Interval of the session connectivity check (default: 1000, 0 = off)
+//!   - Drives the `session-disconnected` and `session-reconnected` signals
+//! * `session-group-linger-ms` - Keep the `session-group` session open once released
+//!   (default: 0 = close right away)
+//!   - The session of a group is closed when its last element stops; with a linger,
+//!     elements briefly overlapping reuse it instead of reopening one
+//! * `metrics-port` - Serve the statistics to Prometheus on this port (default: 0 = off)
+//!   - Requires the `metrics-http` feature; scraped from `http://<host>:<port>/metrics`
+//! * `metrics-address` - Address the metrics endpoint listens on (default: 127.0.0.1)
+//!   - Only local scrapers reach the default; use 0.0.0.0 to serve every interface
 //!
 //! ## Example Pipeline
 //!
@@ -32,10 +74,34 @@
 //!
 //! - `full-path`: "camera/front" → "camera_front"
 //! - `last-segment`: "camera/front" → "front"
-//! - `hash`: "camera/front" → "pad_a1b2c3"
+//! - `hash`: "camera/front" → "pad_8108a0" (64-bit FNV-1a, folded to `hash-bits`)
+//!
+//! With `strip-prefix=factory/line3/`, "factory/line3/camera/front" is named as if it
+//! were "camera/front", e.g. "camera_front" in `full-path` mode.
+//!
+//! `pad-name-map` gives chosen names to some keys, whatever the strategy:
+//! with `pad-name-map="map, camera/front=front_cam"`, "camera/front" gets the
+//! pad "front_cam" and every other key a pad named by `pad-naming`.
+//!
+//! Since pad names can be lossy, each buffer also carries a
+//! [`ZenohKeyMeta`](crate::metadata::ZenohKeyMeta) with its exact source key.
+//! User metadata sent by the publisher (`user.*` / `user-bin.*` attachment
+//! entries) is kept in a [`ZenohUserMeta`](crate::metadata::ZenohUserMeta).
+//!
+//! ## Single Pad
+//!
+//! With `single-pad=true` no pad is created per key: the samples of every key
+//! are pushed on one `src` pad, added when the element starts, and downstream
+//! elements route them by their `ZenohKeyMeta`. The pad takes the caps of the
+//! first sample carrying caps, so the keys should share a format.
+//!
+//! ```bash
+//! gst-launch-1.0 zenohdemux key-expr="sensor/**" single-pad=true ! fakesink
+//! ```
 
 use gst::glib;
 use gst::prelude::*;
+use gst::subclass::prelude::ObjectSubclassIsExt;
 
 pub mod imp;
 
@@ -148,24 +214,171 @@ impl ZenohDemux {
         self.set_property("config", config_path);
     }
 
+    /// Sets the Zenoh configuration inline, as a JSON5 string.
+    ///
+    /// Takes precedence over [`set_config`](Self::set_config), which is handy
+    /// where mounting a configuration file is impractical (containers,
+    /// gst-launch). Scouting, TLS and authentication properties still apply
+    /// on top. Malformed JSON5 makes the start fail with the parse error.
+    pub fn set_config_json5(&self, json5: &str) {
+        self.set_property("config-json5", json5);
+    }
+
+    /// Enables or disables multicast scouting.
+    ///
+    /// Disable it on networks where multicast doesn't work, without having
+    /// to write a configuration file. Must be set before the element is started.
+    pub fn set_scouting(&self, enabled: bool) {
+        self.set_property("scouting", enabled);
+    }
+
+    /// Connects only to the endpoints of the configuration.
+    ///
+    /// Uses client mode with multicast scouting disabled, the usual setup to
+    /// reach a known router on another subnet. Starting fails when the
+    /// configuration has no `connect/endpoints`. Must be set before the
+    /// element is started.
+    pub fn set_endpoints_only(&self, enabled: bool) {
+        self.set_property("endpoints-only", enabled);
+    }
+
+    /// Sets the CA certificate used to verify TLS routers and peers.
+    ///
+    /// Overrides `transport/link/tls/root_ca_certificate` of the configuration file.
+    pub fn set_tls_root_ca(&self, path: &str) {
+        self.set_property("tls-root-ca", path);
+    }
+
+    /// Sets the client certificate presented on TLS links (mutual TLS).
+    ///
+    /// Must be set together with [`set_tls_client_key`](Self::set_tls_client_key).
+    pub fn set_tls_client_cert(&self, path: &str) {
+        self.set_property("tls-client-cert", path);
+    }
+
+    /// Sets the private key of the TLS client certificate.
+    pub fn set_tls_client_key(&self, path: &str) {
+        self.set_property("tls-client-key", path);
+    }
+
+    /// Sets the user name for usrpwd authentication.
+    ///
+    /// Must be set together with [`set_password`](Self::set_password).
+    pub fn set_username(&self, username: &str) {
+        self.set_property("username", username);
+    }
+
+    /// Sets the password for usrpwd authentication.
+    ///
+    /// The property is write-only and the password is never logged.
+    pub fn set_password(&self, password: &str) {
+        self.set_property("password", password);
+    }
+
     /// Sets how pad names are derived from key expressions.
     ///
     /// - [`PadNaming::FullPath`]: "camera/front" → "camera_front"
     /// - [`PadNaming::LastSegment`]: "camera/front" → "front"
-    /// - [`PadNaming::Hash`]: "camera/front" → "pad_a1b2c3"
+    /// - [`PadNaming::Hash`]: "camera/front" → "pad_8108a0"
     pub fn set_pad_naming(&self, naming: PadNaming) {
         self.set_property("pad-naming", naming);
     }
 
+    /// Sets the width in bits (8-64) of the key hash in [`PadNaming::Hash`] pad names.
+    ///
+    /// The hash is stable across builds and platforms. With many keys, raise
+    /// it to avoid collisions; keys whose hashes still collide get a `_<n>`
+    /// suffix in order of arrival. Takes effect on the next start.
+    pub fn set_hash_bits(&self, bits: u32) {
+        self.set_property("hash-bits", bits);
+    }
+
+    /// Sets a prefix removed from key expressions before pad names are derived.
+    ///
+    /// With `"factory/line3/"`, `factory/line3/camera/front` becomes
+    /// `camera_front` in [`PadNaming::FullPath`] mode. The prefix must end on a
+    /// segment boundary; keys that don't start with it keep their full name.
+    /// Takes effect on the next start.
+    pub fn set_strip_prefix(&self, prefix: &str) {
+        self.set_property("strip-prefix", prefix);
+    }
+
+    /// Sets the pad names of given key expressions.
+    ///
+    /// Each field maps a key expression, as received, to its pad name, e.g.
+    /// `camera/front=front_cam`; keys without a field are named by `pad-naming`.
+    /// Names may only use letters, digits, `_` and `-`, and must differ from
+    /// one another, otherwise starting fails. Takes effect on the next start.
+    pub fn set_pad_name_map(&self, map: &gst::Structure) {
+        self.set_property("pad-name-map", map);
+    }
+
+    /// Enables or disables pushing every key on one `src` pad.
+    ///
+    /// The pad is added on start, before any data; each buffer carries its
+    /// source key in a [`ZenohKeyMeta`](crate::metadata::ZenohKeyMeta).
+    /// `pad-naming` and `strip-prefix` don't apply. Takes effect on the next start.
+    pub fn set_single_pad(&self, single_pad: bool) {
+        self.set_property("single-pad", single_pad);
+    }
+
     /// Sets the receive timeout in milliseconds.
     ///
-    /// Lower values increase responsiveness but use more CPU.
-    /// Higher values reduce CPU usage but slow down state changes.
+    /// Lower values increase responsiveness but use more CPU. Polls are
+    /// capped at 100ms, so stopping never waits for a longer timeout.
     /// Valid range: 10-5000ms, default: 100ms.
     pub fn set_receive_timeout_ms(&self, timeout: u64) {
         self.set_property("receive-timeout-ms", timeout);
     }
 
+    /// Enables or disables applying buffer timing metadata from the sender.
+    ///
+    /// When disabled, PTS, DTS, duration and flags are left unset so that
+    /// buffers can be re-timestamped downstream. Caps sent by the publisher
+    /// are still used for pad negotiation.
+    pub fn set_apply_buffer_meta(&self, apply: bool) {
+        self.set_property("apply-buffer-meta", apply);
+    }
+
+    /// Sets the maximum accepted payload size in bytes (0 = unlimited).
+    ///
+    /// Larger samples are dropped before a pad or buffer is created for them
+    /// and counted as errors, as are compressed samples that decompress to more.
+    pub fn set_max_buffer_size(&self, size: u64) {
+        self.set_property("max-buffer-size", size);
+    }
+
+    /// Sets how many buffers are queued per pad (0 = push directly from the receiver thread).
+    ///
+    /// With a queue, each pad is pushed from its own task so a blocked branch
+    /// doesn't stall the others; it drops its oldest buffers instead. Takes effect
+    /// on the next start.
+    pub fn set_per_pad_queue_size(&self, size: u32) {
+        self.set_property("per-pad-queue-size", size);
+    }
+
+    /// Sets how many dynamic pads are created at most (0 = unlimited).
+    ///
+    /// Once reached, samples of keys without a pad are dropped, guarding
+    /// against broad wildcards fanning out to thousands of pads. Takes effect
+    /// on the next start.
+    pub fn set_max_pads(&self, max: u32) {
+        self.set_property("max-pads", max);
+    }
+
+    /// Sets how long without data before a `zenoh-stream-idle` message is
+    /// posted (0 = disabled). Takes effect on the next start.
+    pub fn set_no_data_timeout_ms(&self, timeout: u64) {
+        self.set_property("no-data-timeout-ms", timeout);
+    }
+
+    /// Sets how often the session is checked for router and peer connections,
+    /// for the `session-disconnected` and `session-reconnected` signals
+    /// (0 = disabled, at most 60000). Takes effect on the next start.
+    pub fn set_connectivity_poll_ms(&self, interval: u32) {
+        self.set_property("connectivity-poll-ms", interval);
+    }
+
     /// Sets the session group name for sharing sessions across elements.
     ///
     /// Elements with the same session-group name will share a single
@@ -177,6 +390,34 @@ impl ZenohDemux {
         self.set_property("session-group", group);
     }
 
+    /// Sets how long in milliseconds the session-group session stays open
+    /// after this element was the last one to release it (0 = close right away).
+    ///
+    /// An element joining the group meanwhile reuses the session instead of
+    /// opening a new one. Must be set before the element leaves NULL.
+    pub fn set_session_group_linger_ms(&self, linger: u32) {
+        self.set_property("session-group-linger-ms", linger);
+    }
+
+    /// Sets the port serving the statistics in Prometheus text format on
+    /// `/metrics` while the element is started (0 = disabled).
+    ///
+    /// Takes effect on the next start. If the port can't be bound, a warning
+    /// is posted and the element runs without the endpoint.
+    #[cfg(feature = "metrics-http")]
+    pub fn set_metrics_port(&self, port: u16) {
+        self.set_property("metrics-port", port as u32);
+    }
+
+    /// Sets the address the `metrics-port` endpoint listens on (default:
+    /// 127.0.0.1, reachable from the local host only).
+    ///
+    /// Takes effect on the next start. Use `0.0.0.0` to serve every interface.
+    #[cfg(feature = "metrics-http")]
+    pub fn set_metrics_address(&self, address: &str) {
+        self.set_property("metrics-address", address);
+    }
+
     // -------------------------------------------------------------------------
     // Property Getters
     // -------------------------------------------------------------------------
@@ -191,21 +432,200 @@ impl ZenohDemux {
         self.property("config")
     }
 
+    /// Returns the inline JSON5 configuration, if set.
+    pub fn config_json5(&self) -> Option<String> {
+        self.property("config-json5")
+    }
+
+    /// Returns whether multicast scouting is enabled.
+    pub fn scouting(&self) -> bool {
+        self.property("scouting")
+    }
+
+    /// Returns whether only the configured endpoints are used.
+    pub fn endpoints_only(&self) -> bool {
+        self.property("endpoints-only")
+    }
+
+    /// Returns the path to the TLS CA certificate, if set.
+    pub fn tls_root_ca(&self) -> Option<String> {
+        self.property("tls-root-ca")
+    }
+
+    /// Returns the path to the TLS client certificate, if set.
+    pub fn tls_client_cert(&self) -> Option<String> {
+        self.property("tls-client-cert")
+    }
+
+    /// Returns the path to the TLS client key, if set.
+    pub fn tls_client_key(&self) -> Option<String> {
+        self.property("tls-client-key")
+    }
+
+    /// Returns the user name for usrpwd authentication, if set.
+    pub fn username(&self) -> Option<String> {
+        self.property("username")
+    }
+
     /// Returns the current pad naming strategy.
     pub fn pad_naming(&self) -> PadNaming {
         self.property("pad-naming")
     }
 
+    /// Returns the width in bits of the key hash in `hash` pad names.
+    pub fn hash_bits(&self) -> u32 {
+        self.property("hash-bits")
+    }
+
+    /// Returns the prefix removed from key expressions before pad naming.
+    pub fn strip_prefix(&self) -> String {
+        self.property("strip-prefix")
+    }
+
+    /// Returns the pad names of given key expressions, if set.
+    pub fn pad_name_map(&self) -> Option<gst::Structure> {
+        self.property("pad-name-map")
+    }
+
+    /// Returns whether every key is pushed on one `src` pad.
+    pub fn single_pad(&self) -> bool {
+        self.property("single-pad")
+    }
+
     /// Returns the receive timeout in milliseconds.
     pub fn receive_timeout_ms(&self) -> u64 {
         self.property("receive-timeout-ms")
     }
 
+    /// Returns whether buffer timing metadata from the sender is applied.
+    pub fn apply_buffer_meta(&self) -> bool {
+        self.property("apply-buffer-meta")
+    }
+
+    /// Returns the maximum accepted payload size in bytes (0 = unlimited).
+    pub fn max_buffer_size(&self) -> u64 {
+        self.property("max-buffer-size")
+    }
+
+    /// Returns how many buffers are queued per pad (0 = no queue).
+    pub fn per_pad_queue_size(&self) -> u32 {
+        self.property("per-pad-queue-size")
+    }
+
+    /// Returns how many dynamic pads are created at most (0 = unlimited).
+    pub fn max_pads(&self) -> u32 {
+        self.property("max-pads")
+    }
+
+    /// Returns the idle-stream timeout in milliseconds (0 = disabled).
+    pub fn no_data_timeout_ms(&self) -> u64 {
+        self.property("no-data-timeout-ms")
+    }
+
+    /// Returns the session connectivity check interval in milliseconds (0 = disabled).
+    pub fn connectivity_poll_ms(&self) -> u32 {
+        self.property("connectivity-poll-ms")
+    }
+
     /// Returns the session group name, if set.
     pub fn session_group(&self) -> Option<String> {
         self.property("session-group")
     }
 
+    /// Returns how long the session-group session lingers once released, in milliseconds.
+    pub fn session_group_linger_ms(&self) -> u32 {
+        self.property("session-group-linger-ms")
+    }
+
+    /// Returns the port of the Prometheus statistics endpoint (0 = disabled).
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_port(&self) -> u16 {
+        self.property::<u32>("metrics-port") as u16
+    }
+
+    /// Returns the address the Prometheus statistics endpoint listens on.
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_address(&self) -> String {
+        self.property("metrics-address")
+    }
+
+    // -------------------------------------------------------------------------
+    // Actions
+    // -------------------------------------------------------------------------
+
+    /// Logs a snapshot of the state, settings and statistics at INFO level.
+    ///
+    /// The block goes to the `zenohdemux` debug category, so `GST_DEBUG=zenohdemux:4`
+    /// shows it without per-buffer traces. Same as emitting `dump-state`.
+    pub fn dump_state(&self) {
+        self.emit_by_name::<()>("dump-state", &[]);
+    }
+
+    /// Returns the pads currently active and what each of them received.
+    ///
+    /// One `stream` structure per pad, sorted by pad name, with `pad-name`,
+    /// `key-expr` (the key the pad was created for), `bytes-received` and
+    /// `messages-received`; empty while stopped. Same as emitting `get-streams`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for stream in demux.streams() {
+    ///     println!(
+    ///         "{} <- {}: {} bytes",
+    ///         stream.get::<String>("pad-name").unwrap(),
+    ///         stream.get::<String>("key-expr").unwrap(),
+    ///         stream.get::<u64>("bytes-received").unwrap()
+    ///     );
+    /// }
+    /// ```
+    pub fn streams(&self) -> Vec<gst::Structure> {
+        self.emit_by_name::<gst::Array>("get-streams", &[])
+            .iter()
+            .filter_map(|value| value.get::<gst::Structure>().ok())
+            .collect()
+    }
+
+    // -------------------------------------------------------------------------
+    // Session Introspection (read-only)
+    // -------------------------------------------------------------------------
+
+    /// Returns the Zenoh session used by this element, e.g. to declare extra
+    /// subscribers or run queries on it.
+    ///
+    /// `None` unless the element is started (PAUSED or PLAYING).
+    /// `zenoh::Session` is reference-counted, so this is a cheap clone. Its
+    /// lifetime is still tied to the element being started: the element
+    /// releases the session when it stops and opens a new one on its next
+    /// start, so undeclare whatever you declared on the handle by then
+    /// (holding the handle keeps the old session open).
+    pub fn session(&self) -> Option<zenoh::Session> {
+        self.imp().session()
+    }
+
+    /// Returns the Zenoh ID of the session used by this element.
+    ///
+    /// Empty when the element is stopped.
+    pub fn session_zid(&self) -> String {
+        self.property("session-zid")
+    }
+
+    /// Returns whether the session is shared rather than opened by this element.
+    ///
+    /// True with a `session-group` session, e.g. to check that the group took
+    /// effect. False when the element is stopped.
+    pub fn is_session_shared(&self) -> bool {
+        self.property("session-shared")
+    }
+
+    /// Returns the routers and peers the session is currently connected to.
+    ///
+    /// Entries read `router:<zid>` or `peer:<zid>`, routers first. Empty
+    /// when the element is stopped.
+    pub fn peers(&self) -> Vec<String> {
+        self.property("peers")
+    }
+
     // -------------------------------------------------------------------------
     // Statistics (read-only)
     // -------------------------------------------------------------------------
@@ -220,10 +640,83 @@ impl ZenohDemux {
         self.property("messages-received")
     }
 
+    /// Returns the total number of errors encountered since the element started.
+    pub fn errors(&self) -> u64 {
+        self.property("errors")
+    }
+
     /// Returns the number of dynamic pads created.
     pub fn pads_created(&self) -> u64 {
         self.property("pads-created")
     }
+
+    /// Returns the number of samples dropped without a pad because `max-pads` was reached.
+    pub fn pads_rejected(&self) -> u64 {
+        self.property("pads-rejected")
+    }
+
+    /// Returns the number of buffers dropped because the queue of their pad was full.
+    pub fn dropped_queue_full(&self) -> u64 {
+        self.property("dropped-queue-full")
+    }
+
+    /// Returns the number of serialized attachment bytes received.
+    ///
+    /// Metadata overhead on top of [`bytes_received`](Self::bytes_received).
+    pub fn attachment_bytes_received(&self) -> u64 {
+        self.property("attachment-bytes-received")
+    }
+
+    /// Returns the number of samples whose metadata attachment was malformed.
+    ///
+    /// Such samples are still delivered, without the metadata that couldn't
+    /// be read (e.g. caps).
+    pub fn metadata_errors(&self) -> u64 {
+        self.property("metadata-errors")
+    }
+
+    /// Returns the number of samples missing from the publishers' sequence numbers.
+    ///
+    /// Tracked per key expression, for publishers with `sequence-numbers` enabled.
+    pub fn samples_lost(&self) -> u64 {
+        self.property("samples-lost")
+    }
+
+    /// Returns the number of samples received after a sample with a higher sequence number.
+    pub fn samples_reordered(&self) -> u64 {
+        self.property("samples-reordered")
+    }
+
+    /// Returns the aggregate incoming bitrate in bits per second over the last second.
+    ///
+    /// Drops back to 0 once no data has flowed for a full second.
+    pub fn bitrate(&self) -> u64 {
+        self.property("bitrate")
+    }
+
+    /// Returns the aggregate incoming message rate in messages per second over the last second.
+    pub fn message_rate(&self) -> f64 {
+        self.property("message-rate")
+    }
+
+    /// Returns the priority of the last sample received on any pad
+    /// (1=RealTime to 7=Background), or 0 before any sample.
+    pub fn rx_priority(&self) -> u32 {
+        self.property("rx-priority")
+    }
+
+    /// Returns whether the last sample received on any pad was published with express.
+    pub fn rx_express(&self) -> bool {
+        self.property("rx-express")
+    }
+
+    /// Returns all statistics as one compact JSON object keyed by property name.
+    ///
+    /// The values are read atomically, so exporters get a consistent snapshot in
+    /// a single call, e.g. `{"bytes-received":1024,"messages-received":8,...}`.
+    pub fn stats_json(&self) -> String {
+        self.property("stats-json")
+    }
 }
 
 impl TryFrom<gst::Element> for ZenohDemux {
@@ -273,9 +766,32 @@ impl TryFrom<gst::Element> for ZenohDemux {
 pub struct ZenohDemuxBuilder {
     key_expr: String,
     config: Option<String>,
+    config_json5: Option<String>,
+    scouting: Option<bool>,
+    endpoints_only: Option<bool>,
+    tls_root_ca: Option<String>,
+    tls_client_cert: Option<String>,
+    tls_client_key: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
     pad_naming: Option<PadNaming>,
+    hash_bits: Option<u32>,
+    strip_prefix: Option<String>,
+    pad_name_map: Option<gst::Structure>,
+    single_pad: Option<bool>,
     receive_timeout_ms: Option<u64>,
+    apply_buffer_meta: Option<bool>,
+    max_buffer_size: Option<u64>,
+    per_pad_queue_size: Option<u32>,
+    max_pads: Option<u32>,
+    no_data_timeout_ms: Option<u64>,
+    connectivity_poll_ms: Option<u32>,
     session_group: Option<String>,
+    session_group_linger_ms: Option<u32>,
+    #[cfg(feature = "metrics-http")]
+    metrics_port: Option<u16>,
+    #[cfg(feature = "metrics-http")]
+    metrics_address: Option<String>,
 }
 
 impl ZenohDemuxBuilder {
@@ -284,9 +800,32 @@ impl ZenohDemuxBuilder {
         Self {
             key_expr: key_expr.to_string(),
             config: None,
+            config_json5: None,
+            scouting: None,
+            endpoints_only: None,
+            tls_root_ca: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+            username: None,
+            password: None,
             pad_naming: None,
+            hash_bits: None,
+            strip_prefix: None,
+            pad_name_map: None,
+            single_pad: None,
             receive_timeout_ms: None,
+            apply_buffer_meta: None,
+            max_buffer_size: None,
+            per_pad_queue_size: None,
+            max_pads: None,
+            no_data_timeout_ms: None,
+            connectivity_poll_ms: None,
             session_group: None,
+            session_group_linger_ms: None,
+            #[cfg(feature = "metrics-http")]
+            metrics_port: None,
+            #[cfg(feature = "metrics-http")]
+            metrics_address: None,
         }
     }
 
@@ -296,18 +835,117 @@ impl ZenohDemuxBuilder {
         self
     }
 
+    /// Sets the Zenoh configuration inline, as a JSON5 string (overrides `config`).
+    pub fn config_json5(mut self, json5: &str) -> Self {
+        self.config_json5 = Some(json5.to_string());
+        self
+    }
+
+    /// Enables or disables multicast scouting (default: true).
+    pub fn scouting(mut self, enabled: bool) -> Self {
+        self.scouting = Some(enabled);
+        self
+    }
+
+    /// Connects only to the configured endpoints, in client mode (default: false).
+    pub fn endpoints_only(mut self, enabled: bool) -> Self {
+        self.endpoints_only = Some(enabled);
+        self
+    }
+
+    /// Sets the CA certificate used to verify TLS routers and peers.
+    pub fn tls_root_ca(mut self, path: &str) -> Self {
+        self.tls_root_ca = Some(path.to_string());
+        self
+    }
+
+    /// Sets the client certificate and key for mutual TLS.
+    pub fn tls_client_cert(mut self, cert_path: &str, key_path: &str) -> Self {
+        self.tls_client_cert = Some(cert_path.to_string());
+        self.tls_client_key = Some(key_path.to_string());
+        self
+    }
+
+    /// Sets the user name and password for usrpwd authentication.
+    pub fn credentials(mut self, username: &str, password: &str) -> Self {
+        self.username = Some(username.to_string());
+        self.password = Some(password.to_string());
+        self
+    }
+
     /// Sets the pad naming strategy.
     pub fn pad_naming(mut self, naming: PadNaming) -> Self {
         self.pad_naming = Some(naming);
         self
     }
 
+    /// Sets the width in bits of the key hash in `hash` pad names.
+    pub fn hash_bits(mut self, bits: u32) -> Self {
+        self.hash_bits = Some(bits);
+        self
+    }
+
+    /// Sets a prefix removed from key expressions before pad naming.
+    pub fn strip_prefix(mut self, prefix: &str) -> Self {
+        self.strip_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Sets the pad names of given key expressions, taking precedence over `pad-naming`.
+    pub fn pad_name_map(mut self, map: gst::Structure) -> Self {
+        self.pad_name_map = Some(map);
+        self
+    }
+
+    /// Enables or disables pushing every key on one `src` pad.
+    pub fn single_pad(mut self, single_pad: bool) -> Self {
+        self.single_pad = Some(single_pad);
+        self
+    }
+
     /// Sets the receive timeout in milliseconds.
     pub fn receive_timeout_ms(mut self, timeout: u64) -> Self {
         self.receive_timeout_ms = Some(timeout);
         self
     }
 
+    /// Enables or disables applying buffer timing metadata from the sender.
+    pub fn apply_buffer_meta(mut self, apply: bool) -> Self {
+        self.apply_buffer_meta = Some(apply);
+        self
+    }
+
+    /// Sets the maximum accepted payload size in bytes (0 = unlimited).
+    pub fn max_buffer_size(mut self, size: u64) -> Self {
+        self.max_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets how many buffers are queued per pad (0 = no queue).
+    pub fn per_pad_queue_size(mut self, size: u32) -> Self {
+        self.per_pad_queue_size = Some(size);
+        self
+    }
+
+    /// Sets how many dynamic pads are created at most (0 = unlimited).
+    pub fn max_pads(mut self, max: u32) -> Self {
+        self.max_pads = Some(max);
+        self
+    }
+
+    /// Sets how long without data before a `zenoh-stream-idle` message is
+    /// posted (0 = disabled).
+    pub fn no_data_timeout_ms(mut self, timeout: u64) -> Self {
+        self.no_data_timeout_ms = Some(timeout);
+        self
+    }
+
+    /// Sets how often the session connectivity is checked (default: 1000, 0 = disabled).
+    pub fn connectivity_poll_ms(mut self, interval: u32) -> Self {
+        self.connectivity_poll_ms = Some(interval);
+        self
+    }
+
     /// Sets the session group name for sharing sessions across elements.
     ///
     /// Elements with the same session-group name will share a single
@@ -317,6 +955,33 @@ impl ZenohDemuxBuilder {
         self
     }
 
+    /// Sets how long the session-group session lingers once released (default: 0 ms).
+    pub fn session_group_linger_ms(mut self, linger: u32) -> Self {
+        self.session_group_linger_ms = Some(linger);
+        self
+    }
+
+    /// Serves the statistics in Prometheus text format on this port.
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_port(mut self, port: u16) -> Self {
+        self.metrics_port = Some(port);
+        self
+    }
+
+    /// Sets the address the metrics endpoint listens on (default: 127.0.0.1).
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_address(mut self, address: &str) -> Self {
+        self.metrics_address = Some(address.to_string());
+        self
+    }
+
+    /// Builds the ZenohDemux after checking the key expression, so a malformed
+    /// one is reported here instead of when the element starts.
+    pub fn try_build(self) -> Result<ZenohDemux, String> {
+        crate::utils::validate_key_expr(&self.key_expr)?;
+        Ok(self.build())
+    }
+
     /// Builds the ZenohDemux with the configured properties.
     pub fn build(self) -> ZenohDemux {
         let mut builder = gst::Object::builder::<ZenohDemux>().property("key-expr", &self.key_expr);
@@ -324,15 +989,80 @@ impl ZenohDemuxBuilder {
         if let Some(config) = self.config {
             builder = builder.property("config", config);
         }
+        if let Some(json5) = self.config_json5 {
+            builder = builder.property("config-json5", json5);
+        }
+        if let Some(scouting) = self.scouting {
+            builder = builder.property("scouting", scouting);
+        }
+        if let Some(endpoints_only) = self.endpoints_only {
+            builder = builder.property("endpoints-only", endpoints_only);
+        }
+        if let Some(path) = self.tls_root_ca {
+            builder = builder.property("tls-root-ca", path);
+        }
+        if let Some(path) = self.tls_client_cert {
+            builder = builder.property("tls-client-cert", path);
+        }
+        if let Some(path) = self.tls_client_key {
+            builder = builder.property("tls-client-key", path);
+        }
+        if let Some(username) = self.username {
+            builder = builder.property("username", username);
+        }
+        if let Some(password) = self.password {
+            builder = builder.property("password", password);
+        }
         if let Some(naming) = self.pad_naming {
             builder = builder.property("pad-naming", naming);
         }
+        if let Some(bits) = self.hash_bits {
+            builder = builder.property("hash-bits", bits);
+        }
+        if let Some(ref prefix) = self.strip_prefix {
+            builder = builder.property("strip-prefix", prefix);
+        }
+        if let Some(map) = self.pad_name_map {
+            builder = builder.property("pad-name-map", map);
+        }
+        if let Some(single_pad) = self.single_pad {
+            builder = builder.property("single-pad", single_pad);
+        }
         if let Some(timeout) = self.receive_timeout_ms {
             builder = builder.property("receive-timeout-ms", timeout);
         }
+        if let Some(apply) = self.apply_buffer_meta {
+            builder = builder.property("apply-buffer-meta", apply);
+        }
+        if let Some(size) = self.max_buffer_size {
+            builder = builder.property("max-buffer-size", size);
+        }
+        if let Some(size) = self.per_pad_queue_size {
+            builder = builder.property("per-pad-queue-size", size);
+        }
+        if let Some(max) = self.max_pads {
+            builder = builder.property("max-pads", max);
+        }
+        if let Some(timeout) = self.no_data_timeout_ms {
+            builder = builder.property("no-data-timeout-ms", timeout);
+        }
+        if let Some(interval) = self.connectivity_poll_ms {
+            builder = builder.property("connectivity-poll-ms", interval);
+        }
         if let Some(ref sg) = self.session_group {
             builder = builder.property("session-group", sg);
         }
+        if let Some(linger) = self.session_group_linger_ms {
+            builder = builder.property("session-group-linger-ms", linger);
+        }
+        #[cfg(feature = "metrics-http")]
+        if let Some(port) = self.metrics_port {
+            builder = builder.property("metrics-port", port as u32);
+        }
+        #[cfg(feature = "metrics-http")]
+        if let Some(ref address) = self.metrics_address {
+            builder = builder.property("metrics-address", address);
+        }
 
         builder.build().unwrap()
     }
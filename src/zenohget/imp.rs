@@ -0,0 +1,691 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+
+use gst::{glib, prelude::*, subclass::prelude::*};
+use gst_base::{
+    prelude::BaseSrcExt,
+    subclass::{base_src::CreateSuccess, prelude::*},
+};
+use zenoh::Wait;
+use zenoh::query::{ConsolidationMode, QueryTarget};
+
+use crate::error::{ErrorHandling, FlowErrorHandling, ZenohError, post_error_message};
+use crate::metadata::MetadataParser;
+use crate::qos::{ZenohConsolidation, ZenohQueryTarget};
+use crate::session::{ConnectivityMonitor, SessionConfig, SessionWrapper};
+
+static CAT: LazyLock<gst::DebugCategory> = LazyLock::new(|| {
+    gst::DebugCategory::new("zenohget", gst::DebugColorFlags::empty(), Some("Zenoh Get"))
+});
+
+/// Poll interval used while waiting for replies, to stay responsive to flushing
+const REPLY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Statistics tracking for ZenohGet
+#[derive(Debug, Clone, Default)]
+struct Statistics {
+    bytes_received: u64,
+    replies_received: u64,
+    errors: u64,
+}
+
+struct Started {
+    // Keeping session field to maintain ownership and prevent session from being dropped
+    // while the query is in flight. This can be either owned or shared.
+    session: SessionWrapper,
+    /// Reply channel of the query, `None` until the first `create()` call issues it
+    replies: Option<zenoh::handlers::FifoChannelHandler<zenoh::query::Reply>>,
+    /// Statistics tracking
+    stats: Arc<Mutex<Statistics>>,
+    /// Emits the session signals (when `connectivity-poll-ms` is set)
+    _connectivity: Option<ConnectivityMonitor>,
+}
+
+#[derive(Default)]
+enum State {
+    #[default]
+    Stopped,
+    Started(Started),
+}
+
+impl State {
+    fn name(&self) -> &'static str {
+        match self {
+            State::Stopped => "Stopped",
+            State::Started(_) => "Started",
+        }
+    }
+}
+
+/// Configuration settings for the ZenohGet element.
+#[derive(Debug)]
+struct Settings {
+    /// Zenoh selector to query (required)
+    key_expr: String,
+    /// Optional path to Zenoh configuration file
+    config_file: Option<String>,
+    /// Inline Zenoh configuration (JSON5), takes precedence over `config_file`
+    config_json5: Option<String>,
+    /// Multicast scouting for peer discovery (default: true)
+    scouting: bool,
+    /// Queryables the query is routed to
+    target: ZenohQueryTarget,
+    /// How replies for the same key are consolidated
+    consolidation: ZenohConsolidation,
+    /// Query timeout in milliseconds
+    timeout_ms: u64,
+    /// Apply buffer timing metadata (PTS, DTS, duration, flags) from replies (default: true)
+    apply_buffer_meta: bool,
+    /// Maximum reply payload size in bytes, before and after decompression (0 = unlimited)
+    max_buffer_size: u64,
+    /// Session connectivity check interval for the session signals (0 = disabled)
+    connectivity_poll_ms: u32,
+    /// Optional external Zenoh session to share with other elements (Rust API)
+    external_session: Option<zenoh::Session>,
+    /// Session group name for sharing sessions via property (gst-launch compatible)
+    session_group: Option<String>,
+    /// How long the group session stays open after its last element released it
+    session_group_linger_ms: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            key_expr: String::new(),
+            config_file: None,
+            config_json5: None,
+            scouting: true,
+            target: ZenohQueryTarget::BestMatching,
+            consolidation: ZenohConsolidation::Auto,
+            timeout_ms: 10_000,
+            apply_buffer_meta: true,
+            max_buffer_size: 0,
+            connectivity_poll_ms: crate::session::DEFAULT_CONNECTIVITY_POLL_MS,
+            external_session: None,
+            session_group: None,
+            session_group_linger_ms: crate::session::DEFAULT_SESSION_GROUP_LINGER_MS,
+        }
+    }
+}
+
+/// GStreamer ZenohGet element implementation.
+///
+/// Issues a single Zenoh query when streaming starts, pushes every reply
+/// as a buffer and sends EOS once all replies have been received.
+#[derive(Default)]
+pub struct ZenohGet {
+    settings: Mutex<Settings>,
+    state: Mutex<State>,
+    /// Set to cancel the wait for replies (unlock). Kept outside `state`
+    /// because `create()` holds that lock while it waits for replies.
+    flushing: AtomicBool,
+}
+
+impl ZenohGet {
+    /// Logs the state, settings and statistics at INFO (`dump-state` signal).
+    fn dump_state(&self) {
+        let state = self.state.lock().unwrap().name();
+        crate::utils::dump_state(self.obj().upcast_ref(), *CAT, state);
+    }
+
+    /// Sets the external Zenoh session to use for this element.
+    ///
+    /// This is called from the public API to enable session sharing.
+    pub(crate) fn set_external_session(&self, session: zenoh::Session) {
+        let mut settings = self.settings.lock().unwrap();
+        settings.external_session = Some(session);
+    }
+
+    /// Sends the query and returns the channel its replies arrive on.
+    fn send_query(
+        &self,
+        session: &zenoh::Session,
+    ) -> Result<zenoh::handlers::FifoChannelHandler<zenoh::query::Reply>, gst::FlowError> {
+        let (key_expr, target, consolidation, timeout_ms) = {
+            let settings = self.settings.lock().unwrap();
+            (
+                settings.key_expr.clone(),
+                settings.target,
+                settings.consolidation,
+                settings.timeout_ms,
+            )
+        };
+
+        gst::debug!(
+            CAT,
+            imp = self,
+            "Querying '{}' (target={:?}, consolidation={:?}, timeout={}ms)",
+            key_expr,
+            target,
+            consolidation,
+            timeout_ms
+        );
+
+        session
+            .get(&key_expr)
+            .target(QueryTarget::from(target))
+            .consolidation(ConsolidationMode::from(consolidation))
+            .timeout(Duration::from_millis(timeout_ms))
+            .wait()
+            .map_err(|e| {
+                gst::element_imp_error!(
+                    self,
+                    gst::ResourceError::Read,
+                    ["Failed to query '{}': {}", key_expr, e]
+                );
+                gst::FlowError::Error
+            })
+    }
+}
+
+impl GstObjectImpl for ZenohGet {}
+
+impl ElementImpl for ZenohGet {
+    fn metadata() -> Option<&'static gst::subclass::ElementMetadata> {
+        static ELEMENT_METADATA: LazyLock<gst::subclass::ElementMetadata> = LazyLock::new(|| {
+            gst::subclass::ElementMetadata::new(
+                "Zenoh Get",
+                "Source/Network/Protocol",
+                "Queries Zenoh once and pushes the replies as buffers, then EOS",
+                "Marc Pardo <p13marc@gmail.com>",
+            )
+        });
+        Some(&*ELEMENT_METADATA)
+    }
+
+    fn pad_templates() -> &'static [gst::PadTemplate] {
+        static PAD_TEMPLATES: LazyLock<Vec<gst::PadTemplate>> = LazyLock::new(|| {
+            let src_pad_template = gst::PadTemplate::new(
+                "src",
+                gst::PadDirection::Src,
+                gst::PadPresence::Always,
+                &gst::Caps::new_any(),
+            )
+            .unwrap();
+
+            vec![src_pad_template]
+        });
+
+        PAD_TEMPLATES.as_ref()
+    }
+}
+
+impl ObjectImpl for ZenohGet {
+    fn signals() -> &'static [glib::subclass::Signal] {
+        static SIGNALS: LazyLock<Vec<glib::subclass::Signal>> = LazyLock::new(|| {
+            vec![
+                glib::subclass::Signal::builder("session-disconnected").build(),
+                glib::subclass::Signal::builder("session-reconnected").build(),
+                glib::subclass::Signal::builder("dump-state")
+                    .action()
+                    .class_handler(|args| {
+                        let element = args[0]
+                            .get::<super::ZenohGet>()
+                            .expect("signal arg type checked upstream");
+                        element.imp().dump_state();
+                        None
+                    })
+                    .build(),
+            ]
+        });
+        SIGNALS.as_ref()
+    }
+
+    fn properties() -> &'static [glib::ParamSpec] {
+        static PROPERTIES: LazyLock<Vec<glib::ParamSpec>> = LazyLock::new(|| {
+            vec![
+                glib::ParamSpecString::builder("key-expr")
+                    .nick("Zenoh Selector")
+                    .blurb("Zenoh key expression (optionally with ?parameters) to query")
+                    .build(),
+                glib::ParamSpecString::builder("config")
+                    .nick("Zenoh Configuration")
+                    .blurb("Path to Zenoh configuration file (JSON5 format)")
+                    .build(),
+                glib::ParamSpecString::builder("config-json5")
+                    .nick("Zenoh Configuration (JSON5)")
+                    .blurb("Inline Zenoh configuration in JSON5 format (e.g. '{mode: \"client\", connect: {endpoints: [\"tcp/10.0.0.1:7447\"]}}'), taking precedence over config; scouting, TLS and authentication properties are applied on top")
+                    .build(),
+                glib::ParamSpecBoolean::builder("scouting")
+                    .nick("Multicast Scouting")
+                    .blurb("Discover peers via multicast scouting; disable on networks where multicast doesn't work and rely on configured endpoints")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecEnum::builder_with_default(
+                    "target",
+                    ZenohQueryTarget::BestMatching,
+                )
+                .nick("Query Target")
+                .blurb("Queryables the query is routed to")
+                .build(),
+                glib::ParamSpecEnum::builder_with_default(
+                    "consolidation",
+                    ZenohConsolidation::Auto,
+                )
+                .nick("Consolidation")
+                .blurb("How replies for the same key expression are consolidated")
+                .build(),
+                glib::ParamSpecUInt64::builder("timeout-ms")
+                    .nick("Query Timeout")
+                    .blurb("Time to wait for replies before sending EOS, in milliseconds")
+                    .minimum(1)
+                    .default_value(10_000)
+                    .build(),
+                glib::ParamSpecBoolean::builder("apply-buffer-meta")
+                    .nick("Apply Buffer Metadata")
+                    .blurb("Apply buffer timing metadata (PTS, DTS, duration, flags) from reply attachments")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecUInt64::builder("max-buffer-size")
+                    .nick("Max Buffer Size")
+                    .blurb("Drop replies whose payload, compressed or decompressed, is larger than this many bytes instead of allocating a buffer for them (0 = unlimited)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt::builder("connectivity-poll-ms")
+                    .nick("Connectivity Poll Interval")
+                    .blurb("How often the session is checked for router and peer connections, emitting session-disconnected when the last one is lost and session-reconnected when one is back (0 = disabled)")
+                    .default_value(crate::session::DEFAULT_CONNECTIVITY_POLL_MS)
+                    .maximum(60_000)
+                    .build(),
+                glib::ParamSpecString::builder("session-group")
+                    .nick("Session Group")
+                    .blurb("Name of the session group for sharing Zenoh sessions across elements. Elements with the same group name share a single session.")
+                    .build(),
+                glib::ParamSpecUInt::builder("session-group-linger-ms")
+                    .nick("Session Group Linger")
+                    .blurb("Keep the session-group session open this many milliseconds after this element was the last one to release it, so elements briefly overlapping reuse it instead of reopening one (0 = close right away)")
+                    .maximum(crate::session::MAX_SESSION_GROUP_LINGER_MS)
+                    .default_value(crate::session::DEFAULT_SESSION_GROUP_LINGER_MS)
+                    .build(),
+                // Statistics (read-only)
+                glib::ParamSpecUInt64::builder("bytes-received")
+                    .nick("Bytes Received")
+                    .blurb("Total reply payload bytes received")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("replies-received")
+                    .nick("Replies Received")
+                    .blurb("Total successful replies received")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("errors")
+                    .nick("Errors")
+                    .blurb("Total error replies and processing errors")
+                    .read_only()
+                    .build(),
+            ]
+        });
+
+        PROPERTIES.as_ref()
+    }
+
+    fn set_property(&self, _id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+        let mut settings = self.settings.lock().unwrap();
+
+        match pspec.name() {
+            "key-expr" => {
+                settings.key_expr = value.get::<String>().expect("type checked upstream");
+            }
+            "config" => {
+                settings.config_file = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "config-json5" => {
+                settings.config_json5 = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "scouting" => {
+                settings.scouting = value.get::<bool>().expect("type checked upstream");
+            }
+            "target" => {
+                settings.target = value
+                    .get::<ZenohQueryTarget>()
+                    .expect("type checked upstream");
+            }
+            "consolidation" => {
+                settings.consolidation = value
+                    .get::<ZenohConsolidation>()
+                    .expect("type checked upstream");
+            }
+            "timeout-ms" => {
+                settings.timeout_ms = value.get::<u64>().expect("type checked upstream");
+            }
+            "apply-buffer-meta" => {
+                settings.apply_buffer_meta = value.get::<bool>().expect("type checked upstream");
+            }
+            "max-buffer-size" => {
+                settings.max_buffer_size = value.get::<u64>().expect("type checked upstream");
+            }
+            "connectivity-poll-ms" => {
+                settings.connectivity_poll_ms = value.get::<u32>().expect("type checked upstream");
+            }
+            "session-group" => {
+                settings.session_group = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "session-group-linger-ms" => {
+                settings.session_group_linger_ms =
+                    value.get::<u32>().expect("type checked upstream");
+            }
+            name => {
+                gst::warning!(CAT, imp = self, "Unknown property: {}", name);
+            }
+        }
+    }
+
+    fn property(&self, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+        match pspec.name() {
+            "key-expr" => self.settings.lock().unwrap().key_expr.to_value(),
+            "config" => self.settings.lock().unwrap().config_file.to_value(),
+            "config-json5" => self.settings.lock().unwrap().config_json5.to_value(),
+            "scouting" => self.settings.lock().unwrap().scouting.to_value(),
+            "target" => self.settings.lock().unwrap().target.to_value(),
+            "consolidation" => self.settings.lock().unwrap().consolidation.to_value(),
+            "timeout-ms" => self.settings.lock().unwrap().timeout_ms.to_value(),
+            "apply-buffer-meta" => self.settings.lock().unwrap().apply_buffer_meta.to_value(),
+            "max-buffer-size" => self.settings.lock().unwrap().max_buffer_size.to_value(),
+            "connectivity-poll-ms" => self.settings.lock().unwrap().connectivity_poll_ms.to_value(),
+            "session-group" => self.settings.lock().unwrap().session_group.to_value(),
+            "session-group-linger-ms" => {
+                self.settings.lock().unwrap().session_group_linger_ms.to_value()
+            }
+            "bytes-received" | "replies-received" | "errors" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    let stats = started.stats.lock().unwrap();
+                    match pspec.name() {
+                        "bytes-received" => stats.bytes_received.to_value(),
+                        "replies-received" => stats.replies_received.to_value(),
+                        "errors" => stats.errors.to_value(),
+                        _ => unreachable!(),
+                    }
+                } else {
+                    0u64.to_value()
+                }
+            }
+            name => {
+                gst::warning!(CAT, imp = self, "Unknown property: {}", name);
+                "".to_value()
+            }
+        }
+    }
+
+    fn constructed(&self) {
+        self.parent_constructed();
+        self.obj().set_format(gst::Format::Time);
+    }
+}
+
+#[glib::object_subclass]
+impl ObjectSubclass for ZenohGet {
+    const NAME: &'static str = "GstZenohGet";
+    type Type = super::ZenohGet;
+    type ParentType = gst_base::PushSrc;
+}
+
+impl BaseSrcImpl for ZenohGet {
+    fn start(&self) -> Result<(), gst::ErrorMessage> {
+        let mut state = self.state.lock().unwrap();
+        if matches!(*state, State::Started(_)) {
+            return Ok(());
+        }
+
+        let settings = self.settings.lock().unwrap();
+        let key_expr = settings.key_expr.clone();
+        let session_config = SessionConfig {
+            config_file: settings.config_file.clone(),
+            config_json5: settings.config_json5.clone(),
+            scouting: settings.scouting,
+            ..Default::default()
+        };
+        let external_session = settings.external_session.clone();
+        let session_group = settings.session_group.clone();
+        let session_group_linger = Duration::from_millis(settings.session_group_linger_ms.into());
+        let connectivity_poll_ms = settings.connectivity_poll_ms;
+        drop(settings);
+
+        if key_expr.is_empty() {
+            return Err(gst::error_msg!(
+                gst::ResourceError::Settings,
+                ["Key expression is required"]
+            ));
+        }
+
+        // Determine session source: external (Rust API) > session-group (property) > new session
+        let session_wrapper = if let Some(shared_session) = external_session {
+            gst::debug!(CAT, imp = self, "Using external shared session (Rust API)");
+            SessionWrapper::Shared(shared_session)
+        } else if let Some(ref group) = session_group {
+            gst::debug!(CAT, imp = self, "Using session group '{}'", group);
+            let session =
+                crate::session::get_or_create_session(group, &session_config, session_group_linger)
+                    .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            SessionWrapper::Group(session)
+        } else {
+            gst::debug!(CAT, imp = self, "Creating new Zenoh session");
+            if let Some(ref path) = session_config.config_file
+                && !path.is_empty()
+            {
+                gst::debug!(CAT, imp = self, "Loading Zenoh config from {}", path);
+            }
+            let session = session_config
+                .open()
+                .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            SessionWrapper::Owned(session)
+        };
+
+        let connectivity = ConnectivityMonitor::start(
+            session_wrapper.as_session(),
+            self.obj().upcast_ref(),
+            connectivity_poll_ms,
+        );
+
+        // A previous run may have stopped while flushing
+        self.flushing.store(false, Ordering::SeqCst);
+        *state = State::Started(Started {
+            session: session_wrapper,
+            replies: None,
+            stats: Arc::new(Mutex::new(Statistics::default())),
+            _connectivity: connectivity,
+        });
+
+        gst::debug!(CAT, imp = self, "ZenohGet started");
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), gst::ErrorMessage> {
+        let mut state = self.state.lock().unwrap();
+        // Dropping Started drops the reply channel and releases the session
+        *state = State::Stopped;
+        gst::debug!(CAT, imp = self, "ZenohGet stopped");
+        Ok(())
+    }
+
+    fn is_seekable(&self) -> bool {
+        false
+    }
+
+    fn unlock(&self) -> Result<(), gst::ErrorMessage> {
+        gst::debug!(CAT, imp = self, "Unlock called - cancelling the wait for replies");
+        self.flushing.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn unlock_stop(&self) -> Result<(), gst::ErrorMessage> {
+        self.flushing.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl PushSrcImpl for ZenohGet {
+    fn create(
+        &self,
+        _buffer: Option<&mut gst::BufferRef>,
+    ) -> Result<CreateSuccess, gst::FlowError> {
+        let mut state_locked = self.state.lock().unwrap();
+        let State::Started(ref mut started) = *state_locked else {
+            gst::element_imp_error!(self, gst::CoreError::Failed, ["Not started yet"]);
+            return Err(gst::FlowError::Error);
+        };
+
+        if started.replies.is_none() {
+            started.replies = Some(self.send_query(started.session.as_session())?);
+        }
+        let replies = started.replies.as_ref().unwrap();
+
+        let (apply_buffer_meta, max_buffer_size) = {
+            let settings = self.settings.lock().unwrap();
+            (settings.apply_buffer_meta, settings.max_buffer_size)
+        };
+
+        // Wait for the next successful reply. The channel is closed once the
+        // query is finalized (all replies received or timeout), which ends the stream.
+        let sample = loop {
+            if self.flushing.load(Ordering::SeqCst) {
+                gst::debug!(
+                    CAT,
+                    imp = self,
+                    "Flushing detected while waiting for replies"
+                );
+                return Err(gst::FlowError::Flushing);
+            }
+
+            match replies.recv_timeout(REPLY_POLL_INTERVAL) {
+                Ok(Some(reply)) => match reply.into_result() {
+                    // Refuse oversized replies before anything gets allocated for them
+                    Ok(sample)
+                        if max_buffer_size > 0
+                            && sample.payload().len() as u64 > max_buffer_size =>
+                    {
+                        started.stats.lock().unwrap().errors += 1;
+                        gst::warning!(
+                            CAT,
+                            imp = self,
+                            "Dropping {} byte reply on '{}': exceeds max-buffer-size ({})",
+                            sample.payload().len(),
+                            sample.key_expr(),
+                            max_buffer_size
+                        );
+                    }
+                    Ok(sample) => break sample,
+                    Err(err) => {
+                        let message =
+                            String::from_utf8_lossy(&err.payload().to_bytes()).into_owned();
+                        gst::warning!(CAT, imp = self, "Received error reply: {}", message);
+                        started.stats.lock().unwrap().errors += 1;
+                        let key_expr = self.settings.lock().unwrap().key_expr.clone();
+                        post_error_message(self.obj().upcast_ref(), &key_expr, &message);
+                    }
+                },
+                Ok(None) => continue,
+                Err(_) => {
+                    gst::debug!(CAT, imp = self, "Query finished, sending EOS");
+                    return Err(gst::FlowError::Eos);
+                }
+            }
+        };
+
+        // Parse the reply attachment (caps, buffer timing, compression, etc.)
+        let parsed_metadata = if let Some(attachment) = sample.attachment() {
+            match MetadataParser::parse(attachment) {
+                Ok(metadata) => {
+                    if let Some(caps) = metadata.caps() {
+                        gst::debug!(CAT, imp = self, "Received caps from metadata: {}", caps);
+                        if let Err(e) = self.obj().set_caps(caps) {
+                            gst::warning!(CAT, imp = self, "Failed to set caps: {}", e);
+                        }
+                    }
+                    Some(metadata)
+                }
+                Err(e) => {
+                    gst::warning!(CAT, imp = self, "Failed to parse metadata: {}", e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        // Refuse payloads compressed with an algorithm this build can't decode
+        if let Some(algorithm) = parsed_metadata
+            .as_ref()
+            .and_then(crate::metadata::unsupported_compression)
+        {
+            started.stats.lock().unwrap().errors += 1;
+            let err = ZenohError::UnsupportedCompression {
+                algorithm: algorithm.to_string(),
+            };
+            self.post_error_message(err.to_error_message());
+            return Err(err.to_flow_error());
+        }
+
+        let payload = sample.payload().to_bytes();
+
+        // Decompress if needed
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        let data = match parsed_metadata.as_ref().and_then(|metadata| {
+            metadata
+                .user_metadata()
+                .get(crate::metadata::keys::COMPRESSION)
+                .and_then(|v| crate::compression::CompressionType::from_metadata_value(v))
+        }) {
+            Some(comp_type) => match crate::compression::decompress_with_limit(
+                &payload,
+                comp_type,
+                max_buffer_size,
+            ) {
+                Ok(decompressed) => decompressed,
+                Err(e) => {
+                    started.stats.lock().unwrap().errors += 1;
+                    gst::element_imp_error!(
+                        self,
+                        gst::StreamError::Decode,
+                        ["Decompression failed: {}", e]
+                    );
+                    return Err(gst::FlowError::Error);
+                }
+            },
+            None => payload.to_vec(),
+        };
+
+        #[cfg(not(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        )))]
+        let data = payload.to_vec();
+
+        let len = data.len();
+        let mut buffer = gst::Buffer::from_mut_slice(data);
+        if apply_buffer_meta && let Some(ref metadata) = parsed_metadata {
+            metadata.apply_to_buffer(buffer.get_mut().unwrap());
+        }
+
+        let mut stats = started.stats.lock().unwrap();
+        stats.bytes_received += len as u64;
+        stats.replies_received += 1;
+        drop(stats);
+
+        gst::trace!(
+            CAT,
+            imp = self,
+            "Pushing reply from '{}' ({} bytes)",
+            sample.key_expr(),
+            len
+        );
+
+        Ok(CreateSuccess::NewBuffer(buffer))
+    }
+}
@@ -0,0 +1,477 @@
+//! # ZenohGet Element
+//!
+//! The ZenohGet element performs a single Zenoh query (`session.get()`) when
+//! the pipeline starts streaming, pushes every reply downstream as a buffer
+//! and then sends EOS. It is the client half of [`zenohqueryablesrc`], suited
+//! to snapshot workflows such as thumbnailing or polling sensors.
+//!
+//! Reply attachments are handled like samples in `zenohsrc`: caps are applied
+//! to the source pad, buffer timing metadata is restored, and compressed
+//! payloads are decompressed when the matching feature is enabled.
+//!
+//! ## Properties
+//!
+//! * `key-expr` - Zenoh selector to query (required)
+//!   - May include parameters, e.g. "camera/snapshot?size=small"
+//! * `config` - Path to Zenoh configuration file (optional)
+//! * `config-json5` - Inline Zenoh configuration in JSON5 (optional, overrides `config`)
+//! * `scouting` - Discover peers via multicast scouting (default: true)
+//!   - Disable on networks where multicast doesn't work; peers are then reached
+//!     through the endpoints of the configuration
+//! * `target` - Queryables to target: "best-matching" (default), "all" or "all-complete"
+//! * `consolidation` - Reply consolidation: "auto" (default), "none", "monotonic" or "latest"
+//! * `timeout-ms` - Time to wait for replies before sending EOS (default: 10000)
+//! * `apply-buffer-meta` - Apply buffer timing metadata from replies (default: true)
+//! * `max-buffer-size` - Drop replies larger than this many bytes (default: 0 = unlimited)
+//!   - Also caps the decompressed size of compressed replies
+//! * `connectivity-poll-ms` - Interval of the session connectivity check (default: 1000, 0 = off)
+//!   - Drives the `session-disconnected` and `session-reconnected` signals
+//! * `session-group` - Share a session with other elements in the same group (optional)
+//! * `session-group-linger-ms` - Keep the group session open this long once released (default: 0)
+//!
+//! Error replies are counted in `errors` and posted as `zenoh-error` bus messages;
+//! they don't stop the stream.
+//!
+//! ## Example Pipelines
+//!
+//! ```bash
+//! # Fetch the latest JPEG snapshot served by zenohqueryablesrc and save it
+//! gst-launch-1.0 zenohget key-expr=camera/snapshot ! filesink location=snapshot.jpg
+//! ```
+//!
+//! [`zenohqueryablesrc`]: crate::zenohqueryablesrc
+
+use gst::glib;
+use gst::prelude::*;
+use gst::subclass::prelude::ObjectSubclassIsExt;
+
+use crate::qos::{ZenohConsolidation, ZenohQueryTarget};
+
+pub mod imp;
+
+glib::wrapper! {
+    /// A GStreamer source element that queries Zenoh once and pushes the replies.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use gst::prelude::*;
+    /// use gstzenoh::zenohget::ZenohGet;
+    /// use gstzenoh::qos::ZenohQueryTarget;
+    ///
+    /// gst::init().unwrap();
+    /// gstzenoh::plugin_register_static().unwrap();
+    ///
+    /// let get = ZenohGet::builder("camera/snapshot")
+    ///     .target(ZenohQueryTarget::All)
+    ///     .timeout_ms(2000)
+    ///     .build();
+    /// ```
+    pub struct ZenohGet(ObjectSubclass<imp::ZenohGet>) @extends gst_base::PushSrc, gst_base::BaseSrc, gst::Element, gst::Object;
+}
+
+unsafe impl Send for ZenohGet {}
+unsafe impl Sync for ZenohGet {}
+
+impl Default for ZenohGet {
+    fn default() -> Self {
+        gst::Object::builder().build().unwrap()
+    }
+}
+
+impl ZenohGet {
+    /// Creates a new ZenohGet querying the specified selector.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_expr` - The Zenoh selector to query
+    pub fn new(key_expr: &str) -> Self {
+        gst::Object::builder()
+            .property("key-expr", key_expr)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns a builder for creating a ZenohGet with custom configuration.
+    pub fn builder(key_expr: &str) -> ZenohGetBuilder {
+        ZenohGetBuilder::new(key_expr)
+    }
+
+    // -------------------------------------------------------------------------
+    // Property Setters
+    // -------------------------------------------------------------------------
+
+    /// Sets the Zenoh selector to query.
+    pub fn set_key_expr(&self, key_expr: &str) {
+        self.set_property("key-expr", key_expr);
+    }
+
+    /// Sets the path to a Zenoh configuration file.
+    ///
+    /// The file should be in JSON5 format.
+    pub fn set_config(&self, config_path: &str) {
+        self.set_property("config", config_path);
+    }
+
+    /// Sets the Zenoh configuration inline, as a JSON5 string.
+    ///
+    /// Takes precedence over [`set_config`](Self::set_config), which is handy
+    /// where mounting a configuration file is impractical (containers,
+    /// gst-launch). Scouting, TLS and authentication properties still apply
+    /// on top. Malformed JSON5 makes the start fail with the parse error.
+    pub fn set_config_json5(&self, json5: &str) {
+        self.set_property("config-json5", json5);
+    }
+
+    /// Enables or disables multicast scouting.
+    ///
+    /// Disable it on networks where multicast doesn't work, without having
+    /// to write a configuration file. Must be set before the element is started.
+    pub fn set_scouting(&self, enabled: bool) {
+        self.set_property("scouting", enabled);
+    }
+
+    /// Sets the queryables to target.
+    ///
+    /// - [`ZenohQueryTarget::BestMatching`]: The nearest complete queryable, or all matching ones (default)
+    /// - [`ZenohQueryTarget::All`]: All matching queryables
+    /// - [`ZenohQueryTarget::AllComplete`]: All complete queryables
+    pub fn set_target(&self, target: ZenohQueryTarget) {
+        self.set_property("target", target);
+    }
+
+    /// Sets the reply consolidation mode.
+    ///
+    /// - [`ZenohConsolidation::Auto`]: Let Zenoh decide (default)
+    /// - [`ZenohConsolidation::None`]: Deliver every reply
+    /// - [`ZenohConsolidation::Monotonic`]: Drop replies older than one already delivered for the same key
+    /// - [`ZenohConsolidation::Latest`]: Only deliver the latest reply per key
+    pub fn set_consolidation(&self, consolidation: ZenohConsolidation) {
+        self.set_property("consolidation", consolidation);
+    }
+
+    /// Sets the query timeout in milliseconds.
+    pub fn set_timeout_ms(&self, timeout: u64) {
+        self.set_property("timeout-ms", timeout);
+    }
+
+    /// Enables or disables applying buffer timing metadata from replies.
+    pub fn set_apply_buffer_meta(&self, apply: bool) {
+        self.set_property("apply-buffer-meta", apply);
+    }
+
+    /// Sets the maximum accepted reply payload size in bytes (0 = unlimited).
+    ///
+    /// Larger replies are dropped before any buffer is allocated for them and
+    /// counted as errors. Compressed replies that decompress to more fail to decode.
+    pub fn set_max_buffer_size(&self, size: u64) {
+        self.set_property("max-buffer-size", size);
+    }
+
+    /// Sets how often the session is checked for router and peer connections,
+    /// for the `session-disconnected` and `session-reconnected` signals
+    /// (0 = disabled, at most 60000). Takes effect on the next start.
+    pub fn set_connectivity_poll_ms(&self, interval: u32) {
+        self.set_property("connectivity-poll-ms", interval);
+    }
+
+    /// Sets a shared Zenoh session for this element.
+    ///
+    /// The session must be set before the element transitions to the PLAYING state.
+    pub fn set_session(&self, session: zenoh::Session) {
+        self.imp().set_external_session(session);
+    }
+
+    /// Sets the session group name for sharing sessions across elements.
+    pub fn set_session_group(&self, group: &str) {
+        self.set_property("session-group", group);
+    }
+
+    /// Sets how long in milliseconds the session-group session stays open after
+    /// this element was the last one to release it (0 = close right away).
+    pub fn set_session_group_linger_ms(&self, linger: u32) {
+        self.set_property("session-group-linger-ms", linger);
+    }
+
+    // -------------------------------------------------------------------------
+    // Property Getters
+    // -------------------------------------------------------------------------
+
+    /// Returns the Zenoh selector being queried.
+    pub fn key_expr(&self) -> String {
+        self.property("key-expr")
+    }
+
+    /// Returns the path to the Zenoh configuration file, if set.
+    pub fn config(&self) -> Option<String> {
+        self.property("config")
+    }
+
+    /// Returns the inline JSON5 configuration, if set.
+    pub fn config_json5(&self) -> Option<String> {
+        self.property("config-json5")
+    }
+
+    /// Returns whether multicast scouting is enabled.
+    pub fn scouting(&self) -> bool {
+        self.property("scouting")
+    }
+
+    /// Returns the query target.
+    pub fn target(&self) -> ZenohQueryTarget {
+        self.property("target")
+    }
+
+    /// Returns the reply consolidation mode.
+    pub fn consolidation(&self) -> ZenohConsolidation {
+        self.property("consolidation")
+    }
+
+    /// Returns the query timeout in milliseconds.
+    pub fn timeout_ms(&self) -> u64 {
+        self.property("timeout-ms")
+    }
+
+    /// Returns whether buffer timing metadata is being applied.
+    pub fn apply_buffer_meta(&self) -> bool {
+        self.property("apply-buffer-meta")
+    }
+
+    /// Returns the maximum accepted reply payload size in bytes (0 = unlimited).
+    pub fn max_buffer_size(&self) -> u64 {
+        self.property("max-buffer-size")
+    }
+
+    /// Returns the session connectivity check interval in milliseconds (0 = disabled).
+    pub fn connectivity_poll_ms(&self) -> u32 {
+        self.property("connectivity-poll-ms")
+    }
+
+    /// Returns the session group name, if set.
+    pub fn session_group(&self) -> Option<String> {
+        self.property("session-group")
+    }
+
+    /// Returns how long the session-group session lingers once released, in milliseconds.
+    pub fn session_group_linger_ms(&self) -> u32 {
+        self.property("session-group-linger-ms")
+    }
+
+    // -------------------------------------------------------------------------
+    // Actions
+    // -------------------------------------------------------------------------
+
+    /// Logs a snapshot of the state, settings and statistics at INFO level.
+    ///
+    /// The block goes to the `zenohget` debug category, so `GST_DEBUG=zenohget:4`
+    /// shows it without per-buffer traces. Same as emitting `dump-state`.
+    pub fn dump_state(&self) {
+        self.emit_by_name::<()>("dump-state", &[]);
+    }
+
+    // -------------------------------------------------------------------------
+    // Statistics (read-only)
+    // -------------------------------------------------------------------------
+
+    /// Returns the total number of reply payload bytes received.
+    pub fn bytes_received(&self) -> u64 {
+        self.property("bytes-received")
+    }
+
+    /// Returns the total number of successful replies received.
+    pub fn replies_received(&self) -> u64 {
+        self.property("replies-received")
+    }
+
+    /// Returns the total number of error replies and processing errors.
+    pub fn errors(&self) -> u64 {
+        self.property("errors")
+    }
+}
+
+impl TryFrom<gst::Element> for ZenohGet {
+    type Error = gst::Element;
+
+    /// Attempts to convert a generic GStreamer element to a ZenohGet.
+    ///
+    /// Returns the original element as an error if it's not a ZenohGet.
+    fn try_from(element: gst::Element) -> Result<Self, Self::Error> {
+        element.downcast()
+    }
+}
+
+/// Builder for creating a [`ZenohGet`] with custom configuration.
+pub struct ZenohGetBuilder {
+    key_expr: String,
+    config: Option<String>,
+    config_json5: Option<String>,
+    scouting: Option<bool>,
+    target: Option<ZenohQueryTarget>,
+    consolidation: Option<ZenohConsolidation>,
+    timeout_ms: Option<u64>,
+    apply_buffer_meta: Option<bool>,
+    max_buffer_size: Option<u64>,
+    connectivity_poll_ms: Option<u32>,
+    session: Option<zenoh::Session>,
+    session_group: Option<String>,
+    session_group_linger_ms: Option<u32>,
+}
+
+impl ZenohGetBuilder {
+    /// Creates a new builder with the required selector.
+    pub fn new(key_expr: &str) -> Self {
+        Self {
+            key_expr: key_expr.to_string(),
+            config: None,
+            config_json5: None,
+            scouting: None,
+            target: None,
+            consolidation: None,
+            timeout_ms: None,
+            apply_buffer_meta: None,
+            max_buffer_size: None,
+            connectivity_poll_ms: None,
+            session: None,
+            session_group: None,
+            session_group_linger_ms: None,
+        }
+    }
+
+    /// Sets the path to a Zenoh configuration file.
+    pub fn config(mut self, path: &str) -> Self {
+        self.config = Some(path.to_string());
+        self
+    }
+
+    /// Sets the Zenoh configuration inline, as a JSON5 string (overrides `config`).
+    pub fn config_json5(mut self, json5: &str) -> Self {
+        self.config_json5 = Some(json5.to_string());
+        self
+    }
+
+    /// Enables or disables multicast scouting (default: true).
+    pub fn scouting(mut self, enabled: bool) -> Self {
+        self.scouting = Some(enabled);
+        self
+    }
+
+    /// Sets the query target.
+    pub fn target(mut self, target: ZenohQueryTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sets the reply consolidation mode.
+    pub fn consolidation(mut self, consolidation: ZenohConsolidation) -> Self {
+        self.consolidation = Some(consolidation);
+        self
+    }
+
+    /// Sets the query timeout in milliseconds.
+    pub fn timeout_ms(mut self, timeout: u64) -> Self {
+        self.timeout_ms = Some(timeout);
+        self
+    }
+
+    /// Enables or disables applying buffer timing metadata.
+    pub fn apply_buffer_meta(mut self, apply: bool) -> Self {
+        self.apply_buffer_meta = Some(apply);
+        self
+    }
+
+    /// Sets the maximum accepted reply payload size in bytes (0 = unlimited).
+    pub fn max_buffer_size(mut self, size: u64) -> Self {
+        self.max_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets how often the session connectivity is checked (default: 1000, 0 = disabled).
+    pub fn connectivity_poll_ms(mut self, interval: u32) -> Self {
+        self.connectivity_poll_ms = Some(interval);
+        self
+    }
+
+    /// Sets a shared Zenoh session for this element.
+    pub fn session(mut self, session: zenoh::Session) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// Sets the session group name for sharing sessions across elements.
+    pub fn session_group(mut self, group: &str) -> Self {
+        self.session_group = Some(group.to_string());
+        self
+    }
+
+    /// Sets how long the session-group session lingers once released (default: 0 ms).
+    pub fn session_group_linger_ms(mut self, linger: u32) -> Self {
+        self.session_group_linger_ms = Some(linger);
+        self
+    }
+
+    /// Builds the ZenohGet after checking the key expression part of the
+    /// selector (before any `?parameters`), so a malformed one is reported
+    /// here instead of when the query is sent.
+    pub fn try_build(self) -> Result<ZenohGet, String> {
+        let key_expr = self.key_expr.split_once('?').map_or(&*self.key_expr, |(k, _)| k);
+        crate::utils::validate_key_expr(key_expr)?;
+        Ok(self.build())
+    }
+
+    /// Builds the ZenohGet with the configured properties.
+    pub fn build(self) -> ZenohGet {
+        let mut builder = gst::Object::builder::<ZenohGet>().property("key-expr", &self.key_expr);
+
+        if let Some(config) = self.config {
+            builder = builder.property("config", config);
+        }
+        if let Some(json5) = self.config_json5 {
+            builder = builder.property("config-json5", json5);
+        }
+        if let Some(scouting) = self.scouting {
+            builder = builder.property("scouting", scouting);
+        }
+        if let Some(target) = self.target {
+            builder = builder.property("target", target);
+        }
+        if let Some(consolidation) = self.consolidation {
+            builder = builder.property("consolidation", consolidation);
+        }
+        if let Some(timeout) = self.timeout_ms {
+            builder = builder.property("timeout-ms", timeout);
+        }
+        if let Some(apply) = self.apply_buffer_meta {
+            builder = builder.property("apply-buffer-meta", apply);
+        }
+        if let Some(size) = self.max_buffer_size {
+            builder = builder.property("max-buffer-size", size);
+        }
+        if let Some(interval) = self.connectivity_poll_ms {
+            builder = builder.property("connectivity-poll-ms", interval);
+        }
+        if let Some(ref sg) = self.session_group {
+            builder = builder.property("session-group", sg);
+        }
+        if let Some(linger) = self.session_group_linger_ms {
+            builder = builder.property("session-group-linger-ms", linger);
+        }
+
+        let get: ZenohGet = builder.build().unwrap();
+
+        // Set the session directly (can't be done via properties)
+        if let Some(session) = self.session {
+            get.set_session(session);
+        }
+
+        get
+    }
+}
+
+pub fn register(plugin: &gst::Plugin) -> Result<(), glib::BoolError> {
+    gst::Element::register(
+        Some(plugin),
+        "zenohget",
+        gst::Rank::NONE,
+        ZenohGet::static_type(),
+    )
+}
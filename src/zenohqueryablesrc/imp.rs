@@ -0,0 +1,577 @@
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+
+use gst::{glib, prelude::*, subclass::prelude::*};
+use gst_base::subclass::prelude::*;
+use zenoh::Wait;
+
+use crate::error::{ErrorHandling, ZenohError};
+use crate::metadata::MetadataBuilder;
+use crate::session::{ConnectivityMonitor, SessionConfig, SessionWrapper};
+
+static CAT: LazyLock<gst::DebugCategory> = LazyLock::new(|| {
+    gst::DebugCategory::new(
+        "zenohqueryablesrc",
+        gst::DebugColorFlags::empty(),
+        Some("Zenoh Queryable Source"),
+    )
+});
+
+/// Statistics tracking for ZenohQueryableSrc
+#[derive(Debug, Clone, Default)]
+struct Statistics {
+    queries_received: u64,
+    replies_sent: u64,
+    bytes_sent: u64,
+    errors: u64,
+}
+
+/// Latest buffer received from upstream, served as the reply to every query.
+#[derive(Default)]
+struct Snapshot {
+    buffer: Option<gst::Buffer>,
+    caps: Option<gst::Caps>,
+}
+
+struct Started {
+    // Keeping session field to maintain ownership and prevent session from being dropped
+    // while the queryable is still in use. This can be either owned or shared.
+    _session: SessionWrapper,
+    // Undeclared when dropped
+    _queryable: zenoh::query::Queryable<()>,
+    /// Latest buffer and caps (shared with the queryable callback)
+    snapshot: Arc<Mutex<Snapshot>>,
+    /// Statistics tracking (shared with the queryable callback)
+    stats: Arc<Mutex<Statistics>>,
+    /// Emits the session signals (when `connectivity-poll-ms` is set)
+    _connectivity: Option<ConnectivityMonitor>,
+}
+
+#[derive(Default)]
+enum State {
+    #[default]
+    Stopped,
+    Started(Started),
+}
+
+impl State {
+    fn name(&self) -> &'static str {
+        match self {
+            State::Stopped => "Stopped",
+            State::Started(_) => "Started",
+        }
+    }
+}
+
+/// Configuration settings for the ZenohQueryableSrc element.
+#[derive(Debug)]
+struct Settings {
+    /// Zenoh key expression the queryable answers on (required)
+    key_expr: String,
+    /// Optional path to Zenoh configuration file
+    config_file: Option<String>,
+    /// Inline Zenoh configuration (JSON5), takes precedence over `config_file`
+    config_json5: Option<String>,
+    /// Multicast scouting for peer discovery (default: true)
+    scouting: bool,
+    /// Attach the buffer caps to every reply (default: true)
+    send_caps: bool,
+    /// Attach buffer timing metadata (PTS, DTS, duration, flags) to every reply (default: true)
+    send_buffer_meta: bool,
+    /// Session connectivity check interval for the session signals (0 = disabled)
+    connectivity_poll_ms: u32,
+    /// Optional external Zenoh session to share with other elements (Rust API)
+    external_session: Option<zenoh::Session>,
+    /// Session group name for sharing sessions via property (gst-launch compatible)
+    session_group: Option<String>,
+    /// How long the group session stays open after its last element released it
+    session_group_linger_ms: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            key_expr: String::new(),
+            config_file: None,
+            config_json5: None,
+            scouting: true,
+            send_caps: true,
+            send_buffer_meta: true,
+            connectivity_poll_ms: crate::session::DEFAULT_CONNECTIVITY_POLL_MS,
+            external_session: None,
+            session_group: None,
+            session_group_linger_ms: crate::session::DEFAULT_SESSION_GROUP_LINGER_MS,
+        }
+    }
+}
+
+/// GStreamer ZenohQueryableSrc element implementation.
+///
+/// Keeps the latest buffer received from upstream and serves it as the
+/// reply payload for every Zenoh query matching the configured key expression.
+#[derive(Default)]
+pub struct ZenohQueryableSrc {
+    settings: Mutex<Settings>,
+    state: Mutex<State>,
+}
+
+impl ZenohQueryableSrc {
+    /// Logs the state, settings and statistics at INFO (`dump-state` signal).
+    fn dump_state(&self) {
+        let state = self.state.lock().unwrap().name();
+        crate::utils::dump_state(self.obj().upcast_ref(), *CAT, state);
+    }
+
+    /// Sets the external Zenoh session to use for this element.
+    ///
+    /// This is called from the public API to enable session sharing.
+    pub(crate) fn set_external_session(&self, session: zenoh::Session) {
+        let mut settings = self.settings.lock().unwrap();
+        settings.external_session = Some(session);
+    }
+
+    /// Answers a single query with the latest snapshot.
+    fn reply(
+        query: &zenoh::query::Query,
+        reply_key: &zenoh::key_expr::KeyExpr<'static>,
+        snapshot: &Mutex<Snapshot>,
+        stats: &Mutex<Statistics>,
+        send_caps: bool,
+        send_buffer_meta: bool,
+    ) {
+        stats.lock().unwrap().queries_received += 1;
+
+        let (buffer, caps) = {
+            let snapshot = snapshot.lock().unwrap();
+            (snapshot.buffer.clone(), snapshot.caps.clone())
+        };
+
+        let Some(buffer) = buffer else {
+            gst::debug!(
+                CAT,
+                "Query on '{}' before any buffer was received",
+                query.key_expr()
+            );
+            if let Err(e) = query.reply_err("no buffer available yet").wait() {
+                gst::warning!(CAT, "Failed to send error reply: {}", e);
+                stats.lock().unwrap().errors += 1;
+            }
+            return;
+        };
+
+        let map = match buffer.map_readable() {
+            Ok(map) => map,
+            Err(_) => {
+                gst::warning!(CAT, "Failed to map buffer for reading");
+                stats.lock().unwrap().errors += 1;
+                return;
+            }
+        };
+
+        let mut metadata_builder = MetadataBuilder::new();
+        if send_caps && let Some(ref caps) = caps {
+            metadata_builder = metadata_builder.caps(caps);
+        }
+        if send_buffer_meta {
+            metadata_builder = metadata_builder.buffer_timing(&buffer);
+        }
+
+        let reply_builder = query.reply(reply_key.clone(), map.as_slice());
+        let result = if let Some(attachment) = metadata_builder.build() {
+            reply_builder.attachment(attachment).wait()
+        } else {
+            reply_builder.wait()
+        };
+
+        let mut stats = stats.lock().unwrap();
+        match result {
+            Ok(_) => {
+                stats.replies_sent += 1;
+                stats.bytes_sent += map.len() as u64;
+            }
+            Err(e) => {
+                gst::warning!(CAT, "Failed to reply on '{}': {}", reply_key, e);
+                stats.errors += 1;
+            }
+        }
+    }
+}
+
+impl GstObjectImpl for ZenohQueryableSrc {}
+
+impl ElementImpl for ZenohQueryableSrc {
+    fn metadata() -> Option<&'static gst::subclass::ElementMetadata> {
+        static ELEMENT_METADATA: LazyLock<gst::subclass::ElementMetadata> = LazyLock::new(|| {
+            gst::subclass::ElementMetadata::new(
+                "Zenoh Queryable Source",
+                "Sink/Network/Protocol",
+                "Serves the latest pipeline buffer as the reply to Zenoh queries (request/reply pull streaming)",
+                "Marc Pardo <p13marc@gmail.com>",
+            )
+        });
+        Some(&*ELEMENT_METADATA)
+    }
+
+    fn pad_templates() -> &'static [gst::PadTemplate] {
+        static PAD_TEMPLATES: LazyLock<Vec<gst::PadTemplate>> = LazyLock::new(|| {
+            let sink_pad_template = gst::PadTemplate::new(
+                "sink",
+                gst::PadDirection::Sink,
+                gst::PadPresence::Always,
+                &gst::Caps::new_any(),
+            )
+            .unwrap();
+
+            vec![sink_pad_template]
+        });
+
+        PAD_TEMPLATES.as_ref()
+    }
+}
+
+impl ObjectImpl for ZenohQueryableSrc {
+    fn signals() -> &'static [glib::subclass::Signal] {
+        static SIGNALS: LazyLock<Vec<glib::subclass::Signal>> = LazyLock::new(|| {
+            vec![
+                glib::subclass::Signal::builder("session-disconnected").build(),
+                glib::subclass::Signal::builder("session-reconnected").build(),
+                glib::subclass::Signal::builder("dump-state")
+                    .action()
+                    .class_handler(|args| {
+                        let element = args[0]
+                            .get::<super::ZenohQueryableSrc>()
+                            .expect("signal arg type checked upstream");
+                        element.imp().dump_state();
+                        None
+                    })
+                    .build(),
+            ]
+        });
+        SIGNALS.as_ref()
+    }
+
+    fn properties() -> &'static [glib::ParamSpec] {
+        static PROPERTIES: LazyLock<Vec<glib::ParamSpec>> = LazyLock::new(|| {
+            vec![
+                glib::ParamSpecString::builder("key-expr")
+                    .nick("Zenoh Key Expression")
+                    .blurb("Zenoh key expression to answer queries on. Replies are sent on this key, so it must not contain wildcards.")
+                    .build(),
+                glib::ParamSpecString::builder("config")
+                    .nick("Zenoh Configuration")
+                    .blurb("Path to Zenoh configuration file (JSON5 format)")
+                    .build(),
+                glib::ParamSpecString::builder("config-json5")
+                    .nick("Zenoh Configuration (JSON5)")
+                    .blurb("Inline Zenoh configuration in JSON5 format (e.g. '{mode: \"client\", connect: {endpoints: [\"tcp/10.0.0.1:7447\"]}}'), taking precedence over config; scouting, TLS and authentication properties are applied on top")
+                    .build(),
+                glib::ParamSpecBoolean::builder("scouting")
+                    .nick("Multicast Scouting")
+                    .blurb("Discover peers via multicast scouting; disable on networks where multicast doesn't work and rely on configured endpoints")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecBoolean::builder("send-caps")
+                    .nick("Send Caps")
+                    .blurb("Attach the buffer caps to every reply so clients can negotiate the format")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecBoolean::builder("send-buffer-meta")
+                    .nick("Send Buffer Metadata")
+                    .blurb("Attach buffer timing metadata (PTS, DTS, duration, flags) to every reply")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecUInt::builder("connectivity-poll-ms")
+                    .nick("Connectivity Poll Interval")
+                    .blurb("How often the session is checked for router and peer connections, emitting session-disconnected when the last one is lost and session-reconnected when one is back (0 = disabled)")
+                    .default_value(crate::session::DEFAULT_CONNECTIVITY_POLL_MS)
+                    .maximum(60_000)
+                    .build(),
+                glib::ParamSpecString::builder("session-group")
+                    .nick("Session Group")
+                    .blurb("Name of the session group for sharing Zenoh sessions across elements. Elements with the same group name share a single session.")
+                    .build(),
+                glib::ParamSpecUInt::builder("session-group-linger-ms")
+                    .nick("Session Group Linger")
+                    .blurb("Keep the session-group session open this many milliseconds after this element was the last one to release it, so elements briefly overlapping reuse it instead of reopening one (0 = close right away)")
+                    .maximum(crate::session::MAX_SESSION_GROUP_LINGER_MS)
+                    .default_value(crate::session::DEFAULT_SESSION_GROUP_LINGER_MS)
+                    .build(),
+                // Statistics (read-only)
+                glib::ParamSpecUInt64::builder("queries-received")
+                    .nick("Queries Received")
+                    .blurb("Total queries received since element started")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("replies-sent")
+                    .nick("Replies Sent")
+                    .blurb("Total replies sent since element started")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("bytes-sent")
+                    .nick("Bytes Sent")
+                    .blurb("Total reply payload bytes sent since element started")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("errors")
+                    .nick("Errors")
+                    .blurb("Total number of errors encountered")
+                    .read_only()
+                    .build(),
+            ]
+        });
+
+        PROPERTIES.as_ref()
+    }
+
+    fn set_property(&self, _id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+        let state = self.state.lock().unwrap();
+        if matches!(*state, State::Started(_))
+            && matches!(
+                pspec.name(),
+                "key-expr"
+                    | "config"
+                    | "config-json5"
+                    | "scouting"
+                    | "session-group"
+                    | "session-group-linger-ms"
+                    | "connectivity-poll-ms"
+            )
+        {
+            gst::warning!(
+                CAT,
+                imp = self,
+                "Cannot change property '{}' while element is started",
+                pspec.name()
+            );
+            return;
+        }
+        drop(state);
+
+        let mut settings = self.settings.lock().unwrap();
+
+        match pspec.name() {
+            "key-expr" => {
+                settings.key_expr = value.get::<String>().expect("type checked upstream");
+            }
+            "config" => {
+                settings.config_file = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "config-json5" => {
+                settings.config_json5 = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "scouting" => {
+                settings.scouting = value.get::<bool>().expect("type checked upstream");
+            }
+            "send-caps" => {
+                settings.send_caps = value.get::<bool>().expect("type checked upstream");
+            }
+            "send-buffer-meta" => {
+                settings.send_buffer_meta = value.get::<bool>().expect("type checked upstream");
+            }
+            "connectivity-poll-ms" => {
+                settings.connectivity_poll_ms = value.get::<u32>().expect("type checked upstream");
+            }
+            "session-group" => {
+                settings.session_group = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "session-group-linger-ms" => {
+                settings.session_group_linger_ms =
+                    value.get::<u32>().expect("type checked upstream");
+            }
+            name => {
+                gst::warning!(CAT, imp = self, "Unknown property: {}", name);
+            }
+        }
+    }
+
+    fn property(&self, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+        match pspec.name() {
+            "key-expr" => self.settings.lock().unwrap().key_expr.to_value(),
+            "config" => self.settings.lock().unwrap().config_file.to_value(),
+            "config-json5" => self.settings.lock().unwrap().config_json5.to_value(),
+            "scouting" => self.settings.lock().unwrap().scouting.to_value(),
+            "send-caps" => self.settings.lock().unwrap().send_caps.to_value(),
+            "send-buffer-meta" => self.settings.lock().unwrap().send_buffer_meta.to_value(),
+            "connectivity-poll-ms" => self.settings.lock().unwrap().connectivity_poll_ms.to_value(),
+            "session-group" => self.settings.lock().unwrap().session_group.to_value(),
+            "session-group-linger-ms" => {
+                self.settings.lock().unwrap().session_group_linger_ms.to_value()
+            }
+            "queries-received" | "replies-sent" | "bytes-sent" | "errors" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    let stats = started.stats.lock().unwrap();
+                    match pspec.name() {
+                        "queries-received" => stats.queries_received.to_value(),
+                        "replies-sent" => stats.replies_sent.to_value(),
+                        "bytes-sent" => stats.bytes_sent.to_value(),
+                        "errors" => stats.errors.to_value(),
+                        _ => unreachable!(),
+                    }
+                } else {
+                    0u64.to_value()
+                }
+            }
+            name => {
+                gst::warning!(CAT, imp = self, "Unknown property: {}", name);
+                "".to_value()
+            }
+        }
+    }
+}
+
+#[glib::object_subclass]
+impl ObjectSubclass for ZenohQueryableSrc {
+    const NAME: &'static str = "GstZenohQueryableSrc";
+    type Type = super::ZenohQueryableSrc;
+    type ParentType = gst_base::BaseSink;
+}
+
+impl BaseSinkImpl for ZenohQueryableSrc {
+    fn start(&self) -> Result<(), gst::ErrorMessage> {
+        let mut state = self.state.lock().unwrap();
+        if matches!(*state, State::Started(_)) {
+            return Ok(());
+        }
+
+        let settings = self.settings.lock().unwrap();
+        let key_expr = settings.key_expr.clone();
+        let session_config = SessionConfig {
+            config_file: settings.config_file.clone(),
+            config_json5: settings.config_json5.clone(),
+            scouting: settings.scouting,
+            ..Default::default()
+        };
+        let send_caps = settings.send_caps;
+        let send_buffer_meta = settings.send_buffer_meta;
+        let connectivity_poll_ms = settings.connectivity_poll_ms;
+        let external_session = settings.external_session.clone();
+        let session_group = settings.session_group.clone();
+        let session_group_linger = Duration::from_millis(settings.session_group_linger_ms.into());
+        drop(settings);
+
+        if key_expr.is_empty() {
+            return Err(gst::error_msg!(
+                gst::ResourceError::Settings,
+                ["Key expression is required"]
+            ));
+        }
+
+        let reply_key = zenoh::key_expr::KeyExpr::try_from(key_expr.clone()).map_err(|e| {
+            ZenohError::KeyExpr {
+                key_expr: key_expr.clone(),
+                reason: e.to_string(),
+            }
+            .to_error_message()
+        })?;
+
+        // Determine session source: external (Rust API) > session-group (property) > new session
+        let session_wrapper = if let Some(shared_session) = external_session {
+            gst::debug!(CAT, imp = self, "Using external shared session (Rust API)");
+            SessionWrapper::Shared(shared_session)
+        } else if let Some(ref group) = session_group {
+            gst::debug!(CAT, imp = self, "Using session group '{}'", group);
+            let session =
+                crate::session::get_or_create_session(group, &session_config, session_group_linger)
+                    .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            SessionWrapper::Group(session)
+        } else {
+            gst::debug!(CAT, imp = self, "Creating new Zenoh session");
+            if let Some(ref path) = session_config.config_file
+                && !path.is_empty()
+            {
+                gst::debug!(CAT, imp = self, "Loading Zenoh config from {}", path);
+            }
+            let session = session_config
+                .open()
+                .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            SessionWrapper::Owned(session)
+        };
+
+        let snapshot = Arc::new(Mutex::new(Snapshot::default()));
+        let stats = Arc::new(Mutex::new(Statistics::default()));
+
+        gst::debug!(
+            CAT,
+            imp = self,
+            "Declaring queryable with key_expr='{}'",
+            key_expr
+        );
+
+        let queryable = {
+            let snapshot = snapshot.clone();
+            let stats = stats.clone();
+            session_wrapper
+                .as_session()
+                .declare_queryable(reply_key.clone())
+                .callback(move |query| {
+                    Self::reply(
+                        &query,
+                        &reply_key,
+                        &snapshot,
+                        &stats,
+                        send_caps,
+                        send_buffer_meta,
+                    );
+                })
+                .wait()
+                .map_err(|e| ZenohError::Init(e).to_error_message())?
+        };
+
+        let connectivity = ConnectivityMonitor::start(
+            session_wrapper.as_session(),
+            self.obj().upcast_ref(),
+            connectivity_poll_ms,
+        );
+
+        *state = State::Started(Started {
+            _session: session_wrapper,
+            _queryable: queryable,
+            snapshot,
+            stats,
+            _connectivity: connectivity,
+        });
+
+        gst::debug!(CAT, imp = self, "ZenohQueryableSrc started");
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), gst::ErrorMessage> {
+        let mut state = self.state.lock().unwrap();
+        // Dropping Started undeclares the queryable
+        *state = State::Stopped;
+        gst::debug!(CAT, imp = self, "ZenohQueryableSrc stopped");
+        Ok(())
+    }
+
+    fn render(&self, buffer: &gst::Buffer) -> Result<gst::FlowSuccess, gst::FlowError> {
+        let state = self.state.lock().unwrap();
+        let State::Started(ref started) = *state else {
+            gst::element_imp_error!(self, gst::CoreError::Failed, ["Not started yet"]);
+            return Err(gst::FlowError::Error);
+        };
+
+        let caps = self.obj().sink_pad().current_caps();
+
+        // Keep a reference to the latest buffer; queries are answered from it
+        let mut snapshot = started.snapshot.lock().unwrap();
+        snapshot.buffer = Some(buffer.clone());
+        snapshot.caps = caps;
+
+        gst::trace!(
+            CAT,
+            imp = self,
+            "Updated snapshot ({} bytes, PTS {:?})",
+            buffer.size(),
+            buffer.pts()
+        );
+
+        Ok(gst::FlowSuccess::Ok)
+    }
+}
@@ -0,0 +1,451 @@
+//! # ZenohQueryableSrc Element
+//!
+//! The ZenohQueryableSrc element serves pipeline data over Zenoh's request/reply
+//! model. Instead of pushing every buffer to subscribers like `zenohsink`, it
+//! keeps the latest buffer it received from upstream and answers each Zenoh
+//! query on its key expression with that buffer.
+//!
+//! This is useful for pull-based consumers such as dashboards or thumbnail
+//! services that only need the current frame on demand.
+//!
+//! Although it is the *source* of query replies from the Zenoh point of view,
+//! in the GStreamer pipeline it is a sink: it consumes buffers and produces
+//! nothing downstream.
+//!
+//! ## Features
+//!
+//! * **Pull Streaming**: Clients fetch the latest buffer with `session.get()`
+//! * **Caps Transmission**: Replies carry caps so clients can interpret the payload
+//! * **Buffer Metadata**: PTS, DTS, duration and flags are attached to every reply
+//! * **Session Sharing**: Support for shared Zenoh sessions across elements
+//!
+//! ## Properties
+//!
+//! * `key-expr` - Zenoh key expression to answer queries on (required)
+//!   - Replies are sent on this key, so it must not contain wildcards
+//! * `config` - Path to Zenoh configuration file (optional)
+//! * `config-json5` - Inline Zenoh configuration in JSON5 (optional, overrides `config`)
+//! * `scouting` - Discover peers via multicast scouting (default: true)
+//!   - Disable on networks where multicast doesn't work; peers are then reached
+//!     through the endpoints of the configuration
+//! * `send-caps` - Attach the buffer caps to every reply (default: true)
+//! * `send-buffer-meta` - Attach buffer timing metadata to every reply (default: true)
+//! * `connectivity-poll-ms` - Interval of the session connectivity check (default: 1000, 0 = off)
+//!   - Drives the `session-disconnected` and `session-reconnected` signals
+//! * `session-group` - Share a session with other elements in the same group (optional)
+//! * `session-group-linger-ms` - Keep the group session open this long once released (default: 0)
+//!
+//! Queries arriving before the first buffer receive an error reply.
+//!
+//! ## Example Pipelines
+//!
+//! ```bash
+//! # Serve the latest JPEG snapshot of a camera
+//! gst-launch-1.0 v4l2src ! videoconvert ! jpegenc ! \
+//!   zenohqueryablesrc key-expr=camera/snapshot
+//!
+//! # Fetch it from the command line with the zenoh CLI tools
+//! z_get -s camera/snapshot
+//! ```
+
+use gst::glib;
+use gst::prelude::*;
+use gst::subclass::prelude::ObjectSubclassIsExt;
+
+pub mod imp;
+
+glib::wrapper! {
+    /// A GStreamer sink element that answers Zenoh queries with the latest buffer.
+    ///
+    /// This element declares a Zenoh queryable on its key expression and
+    /// replies to every query with the most recent buffer received from upstream.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use gst::prelude::*;
+    /// use gstzenoh::zenohqueryablesrc::ZenohQueryableSrc;
+    ///
+    /// gst::init().unwrap();
+    /// gstzenoh::plugin_register_static().unwrap();
+    ///
+    /// // Create with the builder pattern
+    /// let queryable = ZenohQueryableSrc::builder("camera/snapshot")
+    ///     .send_caps(true)
+    ///     .build();
+    ///
+    /// // Access statistics
+    /// println!("Queries received: {}", queryable.queries_received());
+    /// ```
+    pub struct ZenohQueryableSrc(ObjectSubclass<imp::ZenohQueryableSrc>) @extends gst_base::BaseSink, gst::Element, gst::Object;
+}
+
+unsafe impl Send for ZenohQueryableSrc {}
+unsafe impl Sync for ZenohQueryableSrc {}
+
+impl Default for ZenohQueryableSrc {
+    fn default() -> Self {
+        gst::Object::builder().build().unwrap()
+    }
+}
+
+impl ZenohQueryableSrc {
+    /// Creates a new ZenohQueryableSrc with the specified key expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_expr` - The Zenoh key expression to answer queries on
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use gstzenoh::zenohqueryablesrc::ZenohQueryableSrc;
+    ///
+    /// gst::init().unwrap();
+    /// gstzenoh::plugin_register_static().unwrap();
+    ///
+    /// let queryable = ZenohQueryableSrc::new("camera/snapshot");
+    /// ```
+    pub fn new(key_expr: &str) -> Self {
+        gst::Object::builder()
+            .property("key-expr", key_expr)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns a builder for creating a ZenohQueryableSrc with custom configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_expr` - The Zenoh key expression to answer queries on
+    pub fn builder(key_expr: &str) -> ZenohQueryableSrcBuilder {
+        ZenohQueryableSrcBuilder::new(key_expr)
+    }
+
+    // -------------------------------------------------------------------------
+    // Property Setters
+    // -------------------------------------------------------------------------
+
+    /// Sets the Zenoh key expression to answer queries on.
+    ///
+    /// Must be set before the element is started. Replies are sent on this
+    /// key, so it must not contain wildcards.
+    pub fn set_key_expr(&self, key_expr: &str) {
+        self.set_property("key-expr", key_expr);
+    }
+
+    /// Sets the path to a Zenoh configuration file.
+    ///
+    /// The file should be in JSON5 format.
+    pub fn set_config(&self, config_path: &str) {
+        self.set_property("config", config_path);
+    }
+
+    /// Sets the Zenoh configuration inline, as a JSON5 string.
+    ///
+    /// Takes precedence over [`set_config`](Self::set_config), which is handy
+    /// where mounting a configuration file is impractical (containers,
+    /// gst-launch). Scouting, TLS and authentication properties still apply
+    /// on top. Malformed JSON5 makes the start fail with the parse error.
+    pub fn set_config_json5(&self, json5: &str) {
+        self.set_property("config-json5", json5);
+    }
+
+    /// Enables or disables multicast scouting.
+    ///
+    /// Disable it on networks where multicast doesn't work, without having
+    /// to write a configuration file. Must be set before the element is started.
+    pub fn set_scouting(&self, enabled: bool) {
+        self.set_property("scouting", enabled);
+    }
+
+    /// Enables or disables attaching caps to replies.
+    pub fn set_send_caps(&self, send: bool) {
+        self.set_property("send-caps", send);
+    }
+
+    /// Enables or disables attaching buffer timing metadata to replies.
+    pub fn set_send_buffer_meta(&self, send: bool) {
+        self.set_property("send-buffer-meta", send);
+    }
+
+    /// Sets how often the session is checked for router and peer connections,
+    /// for the `session-disconnected` and `session-reconnected` signals
+    /// (0 = disabled, at most 60000). Takes effect on the next start.
+    pub fn set_connectivity_poll_ms(&self, interval: u32) {
+        self.set_property("connectivity-poll-ms", interval);
+    }
+
+    /// Sets a shared Zenoh session for this element.
+    ///
+    /// This allows multiple elements to share a single Zenoh session,
+    /// reducing network overhead and resource usage. The session must
+    /// be set before the element transitions to the PLAYING state.
+    pub fn set_session(&self, session: zenoh::Session) {
+        self.imp().set_external_session(session);
+    }
+
+    /// Sets the session group name for sharing sessions across elements.
+    ///
+    /// Must be set before the element transitions to the PLAYING state.
+    pub fn set_session_group(&self, group: &str) {
+        self.set_property("session-group", group);
+    }
+
+    /// Sets how long in milliseconds the session-group session stays open after
+    /// this element was the last one to release it (0 = close right away).
+    pub fn set_session_group_linger_ms(&self, linger: u32) {
+        self.set_property("session-group-linger-ms", linger);
+    }
+
+    // -------------------------------------------------------------------------
+    // Property Getters
+    // -------------------------------------------------------------------------
+
+    /// Returns the current Zenoh key expression.
+    pub fn key_expr(&self) -> String {
+        self.property("key-expr")
+    }
+
+    /// Returns the path to the Zenoh configuration file, if set.
+    pub fn config(&self) -> Option<String> {
+        self.property("config")
+    }
+
+    /// Returns the inline JSON5 configuration, if set.
+    pub fn config_json5(&self) -> Option<String> {
+        self.property("config-json5")
+    }
+
+    /// Returns whether multicast scouting is enabled.
+    pub fn scouting(&self) -> bool {
+        self.property("scouting")
+    }
+
+    /// Returns whether caps are attached to replies.
+    pub fn send_caps(&self) -> bool {
+        self.property("send-caps")
+    }
+
+    /// Returns whether buffer timing metadata is attached to replies.
+    pub fn send_buffer_meta(&self) -> bool {
+        self.property("send-buffer-meta")
+    }
+
+    /// Returns the session connectivity check interval in milliseconds (0 = disabled).
+    pub fn connectivity_poll_ms(&self) -> u32 {
+        self.property("connectivity-poll-ms")
+    }
+
+    /// Returns the session group name, if set.
+    pub fn session_group(&self) -> Option<String> {
+        self.property("session-group")
+    }
+
+    /// Returns how long the session-group session lingers once released, in milliseconds.
+    pub fn session_group_linger_ms(&self) -> u32 {
+        self.property("session-group-linger-ms")
+    }
+
+    // -------------------------------------------------------------------------
+    // Actions
+    // -------------------------------------------------------------------------
+
+    /// Logs a snapshot of the state, settings and statistics at INFO level.
+    ///
+    /// The block goes to the `zenohqueryablesrc` debug category, so `GST_DEBUG=zenohqueryablesrc:4`
+    /// shows it without per-buffer traces. Same as emitting `dump-state`.
+    pub fn dump_state(&self) {
+        self.emit_by_name::<()>("dump-state", &[]);
+    }
+
+    // -------------------------------------------------------------------------
+    // Statistics (read-only)
+    // -------------------------------------------------------------------------
+
+    /// Returns the total number of queries received since the element started.
+    pub fn queries_received(&self) -> u64 {
+        self.property("queries-received")
+    }
+
+    /// Returns the total number of replies sent since the element started.
+    pub fn replies_sent(&self) -> u64 {
+        self.property("replies-sent")
+    }
+
+    /// Returns the total number of reply payload bytes sent since the element started.
+    pub fn bytes_sent(&self) -> u64 {
+        self.property("bytes-sent")
+    }
+
+    /// Returns the total number of errors encountered.
+    pub fn errors(&self) -> u64 {
+        self.property("errors")
+    }
+}
+
+impl TryFrom<gst::Element> for ZenohQueryableSrc {
+    type Error = gst::Element;
+
+    /// Attempts to convert a generic GStreamer element to a ZenohQueryableSrc.
+    ///
+    /// Returns the original element as an error if it's not a ZenohQueryableSrc.
+    fn try_from(element: gst::Element) -> Result<Self, Self::Error> {
+        element.downcast()
+    }
+}
+
+/// Builder for creating a [`ZenohQueryableSrc`] with custom configuration.
+///
+/// # Example
+///
+/// ```no_run
+/// use gstzenoh::zenohqueryablesrc::ZenohQueryableSrc;
+///
+/// gst::init().unwrap();
+/// gstzenoh::plugin_register_static().unwrap();
+///
+/// let queryable = ZenohQueryableSrc::builder("camera/snapshot")
+///     .send_buffer_meta(false)
+///     .build();
+/// ```
+pub struct ZenohQueryableSrcBuilder {
+    key_expr: String,
+    config: Option<String>,
+    config_json5: Option<String>,
+    scouting: Option<bool>,
+    send_caps: Option<bool>,
+    send_buffer_meta: Option<bool>,
+    connectivity_poll_ms: Option<u32>,
+    session: Option<zenoh::Session>,
+    session_group: Option<String>,
+    session_group_linger_ms: Option<u32>,
+}
+
+impl ZenohQueryableSrcBuilder {
+    /// Creates a new builder with the required key expression.
+    pub fn new(key_expr: &str) -> Self {
+        Self {
+            key_expr: key_expr.to_string(),
+            config: None,
+            config_json5: None,
+            scouting: None,
+            send_caps: None,
+            send_buffer_meta: None,
+            connectivity_poll_ms: None,
+            session: None,
+            session_group: None,
+            session_group_linger_ms: None,
+        }
+    }
+
+    /// Sets the path to a Zenoh configuration file.
+    pub fn config(mut self, path: &str) -> Self {
+        self.config = Some(path.to_string());
+        self
+    }
+
+    /// Sets the Zenoh configuration inline, as a JSON5 string (overrides `config`).
+    pub fn config_json5(mut self, json5: &str) -> Self {
+        self.config_json5 = Some(json5.to_string());
+        self
+    }
+
+    /// Enables or disables multicast scouting (default: true).
+    pub fn scouting(mut self, enabled: bool) -> Self {
+        self.scouting = Some(enabled);
+        self
+    }
+
+    /// Enables or disables attaching caps to replies.
+    pub fn send_caps(mut self, send: bool) -> Self {
+        self.send_caps = Some(send);
+        self
+    }
+
+    /// Enables or disables attaching buffer timing metadata to replies.
+    pub fn send_buffer_meta(mut self, send: bool) -> Self {
+        self.send_buffer_meta = Some(send);
+        self
+    }
+
+    /// Sets how often the session connectivity is checked (default: 1000, 0 = disabled).
+    pub fn connectivity_poll_ms(mut self, interval: u32) -> Self {
+        self.connectivity_poll_ms = Some(interval);
+        self
+    }
+
+    /// Sets a shared Zenoh session for this element.
+    pub fn session(mut self, session: zenoh::Session) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// Sets the session group name for sharing sessions across elements.
+    pub fn session_group(mut self, group: &str) -> Self {
+        self.session_group = Some(group.to_string());
+        self
+    }
+
+    /// Sets how long the session-group session lingers once released (default: 0 ms).
+    pub fn session_group_linger_ms(mut self, linger: u32) -> Self {
+        self.session_group_linger_ms = Some(linger);
+        self
+    }
+
+    /// Builds the ZenohQueryableSrc after checking the key expression, so a malformed
+    /// one is reported here instead of when the element starts.
+    pub fn try_build(self) -> Result<ZenohQueryableSrc, String> {
+        crate::utils::validate_key_expr(&self.key_expr)?;
+        Ok(self.build())
+    }
+
+    /// Builds the ZenohQueryableSrc with the configured properties.
+    pub fn build(self) -> ZenohQueryableSrc {
+        let mut builder =
+            gst::Object::builder::<ZenohQueryableSrc>().property("key-expr", &self.key_expr);
+
+        if let Some(config) = self.config {
+            builder = builder.property("config", config);
+        }
+        if let Some(json5) = self.config_json5 {
+            builder = builder.property("config-json5", json5);
+        }
+        if let Some(scouting) = self.scouting {
+            builder = builder.property("scouting", scouting);
+        }
+        if let Some(send) = self.send_caps {
+            builder = builder.property("send-caps", send);
+        }
+        if let Some(send) = self.send_buffer_meta {
+            builder = builder.property("send-buffer-meta", send);
+        }
+        if let Some(interval) = self.connectivity_poll_ms {
+            builder = builder.property("connectivity-poll-ms", interval);
+        }
+        if let Some(ref sg) = self.session_group {
+            builder = builder.property("session-group", sg);
+        }
+        if let Some(linger) = self.session_group_linger_ms {
+            builder = builder.property("session-group-linger-ms", linger);
+        }
+
+        let queryable: ZenohQueryableSrc = builder.build().unwrap();
+
+        // Set the session directly (can't be done via properties)
+        if let Some(session) = self.session {
+            queryable.set_session(session);
+        }
+
+        queryable
+    }
+}
+
+pub fn register(plugin: &gst::Plugin) -> Result<(), glib::BoolError> {
+    gst::Element::register(
+        Some(plugin),
+        "zenohqueryablesrc",
+        gst::Rank::NONE,
+        ZenohQueryableSrc::static_type(),
+    )
+}
@@ -0,0 +1,338 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::sync::{LazyLock, Mutex};
+use std::time::Instant;
+
+use gst::{glib, prelude::*, subclass::prelude::*};
+use gst_base::prelude::BaseSinkExt;
+use gst_base::subclass::prelude::*;
+
+use crate::metadata::MetadataBuilder;
+use crate::recording::{self, Record};
+
+static CAT: LazyLock<gst::DebugCategory> = LazyLock::new(|| {
+    gst::DebugCategory::new(
+        "zenohrecord",
+        gst::DebugColorFlags::empty(),
+        Some("Zenoh Record"),
+    )
+});
+
+/// Statistics tracking for ZenohRecord
+#[derive(Debug, Clone, Default)]
+struct Statistics {
+    records_written: u64,
+    bytes_written: u64,
+}
+
+struct Started {
+    writer: BufWriter<File>,
+    /// Arrival of the first record, the origin of the record times
+    first: Option<Instant>,
+    /// Caps written with the last record that carried caps
+    recorded_caps: Option<gst::Caps>,
+    /// Statistics tracking
+    stats: Statistics,
+}
+
+#[derive(Default)]
+enum State {
+    #[default]
+    Stopped,
+    Started(Started),
+}
+
+impl State {
+    fn name(&self) -> &'static str {
+        match self {
+            State::Stopped => "Stopped",
+            State::Started(_) => "Started",
+        }
+    }
+}
+
+/// Configuration settings for the ZenohRecord element.
+#[derive(Debug, Default)]
+struct Settings {
+    /// Path of the recording to write (required)
+    location: Option<String>,
+}
+
+/// GStreamer ZenohRecord element implementation.
+///
+/// Appends every buffer to a recording file together with its arrival time
+/// and the metadata zenohsink would attach to it, for zenohreplay to play back.
+#[derive(Default)]
+pub struct ZenohRecord {
+    settings: Mutex<Settings>,
+    state: Mutex<State>,
+}
+
+impl ZenohRecord {
+    /// Logs the state, settings and statistics at INFO (`dump-state` signal).
+    fn dump_state(&self) {
+        let state = self.state.lock().unwrap().name();
+        crate::utils::dump_state(self.obj().upcast_ref(), *CAT, state);
+    }
+
+    fn flush(&self) -> Result<(), gst::ErrorMessage> {
+        let mut state = self.state.lock().unwrap();
+        if let State::Started(ref mut started) = *state {
+            started.writer.flush().map_err(|e| {
+                gst::error_msg!(
+                    gst::ResourceError::Write,
+                    ["Failed to flush recording: {}", e]
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl GstObjectImpl for ZenohRecord {}
+
+impl ElementImpl for ZenohRecord {
+    fn metadata() -> Option<&'static gst::subclass::ElementMetadata> {
+        static ELEMENT_METADATA: LazyLock<gst::subclass::ElementMetadata> = LazyLock::new(|| {
+            gst::subclass::ElementMetadata::new(
+                "Zenoh Record",
+                "Sink/File",
+                "Records buffers with their timing and caps to a file for zenohreplay",
+                "Marc Pardo <p13marc@gmail.com>",
+            )
+        });
+        Some(&*ELEMENT_METADATA)
+    }
+
+    fn pad_templates() -> &'static [gst::PadTemplate] {
+        static PAD_TEMPLATES: LazyLock<Vec<gst::PadTemplate>> = LazyLock::new(|| {
+            let sink_pad_template = gst::PadTemplate::new(
+                "sink",
+                gst::PadDirection::Sink,
+                gst::PadPresence::Always,
+                &gst::Caps::new_any(),
+            )
+            .unwrap();
+
+            vec![sink_pad_template]
+        });
+
+        PAD_TEMPLATES.as_ref()
+    }
+}
+
+impl ObjectImpl for ZenohRecord {
+    fn signals() -> &'static [glib::subclass::Signal] {
+        static SIGNALS: LazyLock<Vec<glib::subclass::Signal>> = LazyLock::new(|| {
+            vec![
+                glib::subclass::Signal::builder("dump-state")
+                    .action()
+                    .class_handler(|args| {
+                        let element = args[0]
+                            .get::<super::ZenohRecord>()
+                            .expect("signal arg type checked upstream");
+                        element.imp().dump_state();
+                        None
+                    })
+                    .build(),
+            ]
+        });
+        SIGNALS.as_ref()
+    }
+
+    fn properties() -> &'static [glib::ParamSpec] {
+        static PROPERTIES: LazyLock<Vec<glib::ParamSpec>> = LazyLock::new(|| {
+            vec![
+                glib::ParamSpecString::builder("location")
+                    .nick("Location")
+                    .blurb("Path of the recording to write (overwritten if it exists)")
+                    .build(),
+                // Statistics (read-only)
+                glib::ParamSpecUInt64::builder("records-written")
+                    .nick("Records Written")
+                    .blurb("Total buffers written to the recording")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("bytes-written")
+                    .nick("Bytes Written")
+                    .blurb("Total bytes written to the recording, framing and metadata included")
+                    .read_only()
+                    .build(),
+            ]
+        });
+
+        PROPERTIES.as_ref()
+    }
+
+    fn set_property(&self, _id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+        let mut settings = self.settings.lock().unwrap();
+
+        match pspec.name() {
+            "location" => {
+                settings.location = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            name => {
+                gst::warning!(CAT, imp = self, "Unknown property: {}", name);
+            }
+        }
+    }
+
+    fn property(&self, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+        match pspec.name() {
+            "location" => self.settings.lock().unwrap().location.to_value(),
+            "records-written" | "bytes-written" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    match pspec.name() {
+                        "records-written" => started.stats.records_written.to_value(),
+                        "bytes-written" => started.stats.bytes_written.to_value(),
+                        _ => unreachable!(),
+                    }
+                } else {
+                    0u64.to_value()
+                }
+            }
+            name => {
+                gst::warning!(CAT, imp = self, "Unknown property: {}", name);
+                "".to_value()
+            }
+        }
+    }
+
+    fn constructed(&self) {
+        self.parent_constructed();
+        // Like filesink: buffers are written as they arrive, the arrival time being recorded
+        self.obj().set_sync(false);
+    }
+}
+
+#[glib::object_subclass]
+impl ObjectSubclass for ZenohRecord {
+    const NAME: &'static str = "GstZenohRecord";
+    type Type = super::ZenohRecord;
+    type ParentType = gst_base::BaseSink;
+}
+
+impl BaseSinkImpl for ZenohRecord {
+    fn start(&self) -> Result<(), gst::ErrorMessage> {
+        let mut state = self.state.lock().unwrap();
+        if matches!(*state, State::Started(_)) {
+            return Ok(());
+        }
+
+        let location = self.settings.lock().unwrap().location.clone();
+        let Some(location) = location.filter(|location| !location.is_empty()) else {
+            return Err(gst::error_msg!(
+                gst::ResourceError::Settings,
+                ["Location is required"]
+            ));
+        };
+
+        let mut writer = File::create(&location)
+            .map(BufWriter::new)
+            .and_then(|mut writer| recording::write_header(&mut writer).map(|_| writer))
+            .map_err(|e| {
+                gst::error_msg!(
+                    gst::ResourceError::OpenWrite,
+                    ["Failed to create recording '{}': {}", location, e]
+                )
+            })?;
+        // Flushed now so an empty recording is still a valid one
+        writer.flush().map_err(|e| {
+            gst::error_msg!(
+                gst::ResourceError::Write,
+                ["Failed to write recording '{}': {}", location, e]
+            )
+        })?;
+
+        *state = State::Started(Started {
+            writer,
+            first: None,
+            recorded_caps: None,
+            stats: Statistics {
+                bytes_written: recording::MAGIC.len() as u64,
+                ..Default::default()
+            },
+        });
+
+        gst::debug!(CAT, imp = self, "Recording to '{}'", location);
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), gst::ErrorMessage> {
+        let result = self.flush();
+        // Dropping Started closes the file
+        *self.state.lock().unwrap() = State::Stopped;
+        gst::debug!(CAT, imp = self, "ZenohRecord stopped");
+        result
+    }
+
+    fn event(&self, event: gst::Event) -> bool {
+        if let gst::EventView::Eos(_) = event.view()
+            && let Err(err) = self.flush()
+        {
+            self.post_error_message(err);
+            return false;
+        }
+        self.parent_event(event)
+    }
+
+    fn render(&self, buffer: &gst::Buffer) -> Result<gst::FlowSuccess, gst::FlowError> {
+        let caps = self.obj().sink_pad().current_caps();
+
+        let mut state_locked = self.state.lock().unwrap();
+        let State::Started(ref mut started) = *state_locked else {
+            gst::element_imp_error!(self, gst::CoreError::Failed, ["Not started yet"]);
+            return Err(gst::FlowError::Error);
+        };
+
+        let now = Instant::now();
+        let first = *started.first.get_or_insert(now);
+
+        // Caps are only recorded when they change, like zenohsink sends them
+        let mut metadata = MetadataBuilder::new().buffer_timing(buffer);
+        if let Some(caps) = caps
+            && started.recorded_caps.as_ref() != Some(&caps)
+        {
+            gst::debug!(CAT, imp = self, "Recording caps {}", caps);
+            metadata = metadata.caps(&caps);
+            started.recorded_caps = Some(caps);
+        }
+
+        let map = buffer.map_readable().map_err(|_| {
+            gst::element_imp_error!(self, gst::CoreError::Failed, ["Failed to map buffer"]);
+            gst::FlowError::Error
+        })?;
+        let record = Record {
+            time: now.duration_since(first).as_nanos() as u64,
+            attachment: metadata
+                .build()
+                .map(|attachment| attachment.to_bytes().into_owned())
+                .unwrap_or_default(),
+            payload: map.as_slice().to_vec(),
+        };
+
+        let written = record.write(&mut started.writer).map_err(|e| {
+            gst::element_imp_error!(
+                self,
+                gst::ResourceError::Write,
+                ["Failed to write recording: {}", e]
+            );
+            gst::FlowError::Error
+        })?;
+
+        started.stats.records_written += 1;
+        started.stats.bytes_written += written;
+        gst::trace!(
+            CAT,
+            imp = self,
+            "Recorded {} byte buffer at {} ns",
+            record.payload.len(),
+            record.time
+        );
+
+        Ok(gst::FlowSuccess::Ok)
+    }
+}
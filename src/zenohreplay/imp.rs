@@ -0,0 +1,399 @@
+use std::fs::File;
+use std::io::{self, BufReader, Seek, SeekFrom};
+use std::sync::{LazyLock, Mutex};
+
+use gst::{glib, prelude::*, subclass::prelude::*};
+use gst_base::{
+    prelude::BaseSrcExt,
+    subclass::{base_src::CreateSuccess, prelude::*},
+};
+use zenoh::bytes::ZBytes;
+
+use crate::metadata::MetadataParser;
+use crate::recording::{self, Record};
+
+static CAT: LazyLock<gst::DebugCategory> = LazyLock::new(|| {
+    gst::DebugCategory::new(
+        "zenohreplay",
+        gst::DebugColorFlags::empty(),
+        Some("Zenoh Replay"),
+    )
+});
+
+/// Statistics tracking for ZenohReplay
+#[derive(Debug, Clone, Default)]
+struct Statistics {
+    records_read: u64,
+    bytes_read: u64,
+    loops: u64,
+}
+
+struct Started {
+    reader: BufReader<File>,
+    /// Time of the first record, which is replayed at 0
+    base: Option<u64>,
+    /// Added to the timestamps of the current pass when looping
+    loop_offset: u64,
+    /// End (timestamp plus duration) of the latest buffer pushed
+    end: u64,
+    /// Records read in the current pass
+    pass_records: u64,
+    /// Statistics tracking
+    stats: Statistics,
+}
+
+#[derive(Default)]
+enum State {
+    #[default]
+    Stopped,
+    Started(Started),
+}
+
+impl State {
+    fn name(&self) -> &'static str {
+        match self {
+            State::Stopped => "Stopped",
+            State::Started(_) => "Started",
+        }
+    }
+}
+
+/// Configuration settings for the ZenohReplay element.
+#[derive(Debug, Default)]
+struct Settings {
+    /// Path of the recording to read (required)
+    location: Option<String>,
+    /// Start over at the end of the recording instead of sending EOS
+    loop_: bool,
+}
+
+/// GStreamer ZenohReplay element implementation.
+///
+/// Reads a recording written by zenohrecord and pushes its buffers with their
+/// recorded caps and timing, rebased so the first one starts at 0.
+#[derive(Default)]
+pub struct ZenohReplay {
+    settings: Mutex<Settings>,
+    state: Mutex<State>,
+}
+
+impl ZenohReplay {
+    /// Logs the state, settings and statistics at INFO (`dump-state` signal).
+    fn dump_state(&self) {
+        let state = self.state.lock().unwrap().name();
+        crate::utils::dump_state(self.obj().upcast_ref(), *CAT, state);
+    }
+
+    /// Reads the next record, starting over at the end of the recording
+    /// when looping. `None` once the recording is over.
+    fn next_record(&self, started: &mut Started) -> Result<Option<Record>, gst::FlowError> {
+        loop {
+            match Record::read(&mut started.reader) {
+                Ok(Some(record)) => return Ok(Some(record)),
+                Ok(None) => {}
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                    gst::warning!(CAT, imp = self, "Recording ends with a truncated record");
+                }
+                Err(e) => {
+                    gst::element_imp_error!(
+                        self,
+                        gst::ResourceError::Read,
+                        ["Failed to read recording: {}", e]
+                    );
+                    return Err(gst::FlowError::Error);
+                }
+            }
+
+            // An empty recording would loop forever without pushing anything
+            if !self.settings.lock().unwrap().loop_ || started.pass_records == 0 {
+                return Ok(None);
+            }
+
+            let rewind = started
+                .reader
+                .seek(SeekFrom::Start(recording::MAGIC.len() as u64));
+            if let Err(e) = rewind {
+                gst::element_imp_error!(
+                    self,
+                    gst::ResourceError::Seek,
+                    ["Failed to rewind recording: {}", e]
+                );
+                return Err(gst::FlowError::Error);
+            }
+            started.loop_offset = started.end;
+            started.pass_records = 0;
+            started.stats.loops += 1;
+            gst::debug!(
+                CAT,
+                imp = self,
+                "Looping, next pass starts at {}",
+                gst::ClockTime::from_nseconds(started.loop_offset)
+            );
+        }
+    }
+}
+
+impl GstObjectImpl for ZenohReplay {}
+
+impl ElementImpl for ZenohReplay {
+    fn metadata() -> Option<&'static gst::subclass::ElementMetadata> {
+        static ELEMENT_METADATA: LazyLock<gst::subclass::ElementMetadata> = LazyLock::new(|| {
+            gst::subclass::ElementMetadata::new(
+                "Zenoh Replay",
+                "Source/File",
+                "Replays a zenohrecord recording with its recorded caps and timing",
+                "Marc Pardo <p13marc@gmail.com>",
+            )
+        });
+        Some(&*ELEMENT_METADATA)
+    }
+
+    fn pad_templates() -> &'static [gst::PadTemplate] {
+        static PAD_TEMPLATES: LazyLock<Vec<gst::PadTemplate>> = LazyLock::new(|| {
+            let src_pad_template = gst::PadTemplate::new(
+                "src",
+                gst::PadDirection::Src,
+                gst::PadPresence::Always,
+                &gst::Caps::new_any(),
+            )
+            .unwrap();
+
+            vec![src_pad_template]
+        });
+
+        PAD_TEMPLATES.as_ref()
+    }
+}
+
+impl ObjectImpl for ZenohReplay {
+    fn signals() -> &'static [glib::subclass::Signal] {
+        static SIGNALS: LazyLock<Vec<glib::subclass::Signal>> = LazyLock::new(|| {
+            vec![
+                glib::subclass::Signal::builder("dump-state")
+                    .action()
+                    .class_handler(|args| {
+                        let element = args[0]
+                            .get::<super::ZenohReplay>()
+                            .expect("signal arg type checked upstream");
+                        element.imp().dump_state();
+                        None
+                    })
+                    .build(),
+            ]
+        });
+        SIGNALS.as_ref()
+    }
+
+    fn properties() -> &'static [glib::ParamSpec] {
+        static PROPERTIES: LazyLock<Vec<glib::ParamSpec>> = LazyLock::new(|| {
+            vec![
+                glib::ParamSpecString::builder("location")
+                    .nick("Location")
+                    .blurb("Path of the zenohrecord recording to replay")
+                    .build(),
+                glib::ParamSpecBoolean::builder("loop")
+                    .nick("Loop")
+                    .blurb("Start over at the end of the recording instead of sending EOS, with timestamps continuing from the previous pass")
+                    .default_value(false)
+                    .build(),
+                // Statistics (read-only)
+                glib::ParamSpecUInt64::builder("records-read")
+                    .nick("Records Read")
+                    .blurb("Total buffers read from the recording")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("bytes-read")
+                    .nick("Bytes Read")
+                    .blurb("Total payload bytes read from the recording")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("loops")
+                    .nick("Loops")
+                    .blurb("Times the recording started over")
+                    .read_only()
+                    .build(),
+            ]
+        });
+
+        PROPERTIES.as_ref()
+    }
+
+    fn set_property(&self, _id: usize, value: &glib::Value, pspec: &glib::ParamSpec) {
+        let mut settings = self.settings.lock().unwrap();
+
+        match pspec.name() {
+            "location" => {
+                settings.location = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "loop" => {
+                settings.loop_ = value.get::<bool>().expect("type checked upstream");
+            }
+            name => {
+                gst::warning!(CAT, imp = self, "Unknown property: {}", name);
+            }
+        }
+    }
+
+    fn property(&self, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
+        match pspec.name() {
+            "location" => self.settings.lock().unwrap().location.to_value(),
+            "loop" => self.settings.lock().unwrap().loop_.to_value(),
+            "records-read" | "bytes-read" | "loops" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    match pspec.name() {
+                        "records-read" => started.stats.records_read.to_value(),
+                        "bytes-read" => started.stats.bytes_read.to_value(),
+                        "loops" => started.stats.loops.to_value(),
+                        _ => unreachable!(),
+                    }
+                } else {
+                    0u64.to_value()
+                }
+            }
+            name => {
+                gst::warning!(CAT, imp = self, "Unknown property: {}", name);
+                "".to_value()
+            }
+        }
+    }
+
+    fn constructed(&self) {
+        self.parent_constructed();
+        self.obj().set_format(gst::Format::Time);
+    }
+}
+
+#[glib::object_subclass]
+impl ObjectSubclass for ZenohReplay {
+    const NAME: &'static str = "GstZenohReplay";
+    type Type = super::ZenohReplay;
+    type ParentType = gst_base::PushSrc;
+}
+
+impl BaseSrcImpl for ZenohReplay {
+    fn start(&self) -> Result<(), gst::ErrorMessage> {
+        let mut state = self.state.lock().unwrap();
+        if matches!(*state, State::Started(_)) {
+            return Ok(());
+        }
+
+        let location = self.settings.lock().unwrap().location.clone();
+        let Some(location) = location.filter(|location| !location.is_empty()) else {
+            return Err(gst::error_msg!(
+                gst::ResourceError::Settings,
+                ["Location is required"]
+            ));
+        };
+
+        let mut reader = File::open(&location).map(BufReader::new).map_err(|e| {
+            gst::error_msg!(
+                gst::ResourceError::OpenRead,
+                ["Failed to open recording '{}': {}", location, e]
+            )
+        })?;
+        recording::read_header(&mut reader).map_err(|e| {
+            gst::error_msg!(
+                gst::StreamError::WrongType,
+                ["Invalid recording '{}': {}", location, e]
+            )
+        })?;
+
+        *state = State::Started(Started {
+            reader,
+            base: None,
+            loop_offset: 0,
+            end: 0,
+            pass_records: 0,
+            stats: Statistics::default(),
+        });
+
+        gst::debug!(CAT, imp = self, "Replaying '{}'", location);
+        Ok(())
+    }
+
+    fn stop(&self) -> Result<(), gst::ErrorMessage> {
+        // Dropping Started closes the file
+        *self.state.lock().unwrap() = State::Stopped;
+        gst::debug!(CAT, imp = self, "ZenohReplay stopped");
+        Ok(())
+    }
+
+    fn is_seekable(&self) -> bool {
+        false
+    }
+}
+
+impl PushSrcImpl for ZenohReplay {
+    fn create(
+        &self,
+        _buffer: Option<&mut gst::BufferRef>,
+    ) -> Result<CreateSuccess, gst::FlowError> {
+        let mut state_locked = self.state.lock().unwrap();
+        let State::Started(ref mut started) = *state_locked else {
+            gst::element_imp_error!(self, gst::CoreError::Failed, ["Not started yet"]);
+            return Err(gst::FlowError::Error);
+        };
+
+        let Some(record) = self.next_record(started)? else {
+            gst::debug!(CAT, imp = self, "End of recording, sending EOS");
+            return Err(gst::FlowError::Eos);
+        };
+
+        let metadata = if record.attachment.is_empty() {
+            None
+        } else {
+            match MetadataParser::parse(&ZBytes::from(record.attachment)) {
+                Ok(metadata) => Some(metadata),
+                Err(e) => {
+                    gst::warning!(CAT, imp = self, "Failed to parse recorded metadata: {}", e);
+                    None
+                }
+            }
+        };
+
+        if let Some(caps) = metadata.as_ref().and_then(|metadata| metadata.caps())
+            && self.obj().src_pad().current_caps().as_ref() != Some(caps)
+        {
+            gst::debug!(CAT, imp = self, "Setting recorded caps {}", caps);
+            if let Err(e) = self.obj().set_caps(caps) {
+                gst::warning!(CAT, imp = self, "Failed to set caps: {}", e);
+            }
+        }
+
+        let len = record.payload.len();
+        let mut buffer = gst::Buffer::from_mut_slice(record.payload);
+        let buffer_mut = buffer.get_mut().unwrap();
+        if let Some(ref metadata) = metadata {
+            metadata.apply_to_buffer(buffer_mut);
+        }
+
+        // Buffers recorded without PTS are replayed at their arrival time
+        let time = buffer_mut.pts().map_or(record.time, |pts| pts.nseconds());
+        let base = *started.base.get_or_insert(time);
+        let loop_offset = started.loop_offset;
+        let rebase =
+            |ts: u64| gst::ClockTime::from_nseconds(ts.saturating_sub(base) + loop_offset);
+
+        let pts = rebase(time);
+        buffer_mut.set_pts(pts);
+        if let Some(dts) = buffer_mut.dts() {
+            buffer_mut.set_dts(rebase(dts.nseconds()));
+        }
+        if started.pass_records == 0 && started.stats.loops > 0 {
+            buffer_mut.set_flags(gst::BufferFlags::DISCONT);
+        }
+        let end = pts + buffer_mut.duration().unwrap_or(gst::ClockTime::ZERO);
+        started.end = started.end.max(end.nseconds());
+
+        started.pass_records += 1;
+        started.stats.records_read += 1;
+        started.stats.bytes_read += len as u64;
+
+        gst::trace!(CAT, imp = self, "Pushing {} byte buffer at {}", len, pts);
+
+        Ok(CreateSuccess::NewBuffer(buffer))
+    }
+}
@@ -0,0 +1,195 @@
+//! # ZenohReplay Element
+//!
+//! The ZenohReplay element reads a recording written by [`zenohrecord`] and
+//! pushes its buffers downstream with their recorded caps, PTS, DTS, duration
+//! and flags. Timestamps are rebased so the first buffer starts at 0; buffers
+//! recorded without a PTS get their arrival time instead.
+//!
+//! The source isn't live: inter-buffer timing is honored by synchronizing
+//! sinks (`sync=true`, the default of most sinks including `zenohsink`), while
+//! `sync=false` replays the recording as fast as possible.
+//!
+//! ## Properties
+//!
+//! * `location` - Path of the recording to replay (required)
+//! * `loop` - Start over at the end of the recording instead of sending EOS (default: false)
+//!   - Timestamps keep increasing: each pass starts where the previous one
+//!     ended (last timestamp plus duration), and its first buffer is marked DISCONT
+//!
+//! ## Example Pipelines
+//!
+//! ```bash
+//! # Publish a recorded stream on Zenoh, over and over
+//! gst-launch-1.0 zenohreplay location=video.zrec loop=true ! zenohsink key-expr=demo/video
+//! ```
+//!
+//! [`zenohrecord`]: crate::zenohrecord
+
+use gst::glib;
+use gst::prelude::*;
+
+pub mod imp;
+
+glib::wrapper! {
+    /// A GStreamer source element that replays a zenohrecord recording.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use gst::prelude::*;
+    /// use gstzenoh::zenohreplay::ZenohReplay;
+    ///
+    /// gst::init().unwrap();
+    /// gstzenoh::plugin_register_static().unwrap();
+    ///
+    /// let replay = ZenohReplay::builder("video.zrec")
+    ///     .loop_(true)
+    ///     .build();
+    /// ```
+    pub struct ZenohReplay(ObjectSubclass<imp::ZenohReplay>) @extends gst_base::PushSrc, gst_base::BaseSrc, gst::Element, gst::Object;
+}
+
+unsafe impl Send for ZenohReplay {}
+unsafe impl Sync for ZenohReplay {}
+
+impl Default for ZenohReplay {
+    fn default() -> Self {
+        gst::Object::builder().build().unwrap()
+    }
+}
+
+impl ZenohReplay {
+    /// Creates a new ZenohReplay reading the specified recording.
+    ///
+    /// # Arguments
+    ///
+    /// * `location` - Path of the recording to replay
+    pub fn new(location: &str) -> Self {
+        gst::Object::builder()
+            .property("location", location)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns a builder for creating a ZenohReplay with custom configuration.
+    pub fn builder(location: &str) -> ZenohReplayBuilder {
+        ZenohReplayBuilder::new(location)
+    }
+
+    // -------------------------------------------------------------------------
+    // Property Setters
+    // -------------------------------------------------------------------------
+
+    /// Sets the path of the recording to replay.
+    pub fn set_location(&self, location: &str) {
+        self.set_property("location", location);
+    }
+
+    /// Enables or disables looping.
+    ///
+    /// When enabled, the recording starts over at its end instead of sending
+    /// EOS, with timestamps continuing from the previous pass.
+    pub fn set_loop(&self, enabled: bool) {
+        self.set_property("loop", enabled);
+    }
+
+    // -------------------------------------------------------------------------
+    // Property Getters
+    // -------------------------------------------------------------------------
+
+    /// Returns the path of the recording, if set.
+    pub fn location(&self) -> Option<String> {
+        self.property("location")
+    }
+
+    /// Returns whether the recording is looped.
+    pub fn is_loop(&self) -> bool {
+        self.property("loop")
+    }
+
+    // -------------------------------------------------------------------------
+    // Actions
+    // -------------------------------------------------------------------------
+
+    /// Logs a snapshot of the state, settings and statistics at INFO level.
+    ///
+    /// The block goes to the `zenohreplay` debug category, so
+    /// `GST_DEBUG=zenohreplay:4` shows it without per-buffer traces. Same as
+    /// emitting `dump-state`.
+    pub fn dump_state(&self) {
+        self.emit_by_name::<()>("dump-state", &[]);
+    }
+
+    // -------------------------------------------------------------------------
+    // Statistics (read-only)
+    // -------------------------------------------------------------------------
+
+    /// Returns the total number of buffers read from the recording.
+    pub fn records_read(&self) -> u64 {
+        self.property("records-read")
+    }
+
+    /// Returns the total number of payload bytes read from the recording.
+    pub fn bytes_read(&self) -> u64 {
+        self.property("bytes-read")
+    }
+
+    /// Returns how many times the recording started over.
+    pub fn loops(&self) -> u64 {
+        self.property("loops")
+    }
+}
+
+impl TryFrom<gst::Element> for ZenohReplay {
+    type Error = gst::Element;
+
+    /// Attempts to convert a generic GStreamer element to a ZenohReplay.
+    ///
+    /// Returns the original element as an error if it's not a ZenohReplay.
+    fn try_from(element: gst::Element) -> Result<Self, Self::Error> {
+        element.downcast()
+    }
+}
+
+/// Builder for creating a [`ZenohReplay`] with custom configuration.
+pub struct ZenohReplayBuilder {
+    location: String,
+    loop_: Option<bool>,
+}
+
+impl ZenohReplayBuilder {
+    /// Creates a new builder with the required recording path.
+    pub fn new(location: &str) -> Self {
+        Self {
+            location: location.to_string(),
+            loop_: None,
+        }
+    }
+
+    /// Enables or disables looping (default: false).
+    pub fn loop_(mut self, enabled: bool) -> Self {
+        self.loop_ = Some(enabled);
+        self
+    }
+
+    /// Builds the ZenohReplay with the configured properties.
+    pub fn build(self) -> ZenohReplay {
+        let mut builder =
+            gst::Object::builder::<ZenohReplay>().property("location", &self.location);
+
+        if let Some(enabled) = self.loop_ {
+            builder = builder.property("loop", enabled);
+        }
+
+        builder.build().unwrap()
+    }
+}
+
+pub fn register(plugin: &gst::Plugin) -> Result<(), glib::BoolError> {
+    gst::Element::register(
+        Some(plugin),
+        "zenohreplay",
+        gst::Rank::NONE,
+        ZenohReplay::static_type(),
+    )
+}
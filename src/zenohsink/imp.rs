@@ -1,5 +1,6 @@
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, LazyLock, Mutex};
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard, TryLockError, mpsc};
+use std::time::{Duration, Instant};
 
 use gst::subclass::prelude::URIHandlerImpl;
 use gst::{glib, prelude::*, subclass::prelude::*};
@@ -9,8 +10,28 @@ use zenoh::Wait;
 use zenoh::key_expr::OwnedKeyExpr;
 use zenoh::qos::{CongestionControl, Priority, Reliability};
 
-use crate::error::{ErrorHandling, FlowErrorHandling, ZenohError};
-use crate::metadata::MetadataBuilder;
+use crate::error::{ErrorHandling, FlowErrorHandling, ZenohError, post_error_message};
+use crate::metadata::{
+    BatchPartTiming, CapsChannel, MetadataBuilder, StreamIdentity, ZenohKeyMeta,
+    deserialize_force_key_unit,
+};
+use crate::qos::{ZenohExpressCongestionControl, ZenohLocality};
+use crate::routing::LruCache;
+use crate::session::{ConnectivityMonitor, SessionConfig, SessionWrapper};
+use crate::stats::RateTracker;
+use crate::throttle::TokenBucket;
+use crate::uri::{self, UriBuilder};
+
+#[cfg(any(
+    feature = "compression-zstd",
+    feature = "compression-lz4",
+    feature = "compression-gzip",
+    feature = "compression-brotli",
+    feature = "compression-snappy"
+))]
+use crate::compression::CompressionError;
+#[cfg(feature = "shared-memory")]
+use zenoh::shm::{GarbageCollect, PosixShmProviderBackend, ShmProvider, ShmProviderBuilder};
 
 static CAT: LazyLock<gst::DebugCategory> = LazyLock::new(|| {
     gst::DebugCategory::new(
@@ -26,21 +47,103 @@ struct Statistics {
     bytes_sent: u64,
     messages_sent: u64,
     errors: u64,
-    dropped: u64, // For congestion-control=drop mode
+    /// Puts that failed in congestion-control=drop mode (buffer dropped instead of failing)
+    dropped_congestion: u64,
+    /// Buffers dropped because compressing them failed
+    dropped_compression_error: u64,
+    /// Buffers dropped because their payload exceeded max-buffer-size
+    dropped_oversize: u64,
+    /// Puts abandoned after put-timeout-ms
+    dropped_timeout: u64,
+    /// Buffers dropped by max-bitrate in congestion-control=drop mode
+    dropped_rate_limit: u64,
+    /// Time the streaming thread waited for max-bitrate, in milliseconds
+    rate_limited_ms: u64,
+    /// Delta units skipped by keyframes-only (not counted in `dropped()`)
+    dropped_filtered: u64,
+    /// Serialized attachment (metadata) bytes published, on top of `bytes_sent`
+    attachment_bytes_sent: u64,
+    /// Sliding-window throughput (bitrate / message-rate)
+    rate: RateTracker,
     #[cfg(any(
         feature = "compression-zstd",
         feature = "compression-lz4",
-        feature = "compression-gzip"
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
     ))]
     bytes_before_compression: u64,
     #[cfg(any(
         feature = "compression-zstd",
         feature = "compression-lz4",
-        feature = "compression-gzip"
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
     ))]
     bytes_after_compression: u64,
 }
 
+impl Statistics {
+    /// Total buffers dropped, whatever the reason
+    fn dropped(&self) -> u64 {
+        self.dropped_congestion
+            + self.dropped_compression_error
+            + self.dropped_oversize
+            + self.dropped_timeout
+            + self.dropped_rate_limit
+    }
+
+    /// Compressed size over original size of the buffers that were compressed,
+    /// or 1.0 when nothing was compressed (including builds without compression).
+    fn compression_ratio(&self) -> f64 {
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        if self.bytes_before_compression > 0 {
+            return self.bytes_after_compression as f64 / self.bytes_before_compression as f64;
+        }
+        1.0
+    }
+
+    /// Serializes all statistics into a compact JSON object keyed by property name.
+    fn to_json(&self) -> String {
+        #[allow(unused_mut)]
+        let mut json = serde_json::json!({
+            "bytes-sent": self.bytes_sent,
+            "messages-sent": self.messages_sent,
+            "errors": self.errors,
+            "dropped": self.dropped(),
+            "dropped-congestion": self.dropped_congestion,
+            "dropped-compression-error": self.dropped_compression_error,
+            "dropped-oversize": self.dropped_oversize,
+            "dropped-timeout": self.dropped_timeout,
+            "dropped-rate-limit": self.dropped_rate_limit,
+            "rate-limited-ms": self.rate_limited_ms,
+            "dropped-filtered": self.dropped_filtered,
+            "attachment-bytes-sent": self.attachment_bytes_sent,
+            "bitrate": self.rate.bitrate(),
+            "message-rate": self.rate.message_rate(),
+            "compression-ratio": self.compression_ratio(),
+        });
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        {
+            json["bytes-before-compression"] = self.bytes_before_compression.into();
+            json["bytes-after-compression"] = self.bytes_after_compression.into();
+        }
+        json.to_string()
+    }
+}
+
 /// Zenoh resources created during NULL→READY transition.
 ///
 /// These are lightweight network resources (session + publisher + matching listener)
@@ -49,17 +152,91 @@ struct Statistics {
 struct ReadyState {
     // Keeping session field to maintain ownership and prevent session from being dropped
     // while publisher is still in use. This can be either owned or shared.
-    _session: SessionWrapper,
-    publisher: zenoh::pubsub::Publisher<'static>,
-    /// Whether there are currently matching Zenoh subscribers.
-    /// Updated via Zenoh's background matching listener callback.
+    // Also used to re-declare the publishers when QoS changes at runtime.
+    session: SessionWrapper,
+    /// One publisher per key expression in the (comma-separated) `key-expr`.
+    /// Every buffer is published on all of them. Shared with the put worker.
+    publishers: Vec<Arc<zenoh::pubsub::Publisher<'static>>>,
+    /// Whether there are currently matching Zenoh subscribers on any key.
+    /// Updated via Zenoh's background matching listener callbacks.
     has_subscribers: Arc<AtomicBool>,
+    /// Queryables serving the current caps on `<key>/caps` (when `send-caps` is on),
+    /// so late subscribers don't have to wait for the next caps transmission
+    caps_queryables: Vec<zenoh::query::Queryable<()>>,
+    /// Subscribers of the force-key-unit requests on `<key>/fku` (when `enable-fku` is on)
+    fku_subscribers: Vec<zenoh::pubsub::Subscriber<()>>,
+    /// Algorithms advertised by the receivers of the keys (when `compression-negotiate` is on)
+    #[cfg(any(
+        feature = "compression-zstd",
+        feature = "compression-lz4",
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
+    ))]
+    compression_peers: Option<crate::compression::CompressionPeers>,
+    /// Publishers on `<key>/<suffix>` per routed suffix (`key-suffix-from-meta`),
+    /// declared on first use. They have no matching listener or caps queryable.
+    routed_publishers: LruCache<Vec<Arc<zenoh::pubsub::Publisher<'static>>>>,
+    /// Emits the session signals (when `connectivity-poll-ms` is set)
+    connectivity: Option<ConnectivityMonitor>,
+}
+
+impl ReadyState {
+    /// Undeclares the publishers (and their matching listeners) and closes the
+    /// session if this element owns it.
+    ///
+    /// Dropping would do the same eventually; releasing explicitly guarantees
+    /// nothing stays declared on the network once the element has left READY.
+    fn release(self) {
+        // Stopped first so closing the session isn't reported as a disconnection
+        drop(self.connectivity);
+        for queryable in self.caps_queryables {
+            let key_expr = queryable.key_expr().to_string();
+            if let Err(e) = queryable.undeclare().wait() {
+                gst::warning!(CAT, "Failed to undeclare caps queryable on '{}': {}", key_expr, e);
+            }
+        }
+        for subscriber in self.fku_subscribers {
+            let key_expr = subscriber.key_expr().to_string();
+            if let Err(e) = subscriber.undeclare().wait() {
+                gst::warning!(CAT, "Failed to undeclare FKU subscriber on '{}': {}", key_expr, e);
+            }
+        }
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        if let Some(peers) = self.compression_peers {
+            peers.release();
+        }
+        let routed = self.routed_publishers.into_values().flatten();
+        for publisher in self.publishers.into_iter().chain(routed) {
+            // A put still blocked on the put worker keeps its publisher, which
+            // is undeclared when that put returns
+            let Ok(publisher) = Arc::try_unwrap(publisher) else {
+                continue;
+            };
+            let key_expr = publisher.key_expr().to_string();
+            if let Err(e) = publisher.undeclare().wait() {
+                gst::warning!(CAT, "Failed to undeclare publisher on '{}': {}", key_expr, e);
+            }
+        }
+        if let SessionWrapper::Owned(session) = self.session
+            && let Some(Err(e)) = crate::session::wait_off_runtime(|| session.close().wait())
+        {
+            gst::warning!(CAT, "Failed to close Zenoh session: {}", e);
+        }
+    }
 }
 
 /// Additional resources created during READY→PAUSED (start()) for data rendering.
 struct Started {
-    /// Zenoh resources (session, publisher, matching listener)
-    ready: ReadyState,
+    /// Zenoh resources (session, publisher, matching listener); `None` with
+    /// `lazy-start` until the first buffer is rendered
+    ready: Option<ReadyState>,
     /// Statistics tracking (shared for thread-safe updates)
     stats: Arc<Mutex<Statistics>>,
     /// Track if we've sent caps metadata yet (for first buffer)
@@ -68,32 +245,20 @@ struct Started {
     last_caps_time: Arc<Mutex<Option<std::time::Instant>>>,
     /// Last caps that were sent (for change detection)
     last_caps: Arc<Mutex<Option<gst::Caps>>>,
-}
-
-/// Wrapper to handle both owned and shared Zenoh sessions.
-///
-/// This allows the plugin to either create its own session or use
-/// a shared session provided externally, enabling session reuse
-/// across multiple GStreamer elements.
-///
-/// Note: `zenoh::Session` is internally Arc-based and Clone, so the
-/// distinction between Owned and Shared is mainly for documentation
-/// purposes - both variants use the same underlying type.
-enum SessionWrapper {
-    /// Element created this session (will be dropped when element stops)
-    Owned(zenoh::Session),
-    /// Element is using a shared session (may outlive this element)
-    Shared(zenoh::Session),
-}
-
-impl SessionWrapper {
-    /// Get a reference to the underlying Zenoh session
-    fn as_session(&self) -> &zenoh::Session {
-        match self {
-            SessionWrapper::Owned(session) => session,
-            SessionWrapper::Shared(session) => session,
-        }
-    }
+    /// Sequence number of the next published buffer (`sequence-numbers`)
+    next_seqnum: u64,
+    /// Segment rate and base time receivers were last told about
+    sent_segment: (f64, gst::ClockTime),
+    /// Thread publishing with `put-timeout-ms`, created on first use
+    put_worker: Option<PutWorker>,
+    /// Publishes expired batches of `aggregate-window-ms` (when set)
+    _aggregate_timer: Option<AggregateTimer>,
+    /// Prometheus endpoint serving `stats` (when `metrics-port` is set)
+    #[cfg(feature = "metrics-http")]
+    _metrics: Option<crate::metrics::MetricsServer>,
+    /// Shared-memory provider payloads are allocated from (when `use-shm` is set)
+    #[cfg(feature = "shared-memory")]
+    shm_provider: Option<ShmProvider<PosixShmProviderBackend>>,
 }
 
 #[derive(Default)]
@@ -102,13 +267,24 @@ enum State {
     Stopped,
     /// Zenoh session + publisher created, matching listener active.
     /// No data flows — pipeline is in GStreamer READY state.
-    Ready(ReadyState),
+    /// `None` with `lazy-start`: the resources are created on the first buffer.
+    Ready(Option<ReadyState>),
     Starting, // Intermediate state during startup
     Started(Started),
     Stopping, // Intermediate state during shutdown
 }
 
 impl State {
+    fn name(&self) -> &'static str {
+        match self {
+            State::Stopped => "Stopped",
+            State::Ready(_) => "Ready",
+            State::Starting => "Starting",
+            State::Started(_) => "Started",
+            State::Stopping => "Stopping",
+        }
+    }
+
     fn is_started(&self) -> bool {
         matches!(self, State::Started(_))
     }
@@ -132,84 +308,342 @@ impl State {
     /// Returns the has_subscribers atomic, if available (Ready or Started).
     fn has_subscribers(&self) -> Option<&Arc<AtomicBool>> {
         match self {
-            State::Ready(ready) => Some(&ready.has_subscribers),
-            State::Started(started) => Some(&started.ready.has_subscribers),
+            State::Ready(ready) => ready.as_ref().map(|ready| &ready.has_subscribers),
+            State::Started(started) => started.ready.as_ref().map(|ready| &ready.has_subscribers),
+            _ => None,
+        }
+    }
+
+    /// Returns the session wrapper, if available (Ready or Started).
+    fn session_wrapper(&self) -> Option<&SessionWrapper> {
+        match self {
+            State::Ready(ready) => ready.as_ref().map(|ready| &ready.session),
+            State::Started(started) => started.ready.as_ref().map(|ready| &ready.session),
             _ => None,
         }
     }
+
+    /// Returns the Zenoh session, if available (Ready or Started).
+    fn session(&self) -> Option<&zenoh::Session> {
+        self.session_wrapper().map(SessionWrapper::as_session)
+    }
 }
 
+/// Default size of the shared-memory pool (32 MiB)
+#[cfg(feature = "shared-memory")]
+const DEFAULT_SHM_SIZE: u64 = 32 * 1024 * 1024;
+
+/// Default number of routed key suffixes kept declared with `key-suffix-from-meta`
+const DEFAULT_MAX_ROUTED_PUBLISHERS: u32 = 64;
+
+/// Interval at which `wait-for-subscribers-ms` checks the matching status
+const SUBSCRIBERS_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Longest sleep while waiting for `max-bitrate`, so flushes and limit changes
+/// are noticed quickly
+const RATE_LIMIT_POLL: Duration = Duration::from_millis(20);
+
 /// Configuration settings for the ZenohSink element.
 ///
 /// These settings control how the element connects to and publishes
 /// data via the Zenoh network protocol.
 #[derive(Debug)]
 struct Settings {
-    /// Zenoh key expression for publishing data (required)
+    /// Zenoh key expression for publishing data (required).
+    /// May be a comma-separated list to publish the same stream on several keys.
     key_expr: String,
+    /// Values for the `${name}` placeholders in `key_expr`, substituted at start
+    key_vars: Option<gst::Structure>,
     /// Optional path to Zenoh configuration file
     config_file: Option<String>,
+    /// Inline Zenoh configuration (JSON5), takes precedence over `config_file`
+    config_json5: Option<String>,
+    /// Multicast scouting for peer discovery (default: true)
+    scouting: bool,
+    /// Client mode connecting only to the configured endpoints (default: false)
+    endpoints_only: bool,
+    /// CA certificate for verifying TLS routers/peers (overrides the config file)
+    tls_root_ca: Option<String>,
+    /// Client certificate for mutual TLS
+    tls_client_cert: Option<String>,
+    /// Private key of the client certificate
+    tls_client_key: Option<String>,
+    /// User name for usrpwd authentication
+    username: Option<String>,
+    /// Password for usrpwd authentication (write-only, never logged)
+    password: Option<String>,
     /// Publisher priority level (1-7: 1=RealTime, 2=InteractiveHigh, 3=InteractiveLow, 4=DataHigh, 5=Data(default), 6=DataLow, 7=Background)
     priority: u8,
+    /// Priority for key frames (buffers without DELTA_UNIT), 0 = same as `priority`
+    keyframe_priority: u8,
+    /// Publish only key frames, skipping buffers flagged DELTA_UNIT
+    keyframes_only: bool,
     /// Congestion control policy: "block" or "drop"
     congestion_control: String,
     /// Reliability mode: "best-effort" or "reliable"
     reliability: String,
     /// Enable express mode for lower latency (bypasses some queues)
     express: bool,
+    /// Congestion control used instead of `congestion_control` in express mode
+    express_congestion_control: ZenohExpressCongestionControl,
+    /// Restrict delivery to subscribers in the same session, remote ones, or both
+    locality: ZenohLocality,
     /// Send GStreamer caps as metadata with buffers (default: true)
     send_caps: bool,
     /// Interval in seconds to send caps periodically (0 = only on first buffer and changes, default: 1)
     caps_interval: u32,
+    /// Send caps in the sample attachments or on the `<key>/caps` sidecar key
+    caps_channel: CapsChannel,
+    /// Publish EOS and custom downstream events on the `<key>/events` sidecar key (default: false)
+    forward_events: bool,
+    /// Push the force-key-unit requests received on the `<key>/fku` sidecar key upstream
+    /// (default: false)
+    enable_fku: bool,
+    /// Publish with the Zenoh encoding derived from the caps (default: false)
+    auto_encoding: bool,
     /// Send buffer timing metadata (PTS, DTS, duration, flags) with each buffer (default: true)
     send_buffer_meta: bool,
+    /// Attach a `gst.seqnum` sequence number to each buffer for loss detection (default: false)
+    sequence_numbers: bool,
+    /// Fields sent as `user.` metadata with every buffer (default: none)
+    user_metadata: Option<gst::Structure>,
+    /// Publish each buffer list as a single batched sample (default: false)
+    batch_lists: bool,
+    /// Coalesce small buffers arriving within this window into one batched sample (0 = off)
+    aggregate_window_ms: u32,
+    /// Publish nothing but the buffer bytes, leaving caps and buffer metadata out (default: false)
+    payload_only: bool,
+    /// Publish buffers with a `ZenohKeyMeta` on `<key-expr>/<meta key>` (default: false)
+    key_suffix_from_meta: bool,
+    /// Maximum number of routed key suffixes whose publishers are kept declared (default: 64)
+    max_routed_publishers: u32,
     /// Compression algorithm to use (requires compression features)
     #[cfg(any(
         feature = "compression-zstd",
         feature = "compression-lz4",
-        feature = "compression-gzip"
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
     ))]
     compression: crate::compression::CompressionType,
     /// Compression level (1-9, higher = better compression but slower)
     #[cfg(any(
         feature = "compression-zstd",
         feature = "compression-lz4",
-        feature = "compression-gzip"
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
     ))]
     compression_level: i32,
+    /// Buffers smaller than this many bytes are sent uncompressed (0 = compress all)
+    #[cfg(any(
+        feature = "compression-zstd",
+        feature = "compression-lz4",
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
+    ))]
+    compression_min_size: u32,
+    /// Only compress with an algorithm a receiver advertises, sending
+    /// uncompressed otherwise
+    #[cfg(any(
+        feature = "compression-zstd",
+        feature = "compression-lz4",
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
+    ))]
+    compression_negotiate: bool,
+    /// Allocate payloads from a Zenoh shared-memory pool (requires `shared-memory` feature)
+    #[cfg(feature = "shared-memory")]
+    use_shm: bool,
+    /// Size in bytes of the shared-memory pool
+    #[cfg(feature = "shared-memory")]
+    shm_size: u64,
+    /// Drop buffers whose payload exceeds this many bytes (0 = unlimited)
+    max_buffer_size: u64,
+    /// Egress limit in bits per second (0 = unlimited)
+    max_bitrate: u64,
+    /// Give up on a put that hasn't completed after this many milliseconds (0 = wait)
+    put_timeout_ms: u64,
+    /// How long `start()` waits for a router or peer to be reachable (0 = don't wait)
+    wait_for_connection_ms: u64,
+    /// Fail `start()` instead of proceeding when `wait_for_connection_ms` runs out
+    require_connection: bool,
+    /// Session connectivity check interval for the session signals (0 = disabled)
+    connectivity_poll_ms: u32,
+    /// How long `start()` waits for a matching subscriber (0 = don't wait)
+    wait_for_subscribers_ms: u64,
+    /// Fail `start()` instead of proceeding when `wait_for_subscribers_ms` runs out
+    require_subscribers: bool,
+    /// Defer opening the session and declaring the publishers to the first buffer
+    lazy_start: bool,
+    /// Port of the Prometheus statistics endpoint (0 = disabled)
+    #[cfg(feature = "metrics-http")]
+    metrics_port: u32,
+    /// Address the Prometheus statistics endpoint listens on
+    #[cfg(feature = "metrics-http")]
+    metrics_address: String,
     /// Optional external Zenoh session to share with other elements (Rust API)
     external_session: Option<zenoh::Session>,
     /// Session group name for sharing sessions via property (gst-launch compatible)
     session_group: Option<String>,
+    /// How long the group session stays open after its last element released it
+    session_group_linger_ms: u32,
 }
 
 impl Default for Settings {
     fn default() -> Self {
         Self {
             key_expr: String::new(),
+            key_vars: None,
             config_file: None,
+            config_json5: None,
+            scouting: true,
+            endpoints_only: false,
+            tls_root_ca: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+            username: None,
+            password: None,
             priority: 5, // Default to Priority::Data
+            keyframe_priority: 0,
+            keyframes_only: false,
             congestion_control: "block".into(),
             reliability: "best-effort".into(),
             express: false,
+            express_congestion_control: ZenohExpressCongestionControl::Inherit,
+            locality: ZenohLocality::Any,
             send_caps: true,        // Default to sending caps for ease of use
             caps_interval: 1,       // Send caps every 1 second by default
+            caps_channel: CapsChannel::Attachment,
+            forward_events: false,
+            enable_fku: false,
+            auto_encoding: false,
             send_buffer_meta: true, // Default to sending buffer timing metadata
+            sequence_numbers: false,
+            user_metadata: None,
+            batch_lists: false,
+            aggregate_window_ms: 0,
+            payload_only: false,
+            key_suffix_from_meta: false,
+            max_routed_publishers: DEFAULT_MAX_ROUTED_PUBLISHERS,
             #[cfg(any(
                 feature = "compression-zstd",
                 feature = "compression-lz4",
-                feature = "compression-gzip"
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
             ))]
             compression: crate::compression::CompressionType::None,
             #[cfg(any(
                 feature = "compression-zstd",
                 feature = "compression-lz4",
-                feature = "compression-gzip"
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
             ))]
             compression_level: 5, // Medium compression level
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            compression_min_size: 0,
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            compression_negotiate: false,
+            #[cfg(feature = "shared-memory")]
+            use_shm: false,
+            #[cfg(feature = "shared-memory")]
+            shm_size: DEFAULT_SHM_SIZE,
+            max_buffer_size: 0,
+            max_bitrate: 0,
+            put_timeout_ms: 0,
+            wait_for_connection_ms: 0,
+            require_connection: false,
+            connectivity_poll_ms: crate::session::DEFAULT_CONNECTIVITY_POLL_MS,
+            wait_for_subscribers_ms: 0,
+            require_subscribers: false,
+            lazy_start: false,
+            #[cfg(feature = "metrics-http")]
+            metrics_port: 0,
+            #[cfg(feature = "metrics-http")]
+            metrics_address: crate::metrics::DEFAULT_METRICS_ADDRESS.into(),
             external_session: None,
             session_group: None,
+            session_group_linger_ms: crate::session::DEFAULT_SESSION_GROUP_LINGER_MS,
+        }
+    }
+}
+
+impl Settings {
+    /// `put-timeout-ms` as a duration, `None` when puts may block indefinitely
+    fn put_timeout(&self) -> Option<Duration> {
+        (self.put_timeout_ms > 0).then(|| Duration::from_millis(self.put_timeout_ms))
+    }
+
+    /// Congestion control of the publishers: `express-congestion-control` in
+    /// express mode unless it inherits, `congestion-control` otherwise.
+    fn effective_congestion_control(&self) -> CongestionControl {
+        let regular = match self.congestion_control.as_str() {
+            "drop" => CongestionControl::Drop,
+            _ => CongestionControl::Block,
+        };
+        if self.express {
+            self.express_congestion_control.resolve(regular)
+        } else {
+            regular
+        }
+    }
+
+    /// `send-caps`, off with `payload-only`
+    fn sends_caps(&self) -> bool {
+        self.send_caps && !self.payload_only
+    }
+
+    /// `send-buffer-meta`, off with `payload-only`
+    fn sends_buffer_meta(&self) -> bool {
+        self.send_buffer_meta && !self.payload_only
+    }
+
+    /// With `payload-only`, the enabled properties that can't work without an
+    /// attachment, so that the start fails instead of silently ignoring them.
+    fn payload_only_conflicts(&self) -> Vec<&'static str> {
+        let mut conflicts = Vec::new();
+        if !self.payload_only {
+            return conflicts;
+        }
+        if self.sequence_numbers {
+            conflicts.push("sequence-numbers");
+        }
+        if self.user_metadata.as_ref().is_some_and(|s| s.n_fields() > 0) {
+            conflicts.push("user-metadata");
+        }
+        if self.batch_lists {
+            conflicts.push("batch-lists");
+        }
+        if self.aggregate_window_ms > 0 {
+            conflicts.push("aggregate-window-ms");
+        }
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        if self.compression != crate::compression::CompressionType::None {
+            conflicts.push("compression");
         }
+        conflicts
     }
 }
 
@@ -234,6 +668,26 @@ pub struct ZenohSink {
     settings: Mutex<Settings>,
     /// Current operational state
     state: Mutex<State>,
+    /// Set when a runtime QoS change (e.g. `express`) requires re-declaring
+    /// the publishers; applied by the streaming thread between buffers
+    publishers_outdated: AtomicBool,
+    /// Encoding of the current caps the publishers are declared with (`auto-encoding`)
+    encoding: Mutex<Option<String>>,
+    /// Egress budget enforcing `max-bitrate`
+    rate_limiter: Mutex<TokenBucket>,
+    /// Set between `unlock()` and `unlock_stop()`, so a render waiting for
+    /// `max-bitrate` returns for the flush or state change
+    flushing: AtomicBool,
+    /// Transmitted tags of the current stream, from upstream tag events
+    tags: Mutex<Option<gst::TagList>>,
+    /// Set when `tags` changed since they were last published
+    tags_changed: AtomicBool,
+    /// Identity of the current stream, from the upstream stream-start event
+    stream_identity: Mutex<Option<StreamIdentity>>,
+    /// Set when `stream_identity` changed since it was last published
+    stream_identity_changed: AtomicBool,
+    /// Small buffers held back by `aggregate-window-ms`
+    aggregator: Mutex<Aggregator>,
 }
 
 impl Default for ZenohSink {
@@ -241,11 +695,356 @@ impl Default for ZenohSink {
         Self {
             settings: Mutex::new(Settings::default()),
             state: Mutex::new(State::default()),
+            publishers_outdated: AtomicBool::new(false),
+            encoding: Mutex::new(None),
+            rate_limiter: Mutex::new(TokenBucket::default()),
+            flushing: AtomicBool::new(false),
+            tags: Mutex::new(None),
+            tags_changed: AtomicBool::new(false),
+            stream_identity: Mutex::new(None),
+            stream_identity_changed: AtomicBool::new(false),
+            aggregator: Mutex::new(Aggregator::default()),
+        }
+    }
+}
+
+/// Splits a comma-separated `key-expr` value into individual key expressions.
+///
+/// Each entry is trimmed and validated on its own; an empty entry or a
+/// malformed key expression fails the whole list.
+fn parse_key_exprs(key_expr: &str) -> Result<Vec<OwnedKeyExpr>, ZenohError> {
+    key_expr
+        .split(',')
+        .map(str::trim)
+        .map(|key| {
+            if key.is_empty() {
+                return Err(ZenohError::KeyExpr {
+                    key_expr: key_expr.to_string(),
+                    reason: "empty entry in key expression list".to_string(),
+                });
+            }
+            OwnedKeyExpr::try_from(key.to_string()).map_err(|e| ZenohError::KeyExpr {
+                key_expr: key.to_string(),
+                reason: e.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// QoS the publishers are declared with
+#[derive(Debug, Clone)]
+struct PublisherQos {
+    priority: Priority,
+    congestion_control: CongestionControl,
+    reliability: Reliability,
+    express: bool,
+    locality: ZenohLocality,
+    /// Encoding of the caps with `auto-encoding`, set by [`ZenohSink::publisher_qos`]
+    encoding: Option<String>,
+}
+
+impl PublisherQos {
+    fn from_settings(settings: &Settings) -> Self {
+        let reliability = match settings.reliability.as_str() {
+            "reliable" => Reliability::Reliable,
+            "best-effort" => Reliability::BestEffort,
+            other => {
+                gst::warning!(CAT, "Unknown reliability '{}', using default", other);
+                Reliability::BestEffort
+            }
+        };
+        Self {
+            priority: Priority::try_from(settings.priority).unwrap_or(Priority::default()),
+            congestion_control: settings.effective_congestion_control(),
+            reliability,
+            express: settings.express,
+            locality: settings.locality,
+            encoding: None,
+        }
+    }
+
+    /// Declares a publisher on `key` with this QoS.
+    fn declare(
+        &self,
+        session: &zenoh::Session,
+        key: OwnedKeyExpr,
+    ) -> Result<zenoh::pubsub::Publisher<'static>, ZenohError> {
+        let key_str = key.to_string();
+        let mut publisher_builder = session
+            .declare_publisher(key)
+            .priority(self.priority)
+            .congestion_control(self.congestion_control)
+            .reliability(self.reliability)
+            .allowed_destination(self.locality.into());
+
+        if self.express {
+            publisher_builder = publisher_builder.express(true);
+        }
+        if let Some(ref encoding) = self.encoding {
+            publisher_builder = publisher_builder.encoding(encoding.as_str());
+        }
+
+        publisher_builder.wait().map_err(|e| ZenohError::Publish {
+            key_expr: key_str,
+            source: e,
+        })
+    }
+}
+
+/// A sample prepared by `render_list`: one buffer, or a batch of them with
+/// `batch-lists`
+struct ListSample {
+    payload: zenoh::bytes::ZBytes,
+    attachment: Option<zenoh::bytes::ZBytes>,
+    /// Payload size in bytes
+    size: u64,
+    /// Number of buffers in the payload
+    buffers: u64,
+    /// Size in bytes before compression, when the payload is compressed
+    uncompressed_size: Option<u64>,
+    /// Whether a buffer of the payload is a key frame
+    keyframe: bool,
+}
+
+/// Per-put QoS for key frames, set by `keyframe-priority`
+#[derive(Debug, Clone, Copy)]
+struct KeyframeQos {
+    priority: Priority,
+    express: bool,
+    locality: ZenohLocality,
+}
+
+impl KeyframeQos {
+    /// Returns the key frame QoS, or `None` when `keyframe-priority` is unset.
+    fn from_settings(settings: &Settings) -> Option<Self> {
+        (settings.keyframe_priority > 0).then(|| Self {
+            priority: Priority::try_from(settings.keyframe_priority).unwrap_or_default(),
+            express: settings.express,
+            locality: settings.locality,
+        })
+    }
+}
+
+/// Key frames are the buffers without the DELTA_UNIT flag.
+fn is_keyframe(buffer: &gst::BufferRef) -> bool {
+    !buffer.flags().contains(gst::BufferFlags::DELTA_UNIT)
+}
+
+/// Publishes `payload` on `publisher`.
+///
+/// Publishers fix their priority at declaration, so with `keyframe_qos` the
+/// sample is put through the session instead, on the same key and with the
+/// publisher's congestion control, reliability and encoding but the key frame
+/// priority.
+fn put(
+    session: &zenoh::Session,
+    publisher: &zenoh::pubsub::Publisher<'static>,
+    payload: zenoh::bytes::ZBytes,
+    attachment: Option<zenoh::bytes::ZBytes>,
+    keyframe_qos: Option<KeyframeQos>,
+) -> zenoh::Result<()> {
+    match keyframe_qos {
+        Some(qos) => {
+            let put_builder = session
+                .put(publisher.key_expr().clone(), payload)
+                .priority(qos.priority)
+                .congestion_control(publisher.congestion_control())
+                .reliability(publisher.reliability())
+                .encoding(publisher.encoding().clone())
+                .express(qos.express)
+                .allowed_destination(qos.locality.into());
+            match attachment {
+                Some(attachment) => put_builder.attachment(attachment).wait(),
+                None => put_builder.wait(),
+            }
+        }
+        None => {
+            let put_builder = publisher.put(payload);
+            match attachment {
+                Some(attachment) => put_builder.attachment(attachment).wait(),
+                None => put_builder.wait(),
+            }
+        }
+    }
+}
+
+/// A put handed to the [`PutWorker`]
+type PutJob = Box<dyn FnOnce() -> zenoh::Result<()> + Send>;
+
+/// Thread running the puts bounded by `put-timeout-ms`, so the streaming
+/// thread can give up on a put that blocks (`congestion-control=block` on a
+/// wedged network) and carry on.
+///
+/// Zenoh can't cancel a put: one that timed out keeps running here, and
+/// further puts time out right away until it has returned, so a blocked
+/// network never holds up the streaming thread longer than the timeout.
+struct PutWorker {
+    jobs: mpsc::Sender<(PutJob, mpsc::SyncSender<zenoh::Result<()>>)>,
+    /// Completion of a put that timed out and may still be blocked
+    pending: Option<mpsc::Receiver<zenoh::Result<()>>>,
+}
+
+impl PutWorker {
+    fn new() -> Self {
+        let (jobs, receiver) = mpsc::channel::<(PutJob, mpsc::SyncSender<zenoh::Result<()>>)>();
+        // The thread exits when the worker, and with it the sender, is dropped
+        std::thread::Builder::new()
+            .name("zenohsink-put".into())
+            .spawn(move || {
+                for (job, done) in receiver {
+                    let _ = done.send(job());
+                }
+            })
+            .expect("Failed to spawn put worker thread");
+        Self {
+            jobs,
+            pending: None,
+        }
+    }
+
+    /// Runs `job` on the worker and waits at most `timeout` for it.
+    ///
+    /// Returns `None` when the put timed out, or was not attempted because a
+    /// previous one is still blocked.
+    fn put(
+        &mut self,
+        timeout: Duration,
+        job: impl FnOnce() -> zenoh::Result<()> + Send + 'static,
+    ) -> Option<zenoh::Result<()>> {
+        if let Some(ref pending) = self.pending {
+            if let Err(mpsc::TryRecvError::Empty) = pending.try_recv() {
+                return None;
+            }
+            // Its buffer was already counted as dropped
+            self.pending = None;
+        }
+
+        let (done, completion) = mpsc::sync_channel(1);
+        if self.jobs.send((Box::new(job), done)).is_err() {
+            return Some(Err("put worker thread is gone".into()));
+        }
+        match completion.recv_timeout(timeout) {
+            Ok(result) => Some(result),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                self.pending = Some(completion);
+                None
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                Some(Err("put worker thread is gone".into()))
+            }
+        }
+    }
+}
+
+/// Publishes `payload` with [`put`], on the put worker when `put_timeout` is set.
+///
+/// Returns `None` when the put timed out.
+fn publish(
+    put_worker: &mut Option<PutWorker>,
+    put_timeout: Option<Duration>,
+    session: &zenoh::Session,
+    publisher: &Arc<zenoh::pubsub::Publisher<'static>>,
+    payload: zenoh::bytes::ZBytes,
+    attachment: Option<zenoh::bytes::ZBytes>,
+    keyframe_qos: Option<KeyframeQos>,
+) -> Option<zenoh::Result<()>> {
+    let Some(timeout) = put_timeout else {
+        return Some(put(session, publisher, payload, attachment, keyframe_qos));
+    };
+
+    let session = session.clone();
+    let publisher = publisher.clone();
+    put_worker.get_or_insert_with(PutWorker::new).put(timeout, move || {
+        put(&session, &publisher, payload, attachment, keyframe_qos)
+    })
+}
+
+/// With `aggregate-window-ms`, a batch reaching this many bytes is published
+/// right away, and buffers this large are published on their own
+const AGGREGATE_MAX_SIZE: usize = 16 * 1024;
+
+/// Small buffers held back by `aggregate-window-ms`, published as one batch
+#[derive(Default)]
+struct Aggregator {
+    /// Buffers of the pending batch, in order
+    pending: Vec<gst::Buffer>,
+    /// Arrival of the first buffer of the pending batch
+    since: Option<Instant>,
+    /// Payload bytes of the pending batch
+    size: usize,
+    /// Whether the stream has delta units, so that key frames end a batch
+    delta_coded: bool,
+}
+
+impl Aggregator {
+    /// Whether the pending batch has waited `window` or longer.
+    fn expired(&self, window: Duration) -> bool {
+        self.since.is_some_and(|since| since.elapsed() >= window)
+    }
+
+    /// Drops the pending batch.
+    fn clear(&mut self) {
+        self.pending.clear();
+        self.since = None;
+        self.size = 0;
+    }
+}
+
+/// Thread publishing the pending batch of `aggregate-window-ms` once its
+/// window has run out, when no further buffer arrives to do it.
+///
+/// Dropping the timer wakes the thread up and ends it.
+struct AggregateTimer {
+    /// Dropped to stop the thread
+    _stop: mpsc::Sender<()>,
+}
+
+impl AggregateTimer {
+    fn start(element: &super::ZenohSink, window: Duration) -> Result<Self, gst::ErrorMessage> {
+        // Checked a few times per window, so a batch doesn't wait much longer
+        let interval = (window / 4).max(Duration::from_millis(1));
+        let element = element.downgrade();
+        let (stop, stopped) = mpsc::channel::<()>();
+        std::thread::Builder::new()
+            .name("zenohsink-aggregate".into())
+            .spawn(move || {
+                while let Err(mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                    let Some(element) = element.upgrade() else {
+                        break;
+                    };
+                    element.imp().flush_aggregate(Some(window));
+                }
+            })
+            .map_err(|e| {
+                gst::error_msg!(
+                    gst::ResourceError::Failed,
+                    ["Failed to spawn aggregation timer thread: {}", e]
+                )
+            })?;
+        Ok(Self { _stop: stop })
+    }
+}
+
+/// Locks `mutex`, giving up at `deadline`, or on a poisoned lock.
+fn lock_until<T>(mutex: &Mutex<T>, deadline: Instant) -> Option<MutexGuard<'_, T>> {
+    loop {
+        match mutex.try_lock() {
+            Ok(guard) => return Some(guard),
+            Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            Err(_) => return None,
         }
     }
 }
 
 impl ZenohSink {
+    /// Logs the state, settings and statistics at INFO (`dump-state` signal).
+    fn dump_state(&self) {
+        let state = self.state.lock().unwrap().name();
+        crate::utils::dump_state(self.obj().upcast_ref(), *CAT, state);
+    }
+
     /// Sets the external Zenoh session to use for this element.
     ///
     /// This is called from the public API to enable session sharing.
@@ -254,6 +1053,156 @@ impl ZenohSink {
         settings.external_session = Some(session);
     }
 
+    /// Returns the Zenoh session in use, from READY (or the first buffer with
+    /// `lazy-start`) until the element goes back to NULL.
+    pub(crate) fn session(&self) -> Option<zenoh::Session> {
+        self.state.lock().unwrap().session().cloned()
+    }
+
+    /// Waits until the buffer being published, if any, has been handed to Zenoh.
+    ///
+    /// The streaming thread holds the state lock for the whole of render, so
+    /// acquiring it means every rendered buffer has returned from `put()`.
+    /// Zenoh 1.x has no flush or acknowledgement primitive beyond that: handed
+    /// off data sits in the transmission queue, which the transport drains on
+    /// its own and when the session is closed. Returns `false` if `timeout`
+    /// elapsed first.
+    ///
+    /// A batch held back by `aggregate-window-ms` is published first.
+    pub(crate) fn flush(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        let flushed = lock_until(&self.aggregator, deadline).is_some_and(|mut aggregator| {
+            if aggregator.pending.is_empty() {
+                return true;
+            }
+            // Publishing takes the state lock again, but the streaming thread
+            // can't publish meanwhile: it needs the aggregator first
+            match lock_until(&self.state, deadline).map(|state| state.is_started()) {
+                Some(true) => {
+                    // Failures are counted and posted by publish_list
+                    let _ = self.publish_aggregated(&mut aggregator);
+                    true
+                }
+                Some(false) => true,
+                None => false,
+            }
+        });
+
+        if flushed && lock_until(&self.state, deadline).is_some() {
+            gst::debug!(CAT, imp = self, "Flushed: no publication in progress");
+            true
+        } else {
+            gst::debug!(CAT, imp = self, "Flush timed out after {:?}", timeout);
+            false
+        }
+    }
+
+    /// Returns the element to `Stopped` and releases whatever Zenoh resources
+    /// it held, outside the state lock.
+    fn release_zenoh_resources(&self) {
+        let previous = std::mem::take(&mut *self.state.lock().unwrap());
+        match previous {
+            State::Ready(Some(ready)) => ready.release(),
+            State::Started(Started {
+                ready: Some(ready),
+                ..
+            }) => ready.release(),
+            _ => {}
+        }
+    }
+
+    /// Waits up to `wait-for-connection-ms` for the session to reach a router or
+    /// peer. Fails when the wait runs out and `require-connection` is set.
+    fn wait_for_connection(&self, session: &zenoh::Session) -> Result<(), gst::ErrorMessage> {
+        let (wait_for_connection_ms, require_connection) = {
+            let settings = self.settings.lock().unwrap();
+            (settings.wait_for_connection_ms, settings.require_connection)
+        };
+        if wait_for_connection_ms == 0 {
+            return Ok(());
+        }
+
+        let connected = crate::session::wait_for_connection(
+            session,
+            Duration::from_millis(wait_for_connection_ms),
+        );
+        if connected {
+            gst::debug!(CAT, imp = self, "Session connected, starting");
+            return Ok(());
+        }
+
+        let err = ZenohError::NotConnected {
+            timeout_ms: wait_for_connection_ms,
+        };
+        if require_connection {
+            return Err(err.to_error_message());
+        }
+        gst::warning!(CAT, imp = self, "{}, starting anyway", err);
+        Ok(())
+    }
+
+    /// Waits up to `wait-for-subscribers-ms` for a matching subscriber on any
+    /// key, as reported by the publishers' matching listeners. Fails when the
+    /// wait runs out and `require-subscribers` is set.
+    fn wait_for_subscribers(&self, has_subscribers: &AtomicBool) -> Result<(), gst::ErrorMessage> {
+        let (wait_for_subscribers_ms, require_subscribers) = {
+            let settings = self.settings.lock().unwrap();
+            (settings.wait_for_subscribers_ms, settings.require_subscribers)
+        };
+        if wait_for_subscribers_ms == 0 {
+            return Ok(());
+        }
+
+        let deadline = Instant::now() + Duration::from_millis(wait_for_subscribers_ms);
+        while !has_subscribers.load(Ordering::Relaxed) {
+            let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+                let err = ZenohError::NoSubscribers {
+                    timeout_ms: wait_for_subscribers_ms,
+                };
+                if require_subscribers {
+                    return Err(err.to_error_message());
+                }
+                gst::warning!(CAT, imp = self, "{}, starting anyway", err);
+                return Ok(());
+            };
+            std::thread::sleep(remaining.min(SUBSCRIBERS_POLL_INTERVAL));
+        }
+        gst::debug!(CAT, imp = self, "Subscriber matched, starting");
+        Ok(())
+    }
+
+    /// Creates the Zenoh resources deferred by `lazy-start` when the first
+    /// buffer is rendered. On failure an error is posted and the next buffer
+    /// tries again.
+    fn ensure_zenoh_resources(&self, started: &mut Started) -> Result<(), gst::FlowError> {
+        if started.ready.is_some() {
+            return Ok(());
+        }
+
+        gst::debug!(CAT, imp = self, "Creating Zenoh resources for the first buffer (lazy-start)");
+        let result = self.create_zenoh_resources().and_then(|ready| {
+            let waited = self
+                .wait_for_connection(ready.session.as_session())
+                .and_then(|_| self.wait_for_subscribers(&ready.has_subscribers));
+            if let Err(err) = waited {
+                ready.release();
+                return Err(err);
+            }
+            Ok(ready)
+        });
+        match result {
+            Ok(ready) => {
+                started.ready = Some(ready);
+                Ok(())
+            }
+            Err(err) => {
+                started.stats.lock().unwrap().errors += 1;
+                self.post_error_message(err);
+                Err(gst::FlowError::Error)
+            }
+        }
+    }
+
     /// Creates the Zenoh session, publisher, and matching listener.
     ///
     /// Called during NULL→READY to set up lightweight network resources
@@ -261,13 +1210,20 @@ impl ZenohSink {
     fn create_zenoh_resources(&self) -> Result<ReadyState, gst::ErrorMessage> {
         let settings = self.settings.lock().unwrap();
         let key_expr = settings.key_expr.clone();
-        let config_file = settings.config_file.clone();
-        let priority = settings.priority;
-        let congestion_control = settings.congestion_control.clone();
-        let reliability = settings.reliability.clone();
-        let express = settings.express;
+        let session_config = SessionConfig {
+            config_file: settings.config_file.clone(),
+            config_json5: settings.config_json5.clone(),
+            scouting: settings.scouting,
+            endpoints_only: settings.endpoints_only,
+            tls_root_ca: settings.tls_root_ca.clone(),
+            tls_client_cert: settings.tls_client_cert.clone(),
+            tls_client_key: settings.tls_client_key.clone(),
+            username: settings.username.clone(),
+            password: settings.password.clone(),
+        };
         let external_session = settings.external_session.clone();
         let session_group = settings.session_group.clone();
+        let session_group_linger = Duration::from_millis(settings.session_group_linger_ms.into());
         drop(settings);
 
         // Validate the key expression
@@ -284,141 +1240,1128 @@ impl ZenohSink {
             SessionWrapper::Shared(shared_session)
         } else if let Some(ref group) = session_group {
             gst::debug!(CAT, "Using session group '{}'", group);
-            let session = crate::session::get_or_create_session(group, config_file.as_deref())
-                .map_err(|e| ZenohError::Init(e).to_error_message())?;
-            SessionWrapper::Shared(session)
+            let session =
+                crate::session::get_or_create_session(group, &session_config, session_group_linger)
+                    .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            SessionWrapper::Group(session)
         } else {
             gst::debug!(CAT, "Creating new Zenoh session");
-            let config = match config_file {
-                Some(path) if !path.is_empty() => {
-                    gst::debug!(CAT, "Loading Zenoh config from {}", path);
-                    zenoh::Config::from_file(&path)
-                        .map_err(|e| ZenohError::Init(e).to_error_message())?
+            if let Some(ref path) = session_config.config_file
+                && !path.is_empty()
+            {
+                gst::debug!(CAT, "Loading Zenoh config from {}", path);
+            }
+            let session = session_config
+                .open()
+                .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            SessionWrapper::Owned(session)
+        };
+
+        // Fresh publishers pick up the current settings
+        self.publishers_outdated.store(false, Ordering::SeqCst);
+        let has_subscribers = Arc::new(AtomicBool::new(false));
+        let publishers = self.declare_publishers(session_wrapper.as_session(), &has_subscribers)?;
+        let caps_queryables = if self.settings.lock().unwrap().sends_caps() {
+            self.declare_caps_queryables(session_wrapper.as_session(), &publishers)?
+        } else {
+            Vec::new()
+        };
+        let fku_subscribers = if self.settings.lock().unwrap().enable_fku {
+            self.declare_fku_subscribers(session_wrapper.as_session(), &publishers)?
+        } else {
+            Vec::new()
+        };
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        let compression_peers = if self.settings.lock().unwrap().compression_negotiate {
+            let key_exprs = publishers.iter().map(|publisher| publisher.key_expr().as_str());
+            Some(
+                crate::compression::CompressionPeers::watch(session_wrapper.as_session(), key_exprs)
+                    .map_err(|e| ZenohError::Init(e).to_error_message())?,
+            )
+        } else {
+            None
+        };
+
+        let (max_routed_publishers, connectivity_poll_ms) = {
+            let settings = self.settings.lock().unwrap();
+            (settings.max_routed_publishers, settings.connectivity_poll_ms)
+        };
+        let connectivity = ConnectivityMonitor::start(
+            session_wrapper.as_session(),
+            self.obj().upcast_ref(),
+            connectivity_poll_ms,
+        );
+        Ok(ReadyState {
+            session: session_wrapper,
+            publishers,
+            has_subscribers,
+            caps_queryables,
+            fku_subscribers,
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            compression_peers,
+            routed_publishers: LruCache::new(max_routed_publishers as usize),
+            connectivity,
+        })
+    }
+
+    /// Declares a queryable on `<key>/caps` next to each publisher that replies
+    /// with the caps currently negotiated on the sink pad.
+    ///
+    /// zenohsrc queries it when starting, so a subscriber joining between two
+    /// periodic caps transmissions can still interpret the first buffers.
+    /// No reply is sent while no caps have been negotiated yet.
+    fn declare_caps_queryables(
+        &self,
+        session: &zenoh::Session,
+        publishers: &[Arc<zenoh::pubsub::Publisher<'static>>],
+    ) -> Result<Vec<zenoh::query::Queryable<()>>, gst::ErrorMessage> {
+        publishers
+            .iter()
+            .map(|publisher| {
+                let caps_key = crate::metadata::caps_query_key_expr(publisher.key_expr());
+                let element_weak = self.obj().downgrade();
+                let reply_key = caps_key.clone();
+                session
+                    .declare_queryable(caps_key)
+                    .callback(move |query| {
+                        let Some(caps) = element_weak
+                            .upgrade()
+                            .and_then(|element| element.sink_pad().current_caps())
+                        else {
+                            return;
+                        };
+                        if let Err(e) = query.reply(reply_key.clone(), caps.to_string()).wait() {
+                            gst::warning!(CAT, "Failed to reply to caps query: {}", e);
+                        }
+                    })
+                    .wait()
+                    .map_err(|e| ZenohError::Init(e).to_error_message())
+            })
+            .collect()
+    }
+
+    /// Declares a subscriber on `<key>/fku` next to each publisher that pushes
+    /// the force-key-unit requests of zenohsrc's `enable-fku` upstream, so the
+    /// encoder produces a key unit for the receivers that lost one.
+    fn declare_fku_subscribers(
+        &self,
+        session: &zenoh::Session,
+        publishers: &[Arc<zenoh::pubsub::Publisher<'static>>],
+    ) -> Result<Vec<zenoh::pubsub::Subscriber<()>>, gst::ErrorMessage> {
+        publishers
+            .iter()
+            .map(|publisher| {
+                let fku_key = crate::metadata::fku_key_expr(publisher.key_expr());
+                gst::debug!(CAT, imp = self, "Subscribing to FKU requests on '{}'", fku_key);
+                let element_weak = self.obj().downgrade();
+                session
+                    .declare_subscriber(fku_key)
+                    .callback(move |sample| {
+                        let payload = sample.payload().try_to_string();
+                        let event = payload
+                            .ok()
+                            .and_then(|payload| deserialize_force_key_unit(&payload));
+                        if let (Some(event), Some(element)) = (event, element_weak.upgrade()) {
+                            gst::debug!(
+                                CAT,
+                                obj = element,
+                                "Force key unit requested on '{}'",
+                                sample.key_expr()
+                            );
+                            element.sink_pad().push_event(event);
+                        }
+                    })
+                    .wait()
+                    .map_err(|e| ZenohError::Init(e).to_error_message())
+            })
+            .collect()
+    }
+
+    /// With `caps-channel=sidecar-key`, publishes the `caps` due on the
+    /// `<key>/caps` sidecar key of every publisher and returns `None`, so they
+    /// are left out of the attachments. Returns them unchanged otherwise.
+    ///
+    /// The caps go out with the priority of the data, ahead of the samples
+    /// they apply to. Failures are posted as `zenoh-error` and don't stop the
+    /// stream: the caps queryable still serves the current caps.
+    fn route_caps(&self, started: &Started, caps: Option<gst::Caps>) -> Option<gst::Caps> {
+        let caps = caps?;
+        if self.settings.lock().unwrap().caps_channel != CapsChannel::SidecarKey {
+            return Some(caps);
+        }
+        let ready = started.ready.as_ref().expect("Zenoh resources created above");
+
+        let payload = caps.to_string();
+        for publisher in &ready.publishers {
+            let caps_key = crate::metadata::caps_query_key_expr(publisher.key_expr());
+            gst::debug!(CAT, imp = self, "Publishing caps on '{}': {}", caps_key, payload);
+            let result = ready
+                .session
+                .as_session()
+                .put(caps_key.as_str(), payload.clone())
+                .priority(publisher.priority())
+                .congestion_control(CongestionControl::Block)
+                .wait();
+            if let Err(e) = result {
+                gst::warning!(CAT, imp = self, "Failed to publish caps on '{}': {}", caps_key, e);
+                started.stats.lock().unwrap().errors += 1;
+                post_error_message(self.obj().upcast_ref(), &caps_key, &e);
+            }
+        }
+        None
+    }
+
+    /// Declares one publisher per configured key expression with the current
+    /// QoS settings, and wires their matching listeners to `has_subscribers`.
+    ///
+    /// Used both when creating the Zenoh resources and when the publishers
+    /// are re-declared at runtime (e.g. after an `express` change).
+    fn declare_publishers(
+        &self,
+        session: &zenoh::Session,
+        has_subscribers: &Arc<AtomicBool>,
+    ) -> Result<Vec<Arc<zenoh::pubsub::Publisher<'static>>>, gst::ErrorMessage> {
+        let settings = self.settings.lock().unwrap();
+        let key_expr = crate::key_vars::expand(&settings.key_expr, settings.key_vars.as_deref())
+            .map_err(|placeholder| {
+                ZenohError::KeyExpr {
+                    key_expr: settings.key_expr.clone(),
+                    reason: format!("unresolved placeholder {} (set it in key-vars)", placeholder),
                 }
-                _ => zenoh::Config::default(),
-            };
-            let session = zenoh::open(config)
+                .to_error_message()
+            })?;
+        let qos = self.publisher_qos(&settings);
+        drop(settings);
+
+        gst::debug!(CAT, "Creating publisher with key_expr='{}', {:?}", key_expr, qos);
+
+        let keys = parse_key_exprs(&key_expr).map_err(|e| e.to_error_message())?;
+
+        let mut publishers = Vec::with_capacity(keys.len());
+        for key in keys {
+            let publisher = qos.declare(session, key).map_err(|e| e.to_error_message())?;
+            gst::debug!(CAT, "Publisher created with key_expr='{}'", publisher.key_expr());
+            publishers.push(Arc::new(publisher));
+        }
+
+        // Set up matching status tracking via Zenoh's background callbacks.
+        // With several keys, the element has subscribers as soon as any key does.
+        let per_key_matching: Arc<Vec<AtomicBool>> =
+            Arc::new(publishers.iter().map(|_| AtomicBool::new(false)).collect());
+
+        for (index, publisher) in publishers.iter().enumerate() {
+            let has_subscribers = has_subscribers.clone();
+            let per_key_matching = per_key_matching.clone();
+            let element_weak = self.obj().downgrade();
+
+            publisher
+                .matching_listener()
+                .callback(move |status| {
+                    per_key_matching[index].store(status.matching(), Ordering::Relaxed);
+                    let matching = per_key_matching.iter().any(|m| m.load(Ordering::Relaxed));
+                    if has_subscribers.swap(matching, Ordering::Relaxed) == matching {
+                        // Aggregate state unchanged (another key still matches)
+                        return;
+                    }
+
+                    if let Some(element) = element_weak.upgrade() {
+                        element.emit_by_name::<()>("matching-changed", &[&matching]);
+
+                        let element_ref = element.upcast_ref::<gst::Element>();
+                        if let Some(bus) = element_ref.bus() {
+                            let s = gst::Structure::builder("zenoh-matching-changed")
+                                .field("has-subscribers", matching)
+                                .build();
+                            let _ = bus
+                                .post(gst::message::Element::builder(s).src(element_ref).build());
+                        }
+                    }
+                })
+                .background()
                 .wait()
                 .map_err(|e| ZenohError::Init(e).to_error_message())?;
-            SessionWrapper::Owned(session)
+
+            // Check initial matching status (the callback only fires on *changes*)
+            let status = crate::session::wait_off_runtime(|| publisher.matching_status().wait());
+            if let Some(Ok(initial_status)) = status {
+                per_key_matching[index].store(initial_status.matching(), Ordering::Relaxed);
+            }
+        }
+
+        let initial_matching = per_key_matching.iter().any(|m| m.load(Ordering::Relaxed));
+        has_subscribers.store(initial_matching, Ordering::Relaxed);
+        gst::debug!(
+            CAT,
+            "Initial matching status: has_subscribers={}",
+            initial_matching
+        );
+
+        Ok(publishers)
+    }
+
+    /// Returns the stream tags to attach to the next sample: when they changed
+    /// since last published, and along with the caps so that subscribers
+    /// joining later get them too.
+    ///
+    /// Never with `payload-only`.
+    fn tags_to_send(&self, with_caps: bool) -> Option<gst::TagList> {
+        if self.settings.lock().unwrap().payload_only {
+            return None;
+        }
+        let changed = self.tags_changed.swap(false, Ordering::AcqRel);
+        if changed || with_caps {
+            self.tags.lock().unwrap().clone()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the stream identity to attach to the next sample, on the same
+    /// terms as [`Self::tags_to_send`].
+    fn stream_identity_to_send(&self, with_caps: bool) -> Option<StreamIdentity> {
+        if self.settings.lock().unwrap().payload_only {
+            return None;
+        }
+        let changed = self.stream_identity_changed.swap(false, Ordering::AcqRel);
+        if changed || with_caps {
+            self.stream_identity.lock().unwrap().clone()
+        } else {
+            None
+        }
+    }
+
+    /// Applies `max-bitrate` before publishing: waits until the egress is back
+    /// under the limit, or with `congestion-control=drop` returns `None` so the
+    /// caller drops the buffer. Otherwise returns how long it waited.
+    ///
+    /// Called without the state lock, so property reads aren't blocked meanwhile.
+    fn throttle(&self) -> Result<Option<Duration>, gst::FlowError> {
+        let (max_bitrate, drop_on_congestion) = {
+            let settings = self.settings.lock().unwrap();
+            (
+                settings.max_bitrate,
+                settings.effective_congestion_control() == CongestionControl::Drop,
+            )
+        };
+        if max_bitrate == 0 {
+            // Don't keep a debt from before the limit was lifted
+            self.rate_limiter.lock().unwrap().reset();
+            return Ok(Some(Duration::ZERO));
+        }
+
+        let start = Instant::now();
+        loop {
+            let delay = self.rate_limiter.lock().unwrap().delay(max_bitrate);
+            if delay.is_zero() {
+                return Ok(Some(start.elapsed()));
+            }
+            if drop_on_congestion {
+                return Ok(None);
+            }
+            if self.flushing.load(Ordering::SeqCst) {
+                return Err(gst::FlowError::Flushing);
+            }
+            std::thread::sleep(delay.min(RATE_LIMIT_POLL));
+        }
+    }
+
+    /// Returns the publishers on `<key>/<suffix>` for every configured key,
+    /// declaring them with the current QoS when `suffix` isn't cached yet
+    /// (`key-suffix-from-meta`). Declaring evicts the least recently used suffix
+    /// once `max-routed-publishers` are cached.
+    fn routed_publishers(
+        &self,
+        ready: &mut ReadyState,
+        suffix: &str,
+    ) -> Result<Vec<Arc<zenoh::pubsub::Publisher<'static>>>, ZenohError> {
+        if let Some(publishers) = ready.routed_publishers.get(suffix) {
+            return Ok(publishers.clone());
+        }
+
+        let qos = self.publisher_qos(&self.settings.lock().unwrap());
+        let mut publishers = Vec::with_capacity(ready.publishers.len());
+        for base in &ready.publishers {
+            let key_expr = crate::routing::routed_key_expr(base.key_expr().as_str(), suffix);
+            let key = OwnedKeyExpr::try_from(key_expr.clone()).map_err(|e| {
+                ZenohError::KeyExpr {
+                    key_expr,
+                    reason: e.to_string(),
+                }
+            })?;
+            let publisher = qos.declare(ready.session.as_session(), key)?;
+            gst::debug!(
+                CAT,
+                imp = self,
+                "Routed publisher created with key_expr='{}'",
+                publisher.key_expr()
+            );
+            publishers.push(Arc::new(publisher));
+        }
+
+        // Dropping the evicted publishers undeclares them
+        if let Some((evicted, _)) = ready
+            .routed_publishers
+            .insert(suffix.to_string(), publishers.clone())
+        {
+            gst::debug!(
+                CAT,
+                imp = self,
+                "Undeclaring publishers of least recently used suffix '{}'",
+                evicted
+            );
+        }
+        Ok(publishers)
+    }
+
+    /// Returns the QoS and encoding to declare publishers with.
+    fn publisher_qos(&self, settings: &Settings) -> PublisherQos {
+        PublisherQos {
+            encoding: self.encoding.lock().unwrap().clone(),
+            ..PublisherQos::from_settings(settings)
+        }
+    }
+
+    /// Updates the encoding the publishers are declared with for
+    /// `auto-encoding`, re-declaring them when it changed.
+    fn update_encoding(&self, auto_encoding: bool, caps: Option<&gst::CapsRef>) {
+        let encoding = caps
+            .filter(|_| auto_encoding)
+            .and_then(crate::encoding::encoding_for_caps);
+        let mut current = self.encoding.lock().unwrap();
+        if *current != encoding {
+            gst::debug!(CAT, imp = self, "Publishing with encoding {:?}", encoding);
+            *current = encoding;
+            // Takes effect on the next buffer if the publishers already exist
+            self.publishers_outdated.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Publishes a gap event as an empty `GAP` buffer covering the same time,
+    /// so receivers push a gap downstream instead of seeing nothing.
+    ///
+    /// Only done with `send-buffer-meta`, without which the flag and the
+    /// timing wouldn't reach the receivers.
+    fn publish_gap(&self, gap: &gst::event::Gap) {
+        let send_buffer_meta = self.settings.lock().unwrap().sends_buffer_meta();
+        if !send_buffer_meta || !self.state.lock().unwrap().is_started() {
+            return;
+        }
+
+        let (timestamp, duration) = gap.get();
+        let mut buffer = gst::Buffer::new();
+        {
+            let buffer_mut = buffer.get_mut().expect("newly created buffer");
+            buffer_mut.set_pts(timestamp);
+            buffer_mut.set_duration(duration);
+            buffer_mut.set_flags(gst::BufferFlags::GAP | gst::BufferFlags::DROPPABLE);
+        }
+        gst::log!(CAT, imp = self, "Publishing gap at {} for {:?}", timestamp, duration);
+        if let Err(e) = self.render(&buffer) {
+            gst::debug!(CAT, imp = self, "Failed to publish gap: {:?}", e);
+        }
+    }
+
+    /// With `forward-events`, publishes `event` on the `<key>/events` sidecar
+    /// key of every publisher, for zenohsrc to push it downstream again.
+    ///
+    /// Only EOS and custom downstream events are forwarded. Like the caps on
+    /// the sidecar key, they go out with the priority of the data; failures
+    /// are posted as `zenoh-error` and don't stop the stream.
+    fn forward_event(&self, event: &gst::Event) {
+        if !self.settings.lock().unwrap().forward_events {
+            return;
+        }
+        let Some(payload) = crate::metadata::serialize_event(event) else {
+            return;
+        };
+        let state = self.state.lock().unwrap();
+        let State::Started(ref started) = *state else {
+            return;
+        };
+        let Some(ref ready) = started.ready else {
+            return;
+        };
+
+        for publisher in &ready.publishers {
+            let events_key = crate::metadata::events_key_expr(publisher.key_expr());
+            gst::debug!(CAT, imp = self, "Forwarding {} on '{}'", payload, events_key);
+            let result = ready
+                .session
+                .as_session()
+                .put(events_key.as_str(), payload.clone())
+                .priority(publisher.priority())
+                .congestion_control(CongestionControl::Block)
+                .wait();
+            if let Err(e) = result {
+                gst::warning!(
+                    CAT,
+                    imp = self,
+                    "Failed to forward event on '{}': {}",
+                    events_key,
+                    e
+                );
+                started.stats.lock().unwrap().errors += 1;
+                post_error_message(self.obj().upcast_ref(), &events_key, &e);
+            }
+        }
+    }
+
+    /// Re-declares the publishers if a runtime QoS change is pending.
+    ///
+    /// Called from the streaming thread before publishing, so the switch
+    /// happens between buffers and no data is lost. Statistics live in
+    /// `Started` and are kept across the transition. On failure the old
+    /// publishers stay in use and a `zenoh-error` message is posted.
+    fn refresh_publishers(&self, started: &mut Started) {
+        // Resources created later (lazy-start) pick up the current settings
+        let Some(ready) = started.ready.as_mut() else {
+            return;
+        };
+        if !self.publishers_outdated.swap(false, Ordering::SeqCst) {
+            return;
+        }
+
+        gst::debug!(CAT, imp = self, "Re-declaring publishers after QoS change");
+        match self.declare_publishers(ready.session.as_session(), &ready.has_subscribers) {
+            Ok(publishers) => {
+                // Dropping the old publishers undeclares them. Routed ones are
+                // declared again with the new QoS when next used.
+                ready.publishers = publishers;
+                ready.routed_publishers.clear();
+            }
+            Err(e) => {
+                gst::warning!(
+                    CAT,
+                    imp = self,
+                    "Failed to re-declare publishers, keeping the previous ones: {}",
+                    e
+                );
+                started.stats.lock().unwrap().errors += 1;
+                let key_expr = self.settings.lock().unwrap().key_expr.clone();
+                post_error_message(self.obj().upcast_ref(), &key_expr, &e);
+            }
+        }
+    }
+
+    /// Creates the shared-memory provider if `use-shm` is enabled.
+    ///
+    /// The pool is backed by POSIX shared memory and sized by `shm-size`.
+    #[cfg(feature = "shared-memory")]
+    fn create_shm_provider(
+        &self,
+    ) -> Result<Option<ShmProvider<PosixShmProviderBackend>>, gst::ErrorMessage> {
+        let (use_shm, shm_size) = {
+            let settings = self.settings.lock().unwrap();
+            (settings.use_shm, settings.shm_size)
         };
+        if !use_shm {
+            return Ok(None);
+        }
 
+        let backend = PosixShmProviderBackend::builder(shm_size as usize)
+            .wait()
+            .map_err(|e| {
+                gst::error_msg!(
+                    gst::ResourceError::OpenWrite,
+                    [
+                        "Failed to create shared-memory pool of {} bytes: {}",
+                        shm_size,
+                        e
+                    ]
+                )
+            })?;
         gst::debug!(
             CAT,
-            "Creating publisher with key_expr='{}', priority={}, congestion_control='{}', reliability='{}', express={}",
-            key_expr,
-            priority,
-            congestion_control,
-            reliability,
-            express
+            imp = self,
+            "Publishing from a {} byte shared-memory pool",
+            shm_size
         );
+        Ok(Some(ShmProviderBuilder::backend(backend).wait()))
+    }
 
-        let owned = OwnedKeyExpr::try_from(key_expr.clone()).map_err(|e| {
-            ZenohError::KeyExpr {
-                key_expr: key_expr.clone(),
-                reason: e.to_string(),
+    /// Converts outgoing data into a Zenoh payload.
+    ///
+    /// With a shared-memory provider the data is copied into an SHM buffer, so
+    /// SHM-capable subscribers on the same host map it instead of receiving it
+    /// over the network; Zenoh sends a regular copy to the others. When the
+    /// pool can't hold the buffer (too large, or exhausted by in-flight
+    /// buffers) it falls back to a regular payload.
+    fn make_payload(&self, started: &Started, data: &[u8]) -> zenoh::bytes::ZBytes {
+        #[cfg(feature = "shared-memory")]
+        if let Some(ref provider) = started.shm_provider {
+            let allocation = provider
+                .alloc_layout(data.len())
+                .map_err(|e| e.to_string())
+                .and_then(|layout| {
+                    layout
+                        .alloc()
+                        .with_policy::<GarbageCollect>()
+                        .wait()
+                        .map_err(|e| format!("{:?}", e))
+                });
+            match allocation {
+                Ok(mut shm_buf) => {
+                    shm_buf.copy_from_slice(data);
+                    return shm_buf.into();
+                }
+                Err(e) => {
+                    gst::debug!(
+                        CAT,
+                        imp = self,
+                        "Shared-memory allocation of {} bytes failed ({}), using a regular payload",
+                        data.len(),
+                        e
+                    );
+                }
             }
-            .to_error_message()
-        })?;
+        }
+        #[cfg(not(feature = "shared-memory"))]
+        let _ = started;
+
+        zenoh::bytes::ZBytes::from(data)
+    }
+
+    /// Compresses `data` with the configured algorithm, unless compression is
+    /// disabled, `data` is smaller than `compression-min-size` or, with
+    /// `compression-negotiate`, not every advertising receiver supports the algorithm.
+    ///
+    /// Returns the compressed data and the algorithm used, or `None` when
+    /// `data` goes out uncompressed.
+    #[cfg(any(
+        feature = "compression-zstd",
+        feature = "compression-lz4",
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
+    ))]
+    fn compress_payload(
+        &self,
+        started: &Started,
+        data: &[u8],
+    ) -> Result<Option<(Vec<u8>, crate::compression::CompressionType)>, CompressionError> {
+        let (compression_type, compression_level, compression_min_size) = {
+            let settings = self.settings.lock().unwrap();
+            (
+                settings.compression,
+                settings.compression_level,
+                settings.compression_min_size,
+            )
+        };
+
+        // With compression-negotiate, buffers go out uncompressed unless every
+        // advertising receiver supports the algorithm
+        let unsupported_by_receivers = started
+            .ready
+            .as_ref()
+            .and_then(|ready| ready.compression_peers.as_ref())
+            .is_some_and(|peers| !peers.supports(compression_type));
+
+        if compression_type == crate::compression::CompressionType::None
+            || unsupported_by_receivers
+            || data.len() < compression_min_size as usize
+        {
+            return Ok(None);
+        }
+
+        let compressed_data =
+            crate::compression::compress(data, compression_type, compression_level)?;
+        gst::trace!(
+            CAT,
+            imp = self,
+            "Compressed {} bytes to {} bytes using {:?} (level {}), ratio: {:.2}%",
+            data.len(),
+            compressed_data.len(),
+            compression_type,
+            compression_level,
+            (compressed_data.len() as f64 / data.len() as f64) * 100.0
+        );
+        Ok(Some((compressed_data, compression_type)))
+    }
+
+    /// Publishes the buffers of `list`, one sample per buffer or, with
+    /// `batch_lists`, all of them as a single batch.
+    fn publish_list(
+        &self,
+        list: &gst::BufferList,
+        batch_lists: bool,
+    ) -> Result<gst::FlowSuccess, gst::FlowError> {
+        let throttled = self.throttle()?;
+
+        let mut state_locked = self.state.lock().unwrap();
+        let State::Started(ref mut started) = *state_locked else {
+            gst::element_imp_error!(self, gst::CoreError::Failed, ["Not started yet"]);
+            return Err(gst::FlowError::Error);
+        };
+        let Some(waited) = throttled else {
+            gst::trace!(CAT, imp = self, "Dropping buffer list, over max-bitrate");
+            started.stats.lock().unwrap().dropped_rate_limit += list.len() as u64;
+            return Ok(gst::FlowSuccess::Ok);
+        };
+        if !waited.is_zero() {
+            started.stats.lock().unwrap().rate_limited_ms += waited.as_millis() as u64;
+        }
+        self.ensure_zenoh_resources(started)?;
+        self.refresh_publishers(started);
+
+        // Track statistics for the batch
+        let mut total_bytes = 0u64;
+        let mut total_attachment_bytes = 0u64;
+        let mut total_messages = 0u64;
+        let mut total_uncompressed_bytes = 0u64;
+        let mut total_compressed_bytes = 0u64;
+        let mut errors_count = 0u64;
+        let mut dropped_oversize = 0u64;
+        let mut dropped_congestion = 0u64;
+        let mut dropped_timeout = 0u64;
+        let mut dropped_filtered = 0u64;
+
+        // Get caps and drop settings
+        let (
+            send_caps,
+            caps_interval,
+            max_buffer_size,
+            drop_on_congestion,
+            keyframe_qos,
+            keyframes_only,
+            sequence_numbers,
+            put_timeout,
+            user_metadata,
+            send_buffer_meta,
+        ) = {
+            let settings = self.settings.lock().unwrap();
+            (
+                settings.sends_caps(),
+                settings.caps_interval,
+                settings.max_buffer_size,
+                settings.effective_congestion_control() == CongestionControl::Drop,
+                KeyframeQos::from_settings(&settings),
+                settings.keyframes_only,
+                settings.sequence_numbers,
+                settings.put_timeout(),
+                settings.user_metadata.clone().filter(|s| s.n_fields() > 0),
+                settings.sends_buffer_meta(),
+            )
+        };
+
+        let list_caps = if send_caps {
+            if let Some(caps) = self.obj().sink_pad().current_caps() {
+                let mut should_send = false;
+
+                if !started.caps_sent.load(Ordering::Acquire) {
+                    gst::debug!(
+                        CAT,
+                        imp = self,
+                        "Sending caps on first buffer list: {}",
+                        caps
+                    );
+                    should_send = true;
+                    started.caps_sent.store(true, Ordering::Release);
+                    *started.last_caps.lock().unwrap() = Some(caps.clone());
+                    *started.last_caps_time.lock().unwrap() = Some(std::time::Instant::now());
+                } else {
+                    let last_caps = started.last_caps.lock().unwrap();
+                    if last_caps.as_ref() != Some(&caps) {
+                        gst::debug!(CAT, imp = self, "Caps changed in buffer list: {}", caps);
+                        should_send = true;
+                        drop(last_caps);
+                        *started.last_caps.lock().unwrap() = Some(caps.clone());
+                        *started.last_caps_time.lock().unwrap() = Some(std::time::Instant::now());
+                    } else if caps_interval > 0 {
+                        let last_time = started.last_caps_time.lock().unwrap();
+                        if let Some(last) = *last_time
+                            && last.elapsed().as_secs() >= caps_interval as u64
+                        {
+                            should_send = true;
+                            drop(last_time);
+                            *started.last_caps_time.lock().unwrap() =
+                                Some(std::time::Instant::now());
+                        }
+                    }
+                }
 
-        let zenoh_priority = Priority::try_from(priority).unwrap_or(Priority::default());
+                should_send.then_some(caps)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        let list_tags = self.tags_to_send(list_caps.is_some());
+        let list_identity = self.stream_identity_to_send(list_caps.is_some());
+        let list_caps = self.route_caps(started, list_caps);
+        // Every attachment of the list starts with the user metadata, the stream
+        // identity and the tags (when due)
+        let new_metadata = || {
+            let mut metadata_builder = MetadataBuilder::new();
+            if let Some(ref fields) = user_metadata {
+                metadata_builder = metadata_builder.user_metadata_fields(fields);
+            }
+            if let Some(ref identity) = list_identity {
+                metadata_builder = metadata_builder.stream_identity(identity);
+            }
+            if let Some(ref tags) = list_tags {
+                metadata_builder = metadata_builder.tags(tags);
+            }
+            metadata_builder
+        };
+        let caps_attachment = match list_caps {
+            Some(ref caps) => new_metadata().caps(caps).build(),
+            None if user_metadata.is_some() || list_identity.is_some() || list_tags.is_some() => {
+                new_metadata().build()
+            }
+            None => None,
+        };
+
+        // Prepare the samples to publish: one per buffer, or a single batch
+        // concatenating all buffers with batch-lists
+        let mut samples = Vec::with_capacity(if batch_lists { 1 } else { list.len() });
+        let mut batch_data = Vec::new();
+        let mut batch_sizes = Vec::new();
+        let mut batch_timing = Vec::new();
+        let mut batch_has_keyframe = false;
+        for buffer in list.iter() {
+            if keyframes_only && !is_keyframe(buffer) {
+                dropped_filtered += 1;
+                continue;
+            }
+
+            // Get buffer data
+            let b = buffer.map_readable().map_err(|_| {
+                gst::element_imp_error!(
+                    self,
+                    gst::ResourceError::Read,
+                    ["Failed to map buffer for reading in buffer list"]
+                );
+                errors_count += 1;
+                gst::FlowError::Error
+            })?;
 
-        let zenoh_congestion_control = match congestion_control.as_str() {
-            "block" => CongestionControl::Block,
-            "drop" => CongestionControl::Drop,
-            _ => {
+            if max_buffer_size > 0 && b.len() as u64 > max_buffer_size {
                 gst::warning!(
                     CAT,
-                    "Unknown congestion control '{}', using default",
-                    congestion_control
+                    imp = self,
+                    "Dropping {} byte buffer in list: exceeds max-buffer-size ({})",
+                    b.len(),
+                    max_buffer_size
                 );
-                CongestionControl::Block
+                dropped_oversize += 1;
+                continue;
             }
-        };
 
-        let zenoh_reliability = match reliability.as_str() {
-            "reliable" => Reliability::Reliable,
-            "best-effort" => Reliability::BestEffort,
-            _ => {
-                gst::warning!(CAT, "Unknown reliability '{}', using default", reliability);
-                Reliability::BestEffort
+            if batch_lists {
+                batch_data.extend_from_slice(b.as_slice());
+                batch_sizes.push(b.len());
+                batch_timing.push(BatchPartTiming::from_buffer(buffer));
+                batch_has_keyframe |= is_keyframe(buffer);
+                continue;
             }
-        };
 
-        let mut publisher_builder = session_wrapper
-            .as_session()
-            .declare_publisher(owned)
-            .priority(zenoh_priority)
-            .congestion_control(zenoh_congestion_control)
-            .reliability(zenoh_reliability);
+            // Each buffer needs its own attachment when it carries a sequence number
+            let attachment = if sequence_numbers {
+                let mut metadata_builder = new_metadata().seqnum(started.next_seqnum);
+                started.next_seqnum += 1;
+                if let Some(ref caps) = list_caps {
+                    metadata_builder = metadata_builder.caps(caps);
+                }
+                metadata_builder.build()
+            } else {
+                caps_attachment.clone()
+            };
 
-        if express {
-            publisher_builder = publisher_builder.express(true);
+            samples.push(ListSample {
+                payload: self.make_payload(started, b.as_slice()),
+                attachment,
+                size: b.len() as u64,
+                buffers: 1,
+                uncompressed_size: None,
+                keyframe: is_keyframe(buffer),
+            });
         }
 
-        let publisher = publisher_builder.wait().map_err(|e| {
-            ZenohError::Publish {
-                key_expr: key_expr.clone(),
-                source: e,
+        if !batch_sizes.is_empty() {
+            // The whole batch is one sample, so it takes a single sequence number
+            let mut metadata_builder = new_metadata().batch(&batch_sizes);
+            if send_buffer_meta {
+                metadata_builder = metadata_builder.batch_timing(&batch_timing);
+            }
+            if sequence_numbers {
+                metadata_builder = metadata_builder.seqnum(started.next_seqnum);
+                started.next_seqnum += 1;
+            }
+            if let Some(ref caps) = list_caps {
+                metadata_builder = metadata_builder.caps(caps);
             }
-            .to_error_message()
-        })?;
 
-        gst::debug!(
-            CAT,
-            "Publisher created with key_expr='{}', priority={}, congestion_control='{}', reliability='{}', express={}",
-            key_expr,
-            priority,
-            congestion_control,
-            reliability,
-            express
-        );
+            // The batch is compressed as a whole, like a single buffer
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            let batch = match self.compress_payload(started, &batch_data) {
+                Ok(Some((compressed_data, compression_type))) => {
+                    metadata_builder = metadata_builder.user_metadata(
+                        crate::metadata::keys::COMPRESSION,
+                        compression_type.to_metadata_value(),
+                    );
+                    Some((compressed_data, Some(batch_data.len() as u64)))
+                }
+                Ok(None) => Some((batch_data, None)),
+                Err(e) => {
+                    gst::warning!(CAT, imp = self, "Compression failed: {}, dropping batch", e);
+                    errors_count += 1;
+                    started.stats.lock().unwrap().dropped_compression_error +=
+                        batch_sizes.len() as u64;
+                    None
+                }
+            };
+            #[cfg(not(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            )))]
+            let batch = Some((batch_data, None));
+
+            if let Some((batch_data, uncompressed_size)) = batch {
+                gst::trace!(
+                    CAT,
+                    imp = self,
+                    "Publishing {} buffers as one {} byte batch",
+                    batch_sizes.len(),
+                    batch_data.len()
+                );
+                samples.push(ListSample {
+                    payload: self.make_payload(started, &batch_data),
+                    attachment: metadata_builder.build(),
+                    size: batch_data.len() as u64,
+                    buffers: batch_sizes.len() as u64,
+                    uncompressed_size,
+                    keyframe: batch_has_keyframe,
+                });
+            }
+        }
 
-        // Set up matching status tracking via Zenoh's background callback.
-        let has_subscribers = Arc::new(AtomicBool::new(false));
-        {
-            let has_subscribers = has_subscribers.clone();
-            let element_weak = self.obj().downgrade();
+        // Send the samples on every configured key expression
+        for sample in samples {
+            let attachment_len = sample.attachment.as_ref().map_or(0, |a| a.len() as u64);
+            let buffer_qos = keyframe_qos.filter(|_| sample.keyframe);
+            let ready = started.ready.as_ref().expect("Zenoh resources created above");
+            for publisher in &ready.publishers {
+                let result = publish(
+                    &mut started.put_worker,
+                    put_timeout,
+                    ready.session.as_session(),
+                    publisher,
+                    sample.payload.clone(),
+                    sample.attachment.clone(),
+                    buffer_qos,
+                );
+                let Some(result) = result else {
+                    gst::warning!(
+                        CAT,
+                        imp = self,
+                        "Dropping buffer in list, publishing on '{}' timed out",
+                        publisher.key_expr()
+                    );
+                    dropped_timeout += sample.buffers;
+                    continue;
+                };
 
-            publisher
-                .matching_listener()
-                .callback(move |status| {
-                    let matching = status.matching();
-                    has_subscribers.store(matching, Ordering::Relaxed);
+                match result {
+                    Ok(_) => {
+                        total_bytes += sample.size;
+                        total_attachment_bytes += attachment_len;
+                        total_messages += sample.buffers;
+                        if let Some(uncompressed_size) = sample.uncompressed_size {
+                            total_uncompressed_bytes += uncompressed_size;
+                            total_compressed_bytes += sample.size;
+                        }
+                    }
+                    Err(e) if drop_on_congestion => {
+                        gst::warning!(
+                            CAT,
+                            imp = self,
+                            "Dropping buffer in list, publishing on '{}' failed: {}",
+                            publisher.key_expr(),
+                            e
+                        );
+                        dropped_congestion += sample.buffers;
+                    }
+                    Err(e) => {
+                        errors_count += 1;
+
+                        // Get key expression for error reporting
+                        let key_expr = publisher.key_expr().to_string();
+                        let error_msg = format!("{}", e);
+                        let err = ZenohError::Publish {
+                            key_expr: key_expr.clone(),
+                            source: e,
+                        };
+
+                        if error_msg.contains("timeout")
+                            || error_msg.contains("connection")
+                            || error_msg.contains("network")
+                        {
+                            gst::warning!(
+                                CAT,
+                                imp = self,
+                                "Network error in buffer list: {}",
+                                err
+                            );
+                        } else {
+                            gst::warning!(
+                                CAT,
+                                imp = self,
+                                "Error publishing buffer in list: {}",
+                                err
+                            );
+                        }
 
-                    if let Some(element) = element_weak.upgrade() {
-                        element.emit_by_name::<()>("matching-changed", &[&matching]);
+                        post_error_message(self.obj().upcast_ref(), &key_expr, &err);
 
-                        let element_ref = element.upcast_ref::<gst::Element>();
-                        if let Some(bus) = element_ref.bus() {
-                            let s = gst::Structure::builder("zenoh-matching-changed")
-                                .field("has-subscribers", matching)
-                                .build();
-                            let _ = bus
-                                .post(gst::message::Element::builder(s).src(element_ref).build());
-                        }
+                        // Continue processing remaining buffers instead of failing immediately
+                        // This provides better resilience for batch operations
                     }
-                })
-                .background()
-                .wait()
-                .map_err(|e| ZenohError::Init(e).to_error_message())?;
+                }
+            }
         }
 
-        // Check initial matching status (the callback only fires on *changes*)
-        if let Ok(initial_status) = publisher.matching_status().wait() {
-            has_subscribers.store(initial_status.matching(), Ordering::Relaxed);
-            gst::debug!(
+        // Update statistics in a single operation for better performance
+        {
+            let mut stats = started.stats.lock().unwrap();
+            stats.bytes_sent += total_bytes;
+            stats.messages_sent += total_messages;
+            stats.attachment_bytes_sent += total_attachment_bytes;
+            stats.errors += errors_count;
+            stats.dropped_oversize += dropped_oversize;
+            stats.dropped_congestion += dropped_congestion;
+            stats.dropped_timeout += dropped_timeout;
+            stats.dropped_filtered += dropped_filtered;
+            stats.bytes_before_compression += total_uncompressed_bytes;
+            stats.bytes_after_compression += total_compressed_bytes;
+            stats.rate.record(total_bytes, total_messages);
+        }
+        self.rate_limiter
+            .lock()
+            .unwrap()
+            .consume(total_bytes + total_attachment_bytes);
+
+        if errors_count > 0 {
+            gst::warning!(
                 CAT,
-                "Initial matching status: has_subscribers={}",
-                initial_status.matching()
+                imp = self,
+                "Completed buffer list with {} errors out of {} buffers",
+                errors_count,
+                list.len()
             );
         }
 
-        Ok(ReadyState {
-            _session: session_wrapper,
-            publisher,
-            has_subscribers,
-        })
+        // Return success if at least one buffer was sent successfully
+        if total_messages > 0 {
+            Ok(gst::FlowSuccess::Ok)
+        } else if errors_count > 0 {
+            // All buffers failed
+            gst::element_imp_error!(
+                self,
+                gst::ResourceError::Write,
+                ["Failed to send all buffers in list"]
+            );
+            Err(gst::FlowError::Error)
+        } else {
+            // Empty list
+            Ok(gst::FlowSuccess::Ok)
+        }
+    }
+
+    /// Holds `buffer` back for the next batch with `aggregate-window-ms`.
+    ///
+    /// Returns `None` when the buffer is to be published on its own, after the
+    /// pending batch so the order is kept: aggregation is off or the buffers
+    /// are routed (`key-suffix-from-meta`), or the buffer is a gap or at least
+    /// [`AGGREGATE_MAX_SIZE`] bytes. The batch is published once it has waited
+    /// the window, reached [`AGGREGATE_MAX_SIZE`] or taken the key frame of a
+    /// stream with delta units.
+    fn aggregate(&self, buffer: &gst::Buffer) -> Option<Result<gst::FlowSuccess, gst::FlowError>> {
+        let window = {
+            let settings = self.settings.lock().unwrap();
+            (!settings.key_suffix_from_meta).then_some(settings.aggregate_window_ms)
+        };
+        let window = Duration::from_millis(window.filter(|&window| window > 0)?.into());
+
+        let mut aggregator = self.aggregator.lock().unwrap();
+        if buffer.size() >= AGGREGATE_MAX_SIZE || buffer.flags().contains(gst::BufferFlags::GAP) {
+            return self.publish_aggregated(&mut aggregator).err().map(Err);
+        }
+
+        let keyframe = is_keyframe(buffer);
+        aggregator.delta_coded |= !keyframe;
+        aggregator.since.get_or_insert_with(Instant::now);
+        aggregator.size += buffer.size();
+        aggregator.pending.push(buffer.clone());
+
+        if aggregator.size >= AGGREGATE_MAX_SIZE
+            || (keyframe && aggregator.delta_coded)
+            || aggregator.expired(window)
+        {
+            Some(self.publish_aggregated(&mut aggregator))
+        } else {
+            Some(Ok(gst::FlowSuccess::Ok))
+        }
+    }
+
+    /// Publishes the pending batch of `aggregator`, if any, as one sample.
+    fn publish_aggregated(
+        &self,
+        aggregator: &mut Aggregator,
+    ) -> Result<gst::FlowSuccess, gst::FlowError> {
+        if aggregator.pending.is_empty() {
+            return Ok(gst::FlowSuccess::Ok);
+        }
+
+        let mut list = gst::BufferList::new_sized(aggregator.pending.len());
+        {
+            let list = list.get_mut().expect("newly created list");
+            for buffer in aggregator.pending.drain(..) {
+                list.add(buffer);
+            }
+        }
+        aggregator.clear();
+        gst::trace!(CAT, imp = self, "Publishing {} aggregated buffers", list.len());
+        self.publish_list(&list, true)
+    }
+
+    /// Publishes the pending batch of `aggregate-window-ms`, if any, without
+    /// waiting for more buffers; with `expired_after`, only once it has waited
+    /// that long.
+    ///
+    /// Called on serialized events (EOS included), on stop and by the
+    /// [`AggregateTimer`]. Does nothing unless the element is started.
+    fn flush_aggregate(&self, expired_after: Option<Duration>) {
+        let mut aggregator = self.aggregator.lock().unwrap();
+        if aggregator.pending.is_empty()
+            || expired_after.is_some_and(|window| !aggregator.expired(window))
+            || !self.state.lock().unwrap().is_started()
+        {
+            return;
+        }
+        // Failures are counted and posted by publish_list
+        let _ = self.publish_aggregated(&mut aggregator);
     }
 }
 
@@ -462,23 +2405,45 @@ impl ElementImpl for ZenohSink {
                 // Create Zenoh session, publisher, and matching listener.
                 // This is lightweight — no data flows, but subscriber
                 // matching detection is available from READY state.
-                let ready_state = self.create_zenoh_resources().map_err(|err| {
-                    gst::error!(CAT, "Failed to create Zenoh resources: {:?}", err);
-                    gst::StateChangeError
-                })?;
+                // The session is opened without holding the state lock, so
+                // check the element wasn't set up or torn down meanwhile.
+                // With lazy-start nothing is created until the first buffer.
+                let ready_state = if self.settings.lock().unwrap().lazy_start {
+                    gst::debug!(CAT, "Deferring Zenoh resources to the first buffer (lazy-start)");
+                    None
+                } else {
+                    let ready_state = self.create_zenoh_resources().map_err(|err| {
+                        gst::error!(CAT, "Failed to create Zenoh resources: {:?}", err);
+                        gst::StateChangeError
+                    })?;
+                    Some(ready_state)
+                };
                 let mut state = self.state.lock().unwrap();
-                *state = State::Ready(ready_state);
+                if state.is_stopped() {
+                    *state = State::Ready(ready_state);
+                } else if let Some(ready_state) = ready_state {
+                    drop(state);
+                    gst::warning!(
+                        CAT,
+                        "State changed while opening the Zenoh session, releasing new resources"
+                    );
+                    ready_state.release();
+                }
             }
             gst::StateChange::ReadyToNull => {
                 // Clean up all Zenoh resources.
-                let mut state = self.state.lock().unwrap();
-                *state = State::Stopped;
+                self.release_zenoh_resources();
                 gst::debug!(CAT, "Zenoh resources cleaned up (READY→NULL)");
             }
             _ => {}
         }
 
-        self.parent_change_state(transition)
+        let result = self.parent_change_state(transition);
+        if result.is_err() && transition == gst::StateChange::NullToReady {
+            // The element stays in NULL: don't leave the publishers declared
+            self.release_zenoh_resources();
+        }
+        result
     }
 }
 
@@ -493,6 +2458,37 @@ impl ObjectImpl for ZenohSink {
                 glib::subclass::Signal::builder("matching-changed")
                     .param_types([bool::static_type()])
                     .build(),
+                glib::subclass::Signal::builder("session-disconnected").build(),
+                glib::subclass::Signal::builder("session-reconnected").build(),
+                glib::subclass::Signal::builder("flush")
+                    .param_types([u64::static_type()])
+                    .return_type::<bool>()
+                    .action()
+                    .class_handler(|args| {
+                        let element = args[0]
+                            .get::<super::ZenohSink>()
+                            .expect("signal arg type checked upstream");
+                        let timeout_ms = args[1]
+                            .get::<u64>()
+                            .expect("signal arg type checked upstream");
+                        Some(
+                            element
+                                .imp()
+                                .flush(Duration::from_millis(timeout_ms))
+                                .to_value(),
+                        )
+                    })
+                    .build(),
+                glib::subclass::Signal::builder("dump-state")
+                    .action()
+                    .class_handler(|args| {
+                        let element = args[0]
+                            .get::<super::ZenohSink>()
+                            .expect("signal arg type checked upstream");
+                        element.imp().dump_state();
+                        None
+                    })
+                    .build(),
             ]
         });
         SIGNALS.as_ref()
@@ -504,13 +2500,52 @@ impl ObjectImpl for ZenohSink {
                 // Key expression property
                 glib::ParamSpecString::builder("key-expr")
                     .nick("Zenoh Key Expression")
-                    .blurb("Zenoh key expression for publishing data (e.g., 'demo/video/stream', 'sensors/{device_id}/**')")
+                    .blurb("Zenoh key expression for publishing data (e.g., 'demo/video/stream', 'cameras/${device-id}/video'). A comma-separated list publishes every buffer on each key. ${name} placeholders are filled from key-vars; use $$ for a literal $.")
+                    .build(),
+                glib::ParamSpecBoxed::builder::<gst::Structure>("key-vars")
+                    .nick("Key Expression Variables")
+                    .blurb("Structure whose fields fill the ${name} placeholders of key-expr at start (e.g. 'vars, device-id=front')")
                     .build(),
                 // Config file property
                 glib::ParamSpecString::builder("config")
                     .nick("Zenoh Configuration")
                     .blurb("Path to Zenoh configuration file for custom network settings (JSON5 format)")
                     .build(),
+                glib::ParamSpecString::builder("config-json5")
+                    .nick("Zenoh Configuration (JSON5)")
+                    .blurb("Inline Zenoh configuration in JSON5 format (e.g. '{mode: \"client\", connect: {endpoints: [\"tcp/10.0.0.1:7447\"]}}'), taking precedence over config; scouting, TLS and authentication properties are applied on top")
+                    .build(),
+                glib::ParamSpecBoolean::builder("scouting")
+                    .nick("Multicast Scouting")
+                    .blurb("Discover peers via multicast scouting; disable on networks where multicast doesn't work and rely on configured endpoints")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecBoolean::builder("endpoints-only")
+                    .nick("Endpoints Only")
+                    .blurb("Connect only to the configured endpoints: client mode with multicast scouting disabled; starting fails when the configuration has no connect endpoint")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecString::builder("tls-root-ca")
+                    .nick("TLS Root CA")
+                    .blurb("Path to the CA certificate (PEM) used to verify TLS routers and peers; overrides transport/link/tls/root_ca_certificate of the config file")
+                    .build(),
+                glib::ParamSpecString::builder("tls-client-cert")
+                    .nick("TLS Client Certificate")
+                    .blurb("Path to the client certificate (PEM) presented on TLS links for mutual TLS; requires tls-client-key")
+                    .build(),
+                glib::ParamSpecString::builder("tls-client-key")
+                    .nick("TLS Client Key")
+                    .blurb("Path to the private key (PEM) of tls-client-cert")
+                    .build(),
+                glib::ParamSpecString::builder("username")
+                    .nick("Username")
+                    .blurb("User name for Zenoh usrpwd authentication; overrides transport/auth/usrpwd/user of the config file, requires password")
+                    .build(),
+                glib::ParamSpecString::builder("password")
+                    .nick("Password")
+                    .blurb("Password for Zenoh usrpwd authentication (write-only)")
+                    .write_only()
+                    .build(),
                 // Priority property
                 glib::ParamSpecUInt::builder("priority")
                     .nick("Publisher Priority")
@@ -519,6 +2554,17 @@ impl ObjectImpl for ZenohSink {
                     .minimum(1)
                     .maximum(7)
                     .build(),
+                glib::ParamSpecUInt::builder("keyframe-priority")
+                    .nick("Key Frame Priority")
+                    .blurb("Priority for key frames (buffers without the DELTA_UNIT flag), e.g. to send them ahead of delta frames: 1-7 as for priority, 0 = same as priority")
+                    .default_value(0)
+                    .maximum(7)
+                    .build(),
+                glib::ParamSpecBoolean::builder("keyframes-only")
+                    .nick("Key Frames Only")
+                    .blurb("Publish only key frames and skip buffers flagged DELTA_UNIT (counted in dropped-filtered), for sparse self-decodable preview streams; requires an upstream encoder that flags delta units")
+                    .default_value(false)
+                    .build(),
                 // Congestion control property
                 glib::ParamSpecString::builder("congestion-control")
                     .nick("Congestion Control")
@@ -537,6 +2583,19 @@ impl ObjectImpl for ZenohSink {
                     .blurb("Enable ultra-low latency mode by bypassing internal queues (increases CPU usage but reduces end-to-end latency)")
                     .default_value(false)
                     .build(),
+                // Express congestion control property
+                glib::ParamSpecEnum::builder_with_default(
+                    "express-congestion-control",
+                    ZenohExpressCongestionControl::Inherit,
+                )
+                .nick("Express Congestion Control")
+                .blurb("Congestion control used while express=true: inherit (same as congestion-control, default), block or drop, e.g. to block normally but drop in express mode. Can be changed while playing")
+                .build(),
+                // Locality property
+                glib::ParamSpecEnum::builder_with_default("locality", ZenohLocality::Any)
+                    .nick("Locality")
+                    .blurb("Subscribers allowed to receive the data: any (default), session-local (same Zenoh session only) or remote (other sessions only, prevents local echo)")
+                    .build(),
                 // Send caps property
                 glib::ParamSpecBoolean::builder("send-caps")
                     .nick("Send Capabilities")
@@ -551,26 +2610,85 @@ impl ObjectImpl for ZenohSink {
                     .minimum(0)
                     .maximum(3600)
                     .build(),
+                glib::ParamSpecEnum::builder_with_default("caps-channel", CapsChannel::Attachment)
+                    .nick("Caps Channel")
+                    .blurb("Where caps are sent: attachment (in the data samples, default) or sidecar-key (published on <key-expr>/caps, keeping the data samples free of caps metadata for non-GStreamer subscribers)")
+                    .build(),
+                glib::ParamSpecBoolean::builder("forward-events")
+                    .nick("Forward Events")
+                    .blurb("Publish EOS and custom downstream events on <key-expr>/events, for zenohsrc with forward-events to push them downstream again. Can be changed while playing")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecBoolean::builder("enable-fku")
+                    .nick("Enable Force Key Unit")
+                    .blurb("Subscribe to <key-expr>/fku and push the force-key-unit requests zenohsrc sends there with enable-fku upstream, toward the encoder")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecBoolean::builder("auto-encoding")
+                    .nick("Auto Encoding")
+                    .blurb("Publish with the Zenoh encoding matching the caps (e.g. video/x-h264 → video/h264), so native Zenoh consumers can filter by encoding. Caps without a mapping keep the default encoding")
+                    .default_value(false)
+                    .build(),
                 // Buffer metadata property
                 glib::ParamSpecBoolean::builder("send-buffer-meta")
                     .nick("Send Buffer Metadata")
-                    .blurb("Send buffer timing metadata (PTS, DTS, duration, offset, flags) with each buffer for proper A/V sync")
+                    .blurb("Send buffer timing metadata (PTS, DTS, duration, offset, flags) with each buffer for proper A/V sync, and the segment rate/base when not the defaults")
                     .default_value(true)
                     .build(),
+                glib::ParamSpecBoxed::builder::<gst::Structure>("user-metadata")
+                    .nick("User Metadata")
+                    .blurb("Fields sent as user metadata (user.<field>) with every buffer, e.g. 'user, producer=camera-07'; sent even when caps and buffer metadata aren't")
+                    .build(),
+                glib::ParamSpecBoolean::builder("sequence-numbers")
+                    .nick("Sequence Numbers")
+                    .blurb("Attach a sequence number incremented per buffer, so receivers can count lost and reordered samples (samples-lost / samples-reordered)")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecBoolean::builder("batch-lists")
+                    .nick("Batch Lists")
+                    .blurb("Publish each buffer list as one Zenoh sample concatenating its buffers, split back into buffers by zenohsrc/zenohdemux; cuts per-buffer overhead for payloaded/muxed streams")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecUInt::builder("aggregate-window-ms")
+                    .nick("Aggregate Window")
+                    .blurb("Coalesce small buffers arriving within this many milliseconds into one batched Zenoh sample, split back into buffers by zenohsrc/zenohdemux; a batch is also published when it reaches 16 KiB, on a key frame of a delta-coded stream and on EOS (0 = publish every buffer)")
+                    .default_value(0)
+                    .maximum(10_000)
+                    .build(),
+                glib::ParamSpecBoolean::builder("payload-only")
+                    .nick("Payload Only")
+                    .blurb("Publish exactly the buffer bytes without any attachment: caps, buffer metadata, tags and stream identity are left out, and starting fails if sequence-numbers, user-metadata, batch-lists, aggregate-window-ms or compression is enabled")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecBoolean::builder("key-suffix-from-meta")
+                    .nick("Key Suffix From Meta")
+                    .blurb("Publish buffers carrying a GstZenohKeyMeta on <key-expr>/<meta key expression> instead of key-expr, declaring these publishers on first use; buffers without the meta use key-expr")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecUInt::builder("max-routed-publishers")
+                    .nick("Max Routed Publishers")
+                    .blurb("Maximum number of key suffixes (key-suffix-from-meta) whose publishers stay declared; the least recently used one is undeclared to make room")
+                    .minimum(1)
+                    .default_value(DEFAULT_MAX_ROUTED_PUBLISHERS)
+                    .build(),
                 // Compression properties (conditional on features)
                 #[cfg(any(
                     feature = "compression-zstd",
                     feature = "compression-lz4",
-                    feature = "compression-gzip"
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
                 ))]
                 glib::ParamSpecEnum::builder_with_default("compression", crate::compression::CompressionType::None)
                     .nick("Compression")
-                    .blurb("Compression algorithm to use: none (default), zstd (best ratio), lz4 (fastest), or gzip (compatible)")
+                    .blurb("Compression algorithm to use: none (default), zstd (best ratio), lz4 (fastest), gzip (compatible), brotli (text-like payloads) or snappy (very fast); only algorithms enabled at build time are available")
                     .build(),
                 #[cfg(any(
                     feature = "compression-zstd",
                     feature = "compression-lz4",
-                    feature = "compression-gzip"
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
                 ))]
                 glib::ParamSpecInt::builder("compression-level")
                     .nick("Compression Level")
@@ -579,18 +2697,138 @@ impl ObjectImpl for ZenohSink {
                     .minimum(1)
                     .maximum(9)
                     .build(),
+                #[cfg(any(
+                    feature = "compression-zstd",
+                    feature = "compression-lz4",
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
+                ))]
+                glib::ParamSpecUInt::builder("compression-min-size")
+                    .nick("Compression Minimum Size")
+                    .blurb("Buffers smaller than this many bytes are sent uncompressed, avoiding wasted CPU and negative ratios on small messages (0 = compress every buffer)")
+                    .default_value(0)
+                    .build(),
+                #[cfg(any(
+                    feature = "compression-zstd",
+                    feature = "compression-lz4",
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
+                ))]
+                glib::ParamSpecBoolean::builder("compression-negotiate")
+                    .nick("Compression Negotiate")
+                    .blurb("Only compress while receivers advertise their algorithms (zenohsrc and zenohdemux built with compression do) and all of them support the compression algorithm, sending uncompressed otherwise. Subscribers advertising nothing aren't seen, so they may still get compressed buffers")
+                    .default_value(false)
+                    .build(),
+                // Shared-memory properties (conditional on feature)
+                #[cfg(feature = "shared-memory")]
+                glib::ParamSpecBoolean::builder("use-shm")
+                    .nick("Use Shared Memory")
+                    .blurb("Allocate payloads from a Zenoh shared-memory pool so local subscribers receive them without copies. Remote or non-SHM subscribers transparently get regular messages.")
+                    .default_value(false)
+                    .build(),
+                #[cfg(feature = "shared-memory")]
+                glib::ParamSpecUInt64::builder("shm-size")
+                    .nick("Shared Memory Size")
+                    .blurb("Size in bytes of the shared-memory pool used when use-shm is enabled (should hold several buffers)")
+                    .default_value(DEFAULT_SHM_SIZE)
+                    .minimum(4096)
+                    .build(),
+                glib::ParamSpecUInt64::builder("max-buffer-size")
+                    .nick("Max Buffer Size")
+                    .blurb("Drop buffers whose payload (after compression) is larger than this many bytes instead of publishing them (0 = unlimited)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt64::builder("max-bitrate")
+                    .nick("Max Bitrate")
+                    .blurb("Limit the egress to this many bits per second (payload and metadata), waiting before publishing or, with congestion-control=drop, dropping buffers over the limit (0 = unlimited). Can be changed while playing")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt64::builder("put-timeout-ms")
+                    .nick("Put Timeout")
+                    .blurb("Give up on a put that hasn't completed after this many milliseconds (e.g. congestion-control=block on a wedged network), dropping the buffer and counting it in dropped-timeout, so the streaming thread never stalls longer (0 = wait indefinitely). Can be changed while playing")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt64::builder("wait-for-connection-ms")
+                    .nick("Wait For Connection")
+                    .blurb("When going to PLAYING, wait up to this many milliseconds for the session to reach at least one router or peer, so the first buffers aren't published into the void (0 = don't wait)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecBoolean::builder("require-connection")
+                    .nick("Require Connection")
+                    .blurb("Fail the state change when no router or peer is reachable within wait-for-connection-ms, instead of starting anyway")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecUInt::builder("connectivity-poll-ms")
+                    .nick("Connectivity Poll Interval")
+                    .blurb("How often the session is checked for router and peer connections, emitting session-disconnected when the last one is lost and session-reconnected when one is back (0 = disabled)")
+                    .default_value(crate::session::DEFAULT_CONNECTIVITY_POLL_MS)
+                    .maximum(60_000)
+                    .build(),
+                glib::ParamSpecUInt64::builder("wait-for-subscribers-ms")
+                    .nick("Wait For Subscribers")
+                    .blurb("When going to PLAYING, wait up to this many milliseconds for a matching subscriber on any key, so nothing is published before someone listens (0 = don't wait)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecBoolean::builder("require-subscribers")
+                    .nick("Require Subscribers")
+                    .blurb("Fail the state change when no subscriber matches within wait-for-subscribers-ms, instead of starting anyway")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecBoolean::builder("lazy-start")
+                    .nick("Lazy Start")
+                    .blurb("Open the Zenoh session and declare the publishers when the first buffer is rendered instead of in READY, so an idle sink holds no network resources (has-subscribers stays false until then)")
+                    .default_value(false)
+                    .build(),
+                #[cfg(feature = "metrics-http")]
+                glib::ParamSpecUInt::builder("metrics-port")
+                    .nick("Metrics Port")
+                    .blurb("Serve the statistics in Prometheus text format on http://<metrics-address>:<port>/metrics while the element is started (0 = disabled). Applied on the next start")
+                    .maximum(u16::MAX as u32)
+                    .default_value(0)
+                    .build(),
+                #[cfg(feature = "metrics-http")]
+                glib::ParamSpecString::builder("metrics-address")
+                    .nick("Metrics Address")
+                    .blurb("Address the metrics-port endpoint listens on. The default only accepts connections from the local host; use 0.0.0.0 to serve every interface. Applied on the next start")
+                    .default_value(Some(crate::metrics::DEFAULT_METRICS_ADDRESS))
+                    .build(),
                 // Session sharing property
                 glib::ParamSpecString::builder("session-group")
                     .nick("Session Group")
                     .blurb("Name of the session group for sharing Zenoh sessions across elements. Elements with the same group name share a single session.")
                     .build(),
+                glib::ParamSpecUInt::builder("session-group-linger-ms")
+                    .nick("Session Group Linger")
+                    .blurb("Keep the session-group session open this many milliseconds after this element was the last one to release it, so elements briefly overlapping reuse it instead of reopening one (0 = close right away)")
+                    .maximum(crate::session::MAX_SESSION_GROUP_LINGER_MS)
+                    .default_value(crate::session::DEFAULT_SESSION_GROUP_LINGER_MS)
+                    .build(),
                 // Matching status property (read-only)
                 glib::ParamSpecBoolean::builder("has-subscribers")
                     .nick("Has Subscribers")
-                    .blurb("Whether there are currently matching Zenoh subscribers for this publisher's key expression")
+                    .blurb("Whether there are currently matching Zenoh subscribers for this publisher's key expression (any of them, when several keys are configured)")
+                    .default_value(false)
+                    .read_only()
+                    .build(),
+                // Session introspection (read-only)
+                glib::ParamSpecString::builder("session-zid")
+                    .nick("Session ZID")
+                    .blurb("Zenoh ID of the session used by this element (empty when stopped)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecBoolean::builder("session-shared")
+                    .nick("Session Shared")
+                    .blurb("Whether the session was given with the session() builder method or comes from session-group, rather than opened by this element (false when stopped)")
                     .default_value(false)
                     .read_only()
                     .build(),
+                glib::ParamSpecBoxed::builder::<Vec<String>>("peers")
+                    .nick("Connected Peers")
+                    .blurb("Routers and peers the session is connected to, as 'router:<zid>' or 'peer:<zid>' (empty when stopped)")
+                    .read_only()
+                    .build(),
                 // Statistics properties (read-only)
                 glib::ParamSpecUInt64::builder("bytes-sent")
                     .nick("Bytes Sent")
@@ -609,14 +2847,79 @@ impl ObjectImpl for ZenohSink {
                     .build(),
                 glib::ParamSpecUInt64::builder("dropped")
                     .nick("Dropped")
-                    .blurb("Total messages dropped due to congestion (drop mode)")
+                    .blurb("Total buffers dropped (sum of dropped-congestion, dropped-compression-error, dropped-oversize, dropped-timeout and dropped-rate-limit)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("dropped-congestion")
+                    .nick("Dropped (Congestion)")
+                    .blurb("Buffers dropped because publishing failed in congestion-control=drop mode")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("dropped-compression-error")
+                    .nick("Dropped (Compression Error)")
+                    .blurb("Buffers dropped because compressing them failed")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("dropped-oversize")
+                    .nick("Dropped (Oversize)")
+                    .blurb("Buffers dropped because their payload exceeded max-buffer-size")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("dropped-timeout")
+                    .nick("Dropped (Timeout)")
+                    .blurb("Buffers dropped because publishing did not complete within put-timeout-ms")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("dropped-rate-limit")
+                    .nick("Dropped (Rate Limit)")
+                    .blurb("Buffers dropped because they exceeded max-bitrate in congestion-control=drop mode")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("rate-limited-ms")
+                    .nick("Rate Limited")
+                    .blurb("Total time in milliseconds publishing waited to stay under max-bitrate")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("dropped-filtered")
+                    .nick("Dropped (Filtered)")
+                    .blurb("Delta-unit buffers skipped by keyframes-only (not included in dropped)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("attachment-bytes-sent")
+                    .nick("Attachment Bytes Sent")
+                    .blurb("Serialized metadata attachment bytes published (caps, buffer timing, ...), not included in bytes-sent")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("bitrate")
+                    .nick("Bitrate")
+                    .blurb("Outgoing bitrate in bits per second over the last second (0 when idle)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecDouble::builder("message-rate")
+                    .nick("Message Rate")
+                    .blurb("Outgoing messages per second over the last second (0 when idle)")
+                    .minimum(0.0)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecDouble::builder("compression-ratio")
+                    .nick("Compression Ratio")
+                    .blurb("Bytes after compression divided by bytes before, over the buffers that were compressed (1.0 when nothing was compressed)")
+                    .minimum(0.0)
+                    .default_value(1.0)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecString::builder("stats-json")
+                    .nick("Statistics JSON")
+                    .blurb("All statistics above as one compact JSON object keyed by property name, read atomically (for metrics exporters)")
                     .read_only()
                     .build(),
                 // Compression statistics (conditional on features)
                 #[cfg(any(
                     feature = "compression-zstd",
                     feature = "compression-lz4",
-                    feature = "compression-gzip"
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
                 ))]
                 glib::ParamSpecUInt64::builder("bytes-before-compression")
                     .nick("Bytes Before Compression")
@@ -626,7 +2929,9 @@ impl ObjectImpl for ZenohSink {
                 #[cfg(any(
                     feature = "compression-zstd",
                     feature = "compression-lz4",
-                    feature = "compression-gzip"
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
                 ))]
                 glib::ParamSpecUInt64::builder("bytes-after-compression")
                     .nick("Bytes After Compression")
@@ -648,12 +2953,30 @@ impl ObjectImpl for ZenohSink {
             && matches!(
                 pspec.name(),
                 "key-expr"
+                    | "key-vars"
                     | "config"
-                    | "express"
+                    | "config-json5"
+                    | "scouting"
+                    | "endpoints-only"
+                    | "tls-root-ca"
+                    | "tls-client-cert"
+                    | "tls-client-key"
+                    | "username"
+                    | "password"
+                    | "locality"
                     | "reliability"
                     | "congestion-control"
                     | "priority"
                     | "session-group"
+                    | "session-group-linger-ms"
+                    | "use-shm"
+                    | "shm-size"
+                    | "lazy-start"
+                    | "max-routed-publishers"
+                    | "aggregate-window-ms"
+                    | "enable-fku"
+                    | "payload-only"
+                    | "compression-negotiate"
             )
         {
             gst::warning!(
@@ -663,19 +2986,42 @@ impl ObjectImpl for ZenohSink {
             );
             return;
         }
+        // payload-only checked them at start, they can't be turned on afterwards
+        if state.is_started()
+            && matches!(
+                pspec.name(),
+                "sequence-numbers" | "user-metadata" | "batch-lists" | "compression"
+            )
+            && self.settings.lock().unwrap().payload_only
+        {
+            gst::warning!(
+                CAT,
+                "Cannot change property '{}' while started with payload-only",
+                pspec.name()
+            );
+            return;
+        }
         drop(state);
 
-        // Note: priority, express, reliability, and congestion-control are locked after start
+        // Note: priority, reliability, and congestion-control are locked after start
         // because Zenoh Publishers are immutable - QoS is set during publisher creation.
         // The Zenoh API does not support changing QoS parameters on publisher.put().
-        // To implement runtime QoS changes would require recreating the publisher,
-        // which adds significant complexity and risk of data loss during transition.
         //
         // Properties that CAN be changed at runtime:
+        // - express: Publishers are re-declared by the streaming thread before the next buffer
+        // - express-congestion-control: Same, when express is on
         // - send-caps: Simple boolean check
         // - caps-interval: Simple integer check
+        // - sequence-numbers: Simple boolean check, numbering continues where it was
+        // - batch-lists: Checked per buffer list
+        // - user-metadata: Read per buffer
+        // - max-bitrate: Read per buffer
+        // - key-suffix-from-meta: Checked per buffer
         // - compression: Applied per-buffer
         // - compression-level: Applied per-buffer
+        //
+        // compression-negotiate is locked: the advertised algorithms are followed from start.
+        // use-shm and shm-size are locked too: the shared-memory pool is created at start.
 
         let mut settings = self.settings.lock().unwrap();
 
@@ -683,11 +3029,52 @@ impl ObjectImpl for ZenohSink {
             "key-expr" => {
                 settings.key_expr = value.get::<String>().expect("type checked upstream");
             }
+            "key-vars" => {
+                settings.key_vars = value
+                    .get::<Option<gst::Structure>>()
+                    .expect("type checked upstream");
+            }
             "config" => {
                 settings.config_file = value
                     .get::<Option<String>>()
                     .expect("type checked upstream");
             }
+            "config-json5" => {
+                settings.config_json5 = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "scouting" => {
+                settings.scouting = value.get::<bool>().expect("type checked upstream");
+            }
+            "endpoints-only" => {
+                settings.endpoints_only = value.get::<bool>().expect("type checked upstream");
+            }
+            "tls-root-ca" => {
+                settings.tls_root_ca = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "tls-client-cert" => {
+                settings.tls_client_cert = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "tls-client-key" => {
+                settings.tls_client_key = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "username" => {
+                settings.username = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "password" => {
+                settings.password = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
             "priority" => {
                 let priority_val = value.get::<u32>().expect("type checked upstream") as u8;
                 // Validate priority range
@@ -702,6 +3089,13 @@ impl ObjectImpl for ZenohSink {
                     settings.priority = 5; // Default to Priority::Data
                 }
             }
+            "keyframe-priority" => {
+                settings.keyframe_priority =
+                    value.get::<u32>().expect("type checked upstream") as u8;
+            }
+            "keyframes-only" => {
+                settings.keyframes_only = value.get::<bool>().expect("type checked upstream");
+            }
             "congestion-control" => {
                 let control = value.get::<String>().expect("type checked upstream");
                 // Validate value
@@ -727,7 +3121,28 @@ impl ObjectImpl for ZenohSink {
                 }
             }
             "express" => {
-                settings.express = value.get::<bool>().expect("type checked upstream");
+                let express = value.get::<bool>().expect("type checked upstream");
+                if settings.express != express {
+                    settings.express = express;
+                    // Takes effect on the next buffer if the publishers already exist
+                    self.publishers_outdated.store(true, Ordering::SeqCst);
+                }
+            }
+            "express-congestion-control" => {
+                let control = value
+                    .get::<ZenohExpressCongestionControl>()
+                    .expect("type checked upstream");
+                if settings.express_congestion_control != control {
+                    settings.express_congestion_control = control;
+                    if settings.express {
+                        self.publishers_outdated.store(true, Ordering::SeqCst);
+                    }
+                }
+            }
+            "locality" => {
+                settings.locality = value
+                    .get::<ZenohLocality>()
+                    .expect("type checked upstream");
             }
             "send-caps" => {
                 settings.send_caps = value.get::<bool>().expect("type checked upstream");
@@ -735,13 +3150,36 @@ impl ObjectImpl for ZenohSink {
             "caps-interval" => {
                 settings.caps_interval = value.get::<u32>().expect("type checked upstream");
             }
+            "caps-channel" => {
+                settings.caps_channel = value
+                    .get::<CapsChannel>()
+                    .expect("type checked upstream");
+            }
+            "forward-events" => {
+                settings.forward_events = value.get::<bool>().expect("type checked upstream");
+            }
+            "enable-fku" => {
+                settings.enable_fku = value.get::<bool>().expect("type checked upstream");
+            }
+            "auto-encoding" => {
+                settings.auto_encoding = value.get::<bool>().expect("type checked upstream");
+                let caps = self.obj().sink_pad().current_caps();
+                self.update_encoding(settings.auto_encoding, caps.as_deref());
+            }
             "send-buffer-meta" => {
                 settings.send_buffer_meta = value.get::<bool>().expect("type checked upstream");
             }
+            "user-metadata" => {
+                settings.user_metadata = value
+                    .get::<Option<gst::Structure>>()
+                    .expect("type checked upstream");
+            }
             #[cfg(any(
                 feature = "compression-zstd",
                 feature = "compression-lz4",
-                feature = "compression-gzip"
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
             ))]
             "compression" => {
                 settings.compression = value
@@ -751,7 +3189,9 @@ impl ObjectImpl for ZenohSink {
             #[cfg(any(
                 feature = "compression-zstd",
                 feature = "compression-lz4",
-                feature = "compression-gzip"
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
             ))]
             "compression-level" => {
                 let level = value.get::<i32>().expect("type checked upstream");
@@ -766,11 +3206,104 @@ impl ObjectImpl for ZenohSink {
                     settings.compression_level = 5;
                 }
             }
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            "compression-min-size" => {
+                settings.compression_min_size = value.get::<u32>().expect("type checked upstream");
+            }
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            "compression-negotiate" => {
+                settings.compression_negotiate =
+                    value.get::<bool>().expect("type checked upstream");
+            }
+            #[cfg(feature = "shared-memory")]
+            "use-shm" => {
+                settings.use_shm = value.get::<bool>().expect("type checked upstream");
+            }
+            #[cfg(feature = "shared-memory")]
+            "shm-size" => {
+                settings.shm_size = value.get::<u64>().expect("type checked upstream");
+            }
+            "max-buffer-size" => {
+                settings.max_buffer_size = value.get::<u64>().expect("type checked upstream");
+            }
+            "max-bitrate" => {
+                settings.max_bitrate = value.get::<u64>().expect("type checked upstream");
+            }
+            "put-timeout-ms" => {
+                settings.put_timeout_ms = value.get::<u64>().expect("type checked upstream");
+            }
+            "wait-for-connection-ms" => {
+                settings.wait_for_connection_ms =
+                    value.get::<u64>().expect("type checked upstream");
+            }
+            "require-connection" => {
+                settings.require_connection = value.get::<bool>().expect("type checked upstream");
+            }
+            "connectivity-poll-ms" => {
+                settings.connectivity_poll_ms = value.get::<u32>().expect("type checked upstream");
+            }
+            "wait-for-subscribers-ms" => {
+                settings.wait_for_subscribers_ms =
+                    value.get::<u64>().expect("type checked upstream");
+            }
+            "require-subscribers" => {
+                settings.require_subscribers = value.get::<bool>().expect("type checked upstream");
+            }
+            "lazy-start" => {
+                settings.lazy_start = value.get::<bool>().expect("type checked upstream");
+            }
+            #[cfg(feature = "metrics-http")]
+            "metrics-port" => {
+                settings.metrics_port = value.get::<u32>().expect("type checked upstream");
+            }
+            #[cfg(feature = "metrics-http")]
+            "metrics-address" => {
+                settings.metrics_address = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream")
+                    .unwrap_or_else(|| crate::metrics::DEFAULT_METRICS_ADDRESS.into());
+            }
+            "sequence-numbers" => {
+                settings.sequence_numbers = value.get::<bool>().expect("type checked upstream");
+            }
+            "batch-lists" => {
+                settings.batch_lists = value.get::<bool>().expect("type checked upstream");
+            }
+            "aggregate-window-ms" => {
+                settings.aggregate_window_ms = value.get::<u32>().expect("type checked upstream");
+            }
+            "payload-only" => {
+                settings.payload_only = value.get::<bool>().expect("type checked upstream");
+            }
+            "key-suffix-from-meta" => {
+                settings.key_suffix_from_meta =
+                    value.get::<bool>().expect("type checked upstream");
+            }
+            "max-routed-publishers" => {
+                settings.max_routed_publishers =
+                    value.get::<u32>().expect("type checked upstream");
+            }
             "session-group" => {
                 settings.session_group = value
                     .get::<Option<String>>()
                     .expect("type checked upstream");
             }
+            "session-group-linger-ms" => {
+                settings.session_group_linger_ms =
+                    value.get::<u32>().expect("type checked upstream");
+            }
             name => {
                 gst::warning!(CAT, "Unknown property: {}", name);
             }
@@ -780,27 +3313,73 @@ impl ObjectImpl for ZenohSink {
     fn property(&self, _id: usize, pspec: &gst::glib::ParamSpec) -> gst::glib::Value {
         match pspec.name() {
             // Configuration properties - read from settings
-            "key-expr" | "config" | "priority" | "congestion-control" | "reliability"
-            | "express" | "send-caps" | "caps-interval" | "send-buffer-meta" | "session-group" => {
+            "key-expr" | "key-vars" | "config" | "config-json5" | "scouting" | "endpoints-only"
+            | "tls-root-ca" | "tls-client-cert" | "tls-client-key" | "username" | "priority"
+            | "keyframe-priority" | "keyframes-only" | "congestion-control" | "reliability"
+            | "express" | "express-congestion-control" | "locality" | "send-caps"
+            | "caps-interval" | "caps-channel" | "forward-events" | "enable-fku"
+            | "auto-encoding" | "send-buffer-meta" | "session-group" | "session-group-linger-ms"
+            | "max-buffer-size" | "max-bitrate"
+            | "put-timeout-ms" | "wait-for-connection-ms" | "require-connection"
+            | "connectivity-poll-ms" | "wait-for-subscribers-ms" | "require-subscribers"
+            | "lazy-start"
+            | "sequence-numbers" | "user-metadata" | "batch-lists" | "aggregate-window-ms"
+            | "payload-only" | "key-suffix-from-meta"
+            | "max-routed-publishers" => {
                 let settings = self.settings.lock().unwrap();
                 match pspec.name() {
                     "key-expr" => settings.key_expr.to_value(),
+                    "key-vars" => settings.key_vars.to_value(),
                     "config" => settings.config_file.to_value(),
+                    "config-json5" => settings.config_json5.to_value(),
+                    "scouting" => settings.scouting.to_value(),
+                    "endpoints-only" => settings.endpoints_only.to_value(),
+                    "tls-root-ca" => settings.tls_root_ca.to_value(),
+                    "tls-client-cert" => settings.tls_client_cert.to_value(),
+                    "tls-client-key" => settings.tls_client_key.to_value(),
+                    "username" => settings.username.to_value(),
                     "priority" => (settings.priority as u32).to_value(),
+                    "keyframe-priority" => (settings.keyframe_priority as u32).to_value(),
+                    "keyframes-only" => settings.keyframes_only.to_value(),
                     "congestion-control" => settings.congestion_control.to_value(),
                     "reliability" => settings.reliability.to_value(),
                     "express" => settings.express.to_value(),
+                    "express-congestion-control" => settings.express_congestion_control.to_value(),
+                    "locality" => settings.locality.to_value(),
                     "send-caps" => settings.send_caps.to_value(),
                     "caps-interval" => settings.caps_interval.to_value(),
+                    "caps-channel" => settings.caps_channel.to_value(),
+                    "forward-events" => settings.forward_events.to_value(),
+                    "enable-fku" => settings.enable_fku.to_value(),
+                    "auto-encoding" => settings.auto_encoding.to_value(),
                     "send-buffer-meta" => settings.send_buffer_meta.to_value(),
+                    "user-metadata" => settings.user_metadata.to_value(),
                     "session-group" => settings.session_group.to_value(),
+                    "session-group-linger-ms" => settings.session_group_linger_ms.to_value(),
+                    "max-buffer-size" => settings.max_buffer_size.to_value(),
+                    "max-bitrate" => settings.max_bitrate.to_value(),
+                    "put-timeout-ms" => settings.put_timeout_ms.to_value(),
+                    "wait-for-connection-ms" => settings.wait_for_connection_ms.to_value(),
+                    "require-connection" => settings.require_connection.to_value(),
+                    "connectivity-poll-ms" => settings.connectivity_poll_ms.to_value(),
+                    "wait-for-subscribers-ms" => settings.wait_for_subscribers_ms.to_value(),
+                    "require-subscribers" => settings.require_subscribers.to_value(),
+                    "lazy-start" => settings.lazy_start.to_value(),
+                    "sequence-numbers" => settings.sequence_numbers.to_value(),
+                    "batch-lists" => settings.batch_lists.to_value(),
+                    "aggregate-window-ms" => settings.aggregate_window_ms.to_value(),
+                    "payload-only" => settings.payload_only.to_value(),
+                    "key-suffix-from-meta" => settings.key_suffix_from_meta.to_value(),
+                    "max-routed-publishers" => settings.max_routed_publishers.to_value(),
                     _ => unreachable!(),
                 }
             }
             #[cfg(any(
                 feature = "compression-zstd",
                 feature = "compression-lz4",
-                feature = "compression-gzip"
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
             ))]
             "compression" => {
                 let settings = self.settings.lock().unwrap();
@@ -809,12 +3388,56 @@ impl ObjectImpl for ZenohSink {
             #[cfg(any(
                 feature = "compression-zstd",
                 feature = "compression-lz4",
-                feature = "compression-gzip"
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
             ))]
             "compression-level" => {
                 let settings = self.settings.lock().unwrap();
                 settings.compression_level.to_value()
             }
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            "compression-min-size" => {
+                let settings = self.settings.lock().unwrap();
+                settings.compression_min_size.to_value()
+            }
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            "compression-negotiate" => {
+                let settings = self.settings.lock().unwrap();
+                settings.compression_negotiate.to_value()
+            }
+            #[cfg(feature = "shared-memory")]
+            "use-shm" => {
+                let settings = self.settings.lock().unwrap();
+                settings.use_shm.to_value()
+            }
+            #[cfg(feature = "shared-memory")]
+            "shm-size" => {
+                let settings = self.settings.lock().unwrap();
+                settings.shm_size.to_value()
+            }
+            #[cfg(feature = "metrics-http")]
+            "metrics-port" => {
+                let settings = self.settings.lock().unwrap();
+                settings.metrics_port.to_value()
+            }
+            #[cfg(feature = "metrics-http")]
+            "metrics-address" => {
+                let settings = self.settings.lock().unwrap();
+                settings.metrics_address.to_value()
+            }
             // Matching status - available in Ready or Started state
             "has-subscribers" => {
                 let state = self.state.lock().unwrap();
@@ -824,8 +3447,34 @@ impl ObjectImpl for ZenohSink {
                     false.to_value()
                 }
             }
+            "session-zid" => {
+                let state = self.state.lock().unwrap();
+                state
+                    .session()
+                    .map(|session| session.zid().to_string())
+                    .unwrap_or_default()
+                    .to_value()
+            }
+            "session-shared" => {
+                let state = self.state.lock().unwrap();
+                state
+                    .session_wrapper()
+                    .is_some_and(SessionWrapper::is_shared)
+                    .to_value()
+            }
+            "peers" => {
+                let state = self.state.lock().unwrap();
+                state
+                    .session()
+                    .map(crate::session::connected_peers)
+                    .unwrap_or_default()
+                    .to_value()
+            }
             // Statistics properties - only available in Started state (data is flowing)
-            "bytes-sent" | "messages-sent" | "errors" | "dropped" => {
+            "bytes-sent" | "messages-sent" | "errors" | "dropped" | "dropped-congestion"
+            | "dropped-compression-error" | "dropped-oversize" | "dropped-timeout"
+            | "dropped-rate-limit" | "rate-limited-ms" | "dropped-filtered"
+            | "attachment-bytes-sent" | "bitrate" => {
                 let state = self.state.lock().unwrap();
                 if let State::Started(ref started) = *state {
                     let stats = started.stats.lock().unwrap();
@@ -833,17 +3482,52 @@ impl ObjectImpl for ZenohSink {
                         "bytes-sent" => stats.bytes_sent.to_value(),
                         "messages-sent" => stats.messages_sent.to_value(),
                         "errors" => stats.errors.to_value(),
-                        "dropped" => stats.dropped.to_value(),
+                        "dropped" => stats.dropped().to_value(),
+                        "dropped-congestion" => stats.dropped_congestion.to_value(),
+                        "dropped-compression-error" => stats.dropped_compression_error.to_value(),
+                        "dropped-oversize" => stats.dropped_oversize.to_value(),
+                        "dropped-timeout" => stats.dropped_timeout.to_value(),
+                        "dropped-rate-limit" => stats.dropped_rate_limit.to_value(),
+                        "rate-limited-ms" => stats.rate_limited_ms.to_value(),
+                        "dropped-filtered" => stats.dropped_filtered.to_value(),
+                        "attachment-bytes-sent" => stats.attachment_bytes_sent.to_value(),
+                        "bitrate" => stats.rate.bitrate().to_value(),
                         _ => unreachable!(),
                     }
                 } else {
                     0u64.to_value()
                 }
             }
+            "message-rate" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().rate.message_rate().to_value()
+                } else {
+                    0f64.to_value()
+                }
+            }
+            "compression-ratio" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().compression_ratio().to_value()
+                } else {
+                    1f64.to_value()
+                }
+            }
+            "stats-json" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().to_json().to_value()
+                } else {
+                    Statistics::default().to_json().to_value()
+                }
+            }
             #[cfg(any(
                 feature = "compression-zstd",
                 feature = "compression-lz4",
-                feature = "compression-gzip"
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
             ))]
             "bytes-before-compression" => {
                 let state = self.state.lock().unwrap();
@@ -861,7 +3545,9 @@ impl ObjectImpl for ZenohSink {
             #[cfg(any(
                 feature = "compression-zstd",
                 feature = "compression-lz4",
-                feature = "compression-gzip"
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
             ))]
             "bytes-after-compression" => {
                 let state = self.state.lock().unwrap();
@@ -899,13 +3585,7 @@ impl BaseSinkImpl for ZenohSink {
 
         // Check if we can start from current state (must be Ready)
         if !state.can_start() {
-            let current_state = match *state {
-                State::Stopped => "Stopped",
-                State::Ready(_) => "Ready",
-                State::Starting => "Starting",
-                State::Started(_) => "Started",
-                State::Stopping => "Stopping",
-            };
+            let current_state = state.name();
             gst::warning!(
                 CAT,
                 "Cannot start ZenohSink from state: {}, ignoring start request",
@@ -921,6 +3601,40 @@ impl BaseSinkImpl for ZenohSink {
             }
         }
 
+        let conflicts = self.settings.lock().unwrap().payload_only_conflicts();
+        if !conflicts.is_empty() {
+            return Err(gst::error_msg!(
+                gst::ResourceError::Settings,
+                ["payload-only can't be combined with {}", conflicts.join(", ")]
+            ));
+        }
+
+        // With lazy-start there is no session yet, the waits happen on the first buffer
+        let needs_wait = {
+            let settings = self.settings.lock().unwrap();
+            settings.wait_for_connection_ms > 0 || settings.wait_for_subscribers_ms > 0
+        };
+        if needs_wait && let State::Ready(Some(ref ready)) = *state {
+            let session = ready.session.as_session().clone();
+            let has_subscribers = ready.has_subscribers.clone();
+
+            // Don't block property reads while waiting
+            drop(state);
+            let result = self
+                .wait_for_connection(&session)
+                .and_then(|_| self.wait_for_subscribers(&has_subscribers));
+            state = self.state.lock().unwrap();
+            result?;
+
+            // The element may have been shut down while we were waiting
+            if !state.can_start() {
+                return Err(gst::error_msg!(
+                    gst::ResourceError::Settings,
+                    ["State changed while waiting for a connection or subscribers"]
+                ));
+            }
+        }
+
         gst::debug!(CAT, "ZenohSink transitioning from Ready to Started");
 
         // Take the ReadyState and promote it to Started with render-time resources
@@ -929,12 +3643,58 @@ impl BaseSinkImpl for ZenohSink {
             _ => unreachable!(),
         };
 
+        #[cfg(feature = "shared-memory")]
+        let shm_provider = match self.create_shm_provider() {
+            Ok(provider) => provider,
+            Err(err) => {
+                // Keep the Zenoh resources so a later start can retry
+                *state = State::Ready(ready_state);
+                return Err(err);
+            }
+        };
+
+        let stats = Arc::new(Mutex::new(Statistics::default()));
+        self.rate_limiter.lock().unwrap().reset();
+        let aggregate_window_ms = self.settings.lock().unwrap().aggregate_window_ms;
+        let aggregate_timer = if aggregate_window_ms > 0 {
+            let window = Duration::from_millis(aggregate_window_ms.into());
+            match AggregateTimer::start(&self.obj(), window) {
+                Ok(timer) => Some(timer),
+                Err(err) => {
+                    // Keep the Zenoh resources so a later start can retry
+                    *state = State::Ready(ready_state);
+                    return Err(err);
+                }
+            }
+        } else {
+            None
+        };
+        #[cfg(feature = "metrics-http")]
+        let metrics = {
+            let (address, port) = {
+                let settings = self.settings.lock().unwrap();
+                (settings.metrics_address.clone(), settings.metrics_port)
+            };
+            let stats = stats.clone();
+            crate::metrics::serve(self.obj().upcast_ref(), &address, port, move || {
+                stats.lock().unwrap().to_json()
+            })
+        };
+
         *state = State::Started(Started {
             ready: ready_state,
-            stats: Arc::new(Mutex::new(Statistics::default())),
+            stats,
             caps_sent: Arc::new(AtomicBool::new(false)),
             last_caps_time: Arc::new(Mutex::new(None)),
             last_caps: Arc::new(Mutex::new(None)),
+            next_seqnum: 0,
+            sent_segment: (1.0, gst::ClockTime::ZERO),
+            put_worker: None,
+            _aggregate_timer: aggregate_timer,
+            #[cfg(feature = "metrics-http")]
+            _metrics: metrics,
+            #[cfg(feature = "shared-memory")]
+            shm_provider,
         });
         gst::debug!(CAT, "ZenohSink successfully transitioned to Started state");
 
@@ -942,11 +3702,79 @@ impl BaseSinkImpl for ZenohSink {
     }
 
     fn render(&self, buffer: &gst::Buffer) -> Result<gst::FlowSuccess, gst::FlowError> {
-        let state_locked = self.state.lock().unwrap();
-        let State::Started(ref started) = *state_locked else {
+        if let Some(result) = self.aggregate(buffer) {
+            return result;
+        }
+
+        let throttled = self.throttle()?;
+
+        let mut state_locked = self.state.lock().unwrap();
+        let State::Started(ref mut started) = *state_locked else {
             gst::element_imp_error!(self, gst::CoreError::Failed, ["Not started yet"]);
             return Err(gst::FlowError::Error);
         };
+        let Some(waited) = throttled else {
+            gst::trace!(CAT, imp = self, "Dropping buffer, over max-bitrate");
+            started.stats.lock().unwrap().dropped_rate_limit += 1;
+            return Ok(gst::FlowSuccess::Ok);
+        };
+        if !waited.is_zero() {
+            started.stats.lock().unwrap().rate_limited_ms += waited.as_millis() as u64;
+        }
+        self.ensure_zenoh_resources(started)?;
+        self.refresh_publishers(started);
+
+        let (
+            max_buffer_size,
+            drop_on_congestion,
+            keyframe_qos,
+            keyframes_only,
+            put_timeout,
+            key_suffix_from_meta,
+        ) = {
+            let settings = self.settings.lock().unwrap();
+            (
+                settings.max_buffer_size,
+                settings.effective_congestion_control() == CongestionControl::Drop,
+                KeyframeQos::from_settings(&settings),
+                settings.keyframes_only,
+                settings.put_timeout(),
+                settings.key_suffix_from_meta,
+            )
+        };
+        let keyframe_qos = keyframe_qos.filter(|_| is_keyframe(buffer));
+
+        // Caps aren't marked as sent for skipped buffers, so they go out with
+        // the next key frame
+        if keyframes_only && !is_keyframe(buffer) {
+            gst::trace!(CAT, imp = self, "Skipping delta unit (keyframes-only)");
+            started.stats.lock().unwrap().dropped_filtered += 1;
+            return Ok(gst::FlowSuccess::Ok);
+        }
+
+        // With key-suffix-from-meta, buffers carrying a key meta go to
+        // <key-expr>/<suffix>; an unusable suffix drops only that buffer
+        let routed = match ZenohKeyMeta::key_expr(buffer).filter(|_| key_suffix_from_meta) {
+            Some(suffix) => {
+                let ready = started.ready.as_mut().expect("Zenoh resources created above");
+                match self.routed_publishers(ready, &suffix) {
+                    Ok(publishers) => Some(publishers),
+                    Err(e) => {
+                        gst::warning!(
+                            CAT,
+                            imp = self,
+                            "Dropping buffer routed to key suffix '{}': {}",
+                            suffix,
+                            e
+                        );
+                        started.stats.lock().unwrap().errors += 1;
+                        post_error_message(self.obj().upcast_ref(), &suffix, &e);
+                        return Ok(gst::FlowSuccess::Ok);
+                    }
+                }
+            }
+            None => None,
+        };
 
         // Get buffer data with proper error handling
         let b = buffer.clone().into_mapped_buffer_readable().map_err(|_| {
@@ -962,81 +3790,82 @@ impl BaseSinkImpl for ZenohSink {
         #[cfg(any(
             feature = "compression-zstd",
             feature = "compression-lz4",
-            feature = "compression-gzip"
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
         ))]
         let original_size = b.len();
 
+        // Apply compression if enabled and the buffer is large enough to benefit.
+        // Small buffers go out uncompressed, without a compression marker.
+        // Use Cow to avoid unnecessary copy when compression is disabled
         #[cfg(any(
             feature = "compression-zstd",
             feature = "compression-lz4",
-            feature = "compression-gzip"
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
         ))]
-        let (compression_type, compression_level) = {
-            let settings = self.settings.lock().unwrap();
-            (settings.compression, settings.compression_level)
+        let (data_to_send, compression_type) = match self.compress_payload(started, b.as_slice()) {
+            Ok(Some((compressed_data, compression_type))) => {
+                (std::borrow::Cow::Owned(compressed_data), Some(compression_type))
+            }
+            // No compression (disabled, not supported by the receivers or buffer
+            // below compression-min-size) - borrow the original slice (zero-copy)
+            Ok(None) => (std::borrow::Cow::Borrowed(b.as_slice()), None),
+            Err(e) => {
+                gst::warning!(CAT, imp = self, "Compression failed: {}, dropping buffer", e);
+                let mut stats = started.stats.lock().unwrap();
+                stats.errors += 1;
+                stats.dropped_compression_error += 1;
+                return Ok(gst::FlowSuccess::Ok);
+            }
         };
-
-        // Apply compression if enabled
-        // Use Cow to avoid unnecessary copy when compression is disabled
         #[cfg(any(
             feature = "compression-zstd",
             feature = "compression-lz4",
-            feature = "compression-gzip"
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
         ))]
-        let (data_to_send, compressed): (std::borrow::Cow<'_, [u8]>, bool) = if compression_type
-            != crate::compression::CompressionType::None
-        {
-            match crate::compression::compress(b.as_slice(), compression_type, compression_level) {
-                Ok(compressed_data) => {
-                    gst::trace!(
-                        CAT,
-                        imp = self,
-                        "Compressed {} bytes to {} bytes using {:?} (level {}), ratio: {:.2}%",
-                        original_size,
-                        compressed_data.len(),
-                        compression_type,
-                        compression_level,
-                        (compressed_data.len() as f64 / original_size as f64) * 100.0
-                    );
-                    (std::borrow::Cow::Owned(compressed_data), true)
-                }
-                Err(e) => {
-                    gst::warning!(
-                        CAT,
-                        imp = self,
-                        "Compression failed: {}, sending uncompressed",
-                        e
-                    );
-                    started.stats.lock().unwrap().errors += 1;
-                    // No copy - borrow the original slice
-                    (std::borrow::Cow::Borrowed(b.as_slice()), false)
-                }
-            }
-        } else {
-            // No compression - borrow the original slice (zero-copy)
-            (std::borrow::Cow::Borrowed(b.as_slice()), false)
-        };
+        let compressed = compression_type.is_some();
 
         #[cfg(not(any(
             feature = "compression-zstd",
             feature = "compression-lz4",
-            feature = "compression-gzip"
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
         )))]
         // No compression features - borrow the original slice (zero-copy)
         let (data_to_send, compressed): (std::borrow::Cow<'_, [u8]>, bool) =
             (std::borrow::Cow::Borrowed(b.as_slice()), false);
 
+        if max_buffer_size > 0 && data_to_send.len() as u64 > max_buffer_size {
+            gst::warning!(
+                CAT,
+                imp = self,
+                "Dropping {} byte buffer: exceeds max-buffer-size ({})",
+                data_to_send.len(),
+                max_buffer_size
+            );
+            started.stats.lock().unwrap().dropped_oversize += 1;
+            return Ok(gst::FlowSuccess::Ok);
+        }
+
         // Smart caps transmission: send caps when needed, not on every buffer
-        let (send_caps, caps_interval, send_buffer_meta) = {
+        let (send_caps, caps_interval, send_buffer_meta, sequence_numbers, user_metadata) = {
             let settings = self.settings.lock().unwrap();
             (
-                settings.send_caps,
+                settings.sends_caps(),
                 settings.caps_interval,
-                settings.send_buffer_meta,
+                settings.sends_buffer_meta(),
+                settings.sequence_numbers,
+                settings.user_metadata.clone().filter(|s| s.n_fields() > 0),
             )
         };
 
-        let attachment = if send_caps {
+        let caps_to_send = if send_caps {
             if let Some(caps) = self.obj().sink_pad().current_caps() {
                 let mut should_send = false;
 
@@ -1081,148 +3910,180 @@ impl BaseSinkImpl for ZenohSink {
                     }
                 }
 
-                if should_send {
-                    let mut metadata_builder = MetadataBuilder::new().caps(&caps);
+                should_send.then_some(caps)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
 
-                    // Add buffer timing metadata if enabled
-                    if send_buffer_meta {
-                        metadata_builder = metadata_builder.buffer_timing(buffer);
-                    }
+        let tags_to_send = self.tags_to_send(caps_to_send.is_some());
+        let identity_to_send = self.stream_identity_to_send(caps_to_send.is_some());
+        let caps_to_send = self.route_caps(started, caps_to_send);
 
-                    // Add compression metadata if compressed
-                    #[cfg(any(
-                        feature = "compression-zstd",
-                        feature = "compression-lz4",
-                        feature = "compression-gzip"
-                    ))]
-                    if compressed {
-                        metadata_builder = metadata_builder.user_metadata(
-                            crate::metadata::keys::COMPRESSION,
-                            compression_type.to_metadata_value(),
-                        );
-                    }
+        let seqnum = sequence_numbers.then(|| {
+            let seqnum = started.next_seqnum;
+            started.next_seqnum += 1;
+            seqnum
+        });
 
-                    metadata_builder.build()
-                } else {
-                    // Not sending caps, but may still need buffer timing or compression metadata
-                    let needs_metadata = send_buffer_meta || compressed;
+        // Build the attachment with caps, stream identity and tags (when due), buffer timing,
+        // compression marker, sequence number and user metadata, or none at all when there is
+        // nothing to send
+        let needs_metadata = caps_to_send.is_some()
+            || identity_to_send.is_some()
+            || tags_to_send.is_some()
+            || send_buffer_meta
+            || compressed
+            || seqnum.is_some()
+            || user_metadata.is_some();
+        let attachment = if needs_metadata {
+            let mut metadata_builder = MetadataBuilder::new();
+
+            if let Some(ref fields) = user_metadata {
+                metadata_builder = metadata_builder.user_metadata_fields(fields);
+            }
 
-                    if needs_metadata {
-                        let mut metadata_builder = MetadataBuilder::new();
+            if let Some(ref caps) = caps_to_send {
+                metadata_builder = metadata_builder.caps(caps);
+            }
 
-                        if send_buffer_meta {
-                            metadata_builder = metadata_builder.buffer_timing(buffer);
-                        }
+            if let Some(ref identity) = identity_to_send {
+                metadata_builder = metadata_builder.stream_identity(identity);
+            }
 
-                        #[cfg(any(
-                            feature = "compression-zstd",
-                            feature = "compression-lz4",
-                            feature = "compression-gzip"
-                        ))]
-                        if compressed {
-                            metadata_builder = metadata_builder.user_metadata(
-                                crate::metadata::keys::COMPRESSION,
-                                compression_type.to_metadata_value(),
-                            );
-                        }
+            if let Some(ref tags) = tags_to_send {
+                metadata_builder = metadata_builder.tags(tags);
+            }
 
-                        metadata_builder.build()
-                    } else {
-                        None
+            // Add buffer timing metadata if enabled
+            if send_buffer_meta {
+                metadata_builder = metadata_builder.buffer_timing(buffer);
+
+                // Forward the segment rate and base time while they differ from
+                // the defaults, and once when they return to them
+                if let Ok(segment) = self.obj().segment().downcast::<gst::ClockTime>() {
+                    let rate_base = (segment.rate(), segment.base().unwrap_or_default());
+                    let is_default = rate_base == (1.0, gst::ClockTime::ZERO);
+                    if !is_default || rate_base != started.sent_segment {
+                        metadata_builder = metadata_builder.segment(&segment);
+                        started.sent_segment = rate_base;
                     }
                 }
-            } else {
-                // No caps available, but may still need buffer timing or compression metadata
-                let needs_metadata = send_buffer_meta || compressed;
-
-                if needs_metadata {
-                    let mut metadata_builder = MetadataBuilder::new();
-
-                    if send_buffer_meta {
-                        metadata_builder = metadata_builder.buffer_timing(buffer);
-                    }
+            }
 
-                    #[cfg(any(
-                        feature = "compression-zstd",
-                        feature = "compression-lz4",
-                        feature = "compression-gzip"
-                    ))]
-                    if compressed {
-                        metadata_builder = metadata_builder.user_metadata(
-                            crate::metadata::keys::COMPRESSION,
-                            compression_type.to_metadata_value(),
-                        );
-                    }
+            // Add compression metadata if compressed
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            if let Some(compression_type) = compression_type {
+                metadata_builder = metadata_builder.user_metadata(
+                    crate::metadata::keys::COMPRESSION,
+                    compression_type.to_metadata_value(),
+                );
+            }
 
-                    metadata_builder.build()
-                } else {
-                    None
-                }
+            if let Some(seqnum) = seqnum {
+                metadata_builder = metadata_builder.seqnum(seqnum);
             }
+
+            metadata_builder.build()
         } else {
-            // send_caps is false, but may still need buffer timing or compression metadata
-            let needs_metadata = send_buffer_meta || compressed;
+            None
+        };
 
-            if needs_metadata {
-                let mut metadata_builder = MetadataBuilder::new();
+        // Send with caps attachment on every configured key expression.
+        // The payload is converted once; ZBytes clones share the same buffer.
+        // Note: Zenoh's wait() already handles timeouts internally
+        let payload = self.make_payload(started, &data_to_send);
+        let attachment_len = attachment.as_ref().map_or(0, |a| a.len() as u64);
+        let mut published = 0u64;
+        let mut failure = None;
+        let mut timed_out = false;
+        let ready = started.ready.as_ref().expect("Zenoh resources created above");
+        for publisher in routed.as_deref().unwrap_or(&ready.publishers) {
+            let result = publish(
+                &mut started.put_worker,
+                put_timeout,
+                ready.session.as_session(),
+                publisher,
+                payload.clone(),
+                attachment.clone(),
+                keyframe_qos,
+            );
 
-                if send_buffer_meta {
-                    metadata_builder = metadata_builder.buffer_timing(buffer);
+            match result {
+                Some(Ok(_)) => published += 1,
+                Some(Err(e)) => {
+                    failure = Some((publisher.key_expr().to_string(), e));
+                    break;
                 }
-
-                #[cfg(any(
-                    feature = "compression-zstd",
-                    feature = "compression-lz4",
-                    feature = "compression-gzip"
-                ))]
-                if compressed {
-                    metadata_builder = metadata_builder.user_metadata(
-                        crate::metadata::keys::COMPRESSION,
-                        compression_type.to_metadata_value(),
-                    );
+                None => {
+                    timed_out = true;
+                    break;
                 }
-
-                metadata_builder.build()
-            } else {
-                None
             }
-        };
+        }
 
-        // Send with caps attachment
-        // Note: Zenoh's wait() already handles timeouts internally
-        let put_builder = started.ready.publisher.put(&data_to_send);
-        let result = if let Some(attachment) = attachment {
-            put_builder.attachment(attachment).wait()
-        } else {
-            put_builder.wait()
-        };
+        if published > 0 {
+            // Update statistics (aggregated across all publishers)
+            let bytes = data_to_send.len() as u64 * published;
+            let mut stats = started.stats.lock().unwrap();
+            stats.bytes_sent += bytes;
+            stats.messages_sent += published;
+            stats.attachment_bytes_sent += attachment_len * published;
+            stats.rate.record(bytes, published);
+            self.rate_limiter
+                .lock()
+                .unwrap()
+                .consume(bytes + attachment_len * published);
 
-        match result {
-            Ok(_) => {
-                // Update statistics on success
-                let mut stats = started.stats.lock().unwrap();
-                stats.bytes_sent += data_to_send.len() as u64;
-                stats.messages_sent += 1;
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            if compressed {
+                stats.bytes_before_compression += original_size as u64 * published;
+                stats.bytes_after_compression += bytes;
+            }
+        }
 
-                #[cfg(any(
-                    feature = "compression-zstd",
-                    feature = "compression-lz4",
-                    feature = "compression-gzip"
-                ))]
-                if compressed {
-                    stats.bytes_before_compression += original_size as u64;
-                    stats.bytes_after_compression += data_to_send.len() as u64;
-                }
+        if timed_out {
+            gst::warning!(
+                CAT,
+                imp = self,
+                "Dropping buffer, publishing did not complete within put-timeout-ms"
+            );
+            started.stats.lock().unwrap().dropped_timeout += 1;
+            return Ok(gst::FlowSuccess::Ok);
+        }
 
+        match failure {
+            None => Ok(gst::FlowSuccess::Ok),
+            Some((key_expr, e)) if drop_on_congestion => {
+                // In drop mode losing a buffer is preferred over stalling or failing
+                gst::warning!(
+                    CAT,
+                    imp = self,
+                    "Dropping buffer, publishing on '{}' failed: {}",
+                    key_expr,
+                    e
+                );
+                started.stats.lock().unwrap().dropped_congestion += 1;
                 Ok(gst::FlowSuccess::Ok)
             }
-            Err(e) => {
+            Some((key_expr, e)) => {
                 // Update error statistics
                 started.stats.lock().unwrap().errors += 1;
 
-                // Get key expression for better error reporting
-                let key_expr = self.settings.lock().unwrap().key_expr.clone();
-
                 // Check if this is a network-related error before consuming e
                 let error_msg = format!("{}", e);
                 let err = ZenohError::Publish {
@@ -1255,170 +4116,51 @@ impl BaseSinkImpl for ZenohSink {
             list.len()
         );
 
-        let state_locked = self.state.lock().unwrap();
-        let State::Started(ref started) = *state_locked else {
-            gst::element_imp_error!(self, gst::CoreError::Failed, ["Not started yet"]);
-            return Err(gst::FlowError::Error);
-        };
-
-        // Track statistics for the batch
-        let mut total_bytes = 0u64;
-        let mut total_messages = 0u64;
-        let mut errors_count = 0u64;
+        // Buffers of a list may be routed to different keys
+        if self.settings.lock().unwrap().key_suffix_from_meta {
+            for buffer in list.iter_owned() {
+                self.render(&buffer)?;
+            }
+            return Ok(gst::FlowSuccess::Ok);
+        }
 
-        // Get caps settings
-        let (send_caps, caps_interval) = {
+        // Buffers of a list are batched with the ones around them
+        let (aggregate_window_ms, batch_lists) = {
             let settings = self.settings.lock().unwrap();
-            (settings.send_caps, settings.caps_interval)
-        };
-
-        let caps_attachment = if send_caps {
-            if let Some(caps) = self.obj().sink_pad().current_caps() {
-                let mut should_send = false;
-
-                if !started.caps_sent.load(Ordering::Acquire) {
-                    gst::debug!(
-                        CAT,
-                        imp = self,
-                        "Sending caps on first buffer list: {}",
-                        caps
-                    );
-                    should_send = true;
-                    started.caps_sent.store(true, Ordering::Release);
-                    *started.last_caps.lock().unwrap() = Some(caps.clone());
-                    *started.last_caps_time.lock().unwrap() = Some(std::time::Instant::now());
-                } else {
-                    let last_caps = started.last_caps.lock().unwrap();
-                    if last_caps.as_ref() != Some(&caps) {
-                        gst::debug!(CAT, imp = self, "Caps changed in buffer list: {}", caps);
-                        should_send = true;
-                        drop(last_caps);
-                        *started.last_caps.lock().unwrap() = Some(caps.clone());
-                        *started.last_caps_time.lock().unwrap() = Some(std::time::Instant::now());
-                    } else if caps_interval > 0 {
-                        let last_time = started.last_caps_time.lock().unwrap();
-                        if let Some(last) = *last_time
-                            && last.elapsed().as_secs() >= caps_interval as u64
-                        {
-                            should_send = true;
-                            drop(last_time);
-                            *started.last_caps_time.lock().unwrap() =
-                                Some(std::time::Instant::now());
-                        }
-                    }
-                }
-
-                if should_send {
-                    MetadataBuilder::new().caps(&caps).build()
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
-        } else {
-            None
+            (settings.aggregate_window_ms, settings.batch_lists)
         };
-
-        // Process each buffer in the list
-        for buffer in list.iter() {
-            // Get buffer data
-            let b = buffer.map_readable().map_err(|_| {
-                gst::element_imp_error!(
-                    self,
-                    gst::ResourceError::Read,
-                    ["Failed to map buffer for reading in buffer list"]
-                );
-                errors_count += 1;
-                gst::FlowError::Error
-            })?;
-
-            // Send buffer with caps attachment
-            let put_builder = started.ready.publisher.put(b.as_slice());
-            let result = if let Some(ref attachment) = caps_attachment {
-                put_builder.attachment(attachment.clone()).wait()
-            } else {
-                put_builder.wait()
-            };
-
-            match result {
-                Ok(_) => {
-                    total_bytes += b.len() as u64;
-                    total_messages += 1;
-                }
-                Err(e) => {
-                    errors_count += 1;
-
-                    // Get key expression for error reporting
-                    let key_expr = self.settings.lock().unwrap().key_expr.clone();
-                    let error_msg = format!("{}", e);
-                    let err = ZenohError::Publish {
-                        key_expr,
-                        source: e,
-                    };
-
-                    if error_msg.contains("timeout")
-                        || error_msg.contains("connection")
-                        || error_msg.contains("network")
-                    {
-                        gst::warning!(CAT, imp = self, "Network error in buffer list: {}", err);
-                    } else {
-                        gst::warning!(CAT, imp = self, "Error publishing buffer in list: {}", err);
-                    }
-
-                    // Continue processing remaining buffers instead of failing immediately
-                    // This provides better resilience for batch operations
-                }
+        if aggregate_window_ms > 0 {
+            for buffer in list.iter_owned() {
+                self.render(&buffer)?;
             }
+            return Ok(gst::FlowSuccess::Ok);
         }
 
-        // Update statistics in a single operation for better performance
-        {
-            let mut stats = started.stats.lock().unwrap();
-            stats.bytes_sent += total_bytes;
-            stats.messages_sent += total_messages;
-            stats.errors += errors_count;
-        }
+        self.publish_list(list, batch_lists)
+    }
 
-        if errors_count > 0 {
-            gst::warning!(
-                CAT,
-                imp = self,
-                "Completed buffer list with {} errors out of {} buffers",
-                errors_count,
-                list.len()
-            );
-        }
+    fn unlock(&self) -> Result<(), gst::ErrorMessage> {
+        // Wakes a render waiting for max-bitrate
+        self.flushing.store(true, Ordering::SeqCst);
+        Ok(())
+    }
 
-        // Return success if at least one buffer was sent successfully
-        if total_messages > 0 {
-            Ok(gst::FlowSuccess::Ok)
-        } else if errors_count > 0 {
-            // All buffers failed
-            gst::element_imp_error!(
-                self,
-                gst::ResourceError::Write,
-                ["Failed to send all buffers in list"]
-            );
-            Err(gst::FlowError::Error)
-        } else {
-            // Empty list
-            Ok(gst::FlowSuccess::Ok)
-        }
+    fn unlock_stop(&self) -> Result<(), gst::ErrorMessage> {
+        self.flushing.store(false, Ordering::SeqCst);
+        Ok(())
     }
 
     fn stop(&self) -> Result<(), gst::ErrorMessage> {
+        // A batch still pending (stopped without EOS) is published before the
+        // publisher is demoted, then the aggregator starts over for the next stream
+        self.flush_aggregate(None);
+        *self.aggregator.lock().unwrap() = Aggregator::default();
+
         let mut state = self.state.lock().unwrap();
 
         // Check if we can stop from current state
         if !state.can_stop() {
-            let current_state = match *state {
-                State::Stopped => "Stopped",
-                State::Ready(_) => "Ready",
-                State::Starting => "Starting",
-                State::Started(_) => "Started",
-                State::Stopping => "Stopping",
-            };
+            let current_state = state.name();
             gst::debug!(CAT, "ZenohSink stop called from state: {}", current_state);
             if state.is_stopped() {
                 return Ok(()); // Already stopped is not an error
@@ -1457,6 +4199,12 @@ impl BaseSinkImpl for ZenohSink {
     fn event(&self, event: gst::Event) -> bool {
         use gst::EventView;
 
+        // Whatever the event applies to comes after the buffers held back
+        if event.is_serialized() && event.type_() != gst::EventType::FlushStop {
+            self.flush_aggregate(None);
+        }
+        self.forward_event(&event);
+
         match event.view() {
             EventView::Eos(_) => {
                 gst::debug!(CAT, imp = self, "End of stream");
@@ -1466,12 +4214,54 @@ impl BaseSinkImpl for ZenohSink {
             EventView::FlushStart(_) => {
                 gst::debug!(CAT, imp = self, "Flush start");
                 // Could abort any pending publish operations if needed
-                self.parent_event(event)
+                let result = self.parent_event(event);
+                // Flushed data isn't published, aggregated or not
+                self.aggregator.lock().unwrap().clear();
+                result
             }
             EventView::FlushStop(_) => {
                 gst::debug!(CAT, imp = self, "Flush stop - ready for new data");
                 self.parent_event(event)
             }
+            EventView::Caps(caps_event) => {
+                let auto_encoding = self.settings.lock().unwrap().auto_encoding;
+                self.update_encoding(auto_encoding, Some(caps_event.caps()));
+                self.parent_event(event)
+            }
+            EventView::Gap(gap) => {
+                self.publish_gap(gap);
+                self.parent_event(event)
+            }
+            EventView::StreamStart(stream_start) => {
+                // Tags belong to the stream they were sent in
+                *self.tags.lock().unwrap() = None;
+                self.tags_changed.store(false, Ordering::Release);
+                // Receivers start their stream with the upstream identity
+                let identity = StreamIdentity::from_event(stream_start);
+                let mut current = self.stream_identity.lock().unwrap();
+                if current.as_ref() != Some(&identity) {
+                    gst::debug!(CAT, imp = self, "Publishing stream identity {:?}", identity);
+                    *current = Some(identity);
+                    self.stream_identity_changed.store(true, Ordering::Release);
+                }
+                drop(current);
+                self.parent_event(event)
+            }
+            EventView::Tag(tag_event) => {
+                let mut tags = self.tags.lock().unwrap();
+                let merged = match *tags {
+                    Some(ref current) => current.merge(tag_event.tag(), gst::TagMergeMode::Replace),
+                    None => tag_event.tag_owned(),
+                };
+                let merged = crate::metadata::transmitted_tags(&merged);
+                if merged != *tags {
+                    gst::debug!(CAT, imp = self, "Publishing tags {:?}", merged);
+                    *tags = merged;
+                    self.tags_changed.store(true, Ordering::Release);
+                }
+                drop(tags);
+                self.parent_event(event)
+            }
             _ => {
                 gst::log!(CAT, imp = self, "Handling event {:?}", event);
                 self.parent_event(event)
@@ -1492,72 +4282,182 @@ impl URIHandlerImpl for ZenohSink {
         if settings.key_expr.is_empty() {
             return None;
         }
+        let defaults = Settings::default();
 
-        // Build URI in format: zenoh:key-expr?param1=value1&param2=value2
-        let mut uri = format!("zenoh:{}", settings.key_expr);
-        let mut params = Vec::new();
+        // Build URI in format: zenoh:key-expr?param1=value1&param2=value2,
+        // listing the properties that differ from their default
+        let mut builder = UriBuilder::new(&settings.key_expr);
 
-        if let Some(ref config) = settings.config_file {
-            params.push(format!("config={}", urlencoding::encode(config)));
+        if let Some(ref key_vars) = settings.key_vars {
+            builder.param("key-vars", key_vars);
         }
-        if settings.priority != 5 {
-            params.push(format!("priority={}", settings.priority));
-        }
-        if settings.congestion_control != "block" {
-            params.push(format!(
-                "congestion-control={}",
-                settings.congestion_control
-            ));
+        if let Some(ref user_metadata) = settings.user_metadata {
+            builder.param("user-metadata", user_metadata);
         }
-        if settings.reliability != "best-effort" {
-            params.push(format!("reliability={}", settings.reliability));
+        // The password is left out so it can't leak through the URI
+        for (key, value) in [
+            ("config", &settings.config_file),
+            ("config-json5", &settings.config_json5),
+            ("tls-root-ca", &settings.tls_root_ca),
+            ("tls-client-cert", &settings.tls_client_cert),
+            ("tls-client-key", &settings.tls_client_key),
+            ("username", &settings.username),
+            ("session-group", &settings.session_group),
+        ] {
+            if let Some(value) = value {
+                builder.param(key, value);
+            }
         }
-        if settings.express {
-            params.push("express=true".to_string());
+        builder.non_default("scouting", settings.scouting, defaults.scouting);
+        builder.non_default(
+            "endpoints-only",
+            settings.endpoints_only,
+            defaults.endpoints_only,
+        );
+        builder.non_default("priority", settings.priority, defaults.priority);
+        builder.non_default(
+            "keyframe-priority",
+            settings.keyframe_priority,
+            defaults.keyframe_priority,
+        );
+        builder.non_default("keyframes-only", settings.keyframes_only, defaults.keyframes_only);
+        builder.non_default(
+            "congestion-control",
+            &settings.congestion_control,
+            &defaults.congestion_control,
+        );
+        builder.non_default("reliability", &settings.reliability, &defaults.reliability);
+        builder.non_default("express", settings.express, defaults.express);
+        builder.non_default(
+            "express-congestion-control",
+            uri::enum_nick(settings.express_congestion_control),
+            uri::enum_nick(defaults.express_congestion_control),
+        );
+        builder.non_default(
+            "locality",
+            uri::enum_nick(settings.locality),
+            uri::enum_nick(defaults.locality),
+        );
+        builder.non_default("send-caps", settings.send_caps, defaults.send_caps);
+        builder.non_default("caps-interval", settings.caps_interval, defaults.caps_interval);
+        builder.non_default(
+            "caps-channel",
+            uri::enum_nick(settings.caps_channel),
+            uri::enum_nick(defaults.caps_channel),
+        );
+        builder.non_default("forward-events", settings.forward_events, defaults.forward_events);
+        builder.non_default("enable-fku", settings.enable_fku, defaults.enable_fku);
+        builder.non_default("auto-encoding", settings.auto_encoding, defaults.auto_encoding);
+        builder.non_default(
+            "send-buffer-meta",
+            settings.send_buffer_meta,
+            defaults.send_buffer_meta,
+        );
+        builder.non_default(
+            "sequence-numbers",
+            settings.sequence_numbers,
+            defaults.sequence_numbers,
+        );
+        builder.non_default("batch-lists", settings.batch_lists, defaults.batch_lists);
+        builder.non_default(
+            "aggregate-window-ms",
+            settings.aggregate_window_ms,
+            defaults.aggregate_window_ms,
+        );
+        builder.non_default("payload-only", settings.payload_only, defaults.payload_only);
+        builder.non_default(
+            "key-suffix-from-meta",
+            settings.key_suffix_from_meta,
+            defaults.key_suffix_from_meta,
+        );
+        builder.non_default(
+            "max-routed-publishers",
+            settings.max_routed_publishers,
+            defaults.max_routed_publishers,
+        );
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        {
+            builder.non_default(
+                "compression",
+                uri::enum_nick(settings.compression),
+                uri::enum_nick(defaults.compression),
+            );
+            builder.non_default(
+                "compression-level",
+                settings.compression_level,
+                defaults.compression_level,
+            );
+            builder.non_default(
+                "compression-min-size",
+                settings.compression_min_size,
+                defaults.compression_min_size,
+            );
+            builder.non_default(
+                "compression-negotiate",
+                settings.compression_negotiate,
+                defaults.compression_negotiate,
+            );
         }
-
-        if !params.is_empty() {
-            uri.push('?');
-            uri.push_str(&params.join("&"));
+        #[cfg(feature = "shared-memory")]
+        {
+            builder.non_default("use-shm", settings.use_shm, defaults.use_shm);
+            builder.non_default("shm-size", settings.shm_size, defaults.shm_size);
         }
+        builder.non_default("max-buffer-size", settings.max_buffer_size, defaults.max_buffer_size);
+        builder.non_default("max-bitrate", settings.max_bitrate, defaults.max_bitrate);
+        builder.non_default("put-timeout-ms", settings.put_timeout_ms, defaults.put_timeout_ms);
+        builder.non_default(
+            "wait-for-connection-ms",
+            settings.wait_for_connection_ms,
+            defaults.wait_for_connection_ms,
+        );
+        builder.non_default(
+            "require-connection",
+            settings.require_connection,
+            defaults.require_connection,
+        );
+        builder.non_default(
+            "connectivity-poll-ms",
+            settings.connectivity_poll_ms,
+            defaults.connectivity_poll_ms,
+        );
+        builder.non_default(
+            "session-group-linger-ms",
+            settings.session_group_linger_ms,
+            defaults.session_group_linger_ms,
+        );
+        builder.non_default(
+            "wait-for-subscribers-ms",
+            settings.wait_for_subscribers_ms,
+            defaults.wait_for_subscribers_ms,
+        );
+        builder.non_default(
+            "require-subscribers",
+            settings.require_subscribers,
+            defaults.require_subscribers,
+        );
+        builder.non_default("lazy-start", settings.lazy_start, defaults.lazy_start);
+        #[cfg(feature = "metrics-http")]
+        builder.non_default("metrics-port", settings.metrics_port, defaults.metrics_port);
+        #[cfg(feature = "metrics-http")]
+        builder.non_default(
+            "metrics-address",
+            &settings.metrics_address,
+            &defaults.metrics_address,
+        );
 
-        Some(uri)
+        Some(builder.build())
     }
 
     fn set_uri(&self, uri: &str) -> Result<(), glib::Error> {
         // Parse URI format: zenoh:key-expr?param1=value1&param2=value2
-        if !uri.starts_with("zenoh:") {
-            return Err(glib::Error::new(
-                gst::URIError::BadUri,
-                &format!("Invalid URI scheme, expected 'zenoh:', got: {}", uri),
-            ));
-        }
-
-        let uri_content = &uri[6..]; // Skip "zenoh:"
-
-        // Split into key expression and query parameters
-        let (key_expr, query) = if let Some(pos) = uri_content.find('?') {
-            (&uri_content[..pos], Some(&uri_content[pos + 1..]))
-        } else {
-            (uri_content, None)
-        };
-
-        if key_expr.is_empty() {
-            return Err(glib::Error::new(
-                gst::URIError::BadUri,
-                "Key expression cannot be empty",
-            ));
-        }
-
-        // Decode the key expression
-        let key_expr = urlencoding::decode(key_expr)
-            .map_err(|e| {
-                glib::Error::new(
-                    gst::URIError::BadUri,
-                    &format!("Failed to decode key expression: {}", e),
-                )
-            })?
-            .into_owned();
+        let (key_expr, params) = uri::parse(uri)?;
 
         let mut settings = self.settings.lock().unwrap();
 
@@ -1575,60 +4475,137 @@ impl URIHandlerImpl for ZenohSink {
 
         settings.key_expr = key_expr;
 
-        // Parse query parameters
-        if let Some(query) = query {
-            for param in query.split('&') {
-                if let Some(pos) = param.find('=') {
-                    let key = &param[..pos];
-                    let value = urlencoding::decode(&param[pos + 1..])
-                        .map_err(|e| {
-                            glib::Error::new(
-                                gst::URIError::BadUri,
-                                &format!("Failed to decode parameter value: {}", e),
-                            )
-                        })?
-                        .into_owned();
-
-                    match key {
-                        "config" => settings.config_file = Some(value),
-                        "priority" => {
-                            settings.priority = value.parse().map_err(|_| {
-                                glib::Error::new(
-                                    gst::URIError::BadUri,
-                                    &format!("Invalid priority value: {}", value),
-                                )
-                            })?;
-                        }
-                        "congestion-control" => {
-                            if value != "block" && value != "drop" {
-                                return Err(glib::Error::new(
-                                    gst::URIError::BadUri,
-                                    &format!("Invalid congestion-control value: {}", value),
-                                ));
-                            }
-                            settings.congestion_control = value;
-                        }
-                        "reliability" => {
-                            if value != "best-effort" && value != "reliable" {
-                                return Err(glib::Error::new(
-                                    gst::URIError::BadUri,
-                                    &format!("Invalid reliability value: {}", value),
-                                ));
-                            }
-                            settings.reliability = value;
-                        }
-                        "express" => {
-                            settings.express = value.parse().map_err(|_| {
-                                glib::Error::new(
-                                    gst::URIError::BadUri,
-                                    &format!("Invalid express value: {}", value),
-                                )
-                            })?;
-                        }
-                        _ => {
-                            gst::warning!(CAT, imp = self, "Unknown URI parameter: {}", key);
-                        }
-                    }
+        // Parameters are named and validated like the properties
+        for (key, value) in params {
+            let key = key.as_str();
+            match key {
+                "key-vars" => settings.key_vars = Some(uri::parse_value(key, &value)?),
+                "user-metadata" => settings.user_metadata = Some(uri::parse_value(key, &value)?),
+                "config" => settings.config_file = Some(value),
+                "config-json5" => settings.config_json5 = Some(value),
+                "scouting" => settings.scouting = uri::parse_bool(key, &value)?,
+                "endpoints-only" => settings.endpoints_only = uri::parse_bool(key, &value)?,
+                "tls-root-ca" => settings.tls_root_ca = Some(value),
+                "tls-client-cert" => settings.tls_client_cert = Some(value),
+                "tls-client-key" => settings.tls_client_key = Some(value),
+                "username" => settings.username = Some(value),
+                "password" => settings.password = Some(value),
+                "priority" => settings.priority = uri::parse_in_range(key, &value, 1..=7)?,
+                "keyframe-priority" => {
+                    settings.keyframe_priority = uri::parse_in_range(key, &value, 0..=7)?;
+                }
+                "keyframes-only" => settings.keyframes_only = uri::parse_bool(key, &value)?,
+                "congestion-control" => {
+                    settings.congestion_control =
+                        uri::parse_choice(key, value, &["block", "drop"])?;
+                }
+                "reliability" => {
+                    settings.reliability =
+                        uri::parse_choice(key, value, &["best-effort", "reliable"])?;
+                }
+                "express" => settings.express = uri::parse_bool(key, &value)?,
+                "express-congestion-control" => {
+                    settings.express_congestion_control = uri::parse_enum(key, &value)?;
+                }
+                "locality" => settings.locality = uri::parse_enum(key, &value)?,
+                "send-caps" => settings.send_caps = uri::parse_bool(key, &value)?,
+                "caps-interval" => {
+                    settings.caps_interval = uri::parse_in_range(key, &value, 0..=3600)?;
+                }
+                "caps-channel" => settings.caps_channel = uri::parse_enum(key, &value)?,
+                "forward-events" => settings.forward_events = uri::parse_bool(key, &value)?,
+                "enable-fku" => settings.enable_fku = uri::parse_bool(key, &value)?,
+                "auto-encoding" => settings.auto_encoding = uri::parse_bool(key, &value)?,
+                "send-buffer-meta" => settings.send_buffer_meta = uri::parse_bool(key, &value)?,
+                "sequence-numbers" => settings.sequence_numbers = uri::parse_bool(key, &value)?,
+                "batch-lists" => settings.batch_lists = uri::parse_bool(key, &value)?,
+                "aggregate-window-ms" => {
+                    settings.aggregate_window_ms = uri::parse_in_range(key, &value, 0..=10_000)?;
+                }
+                "payload-only" => settings.payload_only = uri::parse_bool(key, &value)?,
+                "key-suffix-from-meta" => {
+                    settings.key_suffix_from_meta = uri::parse_bool(key, &value)?;
+                }
+                "max-routed-publishers" => {
+                    settings.max_routed_publishers =
+                        uri::parse_in_range(key, &value, 1..=u32::MAX)?;
+                }
+                #[cfg(any(
+                    feature = "compression-zstd",
+                    feature = "compression-lz4",
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
+                ))]
+                "compression" => settings.compression = uri::parse_enum(key, &value)?,
+                #[cfg(any(
+                    feature = "compression-zstd",
+                    feature = "compression-lz4",
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
+                ))]
+                "compression-level" => {
+                    settings.compression_level = uri::parse_in_range(key, &value, 1..=9)?;
+                }
+                #[cfg(any(
+                    feature = "compression-zstd",
+                    feature = "compression-lz4",
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
+                ))]
+                "compression-min-size" => {
+                    settings.compression_min_size = uri::parse_value(key, &value)?;
+                }
+                #[cfg(any(
+                    feature = "compression-zstd",
+                    feature = "compression-lz4",
+                    feature = "compression-gzip",
+                    feature = "compression-brotli",
+                    feature = "compression-snappy"
+                ))]
+                "compression-negotiate" => {
+                    settings.compression_negotiate = uri::parse_bool(key, &value)?;
+                }
+                #[cfg(feature = "shared-memory")]
+                "use-shm" => settings.use_shm = uri::parse_bool(key, &value)?,
+                #[cfg(feature = "shared-memory")]
+                "shm-size" => {
+                    settings.shm_size = uri::parse_in_range(key, &value, 4096..=u64::MAX)?;
+                }
+                "max-buffer-size" => settings.max_buffer_size = uri::parse_value(key, &value)?,
+                "max-bitrate" => settings.max_bitrate = uri::parse_value(key, &value)?,
+                "put-timeout-ms" => settings.put_timeout_ms = uri::parse_value(key, &value)?,
+                "wait-for-connection-ms" => {
+                    settings.wait_for_connection_ms = uri::parse_value(key, &value)?;
+                }
+                "require-connection" => {
+                    settings.require_connection = uri::parse_bool(key, &value)?;
+                }
+                "connectivity-poll-ms" => {
+                    settings.connectivity_poll_ms = uri::parse_in_range(key, &value, 0..=60_000)?;
+                }
+                "wait-for-subscribers-ms" => {
+                    settings.wait_for_subscribers_ms = uri::parse_value(key, &value)?;
+                }
+                "require-subscribers" => {
+                    settings.require_subscribers = uri::parse_bool(key, &value)?;
+                }
+                "lazy-start" => settings.lazy_start = uri::parse_bool(key, &value)?,
+                #[cfg(feature = "metrics-http")]
+                "metrics-port" => {
+                    settings.metrics_port = uri::parse_in_range(key, &value, 0..=u16::MAX as u32)?;
+                }
+                #[cfg(feature = "metrics-http")]
+                "metrics-address" => settings.metrics_address = value,
+                "session-group" => settings.session_group = Some(value),
+                "session-group-linger-ms" => {
+                    let max = crate::session::MAX_SESSION_GROUP_LINGER_MS;
+                    settings.session_group_linger_ms = uri::parse_in_range(key, &value, 0..=max)?;
+                }
+                _ => {
+                    gst::warning!(CAT, imp = self, "Unknown URI parameter: {}", key);
                 }
             }
         }
@@ -1636,3 +4613,34 @@ impl URIHandlerImpl for ZenohSink {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_put_worker_times_out_blocked_put() {
+        let mut worker = PutWorker::new();
+        let timeout = Duration::from_millis(50);
+
+        assert!(matches!(worker.put(timeout, || Ok(())), Some(Ok(()))));
+
+        // A put outlasting the timeout is abandoned...
+        let started = Instant::now();
+        assert!(
+            worker
+                .put(timeout, || {
+                    std::thread::sleep(Duration::from_millis(300));
+                    Ok(())
+                })
+                .is_none()
+        );
+        assert!(started.elapsed() < Duration::from_millis(250));
+
+        // ...and later puts aren't attempted while it is still blocked
+        assert!(worker.put(timeout, || panic!("ran behind a blocked put")).is_none());
+
+        std::thread::sleep(Duration::from_millis(400));
+        assert!(matches!(worker.put(timeout, || Err("failed".into())), Some(Err(_))));
+    }
+}
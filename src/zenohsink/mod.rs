@@ -16,11 +16,34 @@
 //! ## Properties
 //!
 //! * `key-expr` - Zenoh key expression for publishing data (required)
-//!   - Example: "demo/video/stream" or "sensors/temperature/{device_id}"
+//!   - Example: "demo/video/stream" or "cameras/${device-id}/video"
+//!   - A comma-separated list ("cam/front,aliases/main") publishes every buffer on each key;
+//!     each key costs a full copy of the stream in bandwidth
+//!   - `${name}` placeholders are filled from `key-vars` at start; `$$` is a literal `$`
+//!     (Zenoh's `$*` wildcard needs no escaping)
+//! * `key-vars` - Structure with the placeholder values (e.g. "vars, device-id=front")
+//!   - Starting fails if a placeholder has no matching field
 //! * `config` - Path to Zenoh configuration file (optional)
 //!   - Allows custom Zenoh network configuration (endpoints, discovery, etc.)
+//! * `config-json5` - Inline Zenoh configuration in JSON5 (optional, overrides `config`)
+//! * `scouting` - Discover peers via multicast scouting (default: true)
+//!   - Disable on networks where multicast doesn't work; peers are then reached
+//!     through the endpoints of the configuration
+//! * `endpoints-only` - Connect only to the configured endpoints (default: false)
+//!   - Client mode without multicast scouting, for routers on other subnets;
+//!     starting fails when the configuration has no `connect/endpoints`
+//! * `tls-root-ca`, `tls-client-cert`, `tls-client-key` - TLS certificate files (optional)
+//!   - Override the matching `transport/link/tls` keys of the configuration file
+//! * `username`, `password` - Zenoh usrpwd authentication (optional, `password` is write-only)
 //! * `priority` - Publisher priority level (1-7, default: 5)
 //!   - 1=RealTime (highest), 2=InteractiveHigh, 3=InteractiveLow, 4=DataHigh, 5=Data(default), 6=DataLow, 7=Background(lowest)
+//! * `keyframe-priority` - Priority for key frames, i.e. buffers without `DELTA_UNIT` (0-7, default: 0)
+//!   - 0 uses `priority`; otherwise key frames are put with this priority and the
+//!     publisher's other QoS settings, e.g. to send them ahead of delta frames
+//!   - Can be changed while playing
+//! * `keyframes-only` - Publish only key frames, skipping `DELTA_UNIT` buffers (default: false)
+//!   - Produces a sparse, self-decodable preview stream; skipped buffers are counted in
+//!     `dropped-filtered`. Requires an upstream encoder that flags delta units
 //! * `congestion-control` - Congestion control policy (default: "block")
 //!   - `"block"`: Wait for network congestion to clear (ensures delivery)
 //!   - `"drop"`: Drop messages during congestion (maintains real-time performance)
@@ -30,6 +53,88 @@
 //! * `express` - Enable express mode for lower latency (default: false)
 //!   - Bypasses some internal queues for reduced end-to-end latency
 //!   - May increase CPU usage but improves responsiveness
+//!   - Can be toggled while playing (publishers are re-declared between buffers)
+//! * `express-congestion-control` - Congestion control while `express=true` (default: inherit)
+//!   - `inherit`: Same as `congestion-control`
+//!   - `block` / `drop`: Override it in express mode only
+//! * `locality` - Subscribers allowed to receive the data (default: any)
+//!   - `any`: Same session and remote subscribers
+//!   - `session-local`: Only subscribers declared on the same Zenoh session
+//!   - `remote`: Only subscribers on other sessions (prevents local echo)
+//! * `use-shm` - Publish from a Zenoh shared-memory pool (default: false, requires the `shared-memory` feature)
+//!   - Subscribers on the same host receive buffers without a network copy;
+//!     others transparently get regular messages
+//! * `shm-size` - Size of the shared-memory pool in bytes (default: 32 MiB)
+//! * `max-buffer-size` - Drop buffers whose payload exceeds this many bytes (default: 0 = unlimited)
+//!   - Checked after compression; counted in `dropped-oversize`
+//! * `max-bitrate` - Egress limit in bits per second (default: 0 = unlimited)
+//!   - Token bucket over payload and metadata bytes: rendering waits until the rate is
+//!     back under the limit, or drops the buffer with `congestion-control=drop`
+//! * `put-timeout-ms` - Give up on a put that takes longer than this (default: 0 = wait)
+//!   - Escape hatch for `congestion-control=block`: the buffer is dropped and counted
+//!     in `dropped-timeout` instead of stalling the streaming thread
+//!   - Zenoh can't cancel the put, so with `reliability=reliable` the sample may
+//!     still be delivered once the network recovers
+//! * `wait-for-connection-ms` - Wait for a router or peer before starting (default: 0 = don't wait)
+//!   - Avoids publishing the first buffers before the session has joined the network
+//! * `require-connection` - Fail the state change if that wait times out (default: false)
+//! * `connectivity-poll-ms` - Interval of the session connectivity check (default: 1000, 0 = off)
+//!   - Drives the `session-disconnected` and `session-reconnected` signals
+//! * `session-group-linger-ms` - Keep the `session-group` session open once released
+//!   (default: 0 = close right away)
+//!   - The session of a group is closed when its last element stops; with a linger,
+//!     elements briefly overlapping reuse it instead of reopening one
+//! * `wait-for-subscribers-ms` - Wait for a matching subscriber before starting (default: 0 = don't wait)
+//!   - PLAYING then means someone listens, for on-demand pipelines
+//! * `require-subscribers` - Fail the state change if that wait times out (default: false)
+//! * `lazy-start` - Create the session and publishers on the first buffer (default: false)
+//!   - A sink that never receives data holds no Zenoh resources; `has-subscribers`
+//!     and `session-zid` are only available once the first buffer was rendered
+//! * `caps-channel` - Where caps are sent (default: attachment)
+//!   - `attachment`: In the metadata attached to the data samples
+//!   - `sidecar-key`: Published on `<key-expr>/caps`, so subscribers that aren't
+//!     GStreamer see no caps metadata on the data key; pair with zenohsrc's
+//!     `caps-channel=sidecar-key`
+//! * `forward-events` - Publish EOS and custom downstream events on `<key-expr>/events`
+//!   (default: false)
+//!   - zenohsrc with `forward-events=true` pushes them downstream; ordering relative
+//!     to the data samples isn't guaranteed, as they travel on another key
+//! * `enable-fku` - Push the force-key-unit requests of zenohsrc's `enable-fku` upstream
+//!   (default: false)
+//!   - Subscribes to `<key-expr>/fku`, so an encoder upstream produces a key unit
+//!     when a decoder behind a receiver lost one
+//! * `auto-encoding` - Publish with the Zenoh encoding of the caps (default: false)
+//!   - e.g. `video/x-h264` → `video/h264`, for native Zenoh consumers filtering by
+//!     encoding; the counterpart of zenohsrc's `use-encoding-caps`
+//! * `user-metadata` - Structure whose fields are sent as `user.` metadata with every buffer
+//!   - e.g. "user, producer=camera-07"; sent even with `send-caps` and `send-buffer-meta` off
+//! * `sequence-numbers` - Attach a per-buffer sequence number (default: false)
+//!   - zenohsrc and zenohdemux count gaps in `samples-lost` and late arrivals in
+//!     `samples-reordered`, e.g. to diagnose best-effort drops on lossy links
+//! * `batch-lists` - Publish each buffer list as a single sample (default: false)
+//!   - Lowers the per-buffer overhead of payloaded or muxed streams (e.g. RTP);
+//!     zenohsrc and zenohdemux split the batch back into buffers
+//! * `aggregate-window-ms` - Coalesce small buffers arriving within this window (default: 0 = off)
+//!   - For telemetry streams of many tiny buffers: one batched sample per window
+//!     instead of one put per buffer, split back into buffers by the receivers
+//!   - A batch is also published at 16 KiB, on a key frame of a delta-coded
+//!     stream, on serialized events (EOS included), on stop and by the `flush`
+//!     action; larger buffers and gaps are published on their own
+//! * `payload-only` - Publish exactly the buffer bytes, without attachment (default: false)
+//!   - For third-party Zenoh consumers: `send-caps` and `send-buffer-meta` are
+//!     treated as off, tags and the stream identity aren't sent either
+//!   - Starting fails when `sequence-numbers`, `user-metadata`, `batch-lists`,
+//!     `aggregate-window-ms` or `compression` is enabled, as they need one
+//! * `key-suffix-from-meta` - Route buffers by their `GstZenohKeyMeta` (default: false)
+//!   - A buffer whose meta holds `person` is published on `<key-expr>/person`, so one
+//!     sink can fan a stream out to per-class or per-camera keys; buffers without the
+//!     meta use `key-expr`
+//! * `max-routed-publishers` - Routed key suffixes kept declared (default: 64)
+//!   - The least recently used suffix is undeclared to make room
+//! * `metrics-port` - Serve the statistics to Prometheus on this port (default: 0 = off)
+//!   - Requires the `metrics-http` feature; scraped from `http://<host>:<port>/metrics`
+//! * `metrics-address` - Address the metrics endpoint listens on (default: 127.0.0.1)
+//!   - Only local scrapers reach the default; use 0.0.0.0 to serve every interface
 //!
 //! ## Example Pipelines
 //!
@@ -60,6 +165,12 @@
 //!   key-expr=demo/audio/stream config=/path/to/zenoh.json5 priority=4
 //! ```
 //!
+//! ### Publishing on Several Keys
+//! ```bash
+//! # Same stream on a canonical path and a friendly alias (bandwidth is doubled)
+//! gst-launch-1.0 videotestsrc ! zenohsink key-expr="site1/cam/front,cameras/lobby"
+//! ```
+//!
 //! ### Encoded Video with H.264
 //! ```bash
 //! # H.264 encoded video streaming
@@ -71,6 +182,9 @@ use gst::glib;
 use gst::prelude::*;
 use gst::subclass::prelude::ObjectSubclassIsExt;
 
+use crate::metadata::CapsChannel;
+use crate::qos::{ZenohExpressCongestionControl, ZenohLocality};
+
 pub mod imp;
 
 glib::wrapper! {
@@ -170,11 +284,23 @@ impl ZenohSink {
 
     /// Sets the Zenoh key expression for publishing data.
     ///
+    /// Accepts a comma-separated list to publish the same stream on several
+    /// keys. Each key is validated individually when the element starts.
+    ///
     /// Must be set before the element is started.
     pub fn set_key_expr(&self, key_expr: &str) {
         self.set_property("key-expr", key_expr);
     }
 
+    /// Sets the values for the `${name}` placeholders of the key expression.
+    ///
+    /// Each field of the structure fills the placeholder of the same name
+    /// when the element starts; starting fails if a placeholder is left
+    /// unresolved. Must be set before the element is started.
+    pub fn set_key_vars(&self, vars: &gst::Structure) {
+        self.set_property("key-vars", vars);
+    }
+
     /// Sets the path to a Zenoh configuration file.
     ///
     /// The file should be in JSON5 format.
@@ -182,6 +308,67 @@ impl ZenohSink {
         self.set_property("config", config_path);
     }
 
+    /// Sets the Zenoh configuration inline, as a JSON5 string.
+    ///
+    /// Takes precedence over [`set_config`](Self::set_config), which is handy
+    /// where mounting a configuration file is impractical (containers,
+    /// gst-launch). Scouting, TLS and authentication properties still apply
+    /// on top. Malformed JSON5 makes the start fail with the parse error.
+    pub fn set_config_json5(&self, json5: &str) {
+        self.set_property("config-json5", json5);
+    }
+
+    /// Enables or disables multicast scouting.
+    ///
+    /// Disable it on networks where multicast doesn't work, without having
+    /// to write a configuration file. Must be set before the element is started.
+    pub fn set_scouting(&self, enabled: bool) {
+        self.set_property("scouting", enabled);
+    }
+
+    /// Connects only to the endpoints of the configuration.
+    ///
+    /// Uses client mode with multicast scouting disabled, the usual setup to
+    /// reach a known router on another subnet. Starting fails when the
+    /// configuration has no `connect/endpoints`. Must be set before the
+    /// element is started.
+    pub fn set_endpoints_only(&self, enabled: bool) {
+        self.set_property("endpoints-only", enabled);
+    }
+
+    /// Sets the CA certificate used to verify TLS routers and peers.
+    ///
+    /// Overrides `transport/link/tls/root_ca_certificate` of the configuration file.
+    pub fn set_tls_root_ca(&self, path: &str) {
+        self.set_property("tls-root-ca", path);
+    }
+
+    /// Sets the client certificate presented on TLS links (mutual TLS).
+    ///
+    /// Must be set together with [`set_tls_client_key`](Self::set_tls_client_key).
+    pub fn set_tls_client_cert(&self, path: &str) {
+        self.set_property("tls-client-cert", path);
+    }
+
+    /// Sets the private key of the TLS client certificate.
+    pub fn set_tls_client_key(&self, path: &str) {
+        self.set_property("tls-client-key", path);
+    }
+
+    /// Sets the user name for usrpwd authentication.
+    ///
+    /// Must be set together with [`set_password`](Self::set_password).
+    pub fn set_username(&self, username: &str) {
+        self.set_property("username", username);
+    }
+
+    /// Sets the password for usrpwd authentication.
+    ///
+    /// The property is write-only and the password is never logged.
+    pub fn set_password(&self, password: &str) {
+        self.set_property("password", password);
+    }
+
     /// Sets the publisher priority level.
     ///
     /// Valid values: 1-7
@@ -196,6 +383,22 @@ impl ZenohSink {
         self.set_property("priority", priority);
     }
 
+    /// Sets the priority for key frames (buffers without `DELTA_UNIT`).
+    ///
+    /// Same levels as [`set_priority`](Self::set_priority); 0 (the default)
+    /// publishes key frames with the publisher priority.
+    pub fn set_keyframe_priority(&self, priority: u32) {
+        self.set_property("keyframe-priority", priority);
+    }
+
+    /// Publishes only key frames, skipping buffers flagged `DELTA_UNIT`.
+    ///
+    /// Relies on the upstream encoder flagging delta units; unflagged buffers
+    /// are all treated as key frames. Can be changed while playing.
+    pub fn set_keyframes_only(&self, enabled: bool) {
+        self.set_property("keyframes-only", enabled);
+    }
+
     /// Sets the congestion control policy.
     ///
     /// - `"block"`: Wait for network congestion to clear (default)
@@ -216,10 +419,32 @@ impl ZenohSink {
     ///
     /// Express mode bypasses internal queues for lower latency,
     /// but may increase CPU usage.
+    ///
+    /// Can be changed while streaming: the publishers are re-declared
+    /// before the next buffer, keeping statistics.
     pub fn set_express(&self, express: bool) {
         self.set_property("express", express);
     }
 
+    /// Sets the congestion control used while express mode is enabled.
+    ///
+    /// [`ZenohExpressCongestionControl::Inherit`] (the default) keeps
+    /// `congestion-control`; e.g. [`ZenohExpressCongestionControl::Drop`]
+    /// blocks normally but drops in express mode. Can be changed while
+    /// streaming, like [`set_express`](Self::set_express).
+    pub fn set_express_congestion_control(&self, control: ZenohExpressCongestionControl) {
+        self.set_property("express-congestion-control", control);
+    }
+
+    /// Restricts which subscribers receive the data.
+    ///
+    /// Use [`ZenohLocality::Remote`] to avoid echoing data to subscribers
+    /// declared on the same session, or [`ZenohLocality::SessionLocal`]
+    /// for in-process loopback.
+    pub fn set_locality(&self, locality: ZenohLocality) {
+        self.set_property("locality", locality);
+    }
+
     /// Enables or disables sending GStreamer caps as metadata.
     pub fn set_send_caps(&self, send_caps: bool) {
         self.set_property("send-caps", send_caps);
@@ -232,11 +457,192 @@ impl ZenohSink {
         self.set_property("caps-interval", interval);
     }
 
+    /// Sets where caps are sent.
+    ///
+    /// - [`CapsChannel::Attachment`]: In the metadata attached to the data samples (default)
+    /// - [`CapsChannel::SidecarKey`]: Published on `<key-expr>/caps`, leaving the data
+    ///   samples free of caps metadata for subscribers that aren't GStreamer
+    pub fn set_caps_channel(&self, channel: CapsChannel) {
+        self.set_property("caps-channel", channel);
+    }
+
+    /// Enables or disables publishing events on `<key-expr>/events`.
+    ///
+    /// EOS and custom downstream events are serialized and published for
+    /// zenohsrc with `forward-events=true` to push downstream. Can be changed
+    /// while playing.
+    pub fn set_forward_events(&self, enabled: bool) {
+        self.set_property("forward-events", enabled);
+    }
+
+    /// Enables or disables relaying force-key-unit requests upstream.
+    ///
+    /// The requests zenohsrc with `enable-fku=true` publishes on `<key-expr>/fku`
+    /// are pushed upstream as force-key-unit events, toward the encoder. Must be
+    /// set before the element leaves NULL.
+    pub fn set_enable_fku(&self, enabled: bool) {
+        self.set_property("enable-fku", enabled);
+    }
+
+    /// Enables or disables publishing with the Zenoh encoding of the caps.
+    ///
+    /// The encoding comes from [`crate::encoding::encoding_for_caps`]; caps
+    /// without a mapping keep the default encoding. Changing it while running
+    /// re-declares the publishers before the next buffer.
+    pub fn set_auto_encoding(&self, enabled: bool) {
+        self.set_property("auto-encoding", enabled);
+    }
+
     /// Enables or disables sending buffer timing metadata (PTS, DTS, duration, flags).
+    ///
+    /// A segment with a rate or base time other than the defaults is sent along,
+    /// so zenohsrc can restore it. Gap events are published as empty `GAP`
+    /// buffers covering the same time.
     pub fn set_send_buffer_meta(&self, send: bool) {
         self.set_property("send-buffer-meta", send);
     }
 
+    /// Sets fields sent as user metadata with every buffer.
+    ///
+    /// Each field becomes a `user.<field>` attachment entry (strings verbatim,
+    /// other types serialized), e.g. `user, producer=camera-07` to stamp the
+    /// producer's identity. They are sent even when caps and buffer metadata
+    /// aren't. An empty structure sends none. Can be changed while playing.
+    pub fn set_user_metadata(&self, fields: &gst::Structure) {
+        self.set_property("user-metadata", fields);
+    }
+
+    /// Enables or disables attaching a sequence number to each buffer.
+    ///
+    /// Receivers use it to count lost and reordered samples. Numbering starts at 0
+    /// on every start.
+    pub fn set_sequence_numbers(&self, enabled: bool) {
+        self.set_property("sequence-numbers", enabled);
+    }
+
+    /// Enables or disables publishing each buffer list as one batched sample.
+    ///
+    /// The buffers are concatenated in a single payload with a `gst.batch`
+    /// attachment giving their sizes, and zenohsrc/zenohdemux split them again.
+    /// With `send-buffer-meta`, a `gst.batch-timing` entry carries the timing
+    /// and flags of each buffer; the payload is compressed as a whole.
+    /// Receivers older than this feature push the batch as one buffer.
+    pub fn set_batch_lists(&self, enabled: bool) {
+        self.set_property("batch-lists", enabled);
+    }
+
+    /// Sets the window in milliseconds within which small buffers are
+    /// coalesced into one batched sample (0 = disabled, at most 10000).
+    ///
+    /// Uses the `batch-lists` framing: like there, the buffers of a batch
+    /// keep their own timing and the batch takes one sequence number.
+    /// Must be set before the element leaves NULL.
+    pub fn set_aggregate_window_ms(&self, window: u32) {
+        self.set_property("aggregate-window-ms", window);
+    }
+
+    /// Enables or disables publishing the buffer bytes alone, without any attachment.
+    ///
+    /// Caps, buffer metadata, tags and the stream identity are left out. Starting
+    /// fails if a feature needing an attachment (sequence numbers, user metadata,
+    /// batching or compression) is enabled. Must be set before the element
+    /// leaves NULL.
+    pub fn set_payload_only(&self, enabled: bool) {
+        self.set_property("payload-only", enabled);
+    }
+
+    /// Enables or disables routing buffers by their [`ZenohKeyMeta`](crate::metadata::ZenohKeyMeta).
+    ///
+    /// A buffer carrying the meta is published on `<key-expr>/<meta key expression>`
+    /// instead of `key-expr`, e.g. after a pad probe attached it. The publishers of
+    /// these keys are declared on first use; `has-subscribers` and caps queries
+    /// only cover `key-expr`.
+    pub fn set_key_suffix_from_meta(&self, enabled: bool) {
+        self.set_property("key-suffix-from-meta", enabled);
+    }
+
+    /// Sets how many routed key suffixes keep their publishers declared.
+    ///
+    /// Only takes effect before the element is started.
+    pub fn set_max_routed_publishers(&self, max: u32) {
+        self.set_property("max-routed-publishers", max);
+    }
+
+    /// Sets the maximum payload size in bytes (0 = unlimited).
+    ///
+    /// Buffers whose payload (after compression) is larger are dropped
+    /// instead of published and counted in `dropped-oversize`.
+    pub fn set_max_buffer_size(&self, size: u64) {
+        self.set_property("max-buffer-size", size);
+    }
+
+    /// Sets the egress limit in bits per second (0 = unlimited).
+    ///
+    /// Payload and metadata bytes count, on every configured key. Over the
+    /// limit, rendering waits (time counted in `rate-limited-ms`) or, with
+    /// `congestion-control=drop`, drops the buffer (counted in
+    /// `dropped-rate-limit`). Short bursts of up to 100 ms worth of data pass
+    /// without waiting. Can be changed while playing.
+    pub fn set_max_bitrate(&self, bitrate: u64) {
+        self.set_property("max-bitrate", bitrate);
+    }
+
+    /// Sets how long a put may take before the buffer is dropped and counted
+    /// in `dropped-timeout` (0 = wait indefinitely).
+    ///
+    /// Puts then run on a helper thread, so a blocked put (`congestion-control=block`
+    /// on a wedged network) no longer stalls the pipeline. Can be changed while
+    /// playing.
+    pub fn set_put_timeout_ms(&self, timeout: u64) {
+        self.set_property("put-timeout-ms", timeout);
+    }
+
+    /// Sets how long to wait, when starting, for the session to reach at least
+    /// one router or peer (0 = don't wait).
+    ///
+    /// On timeout the element starts anyway with a warning, unless
+    /// [`set_require_connection`](Self::set_require_connection) is enabled.
+    pub fn set_wait_for_connection_ms(&self, timeout: u64) {
+        self.set_property("wait-for-connection-ms", timeout);
+    }
+
+    /// Makes starting fail when no router or peer is reachable within
+    /// `wait-for-connection-ms`.
+    pub fn set_require_connection(&self, require: bool) {
+        self.set_property("require-connection", require);
+    }
+
+    /// Sets how often the session is checked for router and peer connections,
+    /// for the `session-disconnected` and `session-reconnected` signals
+    /// (0 = disabled, at most 60000). Takes effect on the next NULL→READY.
+    pub fn set_connectivity_poll_ms(&self, interval: u32) {
+        self.set_property("connectivity-poll-ms", interval);
+    }
+
+    /// Sets how long to wait, when starting, for a subscriber matching any of
+    /// the keys (0 = don't wait).
+    ///
+    /// Runs after the `wait-for-connection-ms` wait. On timeout the element
+    /// starts anyway with a warning, unless
+    /// [`set_require_subscribers`](Self::set_require_subscribers) is enabled.
+    pub fn set_wait_for_subscribers_ms(&self, timeout: u64) {
+        self.set_property("wait-for-subscribers-ms", timeout);
+    }
+
+    /// Makes starting fail when no subscriber matches within
+    /// `wait-for-subscribers-ms`.
+    pub fn set_require_subscribers(&self, require: bool) {
+        self.set_property("require-subscribers", require);
+    }
+
+    /// Defers opening the Zenoh session and declaring the publishers until the
+    /// first buffer is rendered, so an idle sink holds no network resources.
+    ///
+    /// Must be set before the element leaves the NULL state.
+    pub fn set_lazy_start(&self, lazy: bool) {
+        self.set_property("lazy-start", lazy);
+    }
+
     /// Sets a shared Zenoh session for this element.
     ///
     /// This allows multiple elements to share a single Zenoh session,
@@ -275,6 +681,34 @@ impl ZenohSink {
         self.set_property("session-group", group);
     }
 
+    /// Sets how long in milliseconds the session-group session stays open
+    /// after this element was the last one to release it (0 = close right away).
+    ///
+    /// An element joining the group meanwhile reuses the session instead of
+    /// opening a new one. Must be set before the element leaves NULL.
+    pub fn set_session_group_linger_ms(&self, linger: u32) {
+        self.set_property("session-group-linger-ms", linger);
+    }
+
+    /// Sets the port serving the statistics in Prometheus text format on
+    /// `/metrics` while the element is started (0 = disabled).
+    ///
+    /// Takes effect on the next start. If the port can't be bound, a warning
+    /// is posted and the element runs without the endpoint.
+    #[cfg(feature = "metrics-http")]
+    pub fn set_metrics_port(&self, port: u16) {
+        self.set_property("metrics-port", port as u32);
+    }
+
+    /// Sets the address the `metrics-port` endpoint listens on (default:
+    /// 127.0.0.1, reachable from the local host only).
+    ///
+    /// Takes effect on the next start. Use `0.0.0.0` to serve every interface.
+    #[cfg(feature = "metrics-http")]
+    pub fn set_metrics_address(&self, address: &str) {
+        self.set_property("metrics-address", address);
+    }
+
     // -------------------------------------------------------------------------
     // Property Getters
     // -------------------------------------------------------------------------
@@ -284,16 +718,66 @@ impl ZenohSink {
         self.property("key-expr")
     }
 
+    /// Returns the key expression placeholder values, if set.
+    pub fn key_vars(&self) -> Option<gst::Structure> {
+        self.property("key-vars")
+    }
+
     /// Returns the path to the Zenoh configuration file, if set.
     pub fn config(&self) -> Option<String> {
         self.property("config")
     }
 
+    /// Returns the inline JSON5 configuration, if set.
+    pub fn config_json5(&self) -> Option<String> {
+        self.property("config-json5")
+    }
+
+    /// Returns whether multicast scouting is enabled.
+    pub fn scouting(&self) -> bool {
+        self.property("scouting")
+    }
+
+    /// Returns whether only the configured endpoints are used.
+    pub fn endpoints_only(&self) -> bool {
+        self.property("endpoints-only")
+    }
+
+    /// Returns the path to the TLS CA certificate, if set.
+    pub fn tls_root_ca(&self) -> Option<String> {
+        self.property("tls-root-ca")
+    }
+
+    /// Returns the path to the TLS client certificate, if set.
+    pub fn tls_client_cert(&self) -> Option<String> {
+        self.property("tls-client-cert")
+    }
+
+    /// Returns the path to the TLS client key, if set.
+    pub fn tls_client_key(&self) -> Option<String> {
+        self.property("tls-client-key")
+    }
+
+    /// Returns the user name for usrpwd authentication, if set.
+    pub fn username(&self) -> Option<String> {
+        self.property("username")
+    }
+
     /// Returns the current priority level (1-7).
     pub fn priority(&self) -> u32 {
         self.property("priority")
     }
 
+    /// Returns the key frame priority (0 = same as the publisher priority).
+    pub fn keyframe_priority(&self) -> u32 {
+        self.property("keyframe-priority")
+    }
+
+    /// Returns whether only key frames are published.
+    pub fn keyframes_only(&self) -> bool {
+        self.property("keyframes-only")
+    }
+
     /// Returns the current congestion control mode.
     pub fn congestion_control(&self) -> String {
         self.property("congestion-control")
@@ -309,6 +793,16 @@ impl ZenohSink {
         self.property("express")
     }
 
+    /// Returns the congestion control used while express mode is enabled.
+    pub fn express_congestion_control(&self) -> ZenohExpressCongestionControl {
+        self.property("express-congestion-control")
+    }
+
+    /// Returns the allowed destination locality.
+    pub fn locality(&self) -> ZenohLocality {
+        self.property("locality")
+    }
+
     /// Returns whether caps are being sent as metadata.
     pub fn send_caps(&self) -> bool {
         self.property("send-caps")
@@ -319,16 +813,133 @@ impl ZenohSink {
         self.property("caps-interval")
     }
 
+    /// Returns where caps are sent.
+    pub fn caps_channel(&self) -> CapsChannel {
+        self.property("caps-channel")
+    }
+
+    /// Returns whether events are published on `<key-expr>/events`.
+    pub fn forward_events(&self) -> bool {
+        self.property("forward-events")
+    }
+
+    /// Returns whether force-key-unit requests are relayed upstream.
+    pub fn enable_fku(&self) -> bool {
+        self.property("enable-fku")
+    }
+
+    /// Returns whether the put encoding is derived from the caps.
+    pub fn auto_encoding(&self) -> bool {
+        self.property("auto-encoding")
+    }
+
     /// Returns whether buffer timing metadata is being sent.
     pub fn send_buffer_meta(&self) -> bool {
         self.property("send-buffer-meta")
     }
 
+    /// Returns the fields sent as user metadata with every buffer.
+    pub fn user_metadata(&self) -> Option<gst::Structure> {
+        self.property("user-metadata")
+    }
+
+    /// Returns whether a sequence number is attached to each buffer.
+    pub fn sequence_numbers(&self) -> bool {
+        self.property("sequence-numbers")
+    }
+
+    /// Returns whether buffer lists are published as batched samples.
+    pub fn batch_lists(&self) -> bool {
+        self.property("batch-lists")
+    }
+
+    /// Returns the small-buffer aggregation window in milliseconds (0 = disabled).
+    pub fn aggregate_window_ms(&self) -> u32 {
+        self.property("aggregate-window-ms")
+    }
+
+    /// Returns whether only the buffer bytes are published, without attachment.
+    pub fn payload_only(&self) -> bool {
+        self.property("payload-only")
+    }
+
+    /// Returns whether buffers are routed by their key meta.
+    pub fn key_suffix_from_meta(&self) -> bool {
+        self.property("key-suffix-from-meta")
+    }
+
+    /// Returns how many routed key suffixes keep their publishers declared.
+    pub fn max_routed_publishers(&self) -> u32 {
+        self.property("max-routed-publishers")
+    }
+
+    /// Returns the maximum payload size in bytes (0 = unlimited).
+    pub fn max_buffer_size(&self) -> u64 {
+        self.property("max-buffer-size")
+    }
+
+    /// Returns the egress limit in bits per second (0 = unlimited).
+    pub fn max_bitrate(&self) -> u64 {
+        self.property("max-bitrate")
+    }
+
+    /// Returns how long a put may take before the buffer is dropped (0 = wait).
+    pub fn put_timeout_ms(&self) -> u64 {
+        self.property("put-timeout-ms")
+    }
+
+    /// Returns how long starting waits for a router or peer (0 = don't wait).
+    pub fn wait_for_connection_ms(&self) -> u64 {
+        self.property("wait-for-connection-ms")
+    }
+
+    /// Returns whether starting fails when no router or peer is reachable in time.
+    pub fn require_connection(&self) -> bool {
+        self.property("require-connection")
+    }
+
+    /// Returns the session connectivity check interval in milliseconds (0 = disabled).
+    pub fn connectivity_poll_ms(&self) -> u32 {
+        self.property("connectivity-poll-ms")
+    }
+
+    /// Returns how long starting waits for a matching subscriber (0 = don't wait).
+    pub fn wait_for_subscribers_ms(&self) -> u64 {
+        self.property("wait-for-subscribers-ms")
+    }
+
+    /// Returns whether starting fails when no subscriber matches in time.
+    pub fn require_subscribers(&self) -> bool {
+        self.property("require-subscribers")
+    }
+
+    /// Returns whether the Zenoh resources are created on the first buffer.
+    pub fn lazy_start(&self) -> bool {
+        self.property("lazy-start")
+    }
+
     /// Returns the session group name, if set.
     pub fn session_group(&self) -> Option<String> {
         self.property("session-group")
     }
 
+    /// Returns how long the session-group session lingers once released, in milliseconds.
+    pub fn session_group_linger_ms(&self) -> u32 {
+        self.property("session-group-linger-ms")
+    }
+
+    /// Returns the port of the Prometheus statistics endpoint (0 = disabled).
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_port(&self) -> u16 {
+        self.property::<u32>("metrics-port") as u16
+    }
+
+    /// Returns the address the Prometheus statistics endpoint listens on.
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_address(&self) -> String {
+        self.property("metrics-address")
+    }
+
     // -------------------------------------------------------------------------
     // Matching Status
     // -------------------------------------------------------------------------
@@ -373,6 +984,116 @@ impl ZenohSink {
         })
     }
 
+    /// Connects to the `session-disconnected` signal.
+    ///
+    /// Emitted when the session has lost its last router or peer, as seen by
+    /// the check every `connectivity-poll-ms`. The element keeps running and
+    /// Zenoh drops what is published meanwhile. It runs on a background thread.
+    pub fn connect_session_disconnected<F: Fn(&Self) + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect("session-disconnected", false, move |values| {
+            let element = values[0].get::<gst::Element>().unwrap();
+            let sink = ZenohSink::try_from(element).unwrap();
+            f(&sink);
+            None
+        })
+    }
+
+    /// Connects to the `session-reconnected` signal.
+    ///
+    /// Emitted when the session is connected to a router or peer again after
+    /// `session-disconnected`, or after starting without a connection. It
+    /// runs on a background thread.
+    pub fn connect_session_reconnected<F: Fn(&Self) + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect("session-reconnected", false, move |values| {
+            let element = values[0].get::<gst::Element>().unwrap();
+            let sink = ZenohSink::try_from(element).unwrap();
+            f(&sink);
+            None
+        })
+    }
+
+    // -------------------------------------------------------------------------
+    // Actions
+    // -------------------------------------------------------------------------
+
+    /// Waits until the buffer currently being published has been handed to
+    /// Zenoh, for at most `timeout`.
+    ///
+    /// Call it before setting the pipeline to NULL so the tail of a
+    /// `reliable` + `block` stream isn't cut off mid-publication. Returns
+    /// `false` if the timeout elapsed first (e.g. `put()` is still blocked by
+    /// congestion). Zenoh 1.x exposes no delivery acknowledgement, so data
+    /// already handed off is sent by the transport afterwards (its queue is
+    /// drained when the session closes). A batch held back by
+    /// `aggregate-window-ms` is published first. Same as emitting the `flush`
+    /// action signal with the timeout in milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if !sink.flush(std::time::Duration::from_secs(2)) {
+    ///     eprintln!("publication still blocked, tearing down anyway");
+    /// }
+    /// pipeline.set_state(gst::State::Null)?;
+    /// ```
+    pub fn flush(&self, timeout: std::time::Duration) -> bool {
+        self.imp().flush(timeout)
+    }
+
+    /// Logs a snapshot of the state, settings and statistics at INFO level.
+    ///
+    /// The block goes to the `zenohsink` debug category, so `GST_DEBUG=zenohsink:4`
+    /// shows it without per-buffer traces. Same as emitting `dump-state`.
+    pub fn dump_state(&self) {
+        self.emit_by_name::<()>("dump-state", &[]);
+    }
+
+    // -------------------------------------------------------------------------
+    // Session Introspection (read-only)
+    // -------------------------------------------------------------------------
+
+    /// Returns the Zenoh session used by this element, e.g. to declare extra
+    /// subscribers or run queries on it.
+    ///
+    /// Available from READY (or the first buffer with `lazy-start`) until NULL,
+    /// `None` otherwise. `zenoh::Session` is reference-counted, so this is a
+    /// cheap clone. Its lifetime is still tied to the element: a session the
+    /// element opened itself is closed when it goes back to NULL, after which
+    /// operations on the handle fail. Pass your own session with
+    /// [`set_session`](Self::set_session) to control its lifetime.
+    pub fn session(&self) -> Option<zenoh::Session> {
+        self.imp().session()
+    }
+
+    /// Returns the Zenoh ID of the session used by this element.
+    ///
+    /// Empty when the element is stopped.
+    pub fn session_zid(&self) -> String {
+        self.property("session-zid")
+    }
+
+    /// Returns whether the session is shared rather than opened by this element.
+    ///
+    /// True with an external session or a `session-group` one, e.g. to check
+    /// that the group took effect. False when the element is stopped.
+    pub fn is_session_shared(&self) -> bool {
+        self.property("session-shared")
+    }
+
+    /// Returns the routers and peers the session is currently connected to.
+    ///
+    /// Entries read `router:<zid>` or `peer:<zid>`, routers first. Empty
+    /// when the element is stopped.
+    pub fn peers(&self) -> Vec<String> {
+        self.property("peers")
+    }
+
     // -------------------------------------------------------------------------
     // Statistics (read-only)
     // -------------------------------------------------------------------------
@@ -392,10 +1113,94 @@ impl ZenohSink {
         self.property("errors")
     }
 
-    /// Returns the total number of messages dropped due to congestion.
+    /// Returns the total number of buffers dropped, whatever the reason.
+    ///
+    /// This is the sum of [`dropped_congestion`](Self::dropped_congestion),
+    /// [`dropped_compression_error`](Self::dropped_compression_error),
+    /// [`dropped_oversize`](Self::dropped_oversize) and
+    /// [`dropped_timeout`](Self::dropped_timeout).
     pub fn dropped(&self) -> u64 {
         self.property("dropped")
     }
+
+    /// Returns the number of buffers dropped because publishing failed in
+    /// `congestion-control=drop` mode.
+    ///
+    /// Messages Zenoh discards internally under congestion are not visible
+    /// to the element and are not counted.
+    pub fn dropped_congestion(&self) -> u64 {
+        self.property("dropped-congestion")
+    }
+
+    /// Returns the number of buffers dropped because compressing them failed.
+    pub fn dropped_compression_error(&self) -> u64 {
+        self.property("dropped-compression-error")
+    }
+
+    /// Returns the number of buffers dropped because they exceeded `max-buffer-size`.
+    pub fn dropped_oversize(&self) -> u64 {
+        self.property("dropped-oversize")
+    }
+
+    /// Returns the number of buffers dropped because publishing did not
+    /// complete within `put-timeout-ms`.
+    pub fn dropped_timeout(&self) -> u64 {
+        self.property("dropped-timeout")
+    }
+
+    /// Returns the number of buffers dropped for exceeding `max-bitrate`
+    /// with `congestion-control=drop`.
+    pub fn dropped_rate_limit(&self) -> u64 {
+        self.property("dropped-rate-limit")
+    }
+
+    /// Returns the total time in milliseconds rendering waited for `max-bitrate`.
+    pub fn rate_limited_ms(&self) -> u64 {
+        self.property("rate-limited-ms")
+    }
+
+    /// Returns the number of delta units skipped by `keyframes-only`.
+    ///
+    /// Intentional, so not included in [`dropped`](Self::dropped).
+    pub fn dropped_filtered(&self) -> u64 {
+        self.property("dropped-filtered")
+    }
+
+    /// Returns the number of serialized attachment bytes published.
+    ///
+    /// Metadata overhead on top of [`bytes_sent`](Self::bytes_sent), to weigh
+    /// `caps-interval` and `send-buffer-meta` against it.
+    pub fn attachment_bytes_sent(&self) -> u64 {
+        self.property("attachment-bytes-sent")
+    }
+
+    /// Returns the outgoing bitrate in bits per second over the last second.
+    ///
+    /// Drops back to 0 once no data has flowed for a full second.
+    pub fn bitrate(&self) -> u64 {
+        self.property("bitrate")
+    }
+
+    /// Returns the outgoing message rate in messages per second over the last second.
+    pub fn message_rate(&self) -> f64 {
+        self.property("message-rate")
+    }
+
+    /// Returns the compression ratio (bytes after / bytes before compression).
+    ///
+    /// Only buffers that were actually compressed are counted; returns 1.0
+    /// when nothing was compressed, including builds without compression.
+    pub fn compression_ratio(&self) -> f64 {
+        self.property("compression-ratio")
+    }
+
+    /// Returns all statistics as one compact JSON object keyed by property name.
+    ///
+    /// The values are read atomically, so exporters get a consistent snapshot in
+    /// a single call, e.g. `{"bytes-sent":1024,"messages-sent":8,...}`.
+    pub fn stats_json(&self) -> String {
+        self.property("stats-json")
+    }
 }
 
 impl TryFrom<gst::Element> for ZenohSink {
@@ -446,16 +1251,54 @@ impl TryFrom<gst::Element> for ZenohSink {
 /// ```
 pub struct ZenohSinkBuilder {
     key_expr: String,
+    key_vars: Option<gst::Structure>,
     config: Option<String>,
+    config_json5: Option<String>,
+    scouting: Option<bool>,
+    endpoints_only: Option<bool>,
+    tls_root_ca: Option<String>,
+    tls_client_cert: Option<String>,
+    tls_client_key: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
     priority: Option<u32>,
+    keyframe_priority: Option<u32>,
+    keyframes_only: Option<bool>,
     congestion_control: Option<String>,
     reliability: Option<String>,
     express: Option<bool>,
+    express_congestion_control: Option<ZenohExpressCongestionControl>,
+    locality: Option<ZenohLocality>,
     send_caps: Option<bool>,
     caps_interval: Option<u32>,
+    caps_channel: Option<CapsChannel>,
+    forward_events: Option<bool>,
+    enable_fku: Option<bool>,
+    auto_encoding: Option<bool>,
     send_buffer_meta: Option<bool>,
+    user_metadata: Option<gst::Structure>,
+    sequence_numbers: Option<bool>,
+    batch_lists: Option<bool>,
+    aggregate_window_ms: Option<u32>,
+    payload_only: Option<bool>,
+    key_suffix_from_meta: Option<bool>,
+    max_routed_publishers: Option<u32>,
+    max_buffer_size: Option<u64>,
+    max_bitrate: Option<u64>,
+    put_timeout_ms: Option<u64>,
+    wait_for_connection_ms: Option<u64>,
+    require_connection: Option<bool>,
+    connectivity_poll_ms: Option<u32>,
+    wait_for_subscribers_ms: Option<u64>,
+    require_subscribers: Option<bool>,
+    lazy_start: Option<bool>,
     session: Option<zenoh::Session>,
     session_group: Option<String>,
+    session_group_linger_ms: Option<u32>,
+    #[cfg(feature = "metrics-http")]
+    metrics_port: Option<u16>,
+    #[cfg(feature = "metrics-http")]
+    metrics_address: Option<String>,
 }
 
 impl ZenohSinkBuilder {
@@ -463,31 +1306,125 @@ impl ZenohSinkBuilder {
     pub fn new(key_expr: &str) -> Self {
         Self {
             key_expr: key_expr.to_string(),
+            key_vars: None,
             config: None,
+            config_json5: None,
+            scouting: None,
+            endpoints_only: None,
+            tls_root_ca: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+            username: None,
+            password: None,
             priority: None,
+            keyframe_priority: None,
+            keyframes_only: None,
             congestion_control: None,
             reliability: None,
             express: None,
+            express_congestion_control: None,
+            locality: None,
             send_caps: None,
             caps_interval: None,
+            caps_channel: None,
+            forward_events: None,
+            enable_fku: None,
+            auto_encoding: None,
             send_buffer_meta: None,
+            user_metadata: None,
+            sequence_numbers: None,
+            batch_lists: None,
+            aggregate_window_ms: None,
+            payload_only: None,
+            key_suffix_from_meta: None,
+            max_routed_publishers: None,
+            max_buffer_size: None,
+            max_bitrate: None,
+            put_timeout_ms: None,
+            wait_for_connection_ms: None,
+            require_connection: None,
+            connectivity_poll_ms: None,
+            wait_for_subscribers_ms: None,
+            require_subscribers: None,
+            lazy_start: None,
             session: None,
             session_group: None,
+            session_group_linger_ms: None,
+            #[cfg(feature = "metrics-http")]
+            metrics_port: None,
+            #[cfg(feature = "metrics-http")]
+            metrics_address: None,
         }
     }
 
+    /// Sets the values for the `${name}` placeholders of the key expression.
+    pub fn key_vars(mut self, vars: gst::Structure) -> Self {
+        self.key_vars = Some(vars);
+        self
+    }
+
     /// Sets the path to a Zenoh configuration file.
     pub fn config(mut self, path: &str) -> Self {
         self.config = Some(path.to_string());
         self
     }
 
+    /// Sets the Zenoh configuration inline, as a JSON5 string (overrides `config`).
+    pub fn config_json5(mut self, json5: &str) -> Self {
+        self.config_json5 = Some(json5.to_string());
+        self
+    }
+
+    /// Enables or disables multicast scouting (default: true).
+    pub fn scouting(mut self, enabled: bool) -> Self {
+        self.scouting = Some(enabled);
+        self
+    }
+
+    /// Connects only to the configured endpoints, in client mode (default: false).
+    pub fn endpoints_only(mut self, enabled: bool) -> Self {
+        self.endpoints_only = Some(enabled);
+        self
+    }
+
+    /// Sets the CA certificate used to verify TLS routers and peers.
+    pub fn tls_root_ca(mut self, path: &str) -> Self {
+        self.tls_root_ca = Some(path.to_string());
+        self
+    }
+
+    /// Sets the client certificate and key for mutual TLS.
+    pub fn tls_client_cert(mut self, cert_path: &str, key_path: &str) -> Self {
+        self.tls_client_cert = Some(cert_path.to_string());
+        self.tls_client_key = Some(key_path.to_string());
+        self
+    }
+
+    /// Sets the user name and password for usrpwd authentication.
+    pub fn credentials(mut self, username: &str, password: &str) -> Self {
+        self.username = Some(username.to_string());
+        self.password = Some(password.to_string());
+        self
+    }
+
     /// Sets the publisher priority level (1-7).
     pub fn priority(mut self, priority: u32) -> Self {
         self.priority = Some(priority);
         self
     }
 
+    /// Sets the priority for key frames (0-7, 0 = same as the publisher priority).
+    pub fn keyframe_priority(mut self, priority: u32) -> Self {
+        self.keyframe_priority = Some(priority);
+        self
+    }
+
+    /// Publishes only key frames (default: false).
+    pub fn keyframes_only(mut self, enabled: bool) -> Self {
+        self.keyframes_only = Some(enabled);
+        self
+    }
+
     /// Sets the congestion control policy ("block" or "drop").
     pub fn congestion_control(mut self, mode: &str) -> Self {
         self.congestion_control = Some(mode.to_string());
@@ -506,6 +1443,18 @@ impl ZenohSinkBuilder {
         self
     }
 
+    /// Sets the congestion control used while express mode is enabled.
+    pub fn express_congestion_control(mut self, control: ZenohExpressCongestionControl) -> Self {
+        self.express_congestion_control = Some(control);
+        self
+    }
+
+    /// Restricts which subscribers receive the data.
+    pub fn locality(mut self, locality: ZenohLocality) -> Self {
+        self.locality = Some(locality);
+        self
+    }
+
     /// Enables or disables sending caps as metadata.
     pub fn send_caps(mut self, send: bool) -> Self {
         self.send_caps = Some(send);
@@ -518,12 +1467,133 @@ impl ZenohSinkBuilder {
         self
     }
 
+    /// Sets where caps are sent (default: attachment).
+    pub fn caps_channel(mut self, channel: CapsChannel) -> Self {
+        self.caps_channel = Some(channel);
+        self
+    }
+
+    /// Publishes EOS and custom downstream events on `<key-expr>/events` (default: false).
+    pub fn forward_events(mut self, enabled: bool) -> Self {
+        self.forward_events = Some(enabled);
+        self
+    }
+
+    /// Pushes the force-key-unit requests received on `<key-expr>/fku` upstream (default: false).
+    pub fn enable_fku(mut self, enabled: bool) -> Self {
+        self.enable_fku = Some(enabled);
+        self
+    }
+
+    /// Enables or disables publishing with the Zenoh encoding of the caps
+    /// (default: false).
+    pub fn auto_encoding(mut self, enabled: bool) -> Self {
+        self.auto_encoding = Some(enabled);
+        self
+    }
+
     /// Enables or disables sending buffer timing metadata.
     pub fn send_buffer_meta(mut self, send: bool) -> Self {
         self.send_buffer_meta = Some(send);
         self
     }
 
+    /// Sets fields sent as user metadata with every buffer.
+    pub fn user_metadata(mut self, fields: gst::Structure) -> Self {
+        self.user_metadata = Some(fields);
+        self
+    }
+
+    /// Enables or disables attaching a sequence number to each buffer.
+    pub fn sequence_numbers(mut self, enabled: bool) -> Self {
+        self.sequence_numbers = Some(enabled);
+        self
+    }
+
+    /// Enables or disables publishing each buffer list as one batched sample.
+    pub fn batch_lists(mut self, enabled: bool) -> Self {
+        self.batch_lists = Some(enabled);
+        self
+    }
+
+    /// Sets the small-buffer aggregation window in milliseconds (default: 0 = disabled).
+    pub fn aggregate_window_ms(mut self, window: u32) -> Self {
+        self.aggregate_window_ms = Some(window);
+        self
+    }
+
+    /// Enables or disables publishing the buffer bytes alone (default: false).
+    pub fn payload_only(mut self, enabled: bool) -> Self {
+        self.payload_only = Some(enabled);
+        self
+    }
+
+    /// Enables or disables routing buffers by their key meta.
+    pub fn key_suffix_from_meta(mut self, enabled: bool) -> Self {
+        self.key_suffix_from_meta = Some(enabled);
+        self
+    }
+
+    /// Sets how many routed key suffixes keep their publishers declared.
+    pub fn max_routed_publishers(mut self, max: u32) -> Self {
+        self.max_routed_publishers = Some(max);
+        self
+    }
+
+    /// Sets the maximum payload size in bytes (0 = unlimited).
+    pub fn max_buffer_size(mut self, size: u64) -> Self {
+        self.max_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets the egress limit in bits per second (0 = unlimited).
+    pub fn max_bitrate(mut self, bitrate: u64) -> Self {
+        self.max_bitrate = Some(bitrate);
+        self
+    }
+
+    /// Sets how long a put may take before the buffer is dropped (0 = wait).
+    pub fn put_timeout_ms(mut self, timeout: u64) -> Self {
+        self.put_timeout_ms = Some(timeout);
+        self
+    }
+
+    /// Sets how long starting waits for a router or peer (0 = don't wait).
+    pub fn wait_for_connection_ms(mut self, timeout: u64) -> Self {
+        self.wait_for_connection_ms = Some(timeout);
+        self
+    }
+
+    /// Makes starting fail when no router or peer is reachable in time.
+    pub fn require_connection(mut self, require: bool) -> Self {
+        self.require_connection = Some(require);
+        self
+    }
+
+    /// Sets how often the session connectivity is checked (default: 1000, 0 = disabled).
+    pub fn connectivity_poll_ms(mut self, interval: u32) -> Self {
+        self.connectivity_poll_ms = Some(interval);
+        self
+    }
+
+    /// Sets how long starting waits for a matching subscriber (0 = don't wait).
+    pub fn wait_for_subscribers_ms(mut self, timeout: u64) -> Self {
+        self.wait_for_subscribers_ms = Some(timeout);
+        self
+    }
+
+    /// Makes starting fail when no subscriber matches in time.
+    pub fn require_subscribers(mut self, require: bool) -> Self {
+        self.require_subscribers = Some(require);
+        self
+    }
+
+    /// Defers the Zenoh session and publishers to the first buffer.
+    pub fn lazy_start(mut self, lazy: bool) -> Self {
+        self.lazy_start = Some(lazy);
+        self
+    }
+
     /// Sets a shared Zenoh session for this element.
     ///
     /// This allows multiple elements to share a single Zenoh session,
@@ -545,16 +1615,88 @@ impl ZenohSinkBuilder {
         self
     }
 
+    /// Sets how long the session-group session lingers once released (default: 0 ms).
+    pub fn session_group_linger_ms(mut self, linger: u32) -> Self {
+        self.session_group_linger_ms = Some(linger);
+        self
+    }
+
+    /// Serves the statistics in Prometheus text format on this port.
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_port(mut self, port: u16) -> Self {
+        self.metrics_port = Some(port);
+        self
+    }
+
+    /// Sets the address the metrics endpoint listens on (default: 127.0.0.1).
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_address(mut self, address: &str) -> Self {
+        self.metrics_address = Some(address.to_string());
+        self
+    }
+
+    /// Builds the ZenohSink after checking the key expression.
+    ///
+    /// The `${name}` placeholders are filled from [`key_vars`](Self::key_vars)
+    /// and every entry of a comma-separated list is validated, so a malformed
+    /// key expression is reported here instead of when the element starts.
+    pub fn try_build(self) -> Result<ZenohSink, String> {
+        let key_expr = crate::key_vars::expand(&self.key_expr, self.key_vars.as_deref())
+            .map_err(|placeholder| {
+                format!(
+                    "invalid key expression '{}': unresolved placeholder {} (set it in key-vars)",
+                    self.key_expr, placeholder
+                )
+            })?;
+        for key in key_expr.split(',') {
+            crate::utils::validate_key_expr(key.trim())?;
+        }
+        Ok(self.build())
+    }
+
     /// Builds the ZenohSink with the configured properties.
     pub fn build(self) -> ZenohSink {
         let mut builder = gst::Object::builder::<ZenohSink>().property("key-expr", &self.key_expr);
 
+        if let Some(vars) = self.key_vars {
+            builder = builder.property("key-vars", vars);
+        }
         if let Some(config) = self.config {
             builder = builder.property("config", config);
         }
+        if let Some(json5) = self.config_json5 {
+            builder = builder.property("config-json5", json5);
+        }
+        if let Some(scouting) = self.scouting {
+            builder = builder.property("scouting", scouting);
+        }
+        if let Some(endpoints_only) = self.endpoints_only {
+            builder = builder.property("endpoints-only", endpoints_only);
+        }
+        if let Some(path) = self.tls_root_ca {
+            builder = builder.property("tls-root-ca", path);
+        }
+        if let Some(path) = self.tls_client_cert {
+            builder = builder.property("tls-client-cert", path);
+        }
+        if let Some(path) = self.tls_client_key {
+            builder = builder.property("tls-client-key", path);
+        }
+        if let Some(username) = self.username {
+            builder = builder.property("username", username);
+        }
+        if let Some(password) = self.password {
+            builder = builder.property("password", password);
+        }
         if let Some(priority) = self.priority {
             builder = builder.property("priority", priority);
         }
+        if let Some(priority) = self.keyframe_priority {
+            builder = builder.property("keyframe-priority", priority);
+        }
+        if let Some(enabled) = self.keyframes_only {
+            builder = builder.property("keyframes-only", enabled);
+        }
         if let Some(cc) = self.congestion_control {
             builder = builder.property("congestion-control", cc);
         }
@@ -564,18 +1706,95 @@ impl ZenohSinkBuilder {
         if let Some(exp) = self.express {
             builder = builder.property("express", exp);
         }
+        if let Some(control) = self.express_congestion_control {
+            builder = builder.property("express-congestion-control", control);
+        }
+        if let Some(locality) = self.locality {
+            builder = builder.property("locality", locality);
+        }
         if let Some(sc) = self.send_caps {
             builder = builder.property("send-caps", sc);
         }
         if let Some(ci) = self.caps_interval {
             builder = builder.property("caps-interval", ci);
         }
+        if let Some(channel) = self.caps_channel {
+            builder = builder.property("caps-channel", channel);
+        }
+        if let Some(enabled) = self.forward_events {
+            builder = builder.property("forward-events", enabled);
+        }
+        if let Some(enabled) = self.enable_fku {
+            builder = builder.property("enable-fku", enabled);
+        }
+        if let Some(enabled) = self.auto_encoding {
+            builder = builder.property("auto-encoding", enabled);
+        }
         if let Some(sbm) = self.send_buffer_meta {
             builder = builder.property("send-buffer-meta", sbm);
         }
+        if let Some(fields) = self.user_metadata {
+            builder = builder.property("user-metadata", fields);
+        }
+        if let Some(seq) = self.sequence_numbers {
+            builder = builder.property("sequence-numbers", seq);
+        }
+        if let Some(batch) = self.batch_lists {
+            builder = builder.property("batch-lists", batch);
+        }
+        if let Some(window) = self.aggregate_window_ms {
+            builder = builder.property("aggregate-window-ms", window);
+        }
+        if let Some(enabled) = self.payload_only {
+            builder = builder.property("payload-only", enabled);
+        }
+        if let Some(routed) = self.key_suffix_from_meta {
+            builder = builder.property("key-suffix-from-meta", routed);
+        }
+        if let Some(max) = self.max_routed_publishers {
+            builder = builder.property("max-routed-publishers", max);
+        }
+        if let Some(size) = self.max_buffer_size {
+            builder = builder.property("max-buffer-size", size);
+        }
+        if let Some(bitrate) = self.max_bitrate {
+            builder = builder.property("max-bitrate", bitrate);
+        }
+        if let Some(timeout) = self.put_timeout_ms {
+            builder = builder.property("put-timeout-ms", timeout);
+        }
+        if let Some(timeout) = self.wait_for_connection_ms {
+            builder = builder.property("wait-for-connection-ms", timeout);
+        }
+        if let Some(require) = self.require_connection {
+            builder = builder.property("require-connection", require);
+        }
+        if let Some(interval) = self.connectivity_poll_ms {
+            builder = builder.property("connectivity-poll-ms", interval);
+        }
+        if let Some(timeout) = self.wait_for_subscribers_ms {
+            builder = builder.property("wait-for-subscribers-ms", timeout);
+        }
+        if let Some(require) = self.require_subscribers {
+            builder = builder.property("require-subscribers", require);
+        }
+        if let Some(lazy) = self.lazy_start {
+            builder = builder.property("lazy-start", lazy);
+        }
         if let Some(ref sg) = self.session_group {
             builder = builder.property("session-group", sg);
         }
+        if let Some(linger) = self.session_group_linger_ms {
+            builder = builder.property("session-group-linger-ms", linger);
+        }
+        #[cfg(feature = "metrics-http")]
+        if let Some(port) = self.metrics_port {
+            builder = builder.property("metrics-port", port as u32);
+        }
+        #[cfg(feature = "metrics-http")]
+        if let Some(ref address) = self.metrics_address {
+            builder = builder.property("metrics-address", address);
+        }
 
         let sink: ZenohSink = builder.build().unwrap();
 
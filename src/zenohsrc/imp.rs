@@ -1,67 +1,253 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, LazyLock, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
 
 use gst::subclass::prelude::URIHandlerImpl;
 use gst::{glib, prelude::*, subclass::prelude::*};
 use gst_base::{
-    prelude::BaseSrcExt,
+    prelude::{BaseSrcExt, BaseSrcExtManual},
     subclass::{base_src::CreateSuccess, prelude::*},
 };
 use zenoh::Wait;
 
-use crate::error::{ErrorHandling, ZenohError};
-use crate::metadata::MetadataParser;
+use crate::buffer::PayloadPool;
+use crate::error::{ErrorHandling, FlowErrorHandling, ZenohError};
+use crate::metadata::{
+    BatchPartTiming, CapsChannel, MetadataParser, StreamGroups, StreamIdentity, ZenohUserMeta,
+};
+use crate::qos::ZenohLocality;
+use crate::reorder::ReorderBuffer;
+use crate::session::{ConnectivityMonitor, SessionConfig, SessionWrapper};
+use crate::stats::{IdleTracker, RateTracker, ReceivedQos, SequenceTracker};
+use crate::uri::{self, UriBuilder};
 
 // Define debug category for logging
 static CAT: LazyLock<gst::DebugCategory> = LazyLock::new(|| {
     gst::DebugCategory::new("zenohsrc", gst::DebugColorFlags::empty(), Some("Zenoh Src"))
 });
 
+/// Sizes of the buffers of a received batch, with their timing when applied
+type Batch = (Vec<usize>, Option<Vec<BatchPartTiming>>);
+
+/// How the PTS of output buffers is derived
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "GstZenohSrcTimestampMode")]
+#[repr(u32)]
+pub enum TimestampMode {
+    /// Only the PTS sent in the buffer metadata, if any
+    #[enum_value(name = "None", nick = "none")]
+    None = 0,
+    /// Zenoh timestamp converted to an absolute time when no PTS was sent
+    #[enum_value(name = "Zenoh", nick = "zenoh")]
+    Zenoh = 1,
+    /// Running time of the pipeline clock when the sample is received
+    #[default]
+    #[enum_value(name = "Arrival", nick = "arrival")]
+    Arrival = 2,
+    /// Arrival running time minus the age of the sample's Zenoh timestamp
+    #[enum_value(name = "Pipeline Clock", nick = "pipeline-clock")]
+    PipelineClock = 3,
+}
+
+/// Kinds of Zenoh samples turned into buffers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, glib::Enum)]
+#[enum_type(name = "GstZenohSrcSampleKindFilter")]
+#[repr(u32)]
+pub enum SampleKindFilter {
+    /// Both PUT and DELETE samples
+    #[default]
+    #[enum_value(name = "Any", nick = "any")]
+    Any = 0,
+    /// Only PUT samples
+    #[enum_value(name = "Put", nick = "put")]
+    Put = 1,
+    /// Only DELETE samples
+    #[enum_value(name = "Delete", nick = "delete")]
+    Delete = 2,
+}
+
+impl SampleKindFilter {
+    /// Whether samples of `kind` pass the filter.
+    fn accepts(self, kind: zenoh::sample::SampleKind) -> bool {
+        match self {
+            SampleKindFilter::Any => true,
+            SampleKindFilter::Put => kind == zenoh::sample::SampleKind::Put,
+            SampleKindFilter::Delete => kind == zenoh::sample::SampleKind::Delete,
+        }
+    }
+}
+
 /// Statistics tracking for ZenohSrc
 #[derive(Debug, Clone, Default)]
 struct Statistics {
     bytes_received: u64,
     messages_received: u64,
     errors: u64,
+    /// Samples dropped for being older than `max-lateness-ms`
+    dropped_late: u64,
+    /// Samples dropped for not matching `sample-kind-filter`, or by `drop-empty`
+    dropped_filtered: u64,
+    /// Serialized attachment (metadata) bytes received, on top of `bytes_received`
+    attachment_bytes_received: u64,
+    /// Samples whose metadata was malformed (e.g. unparsable caps), delivered anyway
+    metadata_errors: u64,
+    /// Exponential moving average of end-to-end latency in milliseconds
+    latency_ms: f64,
+    /// Highest end-to-end latency observed in milliseconds
+    max_latency_ms: f64,
+    /// Number of samples that carried a usable Zenoh timestamp
+    latency_samples: u64,
+    /// Sliding-window throughput (bitrate / message-rate)
+    rate: RateTracker,
+    /// Lost / reordered samples according to the publishers' sequence numbers
+    sequence: SequenceTracker,
+    /// QoS of the last sample (`rx-priority` / `rx-express`)
+    rx_qos: ReceivedQos,
+}
+
+/// Smoothing factor for the latency moving average (weight of the newest sample)
+const LATENCY_EMA_ALPHA: f64 = 0.1;
+
+impl Statistics {
+    /// Folds a new latency sample into the moving average and the maximum.
+    fn record_latency(&mut self, latency_ms: f64) {
+        if self.latency_samples == 0 {
+            // Seed the average so it doesn't ramp up from zero
+            self.latency_ms = latency_ms;
+        } else {
+            self.latency_ms += LATENCY_EMA_ALPHA * (latency_ms - self.latency_ms);
+        }
+        self.max_latency_ms = self.max_latency_ms.max(latency_ms);
+        self.latency_samples += 1;
+    }
+
+    /// Serializes all statistics into a compact JSON object keyed by property name.
+    fn to_json(&self) -> String {
+        serde_json::json!({
+            "bytes-received": self.bytes_received,
+            "messages-received": self.messages_received,
+            "errors": self.errors,
+            "dropped-late": self.dropped_late,
+            "dropped-filtered": self.dropped_filtered,
+            "attachment-bytes-received": self.attachment_bytes_received,
+            "metadata-errors": self.metadata_errors,
+            "samples-lost": self.sequence.lost(),
+            "samples-reordered": self.sequence.reordered(),
+            "bitrate": self.rate.bitrate(),
+            "message-rate": self.rate.message_rate(),
+            "latency-ms": self.latency_ms,
+            "max-latency-ms": self.max_latency_ms,
+            "rx-priority": self.rx_qos.priority(),
+            "rx-express": self.rx_qos.express(),
+        })
+        .to_string()
+    }
+}
+
+/// Age of a sample according to the publisher's Zenoh timestamp.
+///
+/// Only meaningful when sender and receiver clocks are synchronized (e.g. via
+/// NTP/PTP). `None` for samples without a timestamp and for samples that appear
+/// to come from the future, rather than letting clock skew distort the result.
+fn sample_age(sample: &zenoh::sample::Sample) -> Option<Duration> {
+    let timestamp = sample.timestamp()?;
+    SystemTime::now()
+        .duration_since(timestamp.get_time().to_system_time())
+        .ok()
+}
+
+/// Whether `sample` is a gap published by zenohsink, an empty buffer flagged GAP.
+fn is_gap_sample(sample: &zenoh::sample::Sample) -> bool {
+    sample
+        .attachment()
+        .and_then(|attachment| MetadataParser::parse(attachment).ok())
+        .and_then(|metadata| metadata.flags())
+        .is_some_and(|flags| flags.contains(gst::BufferFlags::GAP))
 }
 
+/// The sequence number zenohsink's `sequence-numbers` attached to `sample`, if any.
+fn sample_seqnum(sample: &zenoh::sample::Sample) -> Option<u64> {
+    let attachment = sample.attachment()?;
+    MetadataParser::parse(attachment).ok()?.seqnum()
+}
+
+/// Initial delay before re-declaring a lost subscriber
+const RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+/// Longest `create()` waits for a sample before re-checking the flushing flag,
+/// which bounds how long a state change can be held up by a pending receive
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// How long replies to the caps query sent at start are accepted
+const CAPS_QUERY_TIMEOUT: Duration = Duration::from_secs(2);
+/// Upper bound for the exponential reconnection backoff
+const RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(5);
+/// Reconnection attempts before giving up with an error, about 20s of backoff
+const RECONNECT_MAX_ATTEMPTS: u32 = 10;
+/// How often `create()` checks whether the session lost its links (`reconnect`)
+const RECONNECT_CHECK_INTERVAL: Duration = Duration::from_millis(500);
+/// Largest `reorder-window`, bounding the samples held per key
+const MAX_REORDER_WINDOW: u32 = 1024;
+/// Default `reorder-timeout-ms`
+const DEFAULT_REORDER_TIMEOUT_MS: u32 = 200;
+
+/// Subscriber of the data key expression, its samples queued until `create()`
+type SampleSubscriber =
+    zenoh::pubsub::Subscriber<zenoh::handlers::FifoChannelHandler<zenoh::sample::Sample>>;
+
 struct Started {
     // Keeping session field to maintain ownership and prevent session from being dropped
     // while subscriber is still in use. This can be either owned or shared.
-    _session: SessionWrapper,
-    subscriber:
-        zenoh::pubsub::Subscriber<zenoh::handlers::FifoChannelHandler<zenoh::sample::Sample>>,
-    /// Flag to signal that the element is flushing and should cancel blocking operations
-    flushing: Arc<AtomicBool>,
+    // Also used to re-declare the subscriber when reconnecting.
+    session: SessionWrapper,
+    subscriber: SampleSubscriber,
     /// Statistics tracking (shared for thread-safe updates)
     stats: Arc<Mutex<Statistics>>,
+    /// Silence detection for `no-data-timeout-ms`
+    idle: IdleTracker,
+    /// Caps received from a publisher's caps queryable, applied with the first sample
+    queried_caps: Arc<Mutex<Option<gst::Caps>>>,
+    /// Subscriber of the `<key-expr>/caps` sidecar key (`caps-channel=sidecar-key`)
+    caps_subscriber: Option<zenoh::pubsub::Subscriber<()>>,
+    /// Latest caps received on the sidecar key, applied with the next sample
+    sidecar_caps: Arc<Mutex<Option<gst::Caps>>>,
+    /// Subscriber of the `<key-expr>/events` sidecar key (`forward-events`)
+    events_subscriber: Option<zenoh::pubsub::Subscriber<()>>,
+    /// Set by a forwarded EOS, which ends the stream once the samples
+    /// received before it are out
+    forwarded_eos: Arc<AtomicBool>,
+    /// Key force-key-unit requests are published on (`enable-fku`)
+    fku_key: Option<zenoh::key_expr::KeyExpr<'static>>,
+    /// Liveliness tokens advertising the algorithms this build decompresses
+    #[cfg(any(
+        feature = "compression-zstd",
+        feature = "compression-lz4",
+        feature = "compression-gzip",
+        feature = "compression-brotli",
+        feature = "compression-snappy"
+    ))]
+    _compression_tokens: Vec<zenoh::liveliness::LivelinessToken>,
+    /// Samples held to restore their sequence number order (`reorder-window`)
+    reorder: Option<ReorderBuffer<zenoh::sample::Sample>>,
+    /// The next buffer starts anew: the first one, or the first after a reconnection
+    discont: bool,
+    /// Whether the session had a router or peer at the last check (`reconnect`)
+    connected: bool,
+    /// When the session connectivity was last checked (`reconnect`)
+    connectivity_checked: Option<Instant>,
+    /// Pool for copied payloads (`buffer-pool-size`)
+    pool: Option<PayloadPool>,
+    /// Emits the session signals (when `connectivity-poll-ms` is set)
+    _connectivity: Option<ConnectivityMonitor>,
+    /// Prometheus endpoint serving `stats` (when `metrics-port` is set)
+    #[cfg(feature = "metrics-http")]
+    _metrics: Option<crate::metrics::MetricsServer>,
 }
 
-/// Wrapper to handle both owned and shared Zenoh sessions.
-///
-/// This allows the plugin to either create its own session or use
-/// a shared session provided externally, enabling session reuse
-/// across multiple GStreamer elements.
-///
-/// Note: `zenoh::Session` is internally Arc-based and Clone, so the
-/// distinction between Owned and Shared is mainly for documentation
-/// purposes - both variants use the same underlying type.
-enum SessionWrapper {
-    /// Element created this session (will be dropped when element stops)
-    Owned(zenoh::Session),
-    /// Element is using a shared session (may outlive this element)
-    Shared(zenoh::Session),
-}
-
-impl SessionWrapper {
-    /// Get a reference to the underlying Zenoh session
-    fn as_session(&self) -> &zenoh::Session {
-        match self {
-            SessionWrapper::Owned(session) => session,
-            SessionWrapper::Shared(session) => session,
-        }
-    }
+/// Where force-key-unit requests are published (`enable-fku`), set while started.
+struct FkuTarget {
+    session: zenoh::Session,
+    key: zenoh::key_expr::KeyExpr<'static>,
+    /// The statistics of the running stream, counting failed requests
+    stats: Arc<Mutex<Statistics>>,
 }
 
 #[derive(Default)]
@@ -74,6 +260,15 @@ enum State {
 }
 
 impl State {
+    fn name(&self) -> &'static str {
+        match self {
+            State::Stopped => "Stopped",
+            State::Starting => "Starting",
+            State::Started(_) => "Started",
+            State::Stopping => "Stopping",
+        }
+    }
+
     fn is_started(&self) -> bool {
         matches!(self, State::Started(_))
     }
@@ -101,21 +296,96 @@ struct Settings {
     key_expr: String,
     /// Optional path to Zenoh configuration file
     config_file: Option<String>,
+    /// Inline Zenoh configuration (JSON5), takes precedence over `config_file`
+    config_json5: Option<String>,
+    /// Multicast scouting for peer discovery (default: true)
+    scouting: bool,
+    /// Client mode connecting only to the configured endpoints (default: false)
+    endpoints_only: bool,
+    /// CA certificate for verifying TLS routers/peers (overrides the config file)
+    tls_root_ca: Option<String>,
+    /// Client certificate for mutual TLS
+    tls_client_cert: Option<String>,
+    /// Private key of the client certificate
+    tls_client_key: Option<String>,
+    /// User name for usrpwd authentication
+    username: Option<String>,
+    /// Password for usrpwd authentication (write-only, never logged)
+    password: Option<String>,
     /// Subscriber priority level (1-7: 1=RealTime, 2=InteractiveHigh, 3=InteractiveLow, 4=DataHigh, 5=Data(default), 6=DataLow, 7=Background)
+    /// (informational for subscriber)
     priority: u8,
     /// Congestion control policy: "block" or "drop" (informational for subscriber)
     congestion_control: String,
     /// Reliability mode: "best-effort" or "reliable" (matches publisher settings)
     reliability: String,
+    /// Express mode, like zenohsink's (informational for subscriber)
+    express: bool,
     /// Receive timeout in milliseconds for polling Zenoh subscriber
     /// Affects CPU usage vs responsiveness tradeoff (lower = more responsive but higher CPU)
     receive_timeout_ms: u64,
     /// Apply buffer timing metadata (PTS, DTS, duration, flags) from received messages (default: true)
     apply_buffer_meta: bool,
+    /// Mark the first buffer and the ones after a gap or reconnection DISCONT (default: true)
+    mark_discont: bool,
     /// Optional external Zenoh session to share with other elements (Rust API)
     external_session: Option<zenoh::Session>,
     /// Session group name for sharing sessions via property (gst-launch compatible)
     session_group: Option<String>,
+    /// How long the group session stays open after its last element released it
+    session_group_linger_ms: u32,
+    /// Re-declare the subscriber with exponential backoff when it gets disconnected
+    /// instead of failing the pipeline
+    reconnect: bool,
+    /// Fixed output caps for publishers that don't send `gst.caps` metadata
+    caps: Option<gst::Caps>,
+    /// Keep the fixed caps even when received metadata carries caps
+    force_caps: bool,
+    /// Derive caps from the sample encoding when no caps metadata is received
+    use_encoding_caps: bool,
+    /// Expect caps in the sample attachments or on the `<key>/caps` sidecar key
+    caps_channel: CapsChannel,
+    /// Push the events zenohsink forwards on the `<key>/events` sidecar key (default: false)
+    forward_events: bool,
+    /// Relay upstream force-key-unit events to the publishers on the `<key>/fku` sidecar key
+    /// (default: false)
+    enable_fku: bool,
+    /// Accept publications from the same session, remote ones, or both
+    locality: ZenohLocality,
+    /// How output buffers are timestamped
+    timestamp_mode: TimestampMode,
+    /// Kinds of samples delivered, the others are dropped
+    sample_kind_filter: SampleKindFilter,
+    /// Drop zero-length PUT samples (default: false)
+    drop_empty: bool,
+    /// Wrap contiguous payloads in buffers instead of copying them (default: true)
+    zero_copy: bool,
+    /// Drop samples whose payload exceeds this many bytes (0 = unlimited)
+    max_buffer_size: u64,
+    /// Buffers preallocated by the pool for copied payloads (0 = no pool)
+    buffer_pool_size: u32,
+    /// Pool buffers handed out at once before falling back to allocation (0 = unlimited)
+    buffer_pool_max: u32,
+    /// Copy payloads into the buffers of a pool proposed by downstream (default: false)
+    use_downstream_pool: bool,
+    /// Post `zenoh-stream-idle` when no sample arrives for this long (0 = disabled)
+    no_data_timeout_ms: u64,
+    /// Wait after declaring the subscriber before start completes (0 = disabled)
+    declaration_delay_ms: u64,
+    /// Session connectivity check interval for the session signals (0 = disabled)
+    connectivity_poll_ms: u32,
+    /// Drop samples whose Zenoh timestamp is older than this (0 = disabled)
+    max_lateness_ms: u64,
+    /// Samples held per key to restore the sequence number order (0 = disabled)
+    reorder_window: u32,
+    /// Longest a sample is held by `reorder-window` (0 = no limit)
+    reorder_timeout_ms: u32,
+    /// Port of the Prometheus statistics endpoint (0 = disabled)
+    #[cfg(feature = "metrics-http")]
+    metrics_port: u32,
+    /// Address the Prometheus statistics endpoint listens on
+    #[cfg(feature = "metrics-http")]
+    metrics_address: String,
 }
 
 impl Default for Settings {
@@ -123,17 +393,97 @@ impl Default for Settings {
         Self {
             key_expr: String::new(),
             config_file: None,
+            config_json5: None,
+            scouting: true,
+            endpoints_only: false,
+            tls_root_ca: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+            username: None,
+            password: None,
             priority: 5, // Default to Priority::Data
             congestion_control: "block".into(),
             reliability: "best-effort".into(),
+            express: false,
             receive_timeout_ms: 100, // 100ms default for good responsiveness
             apply_buffer_meta: true, // Default to applying buffer timing metadata
+            mark_discont: true,
             external_session: None,
             session_group: None,
+            session_group_linger_ms: crate::session::DEFAULT_SESSION_GROUP_LINGER_MS,
+            reconnect: false,
+            caps: None,
+            force_caps: false,
+            use_encoding_caps: false,
+            caps_channel: CapsChannel::Attachment,
+            forward_events: false,
+            enable_fku: false,
+            locality: ZenohLocality::Any,
+            zero_copy: true,
+            max_buffer_size: 0,
+            buffer_pool_size: 0,
+            buffer_pool_max: 0,
+            use_downstream_pool: false,
+            no_data_timeout_ms: 0,
+            declaration_delay_ms: 0,
+            connectivity_poll_ms: crate::session::DEFAULT_CONNECTIVITY_POLL_MS,
+            max_lateness_ms: 0,
+            reorder_window: 0,
+            reorder_timeout_ms: DEFAULT_REORDER_TIMEOUT_MS,
+            timestamp_mode: TimestampMode::Arrival,
+            sample_kind_filter: SampleKindFilter::Any,
+            drop_empty: false,
+            #[cfg(feature = "metrics-http")]
+            metrics_port: 0,
+            #[cfg(feature = "metrics-http")]
+            metrics_address: crate::metrics::DEFAULT_METRICS_ADDRESS.into(),
+        }
+    }
+}
+
+/// Settings that shape how a received sample becomes a buffer, snapshotted once
+/// per `create()` call.
+#[derive(Debug, Clone, Copy)]
+struct ReceiveSettings {
+    apply_buffer_meta: bool,
+    /// `force-caps` with `caps` set: caps from metadata are ignored
+    ignore_metadata_caps: bool,
+    /// `use-encoding-caps` without `caps`: the sample encoding can provide the caps
+    use_encoding_caps: bool,
+    zero_copy: bool,
+    use_downstream_pool: bool,
+    mark_discont: bool,
+    timestamp_mode: TimestampMode,
+    /// Also caps the decompressed size of compressed payloads
+    max_buffer_size: u64,
+}
+
+impl ReceiveSettings {
+    fn from_settings(settings: &Settings) -> Self {
+        Self {
+            apply_buffer_meta: settings.apply_buffer_meta,
+            ignore_metadata_caps: settings.force_caps && settings.caps.is_some(),
+            use_encoding_caps: settings.use_encoding_caps && settings.caps.is_none(),
+            zero_copy: settings.zero_copy,
+            use_downstream_pool: settings.use_downstream_pool,
+            mark_discont: settings.mark_discont,
+            timestamp_mode: settings.timestamp_mode,
+            max_buffer_size: settings.max_buffer_size,
         }
     }
 }
 
+/// Moves the PTS of `buffer` to `pts`, shifting the DTS by the same amount so
+/// the PTS/DTS offset of reordered frames is preserved.
+fn retime(buffer: &mut gst::BufferRef, pts: gst::ClockTime) {
+    let dts = match (buffer.pts(), buffer.dts()) {
+        (Some(old_pts), Some(dts)) => pts.checked_add(dts).and_then(|t| t.checked_sub(old_pts)),
+        _ => None,
+    };
+    buffer.set_pts(pts);
+    buffer.set_dts(dts);
+}
+
 /// GStreamer ZenohSrc element implementation.
 ///
 /// This element subscribes to data from a Zenoh network using the
@@ -152,9 +502,35 @@ pub struct ZenohSrc {
     settings: Mutex<Settings>,
     /// Current operational state
     state: Mutex<State>,
+    /// Set to cancel blocking operations (unlock, flush-start). Kept outside `state`
+    /// because `create()` holds that lock while it waits for data.
+    flushing: AtomicBool,
+    /// Segment rate and base time last applied from the publishers' metadata
+    applied_segment: Mutex<Option<(f64, gst::ClockTime)>>,
+    /// Tags last pushed downstream from the publishers' metadata
+    applied_tags: Mutex<Option<gst::TagList>>,
+    /// Stream identity last pushed downstream from the publishers' metadata
+    applied_stream_identity: Mutex<Option<StreamIdentity>>,
+    /// Local group ids of the publishers' stream groups
+    stream_groups: Mutex<StreamGroups>,
+    /// Output caps last announced with the `caps-changed` signal
+    signalled_caps: Mutex<Option<gst::Caps>>,
+    /// Force-key-unit target (`enable-fku`). Kept outside `state` so upstream
+    /// events don't wait for `create()` to release it.
+    fku: Mutex<Option<FkuTarget>>,
+    /// Pool proposed by downstream and the size of its buffers (`use-downstream-pool`)
+    downstream_pool: Mutex<Option<(gst::BufferPool, usize)>>,
+    /// Caps of the last allocation query (`use-downstream-pool`)
+    allocation_caps: Mutex<Option<gst::Caps>>,
 }
 
 impl ZenohSrc {
+    /// Logs the state, settings and statistics at INFO (`dump-state` signal).
+    fn dump_state(&self) {
+        let state = self.state.lock().unwrap().name();
+        crate::utils::dump_state(self.obj().upcast_ref(), *CAT, state);
+    }
+
     /// Sets the external Zenoh session to use for this element.
     ///
     /// This is called from the public API to enable session sharing.
@@ -162,6 +538,607 @@ impl ZenohSrc {
         let mut settings = self.settings.lock().unwrap();
         settings.external_session = Some(session);
     }
+
+    /// Returns the Zenoh session in use, while started.
+    pub(crate) fn session(&self) -> Option<zenoh::Session> {
+        match *self.state.lock().unwrap() {
+            State::Started(ref started) => Some(started.session.as_session().clone()),
+            _ => None,
+        }
+    }
+
+    /// Whether the session of `started` lost its last router or peer since the
+    /// previous check, done at most every [`RECONNECT_CHECK_INTERVAL`].
+    ///
+    /// A subscriber outlives the links of its session, so `recv_timeout` just
+    /// keeps timing out on a lost connection: only the session tells.
+    fn connection_lost(started: &mut Started) -> bool {
+        if started
+            .connectivity_checked
+            .is_some_and(|checked| checked.elapsed() < RECONNECT_CHECK_INTERVAL)
+        {
+            return false;
+        }
+        started.connectivity_checked = Some(Instant::now());
+        let connected = crate::session::is_connected(started.session.as_session());
+        std::mem::replace(&mut started.connected, connected) && !connected
+    }
+
+    /// Re-declares the subscriber on `session` once it is connected to a router
+    /// or peer again, checking with capped exponential backoff. Gives up with an
+    /// error after [`RECONNECT_MAX_ATTEMPTS`], or right away when the session
+    /// is closed, since it can't be re-opened under the elements sharing it.
+    ///
+    /// Runs without the state lock, so that the `reconnecting` signal emitted
+    /// before every attempt can query the element.
+    fn reconnect_subscriber(
+        &self,
+        session: &zenoh::Session,
+    ) -> Result<SampleSubscriber, gst::FlowError> {
+        let (key_expr, locality) = {
+            let settings = self.settings.lock().unwrap();
+            (settings.key_expr.clone(), settings.locality)
+        };
+        if session.is_closed() {
+            gst::element_imp_error!(
+                self,
+                gst::ResourceError::Read,
+                ["Session of '{}' closed, can't reconnect the subscriber", key_expr]
+            );
+            return Err(gst::FlowError::Error);
+        }
+
+        let mut backoff = RECONNECT_INITIAL_BACKOFF;
+        for attempt in 1..=RECONNECT_MAX_ATTEMPTS {
+            gst::info!(
+                CAT,
+                imp = self,
+                "Reconnecting subscriber on '{}' (attempt {}/{})",
+                key_expr,
+                attempt,
+                RECONNECT_MAX_ATTEMPTS
+            );
+            self.obj().emit_by_name::<()>("reconnecting", &[&attempt]);
+
+            if !crate::session::is_connected(session) {
+                gst::debug!(CAT, imp = self, "No router or peer yet, retrying in {:?}", backoff);
+            } else {
+                match session
+                    .declare_subscriber(key_expr.clone())
+                    .allowed_origin(locality.into())
+                    .wait()
+                {
+                    Ok(subscriber) => {
+                        gst::info!(
+                            CAT,
+                            imp = self,
+                            "Subscriber re-declared after {} attempt(s)",
+                            attempt
+                        );
+                        return Ok(subscriber);
+                    }
+                    Err(e) => {
+                        gst::warning!(
+                            CAT,
+                            imp = self,
+                            "Reconnection attempt {} failed: {}, retrying in {:?}",
+                            attempt,
+                            e,
+                            backoff
+                        );
+                    }
+                }
+            }
+
+            // Sleep in short slices so flushing still cancels promptly
+            let deadline = Instant::now() + backoff;
+            while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+                if self.flushing.load(Ordering::SeqCst) {
+                    gst::debug!(CAT, imp = self, "Flushing detected during reconnection");
+                    return Err(gst::FlowError::Flushing);
+                }
+                std::thread::sleep(remaining.min(Duration::from_millis(50)));
+            }
+
+            backoff = (backoff * 2).min(RECONNECT_MAX_BACKOFF);
+        }
+
+        gst::element_imp_error!(
+            self,
+            gst::ResourceError::Read,
+            [
+                "Connection of '{}' not back after {} reconnection attempts",
+                key_expr,
+                RECONNECT_MAX_ATTEMPTS
+            ]
+        );
+        Err(gst::FlowError::Error)
+    }
+
+    /// Publishes a serialized force-key-unit request on the `<key>/fku` key
+    /// with `enable-fku`. Returns whether it was sent to the publishers.
+    fn request_key_unit(&self, payload: &str) -> bool {
+        let fku = self.fku.lock().unwrap();
+        let Some(ref fku) = *fku else {
+            return false;
+        };
+
+        gst::debug!(CAT, imp = self, "Requesting a key unit on '{}'", fku.key);
+        let result = fku
+            .session
+            .put(&fku.key, payload)
+            .congestion_control(zenoh::qos::CongestionControl::Block)
+            .wait();
+        if let Err(e) = result {
+            gst::warning!(CAT, imp = self, "Failed to request a key unit on '{}': {}", fku.key, e);
+            fku.stats.lock().unwrap().errors += 1;
+            return false;
+        }
+        true
+    }
+
+    /// Posts a `zenoh-stream-idle` / `zenoh-stream-resumed` message for the
+    /// subscribed key expression.
+    fn post_idle_message(&self, name: &str, silence: Duration) {
+        let key_expr = self.settings.lock().unwrap().key_expr.clone();
+        gst::info!(
+            CAT,
+            imp = self,
+            "{} on '{}' after {:?} without data",
+            name,
+            key_expr,
+            silence
+        );
+        crate::stats::post_idle_message(self.obj().upcast_ref(), name, &key_expr, silence);
+    }
+
+    /// Creates the buffer of an uncompressed payload. With `use-downstream-pool`,
+    /// it is copied into a buffer of the pool downstream proposed when it fits.
+    fn payload_buffer(
+        &self,
+        payload: &zenoh::bytes::ZBytes,
+        settings: &ReceiveSettings,
+        pool: Option<&mut PayloadPool>,
+    ) -> gst::Buffer {
+        if settings.use_downstream_pool {
+            self.renegotiate_allocation();
+            let downstream_pool = self.downstream_pool.lock().unwrap().clone();
+            if let Some((downstream_pool, size)) = downstream_pool
+                && let Some(buffer) =
+                    crate::buffer::copy_into_pool(&downstream_pool, size, payload)
+            {
+                return buffer;
+            }
+        }
+        crate::buffer::buffer_from_payload(payload, settings.zero_copy, pool)
+    }
+
+    /// Negotiates the allocation again when the caps changed since it last
+    /// was, so downstream proposes a pool for them. The caps are set from the
+    /// first samples, after BaseSrc negotiated without any.
+    fn renegotiate_allocation(&self) {
+        let caps = self.obj().src_pad().current_caps();
+        {
+            let mut allocation_caps = self.allocation_caps.lock().unwrap();
+            if caps.is_none() || *allocation_caps == caps {
+                return;
+            }
+            *allocation_caps = caps;
+        }
+        if !self.obj().negotiate() {
+            gst::debug!(CAT, imp = self, "Allocation negotiation failed, no downstream pool");
+        }
+    }
+
+    /// Parses the attachment metadata of `sample` and applies what concerns the
+    /// stream rather than the buffer: its identity, caps and tags, in that
+    /// order, since a new stream drops the sticky tags.
+    fn parse_sample_metadata(
+        &self,
+        sample: &zenoh::sample::Sample,
+        settings: &ReceiveSettings,
+        stats: &Mutex<Statistics>,
+    ) -> Option<MetadataParser> {
+        let attachment = sample.attachment()?;
+        stats.lock().unwrap().attachment_bytes_received += attachment.len() as u64;
+        let metadata = match MetadataParser::parse(attachment) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                gst::warning!(CAT, imp = self, "Failed to parse metadata: {}", e);
+                stats.lock().unwrap().metadata_errors += 1;
+                return None;
+            }
+        };
+
+        if let Some(identity) = metadata.stream_identity() {
+            self.apply_stream_identity(&identity);
+        }
+
+        // Unless force-caps pins the configured caps
+        if let Some(caps) = metadata.caps() {
+            gst::debug!(CAT, imp = self, "Received caps from metadata: {}", caps);
+
+            if settings.ignore_metadata_caps {
+                gst::trace!(CAT, imp = self, "Ignoring metadata caps (force-caps)");
+            } else if let Err(e) = self.obj().set_caps(caps) {
+                gst::warning!(CAT, imp = self, "Failed to set caps: {}", e);
+            }
+        }
+        if let Some(e) = metadata.caps_error() {
+            // The pad keeps its current caps
+            gst::warning!(CAT, imp = self, "Ignoring malformed caps metadata: {}", e);
+            stats.lock().unwrap().metadata_errors += 1;
+        }
+
+        if let Some(tags) = metadata.tags() {
+            self.apply_tags(tags);
+        }
+
+        if !metadata.user_metadata().is_empty() {
+            gst::trace!(
+                CAT,
+                imp = self,
+                "Received user metadata: {:?}",
+                metadata.user_metadata()
+            );
+        }
+
+        Some(metadata)
+    }
+
+    /// Turns a received sample into an output buffer: parses the attachment
+    /// metadata (caps, buffer timing, compression), decompresses the payload and
+    /// applies the sender's timing or, failing that, the Zenoh timestamp.
+    ///
+    /// For a batch published with `batch-lists` or `aggregate-window-ms`, also
+    /// returns the sizes of the buffers it holds and, with `apply-buffer-meta`,
+    /// their timing.
+    fn sample_to_buffer(
+        &self,
+        sample: &zenoh::sample::Sample,
+        settings: &ReceiveSettings,
+        stats: &Mutex<Statistics>,
+        pool: Option<&mut PayloadPool>,
+    ) -> Result<(gst::Buffer, Option<Batch>), gst::FlowError> {
+        let parsed_metadata = self.parse_sample_metadata(sample, settings, stats);
+        let seqnum = parsed_metadata.as_ref().and_then(|m| m.seqnum());
+        let after_gap = seqnum.is_some_and(|seqnum| {
+            stats.lock().unwrap().sequence.record(sample.key_expr().as_str(), seqnum)
+        });
+
+        // Refuse payloads compressed with an algorithm this build can't decode,
+        // rather than pushing them downstream as if they were raw
+        if let Some(algorithm) = parsed_metadata
+            .as_ref()
+            .and_then(crate::metadata::unsupported_compression)
+        {
+            stats.lock().unwrap().errors += 1;
+            let err = ZenohError::UnsupportedCompression {
+                algorithm: algorithm.to_string(),
+            };
+            self.post_error_message(err.to_error_message());
+            return Err(err.to_flow_error());
+        }
+
+        // Without caps metadata, fall back to caps derived from the sample encoding
+        if settings.use_encoding_caps
+            && parsed_metadata.as_ref().and_then(|m| m.caps()).is_none()
+            && let Some(caps) = crate::encoding::caps_for_encoding(&sample.encoding().to_string())
+            && self.obj().src_pad().current_caps().as_ref() != Some(&caps)
+        {
+            gst::debug!(
+                CAT,
+                imp = self,
+                "Using caps {} for encoding {}",
+                caps,
+                sample.encoding()
+            );
+            if let Err(e) = self.obj().set_caps(&caps) {
+                gst::warning!(CAT, imp = self, "Failed to set encoding caps: {}", e);
+            }
+        }
+
+        let payload = sample.payload();
+
+        // Decompress if needed. Uncompressed payloads are wrapped without a copy
+        // when contiguous (see `crate::buffer`).
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        let mut buffer = if let Some(comp_type) = parsed_metadata
+            .as_ref()
+            .and_then(|m| m.compression())
+            .and_then(crate::compression::CompressionType::from_metadata_value)
+        {
+            let compressed_data = payload.to_bytes();
+            let max_size = settings.max_buffer_size;
+            match crate::compression::decompress_with_limit(&compressed_data, comp_type, max_size) {
+                Ok(decompressed) => {
+                    gst::trace!(
+                        CAT,
+                        imp = self,
+                        "Decompressed {} bytes to {} bytes using {:?}",
+                        compressed_data.len(),
+                        decompressed.len(),
+                        comp_type
+                    );
+                    gst::Buffer::from_mut_slice(decompressed)
+                }
+                Err(e) => {
+                    stats.lock().unwrap().errors += 1;
+                    gst::element_imp_error!(
+                        self,
+                        gst::StreamError::Decode,
+                        ["Decompression failed: {}", e]
+                    );
+                    return Err(gst::FlowError::Error);
+                }
+            }
+        } else {
+            self.payload_buffer(payload, settings, pool)
+        };
+
+        #[cfg(not(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        )))]
+        let mut buffer = self.payload_buffer(payload, settings, pool);
+
+        {
+            let buffer_mut = buffer.get_mut().ok_or_else(|| {
+                gst::element_imp_error!(
+                    self,
+                    gst::ResourceError::Failed,
+                    ["Failed to get mutable buffer reference"]
+                );
+                gst::FlowError::Error
+            })?;
+
+            // Apply buffer timing metadata if enabled and available
+            // This preserves PTS, DTS, duration, offset, and flags from the sender
+            if settings.apply_buffer_meta && let Some(ref metadata) = parsed_metadata {
+                // Check if we have buffer timing metadata
+                let has_timing = metadata.pts().is_some()
+                    || metadata.dts().is_some()
+                    || metadata.duration().is_some()
+                    || metadata.offset().is_some()
+                    || metadata.offset_end().is_some()
+                    || metadata.flags().is_some();
+
+                if has_timing {
+                    metadata.apply_to_buffer(buffer_mut);
+                    gst::trace!(
+                        CAT,
+                        imp = self,
+                        "Applied buffer timing metadata: PTS={:?}, DTS={:?}, duration={:?}, flags={:?}",
+                        metadata.pts(),
+                        metadata.dts(),
+                        metadata.duration(),
+                        metadata.flags()
+                    );
+                }
+            }
+
+            self.apply_timestamp_mode(buffer_mut, sample, settings.timestamp_mode);
+
+            // Whatever the sender's flags, samples were lost before this one
+            if settings.mark_discont && after_gap {
+                gst::debug!(
+                    CAT,
+                    imp = self,
+                    "Sequence gap on '{}', marking DISCONT",
+                    sample.key_expr()
+                );
+                buffer_mut.set_flags(gst::BufferFlags::DISCONT);
+            }
+
+            // Keep application-defined fields for downstream consumers
+            if let Some(ref metadata) = parsed_metadata
+                && let Err(e) = ZenohUserMeta::add(buffer_mut, metadata)
+            {
+                gst::warning!(CAT, imp = self, "Failed to attach user metadata meta: {}", e);
+            }
+        }
+
+        if settings.apply_buffer_meta
+            && let Some(segment) = parsed_metadata.as_ref().and_then(|m| m.segment())
+        {
+            self.apply_segment(&segment);
+        }
+
+        let batch = parsed_metadata.as_ref().and_then(|m| {
+            let timing = m.batch_timing().filter(|_| settings.apply_buffer_meta);
+            Some((m.batch()?.to_vec(), timing.map(<[BatchPartTiming]>::to_vec)))
+        });
+        Ok((buffer, batch))
+    }
+
+    /// Outputs a new segment with the rate and base time sent by the publisher
+    /// when they differ from the ones last applied, so a replayed stream keeps
+    /// the segment it was recorded with.
+    fn apply_segment(&self, received: &gst::FormattedSegment<gst::ClockTime>) {
+        let rate_base = (received.rate(), received.base().unwrap_or_default());
+        {
+            let mut applied = self.applied_segment.lock().unwrap();
+            if *applied == Some(rate_base) {
+                return;
+            }
+            *applied = Some(rate_base);
+        }
+
+        let Ok(mut segment) = self.obj().segment().downcast::<gst::ClockTime>() else {
+            return;
+        };
+        segment.set_rate(rate_base.0);
+        segment.set_base(rate_base.1);
+
+        gst::debug!(
+            CAT,
+            imp = self,
+            "Applying segment from metadata: rate={}, base={}",
+            rate_base.0,
+            rate_base.1
+        );
+        if let Err(e) = self.obj().new_segment(segment.upcast_ref()) {
+            gst::warning!(CAT, imp = self, "Failed to apply segment: {}", e);
+        }
+    }
+
+    /// Sends the tags received from the publisher downstream, ahead of the
+    /// buffer being created, unless they are the ones last sent.
+    fn apply_tags(&self, tags: &gst::TagList) {
+        {
+            let mut applied = self.applied_tags.lock().unwrap();
+            if applied.as_ref() == Some(tags) {
+                return;
+            }
+            *applied = Some(tags.clone());
+        }
+
+        gst::debug!(CAT, imp = self, "Applying tags from metadata: {:?}", tags);
+        // BaseSrc queues serialized events and pushes them before the next buffer
+        if !self.obj().send_event(gst::event::Tag::new(tags.clone())) {
+            gst::warning!(CAT, imp = self, "Failed to send tags {:?}", tags);
+        }
+    }
+
+    /// Pushes a stream-start with the identity of the publisher's stream,
+    /// unless it is the one last pushed. Called from `create()`, so it goes
+    /// out ahead of the buffer being created.
+    fn apply_stream_identity(&self, identity: &StreamIdentity) {
+        let event = {
+            let mut applied = self.applied_stream_identity.lock().unwrap();
+            if applied.as_ref() == Some(identity) {
+                return;
+            }
+            *applied = Some(identity.clone());
+            self.stream_groups.lock().unwrap().stream_start(identity)
+        };
+
+        gst::debug!(CAT, imp = self, "Applying stream identity from metadata: {:?}", identity);
+        // The pad dropped its tags with the new stream, the publisher's are pushed again
+        *self.applied_tags.lock().unwrap() = None;
+        if !self.obj().src_pad().push_event(event) {
+            gst::warning!(CAT, imp = self, "Failed to push stream-start {:?}", identity);
+        }
+    }
+
+    /// Waits `delay` for the subscriber declaration to propagate
+    /// (`declaration-delay-ms`), returning early if the element is stopped.
+    fn settle_declaration(&self, delay: Duration) {
+        gst::debug!(CAT, imp = self, "Waiting {:?} for the subscriber declaration", delay);
+        let deadline = Instant::now() + delay;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() || !matches!(*self.state.lock().unwrap(), State::Starting) {
+                return;
+            }
+            std::thread::sleep(remaining.min(MAX_POLL_INTERVAL));
+        }
+    }
+
+    /// Returns the current output caps if they changed since they were last
+    /// announced with `caps-changed`.
+    fn caps_to_signal(&self) -> Option<gst::Caps> {
+        let caps = self.obj().src_pad().current_caps()?;
+        let mut signalled = self.signalled_caps.lock().unwrap();
+        if signalled.as_ref() == Some(&caps) {
+            return None;
+        }
+        *signalled = Some(caps.clone());
+        Some(caps)
+    }
+
+    /// Returns the live flag of the base class, which `is-live` sets. The
+    /// wrapper's `is_live()` reads the property and would recurse here.
+    fn is_live(&self) -> bool {
+        self.obj().upcast_ref::<gst_base::BaseSrc>().is_live()
+    }
+
+    /// Derives the buffer PTS according to `timestamp-mode`, after the sender's
+    /// buffer metadata has been applied, and stamps buffers left untimed when live.
+    fn apply_timestamp_mode(
+        &self,
+        buffer: &mut gst::BufferRef,
+        sample: &zenoh::sample::Sample,
+        mode: TimestampMode,
+    ) {
+        match mode {
+            TimestampMode::None => {}
+            TimestampMode::Zenoh => {
+                // If no buffer timing metadata was applied, try Zenoh timestamp as fallback
+                // This is useful when receiving from a sender that doesn't use buffer metadata
+                if buffer.pts().is_none()
+                    && let Some(timestamp) = sample.timestamp()
+                {
+                    // Zenoh timestamps are in NTP64 format (64-bit timestamp)
+                    // Convert to GStreamer ClockTime (nanoseconds since epoch)
+                    let ntp_time = timestamp.get_time();
+
+                    // NTP64 timestamp is split into:
+                    // - upper 32 bits: seconds since NTP epoch (Jan 1, 1900)
+                    // - lower 32 bits: fractional seconds
+                    // We need to convert this to nanoseconds since Unix epoch (Jan 1, 1970)
+
+                    // NTP epoch is 2208988800 seconds before Unix epoch
+                    const NTP_UNIX_OFFSET: u64 = 2208988800;
+
+                    let ntp_secs = ntp_time.as_secs() as u64;
+                    let ntp_nanos = ntp_time.subsec_nanos() as u64;
+
+                    // Convert to Unix epoch
+                    if ntp_secs >= NTP_UNIX_OFFSET {
+                        let unix_secs = ntp_secs - NTP_UNIX_OFFSET;
+                        let total_nanos = unix_secs * 1_000_000_000 + ntp_nanos;
+
+                        let pts = gst::ClockTime::from_nseconds(total_nanos);
+                        buffer.set_pts(pts);
+
+                        gst::trace!(
+                            CAT,
+                            imp = self,
+                            "Applied Zenoh timestamp to buffer: PTS = {}",
+                            pts
+                        );
+                    }
+                }
+            }
+            TimestampMode::Arrival => {
+                if let Some(now) = self.obj().current_running_time() {
+                    retime(buffer, now);
+                }
+            }
+            TimestampMode::PipelineClock => {
+                // Backdate the arrival by the sample age so the PTS reflects
+                // when the publisher produced the sample; without a Zenoh
+                // timestamp this is the same as `arrival`
+                if let Some(now) = self.obj().current_running_time() {
+                    let age = sample_age(sample)
+                        .map(|age| gst::ClockTime::from_nseconds(age.as_nanos() as u64))
+                        .unwrap_or(gst::ClockTime::ZERO);
+                    retime(buffer, now.saturating_sub(age));
+                }
+            }
+        }
+
+        // Live, buffers left untimed are stamped with their arrival, as
+        // do-timestamp would, without touching those that have a PTS
+        if buffer.pts().is_none()
+            && buffer.dts().is_none()
+            && self.is_live()
+            && let Some(now) = self.obj().current_running_time()
+        {
+            buffer.set_pts(now);
+            buffer.set_dts(now);
+        }
+    }
 }
 
 impl GstObjectImpl for ZenohSrc {}
@@ -204,6 +1181,36 @@ impl ElementImpl for ZenohSrc {
 }
 
 impl ObjectImpl for ZenohSrc {
+    fn signals() -> &'static [glib::subclass::Signal] {
+        static SIGNALS: LazyLock<Vec<glib::subclass::Signal>> = LazyLock::new(|| {
+            vec![
+                glib::subclass::Signal::builder("reconnecting")
+                    .param_types([u32::static_type()])
+                    .build(),
+                glib::subclass::Signal::builder("first-buffer").build(),
+                glib::subclass::Signal::builder("caps-changed")
+                    .param_types([gst::Caps::static_type()])
+                    .build(),
+                glib::subclass::Signal::builder("eos")
+                    .param_types([String::static_type()])
+                    .build(),
+                glib::subclass::Signal::builder("session-disconnected").build(),
+                glib::subclass::Signal::builder("session-reconnected").build(),
+                glib::subclass::Signal::builder("dump-state")
+                    .action()
+                    .class_handler(|args| {
+                        let element = args[0]
+                            .get::<super::ZenohSrc>()
+                            .expect("signal arg type checked upstream");
+                        element.imp().dump_state();
+                        None
+                    })
+                    .build(),
+            ]
+        });
+        SIGNALS.as_ref()
+    }
+
     fn properties() -> &'static [glib::ParamSpec] {
         static PROPERTIES: LazyLock<Vec<glib::ParamSpec>> = LazyLock::new(|| {
             vec![
@@ -218,11 +1225,46 @@ impl ObjectImpl for ZenohSrc {
                     .nick("Zenoh Configuration")
                     .blurb("Path to Zenoh configuration file for custom network settings (JSON5 format)")
                     .build(),
+                glib::ParamSpecString::builder("config-json5")
+                    .nick("Zenoh Configuration (JSON5)")
+                    .blurb("Inline Zenoh configuration in JSON5 format (e.g. '{mode: \"client\", connect: {endpoints: [\"tcp/10.0.0.1:7447\"]}}'), taking precedence over config; scouting, TLS and authentication properties are applied on top")
+                    .build(),
+                glib::ParamSpecBoolean::builder("scouting")
+                    .nick("Multicast Scouting")
+                    .blurb("Discover peers via multicast scouting; disable on networks where multicast doesn't work and rely on configured endpoints")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecBoolean::builder("endpoints-only")
+                    .nick("Endpoints Only")
+                    .blurb("Connect only to the configured endpoints: client mode with multicast scouting disabled; starting fails when the configuration has no connect endpoint")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecString::builder("tls-root-ca")
+                    .nick("TLS Root CA")
+                    .blurb("Path to the CA certificate (PEM) used to verify TLS routers and peers; overrides transport/link/tls/root_ca_certificate of the config file")
+                    .build(),
+                glib::ParamSpecString::builder("tls-client-cert")
+                    .nick("TLS Client Certificate")
+                    .blurb("Path to the client certificate (PEM) presented on TLS links for mutual TLS; requires tls-client-key")
+                    .build(),
+                glib::ParamSpecString::builder("tls-client-key")
+                    .nick("TLS Client Key")
+                    .blurb("Path to the private key (PEM) of tls-client-cert")
+                    .build(),
+                glib::ParamSpecString::builder("username")
+                    .nick("Username")
+                    .blurb("User name for Zenoh usrpwd authentication; overrides transport/auth/usrpwd/user of the config file, requires password")
+                    .build(),
+                glib::ParamSpecString::builder("password")
+                    .nick("Password")
+                    .blurb("Password for Zenoh usrpwd authentication (write-only)")
+                    .write_only()
+                    .build(),
 
                 // Priority property
                 glib::ParamSpecUInt::builder("priority")
                     .nick("Subscriber Priority")
-                    .blurb("Message priority level: 1=RealTime(highest), 2=InteractiveHigh, 3=InteractiveLow, 4=DataHigh, 5=Data(default), 6=DataLow, 7=Background(lowest)")
+                    .blurb("Expected priority level (informational): 1=RealTime(highest), 2=InteractiveHigh, 3=InteractiveLow, 4=DataHigh, 5=Data(default), 6=DataLow, 7=Background(lowest). Zenoh subscribers can't declare one, samples carry the priority of their publisher (see rx-priority).")
                     .default_value(5)
                     .minimum(1)
                     .maximum(7)
@@ -241,27 +1283,201 @@ impl ObjectImpl for ZenohSrc {
                     .blurb("Expected reliability mode (informational): 'best-effort' or 'reliable'. Actual reliability is determined by publisher.")
                     .default_value(Some("best-effort"))
                     .build(),
+                glib::ParamSpecBoolean::builder("express")
+                    .nick("Express Mode")
+                    .blurb("Express mode, as on zenohsink (informational): Zenoh 1.x subscribers can't request express delivery, which is set by the publishers")
+                    .default_value(false)
+                    .build(),
 
                 // Receive timeout property
                 glib::ParamSpecUInt64::builder("receive-timeout-ms")
                     .nick("Receive Timeout")
-                    .blurb("Timeout in milliseconds for polling Zenoh subscriber. Lower values increase responsiveness but use more CPU. Higher values reduce CPU but slow down state changes.")
+                    .blurb("Timeout in milliseconds for polling Zenoh subscriber. Lower values use more CPU. Polls are capped at 100ms internally so state changes stay responsive whatever the value.")
                     .default_value(100)
                     .minimum(10)
                     .maximum(5000)
                     .build(),
 
-                // Buffer metadata property
-                glib::ParamSpecBoolean::builder("apply-buffer-meta")
-                    .nick("Apply Buffer Metadata")
-                    .blurb("Apply buffer timing metadata (PTS, DTS, duration, offset, flags) from received messages for proper A/V sync")
-                    .default_value(true)
+                // Buffer metadata property
+                glib::ParamSpecBoolean::builder("apply-buffer-meta")
+                    .nick("Apply Buffer Metadata")
+                    .blurb("Apply buffer timing metadata (PTS, DTS, duration, offset, flags) and the segment rate/base from received messages for proper A/V sync")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecBoolean::builder("mark-discont")
+                    .nick("Mark Discontinuities")
+                    .blurb("Set the DISCONT flag on the first buffer, after a reconnection and after a gap in the sequence numbers of zenohsink's sequence-numbers, whatever the sender's flags, so parsers and decoders resynchronize")
+                    .default_value(true)
+                    .build(),
+
+                // Zero-copy reception property
+                glib::ParamSpecBoolean::builder("zero-copy")
+                    .nick("Zero Copy")
+                    .blurb("Wrap received payloads in buffers without copying when they are contiguous (fragmented or compressed payloads are always copied)")
+                    .default_value(true)
+                    .build(),
+                glib::ParamSpecUInt64::builder("max-buffer-size")
+                    .nick("Max Buffer Size")
+                    .blurb("Drop samples whose payload is larger than this many bytes instead of allocating a buffer for them; compressed samples that decompress to more fail to decode (0 = unlimited)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt::builder("buffer-pool-size")
+                    .nick("Buffer Pool Size")
+                    .blurb("Buffers preallocated by a pool for the payloads that are copied (with zero-copy disabled, fragmented payloads), so they don't need an allocation per sample. The pool buffers fit the largest payload seen (0 = no pool)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt::builder("buffer-pool-max")
+                    .nick("Buffer Pool Max")
+                    .blurb("Pool buffers in use at once, beyond which payloads get a newly allocated buffer (0 = unlimited). Must be 0 or at least buffer-pool-size")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecBoolean::builder("use-downstream-pool")
+                    .nick("Use Downstream Pool")
+                    .blurb("Copy payloads into the buffers of the pool downstream proposes in the allocation query (e.g. DMA buffers of a hardware decoder) instead of wrapping or allocating them. Payloads that don't fit, compressed ones and those arriving while no pool buffer is free get buffers of their own")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecUInt64::builder("max-lateness-ms")
+                    .nick("Max Lateness")
+                    .blurb("Drop samples whose Zenoh timestamp is older than this many milliseconds, so a live consumer that fell behind skips stale data (0 = disabled). Requires timestamped samples and synchronized clocks; samples without a timestamp are never dropped")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt::builder("reorder-window")
+                    .nick("Reorder Window")
+                    .blurb("Hold up to this many samples per key to output them in the order of their sequence numbers (zenohsink sequence-numbers); a missing sample is skipped once the window is full, and dropped as late if it arrives afterwards (0 = disabled). Samples without a sequence number pass through")
+                    .maximum(MAX_REORDER_WINDOW)
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt::builder("reorder-timeout-ms")
+                    .nick("Reorder Timeout")
+                    .blurb("Longest a sample is held by reorder-window waiting for the ones before it, in milliseconds; the missing ones are skipped then (0 = no limit)")
+                    .default_value(DEFAULT_REORDER_TIMEOUT_MS)
+                    .build(),
+                glib::ParamSpecUInt64::builder("no-data-timeout-ms")
+                    .nick("No Data Timeout")
+                    .blurb("Post a zenoh-stream-idle element message when no sample arrives for this many milliseconds, and zenoh-stream-resumed when data returns (0 = disabled)")
+                    .default_value(0)
+                    .build(),
+                glib::ParamSpecUInt64::builder("declaration-delay-ms")
+                    .nick("Declaration Delay")
+                    .blurb("Wait this many milliseconds after declaring the subscriber before completing the state change, so the declaration reaches the routers and peers and samples published once the element is PAUSED/PLAYING aren't lost (0 = disabled)")
+                    .default_value(0)
+                    .maximum(10_000)
+                    .build(),
+                glib::ParamSpecUInt::builder("connectivity-poll-ms")
+                    .nick("Connectivity Poll Interval")
+                    .blurb("How often the session is checked for router and peer connections, emitting session-disconnected when the last one is lost and session-reconnected when one is back (0 = disabled)")
+                    .default_value(crate::session::DEFAULT_CONNECTIVITY_POLL_MS)
+                    .maximum(60_000)
+                    .build(),
+
+                // Session sharing property
+                glib::ParamSpecString::builder("session-group")
+                    .nick("Session Group")
+                    .blurb("Name of the session group for sharing Zenoh sessions across elements. Elements with the same group name share a single session.")
+                    .build(),
+                glib::ParamSpecUInt::builder("session-group-linger-ms")
+                    .nick("Session Group Linger")
+                    .blurb("Keep the session-group session open this many milliseconds after this element was the last one to release it, so elements briefly overlapping reuse it instead of reopening one (0 = close right away)")
+                    .maximum(crate::session::MAX_SESSION_GROUP_LINGER_MS)
+                    .default_value(crate::session::DEFAULT_SESSION_GROUP_LINGER_MS)
+                    .build(),
+
+                // Reconnection property
+                glib::ParamSpecBoolean::builder("reconnect")
+                    .nick("Reconnect")
+                    .blurb("When the session loses its last router or peer, re-declare the subscriber once the connection is back, checked with exponential backoff; errors out after 10 attempts or when the session was closed")
+                    .default_value(false)
+                    .build(),
+
+                // Fixed caps properties
+                glib::ParamSpecBoxed::builder::<gst::Caps>("caps")
+                    .nick("Caps")
+                    .blurb("Fixed output caps, used when the publisher doesn't send caps metadata (e.g. non-GStreamer publishers). Caps received in metadata take precedence unless force-caps is set.")
+                    .build(),
+                glib::ParamSpecBoolean::builder("force-caps")
+                    .nick("Force Caps")
+                    .blurb("Always use the caps property, ignoring caps received in metadata")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecBoolean::builder("use-encoding-caps")
+                    .nick("Use Encoding Caps")
+                    .blurb("Derive output caps from the Zenoh sample encoding (e.g. video/h264) when no caps metadata is received and the caps property is unset; unknown encodings leave the caps as ANY")
+                    .default_value(false)
+                    .build(),
+
+                // Locality property
+                glib::ParamSpecEnum::builder_with_default("locality", ZenohLocality::Any)
+                    .nick("Locality")
+                    .blurb("Publishers to receive from: any (default), session-local (same Zenoh session only) or remote (other sessions only, prevents local echo)")
+                    .build(),
+
+                // Timestamping property
+                glib::ParamSpecEnum::builder_with_default("timestamp-mode", TimestampMode::Arrival)
+                    .nick("Timestamp Mode")
+                    .blurb("How buffer PTS is derived: none (sender's PTS only), zenoh (Zenoh timestamp as absolute time when no PTS was sent), arrival (pipeline running time at receive, default) or pipeline-clock (arrival minus the age of the Zenoh timestamp; needs synchronized clocks)")
+                    .build(),
+                glib::ParamSpecBoolean::builder("is-live")
+                    .nick("Is Live")
+                    .blurb("Act as a live source. Live, buffers left without a PTS by timestamp-mode are stamped with their arrival running time; not live, they are pushed untimed")
+                    .default_value(true)
+                    .build(),
+
+                glib::ParamSpecEnum::builder_with_default("caps-channel", CapsChannel::Attachment)
+                    .nick("Caps Channel")
+                    .blurb("Where publishers send caps, as set on zenohsink: attachment (in the sample metadata, default) or sidecar-key (subscribes to <key-expr>/caps and applies the caps received there to the following samples)")
+                    .build(),
+                glib::ParamSpecBoolean::builder("forward-events")
+                    .nick("Forward Events")
+                    .blurb("Subscribe to <key-expr>/events and push the EOS and custom downstream events zenohsink sends there with forward-events; EOS follows the samples already received, custom events go out ahead of the next buffer")
+                    .default_value(false)
+                    .build(),
+                glib::ParamSpecBoolean::builder("enable-fku")
+                    .nick("Enable Force Key Unit")
+                    .blurb("Publish the upstream force-key-unit events of downstream elements (e.g. a decoder that lost a frame) on <key-expr>/fku, for zenohsink with enable-fku to request a key unit from its encoder")
+                    .default_value(false)
+                    .build(),
+
+                // Sample kind property
+                glib::ParamSpecEnum::builder_with_default("sample-kind-filter", SampleKindFilter::Any)
+                    .nick("Sample Kind Filter")
+                    .blurb("Kinds of Zenoh samples turned into buffers: any (default), put or delete. Other samples are dropped and counted in dropped-filtered. Can be changed while playing")
+                    .build(),
+                glib::ParamSpecBoolean::builder("drop-empty")
+                    .nick("Drop Empty")
+                    .blurb("Drop zero-length PUT samples instead of pushing empty buffers, counted in dropped-filtered. DELETE samples and gaps from zenohsink are still delivered. Can be changed while playing")
+                    .default_value(false)
                     .build(),
 
-                // Session sharing property
-                glib::ParamSpecString::builder("session-group")
-                    .nick("Session Group")
-                    .blurb("Name of the session group for sharing Zenoh sessions across elements. Elements with the same group name share a single session.")
+                #[cfg(feature = "metrics-http")]
+                glib::ParamSpecUInt::builder("metrics-port")
+                    .nick("Metrics Port")
+                    .blurb("Serve the statistics in Prometheus text format on http://<metrics-address>:<port>/metrics while the element is started (0 = disabled). Applied on the next start")
+                    .maximum(u16::MAX as u32)
+                    .default_value(0)
+                    .build(),
+                #[cfg(feature = "metrics-http")]
+                glib::ParamSpecString::builder("metrics-address")
+                    .nick("Metrics Address")
+                    .blurb("Address the metrics-port endpoint listens on. The default only accepts connections from the local host; use 0.0.0.0 to serve every interface. Applied on the next start")
+                    .default_value(Some(crate::metrics::DEFAULT_METRICS_ADDRESS))
+                    .build(),
+
+                // Session introspection (read-only)
+                glib::ParamSpecString::builder("session-zid")
+                    .nick("Session ZID")
+                    .blurb("Zenoh ID of the session used by this element (empty when stopped)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecBoolean::builder("session-shared")
+                    .nick("Session Shared")
+                    .blurb("Whether the session was given with the session() builder method or comes from session-group, rather than opened by this element (false when stopped)")
+                    .default_value(false)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecBoxed::builder::<Vec<String>>("peers")
+                    .nick("Connected Peers")
+                    .blurb("Routers and peers the session is connected to, as 'router:<zid>' or 'peer:<zid>' (empty when stopped)")
+                    .read_only()
                     .build(),
 
                 // Statistics properties (read-only)
@@ -280,6 +1496,75 @@ impl ObjectImpl for ZenohSrc {
                     .blurb("Total number of errors encountered")
                     .read_only()
                     .build(),
+                glib::ParamSpecUInt64::builder("dropped-late")
+                    .nick("Dropped Late")
+                    .blurb("Samples dropped for being older than max-lateness-ms, or for arriving after reorder-window skipped them")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("dropped-filtered")
+                    .nick("Dropped Filtered")
+                    .blurb("Samples dropped for not matching sample-kind-filter, or by drop-empty")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("attachment-bytes-received")
+                    .nick("Attachment Bytes Received")
+                    .blurb("Serialized metadata attachment bytes received (caps, buffer timing, ...), not included in bytes-received")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("metadata-errors")
+                    .nick("Metadata Errors")
+                    .blurb("Samples whose metadata attachment was malformed (e.g. unparsable caps); their payload is still delivered")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("samples-lost")
+                    .nick("Samples Lost")
+                    .blurb("Samples missing from the publishers' sequence numbers (requires sequence-numbers on zenohsink)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("samples-reordered")
+                    .nick("Samples Reordered")
+                    .blurb("Samples received after a sample with a higher sequence number (requires sequence-numbers on zenohsink)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt64::builder("bitrate")
+                    .nick("Bitrate")
+                    .blurb("Incoming bitrate in bits per second over the last second (0 when idle)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecDouble::builder("message-rate")
+                    .nick("Message Rate")
+                    .blurb("Incoming messages per second over the last second (0 when idle)")
+                    .minimum(0.0)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecDouble::builder("latency-ms")
+                    .nick("Latency")
+                    .blurb("Moving average of end-to-end latency in milliseconds, computed from Zenoh sample timestamps. Requires synchronized clocks between sender and receiver.")
+                    .minimum(0.0)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecDouble::builder("max-latency-ms")
+                    .nick("Maximum Latency")
+                    .blurb("Highest end-to-end latency observed in milliseconds. Requires synchronized clocks between sender and receiver.")
+                    .minimum(0.0)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecUInt::builder("rx-priority")
+                    .nick("Received Priority")
+                    .blurb("Priority of the last received sample (1=RealTime to 7=Background, 0 before any sample), to check the QoS the publisher set")
+                    .maximum(7)
+                    .read_only()
+                    .build(),
+                glib::ParamSpecBoolean::builder("rx-express")
+                    .nick("Received Express")
+                    .blurb("Whether the last received sample was published with express (false before any sample)")
+                    .read_only()
+                    .build(),
+                glib::ParamSpecString::builder("stats-json")
+                    .nick("Statistics JSON")
+                    .blurb("All statistics above as one compact JSON object keyed by property name, read atomically (for metrics exporters)")
+                    .read_only()
+                    .build(),
             ]
         });
 
@@ -294,10 +1579,30 @@ impl ObjectImpl for ZenohSrc {
                 pspec.name(),
                 "key-expr"
                     | "config"
+                    | "config-json5"
+                    | "scouting"
+                    | "endpoints-only"
+                    | "tls-root-ca"
+                    | "tls-client-cert"
+                    | "tls-client-key"
+                    | "username"
+                    | "password"
                     | "reliability"
+                    | "express"
                     | "congestion-control"
                     | "priority"
                     | "session-group"
+                    | "session-group-linger-ms"
+                    | "locality"
+                    | "is-live"
+                    | "caps-channel"
+                    | "forward-events"
+                    | "enable-fku"
+                    | "reorder-window"
+                    | "reorder-timeout-ms"
+                    | "buffer-pool-size"
+                    | "buffer-pool-max"
+                    | "connectivity-poll-ms"
             )
         {
             gst::warning!(
@@ -320,6 +1625,42 @@ impl ObjectImpl for ZenohSrc {
                     .get::<Option<String>>()
                     .expect("type checked upstream");
             }
+            "config-json5" => {
+                settings.config_json5 = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "scouting" => {
+                settings.scouting = value.get::<bool>().expect("type checked upstream");
+            }
+            "endpoints-only" => {
+                settings.endpoints_only = value.get::<bool>().expect("type checked upstream");
+            }
+            "tls-root-ca" => {
+                settings.tls_root_ca = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "tls-client-cert" => {
+                settings.tls_client_cert = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "tls-client-key" => {
+                settings.tls_client_key = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "username" => {
+                settings.username = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
+            "password" => {
+                settings.password = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream");
+            }
             "priority" => {
                 let priority_val = value.get::<u32>().expect("type checked upstream") as u8;
                 // Validate priority range
@@ -358,6 +1699,9 @@ impl ObjectImpl for ZenohSrc {
                     ),
                 }
             }
+            "express" => {
+                settings.express = value.get::<bool>().expect("type checked upstream");
+            }
             "receive-timeout-ms" => {
                 let timeout = value.get::<u64>().expect("type checked upstream");
                 // Clamp to valid range (10-5000ms)
@@ -374,11 +1718,108 @@ impl ObjectImpl for ZenohSrc {
             "apply-buffer-meta" => {
                 settings.apply_buffer_meta = value.get::<bool>().expect("type checked upstream");
             }
+            "mark-discont" => {
+                settings.mark_discont = value.get::<bool>().expect("type checked upstream");
+            }
+            "zero-copy" => {
+                settings.zero_copy = value.get::<bool>().expect("type checked upstream");
+            }
+            "max-buffer-size" => {
+                settings.max_buffer_size = value.get::<u64>().expect("type checked upstream");
+            }
+            "buffer-pool-size" => {
+                settings.buffer_pool_size = value.get::<u32>().expect("type checked upstream");
+            }
+            "buffer-pool-max" => {
+                settings.buffer_pool_max = value.get::<u32>().expect("type checked upstream");
+            }
+            "use-downstream-pool" => {
+                settings.use_downstream_pool = value.get::<bool>().expect("type checked upstream");
+            }
+            "no-data-timeout-ms" => {
+                settings.no_data_timeout_ms = value.get::<u64>().expect("type checked upstream");
+            }
+            "declaration-delay-ms" => {
+                settings.declaration_delay_ms = value.get::<u64>().expect("type checked upstream");
+            }
+            "connectivity-poll-ms" => {
+                settings.connectivity_poll_ms = value.get::<u32>().expect("type checked upstream");
+            }
+            "max-lateness-ms" => {
+                settings.max_lateness_ms = value.get::<u64>().expect("type checked upstream");
+            }
+            "reorder-window" => {
+                settings.reorder_window = value.get::<u32>().expect("type checked upstream");
+            }
+            "reorder-timeout-ms" => {
+                settings.reorder_timeout_ms = value.get::<u32>().expect("type checked upstream");
+            }
             "session-group" => {
                 settings.session_group = value
                     .get::<Option<String>>()
                     .expect("type checked upstream");
             }
+            "session-group-linger-ms" => {
+                settings.session_group_linger_ms =
+                    value.get::<u32>().expect("type checked upstream");
+            }
+            "reconnect" => {
+                settings.reconnect = value.get::<bool>().expect("type checked upstream");
+            }
+            "caps" => {
+                settings.caps = value
+                    .get::<Option<gst::Caps>>()
+                    .expect("type checked upstream");
+            }
+            "force-caps" => {
+                settings.force_caps = value.get::<bool>().expect("type checked upstream");
+            }
+            "use-encoding-caps" => {
+                settings.use_encoding_caps = value.get::<bool>().expect("type checked upstream");
+            }
+            "caps-channel" => {
+                settings.caps_channel = value
+                    .get::<CapsChannel>()
+                    .expect("type checked upstream");
+            }
+            "forward-events" => {
+                settings.forward_events = value.get::<bool>().expect("type checked upstream");
+            }
+            "enable-fku" => {
+                settings.enable_fku = value.get::<bool>().expect("type checked upstream");
+            }
+            "locality" => {
+                settings.locality = value
+                    .get::<ZenohLocality>()
+                    .expect("type checked upstream");
+            }
+            "timestamp-mode" => {
+                settings.timestamp_mode = value
+                    .get::<TimestampMode>()
+                    .expect("type checked upstream");
+            }
+            "is-live" => {
+                self.obj().set_live(value.get::<bool>().expect("type checked upstream"));
+            }
+            "sample-kind-filter" => {
+                settings.sample_kind_filter = value
+                    .get::<SampleKindFilter>()
+                    .expect("type checked upstream");
+            }
+            "drop-empty" => {
+                settings.drop_empty = value.get::<bool>().expect("type checked upstream");
+            }
+            #[cfg(feature = "metrics-http")]
+            "metrics-port" => {
+                settings.metrics_port = value.get::<u32>().expect("type checked upstream");
+            }
+            #[cfg(feature = "metrics-http")]
+            "metrics-address" => {
+                settings.metrics_address = value
+                    .get::<Option<String>>()
+                    .expect("type checked upstream")
+                    .unwrap_or_else(|| crate::metrics::DEFAULT_METRICS_ADDRESS.into());
+            }
             name => {
                 gst::warning!(CAT, "Unknown property: {}", name);
             }
@@ -388,21 +1829,72 @@ impl ObjectImpl for ZenohSrc {
     fn property(&self, _id: usize, pspec: &glib::ParamSpec) -> glib::Value {
         match pspec.name() {
             // Configuration properties - read from settings
-            "key-expr" | "config" | "priority" | "congestion-control" | "reliability"
-            | "receive-timeout-ms" | "apply-buffer-meta" | "session-group" => {
+            "key-expr" | "config" | "config-json5" | "scouting" | "endpoints-only"
+            | "tls-root-ca" | "tls-client-cert" | "tls-client-key" | "username" | "priority"
+            | "congestion-control" | "reliability" | "express" | "receive-timeout-ms"
+            | "apply-buffer-meta" | "mark-discont" | "session-group" | "reconnect" | "caps"
+            | "force-caps" | "use-encoding-caps" | "caps-channel" | "locality" | "zero-copy"
+            | "max-buffer-size" | "no-data-timeout-ms" | "declaration-delay-ms"
+            | "max-lateness-ms" | "reorder-window" | "reorder-timeout-ms" | "timestamp-mode"
+            | "sample-kind-filter" | "buffer-pool-size" | "buffer-pool-max"
+            | "use-downstream-pool" | "connectivity-poll-ms" | "drop-empty" | "forward-events"
+            | "enable-fku" | "session-group-linger-ms" => {
                 let settings = self.settings.lock().unwrap();
                 match pspec.name() {
                     "key-expr" => settings.key_expr.to_value(),
                     "config" => settings.config_file.to_value(),
+                    "config-json5" => settings.config_json5.to_value(),
+                    "scouting" => settings.scouting.to_value(),
+                    "endpoints-only" => settings.endpoints_only.to_value(),
+                    "tls-root-ca" => settings.tls_root_ca.to_value(),
+                    "tls-client-cert" => settings.tls_client_cert.to_value(),
+                    "tls-client-key" => settings.tls_client_key.to_value(),
+                    "username" => settings.username.to_value(),
                     "priority" => (settings.priority as u32).to_value(),
                     "congestion-control" => settings.congestion_control.to_value(),
                     "reliability" => settings.reliability.to_value(),
+                    "express" => settings.express.to_value(),
                     "receive-timeout-ms" => settings.receive_timeout_ms.to_value(),
                     "apply-buffer-meta" => settings.apply_buffer_meta.to_value(),
+                    "mark-discont" => settings.mark_discont.to_value(),
                     "session-group" => settings.session_group.to_value(),
+                    "session-group-linger-ms" => settings.session_group_linger_ms.to_value(),
+                    "reconnect" => settings.reconnect.to_value(),
+                    "caps" => settings.caps.to_value(),
+                    "force-caps" => settings.force_caps.to_value(),
+                    "use-encoding-caps" => settings.use_encoding_caps.to_value(),
+                    "caps-channel" => settings.caps_channel.to_value(),
+                    "forward-events" => settings.forward_events.to_value(),
+                    "enable-fku" => settings.enable_fku.to_value(),
+                    "locality" => settings.locality.to_value(),
+                    "zero-copy" => settings.zero_copy.to_value(),
+                    "max-buffer-size" => settings.max_buffer_size.to_value(),
+                    "buffer-pool-size" => settings.buffer_pool_size.to_value(),
+                    "buffer-pool-max" => settings.buffer_pool_max.to_value(),
+                    "use-downstream-pool" => settings.use_downstream_pool.to_value(),
+                    "no-data-timeout-ms" => settings.no_data_timeout_ms.to_value(),
+                    "declaration-delay-ms" => settings.declaration_delay_ms.to_value(),
+                    "connectivity-poll-ms" => settings.connectivity_poll_ms.to_value(),
+                    "max-lateness-ms" => settings.max_lateness_ms.to_value(),
+                    "reorder-window" => settings.reorder_window.to_value(),
+                    "reorder-timeout-ms" => settings.reorder_timeout_ms.to_value(),
+                    "timestamp-mode" => settings.timestamp_mode.to_value(),
+                    "sample-kind-filter" => settings.sample_kind_filter.to_value(),
+                    "drop-empty" => settings.drop_empty.to_value(),
                     _ => unreachable!(),
                 }
             }
+            #[cfg(feature = "metrics-http")]
+            "metrics-port" => {
+                let settings = self.settings.lock().unwrap();
+                settings.metrics_port.to_value()
+            }
+            #[cfg(feature = "metrics-http")]
+            "metrics-address" => {
+                let settings = self.settings.lock().unwrap();
+                settings.metrics_address.to_value()
+            }
+            "is-live" => self.is_live().to_value(),
             // Statistics properties - read from state
             "bytes-received" => {
                 let state = self.state.lock().unwrap();
@@ -420,6 +1912,30 @@ impl ObjectImpl for ZenohSrc {
                     0u64.to_value()
                 }
             }
+            "session-zid" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.session.as_session().zid().to_string().to_value()
+                } else {
+                    String::new().to_value()
+                }
+            }
+            "session-shared" => {
+                let state = self.state.lock().unwrap();
+                let shared = match *state {
+                    State::Started(ref started) => started.session.is_shared(),
+                    _ => false,
+                };
+                shared.to_value()
+            }
+            "peers" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    crate::session::connected_peers(started.session.as_session()).to_value()
+                } else {
+                    Vec::<String>::new().to_value()
+                }
+            }
             "errors" => {
                 let state = self.state.lock().unwrap();
                 if let State::Started(ref started) = *state {
@@ -428,6 +1944,110 @@ impl ObjectImpl for ZenohSrc {
                     0u64.to_value()
                 }
             }
+            "dropped-late" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().dropped_late.to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "dropped-filtered" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().dropped_filtered.to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "attachment-bytes-received" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().attachment_bytes_received.to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "metadata-errors" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().metadata_errors.to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "samples-lost" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().sequence.lost().to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "samples-reordered" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().sequence.reordered().to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "bitrate" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().rate.bitrate().to_value()
+                } else {
+                    0u64.to_value()
+                }
+            }
+            "message-rate" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().rate.message_rate().to_value()
+                } else {
+                    0f64.to_value()
+                }
+            }
+            "latency-ms" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().latency_ms.to_value()
+                } else {
+                    0f64.to_value()
+                }
+            }
+            "max-latency-ms" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().max_latency_ms.to_value()
+                } else {
+                    0f64.to_value()
+                }
+            }
+            "rx-priority" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().rx_qos.priority().to_value()
+                } else {
+                    0u32.to_value()
+                }
+            }
+            "rx-express" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().rx_qos.express().to_value()
+                } else {
+                    false.to_value()
+                }
+            }
+            "stats-json" => {
+                let state = self.state.lock().unwrap();
+                if let State::Started(ref started) = *state {
+                    started.stats.lock().unwrap().to_json().to_value()
+                } else {
+                    Statistics::default().to_json().to_value()
+                }
+            }
             name => {
                 gst::warning!(CAT, "Unknown property: {}", name);
                 // Return an empty string value as default
@@ -439,7 +2059,8 @@ impl ObjectImpl for ZenohSrc {
     fn constructed(&self) {
         self.parent_constructed();
         self.obj().set_format(gst::Format::Time);
-        self.obj().set_do_timestamp(true);
+        // Buffers are stamped in apply_timestamp_mode() rather than with
+        // do-timestamp, which would make up a DTS for buffers keeping the sender's PTS
         self.obj().set_live(true);
     }
 }
@@ -458,12 +2079,7 @@ impl BaseSrcImpl for ZenohSrc {
 
         // Check if we can start from current state
         if !state.can_start() {
-            let current_state = match *state {
-                State::Stopped => "Stopped",
-                State::Starting => "Starting",
-                State::Started(_) => "Started",
-                State::Stopping => "Stopping",
-            };
+            let current_state = state.name();
             gst::warning!(
                 CAT,
                 "Cannot start ZenohSrc from state: {}, ignoring start request",
@@ -486,12 +2102,37 @@ impl BaseSrcImpl for ZenohSrc {
         // Get settings
         let settings = self.settings.lock().unwrap();
         let key_expr = settings.key_expr.clone();
-        let config_file = settings.config_file.clone();
+        let session_config = SessionConfig {
+            config_file: settings.config_file.clone(),
+            config_json5: settings.config_json5.clone(),
+            scouting: settings.scouting,
+            endpoints_only: settings.endpoints_only,
+            tls_root_ca: settings.tls_root_ca.clone(),
+            tls_client_cert: settings.tls_client_cert.clone(),
+            tls_client_key: settings.tls_client_key.clone(),
+            username: settings.username.clone(),
+            password: settings.password.clone(),
+        };
         let priority = settings.priority;
         let congestion_control = settings.congestion_control.clone();
         let reliability = settings.reliability.clone();
+        let express = settings.express;
         let external_session = settings.external_session.clone();
         let session_group = settings.session_group.clone();
+        let session_group_linger = Duration::from_millis(settings.session_group_linger_ms.into());
+        let locality = settings.locality;
+        let ignore_metadata_caps = settings.force_caps && settings.caps.is_some();
+        let caps_channel = settings.caps_channel;
+        let forward_events = settings.forward_events;
+        let enable_fku = settings.enable_fku;
+        let reorder = (settings.reorder_window > 0).then(|| {
+            let timeout = (settings.reorder_timeout_ms > 0)
+                .then(|| Duration::from_millis(settings.reorder_timeout_ms.into()));
+            ReorderBuffer::new(settings.reorder_window as usize, timeout)
+        });
+        let declaration_delay = Duration::from_millis(settings.declaration_delay_ms);
+        let (buffer_pool_size, buffer_pool_max) =
+            (settings.buffer_pool_size, settings.buffer_pool_max);
         drop(settings);
 
         // Validate the key expression
@@ -502,6 +2143,17 @@ impl BaseSrcImpl for ZenohSrc {
             ));
         }
 
+        if buffer_pool_max > 0 && buffer_pool_max < buffer_pool_size {
+            return Err(gst::error_msg!(
+                gst::ResourceError::Settings,
+                [
+                    "buffer-pool-max ({}) must be 0 or at least buffer-pool-size ({})",
+                    buffer_pool_max,
+                    buffer_pool_size
+                ]
+            ));
+        }
+
         // Determine session source: external (Rust API) > session-group (property) > new session
         let session_wrapper = if let Some(shared_session) = external_session {
             // Priority 1: External session provided via Rust API
@@ -510,22 +2162,20 @@ impl BaseSrcImpl for ZenohSrc {
         } else if let Some(ref group) = session_group {
             // Priority 2: Session group property (gst-launch compatible)
             gst::debug!(CAT, "Using session group '{}'", group);
-            let session = crate::session::get_or_create_session(group, config_file.as_deref())
-                .map_err(|e| ZenohError::Init(e).to_error_message())?;
-            SessionWrapper::Shared(session)
+            let session =
+                crate::session::get_or_create_session(group, &session_config, session_group_linger)
+                    .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            SessionWrapper::Group(session)
         } else {
             // Priority 3: Create a new owned session
             gst::debug!(CAT, "Creating new Zenoh session");
-            let config = match config_file {
-                Some(path) if !path.is_empty() => {
-                    gst::debug!(CAT, "Loading Zenoh config from {}", path);
-                    zenoh::Config::from_file(&path)
-                        .map_err(|e| ZenohError::Init(e).to_error_message())?
-                }
-                _ => zenoh::Config::default(),
-            };
-            let session = zenoh::open(config)
-                .wait()
+            if let Some(ref path) = session_config.config_file
+                && !path.is_empty()
+            {
+                gst::debug!(CAT, "Loading Zenoh config from {}", path);
+            }
+            let session = session_config
+                .open()
                 .map_err(|e| ZenohError::Init(e).to_error_message())?;
             SessionWrapper::Owned(session)
         };
@@ -539,20 +2189,171 @@ impl BaseSrcImpl for ZenohSrc {
             reliability
         );
 
-        // Note: Zenoh subscriber reliability is automatically determined by the publisher
-        //
-        // Unlike publishers, subscribers don't explicitly configure reliability modes.
-        // Instead, they automatically adapt to match the reliability mode of the
-        // publisher they're receiving from. This ensures consistent delivery guarantees
-        // across the pub-sub connection without requiring manual coordination.
+        // Zenoh 1.x subscribers can't declare a preferred reliability: the
+        // subscriber builder has no `reliability()` (it was removed in 1.0), so
+        // delivery follows the reliability each publisher declares. Keep the
+        // property informational and say so, rather than silently dropping it.
+        // Same for the priority: the subscriber builder has no QoS at all.
+        if priority != 5 {
+            gst::debug!(
+                CAT,
+                imp = self,
+                "priority={} is informational: Zenoh subscribers can't declare a priority, \
+                 samples carry the one of their publisher",
+                priority
+            );
+        }
+        if reliability != "best-effort" {
+            gst::debug!(
+                CAT,
+                imp = self,
+                "reliability='{}' is informational: Zenoh subscribers can't declare a reliability, \
+                 it is set by the publishers",
+                reliability
+            );
+        }
+        if express {
+            gst::debug!(
+                CAT,
+                imp = self,
+                "express=true is informational: Zenoh subscribers can't request express \
+                 delivery, it is set by the publishers"
+            );
+        }
 
         // Create subscriber
         let subscriber = session_wrapper
             .as_session()
-            .declare_subscriber(key_expr)
+            .declare_subscriber(key_expr.clone())
+            .allowed_origin(locality.into())
             .wait()
             .map_err(|e| ZenohError::Init(e).to_error_message())?;
 
+        // Ask the publishing zenohsinks for their current caps, so the first buffers
+        // can be interpreted without waiting for the next caps transmission. Replies
+        // arrive in the background; publishers without a caps queryable just don't answer.
+        let queried_caps = Arc::new(Mutex::new(None));
+        if !ignore_metadata_caps {
+            let queried_caps = queried_caps.clone();
+            let caps_key = crate::metadata::caps_query_key_expr(&key_expr);
+            let result = session_wrapper
+                .as_session()
+                .get(caps_key.clone())
+                .timeout(CAPS_QUERY_TIMEOUT)
+                .callback(move |reply| {
+                    let caps = reply.result().ok().and_then(|sample| {
+                        sample.payload().try_to_string().ok()?.parse::<gst::Caps>().ok()
+                    });
+                    if let Some(caps) = caps {
+                        queried_caps.lock().unwrap().get_or_insert(caps);
+                    }
+                })
+                .wait();
+            if let Err(e) = result {
+                gst::debug!(CAT, imp = self, "Failed to query caps on '{}': {}", caps_key, e);
+            }
+        }
+
+        // With caps-channel=sidecar-key the publishers send their caps on
+        // `<key-expr>/caps` ahead of the samples they apply to
+        let sidecar_caps = Arc::new(Mutex::new(None));
+        let caps_subscriber = if caps_channel == CapsChannel::SidecarKey && !ignore_metadata_caps {
+            let sidecar_caps = sidecar_caps.clone();
+            let caps_key = crate::metadata::caps_query_key_expr(&key_expr);
+            gst::debug!(CAT, imp = self, "Subscribing to caps on '{}'", caps_key);
+            let subscriber = session_wrapper
+                .as_session()
+                .declare_subscriber(caps_key)
+                .allowed_origin(locality.into())
+                .callback(move |sample| {
+                    let caps = sample
+                        .payload()
+                        .try_to_string()
+                        .ok()
+                        .and_then(|caps| caps.parse::<gst::Caps>().ok());
+                    if let Some(caps) = caps {
+                        *sidecar_caps.lock().unwrap() = Some(caps);
+                    }
+                })
+                .wait()
+                .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            Some(subscriber)
+        } else {
+            None
+        };
+
+        // With forward-events the publishers send EOS and custom events on
+        // `<key-expr>/events`. Sending custom events to the element hands them
+        // to BaseSrc, which pushes them ahead of the next buffer. EOS is left
+        // to create(): sent to the element, it would flush the samples still
+        // queued behind it.
+        let forwarded_eos = Arc::new(AtomicBool::new(false));
+        let events_subscriber = if forward_events {
+            let element = self.obj().downgrade();
+            let forwarded_eos = forwarded_eos.clone();
+            let events_key = crate::metadata::events_key_expr(&key_expr);
+            gst::debug!(CAT, imp = self, "Subscribing to events on '{}'", events_key);
+            let subscriber = session_wrapper
+                .as_session()
+                .declare_subscriber(events_key)
+                .allowed_origin(locality.into())
+                .callback(move |sample| {
+                    let event = sample
+                        .payload()
+                        .try_to_string()
+                        .ok()
+                        .and_then(|payload| crate::metadata::deserialize_event(&payload));
+                    if let (Some(event), Some(element)) = (event, element.upgrade()) {
+                        gst::debug!(CAT, obj = element, "Forwarded {:?} event", event.type_());
+                        if event.type_() == gst::EventType::Eos {
+                            forwarded_eos.store(true, Ordering::SeqCst);
+                        } else {
+                            element.send_event(event);
+                        }
+                    }
+                })
+                .wait()
+                .map_err(|e| ZenohError::Init(e).to_error_message())?;
+            Some(subscriber)
+        } else {
+            None
+        };
+
+        // With enable-fku, upstream force-key-unit events are published on
+        // `<key-expr>/fku`, where the publishers' zenohsink listens
+        let fku_key = if enable_fku {
+            let fku_key = crate::metadata::fku_key_expr(&key_expr);
+            Some(
+                zenoh::key_expr::KeyExpr::try_from(fku_key)
+                    .map_err(|e| ZenohError::Init(e).to_error_message())?,
+            )
+        } else {
+            None
+        };
+
+        // Tell the zenohsinks using compression-negotiate which algorithms can
+        // be decompressed here. Without the tokens they just send uncompressed.
+        #[cfg(any(
+            feature = "compression-zstd",
+            feature = "compression-lz4",
+            feature = "compression-gzip",
+            feature = "compression-brotli",
+            feature = "compression-snappy"
+        ))]
+        let compression_tokens =
+            crate::compression::advertise(session_wrapper.as_session(), &key_expr)
+                .unwrap_or_else(|e| {
+                    gst::debug!(CAT, imp = self, "Failed to advertise compression: {}", e);
+                    Vec::new()
+                });
+
+        // Zenoh doesn't acknowledge subscriber declarations: the declaration
+        // reaches routers and peers asynchronously, and what is published
+        // meanwhile isn't routed to the new subscriber
+        if !declaration_delay.is_zero() {
+            self.settle_declaration(declaration_delay);
+        }
+
         // Reacquire state lock to complete transition
         let mut state = self.state.lock().unwrap();
 
@@ -568,11 +2369,60 @@ impl BaseSrcImpl for ZenohSrc {
             ));
         }
 
+        let stats = Arc::new(Mutex::new(Statistics::default()));
+        #[cfg(feature = "metrics-http")]
+        let metrics = {
+            let (address, port) = {
+                let settings = self.settings.lock().unwrap();
+                (settings.metrics_address.clone(), settings.metrics_port)
+            };
+            let stats = stats.clone();
+            crate::metrics::serve(self.obj().upcast_ref(), &address, port, move || {
+                stats.lock().unwrap().to_json()
+            })
+        };
+
+        let connectivity = ConnectivityMonitor::start(
+            session_wrapper.as_session(),
+            self.obj().upcast_ref(),
+            self.settings.lock().unwrap().connectivity_poll_ms,
+        );
+
+        // A previous run may have stopped while flushing
+        self.flushing.store(false, Ordering::SeqCst);
+        *self.fku.lock().unwrap() = fku_key.clone().map(|key| FkuTarget {
+            session: session_wrapper.as_session().clone(),
+            key,
+            stats: stats.clone(),
+        });
         *state = State::Started(Started {
-            _session: session_wrapper,
+            session: session_wrapper,
             subscriber,
-            flushing: Arc::new(AtomicBool::new(false)),
-            stats: Arc::new(Mutex::new(Statistics::default())),
+            stats,
+            idle: IdleTracker::default(),
+            queried_caps,
+            caps_subscriber,
+            sidecar_caps,
+            events_subscriber,
+            forwarded_eos,
+            fku_key,
+            #[cfg(any(
+                feature = "compression-zstd",
+                feature = "compression-lz4",
+                feature = "compression-gzip",
+                feature = "compression-brotli",
+                feature = "compression-snappy"
+            ))]
+            _compression_tokens: compression_tokens,
+            reorder,
+            discont: true,
+            connected: false,
+            connectivity_checked: None,
+            pool: (buffer_pool_size > 0)
+                .then(|| PayloadPool::new(buffer_pool_size, buffer_pool_max)),
+            _connectivity: connectivity,
+            #[cfg(feature = "metrics-http")]
+            _metrics: metrics,
         });
 
         gst::debug!(CAT, "ZenohSrc successfully transitioned to Started state");
@@ -581,16 +2431,13 @@ impl BaseSrcImpl for ZenohSrc {
     }
 
     fn stop(&self) -> Result<(), gst::ErrorMessage> {
+        // Released first so the session isn't kept open by its clone
+        *self.fku.lock().unwrap() = None;
         let mut state = self.state.lock().unwrap();
 
         // Check if we can stop from current state
         if !state.can_stop() {
-            let current_state = match *state {
-                State::Stopped => "Stopped",
-                State::Starting => "Starting",
-                State::Started(_) => "Started",
-                State::Stopping => "Stopping",
-            };
+            let current_state = state.name();
             gst::debug!(CAT, "ZenohSrc stop called from state: {}", current_state);
             if state.is_stopped() {
                 return Ok(()); // Already stopped is not an error
@@ -616,6 +2463,12 @@ impl BaseSrcImpl for ZenohSrc {
         }
 
         *state = State::Stopped;
+        *self.applied_segment.lock().unwrap() = None;
+        *self.applied_tags.lock().unwrap() = None;
+        *self.applied_stream_identity.lock().unwrap() = None;
+        *self.signalled_caps.lock().unwrap() = None;
+        *self.downstream_pool.lock().unwrap() = None;
+        *self.allocation_caps.lock().unwrap() = None;
         gst::debug!(CAT, "ZenohSrc successfully transitioned to Stopped state");
 
         Ok(())
@@ -627,10 +2480,7 @@ impl BaseSrcImpl for ZenohSrc {
             imp = self,
             "Unlock called - cancelling blocking operations"
         );
-        let state = self.state.lock().unwrap();
-        if let State::Started(ref started) = *state {
-            started.flushing.store(true, Ordering::SeqCst);
-        }
+        self.flushing.store(true, Ordering::SeqCst);
         Ok(())
     }
 
@@ -640,10 +2490,7 @@ impl BaseSrcImpl for ZenohSrc {
             imp = self,
             "Unlock stop called - resuming normal operation"
         );
-        let state = self.state.lock().unwrap();
-        if let State::Started(ref started) = *state {
-            started.flushing.store(false, Ordering::SeqCst);
-        }
+        self.flushing.store(false, Ordering::SeqCst);
         Ok(())
     }
 
@@ -653,17 +2500,19 @@ impl BaseSrcImpl for ZenohSrc {
         match event.view() {
             EventView::FlushStart(_) => {
                 gst::debug!(CAT, imp = self, "Flush start - cancelling operations");
-                let state = self.state.lock().unwrap();
-                if let State::Started(ref started) = *state {
-                    started.flushing.store(true, Ordering::SeqCst);
-                }
+                self.flushing.store(true, Ordering::SeqCst);
                 self.parent_event(event)
             }
             EventView::FlushStop(_) => {
                 gst::debug!(CAT, imp = self, "Flush stop - resuming operations");
-                let state = self.state.lock().unwrap();
-                if let State::Started(ref started) = *state {
-                    started.flushing.store(false, Ordering::SeqCst);
+                self.flushing.store(false, Ordering::SeqCst);
+                self.parent_event(event)
+            }
+            EventView::CustomUpstream(_) => {
+                if let Some(payload) = crate::metadata::serialize_force_key_unit(event)
+                    && self.request_key_unit(&payload)
+                {
+                    return true;
                 }
                 self.parent_event(event)
             }
@@ -671,6 +2520,65 @@ impl BaseSrcImpl for ZenohSrc {
         }
     }
 
+    fn decide_allocation(
+        &self,
+        query: &mut gst::query::Allocation,
+    ) -> Result<(), gst::LoggableError> {
+        // Without a proposed pool, BaseSrc configures one of its own, which
+        // is no better than the buffers zenohsrc makes itself
+        let proposed = query
+            .allocation_pools()
+            .into_iter()
+            .any(|(pool, ..)| pool.is_some());
+        self.parent_decide_allocation(query)?;
+
+        // BaseSrc uses the first pool, configured for the caps by now
+        let use_downstream_pool = proposed && self.settings.lock().unwrap().use_downstream_pool;
+        let downstream_pool = query
+            .allocation_pools()
+            .into_iter()
+            .next()
+            .filter(|_| use_downstream_pool)
+            .and_then(|(pool, size, _, _)| Some((pool?, size as usize)));
+        if let Some((ref pool, size)) = downstream_pool {
+            gst::debug!(
+                CAT,
+                imp = self,
+                "Copying payloads into downstream pool {} of {} byte buffers",
+                pool.name(),
+                size
+            );
+        }
+        *self.downstream_pool.lock().unwrap() = downstream_pool;
+        *self.allocation_caps.lock().unwrap() = query.get().0.map(|caps| caps.to_owned());
+        Ok(())
+    }
+
+    fn caps(&self, filter: Option<&gst::Caps>) -> Option<gst::Caps> {
+        let (fixed_caps, force_caps) = {
+            let settings = self.settings.lock().unwrap();
+            (settings.caps.clone(), settings.force_caps)
+        };
+
+        let Some(fixed_caps) = fixed_caps else {
+            return BaseSrcImplExt::parent_caps(self, filter);
+        };
+
+        // Once caps from metadata have been applied they win over the fixed caps,
+        // so renegotiation doesn't revert them (unless force-caps is set)
+        let caps = match self.obj().src_pad().current_caps() {
+            Some(current) if !force_caps => current,
+            _ => fixed_caps,
+        };
+
+        gst::debug!(CAT, imp = self, "Reporting caps {}", caps);
+
+        Some(match filter {
+            Some(filter) => filter.intersect_with_mode(&caps, gst::CapsIntersectMode::First),
+            None => caps,
+        })
+    }
+
     fn query(&self, query: &mut gst::QueryRef) -> bool {
         use gst::QueryViewMut;
 
@@ -705,290 +2613,324 @@ impl PushSrcImpl for ZenohSrc {
         &self,
         _buffer: Option<&mut gst::BufferRef>,
     ) -> Result<CreateSuccess, gst::FlowError> {
-        let state_locked = self.state.lock().unwrap();
-        let State::Started(ref started) = *state_locked else {
-            gst::element_imp_error!(self, gst::CoreError::Failed, ["Not started yet"]);
-            return Err(gst::FlowError::Error);
+        let mut state_locked = self.state.lock().unwrap();
+        let mut started = match *state_locked {
+            State::Started(ref mut started) => started,
+            _ => {
+                gst::element_imp_error!(self, gst::CoreError::Failed, ["Not started yet"]);
+                return Err(gst::FlowError::Error);
+            }
         };
 
         // Check if we're flushing before attempting to receive
-        if started.flushing.load(Ordering::SeqCst) {
+        if self.flushing.load(Ordering::SeqCst) {
             gst::debug!(CAT, imp = self, "Flushing - returning Flushing flow");
             return Err(gst::FlowError::Flushing);
         }
 
         // Get the configured settings
-        let (receive_timeout_ms, apply_buffer_meta) = {
+        let (
+            receive_timeout_ms,
+            reconnect,
+            max_buffer_size,
+            no_data_timeout,
+            max_lateness,
+            sample_kind_filter,
+            drop_empty,
+            receive_settings,
+        ) = {
             let settings = self.settings.lock().unwrap();
-            (settings.receive_timeout_ms, settings.apply_buffer_meta)
+            (
+                settings.receive_timeout_ms,
+                settings.reconnect,
+                settings.max_buffer_size,
+                Duration::from_millis(settings.no_data_timeout_ms),
+                Duration::from_millis(settings.max_lateness_ms),
+                settings.sample_kind_filter,
+                settings.drop_empty,
+                ReceiveSettings::from_settings(&settings),
+            )
         };
 
+        // Poll at most every MAX_POLL_INTERVAL whatever receive-timeout-ms is, so
+        // unlock() is honored promptly even with a long timeout
+        let poll_interval = Duration::from_millis(receive_timeout_ms).min(MAX_POLL_INTERVAL);
+
         // CRITICAL: Use recv_timeout() instead of blocking recv()
         // This allows us to check the flushing flag periodically without sleeping
         let sample: zenoh::sample::Sample = loop {
-            if started.flushing.load(Ordering::SeqCst) {
+            if self.flushing.load(Ordering::SeqCst) {
                 gst::debug!(CAT, imp = self, "Flushing detected during receive");
                 return Err(gst::FlowError::Flushing);
             }
 
-            // Use recv_timeout with configurable timeout to remain responsive to flushing
-            // recv_timeout returns Result<Option<Sample>, RecvTimeoutError>
-            match started
-                .subscriber
-                .recv_timeout(Duration::from_millis(receive_timeout_ms))
-            {
-                Ok(Some(sample)) => break sample,
-                Ok(None) => {
-                    // No sample available, continue loop
-                    continue;
+            // After a forwarded EOS, only the samples already queued are taken
+            let eos = started.forwarded_eos.load(Ordering::SeqCst);
+
+            // Samples put back in order by reorder-window go first, and the wait
+            // for new ones ends when a held sample times out
+            let mut wait = if eos { Duration::ZERO } else { poll_interval };
+            if let Some(ref mut reorder) = started.reorder {
+                let now = Instant::now();
+                if let Some(sample) = reorder.pop(now) {
+                    break sample;
                 }
-                Err(e) => {
-                    // Check if it's a timeout or disconnection
-                    let err_msg = format!("{:?}", e);
-                    if err_msg.contains("Timeout") {
-                        // Timeout - check flushing flag and retry
-                        continue;
-                    } else {
-                        // Disconnected or other error
-                        started.stats.lock().unwrap().errors += 1;
-                        gst::element_imp_error!(
-                            self,
-                            gst::ResourceError::Read,
-                            ["Subscriber error: {}", e]
-                        );
-                        return Err(gst::FlowError::Error);
-                    }
+                if let Some(deadline) = reorder.deadline() {
+                    wait = wait.min(deadline.saturating_duration_since(now));
                 }
             }
-        };
 
-        // Check if the sample has attachment metadata (caps, buffer timing, compression, etc.)
-        // Parse metadata once and extract all relevant information
-        #[cfg(any(
-            feature = "compression-zstd",
-            feature = "compression-lz4",
-            feature = "compression-gzip"
-        ))]
-        let (parsed_metadata, compression_type) = if let Some(attachment) = sample.attachment() {
-            match MetadataParser::parse(attachment) {
-                Ok(metadata) => {
-                    // If caps are present in metadata, set them on the source pad
-                    if let Some(caps) = metadata.caps() {
-                        gst::debug!(CAT, imp = self, "Received caps from metadata: {}", caps);
-
-                        // Set caps on the source pad
-                        if let Err(e) = self.obj().set_caps(caps) {
-                            gst::warning!(CAT, imp = self, "Failed to set caps: {}", e);
-                        }
+            // recv_timeout returns Result<Option<Sample>, RecvTimeoutError>
+            match started.subscriber.recv_timeout(wait) {
+                Ok(Some(sample)) => {
+                    if let Some(silence) = started.idle.record_data() {
+                        self.post_idle_message(crate::stats::STREAM_RESUMED_MESSAGE, silence);
                     }
 
-                    // Check for compression metadata
-                    let compression = metadata
-                        .user_metadata()
-                        .get(crate::metadata::keys::COMPRESSION)
-                        .and_then(|v| crate::compression::CompressionType::from_metadata_value(v));
+                    // Caps messages on the sidecar key, received through a
+                    // wildcard key expression, aren't data
+                    if let Some(ref caps_subscriber) = started.caps_subscriber
+                        && caps_subscriber.key_expr().includes(sample.key_expr())
+                    {
+                        continue;
+                    }
+                    // Nor are forwarded events
+                    if let Some(ref events_subscriber) = started.events_subscriber
+                        && events_subscriber.key_expr().includes(sample.key_expr())
+                    {
+                        continue;
+                    }
+                    // Nor the force-key-unit requests of other receivers
+                    if let Some(ref fku_key) = started.fku_key
+                        && fku_key.includes(sample.key_expr())
+                    {
+                        continue;
+                    }
 
-                    // Log any user metadata
-                    if !metadata.user_metadata().is_empty() {
+                    if !sample_kind_filter.accepts(sample.kind()) {
+                        started.stats.lock().unwrap().dropped_filtered += 1;
                         gst::trace!(
                             CAT,
                             imp = self,
-                            "Received user metadata: {:?}",
-                            metadata.user_metadata()
+                            "Dropping {:?} sample on '{}': filtered by sample-kind-filter",
+                            sample.kind(),
+                            sample.key_expr()
                         );
+                        continue;
                     }
 
-                    (Some(metadata), compression)
-                }
-                Err(e) => {
-                    gst::warning!(CAT, imp = self, "Failed to parse metadata: {}", e);
-                    (None, None)
-                }
-            }
-        } else {
-            (None, None)
-        };
+                    // DELETEs are empty by nature, and so are the gaps zenohsink publishes
+                    if drop_empty
+                        && sample.kind() == zenoh::sample::SampleKind::Put
+                        && sample.payload().is_empty()
+                        && !is_gap_sample(&sample)
+                    {
+                        started.stats.lock().unwrap().dropped_filtered += 1;
+                        gst::trace!(
+                            CAT,
+                            imp = self,
+                            "Dropping empty sample on '{}' (drop-empty)",
+                            sample.key_expr()
+                        );
+                        continue;
+                    }
 
-        #[cfg(not(any(
-            feature = "compression-zstd",
-            feature = "compression-lz4",
-            feature = "compression-gzip"
-        )))]
-        let parsed_metadata = if let Some(attachment) = sample.attachment() {
-            match MetadataParser::parse(attachment) {
-                Ok(metadata) => {
-                    // If caps are present in metadata, set them on the source pad
-                    if let Some(caps) = metadata.caps() {
-                        gst::debug!(CAT, imp = self, "Received caps from metadata: {}", caps);
-
-                        // Set caps on the source pad
-                        if let Err(e) = self.obj().set_caps(caps) {
-                            gst::warning!(CAT, imp = self, "Failed to set caps: {}", e);
-                        }
+                    // Refuse oversized samples before anything gets allocated for them
+                    let size = sample.payload().len() as u64;
+                    if max_buffer_size > 0 && size > max_buffer_size {
+                        started.stats.lock().unwrap().errors += 1;
+                        gst::warning!(
+                            CAT,
+                            imp = self,
+                            "Dropping {} byte sample on '{}': exceeds max-buffer-size ({})",
+                            size,
+                            sample.key_expr(),
+                            max_buffer_size
+                        );
+                        continue;
                     }
 
-                    // Log any user metadata
-                    if !metadata.user_metadata().is_empty() {
-                        gst::trace!(
+                    // Skip stale samples so a consumer that fell behind catches up.
+                    // The next iteration re-checks flushing and blocks in recv_timeout.
+                    if !max_lateness.is_zero()
+                        && let Some(age) = sample_age(&sample)
+                        && age > max_lateness
+                    {
+                        started.stats.lock().unwrap().dropped_late += 1;
+                        gst::debug!(
                             CAT,
                             imp = self,
-                            "Received user metadata: {:?}",
-                            metadata.user_metadata()
+                            "Dropping sample on '{}': {:?} old, exceeds max-lateness-ms ({:?})",
+                            sample.key_expr(),
+                            age,
+                            max_lateness
                         );
+                        continue;
                     }
 
-                    Some(metadata)
+                    // Held until the samples before it arrived, or given up on
+                    if let Some(ref mut reorder) = started.reorder
+                        && let Some(seqnum) = sample_seqnum(&sample)
+                    {
+                        let key_expr = sample.key_expr().to_string();
+                        if reorder.push(&key_expr, seqnum, sample, Instant::now()).is_some() {
+                            started.stats.lock().unwrap().dropped_late += 1;
+                            gst::debug!(
+                                CAT,
+                                imp = self,
+                                "Dropping sample {} on '{}': too late for reorder-window",
+                                seqnum,
+                                key_expr
+                            );
+                        }
+                        continue;
+                    }
+                    break sample;
                 }
-                Err(e) => {
-                    gst::warning!(CAT, imp = self, "Failed to parse metadata: {}", e);
-                    None
+                Ok(None) if eos => {
+                    // The samples held by reorder-window go out before the EOS
+                    if let Some(sample) =
+                        started.reorder.as_mut().and_then(ReorderBuffer::pop_held)
+                    {
+                        break sample;
+                    }
+                    gst::debug!(CAT, imp = self, "Forwarded EOS, queued samples are out");
+                    return Err(gst::FlowError::Eos);
                 }
-            }
-        } else {
-            None
-        };
-
-        let payload = sample.payload();
-        let compressed_data = payload.to_bytes();
-
-        // Decompress if needed
-        #[cfg(any(
-            feature = "compression-zstd",
-            feature = "compression-lz4",
-            feature = "compression-gzip"
-        ))]
-        let slice = if let Some(comp_type) = compression_type {
-            match crate::compression::decompress(&compressed_data, comp_type) {
-                Ok(decompressed) => {
-                    gst::trace!(
-                        CAT,
-                        imp = self,
-                        "Decompressed {} bytes to {} bytes using {:?}",
-                        compressed_data.len(),
-                        decompressed.len(),
-                        comp_type
-                    );
-                    decompressed
+                Ok(None) => {
+                    // No sample available, check for a silent stream or a lost
+                    // connection and the flushing flag, and retry
+                    if let Some(silence) = started.idle.check(no_data_timeout) {
+                        self.post_idle_message(crate::stats::STREAM_IDLE_MESSAGE, silence);
+                    }
+                    if !(reconnect && Self::connection_lost(started)) {
+                        continue;
+                    }
+                    gst::warning!(CAT, imp = self, "Session lost its last router or peer");
+                }
+                Err(e) if reconnect => {
+                    // The subscriber channel was closed along with the session
+                    gst::warning!(CAT, imp = self, "Subscriber disconnected: {}", e);
                 }
                 Err(e) => {
                     started.stats.lock().unwrap().errors += 1;
                     gst::element_imp_error!(
                         self,
-                        gst::StreamError::Decode,
-                        ["Decompression failed: {}", e]
+                        gst::ResourceError::Read,
+                        ["Subscriber error: {}", e]
                     );
                     return Err(gst::FlowError::Error);
                 }
             }
-        } else {
-            compressed_data.to_vec()
-        };
-
-        #[cfg(not(any(
-            feature = "compression-zstd",
-            feature = "compression-lz4",
-            feature = "compression-gzip"
-        )))]
-        let slice = compressed_data.to_vec();
-
-        let mut buffer = gst::Buffer::with_size(slice.len()).map_err(|_| {
-            gst::element_imp_error!(
-                self,
-                gst::ResourceError::Failed,
-                ["Failed to allocate buffer"]
-            );
-            gst::FlowError::Error
-        })?;
 
-        {
-            let buffer_mut = buffer.get_mut().ok_or_else(|| {
-                gst::element_imp_error!(
-                    self,
-                    gst::ResourceError::Failed,
-                    ["Failed to get mutable buffer reference"]
-                );
-                gst::FlowError::Error
-            })?;
+            // Disconnected: reconnect without the state lock, which the signal
+            // handlers and the other threads of the element may need meanwhile
+            started.stats.lock().unwrap().errors += 1;
+            let session = started.session.as_session().clone();
+            drop(state_locked);
+            let subscriber = self.reconnect_subscriber(&session)?;
 
-            buffer_mut.copy_from_slice(0, &slice).map_err(|_| {
-                gst::element_imp_error!(
-                    self,
-                    gst::ResourceError::Failed,
-                    ["Failed to copy data to buffer"]
-                );
-                gst::FlowError::Error
-            })?;
+            state_locked = self.state.lock().unwrap();
+            let State::Started(ref mut restarted) = *state_locked else {
+                return Err(gst::FlowError::Flushing);
+            };
+            restarted.subscriber = subscriber;
+            // Samples published meanwhile are lost
+            restarted.discont = true;
+            restarted.connected = true;
+            restarted.connectivity_checked = Some(Instant::now());
+            started = restarted;
+        };
 
-            // Apply buffer timing metadata if enabled and available
-            // This preserves PTS, DTS, duration, offset, and flags from the sender
-            if apply_buffer_meta && let Some(ref metadata) = parsed_metadata {
-                // Check if we have buffer timing metadata
-                let has_timing = metadata.pts().is_some()
-                    || metadata.dts().is_some()
-                    || metadata.duration().is_some()
-                    || metadata.offset().is_some()
-                    || metadata.offset_end().is_some()
-                    || metadata.flags().is_some();
+        // End-to-end latency from the publisher's Zenoh timestamp
+        let latency_ms = sample_age(&sample).map(|latency| latency.as_secs_f64() * 1000.0);
 
-                if has_timing {
-                    metadata.apply_to_buffer(buffer_mut);
-                    gst::trace!(
-                        CAT,
-                        imp = self,
-                        "Applied buffer timing metadata: PTS={:?}, DTS={:?}, duration={:?}, flags={:?}",
-                        metadata.pts(),
-                        metadata.dts(),
-                        metadata.duration(),
-                        metadata.flags()
-                    );
-                }
+        // Caps received on the sidecar key apply to the samples published after them
+        if let Some(caps) = started.sidecar_caps.lock().unwrap().take()
+            && self.obj().src_pad().current_caps().as_ref() != Some(&caps)
+        {
+            gst::debug!(CAT, imp = self, "Using caps from the sidecar key: {}", caps);
+            if let Err(e) = self.obj().set_caps(&caps) {
+                gst::warning!(CAT, imp = self, "Failed to set sidecar caps: {}", e);
             }
+        }
 
-            // If no buffer timing metadata was applied, try Zenoh timestamp as fallback
-            // This is useful when receiving from a sender that doesn't use buffer metadata
-            if buffer_mut.pts().is_none()
-                && let Some(timestamp) = sample.timestamp()
-            {
-                // Zenoh timestamps are in NTP64 format (64-bit timestamp)
-                // Convert to GStreamer ClockTime (nanoseconds since epoch)
-                let ntp_time = timestamp.get_time();
-
-                // NTP64 timestamp is split into:
-                // - upper 32 bits: seconds since NTP epoch (Jan 1, 1900)
-                // - lower 32 bits: fractional seconds
-                // We need to convert this to nanoseconds since Unix epoch (Jan 1, 1970)
-
-                // NTP epoch is 2208988800 seconds before Unix epoch
-                const NTP_UNIX_OFFSET: u64 = 2208988800;
-
-                let ntp_secs = ntp_time.as_secs() as u64;
-                let ntp_nanos = ntp_time.subsec_nanos() as u64;
-
-                // Convert to Unix epoch
-                if ntp_secs >= NTP_UNIX_OFFSET {
-                    let unix_secs = ntp_secs - NTP_UNIX_OFFSET;
-                    let total_nanos = unix_secs * 1_000_000_000 + ntp_nanos;
+        // Caps from the caps query apply until caps metadata arrives with a sample
+        if let Some(caps) = started.queried_caps.lock().unwrap().take()
+            && self.obj().src_pad().current_caps().is_none()
+        {
+            gst::debug!(CAT, imp = self, "Using caps from caps query: {}", caps);
+            if let Err(e) = self.obj().set_caps(&caps) {
+                gst::warning!(CAT, imp = self, "Failed to set queried caps: {}", e);
+            }
+        }
 
-                    let pts = gst::ClockTime::from_nseconds(total_nanos);
-                    buffer_mut.set_pts(pts);
+        let (mut buffer, batch) = self.sample_to_buffer(
+            &sample,
+            &receive_settings,
+            &started.stats,
+            started.pool.as_mut(),
+        )?;
+        let size = buffer.size();
+
+        // The first buffer and the first after a reconnection start anew
+        if std::mem::take(&mut started.discont) && receive_settings.mark_discont {
+            buffer.make_mut().set_flags(gst::BufferFlags::DISCONT);
+        }
 
-                    gst::trace!(
-                        CAT,
-                        imp = self,
-                        "Applied Zenoh timestamp to buffer: PTS = {}",
-                        pts
-                    );
-                }
+        // A batch from batch-lists or aggregate-window-ms goes downstream as the buffer list it was
+        let batch_list = batch.and_then(|(sizes, timing)| {
+            let list = crate::buffer::split_batch(&buffer, &sizes, timing.as_deref());
+            if list.is_none() {
+                started.stats.lock().unwrap().errors += 1;
+                gst::warning!(
+                    CAT,
+                    imp = self,
+                    "Batch sizes on '{}' don't match the {} byte payload, pushing it as one buffer",
+                    sample.key_expr(),
+                    size
+                );
             }
-        }
+            list
+        });
+        let buffers = batch_list.as_ref().map_or(1, |list| list.len() as u64);
 
         // Update statistics on success
         let mut stats = started.stats.lock().unwrap();
-        stats.bytes_received += slice.len() as u64;
-        stats.messages_received += 1;
+        if let Some(latency_ms) = latency_ms {
+            stats.record_latency(latency_ms);
+        }
+        stats.bytes_received += size as u64;
+        stats.messages_received += buffers;
+        stats.rate.record(size as u64, buffers);
+        stats.rx_qos.record(&sample);
+        let first_buffer = stats.messages_received == buffers;
         drop(stats);
 
-        Ok(CreateSuccess::NewBuffer(buffer))
+        // Lifecycle signals are emitted without the state lock so handlers
+        // can query the element
+        drop(state_locked);
+        if first_buffer {
+            gst::debug!(CAT, imp = self, "First buffer received");
+            self.obj().emit_by_name::<()>("first-buffer", &[]);
+        }
+        // BaseSrc::set_caps() already pushed the caps event, ahead of this
+        // buffer. Comparing the pad caps covers every source of caps (metadata,
+        // sample encoding, caps query).
+        if let Some(caps) = self.caps_to_signal() {
+            gst::debug!(CAT, imp = self, "Output caps changed to {}", caps);
+            self.obj().emit_by_name::<()>("caps-changed", &[&caps]);
+        }
+        if sample.kind() == zenoh::sample::SampleKind::Delete {
+            let key_expr = sample.key_expr().to_string();
+            gst::debug!(CAT, imp = self, "DELETE received on '{}'", key_expr);
+            self.obj().emit_by_name::<()>("eos", &[&key_expr]);
+        }
+
+        Ok(match batch_list {
+            Some(list) => CreateSuccess::NewBufferList(list),
+            None => CreateSuccess::NewBuffer(buffer),
+        })
     }
 }
 
@@ -1004,78 +2946,136 @@ impl URIHandlerImpl for ZenohSrc {
         if settings.key_expr.is_empty() {
             return None;
         }
-
-        // Build URI in format: zenoh:key-expr?param1=value1&param2=value2
-        let mut uri = format!("zenoh:{}", settings.key_expr);
-        let mut params = Vec::new();
-
-        if let Some(ref config) = settings.config_file {
-            params.push(format!("config={}", urlencoding::encode(config)));
-        }
-        if settings.priority != 5 {
-            params.push(format!("priority={}", settings.priority));
-        }
-        if settings.congestion_control != "block" {
-            params.push(format!(
-                "congestion-control={}",
-                settings.congestion_control
-            ));
-        }
-        if settings.reliability != "best-effort" {
-            params.push(format!("reliability={}", settings.reliability));
-        }
-        if settings.receive_timeout_ms != 100 {
-            params.push(format!(
-                "receive-timeout-ms={}",
-                settings.receive_timeout_ms
-            ));
-        }
-        if !settings.apply_buffer_meta {
-            params.push("apply-buffer-meta=false".to_string());
+        let defaults = Settings::default();
+
+        // Build URI in format: zenoh:key-expr?param1=value1&param2=value2,
+        // listing the properties that differ from their default
+        let mut builder = UriBuilder::new(&settings.key_expr);
+
+        // The password is left out so it can't leak through the URI
+        for (key, value) in [
+            ("config", &settings.config_file),
+            ("config-json5", &settings.config_json5),
+            ("tls-root-ca", &settings.tls_root_ca),
+            ("tls-client-cert", &settings.tls_client_cert),
+            ("tls-client-key", &settings.tls_client_key),
+            ("username", &settings.username),
+            ("session-group", &settings.session_group),
+        ] {
+            if let Some(value) = value {
+                builder.param(key, value);
+            }
         }
-
-        if !params.is_empty() {
-            uri.push('?');
-            uri.push_str(&params.join("&"));
+        if let Some(ref caps) = settings.caps {
+            builder.param("caps", caps);
         }
+        builder.non_default("scouting", settings.scouting, defaults.scouting);
+        builder.non_default(
+            "endpoints-only",
+            settings.endpoints_only,
+            defaults.endpoints_only,
+        );
+        builder.non_default("priority", settings.priority, defaults.priority);
+        builder.non_default(
+            "congestion-control",
+            &settings.congestion_control,
+            &defaults.congestion_control,
+        );
+        builder.non_default("reliability", &settings.reliability, &defaults.reliability);
+        builder.non_default("express", settings.express, defaults.express);
+        builder.non_default(
+            "receive-timeout-ms",
+            settings.receive_timeout_ms,
+            defaults.receive_timeout_ms,
+        );
+        builder.non_default(
+            "apply-buffer-meta",
+            settings.apply_buffer_meta,
+            defaults.apply_buffer_meta,
+        );
+        builder.non_default("mark-discont", settings.mark_discont, defaults.mark_discont);
+        builder.non_default("zero-copy", settings.zero_copy, defaults.zero_copy);
+        builder.non_default("max-buffer-size", settings.max_buffer_size, defaults.max_buffer_size);
+        builder.non_default("buffer-pool-size", settings.buffer_pool_size, defaults.buffer_pool_size);
+        builder.non_default("buffer-pool-max", settings.buffer_pool_max, defaults.buffer_pool_max);
+        builder.non_default(
+            "use-downstream-pool",
+            settings.use_downstream_pool,
+            defaults.use_downstream_pool,
+        );
+        builder.non_default("max-lateness-ms", settings.max_lateness_ms, defaults.max_lateness_ms);
+        builder.non_default("reorder-window", settings.reorder_window, defaults.reorder_window);
+        builder.non_default(
+            "reorder-timeout-ms",
+            settings.reorder_timeout_ms,
+            defaults.reorder_timeout_ms,
+        );
+        builder.non_default(
+            "no-data-timeout-ms",
+            settings.no_data_timeout_ms,
+            defaults.no_data_timeout_ms,
+        );
+        builder.non_default(
+            "declaration-delay-ms",
+            settings.declaration_delay_ms,
+            defaults.declaration_delay_ms,
+        );
+        builder.non_default(
+            "connectivity-poll-ms",
+            settings.connectivity_poll_ms,
+            defaults.connectivity_poll_ms,
+        );
+        builder.non_default(
+            "session-group-linger-ms",
+            settings.session_group_linger_ms,
+            defaults.session_group_linger_ms,
+        );
+        builder.non_default("reconnect", settings.reconnect, defaults.reconnect);
+        builder.non_default("force-caps", settings.force_caps, defaults.force_caps);
+        builder.non_default(
+            "use-encoding-caps",
+            settings.use_encoding_caps,
+            defaults.use_encoding_caps,
+        );
+        builder.non_default(
+            "caps-channel",
+            uri::enum_nick(settings.caps_channel),
+            uri::enum_nick(defaults.caps_channel),
+        );
+        builder.non_default("forward-events", settings.forward_events, defaults.forward_events);
+        builder.non_default("enable-fku", settings.enable_fku, defaults.enable_fku);
+        builder.non_default(
+            "locality",
+            uri::enum_nick(settings.locality),
+            uri::enum_nick(defaults.locality),
+        );
+        builder.non_default(
+            "timestamp-mode",
+            uri::enum_nick(settings.timestamp_mode),
+            uri::enum_nick(defaults.timestamp_mode),
+        );
+        builder.non_default("is-live", self.is_live(), true);
+        builder.non_default(
+            "sample-kind-filter",
+            uri::enum_nick(settings.sample_kind_filter),
+            uri::enum_nick(defaults.sample_kind_filter),
+        );
+        builder.non_default("drop-empty", settings.drop_empty, defaults.drop_empty);
+        #[cfg(feature = "metrics-http")]
+        builder.non_default("metrics-port", settings.metrics_port, defaults.metrics_port);
+        #[cfg(feature = "metrics-http")]
+        builder.non_default(
+            "metrics-address",
+            &settings.metrics_address,
+            &defaults.metrics_address,
+        );
 
-        Some(uri)
+        Some(builder.build())
     }
 
     fn set_uri(&self, uri: &str) -> Result<(), glib::Error> {
         // Parse URI format: zenoh:key-expr?param1=value1&param2=value2
-        if !uri.starts_with("zenoh:") {
-            return Err(glib::Error::new(
-                gst::URIError::BadUri,
-                &format!("Invalid URI scheme, expected 'zenoh:', got: {}", uri),
-            ));
-        }
-
-        let uri_content = &uri[6..]; // Skip "zenoh:"
-
-        // Split into key expression and query parameters
-        let (key_expr, query) = if let Some(pos) = uri_content.find('?') {
-            (&uri_content[..pos], Some(&uri_content[pos + 1..]))
-        } else {
-            (uri_content, None)
-        };
-
-        if key_expr.is_empty() {
-            return Err(glib::Error::new(
-                gst::URIError::BadUri,
-                "Key expression cannot be empty",
-            ));
-        }
-
-        // Decode the key expression
-        let key_expr = urlencoding::decode(key_expr)
-            .map_err(|e| {
-                glib::Error::new(
-                    gst::URIError::BadUri,
-                    &format!("Failed to decode key expression: {}", e),
-                )
-            })?
-            .into_owned();
+        let (key_expr, params) = uri::parse(uri)?;
 
         let mut settings = self.settings.lock().unwrap();
 
@@ -1093,74 +3093,87 @@ impl URIHandlerImpl for ZenohSrc {
 
         settings.key_expr = key_expr;
 
-        // Parse query parameters
-        if let Some(query) = query {
-            for param in query.split('&') {
-                if let Some(pos) = param.find('=') {
-                    let key = &param[..pos];
-                    let value = urlencoding::decode(&param[pos + 1..])
-                        .map_err(|e| {
-                            glib::Error::new(
-                                gst::URIError::BadUri,
-                                &format!("Failed to decode parameter value: {}", e),
-                            )
-                        })?
-                        .into_owned();
-
-                    match key {
-                        "config" => settings.config_file = Some(value),
-                        "priority" => {
-                            settings.priority = value.parse().map_err(|_| {
-                                glib::Error::new(
-                                    gst::URIError::BadUri,
-                                    &format!("Invalid priority value: {}", value),
-                                )
-                            })?;
-                        }
-                        "congestion-control" => {
-                            if value != "block" && value != "drop" {
-                                return Err(glib::Error::new(
-                                    gst::URIError::BadUri,
-                                    &format!("Invalid congestion-control value: {}", value),
-                                ));
-                            }
-                            settings.congestion_control = value;
-                        }
-                        "reliability" => {
-                            if value != "best-effort" && value != "reliable" {
-                                return Err(glib::Error::new(
-                                    gst::URIError::BadUri,
-                                    &format!("Invalid reliability value: {}", value),
-                                ));
-                            }
-                            settings.reliability = value;
-                        }
-                        "receive-timeout-ms" => {
-                            let timeout: u64 = value.parse().map_err(|_| {
-                                glib::Error::new(
-                                    gst::URIError::BadUri,
-                                    &format!("Invalid receive-timeout-ms value: {}", value),
-                                )
-                            })?;
-                            // Clamp to valid range
-                            settings.receive_timeout_ms = timeout.clamp(10, 5000);
-                        }
-                        "apply-buffer-meta" => {
-                            settings.apply_buffer_meta = match value.as_str() {
-                                "true" | "1" | "yes" => true,
-                                "false" | "0" | "no" => false,
-                                _ => {
-                                    return Err(glib::Error::new(
-                                        gst::URIError::BadUri,
-                                        &format!("Invalid apply-buffer-meta value: {}", value),
-                                    ));
-                                }
-                            };
-                        }
-                        _ => {
-                            gst::warning!(CAT, imp = self, "Unknown URI parameter: {}", key);
-                        }
-                    }
+        // Parameters are named and validated like the properties
+        for (key, value) in params {
+            let key = key.as_str();
+            match key {
+                "config" => settings.config_file = Some(value),
+                "config-json5" => settings.config_json5 = Some(value),
+                "scouting" => settings.scouting = uri::parse_bool(key, &value)?,
+                "endpoints-only" => settings.endpoints_only = uri::parse_bool(key, &value)?,
+                "tls-root-ca" => settings.tls_root_ca = Some(value),
+                "tls-client-cert" => settings.tls_client_cert = Some(value),
+                "tls-client-key" => settings.tls_client_key = Some(value),
+                "username" => settings.username = Some(value),
+                "password" => settings.password = Some(value),
+                "priority" => settings.priority = uri::parse_in_range(key, &value, 1..=7)?,
+                "congestion-control" => {
+                    settings.congestion_control =
+                        uri::parse_choice(key, value, &["block", "drop"])?;
+                }
+                "reliability" => {
+                    settings.reliability =
+                        uri::parse_choice(key, value, &["best-effort", "reliable"])?;
+                }
+                "express" => settings.express = uri::parse_bool(key, &value)?,
+                "receive-timeout-ms" => {
+                    let timeout: u64 = uri::parse_value(key, &value)?;
+                    // Clamp to valid range
+                    settings.receive_timeout_ms = timeout.clamp(10, 5000);
+                }
+                "apply-buffer-meta" => settings.apply_buffer_meta = uri::parse_bool(key, &value)?,
+                "mark-discont" => settings.mark_discont = uri::parse_bool(key, &value)?,
+                "zero-copy" => settings.zero_copy = uri::parse_bool(key, &value)?,
+                "max-buffer-size" => settings.max_buffer_size = uri::parse_value(key, &value)?,
+                "buffer-pool-size" => settings.buffer_pool_size = uri::parse_value(key, &value)?,
+                "buffer-pool-max" => settings.buffer_pool_max = uri::parse_value(key, &value)?,
+                "use-downstream-pool" => {
+                    settings.use_downstream_pool = uri::parse_bool(key, &value)?;
+                }
+                "max-lateness-ms" => settings.max_lateness_ms = uri::parse_value(key, &value)?,
+                "reorder-window" => {
+                    settings.reorder_window =
+                        uri::parse_in_range(key, &value, 0..=MAX_REORDER_WINDOW)?;
+                }
+                "reorder-timeout-ms" => {
+                    settings.reorder_timeout_ms = uri::parse_value(key, &value)?;
+                }
+                "no-data-timeout-ms" => {
+                    settings.no_data_timeout_ms = uri::parse_value(key, &value)?;
+                }
+                "declaration-delay-ms" => {
+                    settings.declaration_delay_ms = uri::parse_value(key, &value)?;
+                }
+                "connectivity-poll-ms" => {
+                    settings.connectivity_poll_ms = uri::parse_in_range(key, &value, 0..=60_000)?;
+                }
+                "session-group" => settings.session_group = Some(value),
+                "session-group-linger-ms" => {
+                    let max = crate::session::MAX_SESSION_GROUP_LINGER_MS;
+                    settings.session_group_linger_ms = uri::parse_in_range(key, &value, 0..=max)?;
+                }
+                "reconnect" => settings.reconnect = uri::parse_bool(key, &value)?,
+                "caps" => settings.caps = Some(uri::parse_value(key, &value)?),
+                "force-caps" => settings.force_caps = uri::parse_bool(key, &value)?,
+                "use-encoding-caps" => settings.use_encoding_caps = uri::parse_bool(key, &value)?,
+                "caps-channel" => settings.caps_channel = uri::parse_enum(key, &value)?,
+                "forward-events" => settings.forward_events = uri::parse_bool(key, &value)?,
+                "enable-fku" => settings.enable_fku = uri::parse_bool(key, &value)?,
+                "locality" => settings.locality = uri::parse_enum(key, &value)?,
+                "timestamp-mode" => settings.timestamp_mode = uri::parse_enum(key, &value)?,
+                "is-live" => self.obj().set_live(uri::parse_bool(key, &value)?),
+                "sample-kind-filter" => {
+                    settings.sample_kind_filter = uri::parse_enum(key, &value)?;
+                }
+                "drop-empty" => settings.drop_empty = uri::parse_bool(key, &value)?,
+                #[cfg(feature = "metrics-http")]
+                "metrics-port" => {
+                    settings.metrics_port = uri::parse_in_range(key, &value, 0..=u16::MAX as u32)?;
+                }
+                #[cfg(feature = "metrics-http")]
+                "metrics-address" => settings.metrics_address = value,
+                _ => {
+                    gst::warning!(CAT, imp = self, "Unknown URI parameter: {}", key);
                 }
             }
         }
@@ -1168,3 +3181,114 @@ impl URIHandlerImpl for ZenohSrc {
         Ok(())
     }
 }
+
+#[cfg(test)]
+impl ZenohSrc {
+    /// Runs a synthetic sample through the receive path of `create()` with the
+    /// current settings, bypassing the session and subscriber.
+    pub(crate) fn inject_sample(
+        &self,
+        payload: impl Into<zenoh::bytes::ZBytes>,
+        attachment: Option<zenoh::bytes::ZBytes>,
+    ) -> Result<gst::Buffer, gst::FlowError> {
+        let key_expr = zenoh::key_expr::KeyExpr::try_from("test/injected").unwrap();
+        let sample: zenoh::sample::Sample = zenoh::sample::SampleBuilder::put(key_expr, payload)
+            .attachment(attachment)
+            .into();
+        let settings = ReceiveSettings::from_settings(&self.settings.lock().unwrap());
+        self.sample_to_buffer(&sample, &settings, &Mutex::new(Statistics::default()), None)
+            .map(|(buffer, _)| buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::{MetadataBuilder, keys};
+
+    fn new_src() -> crate::zenohsrc::ZenohSrc {
+        gst::init().unwrap();
+        crate::zenohsrc::ZenohSrc::new("test/injected")
+    }
+
+    #[test]
+    fn test_injected_sample_applies_buffer_meta() {
+        let src = new_src();
+        let attachment = MetadataBuilder::new()
+            .pts(Some(gst::ClockTime::from_seconds(1)))
+            .duration(Some(gst::ClockTime::from_mseconds(40)))
+            .flags(gst::BufferFlags::DELTA_UNIT)
+            .build();
+
+        let buffer = src.imp().inject_sample(vec![1u8, 2, 3], attachment.clone()).unwrap();
+        assert_eq!(buffer.map_readable().unwrap().as_slice(), &[1, 2, 3]);
+        assert_eq!(buffer.pts(), Some(gst::ClockTime::from_seconds(1)));
+        assert_eq!(buffer.duration(), Some(gst::ClockTime::from_mseconds(40)));
+        assert!(buffer.flags().contains(gst::BufferFlags::DELTA_UNIT));
+
+        // Without apply-buffer-meta the sender's timing and flags are ignored
+        src.set_apply_buffer_meta(false);
+        let buffer = src.imp().inject_sample(vec![1u8, 2, 3], attachment).unwrap();
+        assert_eq!(buffer.pts(), None);
+        assert_eq!(buffer.duration(), None);
+        assert!(!buffer.flags().contains(gst::BufferFlags::DELTA_UNIT));
+    }
+
+    #[test]
+    fn test_injected_sample_without_attachment() {
+        let src = new_src();
+        let buffer = src.imp().inject_sample(vec![0u8; 16], None).unwrap();
+        assert_eq!(buffer.size(), 16);
+        assert_eq!(buffer.pts(), None);
+    }
+
+    #[test]
+    fn test_retime_keeps_pts_dts_offset() {
+        gst::init().unwrap();
+        let mut buffer = gst::Buffer::new();
+        let buffer_ref = buffer.get_mut().unwrap();
+        buffer_ref.set_pts(gst::ClockTime::from_mseconds(120));
+        buffer_ref.set_dts(gst::ClockTime::from_mseconds(80));
+
+        retime(buffer_ref, gst::ClockTime::from_seconds(5));
+        assert_eq!(buffer_ref.pts(), Some(gst::ClockTime::from_mseconds(5000)));
+        assert_eq!(buffer_ref.dts(), Some(gst::ClockTime::from_mseconds(4960)));
+
+        // A DTS that would fall before zero is dropped
+        buffer_ref.set_dts(gst::ClockTime::ZERO);
+        retime(buffer_ref, gst::ClockTime::from_mseconds(10));
+        assert_eq!(buffer_ref.dts(), None);
+    }
+
+    #[test]
+    fn test_injected_unknown_compression_is_refused() {
+        let src = new_src();
+        let attachment = MetadataBuilder::new()
+            .user_metadata(keys::COMPRESSION, "unknown")
+            .build();
+
+        assert_eq!(
+            src.imp().inject_sample(vec![1u8, 2, 3], attachment),
+            Err(gst::FlowError::NotSupported)
+        );
+    }
+
+    #[cfg(feature = "compression-zstd")]
+    #[test]
+    fn test_injected_sample_is_decompressed() {
+        use crate::compression::{CompressionType, compress};
+
+        let src = new_src();
+        let data: Vec<u8> = (0..4096u32).map(|i| (i % 7) as u8).collect();
+        let compressed = compress(&data, CompressionType::Zstd, 3).unwrap();
+        assert!(compressed.len() < data.len());
+        let attachment = MetadataBuilder::new()
+            .user_metadata(keys::COMPRESSION, CompressionType::Zstd.to_metadata_value())
+            .pts(Some(gst::ClockTime::from_mseconds(20)))
+            .build();
+
+        let buffer = src.imp().inject_sample(compressed, attachment).unwrap();
+        assert_eq!(buffer.map_readable().unwrap().as_slice(), data.as_slice());
+        assert_eq!(buffer.pts(), Some(gst::ClockTime::from_mseconds(20)));
+    }
+}
@@ -19,13 +19,114 @@
 //!   - Supports Zenoh key expression wildcards like "*" and "**"
 //! * `config` - Path to Zenoh configuration file (optional)
 //!   - Allows custom Zenoh network configuration (endpoints, discovery, etc.)
-//! * `priority` - Subscriber priority level (1-7, default: 5)
+//! * `config-json5` - Inline Zenoh configuration in JSON5 (optional, overrides `config`)
+//! * `scouting` - Discover peers via multicast scouting (default: true)
+//!   - Disable on networks where multicast doesn't work; peers are then reached
+//!     through the endpoints of the configuration
+//! * `endpoints-only` - Connect only to the configured endpoints (default: false)
+//!   - Client mode without multicast scouting, for routers on other subnets;
+//!     starting fails when the configuration has no `connect/endpoints`
+//! * `tls-root-ca`, `tls-client-cert`, `tls-client-key` - TLS certificate files (optional)
+//!   - Override the matching `transport/link/tls` keys of the configuration file
+//! * `username`, `password` - Zenoh usrpwd authentication (optional, `password` is write-only)
+//! * `priority` - Expected priority level (informational, 1-7, default: 5)
 //!   - 1=RealTime (highest), 2=InteractiveHigh, 3=InteractiveLow, 4=DataHigh, 5=Data(default), 6=DataLow, 7=Background(lowest)
+//!   - Zenoh 1.x subscribers can't declare a priority: samples carry the one their
+//!     publisher set (see `rx-priority`)
 //! * `congestion-control` - Congestion control policy (informational, default: "block")
 //!   - Mainly for configuration consistency with zenohsink
 //! * `reliability` - Expected reliability mode (informational, default: "best-effort")
 //!   - Actual reliability is determined by the matching publisher
+//!   - Zenoh 1.x subscribers can't declare a reliability, so it is not passed to Zenoh
 //!   - Used for documentation and pipeline validation
+//! * `express` - Express mode, as on zenohsink (informational, default: false)
+//!   - Zenoh 1.x subscribers can't request express delivery; the publishers' setting applies
+//! * `reconnect` - Re-declare the subscriber when it gets disconnected (default: false)
+//!   - The session is checked for routers and peers every 500ms while no data arrives
+//!   - Once one is back, checked with exponential backoff (100ms up to 5s), the
+//!     subscriber is re-declared; errors out after 10 attempts or on a closed session
+//!   - Emits the `reconnecting` signal with the attempt number before each retry
+//! * `mark-discont` - Set `DISCONT` on buffers following a discontinuity (default: true)
+//!   - The first buffer, the first after a reconnection, and the first after a gap in
+//!     zenohsink's `sequence-numbers`, whatever flags the sender set
+//! * `caps` - Fixed output caps (optional)
+//!   - Needed when the publisher doesn't send `gst.caps` metadata (e.g. non-GStreamer publishers)
+//!   - Caps received in metadata take precedence unless `force-caps` is set
+//! * `force-caps` - Always use the `caps` property, ignoring metadata caps (default: false)
+//! * `use-encoding-caps` - Derive caps from the Zenoh sample encoding (default: false)
+//!   - Applies when no `gst.caps` metadata is received and `caps` is unset
+//!   - Common encodings (`video/h264`, `image/jpeg`, `audio/aac`, ...) are mapped;
+//!     more can be added with [`crate::encoding::register_encoding_caps`]
+//!   - Unknown encodings leave the caps as ANY
+//! * `caps-channel` - Where publishers send caps, as set on zenohsink (default: attachment)
+//!   - `sidecar-key`: Subscribes to `<key-expr>/caps`; the caps received there apply
+//!     to the following samples, and caps messages matched by a wildcard
+//!     `key-expr` aren't turned into buffers
+//! * `forward-events` - Push the events zenohsink publishes with `forward-events=true`
+//!   downstream (default: false)
+//!   - Subscribes to `<key-expr>/events`; EOS and custom downstream events are
+//!     re-injected, in no guaranteed order relative to the data samples
+//! * `enable-fku` - Relay upstream force-key-unit events to the publishers (default: false)
+//!   - Published on `<key-expr>/fku` for zenohsink with `enable-fku=true`, which
+//!     pushes them upstream toward its encoder; the running time is left out
+//!   - With a wildcard `key-expr` every matching publisher is asked for a key unit
+//! * `locality` - Publishers to receive from (default: any)
+//!   - `any`: Same session and remote publishers
+//!   - `session-local`: Only publishers declared on the same Zenoh session
+//!   - `remote`: Only publishers on other sessions (prevents local echo)
+//! * `zero-copy` - Wrap contiguous payloads in buffers without copying (default: true)
+//!   - Buffers are read-only views of the Zenoh payload; fragmented or compressed
+//!     payloads are still copied
+//! * `max-buffer-size` - Drop samples larger than this many bytes (default: 0 = unlimited)
+//!   - Dropped samples are counted in `errors`
+//!   - Also caps the decompressed size of compressed samples
+//! * `buffer-pool-size` - Buffers preallocated by a pool for copied payloads (default: 0 = no pool)
+//!   - Used for the payloads that are copied (`zero-copy=false`, fragmented payloads);
+//!     the pool buffers fit the largest payload seen so far
+//! * `buffer-pool-max` - Pool buffers in use at once (default: 0 = unlimited)
+//!   - Beyond it, payloads get a newly allocated buffer instead of waiting for one
+//! * `use-downstream-pool` - Copy payloads into the pool downstream proposes (default: false)
+//!   - For memory downstream controls, e.g. DMA buffers of a hardware decoder;
+//!     compressed payloads and those that don't fit a pool buffer aren't
+//! * `max-lateness-ms` - Drop samples older than this according to their Zenoh timestamp (0 = off)
+//!   - Lets a live consumer that fell behind skip stale data; counted in `dropped-late`
+//! * `reorder-window` - Samples held per key to output them in sequence number order (0 = off)
+//!   - Needs zenohsink's `sequence-numbers`; samples without one pass through
+//!   - A missing sample is skipped once the window is full or `reorder-timeout-ms`
+//!     (default: 200, 0 = no limit) ran out, and dropped as late if it arrives afterwards
+//!   - Needs timestamped samples and synchronized clocks; untimestamped samples are kept
+//! * `declaration-delay-ms` - Wait after declaring the subscriber before start completes (0 = off)
+//!   - Zenoh doesn't acknowledge declarations; samples published before the declaration
+//!     reached the routers and peers are lost, so this makes PLAYING mean ready to receive
+//! * `connectivity-poll-ms` - Interval of the session connectivity check (default: 1000, 0 = off)
+//!   - Drives the `session-disconnected` and `session-reconnected` signals
+//! * `session-group-linger-ms` - Keep the `session-group` session open once released
+//!   (default: 0 = close right away)
+//!   - The session of a group is closed when its last element stops; with a linger,
+//!     elements briefly overlapping reuse it instead of reopening one
+//! * `no-data-timeout-ms` - Post `zenoh-stream-idle` when no data arrives for this long (0 = off)
+//!   - `zenoh-stream-resumed` is posted when data arrives again
+//!   - Checked on every receive poll, so precision is `receive-timeout-ms` (at most 100ms)
+//! * `timestamp-mode` - How buffer PTS is derived (default: arrival)
+//!   - `none`: Only the PTS sent by zenohsink in the buffer metadata
+//!   - `zenoh`: The Zenoh timestamp as an absolute time, when no PTS was sent
+//!   - `arrival`: Pipeline running time when the sample is received
+//!   - `pipeline-clock`: Arrival running time minus the age of the Zenoh timestamp
+//! * `is-live` - Act as a live source (default: true)
+//!   - Live, buffers left untimed (`none`, `zenoh`) are stamped with their arrival running time
+//! * `sample-kind-filter` - Kinds of samples delivered (default: any)
+//!   - `put` or `delete` drop the other kind before a buffer is produced,
+//!     counted in `dropped-filtered`
+//!   - With `put`, DELETE samples never reach the `eos` signal
+//! * `drop-empty` - Drop zero-length PUT samples (default: false)
+//!   - Counted in `dropped-filtered`; without it they go downstream as empty buffers
+//!   - DELETEs, empty by nature, are still delivered and emit `eos` (filter them
+//!     with `sample-kind-filter=put`), and so are the GAP buffers zenohsink
+//!     publishes for gap events
+//! * `metrics-port` - Serve the statistics to Prometheus on this port (default: 0 = off)
+//!   - Requires the `metrics-http` feature; scraped from `http://<host>:<port>/metrics`
+//! * `metrics-address` - Address the metrics endpoint listens on (default: 127.0.0.1)
+//!   - Only local scrapers reach the default; use 0.0.0.0 to serve every interface
 //!
 //! ## Example Pipelines
 //!
@@ -49,6 +150,13 @@
 //! gst-launch-1.0 zenohsrc key-expr=demo/audio/stream ! audioconvert ! autoaudiosink
 //! ```
 //!
+//! ### Raw Stream from a Non-GStreamer Publisher
+//! ```bash
+//! # Publisher sends plain H.264 byte-stream without caps metadata
+//! gst-launch-1.0 zenohsrc key-expr=camera/h264 caps="video/x-h264,stream-format=byte-stream" ! \
+//!   h264parse ! avdec_h264 ! videoconvert ! autovideosink
+//! ```
+//!
 //! ### Multiple Stream Subscription with Wildcards
 //! ```bash
 //! # Subscribe to all streams from a specific device
@@ -60,8 +168,14 @@ use gst::glib;
 use gst::prelude::*;
 use gst::subclass::prelude::ObjectSubclassIsExt;
 
+use crate::metadata::CapsChannel;
+use crate::qos::ZenohLocality;
+
 pub mod imp;
 
+// Re-export the enum types for public API
+pub use imp::{SampleKindFilter, TimestampMode};
+
 glib::wrapper! {
     /// A GStreamer source element that subscribes to data via Zenoh.
     ///
@@ -168,7 +282,68 @@ impl ZenohSrc {
         self.set_property("config", config_path);
     }
 
-    /// Sets the subscriber priority level.
+    /// Sets the Zenoh configuration inline, as a JSON5 string.
+    ///
+    /// Takes precedence over [`set_config`](Self::set_config), which is handy
+    /// where mounting a configuration file is impractical (containers,
+    /// gst-launch). Scouting, TLS and authentication properties still apply
+    /// on top. Malformed JSON5 makes the start fail with the parse error.
+    pub fn set_config_json5(&self, json5: &str) {
+        self.set_property("config-json5", json5);
+    }
+
+    /// Enables or disables multicast scouting.
+    ///
+    /// Disable it on networks where multicast doesn't work, without having
+    /// to write a configuration file. Must be set before the element is started.
+    pub fn set_scouting(&self, enabled: bool) {
+        self.set_property("scouting", enabled);
+    }
+
+    /// Connects only to the endpoints of the configuration.
+    ///
+    /// Uses client mode with multicast scouting disabled, the usual setup to
+    /// reach a known router on another subnet. Starting fails when the
+    /// configuration has no `connect/endpoints`. Must be set before the
+    /// element is started.
+    pub fn set_endpoints_only(&self, enabled: bool) {
+        self.set_property("endpoints-only", enabled);
+    }
+
+    /// Sets the CA certificate used to verify TLS routers and peers.
+    ///
+    /// Overrides `transport/link/tls/root_ca_certificate` of the configuration file.
+    pub fn set_tls_root_ca(&self, path: &str) {
+        self.set_property("tls-root-ca", path);
+    }
+
+    /// Sets the client certificate presented on TLS links (mutual TLS).
+    ///
+    /// Must be set together with [`set_tls_client_key`](Self::set_tls_client_key).
+    pub fn set_tls_client_cert(&self, path: &str) {
+        self.set_property("tls-client-cert", path);
+    }
+
+    /// Sets the private key of the TLS client certificate.
+    pub fn set_tls_client_key(&self, path: &str) {
+        self.set_property("tls-client-key", path);
+    }
+
+    /// Sets the user name for usrpwd authentication.
+    ///
+    /// Must be set together with [`set_password`](Self::set_password).
+    pub fn set_username(&self, username: &str) {
+        self.set_property("username", username);
+    }
+
+    /// Sets the password for usrpwd authentication.
+    ///
+    /// The property is write-only and the password is never logged.
+    pub fn set_password(&self, password: &str) {
+        self.set_property("password", password);
+    }
+
+    /// Sets the expected priority level (informational).
     ///
     /// Valid values: 1-7
     /// - 1: RealTime (highest priority)
@@ -178,6 +353,11 @@ impl ZenohSrc {
     /// - 5: Data (default)
     /// - 6: DataLow
     /// - 7: Background (lowest priority)
+    ///
+    /// Note: Zenoh 1.x subscribers have no way to declare a priority, samples
+    /// are delivered with the one of their publisher (see
+    /// [`rx_priority`](Self::rx_priority)). The value is only logged when the
+    /// subscriber is declared.
     pub fn set_priority(&self, priority: u32) {
         self.set_property("priority", priority);
     }
@@ -197,15 +377,27 @@ impl ZenohSrc {
     /// - `"best-effort"`: Fire-and-forget delivery (default)
     /// - `"reliable"`: Acknowledged delivery with retransmission
     ///
-    /// Note: Actual reliability is determined by the publisher.
+    /// Note: Actual reliability is determined by the publisher. Zenoh 1.x
+    /// subscribers have no way to declare a reliability, so the value is only
+    /// logged when the subscriber is declared.
     pub fn set_reliability(&self, mode: &str) {
         self.set_property("reliability", mode);
     }
 
+    /// Sets express mode (informational), named as on zenohsink.
+    ///
+    /// Zenoh 1.x subscribers can't request express delivery: it is set by
+    /// each publisher (zenohsink's `express`). The value is only logged when
+    /// the subscriber is declared.
+    pub fn set_express(&self, express: bool) {
+        self.set_property("express", express);
+    }
+
     /// Sets the receive timeout in milliseconds.
     ///
-    /// Lower values increase responsiveness but use more CPU.
-    /// Higher values reduce CPU usage but slow down state changes.
+    /// This is how long each poll of the subscriber waits for a sample. Values
+    /// above 100ms are capped internally, so state changes never wait longer
+    /// than that for a pending receive. Lower values use more CPU.
     /// Valid range: 10-5000ms, default: 100ms.
     pub fn set_receive_timeout_ms(&self, timeout: u64) {
         self.set_property("receive-timeout-ms", timeout);
@@ -214,11 +406,208 @@ impl ZenohSrc {
     /// Enables or disables applying buffer timing metadata from received messages.
     ///
     /// When enabled, PTS, DTS, duration, offset, and flags are restored
-    /// from the sender's buffer metadata for proper A/V sync.
+    /// from the sender's buffer metadata for proper A/V sync, and the segment
+    /// rate and base time sent by zenohsink are applied to the output segment.
     pub fn set_apply_buffer_meta(&self, apply: bool) {
         self.set_property("apply-buffer-meta", apply);
     }
 
+    /// Enables or disables marking discontinuities.
+    ///
+    /// When enabled, the first buffer, the first after a reconnection and the
+    /// first after a sequence number gap carry `BufferFlags::DISCONT`, so
+    /// parsers and decoders resynchronize whatever flags the sender set.
+    pub fn set_mark_discont(&self, enabled: bool) {
+        self.set_property("mark-discont", enabled);
+    }
+
+    /// Enables or disables zero-copy reception.
+    ///
+    /// When enabled, contiguous payloads are wrapped in buffers instead of
+    /// being copied. The buffers are read-only; mapping one writable copies it.
+    pub fn set_zero_copy(&self, zero_copy: bool) {
+        self.set_property("zero-copy", zero_copy);
+    }
+
+    /// Sets the maximum accepted payload size in bytes (0 = unlimited).
+    ///
+    /// Larger samples are dropped before any buffer is allocated for them
+    /// and counted as errors. Compressed samples that decompress to more
+    /// fail to decode.
+    pub fn set_max_buffer_size(&self, size: u64) {
+        self.set_property("max-buffer-size", size);
+    }
+
+    /// Sets how many buffers the pool for copied payloads preallocates
+    /// (0 = no pool, each copied payload gets a new allocation).
+    ///
+    /// Must be set before the element is started.
+    pub fn set_buffer_pool_size(&self, size: u32) {
+        self.set_property("buffer-pool-size", size);
+    }
+
+    /// Sets how many pool buffers can be in use at once (0 = unlimited).
+    ///
+    /// Payloads arriving while all are in use get a newly allocated buffer.
+    /// Must be 0 or at least `buffer-pool-size`, and set before the element
+    /// is started.
+    pub fn set_buffer_pool_max(&self, max: u32) {
+        self.set_property("buffer-pool-max", max);
+    }
+
+    /// Enables or disables copying payloads into the buffer pool proposed by
+    /// downstream in the allocation query.
+    ///
+    /// Lets downstream control the memory, e.g. DMA buffers for a hardware
+    /// decoder, at the cost of a copy. Payloads that don't fit a pool buffer,
+    /// compressed ones and those arriving while none is free get buffers of
+    /// their own. Can be changed while running.
+    pub fn set_use_downstream_pool(&self, enabled: bool) {
+        self.set_property("use-downstream-pool", enabled);
+    }
+
+    /// Sets the maximum age of a sample, from its Zenoh timestamp, before it is
+    /// dropped instead of delivered (0 = disabled). Can be changed while running.
+    ///
+    /// Samples without a timestamp are never dropped.
+    pub fn set_max_lateness_ms(&self, lateness: u64) {
+        self.set_property("max-lateness-ms", lateness);
+    }
+
+    /// Sets how many samples are held per key to restore the order of their
+    /// sequence numbers (0 = disabled).
+    ///
+    /// Needs zenohsink's `sequence-numbers`. A missing sample is skipped once
+    /// the window is full, and counted in `dropped-late` if it arrives
+    /// afterwards. Must be set before the element is started.
+    pub fn set_reorder_window(&self, window: u32) {
+        self.set_property("reorder-window", window);
+    }
+
+    /// Sets the longest a sample is held by `reorder-window` waiting for the
+    /// ones before it (0 = no limit, default: 200).
+    ///
+    /// Must be set before the element is started.
+    pub fn set_reorder_timeout_ms(&self, timeout: u32) {
+        self.set_property("reorder-timeout-ms", timeout);
+    }
+
+    /// Sets how long without data before a `zenoh-stream-idle` message is
+    /// posted (0 = disabled). Can be changed while running.
+    pub fn set_no_data_timeout_ms(&self, timeout: u64) {
+        self.set_property("no-data-timeout-ms", timeout);
+    }
+
+    /// Sets how long to wait after declaring the subscriber before the state
+    /// change completes (0 = disabled, at most 10000).
+    ///
+    /// Zenoh declarations aren't acknowledged, so samples published right
+    /// after start can be missed until the declaration has reached the
+    /// routers and peers. Takes effect on the next start.
+    pub fn set_declaration_delay_ms(&self, delay: u64) {
+        self.set_property("declaration-delay-ms", delay);
+    }
+
+    /// Sets how often the session is checked for router and peer connections,
+    /// for the `session-disconnected` and `session-reconnected` signals
+    /// (0 = disabled, at most 60000). Must be set before the element is started.
+    pub fn set_connectivity_poll_ms(&self, interval: u32) {
+        self.set_property("connectivity-poll-ms", interval);
+    }
+
+    /// Enables or disables automatic reconnection.
+    ///
+    /// When enabled, a disconnected subscriber is re-declared with capped
+    /// exponential backoff instead of failing the pipeline with an error.
+    pub fn set_reconnect(&self, reconnect: bool) {
+        self.set_property("reconnect", reconnect);
+    }
+
+    /// Sets fixed output caps.
+    ///
+    /// Used when the publisher doesn't send caps metadata. Caps received in
+    /// metadata still take precedence unless [`set_force_caps`](Self::set_force_caps)
+    /// is enabled. Named `set_fixed_caps` to avoid clashing with `BaseSrcExt::set_caps`.
+    pub fn set_fixed_caps(&self, caps: &gst::Caps) {
+        self.set_property("caps", caps);
+    }
+
+    /// Forces the fixed caps, ignoring caps received in metadata.
+    pub fn set_force_caps(&self, force: bool) {
+        self.set_property("force-caps", force);
+    }
+
+    /// Derives output caps from the Zenoh sample encoding when no caps
+    /// metadata is received.
+    pub fn set_use_encoding_caps(&self, enabled: bool) {
+        self.set_property("use-encoding-caps", enabled);
+    }
+
+    /// Sets where publishers send caps, matching zenohsink's `caps-channel`.
+    ///
+    /// With [`CapsChannel::SidecarKey`], caps are received on `<key-expr>/caps`.
+    /// Must be set before the element is started.
+    pub fn set_caps_channel(&self, channel: CapsChannel) {
+        self.set_property("caps-channel", channel);
+    }
+
+    /// Enables or disables receiving events published by zenohsink.
+    ///
+    /// EOS and custom downstream events received on `<key-expr>/events` are
+    /// pushed downstream. Must be set before the element is started.
+    pub fn set_forward_events(&self, enabled: bool) {
+        self.set_property("forward-events", enabled);
+    }
+
+    /// Enables or disables relaying force-key-unit events to the publishers.
+    ///
+    /// Upstream force-key-unit events, as sent by a decoder that lost a frame,
+    /// are published on `<key-expr>/fku` for zenohsink with `enable-fku=true`.
+    /// Must be set before the element is started.
+    pub fn set_enable_fku(&self, enabled: bool) {
+        self.set_property("enable-fku", enabled);
+    }
+
+    /// Restricts which publishers data is received from.
+    ///
+    /// Use [`ZenohLocality::Remote`] to ignore publishers declared on the
+    /// same session (no local echo in peer-to-peer setups).
+    pub fn set_locality(&self, locality: ZenohLocality) {
+        self.set_property("locality", locality);
+    }
+
+    /// Sets how the PTS of output buffers is derived.
+    ///
+    /// Can be changed while running; takes effect with the next sample.
+    pub fn set_timestamp_mode(&self, mode: TimestampMode) {
+        self.set_property("timestamp-mode", mode);
+    }
+
+    /// Sets whether the source is live.
+    ///
+    /// Must be set before starting.
+    pub fn set_is_live(&self, is_live: bool) {
+        self.set_property("is-live", is_live);
+    }
+
+    /// Restricts the delivered samples to PUTs or DELETEs.
+    ///
+    /// Can be changed while running; samples of the other kind are counted
+    /// in [`dropped_filtered`](Self::dropped_filtered).
+    pub fn set_sample_kind_filter(&self, filter: SampleKindFilter) {
+        self.set_property("sample-kind-filter", filter);
+    }
+
+    /// Enables or disables dropping zero-length PUT samples.
+    ///
+    /// Some elements mishandle empty buffers; with this enabled such samples
+    /// are counted in [`dropped_filtered`](Self::dropped_filtered) instead.
+    /// DELETE samples and gaps published by zenohsink are still delivered.
+    /// Can be changed while running.
+    pub fn set_drop_empty(&self, drop_empty: bool) {
+        self.set_property("drop-empty", drop_empty);
+    }
+
     /// Sets a shared Zenoh session for this element.
     ///
     /// This allows multiple elements to share a single Zenoh session,
@@ -257,6 +646,34 @@ impl ZenohSrc {
         self.set_property("session-group", group);
     }
 
+    /// Sets how long in milliseconds the session-group session stays open
+    /// after this element was the last one to release it (0 = close right away).
+    ///
+    /// An element joining the group meanwhile reuses the session instead of
+    /// opening a new one. Must be set before the element leaves NULL.
+    pub fn set_session_group_linger_ms(&self, linger: u32) {
+        self.set_property("session-group-linger-ms", linger);
+    }
+
+    /// Sets the port serving the statistics in Prometheus text format on
+    /// `/metrics` while the element is started (0 = disabled).
+    ///
+    /// Takes effect on the next start. If the port can't be bound, a warning
+    /// is posted and the element runs without the endpoint.
+    #[cfg(feature = "metrics-http")]
+    pub fn set_metrics_port(&self, port: u16) {
+        self.set_property("metrics-port", port as u32);
+    }
+
+    /// Sets the address the `metrics-port` endpoint listens on (default:
+    /// 127.0.0.1, reachable from the local host only).
+    ///
+    /// Takes effect on the next start. Use `0.0.0.0` to serve every interface.
+    #[cfg(feature = "metrics-http")]
+    pub fn set_metrics_address(&self, address: &str) {
+        self.set_property("metrics-address", address);
+    }
+
     // -------------------------------------------------------------------------
     // Property Getters
     // -------------------------------------------------------------------------
@@ -271,6 +688,41 @@ impl ZenohSrc {
         self.property("config")
     }
 
+    /// Returns the inline JSON5 configuration, if set.
+    pub fn config_json5(&self) -> Option<String> {
+        self.property("config-json5")
+    }
+
+    /// Returns whether multicast scouting is enabled.
+    pub fn scouting(&self) -> bool {
+        self.property("scouting")
+    }
+
+    /// Returns whether only the configured endpoints are used.
+    pub fn endpoints_only(&self) -> bool {
+        self.property("endpoints-only")
+    }
+
+    /// Returns the path to the TLS CA certificate, if set.
+    pub fn tls_root_ca(&self) -> Option<String> {
+        self.property("tls-root-ca")
+    }
+
+    /// Returns the path to the TLS client certificate, if set.
+    pub fn tls_client_cert(&self) -> Option<String> {
+        self.property("tls-client-cert")
+    }
+
+    /// Returns the path to the TLS client key, if set.
+    pub fn tls_client_key(&self) -> Option<String> {
+        self.property("tls-client-key")
+    }
+
+    /// Returns the user name for usrpwd authentication, if set.
+    pub fn username(&self) -> Option<String> {
+        self.property("username")
+    }
+
     /// Returns the current priority level (1-7).
     pub fn priority(&self) -> u32 {
         self.property("priority")
@@ -286,6 +738,11 @@ impl ZenohSrc {
         self.property("reliability")
     }
 
+    /// Returns whether express mode is set.
+    pub fn express(&self) -> bool {
+        self.property("express")
+    }
+
     /// Returns the receive timeout in milliseconds.
     pub fn receive_timeout_ms(&self) -> u64 {
         self.property("receive-timeout-ms")
@@ -296,11 +753,347 @@ impl ZenohSrc {
         self.property("apply-buffer-meta")
     }
 
+    /// Returns whether discontinuities are marked with `DISCONT`.
+    pub fn mark_discont(&self) -> bool {
+        self.property("mark-discont")
+    }
+
+    /// Returns whether zero-copy reception is enabled.
+    pub fn zero_copy(&self) -> bool {
+        self.property("zero-copy")
+    }
+
+    /// Returns the maximum accepted payload size in bytes (0 = unlimited).
+    pub fn max_buffer_size(&self) -> u64 {
+        self.property("max-buffer-size")
+    }
+
+    /// Returns how many buffers the payload pool preallocates (0 = no pool).
+    pub fn buffer_pool_size(&self) -> u32 {
+        self.property("buffer-pool-size")
+    }
+
+    /// Returns how many pool buffers can be in use at once (0 = unlimited).
+    pub fn buffer_pool_max(&self) -> u32 {
+        self.property("buffer-pool-max")
+    }
+
+    /// Returns whether payloads are copied into the pool downstream proposes.
+    pub fn use_downstream_pool(&self) -> bool {
+        self.property("use-downstream-pool")
+    }
+
+    /// Returns the maximum sample age in milliseconds (0 = disabled).
+    pub fn max_lateness_ms(&self) -> u64 {
+        self.property("max-lateness-ms")
+    }
+
+    /// Returns how many samples are held per key for reordering (0 = disabled).
+    pub fn reorder_window(&self) -> u32 {
+        self.property("reorder-window")
+    }
+
+    /// Returns the longest a sample is held for reordering in milliseconds (0 = no limit).
+    pub fn reorder_timeout_ms(&self) -> u32 {
+        self.property("reorder-timeout-ms")
+    }
+
+    /// Returns the idle-stream timeout in milliseconds (0 = disabled).
+    pub fn no_data_timeout_ms(&self) -> u64 {
+        self.property("no-data-timeout-ms")
+    }
+
+    /// Returns the wait after declaring the subscriber in milliseconds (0 = disabled).
+    pub fn declaration_delay_ms(&self) -> u64 {
+        self.property("declaration-delay-ms")
+    }
+
+    /// Returns the session connectivity check interval in milliseconds (0 = disabled).
+    pub fn connectivity_poll_ms(&self) -> u32 {
+        self.property("connectivity-poll-ms")
+    }
+
     /// Returns the session group name, if set.
     pub fn session_group(&self) -> Option<String> {
         self.property("session-group")
     }
 
+    /// Returns how long the session-group session lingers once released, in milliseconds.
+    pub fn session_group_linger_ms(&self) -> u32 {
+        self.property("session-group-linger-ms")
+    }
+
+    /// Returns the port of the Prometheus statistics endpoint (0 = disabled).
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_port(&self) -> u16 {
+        self.property::<u32>("metrics-port") as u16
+    }
+
+    /// Returns the address the Prometheus statistics endpoint listens on.
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_address(&self) -> String {
+        self.property("metrics-address")
+    }
+
+    /// Returns whether automatic reconnection is enabled.
+    pub fn reconnect(&self) -> bool {
+        self.property("reconnect")
+    }
+
+    /// Returns the fixed output caps, if set.
+    pub fn fixed_caps(&self) -> Option<gst::Caps> {
+        self.property("caps")
+    }
+
+    /// Returns whether the fixed caps override metadata caps.
+    pub fn force_caps(&self) -> bool {
+        self.property("force-caps")
+    }
+
+    /// Returns whether caps are derived from the sample encoding.
+    pub fn use_encoding_caps(&self) -> bool {
+        self.property("use-encoding-caps")
+    }
+
+    /// Returns where caps are expected.
+    pub fn caps_channel(&self) -> CapsChannel {
+        self.property("caps-channel")
+    }
+
+    /// Returns whether events received on `<key-expr>/events` are pushed downstream.
+    pub fn forward_events(&self) -> bool {
+        self.property("forward-events")
+    }
+
+    /// Returns whether force-key-unit events are relayed to the publishers.
+    pub fn enable_fku(&self) -> bool {
+        self.property("enable-fku")
+    }
+
+    /// Returns the allowed origin locality.
+    pub fn locality(&self) -> ZenohLocality {
+        self.property("locality")
+    }
+
+    /// Returns how the PTS of output buffers is derived.
+    pub fn timestamp_mode(&self) -> TimestampMode {
+        self.property("timestamp-mode")
+    }
+
+    /// Returns whether the source is live.
+    pub fn is_live(&self) -> bool {
+        self.property("is-live")
+    }
+
+    /// Returns the kinds of samples delivered.
+    pub fn sample_kind_filter(&self) -> SampleKindFilter {
+        self.property("sample-kind-filter")
+    }
+
+    /// Returns whether zero-length PUT samples are dropped.
+    pub fn drop_empty(&self) -> bool {
+        self.property("drop-empty")
+    }
+
+    // -------------------------------------------------------------------------
+    // Signals
+    // -------------------------------------------------------------------------
+
+    /// Connects to the `reconnecting` signal.
+    ///
+    /// The callback receives the attempt number (starting at 1) each time the
+    /// element tries to re-declare its subscriber after a disconnection.
+    /// It runs on the streaming thread, which it holds up while it runs.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use gstzenoh::ZenohSrc;
+    ///
+    /// let src = ZenohSrc::builder("demo/video").reconnect(true).build();
+    /// src.connect_reconnecting(|_src, attempt| {
+    ///     eprintln!("Zenoh link lost, reconnection attempt {}", attempt);
+    /// });
+    /// ```
+    pub fn connect_reconnecting<F: Fn(&Self, u32) + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect("reconnecting", false, move |values| {
+            let element = values[0].get::<gst::Element>().unwrap();
+            let src = ZenohSrc::try_from(element).unwrap();
+            let attempt = values[1].get::<u32>().unwrap();
+            f(&src, attempt);
+            None
+        })
+    }
+
+    /// Connects to the `first-buffer` signal.
+    ///
+    /// Emitted once per start, when the first sample has been received and is
+    /// about to be pushed downstream. Unlike reaching PLAYING, this means the
+    /// subscription is live and data is flowing, e.g. to start dependent
+    /// pipelines. It runs on the streaming thread.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use gstzenoh::ZenohSrc;
+    ///
+    /// let src = ZenohSrc::new("demo/video");
+    /// src.connect_first_buffer(|_src| {
+    ///     println!("Data is flowing");
+    /// });
+    /// ```
+    pub fn connect_first_buffer<F: Fn(&Self) + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect("first-buffer", false, move |values| {
+            let element = values[0].get::<gst::Element>().unwrap();
+            let src = ZenohSrc::try_from(element).unwrap();
+            f(&src);
+            None
+        })
+    }
+
+    /// Connects to the `caps-changed` signal.
+    ///
+    /// Emitted with the new caps when the caps of the output buffers change,
+    /// including the first caps after start, e.g. when a sender switches
+    /// resolution. The caps event has already been pushed downstream, ahead of
+    /// the first buffer with the new caps. It runs on the streaming thread.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use gstzenoh::ZenohSrc;
+    ///
+    /// let src = ZenohSrc::new("demo/video");
+    /// src.connect_caps_changed(|_src, caps| {
+    ///     println!("Now receiving {}", caps);
+    /// });
+    /// ```
+    pub fn connect_caps_changed<F: Fn(&Self, &gst::Caps) + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect("caps-changed", false, move |values| {
+            let element = values[0].get::<gst::Element>().unwrap();
+            let src = ZenohSrc::try_from(element).unwrap();
+            let caps = values[1].get::<gst::Caps>().unwrap();
+            f(&src, &caps);
+            None
+        })
+    }
+
+    /// Connects to the `eos` signal.
+    ///
+    /// Emitted with the sample's key expression when a Zenoh DELETE is
+    /// received, i.e. a publisher signalled the end of its stream. The
+    /// sample itself is still delivered (as an empty buffer), so this is a
+    /// notification only and does not end the pipeline. It runs on the
+    /// streaming thread.
+    pub fn connect_eos<F: Fn(&Self, &str) + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect("eos", false, move |values| {
+            let element = values[0].get::<gst::Element>().unwrap();
+            let src = ZenohSrc::try_from(element).unwrap();
+            let key_expr = values[1].get::<String>().unwrap();
+            f(&src, &key_expr);
+            None
+        })
+    }
+
+    /// Connects to the `session-disconnected` signal.
+    ///
+    /// Emitted when the session has lost its last router or peer, as seen by
+    /// the check every `connectivity-poll-ms`. The element keeps running;
+    /// see `reconnect` to recover the subscriber. It runs on a background
+    /// thread.
+    pub fn connect_session_disconnected<F: Fn(&Self) + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect("session-disconnected", false, move |values| {
+            let element = values[0].get::<gst::Element>().unwrap();
+            let src = ZenohSrc::try_from(element).unwrap();
+            f(&src);
+            None
+        })
+    }
+
+    /// Connects to the `session-reconnected` signal.
+    ///
+    /// Emitted when the session is connected to a router or peer again after
+    /// `session-disconnected`, or after starting without a connection. It
+    /// runs on a background thread.
+    pub fn connect_session_reconnected<F: Fn(&Self) + Send + Sync + 'static>(
+        &self,
+        f: F,
+    ) -> glib::SignalHandlerId {
+        self.connect("session-reconnected", false, move |values| {
+            let element = values[0].get::<gst::Element>().unwrap();
+            let src = ZenohSrc::try_from(element).unwrap();
+            f(&src);
+            None
+        })
+    }
+
+    // -------------------------------------------------------------------------
+    // Actions
+    // -------------------------------------------------------------------------
+
+    /// Logs a snapshot of the state, settings and statistics at INFO level.
+    ///
+    /// The block goes to the `zenohsrc` debug category, so `GST_DEBUG=zenohsrc:4`
+    /// shows it without per-buffer traces. Same as emitting `dump-state`.
+    pub fn dump_state(&self) {
+        self.emit_by_name::<()>("dump-state", &[]);
+    }
+
+    // -------------------------------------------------------------------------
+    // Session Introspection (read-only)
+    // -------------------------------------------------------------------------
+
+    /// Returns the Zenoh session used by this element, e.g. to declare extra
+    /// subscribers or run queries on it.
+    ///
+    /// `None` unless the element is started (PAUSED or PLAYING).
+    /// `zenoh::Session` is reference-counted, so this is a cheap clone. Its
+    /// lifetime is still tied to the element being started: the element
+    /// releases the session when it stops and opens a new one on its next
+    /// start, so undeclare whatever you declared on the handle by then
+    /// (holding the handle keeps the old session open).
+    pub fn session(&self) -> Option<zenoh::Session> {
+        self.imp().session()
+    }
+
+    /// Returns the Zenoh ID of the session used by this element.
+    ///
+    /// Empty when the element is stopped.
+    pub fn session_zid(&self) -> String {
+        self.property("session-zid")
+    }
+
+    /// Returns whether the session is shared rather than opened by this element.
+    ///
+    /// True with an external session or a `session-group` one, e.g. to check
+    /// that the group took effect. False when the element is stopped.
+    pub fn is_session_shared(&self) -> bool {
+        self.property("session-shared")
+    }
+
+    /// Returns the routers and peers the session is currently connected to.
+    ///
+    /// Entries read `router:<zid>` or `peer:<zid>`, routers first. Empty
+    /// when the element is stopped.
+    pub fn peers(&self) -> Vec<String> {
+        self.property("peers")
+    }
+
     // -------------------------------------------------------------------------
     // Statistics (read-only)
     // -------------------------------------------------------------------------
@@ -319,6 +1112,94 @@ impl ZenohSrc {
     pub fn errors(&self) -> u64 {
         self.property("errors")
     }
+
+    /// Returns the number of samples dropped for exceeding `max-lateness-ms`, or
+    /// for arriving after `reorder-window` skipped them.
+    pub fn dropped_late(&self) -> u64 {
+        self.property("dropped-late")
+    }
+
+    /// Returns the number of samples dropped for not matching `sample-kind-filter`,
+    /// or for being empty with `drop-empty`.
+    pub fn dropped_filtered(&self) -> u64 {
+        self.property("dropped-filtered")
+    }
+
+    /// Returns the number of serialized attachment bytes received.
+    ///
+    /// Metadata overhead on top of [`bytes_received`](Self::bytes_received).
+    pub fn attachment_bytes_received(&self) -> u64 {
+        self.property("attachment-bytes-received")
+    }
+
+    /// Returns the number of samples whose metadata attachment was malformed.
+    ///
+    /// Such samples are still delivered, without the metadata that couldn't
+    /// be read (e.g. caps).
+    pub fn metadata_errors(&self) -> u64 {
+        self.property("metadata-errors")
+    }
+
+    /// Returns the number of samples missing from the publishers' sequence numbers.
+    ///
+    /// Only counted for publishers with `sequence-numbers` enabled. A sample that
+    /// arrives after a gap was counted moves to [`samples_reordered`](Self::samples_reordered).
+    pub fn samples_lost(&self) -> u64 {
+        self.property("samples-lost")
+    }
+
+    /// Returns the number of samples received after a sample with a higher sequence number.
+    pub fn samples_reordered(&self) -> u64 {
+        self.property("samples-reordered")
+    }
+
+    /// Returns the incoming bitrate in bits per second over the last second.
+    ///
+    /// Drops back to 0 once no data has flowed for a full second.
+    pub fn bitrate(&self) -> u64 {
+        self.property("bitrate")
+    }
+
+    /// Returns the incoming message rate in messages per second over the last second.
+    pub fn message_rate(&self) -> f64 {
+        self.property("message-rate")
+    }
+
+    /// Returns the moving average of end-to-end latency in milliseconds.
+    ///
+    /// Computed from Zenoh sample timestamps, so it is only meaningful when
+    /// the sender and receiver clocks are synchronized. Stays at 0 when
+    /// samples carry no timestamp (timestamping disabled on the publisher side).
+    pub fn latency_ms(&self) -> f64 {
+        self.property("latency-ms")
+    }
+
+    /// Returns the highest end-to-end latency observed in milliseconds.
+    pub fn max_latency_ms(&self) -> f64 {
+        self.property("max-latency-ms")
+    }
+
+    /// Returns the priority of the last received sample (1=RealTime to
+    /// 7=Background), or 0 before any sample.
+    ///
+    /// Together with [`rx_express`](Self::rx_express), this shows the QoS the
+    /// samples actually carried, e.g. to check the publisher's configuration.
+    pub fn rx_priority(&self) -> u32 {
+        self.property("rx-priority")
+    }
+
+    /// Returns whether the last received sample was published with express.
+    pub fn rx_express(&self) -> bool {
+        self.property("rx-express")
+    }
+
+    /// Returns all statistics as one compact JSON object keyed by property name.
+    ///
+    /// The values are read atomically, so exporters get a consistent snapshot in
+    /// a single call, e.g. `{"bytes-received":1024,"messages-received":8,...}`.
+    pub fn stats_json(&self) -> String {
+        self.property("stats-json")
+    }
 }
 
 impl TryFrom<gst::Element> for ZenohSrc {
@@ -368,13 +1249,51 @@ impl TryFrom<gst::Element> for ZenohSrc {
 pub struct ZenohSrcBuilder {
     key_expr: String,
     config: Option<String>,
+    config_json5: Option<String>,
+    scouting: Option<bool>,
+    endpoints_only: Option<bool>,
+    tls_root_ca: Option<String>,
+    tls_client_cert: Option<String>,
+    tls_client_key: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
     priority: Option<u32>,
     congestion_control: Option<String>,
     reliability: Option<String>,
+    express: Option<bool>,
     receive_timeout_ms: Option<u64>,
     apply_buffer_meta: Option<bool>,
+    mark_discont: Option<bool>,
     session: Option<zenoh::Session>,
     session_group: Option<String>,
+    session_group_linger_ms: Option<u32>,
+    #[cfg(feature = "metrics-http")]
+    metrics_port: Option<u16>,
+    #[cfg(feature = "metrics-http")]
+    metrics_address: Option<String>,
+    reconnect: Option<bool>,
+    caps: Option<gst::Caps>,
+    force_caps: Option<bool>,
+    use_encoding_caps: Option<bool>,
+    caps_channel: Option<CapsChannel>,
+    forward_events: Option<bool>,
+    enable_fku: Option<bool>,
+    locality: Option<ZenohLocality>,
+    zero_copy: Option<bool>,
+    max_buffer_size: Option<u64>,
+    buffer_pool_size: Option<u32>,
+    buffer_pool_max: Option<u32>,
+    use_downstream_pool: Option<bool>,
+    no_data_timeout_ms: Option<u64>,
+    declaration_delay_ms: Option<u64>,
+    connectivity_poll_ms: Option<u32>,
+    max_lateness_ms: Option<u64>,
+    reorder_window: Option<u32>,
+    reorder_timeout_ms: Option<u32>,
+    timestamp_mode: Option<TimestampMode>,
+    is_live: Option<bool>,
+    sample_kind_filter: Option<SampleKindFilter>,
+    drop_empty: Option<bool>,
 }
 
 impl ZenohSrcBuilder {
@@ -383,13 +1302,51 @@ impl ZenohSrcBuilder {
         Self {
             key_expr: key_expr.to_string(),
             config: None,
+            config_json5: None,
+            scouting: None,
+            endpoints_only: None,
+            tls_root_ca: None,
+            tls_client_cert: None,
+            tls_client_key: None,
+            username: None,
+            password: None,
             priority: None,
             congestion_control: None,
             reliability: None,
+            express: None,
             receive_timeout_ms: None,
             apply_buffer_meta: None,
+            mark_discont: None,
             session: None,
             session_group: None,
+            session_group_linger_ms: None,
+            #[cfg(feature = "metrics-http")]
+            metrics_port: None,
+            #[cfg(feature = "metrics-http")]
+            metrics_address: None,
+            reconnect: None,
+            caps: None,
+            force_caps: None,
+            use_encoding_caps: None,
+            caps_channel: None,
+            forward_events: None,
+            enable_fku: None,
+            locality: None,
+            zero_copy: None,
+            max_buffer_size: None,
+            buffer_pool_size: None,
+            buffer_pool_max: None,
+            use_downstream_pool: None,
+            no_data_timeout_ms: None,
+            declaration_delay_ms: None,
+            connectivity_poll_ms: None,
+            max_lateness_ms: None,
+            reorder_window: None,
+            reorder_timeout_ms: None,
+            timestamp_mode: None,
+            is_live: None,
+            sample_kind_filter: None,
+            drop_empty: None,
         }
     }
 
@@ -399,7 +1356,45 @@ impl ZenohSrcBuilder {
         self
     }
 
-    /// Sets the subscriber priority level (1-7).
+    /// Sets the Zenoh configuration inline, as a JSON5 string (overrides `config`).
+    pub fn config_json5(mut self, json5: &str) -> Self {
+        self.config_json5 = Some(json5.to_string());
+        self
+    }
+
+    /// Enables or disables multicast scouting (default: true).
+    pub fn scouting(mut self, enabled: bool) -> Self {
+        self.scouting = Some(enabled);
+        self
+    }
+
+    /// Connects only to the configured endpoints, in client mode (default: false).
+    pub fn endpoints_only(mut self, enabled: bool) -> Self {
+        self.endpoints_only = Some(enabled);
+        self
+    }
+
+    /// Sets the CA certificate used to verify TLS routers and peers.
+    pub fn tls_root_ca(mut self, path: &str) -> Self {
+        self.tls_root_ca = Some(path.to_string());
+        self
+    }
+
+    /// Sets the client certificate and key for mutual TLS.
+    pub fn tls_client_cert(mut self, cert_path: &str, key_path: &str) -> Self {
+        self.tls_client_cert = Some(cert_path.to_string());
+        self.tls_client_key = Some(key_path.to_string());
+        self
+    }
+
+    /// Sets the user name and password for usrpwd authentication.
+    pub fn credentials(mut self, username: &str, password: &str) -> Self {
+        self.username = Some(username.to_string());
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Sets the expected priority level (1-7, informational).
     pub fn priority(mut self, priority: u32) -> Self {
         self.priority = Some(priority);
         self
@@ -417,6 +1412,12 @@ impl ZenohSrcBuilder {
         self
     }
 
+    /// Sets express mode (informational).
+    pub fn express(mut self, express: bool) -> Self {
+        self.express = Some(express);
+        self
+    }
+
     /// Sets the receive timeout in milliseconds.
     pub fn receive_timeout_ms(mut self, timeout: u64) -> Self {
         self.receive_timeout_ms = Some(timeout);
@@ -429,6 +1430,12 @@ impl ZenohSrcBuilder {
         self
     }
 
+    /// Enables or disables marking discontinuities with `DISCONT` (default: true).
+    pub fn mark_discont(mut self, enabled: bool) -> Self {
+        self.mark_discont = Some(enabled);
+        self
+    }
+
     /// Sets a shared Zenoh session for this element.
     ///
     /// This allows multiple elements to share a single Zenoh session,
@@ -450,6 +1457,174 @@ impl ZenohSrcBuilder {
         self
     }
 
+    /// Sets how long the session-group session lingers once released (default: 0 ms).
+    pub fn session_group_linger_ms(mut self, linger: u32) -> Self {
+        self.session_group_linger_ms = Some(linger);
+        self
+    }
+
+    /// Serves the statistics in Prometheus text format on this port.
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_port(mut self, port: u16) -> Self {
+        self.metrics_port = Some(port);
+        self
+    }
+
+    /// Sets the address the metrics endpoint listens on (default: 127.0.0.1).
+    #[cfg(feature = "metrics-http")]
+    pub fn metrics_address(mut self, address: &str) -> Self {
+        self.metrics_address = Some(address.to_string());
+        self
+    }
+
+    /// Enables or disables automatic reconnection.
+    pub fn reconnect(mut self, reconnect: bool) -> Self {
+        self.reconnect = Some(reconnect);
+        self
+    }
+
+    /// Sets fixed output caps for publishers that don't send caps metadata.
+    pub fn caps(mut self, caps: gst::Caps) -> Self {
+        self.caps = Some(caps);
+        self
+    }
+
+    /// Forces the fixed caps, ignoring caps received in metadata.
+    pub fn force_caps(mut self, force: bool) -> Self {
+        self.force_caps = Some(force);
+        self
+    }
+
+    /// Derives output caps from the Zenoh sample encoding.
+    pub fn use_encoding_caps(mut self, enabled: bool) -> Self {
+        self.use_encoding_caps = Some(enabled);
+        self
+    }
+
+    /// Sets where publishers send caps (default: attachment).
+    pub fn caps_channel(mut self, channel: CapsChannel) -> Self {
+        self.caps_channel = Some(channel);
+        self
+    }
+
+    /// Pushes the events received on `<key-expr>/events` downstream (default: false).
+    pub fn forward_events(mut self, enabled: bool) -> Self {
+        self.forward_events = Some(enabled);
+        self
+    }
+
+    /// Relays upstream force-key-unit events on `<key-expr>/fku` (default: false).
+    pub fn enable_fku(mut self, enabled: bool) -> Self {
+        self.enable_fku = Some(enabled);
+        self
+    }
+
+    /// Restricts which publishers data is received from.
+    pub fn locality(mut self, locality: ZenohLocality) -> Self {
+        self.locality = Some(locality);
+        self
+    }
+
+    /// Enables or disables zero-copy reception.
+    pub fn zero_copy(mut self, zero_copy: bool) -> Self {
+        self.zero_copy = Some(zero_copy);
+        self
+    }
+
+    /// Sets the maximum accepted payload size in bytes (0 = unlimited).
+    pub fn max_buffer_size(mut self, size: u64) -> Self {
+        self.max_buffer_size = Some(size);
+        self
+    }
+
+    /// Sets how many buffers the pool for copied payloads preallocates
+    /// (default: 0 = no pool).
+    pub fn buffer_pool_size(mut self, size: u32) -> Self {
+        self.buffer_pool_size = Some(size);
+        self
+    }
+
+    /// Sets how many pool buffers can be in use at once (default: 0 = unlimited).
+    pub fn buffer_pool_max(mut self, max: u32) -> Self {
+        self.buffer_pool_max = Some(max);
+        self
+    }
+
+    /// Enables or disables copying payloads into the pool downstream proposes
+    /// (default: false).
+    pub fn use_downstream_pool(mut self, enabled: bool) -> Self {
+        self.use_downstream_pool = Some(enabled);
+        self
+    }
+
+    /// Sets how long without data before a `zenoh-stream-idle` message is
+    /// posted (0 = disabled).
+    pub fn no_data_timeout_ms(mut self, timeout: u64) -> Self {
+        self.no_data_timeout_ms = Some(timeout);
+        self
+    }
+
+    /// Sets how long to wait after declaring the subscriber before starting completes.
+    pub fn declaration_delay_ms(mut self, delay: u64) -> Self {
+        self.declaration_delay_ms = Some(delay);
+        self
+    }
+
+    /// Sets how often the session connectivity is checked (default: 1000, 0 = disabled).
+    pub fn connectivity_poll_ms(mut self, interval: u32) -> Self {
+        self.connectivity_poll_ms = Some(interval);
+        self
+    }
+
+    /// Sets the maximum sample age in milliseconds before it is dropped (0 = disabled).
+    pub fn max_lateness_ms(mut self, lateness: u64) -> Self {
+        self.max_lateness_ms = Some(lateness);
+        self
+    }
+
+    /// Sets how many samples are held per key for reordering (0 = disabled).
+    pub fn reorder_window(mut self, window: u32) -> Self {
+        self.reorder_window = Some(window);
+        self
+    }
+
+    /// Sets the longest a sample is held for reordering (0 = no limit, default: 200).
+    pub fn reorder_timeout_ms(mut self, timeout: u32) -> Self {
+        self.reorder_timeout_ms = Some(timeout);
+        self
+    }
+
+    /// Sets how the PTS of output buffers is derived.
+    pub fn timestamp_mode(mut self, mode: TimestampMode) -> Self {
+        self.timestamp_mode = Some(mode);
+        self
+    }
+
+    /// Sets whether the source is live.
+    pub fn is_live(mut self, is_live: bool) -> Self {
+        self.is_live = Some(is_live);
+        self
+    }
+
+    /// Restricts the delivered samples to PUTs or DELETEs.
+    pub fn sample_kind_filter(mut self, filter: SampleKindFilter) -> Self {
+        self.sample_kind_filter = Some(filter);
+        self
+    }
+
+    /// Enables or disables dropping zero-length PUT samples.
+    pub fn drop_empty(mut self, drop_empty: bool) -> Self {
+        self.drop_empty = Some(drop_empty);
+        self
+    }
+
+    /// Builds the ZenohSrc after checking the key expression, so a malformed
+    /// one is reported here instead of when the element starts.
+    pub fn try_build(self) -> Result<ZenohSrc, String> {
+        crate::utils::validate_key_expr(&self.key_expr)?;
+        Ok(self.build())
+    }
+
     /// Builds the ZenohSrc with the configured properties.
     pub fn build(self) -> ZenohSrc {
         let mut builder = gst::Object::builder::<ZenohSrc>().property("key-expr", &self.key_expr);
@@ -457,6 +1632,30 @@ impl ZenohSrcBuilder {
         if let Some(config) = self.config {
             builder = builder.property("config", config);
         }
+        if let Some(json5) = self.config_json5 {
+            builder = builder.property("config-json5", json5);
+        }
+        if let Some(scouting) = self.scouting {
+            builder = builder.property("scouting", scouting);
+        }
+        if let Some(endpoints_only) = self.endpoints_only {
+            builder = builder.property("endpoints-only", endpoints_only);
+        }
+        if let Some(path) = self.tls_root_ca {
+            builder = builder.property("tls-root-ca", path);
+        }
+        if let Some(path) = self.tls_client_cert {
+            builder = builder.property("tls-client-cert", path);
+        }
+        if let Some(path) = self.tls_client_key {
+            builder = builder.property("tls-client-key", path);
+        }
+        if let Some(username) = self.username {
+            builder = builder.property("username", username);
+        }
+        if let Some(password) = self.password {
+            builder = builder.property("password", password);
+        }
         if let Some(priority) = self.priority {
             builder = builder.property("priority", priority);
         }
@@ -466,15 +1665,101 @@ impl ZenohSrcBuilder {
         if let Some(rel) = self.reliability {
             builder = builder.property("reliability", rel);
         }
+        if let Some(express) = self.express {
+            builder = builder.property("express", express);
+        }
         if let Some(timeout) = self.receive_timeout_ms {
             builder = builder.property("receive-timeout-ms", timeout);
         }
         if let Some(apply) = self.apply_buffer_meta {
             builder = builder.property("apply-buffer-meta", apply);
         }
+        if let Some(enabled) = self.mark_discont {
+            builder = builder.property("mark-discont", enabled);
+        }
         if let Some(ref sg) = self.session_group {
             builder = builder.property("session-group", sg);
         }
+        if let Some(linger) = self.session_group_linger_ms {
+            builder = builder.property("session-group-linger-ms", linger);
+        }
+        #[cfg(feature = "metrics-http")]
+        if let Some(port) = self.metrics_port {
+            builder = builder.property("metrics-port", port as u32);
+        }
+        #[cfg(feature = "metrics-http")]
+        if let Some(ref address) = self.metrics_address {
+            builder = builder.property("metrics-address", address);
+        }
+        if let Some(reconnect) = self.reconnect {
+            builder = builder.property("reconnect", reconnect);
+        }
+        if let Some(caps) = self.caps {
+            builder = builder.property("caps", caps);
+        }
+        if let Some(force) = self.force_caps {
+            builder = builder.property("force-caps", force);
+        }
+        if let Some(enabled) = self.use_encoding_caps {
+            builder = builder.property("use-encoding-caps", enabled);
+        }
+        if let Some(channel) = self.caps_channel {
+            builder = builder.property("caps-channel", channel);
+        }
+        if let Some(enabled) = self.forward_events {
+            builder = builder.property("forward-events", enabled);
+        }
+        if let Some(enabled) = self.enable_fku {
+            builder = builder.property("enable-fku", enabled);
+        }
+        if let Some(locality) = self.locality {
+            builder = builder.property("locality", locality);
+        }
+        if let Some(zero_copy) = self.zero_copy {
+            builder = builder.property("zero-copy", zero_copy);
+        }
+        if let Some(size) = self.max_buffer_size {
+            builder = builder.property("max-buffer-size", size);
+        }
+        if let Some(size) = self.buffer_pool_size {
+            builder = builder.property("buffer-pool-size", size);
+        }
+        if let Some(max) = self.buffer_pool_max {
+            builder = builder.property("buffer-pool-max", max);
+        }
+        if let Some(enabled) = self.use_downstream_pool {
+            builder = builder.property("use-downstream-pool", enabled);
+        }
+        if let Some(timeout) = self.no_data_timeout_ms {
+            builder = builder.property("no-data-timeout-ms", timeout);
+        }
+        if let Some(delay) = self.declaration_delay_ms {
+            builder = builder.property("declaration-delay-ms", delay);
+        }
+        if let Some(interval) = self.connectivity_poll_ms {
+            builder = builder.property("connectivity-poll-ms", interval);
+        }
+        if let Some(lateness) = self.max_lateness_ms {
+            builder = builder.property("max-lateness-ms", lateness);
+        }
+        if let Some(window) = self.reorder_window {
+            builder = builder.property("reorder-window", window);
+        }
+        if let Some(timeout) = self.reorder_timeout_ms {
+            builder = builder.property("reorder-timeout-ms", timeout);
+        }
+        if let Some(mode) = self.timestamp_mode {
+            builder = builder.property("timestamp-mode", mode);
+        }
+        if let Some(is_live) = self.is_live {
+            builder = builder.property("is-live", is_live);
+        }
+        if let Some(filter) = self.sample_kind_filter {
+            builder = builder.property("sample-kind-filter", filter);
+        }
+        if let Some(drop_empty) = self.drop_empty {
+            builder = builder.property("drop-empty", drop_empty);
+        }
 
         let src: ZenohSrc = builder.build().unwrap();
 
@@ -6,7 +6,9 @@
 #![cfg(any(
     feature = "compression-zstd",
     feature = "compression-lz4",
-    feature = "compression-gzip"
+    feature = "compression-gzip",
+    feature = "compression-brotli",
+    feature = "compression-snappy"
 ))]
 
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -231,6 +233,41 @@ fn test_gzip_compression_roundtrip() {
     compression_roundtrip_test(CompressionType::Gzip, 6, 4096);
 }
 
+/// Test brotli compression (requires compression-brotli feature)
+#[cfg(feature = "compression-brotli")]
+#[test]
+#[serial]
+fn test_brotli_compression_roundtrip() {
+    compression_roundtrip_test(CompressionType::Brotli, 5, 4096);
+}
+
+/// Test snappy compression (requires compression-snappy feature)
+#[cfg(feature = "compression-snappy")]
+#[test]
+#[serial]
+fn test_snappy_compression_roundtrip() {
+    compression_roundtrip_test(CompressionType::Snappy, 5, 4096);
+}
+
+/// Test that the registered enum only lists the algorithms compiled in
+#[test]
+fn test_compression_enum_values_match_features() {
+    let class = gst::glib::EnumClass::with_type(CompressionType::static_type()).unwrap();
+    let nicks: Vec<&str> = class.values().iter().map(|v| v.nick()).collect();
+
+    let expected = [
+        ("none", true),
+        ("zstd", cfg!(feature = "compression-zstd")),
+        ("lz4", cfg!(feature = "compression-lz4")),
+        ("gzip", cfg!(feature = "compression-gzip")),
+        ("brotli", cfg!(feature = "compression-brotli")),
+        ("snappy", cfg!(feature = "compression-snappy")),
+    ];
+    for (nick, enabled) in expected {
+        assert_eq!(nicks.contains(&nick), enabled, "unexpected presence of '{}'", nick);
+    }
+}
+
 /// Test compression with large data
 #[cfg(feature = "compression-zstd")]
 #[test]
@@ -238,3 +275,391 @@ fn test_gzip_compression_roundtrip() {
 fn test_zstd_large_data() {
     compression_roundtrip_test(CompressionType::Zstd, 3, 64 * 1024); // 64KB
 }
+
+/// Test that buffers below compression-min-size are sent uncompressed and
+/// without a compression marker, and are excluded from compression statistics
+#[cfg(feature = "compression-zstd")]
+#[test]
+#[serial]
+fn test_compression_min_size_skips_small_buffers() {
+    use gstzenoh::metadata::{MetadataParser, keys};
+
+    init();
+
+    let key_expr = unique_key_expr("comp_min_size");
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    sink_elem.set_property("compression", CompressionType::Zstd);
+    sink_elem.set_property("compression-min-size", 1024u32);
+    assert_eq!(sink_elem.property::<u32>("compression-min-size"), 1024);
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    // (size, expected to be compressed)
+    for (size, expect_compressed) in [(100usize, false), (8192usize, true)] {
+        let data = generate_test_data(size);
+        let mut buffer = gst::Buffer::with_size(data.len()).unwrap();
+        buffer.get_mut().unwrap().copy_from_slice(0, &data).unwrap();
+        appsrc.push_buffer(buffer).unwrap();
+
+        let sample = subscriber
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap()
+            .expect("Sample not received");
+        let marker = sample
+            .attachment()
+            .and_then(|a| MetadataParser::parse(a).ok())
+            .and_then(|m| m.get_user_metadata(keys::COMPRESSION).map(str::to_string));
+
+        if expect_compressed {
+            assert_eq!(marker.as_deref(), Some("zstd"));
+            assert!(sample.payload().len() < size);
+        } else {
+            assert_eq!(marker, None, "small buffer must not carry a compression marker");
+            assert!(verify_test_data(&sample.payload().to_bytes(), size));
+        }
+    }
+
+    // Only the large buffer is accounted in the compression statistics
+    let start = Instant::now();
+    while sink_elem.property::<u64>("messages-sent") < 2
+        && start.elapsed() < Duration::from_secs(2)
+    {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(sink_elem.property::<u64>("bytes-before-compression"), 8192);
+
+    // The ratio only covers the compressed buffer
+    let after = sink_elem.property::<u64>("bytes-after-compression");
+    let ratio = zenohsink.compression_ratio();
+    assert!((ratio - after as f64 / 8192.0).abs() < 1e-9);
+    assert!(ratio < 1.0);
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+}
+
+/// Test that zenohdemux accounts decompressed buffers in its statistics
+#[cfg(feature = "compression-zstd")]
+#[test]
+#[serial]
+fn test_demux_decompression_statistics() {
+    init();
+
+    let base_key = unique_key_expr("comp_demux");
+    let session_group = format!("comp_demux_{}", std::process::id());
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohdemux = gstzenoh::ZenohDemux::builder(&format!("{}/*", base_key))
+        .session_group(&session_group)
+        .receive_timeout_ms(50)
+        .build();
+    let demux_elem: gst::Element = zenohdemux.clone().upcast();
+    recv_pipeline.add(&demux_elem).unwrap();
+
+    demux_elem.connect_pad_added(|demux, pad| {
+        let pipeline = demux.parent().and_downcast::<gst::Pipeline>().unwrap();
+        let fakesink = gst::ElementFactory::make("fakesink")
+            .property("sync", false)
+            .property("async", false)
+            .build()
+            .unwrap();
+        pipeline.add(&fakesink).unwrap();
+        fakesink.sync_state_with_parent().unwrap();
+        let _ = pad.link(&fakesink.static_pad("sink").unwrap());
+    });
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&format!("{}/stream", base_key))
+        .session_group(&session_group)
+        .build();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    sink_elem.set_property("compression", CompressionType::Zstd);
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let data = generate_test_data(8192);
+    let start = Instant::now();
+    while demux_elem.property::<u64>("bytes-after-decompression") == 0
+        && start.elapsed() < Duration::from_secs(10)
+    {
+        let mut buffer = gst::Buffer::with_size(data.len()).unwrap();
+        buffer.get_mut().unwrap().copy_from_slice(0, &data).unwrap();
+        appsrc.push_buffer(buffer).unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    // Statistics are only available while running
+    let before = demux_elem.property::<u64>("bytes-before-decompression");
+    let after = demux_elem.property::<u64>("bytes-after-decompression");
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert!(before > 0, "no compressed bytes accounted");
+    assert_eq!(after % 8192, 0, "decompressed size must be a multiple of the buffer size");
+    assert!(after > before, "decompression should expand the payload");
+}
+
+/// Test that max-buffer-size also caps the decompressed size: a payload of
+/// zeros compresses far below the limit but expands beyond it
+#[cfg(feature = "compression-zstd")]
+#[test]
+#[serial]
+fn test_demux_max_buffer_size_caps_decompressed_size() {
+    init();
+
+    let base_key = unique_key_expr("comp_demux_cap");
+    let session_group = format!("comp_demux_cap_{}", std::process::id());
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohdemux = gstzenoh::ZenohDemux::builder(&format!("{}/*", base_key))
+        .session_group(&session_group)
+        .receive_timeout_ms(50)
+        .max_buffer_size(64 * 1024)
+        .build();
+    let demux_elem: gst::Element = zenohdemux.upcast();
+    recv_pipeline.add(&demux_elem).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&format!("{}/stream", base_key))
+        .session_group(&session_group)
+        .build();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    sink_elem.set_property("compression", CompressionType::Zstd);
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let data = vec![0u8; 1024 * 1024];
+    let start = Instant::now();
+    while demux_elem.property::<u64>("errors") == 0 && start.elapsed() < Duration::from_secs(10) {
+        appsrc.push_buffer(gst::Buffer::from_slice(data.clone())).unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    let errors = demux_elem.property::<u64>("errors");
+    let after = demux_elem.property::<u64>("bytes-after-decompression");
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert!(errors > 0, "oversized decompressed payload was not refused");
+    assert_eq!(after, 0, "no payload should have been decompressed");
+}
+
+/// Test that a batch of aggregate-window-ms is compressed as a whole and
+/// carries the timing and flags of each buffer
+#[cfg(feature = "compression-zstd")]
+#[test]
+#[serial]
+fn test_aggregated_batch_compressed_with_timing() {
+    use gstzenoh::metadata::MetadataParser;
+
+    init();
+
+    let key_expr = unique_key_expr("comp_aggregate");
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .aggregate_window_ms(1000)
+        .build();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    sink_elem.set_property("compression", CompressionType::Zstd);
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    // A key frame followed by delta units, published as one batch on EOS
+    let data = generate_test_data(1024);
+    for i in 0..4u64 {
+        let mut buffer = gst::Buffer::from_slice(data.clone());
+        {
+            let buffer_mut = buffer.get_mut().unwrap();
+            buffer_mut.set_pts(gst::ClockTime::from_mseconds(i * 10));
+            buffer_mut.set_duration(gst::ClockTime::from_mseconds(10));
+            if i > 0 {
+                buffer_mut.set_flags(gst::BufferFlags::DELTA_UNIT);
+            }
+        }
+        appsrc.push_buffer(buffer).unwrap();
+    }
+    appsrc.end_of_stream().unwrap();
+    let bus = send_pipeline.bus().unwrap();
+    let msg = bus.timed_pop_filtered(
+        gst::ClockTime::from_seconds(5),
+        &[gst::MessageType::Eos, gst::MessageType::Error],
+    );
+    assert!(matches!(msg.map(|m| m.type_()), Some(gst::MessageType::Eos)));
+    let bytes_before_compression = sink_elem.property::<u64>("bytes-before-compression");
+
+    let sample = subscriber
+        .recv_timeout(Duration::from_secs(5))
+        .unwrap()
+        .expect("Batch not received");
+    let metadata = MetadataParser::parse(sample.attachment().expect("batch metadata")).unwrap();
+    assert_eq!(metadata.compression(), Some("zstd"));
+    assert_eq!(metadata.batch(), Some(&[1024; 4][..]));
+
+    let timing = metadata.batch_timing().expect("batch timing");
+    let pts: Vec<_> = timing.iter().map(|part| part.pts).collect();
+    let expected: Vec<_> = (0..4).map(|i| Some(gst::ClockTime::from_mseconds(i * 10))).collect();
+    assert_eq!(pts, expected);
+    assert!(timing.iter().all(|part| part.duration == Some(gst::ClockTime::from_mseconds(10))));
+    assert!(!timing[0].flags.contains(gst::BufferFlags::DELTA_UNIT));
+    assert!(timing[1..].iter().all(|part| part.flags.contains(gst::BufferFlags::DELTA_UNIT)));
+
+    let payload = sample.payload().to_bytes();
+    assert!(payload.len() < 4096, "the batch should be compressed");
+    let decompressed = gstzenoh::compression::decompress(&payload, CompressionType::Zstd).unwrap();
+    assert_eq!(decompressed, data.repeat(4));
+    assert_eq!(bytes_before_compression, 4096);
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+}
+
+/// Test that compression-negotiate only compresses with an algorithm every
+/// advertising receiver supports: buffers go out uncompressed to a receiver that
+/// only decompresses lz4, still uncompressed once a zenohsrc built with zstd
+/// joins it, compressed after the lz4 receiver leaves, and uncompressed again
+/// after zenohsrc leaves too
+#[cfg(all(feature = "compression-zstd", feature = "compression-lz4"))]
+#[test]
+#[serial]
+fn test_compression_negotiate() {
+    use gstzenoh::metadata::{MetadataParser, keys};
+
+    init();
+
+    let key_expr = unique_key_expr("comp_negotiate");
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    // A receiver that can only decompress lz4
+    let lz4_token = zenoh_session
+        .liveliness()
+        .declare_token(format!("{}/compression/lz4/test", key_expr))
+        .wait()
+        .unwrap();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let sink_elem: gst::Element = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build()
+        .upcast();
+    sink_elem.set_property("compression", CompressionType::Zstd);
+    sink_elem.set_property("compression-negotiate", true);
+    assert!(sink_elem.property::<bool>("compression-negotiate"));
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    // Pushes a compressible buffer and returns its compression marker
+    let data = generate_test_data(8192);
+    let send = || {
+        let mut buffer = gst::Buffer::with_size(data.len()).unwrap();
+        buffer.get_mut().unwrap().copy_from_slice(0, &data).unwrap();
+        appsrc.push_buffer(buffer).unwrap();
+
+        let sample = subscriber
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap()
+            .expect("Sample not received");
+        let marker = sample
+            .attachment()
+            .and_then(|a| MetadataParser::parse(a).ok())
+            .and_then(|m| m.get_user_metadata(keys::COMPRESSION).map(str::to_string));
+        if marker.is_none() {
+            assert!(verify_test_data(&sample.payload().to_bytes(), data.len()));
+        }
+        marker
+    };
+
+    assert_eq!(send(), None, "No receiver advertises zstd");
+
+    // zenohsrc advertises every algorithm of the build
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+    let src_elem: gst::Element = zenohsrc.upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    assert_eq!(send(), None, "The lz4 receiver can't decompress zstd");
+
+    lz4_token.undeclare().wait().unwrap();
+    thread::sleep(Duration::from_millis(300));
+    assert_eq!(send().as_deref(), Some("zstd"), "Only zenohsrc, which supports zstd, is left");
+
+    // Its advertisement is withdrawn when it stops
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+    thread::sleep(Duration::from_millis(300));
+    assert_eq!(send(), None, "No receiver advertises zstd anymore");
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+}
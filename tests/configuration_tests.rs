@@ -71,6 +71,39 @@ fn test_zenoh_sink_express_mode() {
     assert!(sink.property::<bool>("express"));
 }
 
+#[test]
+#[serial]
+fn test_zenoh_sink_express_congestion_control() {
+    gst::init().unwrap();
+    gstzenoh::plugin_register_static().unwrap();
+
+    let sink = gstzenoh::ZenohSink::builder("test/sink/express-cc")
+        .congestion_control("block")
+        .express(true)
+        .build();
+
+    // Inherits congestion-control by default
+    assert_eq!(
+        sink.express_congestion_control(),
+        gstzenoh::ZenohExpressCongestionControl::Inherit
+    );
+
+    sink.set_express_congestion_control(gstzenoh::ZenohExpressCongestionControl::Drop);
+    assert_eq!(
+        sink.express_congestion_control(),
+        gstzenoh::ZenohExpressCongestionControl::Drop
+    );
+    // The regular policy is left untouched
+    assert_eq!(sink.congestion_control(), "block");
+
+    // Settable by nick, e.g. from gst-launch
+    sink.set_property_from_str("express-congestion-control", "inherit");
+    assert_eq!(
+        sink.express_congestion_control(),
+        gstzenoh::ZenohExpressCongestionControl::Inherit
+    );
+}
+
 #[test]
 #[serial]
 fn test_zenoh_sink_priority_configuration() {
@@ -128,6 +161,34 @@ fn test_zenoh_src_reliability_configuration() {
     assert_eq!(src.property::<String>("reliability"), "reliable");
 }
 
+#[test]
+#[serial]
+fn test_zenoh_src_reconnect_configuration() {
+    gst::init().unwrap();
+    gstzenoh::plugin_register_static().unwrap();
+
+    let src = gst::ElementFactory::make("zenohsrc")
+        .build()
+        .expect("Failed to create zenohsrc element");
+
+    // Disabled by default
+    assert!(!src.property::<bool>("reconnect"));
+
+    src.set_property("reconnect", true);
+    assert!(src.property::<bool>("reconnect"));
+
+    // Typed API
+    let src = gstzenoh::ZenohSrc::builder("test/src/reconnect")
+        .reconnect(true)
+        .build();
+    assert!(src.reconnect());
+    src.set_reconnect(false);
+    assert!(!src.reconnect());
+
+    // Signal is registered and connectable
+    src.connect_reconnecting(|_src, _attempt| {});
+}
+
 // Note: Property locking test requires actual Zenoh session which is complex to set up in unit tests
 
 #[test]
@@ -185,3 +246,207 @@ fn test_end_to_end_configuration() {
     assert_eq!(sink.property::<u32>("priority"), 2);
     assert_eq!(src.property::<String>("reliability"), "reliable");
 }
+
+#[test]
+#[serial]
+fn test_locality_configuration() {
+    gst::init().unwrap();
+    gstzenoh::plugin_register_static().unwrap();
+
+    let sink = gst::ElementFactory::make("zenohsink")
+        .build()
+        .expect("Failed to create zenohsink element");
+    assert_eq!(
+        sink.property::<gstzenoh::ZenohLocality>("locality"),
+        gstzenoh::ZenohLocality::Any
+    );
+
+    // Settable by nick, as from gst-launch
+    sink.set_property_from_str("locality", "session-local");
+    assert_eq!(
+        sink.property::<gstzenoh::ZenohLocality>("locality"),
+        gstzenoh::ZenohLocality::SessionLocal
+    );
+
+    let src = gstzenoh::ZenohSrc::builder("test/src/locality")
+        .locality(gstzenoh::ZenohLocality::Remote)
+        .build();
+    assert_eq!(src.locality(), gstzenoh::ZenohLocality::Remote);
+}
+
+#[test]
+#[serial]
+fn test_key_vars_substitution() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    gst::init().unwrap();
+    gstzenoh::plugin_register_static().unwrap();
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let prefix = format!("test/key_vars/{}", std::process::id());
+    let subscriber = session
+        .declare_subscriber(format!("{}/front/video", prefix))
+        .wait()
+        .unwrap();
+
+    let sink = gstzenoh::ZenohSink::builder(&format!("{}/${{device-id}}/video", prefix))
+        .session(session.clone())
+        .build();
+    // Settable from a string, as from gst-launch
+    sink.set_property_from_str("key-vars", "vars, device-id=front");
+    assert_eq!(
+        sink.key_vars().unwrap().get::<String>("device-id").unwrap(),
+        "front"
+    );
+
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    pipeline
+        .add_many([
+            appsrc.upcast_ref::<gst::Element>(),
+            sink.upcast_ref::<gst::Element>(),
+        ])
+        .unwrap();
+    appsrc.link(&sink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(b"hello".to_vec()))
+        .unwrap();
+    let sample = subscriber
+        .recv_timeout(Duration::from_secs(5))
+        .unwrap()
+        .expect("Nothing published on the expanded key");
+    assert_eq!(sample.payload().to_bytes().as_ref(), b"hello");
+    // The template itself is kept as the property value
+    assert!(sink.key_expr().contains("${device-id}"));
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_key_vars_unresolved_fails_start() {
+    gst::init().unwrap();
+    gstzenoh::plugin_register_static().unwrap();
+
+    let sink = gstzenoh::ZenohSink::builder("cameras/${device-id}/${site}/video")
+        .key_vars(
+            gst::Structure::builder("vars")
+                .field("device-id", "front")
+                .build(),
+        )
+        .build();
+
+    // ${site} has no value: the publisher must not be declared
+    assert!(sink.set_state(gst::State::Ready).is_err());
+    let _ = sink.set_state(gst::State::Null);
+}
+
+#[test]
+#[serial]
+fn test_scouting_configuration() {
+    gst::init().unwrap();
+    gstzenoh::plugin_register_static().unwrap();
+
+    for name in [
+        "zenohsink",
+        "zenohsrc",
+        "zenohdemux",
+        "zenohget",
+        "zenohqueryablesrc",
+    ] {
+        let element = gst::ElementFactory::make(name)
+            .build()
+            .unwrap_or_else(|_| panic!("Failed to create {}", name));
+        assert!(element.property::<bool>("scouting"), "{} default", name);
+        element.set_property_from_str("scouting", "false");
+        assert!(!element.property::<bool>("scouting"), "{} from string", name);
+    }
+
+    let src = gstzenoh::ZenohSrc::builder("test/src/scouting")
+        .scouting(false)
+        .build();
+    assert!(!src.scouting());
+
+    // A session still opens with multicast scouting disabled
+    let sink = gstzenoh::ZenohSink::builder("test/sink/scouting")
+        .scouting(false)
+        .build();
+    assert!(!sink.scouting());
+    sink.set_state(gst::State::Ready)
+        .expect("Session should open without multicast scouting");
+    sink.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_endpoints_only_configuration() {
+    gst::init().unwrap();
+    gstzenoh::plugin_register_static().unwrap();
+
+    for name in ["zenohsink", "zenohsrc", "zenohdemux"] {
+        let element = gst::ElementFactory::make(name)
+            .build()
+            .unwrap_or_else(|_| panic!("Failed to create {}", name));
+        assert!(!element.property::<bool>("endpoints-only"), "{} default", name);
+        element.set_property_from_str("endpoints-only", "true");
+        assert!(element.property::<bool>("endpoints-only"), "{} from string", name);
+    }
+
+    let src = gstzenoh::ZenohSrc::builder("test/src/endpoints-only")
+        .endpoints_only(true)
+        .build();
+    assert!(src.endpoints_only());
+
+    // Without connect endpoints there is nothing to connect to
+    let sink = gstzenoh::ZenohSink::builder("test/sink/endpoints-only")
+        .endpoints_only(true)
+        .build();
+    assert!(sink.set_state(gst::State::Ready).is_err());
+    sink.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_inline_json5_configuration() {
+    gst::init().unwrap();
+    gstzenoh::plugin_register_static().unwrap();
+
+    for name in [
+        "zenohsink",
+        "zenohsrc",
+        "zenohdemux",
+        "zenohget",
+        "zenohqueryablesrc",
+    ] {
+        let element = gst::ElementFactory::make(name)
+            .build()
+            .unwrap_or_else(|_| panic!("Failed to create {}", name));
+        assert!(element.property::<Option<String>>("config-json5").is_none());
+    }
+
+    // The session opens with the inline configuration, which wins over the file
+    let json5 = r#"{ mode: "peer", scouting: { multicast: { enabled: false } } }"#;
+    let sink = gstzenoh::ZenohSink::builder("test/sink/config-json5")
+        .config("/nonexistent/zenoh.json5")
+        .config_json5(json5)
+        .build();
+    assert_eq!(sink.config_json5().as_deref(), Some(json5));
+    sink.set_state(gst::State::Ready)
+        .expect("Session should open with the inline configuration");
+    assert!(!sink.session_zid().is_empty());
+    sink.set_state(gst::State::Null).unwrap();
+
+    // Malformed JSON5 fails the start
+    let sink = gstzenoh::ZenohSink::builder("test/sink/config-json5")
+        .config_json5("{ mode: ")
+        .build();
+    assert!(sink.set_state(gst::State::Ready).is_err());
+    let _ = sink.set_state(gst::State::Null);
+}
@@ -503,3 +503,2001 @@ fn test_statistics_during_data_flow() {
         num_buffers * buffer_size
     );
 }
+
+/// Test fixed caps on zenohsrc when receiving from a non-GStreamer publisher.
+///
+/// The publisher is a plain Zenoh publisher that sends no caps metadata, so the
+/// only way for h264parse to negotiate is through the `caps` property.
+#[test]
+#[serial]
+fn test_fixed_caps_without_metadata() {
+    init();
+
+    if gst::ElementFactory::find("h264parse").is_none() {
+        println!("Skipping test: h264parse not available");
+        return;
+    }
+
+    let key_expr = unique_key_expr("fixed_caps");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .caps(gst::Caps::builder("video/x-h264").build())
+        .build();
+
+    let h264parse = gst::ElementFactory::make("h264parse").build().unwrap();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline
+        .add_many([&src_elem, &h264parse, &fakesink])
+        .unwrap();
+    gst::Element::link_many([&src_elem, &h264parse, &fakesink]).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    // Plain Zenoh publisher: H.264 access unit delimiters, no attachment
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .wait()
+        .expect("Failed to declare publisher");
+
+    let srcpad = zenohsrc.static_pad("src").unwrap();
+    let start = Instant::now();
+    let mut caps = None;
+    while start.elapsed() < Duration::from_secs(5) {
+        publisher
+            .put(vec![0x00, 0x00, 0x00, 0x01, 0x09, 0x10])
+            .wait()
+            .unwrap();
+        caps = srcpad.current_caps();
+        if caps.is_some() {
+            break;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let caps = caps.expect("zenohsrc should have negotiated the fixed caps");
+    assert_eq!(caps.structure(0).unwrap().name(), "video/x-h264");
+}
+
+/// Test caps derived from the sample encoding with `use-encoding-caps`.
+///
+/// A plain Zenoh publisher tags its samples with `image/jpeg`; zenohsrc maps the
+/// encoding to caps since no caps metadata is attached.
+#[test]
+#[serial]
+fn test_encoding_caps_without_metadata() {
+    init();
+
+    let key_expr = unique_key_expr("encoding_caps");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .use_encoding_caps(true)
+        .build();
+
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .encoding(zenoh::bytes::Encoding::IMAGE_JPEG)
+        .wait()
+        .expect("Failed to declare publisher");
+
+    let srcpad = zenohsrc.static_pad("src").unwrap();
+    let start = Instant::now();
+    let mut caps = None;
+    while start.elapsed() < Duration::from_secs(5) {
+        publisher.put(vec![0xff, 0xd8, 0xff, 0xd9]).wait().unwrap();
+        caps = srcpad.current_caps();
+        if caps.is_some() {
+            break;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let caps = caps.expect("zenohsrc should have set caps from the encoding");
+    assert_eq!(caps.structure(0).unwrap().name(), "image/jpeg");
+}
+
+/// Test that `auto-encoding` publishes with the encoding of the caps, and that
+/// a caps change switches the encoding.
+#[test]
+#[serial]
+fn test_auto_encoding_from_caps() {
+    init();
+
+    let key_expr = unique_key_expr("auto_encoding");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let encodings: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let encodings_clone = encodings.clone();
+    let _subscriber = zenoh_session
+        .declare_subscriber(key_expr.as_str())
+        .callback(move |sample| {
+            encodings_clone
+                .lock()
+                .unwrap()
+                .push(sample.encoding().to_string());
+        })
+        .wait()
+        .expect("Failed to declare subscriber");
+
+    let pipeline = gst::Pipeline::new();
+    let h264_caps = gst::Caps::builder("video/x-h264")
+        .field("stream-format", "avc")
+        .build();
+    let appsrc = gst_app::AppSrc::builder()
+        .caps(&h264_caps)
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .auto_encoding(true)
+        .build();
+    assert!(zenohsink.auto_encoding());
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![0u8; 16]))
+        .expect("Failed to push buffer");
+    appsrc.set_caps(Some(&gst::Caps::builder("image/jpeg").build()));
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![0u8; 16]))
+        .expect("Failed to push buffer");
+
+    let start = Instant::now();
+    while encodings.lock().unwrap().len() < 2 && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(20));
+    }
+    pipeline.set_state(gst::State::Null).unwrap();
+
+    let encodings = encodings.lock().unwrap();
+    assert_eq!(encodings.len(), 2, "Expected both samples");
+    assert!(encodings[0].starts_with("video/h264"), "{}", encodings[0]);
+    assert!(encodings[1].starts_with("image/jpeg"), "{}", encodings[1]);
+}
+
+/// Test that `reliability` on zenohsrc is informational.
+///
+/// Zenoh 1.x subscribers can't declare a reliability, so a subscriber asking
+/// for `reliable` must still receive from a best-effort publisher.
+#[test]
+#[serial]
+fn test_src_reliability_follows_publisher() {
+    init();
+
+    let key_expr = unique_key_expr("src_reliability");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .reliability("reliable")
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .reliability(zenoh::qos::Reliability::BestEffort)
+        .wait()
+        .expect("Failed to declare publisher");
+
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(5) && zenohsrc.messages_received() == 0 {
+        publisher.put(vec![1, 2, 3]).wait().unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    let received = zenohsrc.messages_received();
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(zenohsrc.reliability(), "reliable");
+    assert!(
+        received > 0,
+        "best-effort samples should be delivered regardless of the src reliability"
+    );
+}
+
+/// Test that `priority` on zenohsrc is a hint.
+///
+/// Zenoh 1.x subscribers can't declare a priority, so samples are delivered
+/// with the priority of their publisher whatever the src asks for.
+#[test]
+#[serial]
+fn test_src_priority_follows_publisher() {
+    init();
+
+    let key_expr = unique_key_expr("src_priority");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .priority(1)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .priority(zenoh::qos::Priority::Background)
+        .wait()
+        .expect("Failed to declare publisher");
+
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(5) && zenohsrc.messages_received() == 0 {
+        publisher.put(vec![1, 2, 3]).wait().unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    let received = zenohsrc.messages_received();
+    let rx_priority = zenohsrc.rx_priority();
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(zenohsrc.priority(), 1);
+    assert!(
+        received > 0,
+        "Background samples should be delivered regardless of the src priority"
+    );
+    assert_eq!(
+        rx_priority, 7,
+        "Samples should keep the priority of their publisher"
+    );
+}
+
+/// Test the `first-buffer` and `eos` lifecycle signals on zenohsrc.
+#[test]
+#[serial]
+fn test_first_buffer_and_eos_signals() {
+    init();
+
+    let key_expr = unique_key_expr("lifecycle_signals");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .build();
+
+    let first_buffers = Arc::new(AtomicU64::new(0));
+    let first_buffers_clone = first_buffers.clone();
+    zenohsrc.connect_first_buffer(move |_src| {
+        first_buffers_clone.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let eos_key = Arc::new(Mutex::new(None::<String>));
+    let eos_key_clone = eos_key.clone();
+    zenohsrc.connect_eos(move |_src, key| {
+        *eos_key_clone.lock().unwrap() = Some(key.to_string());
+    });
+
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .wait()
+        .expect("Failed to declare publisher");
+
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(5) && zenohsrc.messages_received() < 3 {
+        publisher.put(vec![1, 2, 3]).wait().unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+    assert_eq!(first_buffers.load(Ordering::SeqCst), 1);
+
+    publisher.delete().wait().unwrap();
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(5) && eos_key.lock().unwrap().is_none() {
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(first_buffers.load(Ordering::SeqCst), 1);
+    assert_eq!(eos_key.lock().unwrap().as_deref(), Some(key_expr.as_str()));
+}
+
+/// Test publishing the same stream on several keys via a comma-separated key-expr.
+#[test]
+#[serial]
+fn test_multi_key_publish() {
+    init();
+
+    let key_a = unique_key_expr("multi_key_a");
+    let key_b = unique_key_expr("multi_key_b");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let sub_a = zenoh_session
+        .declare_subscriber(key_a.clone())
+        .wait()
+        .unwrap();
+    let sub_b = zenoh_session
+        .declare_subscriber(key_b.clone())
+        .wait()
+        .unwrap();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&format!("{}, {}", key_a, key_b))
+        .session(zenoh_session.clone())
+        .build();
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let data = generate_test_pattern(7, 64);
+    let mut buffer = gst::Buffer::with_size(data.len()).unwrap();
+    buffer.get_mut().unwrap().copy_from_slice(0, &data).unwrap();
+    appsrc.push_buffer(buffer).unwrap();
+
+    let sample_a = sub_a.recv_timeout(Duration::from_secs(5)).unwrap();
+    let sample_b = sub_b.recv_timeout(Duration::from_secs(5)).unwrap();
+
+    // Statistics are updated once all publishers are done
+    let start = Instant::now();
+    while zenohsink.messages_sent() < 2 && start.elapsed() < Duration::from_secs(2) {
+        thread::sleep(Duration::from_millis(10));
+    }
+    let messages_sent = zenohsink.messages_sent();
+    let bytes_sent = zenohsink.bytes_sent();
+    let _ = send_pipeline.set_state(gst::State::Null);
+
+    let sample_a = sample_a.expect("No sample on first key");
+    let sample_b = sample_b.expect("No sample on second key");
+    verify_test_pattern(&sample_a.payload().to_bytes(), 7).unwrap();
+    verify_test_pattern(&sample_b.payload().to_bytes(), 7).unwrap();
+
+    // Statistics aggregate across both publishers
+    assert_eq!(messages_sent, 2);
+    assert_eq!(bytes_sent, 128);
+}
+
+/// Test the `flush` action: once it returns, every rendered buffer was handed to
+/// Zenoh and the tail of a reliable stream survives going to NULL.
+#[test]
+#[serial]
+fn test_flush_before_shutdown() {
+    init();
+
+    let key_expr = unique_key_expr("flush");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .reliability("reliable")
+        .congestion_control("block")
+        .build();
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let num_buffers = 20;
+    for i in 0..num_buffers {
+        let data = generate_test_pattern(i as u32, 256);
+        appsrc.push_buffer(gst::Buffer::from_mut_slice(data)).unwrap();
+    }
+
+    // Wait for appsrc to hand everything to the streaming thread
+    let start = Instant::now();
+    while appsrc.current_level_buffers() > 0 && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    assert!(sink_elem.emit_by_name::<bool>("flush", &[&2000u64]));
+    assert!(zenohsink.flush(Duration::from_secs(2)));
+    let messages_sent = zenohsink.messages_sent();
+    let _ = send_pipeline.set_state(gst::State::Null);
+
+    assert_eq!(messages_sent, num_buffers);
+    let mut received = 0;
+    while let Ok(Some(_)) = subscriber.recv_timeout(Duration::from_secs(2)) {
+        received += 1;
+        if received == num_buffers {
+            break;
+        }
+    }
+    assert_eq!(received, num_buffers);
+}
+
+/// Test that `keyframe-priority` publishes key frames and delta frames with
+/// different Zenoh priorities.
+#[test]
+#[serial]
+fn test_keyframe_priority() {
+    init();
+
+    let key_expr = unique_key_expr("keyframe_priority");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .priority(5)
+        .keyframe_priority(2)
+        .build();
+    assert_eq!(zenohsink.keyframe_priority(), 2);
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let keyframe = gst::Buffer::from_slice(vec![1u8; 32]);
+    let mut delta = gst::Buffer::from_slice(vec![2u8; 32]);
+    delta.get_mut().unwrap().set_flags(gst::BufferFlags::DELTA_UNIT);
+    appsrc.push_buffer(keyframe).unwrap();
+    appsrc.push_buffer(delta).unwrap();
+
+    let keyframe_sample = subscriber.recv_timeout(Duration::from_secs(5)).unwrap();
+    let delta_sample = subscriber.recv_timeout(Duration::from_secs(5)).unwrap();
+    let _ = send_pipeline.set_state(gst::State::Null);
+
+    let keyframe_sample = keyframe_sample.expect("No key frame received");
+    let delta_sample = delta_sample.expect("No delta frame received");
+    assert_eq!(keyframe_sample.payload().to_bytes()[0], 1);
+    assert_eq!(keyframe_sample.priority(), zenoh::qos::Priority::InteractiveHigh);
+    assert_eq!(delta_sample.payload().to_bytes()[0], 2);
+    assert_eq!(delta_sample.priority(), zenoh::qos::Priority::Data);
+}
+
+/// Test that session-local publishing doesn't reach a remote-only subscriber in the same session.
+#[test]
+#[serial]
+fn test_locality_session_local_not_received_remote_only() {
+    init();
+
+    let key_expr = unique_key_expr("locality");
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    // Positive control: a plain subscriber on the same session accepts any origin
+    let local_sub = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .locality(gstzenoh::ZenohLocality::Remote)
+        .receive_timeout_ms(50)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .locality(gstzenoh::ZenohLocality::SessionLocal)
+        .build();
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let data = generate_test_pattern(3, 32);
+    for _ in 0..5 {
+        let mut buffer = gst::Buffer::with_size(data.len()).unwrap();
+        buffer.get_mut().unwrap().copy_from_slice(0, &data).unwrap();
+        appsrc.push_buffer(buffer).unwrap();
+    }
+
+    let sample = local_sub.recv_timeout(Duration::from_secs(5)).unwrap();
+    thread::sleep(Duration::from_millis(500));
+    let received_remote_only = zenohsrc.messages_received();
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(5));
+
+    let sample = sample.expect("Session-local subscriber should receive the data");
+    verify_test_pattern(&sample.payload().to_bytes(), 3).unwrap();
+    assert_eq!(
+        received_remote_only, 0,
+        "Remote-only subscriber must not receive session-local publications"
+    );
+}
+
+/// Test toggling express mode while playing: publishers are re-declared without losing data.
+#[test]
+#[serial]
+fn test_express_change_while_playing() {
+    init();
+
+    let key_expr = unique_key_expr("express_runtime");
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build();
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    for seq in 0..2u8 {
+        if seq == 1 {
+            zenohsink.set_express(true);
+        }
+        let data = generate_test_pattern(seq as u32, 64);
+        let mut buffer = gst::Buffer::with_size(data.len()).unwrap();
+        buffer.get_mut().unwrap().copy_from_slice(0, &data).unwrap();
+        appsrc.push_buffer(buffer).unwrap();
+
+        let sample = subscriber
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap()
+            .unwrap_or_else(|| panic!("Buffer {} not received", seq));
+        verify_test_pattern(&sample.payload().to_bytes(), seq as u32).unwrap();
+    }
+
+    // The change is accepted while playing and statistics span both publishers
+    let start = Instant::now();
+    while zenohsink.messages_sent() < 2 && start.elapsed() < Duration::from_secs(2) {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(zenohsink.express());
+    assert_eq!(zenohsink.messages_sent(), 2);
+    assert_eq!(zenohsink.errors(), 0);
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+}
+
+/// Test that zenohsrc posts zenoh-stream-idle when publishing stops and
+/// zenoh-stream-resumed when it starts again.
+#[test]
+#[serial]
+fn test_no_data_timeout_messages() {
+    init();
+
+    let key_expr = unique_key_expr("no_data_timeout");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .no_data_timeout_ms(300)
+        .build();
+    assert_eq!(zenohsrc.no_data_timeout_ms(), 300);
+
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    let bus = recv_pipeline.bus().unwrap();
+    let wait_for = |name: &str| -> Option<gst::Structure> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            let msg = bus.timed_pop_filtered(
+                gst::ClockTime::from_nseconds(remaining.as_nanos() as u64),
+                &[gst::MessageType::Element],
+            )?;
+            if let Some(s) = msg.structure()
+                && s.name() == name
+            {
+                return Some(s.to_owned());
+            }
+        }
+        None
+    };
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .wait()
+        .expect("Failed to declare publisher");
+
+    // Publish faster than the timeout. The element started before the publisher,
+    // so its first idle period (and the matching resume) is discarded.
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_millis(800) {
+        publisher.put(vec![1, 2, 3]).wait().unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+    while bus.pop_filtered(&[gst::MessageType::Element]).is_some() {}
+
+    // Stop publishing
+    let idle = wait_for("zenoh-stream-idle");
+
+    // Publish again
+    publisher.put(vec![4, 5, 6]).wait().unwrap();
+    let resumed_again = wait_for("zenoh-stream-resumed");
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let idle = idle.expect("zenoh-stream-idle not posted after publishing stopped");
+    assert_eq!(idle.get::<String>("key-expr").unwrap(), key_expr);
+    assert!(idle.get::<u64>("idle-ms").unwrap() >= 300);
+
+    let resumed_again = resumed_again.expect("zenoh-stream-resumed not posted");
+    assert_eq!(resumed_again.get::<String>("key-expr").unwrap(), key_expr);
+    assert!(resumed_again.get::<u64>("idle-ms").unwrap() >= 300);
+}
+
+/// Test that zenohsrc drops samples older than max-lateness-ms and still
+/// delivers fresh ones.
+#[test]
+#[serial]
+fn test_max_lateness_drops_stale_samples() {
+    init();
+
+    let key_expr = unique_key_expr("max_lateness");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .max_lateness_ms(500)
+        .build();
+    assert_eq!(zenohsrc.max_lateness_ms(), 500);
+
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .wait()
+        .expect("Failed to declare publisher");
+
+    // Alternate samples stamped 5 seconds in the past with fresh ones
+    for _ in 0..5 {
+        let now = zenoh_session.new_timestamp();
+        let stale = zenoh::time::Timestamp::new(
+            zenoh::time::NTP64::from(now.get_time().to_duration() - Duration::from_secs(5)),
+            *now.get_id(),
+        );
+        publisher.put(vec![0u8; 16]).timestamp(stale).wait().unwrap();
+        publisher
+            .put(vec![1u8; 16])
+            .timestamp(zenoh_session.new_timestamp())
+            .wait()
+            .unwrap();
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    let start = Instant::now();
+    while (zenohsrc.messages_received() < 5 || zenohsrc.dropped_late() < 5)
+        && start.elapsed() < Duration::from_secs(3)
+    {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let received = zenohsrc.messages_received();
+    let dropped_late = zenohsrc.dropped_late();
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(received, 5, "fresh samples should be delivered");
+    assert_eq!(dropped_late, 5, "stale samples should be dropped");
+}
+
+/// Test that sample-kind-filter=put drops DELETE samples and counts them.
+#[test]
+#[serial]
+fn test_sample_kind_filter_drops_deletes() {
+    init();
+
+    let key_expr = unique_key_expr("sample_kind_filter");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .sample_kind_filter(gstzenoh::SampleKindFilter::Put)
+        .build();
+    assert_eq!(zenohsrc.sample_kind_filter(), gstzenoh::SampleKindFilter::Put);
+
+    let eos_count = Arc::new(Mutex::new(0u32));
+    let eos_clone = eos_count.clone();
+    zenohsrc.connect_eos(move |_, _| {
+        *eos_clone.lock().unwrap() += 1;
+    });
+
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .wait()
+        .expect("Failed to declare publisher");
+
+    for _ in 0..3 {
+        publisher.put(vec![1u8; 16]).wait().unwrap();
+        publisher.delete().wait().unwrap();
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    let start = Instant::now();
+    while (zenohsrc.messages_received() < 3 || zenohsrc.dropped_filtered() < 3)
+        && start.elapsed() < Duration::from_secs(3)
+    {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let received = zenohsrc.messages_received();
+    let dropped_filtered = zenohsrc.dropped_filtered();
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(received, 3, "PUT samples should be delivered");
+    assert_eq!(dropped_filtered, 3, "DELETE samples should be filtered");
+    assert_eq!(*eos_count.lock().unwrap(), 0, "filtered DELETEs must not emit eos");
+}
+
+/// Publishes an empty buffer then a 16 byte one through zenohsink, returning
+/// the sizes of the buffers zenohsrc pushed and its `dropped-filtered` count.
+fn empty_buffer_round_trip(drop_empty: bool) -> (Vec<usize>, u64) {
+    let key_expr = unique_key_expr("drop_empty");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .drop_empty(drop_empty)
+        .build();
+    assert_eq!(zenohsrc.drop_empty(), drop_empty);
+
+    let sizes: Arc<Mutex<Vec<usize>>> = Arc::new(Mutex::new(Vec::new()));
+    let sizes_clone = sizes.clone();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .property("signal-handoffs", true)
+        .build()
+        .unwrap();
+    fakesink.connect("handoff", false, move |values| {
+        let buffer = values[1].get::<gst::Buffer>().unwrap();
+        sizes_clone.lock().unwrap().push(buffer.size());
+        None
+    });
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build();
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    appsrc.push_buffer(gst::Buffer::new()).unwrap();
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![7u8; 16]))
+        .unwrap();
+
+    // The 16 byte buffer arrives last
+    let start = Instant::now();
+    while !sizes.lock().unwrap().contains(&16) && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let dropped_filtered = zenohsrc.dropped_filtered();
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let sizes = sizes.lock().unwrap().clone();
+    (sizes, dropped_filtered)
+}
+
+/// Test that empty PUT samples are pushed as empty buffers, or dropped with drop-empty.
+#[test]
+#[serial]
+fn test_drop_empty() {
+    init();
+
+    let (sizes, dropped_filtered) = empty_buffer_round_trip(false);
+    assert_eq!(sizes, [0, 16], "the empty buffer should be delivered");
+    assert_eq!(dropped_filtered, 0);
+
+    let (sizes, dropped_filtered) = empty_buffer_round_trip(true);
+    assert_eq!(sizes, [16], "the empty buffer should be dropped");
+    assert_eq!(dropped_filtered, 1);
+}
+
+/// Test that forward-events carries a custom downstream event and EOS from
+/// zenohsink to zenohsrc.
+#[test]
+#[serial]
+fn test_forward_events() {
+    init();
+
+    let key_expr = unique_key_expr("forward_events");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .forward_events(true)
+        .build();
+    assert!(zenohsrc.forward_events());
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    // Custom events reaching the sink, by structure name
+    let events: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    fakesink.static_pad("sink").unwrap().add_probe(
+        gst::PadProbeType::EVENT_DOWNSTREAM,
+        move |_pad, info| {
+            if let Some(gst::PadProbeData::Event(ref event)) = info.data
+                && event.type_() == gst::EventType::CustomDownstream
+                && let Some(s) = event.structure()
+            {
+                events_clone.lock().unwrap().push(s.name().to_string());
+            }
+            gst::PadProbeReturn::Ok
+        },
+    );
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .forward_events(true)
+        .build();
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let marker = gst::Structure::builder("test-marker")
+        .field("index", 1i32)
+        .build();
+    appsrc.send_event(gst::event::CustomDownstream::new(marker));
+
+    // Both the sending and receiving sources push custom events ahead of
+    // their next buffer, so keep the data flowing
+    let start = Instant::now();
+    while events.lock().unwrap().is_empty() && start.elapsed() < Duration::from_secs(5) {
+        appsrc
+            .push_buffer(gst::Buffer::from_slice(vec![0u8; 16]))
+            .unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+    assert_eq!(*events.lock().unwrap(), ["test-marker"]);
+
+    // EOS ends the receiving pipeline too
+    appsrc.end_of_stream().unwrap();
+    let msg = recv_pipeline.bus().unwrap().timed_pop_filtered(
+        gst::ClockTime::from_seconds(5),
+        &[gst::MessageType::Eos, gst::MessageType::Error],
+    );
+    assert!(
+        matches!(msg.map(|m| m.type_()), Some(gst::MessageType::Eos)),
+        "EOS should be forwarded to the receiving pipeline"
+    );
+
+    stop_pipeline_with_timeout(&send_pipeline, Duration::from_secs(5));
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(5));
+}
+
+/// Test that a forwarded EOS ends the receiving stream after the buffers
+/// published before it, even when they are still queued in zenohsrc.
+#[test]
+#[serial]
+fn test_forward_events_eos_after_queued_buffers() {
+    init();
+
+    let key_expr = unique_key_expr("forward_eos_order");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .forward_events(true)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    // A slow consumer, so the buffers pile up behind the EOS
+    let received = Arc::new(AtomicU64::new(0));
+    let received_clone = received.clone();
+    fakesink
+        .static_pad("sink")
+        .unwrap()
+        .add_probe(gst::PadProbeType::BUFFER, move |_pad, _info| {
+            thread::sleep(Duration::from_millis(10));
+            received_clone.fetch_add(1, Ordering::SeqCst);
+            gst::PadProbeReturn::Ok
+        });
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .forward_events(true)
+        .build();
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    const BUFFERS: u64 = 30;
+    for i in 0..BUFFERS {
+        appsrc
+            .push_buffer(gst::Buffer::from_slice(vec![i as u8; 16]))
+            .unwrap();
+    }
+    appsrc.end_of_stream().unwrap();
+
+    let msg = recv_pipeline.bus().unwrap().timed_pop_filtered(
+        gst::ClockTime::from_seconds(10),
+        &[gst::MessageType::Eos, gst::MessageType::Error],
+    );
+    assert!(
+        matches!(msg.map(|m| m.type_()), Some(gst::MessageType::Eos)),
+        "EOS should be forwarded to the receiving pipeline"
+    );
+    assert_eq!(
+        received.load(Ordering::SeqCst),
+        BUFFERS,
+        "every buffer published before EOS should arrive"
+    );
+
+    stop_pipeline_with_timeout(&send_pipeline, Duration::from_secs(5));
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(5));
+}
+
+/// Test that enable-fku relays a force-key-unit event sent upstream by the
+/// receiving pipeline to the upstream of zenohsink.
+#[test]
+#[serial]
+fn test_force_key_unit_relay() {
+    init();
+
+    let key_expr = unique_key_expr("fku");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Time)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .enable_fku(true)
+        .build();
+    assert!(zenohsink.enable_fku());
+
+    // Force-key-unit requests reaching the sending source, by all-headers
+    let requests: Arc<Mutex<Vec<bool>>> = Arc::new(Mutex::new(Vec::new()));
+    let requests_clone = requests.clone();
+    appsrc.static_pad("src").unwrap().add_probe(
+        gst::PadProbeType::EVENT_UPSTREAM,
+        move |_pad, info| {
+            if let Some(gst::PadProbeData::Event(ref event)) = info.data
+                && let Some(s) = event.structure()
+                && s.name() == "GstForceKeyUnit"
+            {
+                requests_clone
+                    .lock()
+                    .unwrap()
+                    .push(s.get::<bool>("all-headers").unwrap());
+            }
+            gst::PadProbeReturn::Ok
+        },
+    );
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .enable_fku(true)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    // What a decoder that lost a frame sends upstream
+    let fku = gst::Structure::builder("GstForceKeyUnit")
+        .field("running-time", gst::ClockTime::from_seconds(1))
+        .field("all-headers", true)
+        .field("count", 0u32)
+        .build();
+
+    // The request is lost until the subscriber declaration reaches the session
+    let start = Instant::now();
+    while requests.lock().unwrap().is_empty() && start.elapsed() < Duration::from_secs(5) {
+        assert!(
+            fakesink.send_event(gst::event::CustomUpstream::new(fku.clone())),
+            "zenohsrc should handle the force-key-unit event"
+        );
+        thread::sleep(Duration::from_millis(100));
+    }
+    assert_eq!(requests.lock().unwrap().first(), Some(&true));
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(5));
+    stop_pipeline_with_timeout(&send_pipeline, Duration::from_secs(5));
+}
+
+/// Test that batch-lists publishes a buffer list as one sample and that
+/// zenohsrc splits it back into the original buffers.
+#[test]
+#[serial]
+fn test_batch_lists_round_trip() {
+    init();
+
+    let key_expr = unique_key_expr("batch_lists");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .build();
+
+    // fakesink renders the buffers of a list one by one
+    let received: Arc<Mutex<Vec<Vec<u8>>>> = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .property("signal-handoffs", true)
+        .build()
+        .unwrap();
+    fakesink.connect("handoff", false, move |values| {
+        let buffer = values[1].get::<gst::Buffer>().unwrap();
+        let data = buffer.map_readable().unwrap().to_vec();
+        received_clone.lock().unwrap().push(data);
+        None
+    });
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .batch_lists(true)
+        .build();
+    assert!(zenohsink.batch_lists());
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let sent: Vec<Vec<u8>> = vec![vec![1; 100], vec![2; 7], vec![3; 1200]];
+    let mut list = gst::BufferList::new();
+    {
+        let list_mut = list.get_mut().unwrap();
+        for data in &sent {
+            list_mut.add(gst::Buffer::from_slice(data.clone()));
+        }
+    }
+    appsrc.push_buffer_list(list).expect("Failed to push buffer list");
+
+    let start = Instant::now();
+    while received.lock().unwrap().len() < sent.len() && start.elapsed() < Duration::from_secs(5)
+    {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let messages_sent = zenohsink.messages_sent();
+    let messages_received = zenohsrc.messages_received();
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(*received.lock().unwrap(), sent, "batch should be split into the sent buffers");
+    assert_eq!(messages_sent, 3, "messages-sent counts buffers");
+    assert_eq!(messages_received, 3, "messages-received counts buffers");
+}
+
+/// Test that aggregate-window-ms coalesces small buffers into a few batched puts.
+#[test]
+#[serial]
+fn test_aggregate_window_reduces_puts() {
+    init();
+
+    let key_expr = unique_key_expr("aggregate_window");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .aggregate_window_ms(100)
+        .build();
+    assert_eq!(zenohsink.aggregate_window_ms(), 100);
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    for i in 0..50u8 {
+        appsrc
+            .push_buffer(gst::Buffer::from_slice(vec![i; 64]))
+            .expect("Failed to push buffer");
+    }
+    // EOS publishes what is still pending
+    appsrc.end_of_stream().unwrap();
+    let bus = send_pipeline.bus().unwrap();
+    let msg = bus.timed_pop_filtered(
+        gst::ClockTime::from_seconds(5),
+        &[gst::MessageType::Eos, gst::MessageType::Error],
+    );
+    assert!(matches!(msg.map(|m| m.type_()), Some(gst::MessageType::Eos)));
+    let messages_sent = zenohsink.messages_sent();
+
+    thread::sleep(Duration::from_millis(200));
+    let mut puts = 0;
+    while let Ok(Some(_)) = subscriber.try_recv() {
+        puts += 1;
+    }
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+
+    assert_eq!(messages_sent, 50, "messages-sent counts buffers");
+    assert!(puts >= 1, "the aggregated buffers should be published");
+    assert!(puts <= 5, "50 small buffers should take a few puts, got {}", puts);
+}
+
+/// Test that stopping without EOS still publishes the pending aggregated batch.
+#[test]
+#[serial]
+fn test_aggregate_window_published_on_stop() {
+    init();
+
+    let key_expr = unique_key_expr("aggregate_stop");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    // A window long enough that only stopping can publish the batch
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .aggregate_window_ms(10000)
+        .build();
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    for i in 0..3u8 {
+        appsrc
+            .push_buffer(gst::Buffer::from_slice(vec![i; 64]))
+            .expect("Failed to push buffer");
+    }
+    thread::sleep(Duration::from_millis(300));
+    assert!(subscriber.try_recv().unwrap().is_none(), "the batch should be pending");
+
+    // No EOS: the pending batch goes out when the sink stops
+    send_pipeline.set_state(gst::State::Ready).unwrap();
+    thread::sleep(Duration::from_millis(200));
+    let mut puts = 0;
+    while let Ok(Some(_)) = subscriber.try_recv() {
+        puts += 1;
+    }
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+
+    assert_eq!(puts, 1, "the pending batch should be published on stop");
+}
+
+/// Test that timestamp-mode=arrival stamps buffers with the running time at
+/// receive, and that pipeline-clock backdates it by the age of the sample.
+#[test]
+#[serial]
+fn test_timestamp_modes() {
+    init();
+
+    let key_expr = unique_key_expr("timestamp_mode");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+
+    // Records (PTS, running time) of the first buffer of each source
+    let mut captured = Vec::new();
+    for mode in [gstzenoh::TimestampMode::Arrival, gstzenoh::TimestampMode::PipelineClock] {
+        let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+            .session(zenoh_session.clone())
+            .receive_timeout_ms(50)
+            .timestamp_mode(mode)
+            .build();
+        assert_eq!(zenohsrc.timestamp_mode(), mode);
+
+        let fakesink = gst::ElementFactory::make("fakesink")
+            .property("sync", false)
+            .build()
+            .unwrap();
+
+        let src_elem: gst::Element = zenohsrc.clone().upcast();
+        recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+        src_elem.link(&fakesink).unwrap();
+
+        let first = Arc::new(Mutex::new(None));
+        let first_clone = first.clone();
+        let src_clone = src_elem.clone();
+        let srcpad = zenohsrc.static_pad("src").unwrap();
+        srcpad.add_probe(gst::PadProbeType::BUFFER, move |_, probe_info| {
+            if let Some(gst::PadProbeData::Buffer(ref buffer)) = probe_info.data {
+                *first_clone.lock().unwrap() =
+                    Some((buffer.pts(), src_clone.current_running_time()));
+            }
+            gst::PadProbeReturn::Remove
+        });
+        captured.push(first);
+    }
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    // Let the running time grow past the sample age
+    thread::sleep(Duration::from_millis(1500));
+
+    let now = zenoh_session.new_timestamp();
+    let backdated = zenoh::time::Timestamp::new(
+        zenoh::time::NTP64::from(now.get_time().to_duration() - Duration::from_secs(1)),
+        *now.get_id(),
+    );
+    zenoh_session
+        .put(key_expr.clone(), vec![0u8; 16])
+        .timestamp(backdated)
+        .wait()
+        .unwrap();
+
+    let start = Instant::now();
+    while captured.iter().any(|c| c.lock().unwrap().is_none())
+        && start.elapsed() < Duration::from_secs(3)
+    {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let offset = |index: usize| {
+        let (pts, running_time) = captured[index].lock().unwrap().expect("No buffer received");
+        let pts = pts.expect("Buffer has no PTS");
+        running_time.expect("No running time") - pts
+    };
+
+    // Arrival: PTS is the running time when the sample was received
+    assert!(offset(0) < gst::ClockTime::from_mseconds(500));
+    // Pipeline clock: PTS is about one second (the sample age) earlier
+    assert!(offset(1) >= gst::ClockTime::from_mseconds(900));
+}
+
+/// Test that zenohsink numbers consecutive buffers when sequence-numbers is enabled.
+#[test]
+#[serial]
+fn test_sink_sequence_numbers() {
+    init();
+
+    let key_expr = unique_key_expr("seqnum_sink");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let seqnums = Arc::new(Mutex::new(Vec::new()));
+    let seqnums_clone = seqnums.clone();
+    let _subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .callback(move |sample| {
+            let seqnum = sample
+                .attachment()
+                .and_then(|a| gstzenoh::metadata::MetadataParser::parse(a).ok())
+                .and_then(|m| m.seqnum());
+            seqnums_clone.lock().unwrap().push(seqnum);
+        })
+        .wait()
+        .expect("Failed to declare subscriber");
+
+    let pipeline = gst::Pipeline::new();
+    let src = gst::ElementFactory::make("videotestsrc")
+        .property("num-buffers", 5i32)
+        .build()
+        .unwrap();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .sequence_numbers(true)
+        .build();
+    assert!(zenohsink.sequence_numbers());
+
+    let sink_elem: gst::Element = zenohsink.upcast();
+    pipeline.add_many([&src, &sink_elem]).unwrap();
+    src.link(&sink_elem).unwrap();
+
+    pipeline.set_state(gst::State::Playing).unwrap();
+    let bus = pipeline.bus().unwrap();
+    bus.timed_pop_filtered(
+        gst::ClockTime::from_seconds(5),
+        &[gst::MessageType::Eos, gst::MessageType::Error],
+    );
+    thread::sleep(Duration::from_millis(200));
+    pipeline.set_state(gst::State::Null).unwrap();
+
+    let seqnums = seqnums.lock().unwrap();
+    assert_eq!(*seqnums, (0..5).map(Some).collect::<Vec<_>>());
+}
+
+/// Test that zenohsrc counts gaps and late arrivals in the publisher's sequence numbers.
+#[test]
+#[serial]
+fn test_src_samples_lost_and_reordered() {
+    init();
+
+    let key_expr = unique_key_expr("seqnum_src");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .build();
+
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .wait()
+        .expect("Failed to declare publisher");
+
+    // 3, 4, 7 and 8 are "dropped"; 4 shows up after 6
+    let sent = [0u64, 1, 2, 5, 6, 4, 9];
+    for seqnum in sent {
+        let attachment = gstzenoh::metadata::MetadataBuilder::new()
+            .seqnum(seqnum)
+            .build()
+            .unwrap();
+        publisher.put(vec![0u8; 16]).attachment(attachment).wait().unwrap();
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    let start = Instant::now();
+    while zenohsrc.messages_received() < sent.len() as u64
+        && start.elapsed() < Duration::from_secs(3)
+    {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let received = zenohsrc.messages_received();
+    let lost = zenohsrc.samples_lost();
+    let reordered = zenohsrc.samples_reordered();
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(received, sent.len() as u64);
+    assert_eq!(lost, 3, "3, 7 and 8 never arrived");
+    assert_eq!(reordered, 1, "4 arrived after 6");
+}
+
+/// Test that reorder-window outputs shuffled samples in sequence number order,
+/// and drops the one arriving after the window gave up on it.
+#[test]
+#[serial]
+fn test_src_reorder_window() {
+    init();
+
+    let key_expr = unique_key_expr("reorder_window");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .reorder_window(4)
+        .reorder_timeout_ms(2000)
+        .build();
+    assert_eq!(zenohsrc.reorder_window(), 4);
+
+    // The payload of each sample is its sequence number
+    let received: Arc<Mutex<Vec<u8>>> = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .property("signal-handoffs", true)
+        .build()
+        .unwrap();
+    fakesink.connect("handoff", false, move |values| {
+        let buffer = values[1].get::<gst::Buffer>().unwrap();
+        let data = buffer.map_readable().unwrap();
+        received_clone.lock().unwrap().push(data[0]);
+        None
+    });
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    let publisher = zenoh_session
+        .declare_publisher(key_expr.clone())
+        .wait()
+        .expect("Failed to declare publisher");
+
+    // 4 is held back until 5 to 9 fill the window, which then skips it
+    let sent = [0u8, 2, 1, 3, 6, 5, 8, 7, 9, 4, 10];
+    for seqnum in sent {
+        let attachment = gstzenoh::metadata::MetadataBuilder::new()
+            .seqnum(seqnum.into())
+            .build()
+            .unwrap();
+        publisher.put(vec![seqnum]).attachment(attachment).wait().unwrap();
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    let start = Instant::now();
+    while received.lock().unwrap().last() != Some(&10) && start.elapsed() < Duration::from_secs(3)
+    {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let dropped_late = zenohsrc.dropped_late();
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(*received.lock().unwrap(), [0, 1, 2, 3, 5, 6, 7, 8, 9, 10]);
+    assert_eq!(dropped_late, 1, "4 arrived after the window skipped it");
+}
+
+/// With key-suffix-from-meta, one zenohsink publishes buffers on the key
+/// suffix carried by their ZenohKeyMeta, and the others on its key-expr.
+#[test]
+#[serial]
+fn test_key_suffix_from_meta_routing() {
+    init();
+
+    let key_expr = unique_key_expr("key_suffix_routing");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let count_on = |key: String| {
+        let count = Arc::new(AtomicU64::new(0));
+        let count_clone = count.clone();
+        let subscriber = zenoh_session
+            .declare_subscriber(key)
+            .callback(move |_| {
+                count_clone.fetch_add(1, Ordering::SeqCst);
+            })
+            .wait()
+            .expect("Failed to declare subscriber");
+        (subscriber, count)
+    };
+    let (_sub_base, count_base) = count_on(key_expr.clone());
+    let (_sub_a, count_a) = count_on(format!("{}/a", key_expr));
+    let (_sub_b, count_b) = count_on(format!("{}/b", key_expr));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .key_suffix_from_meta(true)
+        .max_routed_publishers(1)
+        .build();
+    assert!(zenohsink.key_suffix_from_meta());
+    assert_eq!(zenohsink.max_routed_publishers(), 1);
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    // Alternating suffixes also exercises re-declaring evicted publishers
+    for suffix in ["a", "b", "a", "b", "a"].into_iter().map(Some).chain([None]) {
+        let mut buffer = gst::Buffer::from_slice(vec![0u8; 16]);
+        if let Some(suffix) = suffix {
+            gstzenoh::metadata::ZenohKeyMeta::add(buffer.get_mut().unwrap(), suffix).unwrap();
+        }
+        appsrc.push_buffer(buffer).expect("Failed to push buffer");
+    }
+
+    let start = Instant::now();
+    while (count_a.load(Ordering::SeqCst) < 3
+        || count_b.load(Ordering::SeqCst) < 2
+        || count_base.load(Ordering::SeqCst) < 1)
+        && start.elapsed() < Duration::from_secs(5)
+    {
+        thread::sleep(Duration::from_millis(20));
+    }
+    let _ = send_pipeline.set_state(gst::State::Null);
+
+    assert_eq!(count_a.load(Ordering::SeqCst), 3, "buffers routed to <key>/a");
+    assert_eq!(count_b.load(Ordering::SeqCst), 2, "buffers routed to <key>/b");
+    assert_eq!(count_base.load(Ordering::SeqCst), 1, "buffer without meta on <key>");
+}
+
+/// Test that with declaration-delay-ms, a sample published from another session
+/// as soon as the receiver is PLAYING is received.
+#[test]
+#[serial]
+fn test_declaration_delay_first_sample() {
+    init();
+
+    let key_expr = unique_key_expr("declaration_delay");
+
+    let publisher_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    // The receiver opens its own session, so its declaration has to reach the publisher
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .receive_timeout_ms(50)
+        .declaration_delay_ms(500)
+        .build();
+    assert_eq!(zenohsrc.declaration_delay_ms(), 500);
+
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    let start = Instant::now();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(500));
+
+    // No sleep: PLAYING means ready to receive
+    publisher_session.put(key_expr.clone(), vec![0u8; 16]).wait().unwrap();
+
+    let start = Instant::now();
+    while zenohsrc.messages_received() == 0 && start.elapsed() < Duration::from_secs(3) {
+        thread::sleep(Duration::from_millis(20));
+    }
+    let received = zenohsrc.messages_received();
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(received, 1, "Sample published right after start was lost");
+}
+
+/// Test that `mark-discont` flags the first buffer and the one after a
+/// sequence number gap, even though the sender's flags don't have DISCONT.
+#[test]
+#[serial]
+fn test_src_mark_discont() {
+    use gstzenoh::metadata::MetadataBuilder;
+
+    init();
+
+    let key_expr = unique_key_expr("src_mark_discont");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .build();
+    assert!(zenohsrc.mark_discont());
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    let sink_elem: gst::Element = appsink.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &sink_elem]).unwrap();
+    src_elem.link(&sink_elem).unwrap();
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(200));
+
+    // Sequence number 2 never arrives
+    for seqnum in [0u64, 1, 3, 4] {
+        let attachment = MetadataBuilder::new()
+            .flags(gst::BufferFlags::DELTA_UNIT)
+            .seqnum(seqnum)
+            .build()
+            .unwrap();
+        zenoh_session
+            .put(&key_expr, vec![seqnum as u8; 8])
+            .attachment(attachment)
+            .wait()
+            .unwrap();
+    }
+
+    let mut discont = Vec::new();
+    for _ in 0..4 {
+        let sample = appsink
+            .try_pull_sample(gst::ClockTime::from_seconds(5))
+            .expect("Expected a buffer");
+        let buffer = sample.buffer().unwrap();
+        assert!(buffer.flags().contains(gst::BufferFlags::DELTA_UNIT));
+        discont.push(buffer.flags().contains(gst::BufferFlags::DISCONT));
+    }
+    let samples_lost = zenohsrc.samples_lost();
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert_eq!(samples_lost, 1);
+    assert_eq!(discont, [true, false, true, false]);
+}
+
+/// Test that reconnect notices the session losing its only peer, emits
+/// `reconnecting`, and delivers the data of the peer that comes back.
+#[test]
+#[serial]
+fn test_src_reconnect_after_peer_loss() {
+    init();
+
+    let key_expr = unique_key_expr("src_reconnect");
+
+    // Only the peers connecting to the listening zenohsrc session take part
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .config_json5(
+            r#"{
+                listen: { endpoints: ["tcp/127.0.0.1:0"] },
+                scouting: { multicast: { enabled: false } },
+            }"#,
+        )
+        .receive_timeout_ms(50)
+        .reconnect(true)
+        .build();
+    let attempts = Arc::new(AtomicU64::new(0));
+    let attempts_clone = attempts.clone();
+    zenohsrc.connect_reconnecting(move |src, _attempt| {
+        // The element isn't locked while reconnecting
+        let _ = src.messages_received();
+        attempts_clone.fetch_add(1, Ordering::SeqCst);
+    });
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    let sink_elem: gst::Element = appsink.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &sink_elem]).unwrap();
+    src_elem.link(&sink_elem).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let start = Instant::now();
+    let locator = loop {
+        if let Some(session) = zenohsrc.session() {
+            break session.info().locators().wait().remove(0);
+        }
+        assert!(start.elapsed() < Duration::from_secs(5), "zenohsrc didn't start");
+        thread::sleep(Duration::from_millis(10));
+    };
+    let open_peer = || {
+        let mut config = zenoh::Config::default();
+        config
+            .insert_json5("connect/endpoints", &format!(r#"["{}"]"#, locator))
+            .unwrap();
+        config
+            .insert_json5("scouting/multicast/enabled", "false")
+            .unwrap();
+        zenoh::open(config).wait().expect("Failed to open Zenoh session")
+    };
+    // Publishes `payload` until zenohsrc outputs it, returning its buffer
+    let receive_from = |peer: &zenoh::Session, payload: &[u8]| {
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_secs(10) {
+            peer.put(&key_expr, payload.to_vec()).wait().unwrap();
+            while let Some(sample) = appsink.try_pull_sample(gst::ClockTime::from_mseconds(100)) {
+                let buffer = sample.buffer_owned().unwrap();
+                if buffer.map_readable().unwrap().as_slice() == payload {
+                    return Some(buffer);
+                }
+            }
+        }
+        None
+    };
+
+    let peer = open_peer();
+    assert!(receive_from(&peer, b"before").is_some(), "no data before the loss");
+    assert_eq!(attempts.load(Ordering::SeqCst), 0);
+    // Let the connectivity check see the peer before it goes
+    thread::sleep(Duration::from_secs(1));
+
+    // Losing the only peer leaves the subscriber without data, not with an error
+    peer.close().wait().unwrap();
+    let start = Instant::now();
+    while attempts.load(Ordering::SeqCst) == 0 && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(attempts.load(Ordering::SeqCst) > 0, "reconnecting not emitted");
+
+    // Data flows again from the peer that comes back, starting anew
+    let peer = open_peer();
+    let buffer = receive_from(&peer, b"after").expect("no data after reconnecting");
+    assert!(buffer.flags().contains(gst::BufferFlags::DISCONT));
+
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(5));
+}
@@ -397,3 +397,689 @@ fn test_demux_data_routing() {
         "Received data should match sent pattern"
     );
 }
+
+/// Test that apply-buffer-meta=false leaves buffers untimed but still sets caps
+#[test]
+#[serial]
+fn test_demux_apply_buffer_meta_toggle() {
+    use gstzenoh::metadata::MetadataBuilder;
+    use zenoh::Wait;
+
+    init();
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let caps = gst::Caps::builder("application/x-test").build();
+
+    for apply in [true, false] {
+        let base_key = unique_key_expr("demux_apply_meta");
+        let demux = gstzenoh::ZenohDemux::builder(&format!("{}/*", base_key))
+            .session_group(&format!("test_apply_meta_{}", std::process::id()))
+            .apply_buffer_meta(apply)
+            .build();
+        assert_eq!(demux.apply_buffer_meta(), apply);
+
+        let received: Arc<Mutex<Option<(Option<gst::ClockTime>, Option<gst::Caps>)>>> =
+            Arc::new(Mutex::new(None));
+        let received_clone = received.clone();
+        demux.connect_pad_added(move |_, pad| {
+            let received = received_clone.clone();
+            pad.add_probe(gst::PadProbeType::BUFFER, move |pad, probe_info| {
+                if let Some(gst::PadProbeData::Buffer(ref buffer)) = probe_info.data {
+                    *received.lock().unwrap() = Some((buffer.pts(), pad.current_caps()));
+                }
+                gst::PadProbeReturn::Drop
+            });
+        });
+
+        let pipeline = gst::Pipeline::new();
+        pipeline.add(&demux).unwrap();
+        pipeline.set_state(gst::State::Playing).unwrap();
+        thread::sleep(Duration::from_millis(300));
+
+        let attachment = MetadataBuilder::new()
+            .caps(&caps)
+            .pts(Some(gst::ClockTime::from_seconds(1)))
+            .build()
+            .unwrap();
+        session
+            .put(format!("{}/stream", base_key), vec![1u8, 2, 3])
+            .attachment(attachment)
+            .wait()
+            .unwrap();
+
+        let start = Instant::now();
+        while received.lock().unwrap().is_none() && start.elapsed() < Duration::from_secs(5) {
+            thread::sleep(Duration::from_millis(20));
+        }
+        stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+        let (pts, pad_caps) = received
+            .lock()
+            .unwrap()
+            .take()
+            .expect("No buffer received");
+        let expected_pts = apply.then_some(gst::ClockTime::from_seconds(1));
+        assert_eq!(pts, expected_pts, "apply-buffer-meta={}", apply);
+        assert_eq!(pad_caps.as_ref(), Some(&caps), "caps must be set either way");
+    }
+}
+
+/// Test that each buffer carries its source key, even when pad names are hashed
+#[test]
+#[serial]
+fn test_demux_key_meta_with_hash_naming() {
+    use gstzenoh::metadata::ZenohKeyMeta;
+    use zenoh::Wait;
+
+    init();
+
+    let base_key = unique_key_expr("demux_key_meta");
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/*", base_key))
+        .session_group(&format!("test_key_meta_{}", std::process::id()))
+        .pad_naming(gstzenoh::PadNaming::Hash)
+        .build();
+
+    let received: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    demux.connect_pad_added(move |_, pad| {
+        let received = received_clone.clone();
+        pad.add_probe(gst::PadProbeType::BUFFER, move |_, probe_info| {
+            if let Some(gst::PadProbeData::Buffer(ref buffer)) = probe_info.data {
+                if let Some(key) = ZenohKeyMeta::key_expr(buffer) {
+                    received.lock().unwrap().push(key);
+                }
+            }
+            gst::PadProbeReturn::Drop
+        });
+    });
+
+    let pipeline = gst::Pipeline::new();
+    pipeline.add(&demux).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let keys = [format!("{}/front", base_key), format!("{}/rear", base_key)];
+    for key in &keys {
+        session.put(key, vec![0u8; 8]).wait().unwrap();
+    }
+
+    let start = Instant::now();
+    while received.lock().unwrap().len() < keys.len() && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(20));
+    }
+    stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+    let mut received = received.lock().unwrap().clone();
+    received.sort();
+    assert_eq!(received, keys);
+}
+
+/// Test that single-pad pushes every key on one pad, tagged with its source key
+#[test]
+#[serial]
+fn test_demux_single_pad() {
+    use gstzenoh::metadata::ZenohKeyMeta;
+    use zenoh::Wait;
+
+    init();
+
+    let base_key = unique_key_expr("demux_single_pad");
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/sensor/**", base_key))
+        .session_group(&format!("test_single_pad_{}", std::process::id()))
+        .single_pad(true)
+        .build();
+    assert!(demux.single_pad());
+
+    let received: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    demux.connect_pad_added(move |_, pad| {
+        let received = received_clone.clone();
+        pad.add_probe(gst::PadProbeType::BUFFER, move |_, probe_info| {
+            if let Some(gst::PadProbeData::Buffer(ref buffer)) = probe_info.data {
+                if let Some(key) = ZenohKeyMeta::key_expr(buffer) {
+                    received.lock().unwrap().push(key);
+                }
+            }
+            gst::PadProbeReturn::Drop
+        });
+    });
+
+    let pipeline = gst::Pipeline::new();
+    pipeline.add(&demux).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    // The pad is there before any data
+    assert!(demux.static_pad("src").is_some());
+    thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let keys = [
+        format!("{}/sensor/humidity", base_key),
+        format!("{}/sensor/room1/temperature", base_key),
+        format!("{}/sensor/room2/temperature", base_key),
+    ];
+    for key in &keys {
+        session.put(key, vec![0u8; 8]).wait().unwrap();
+    }
+
+    let start = Instant::now();
+    while received.lock().unwrap().len() < keys.len() && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(20));
+    }
+    assert_eq!(demux.src_pads().len(), 1);
+    assert_eq!(demux.pads_created(), 1);
+    stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+    let mut received = received.lock().unwrap().clone();
+    received.sort();
+    assert_eq!(received, keys);
+}
+
+/// Returns the name of the first pad zenohdemux adds for data published on
+/// `<base_key>/camera/front`.
+fn stripped_pad_name(base_key: &str, naming: gstzenoh::PadNaming) -> Option<String> {
+    use zenoh::Wait;
+
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/**", base_key))
+        .session_group(&format!("test_strip_prefix_{}", std::process::id()))
+        .pad_naming(naming)
+        .strip_prefix(&format!("{}/", base_key))
+        .build();
+    assert_eq!(demux.strip_prefix(), format!("{}/", base_key));
+
+    let pad_name: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+    let pad_name_clone = pad_name.clone();
+    demux.connect_pad_added(move |_, pad| {
+        pad_name_clone
+            .lock()
+            .unwrap()
+            .get_or_insert_with(|| pad.name().to_string());
+    });
+
+    let pipeline = gst::Pipeline::new();
+    pipeline.add(&demux).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let start = Instant::now();
+    while pad_name.lock().unwrap().is_none() && start.elapsed() < Duration::from_secs(5) {
+        session
+            .put(format!("{}/camera/front", base_key), vec![0u8; 8])
+            .wait()
+            .unwrap();
+        thread::sleep(Duration::from_millis(50));
+    }
+    stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+    pad_name.lock().unwrap().take()
+}
+
+/// Test that strip-prefix is applied before each pad naming strategy
+#[test]
+#[serial]
+fn test_demux_strip_prefix_pad_naming() {
+    init();
+
+    let base_key = unique_key_expr("demux_strip_prefix");
+    assert_eq!(
+        stripped_pad_name(&base_key, gstzenoh::PadNaming::FullPath).as_deref(),
+        Some("camera_front")
+    );
+    assert_eq!(
+        stripped_pad_name(&base_key, gstzenoh::PadNaming::LastSegment).as_deref(),
+        Some("front")
+    );
+
+    // Hashing only sees the stripped key, so the name doesn't depend on the prefix
+    let hashed = stripped_pad_name(&base_key, gstzenoh::PadNaming::Hash).expect("No pad added");
+    let other_base_key = unique_key_expr("demux_strip_prefix_other");
+    assert_eq!(
+        stripped_pad_name(&other_base_key, gstzenoh::PadNaming::Hash).as_deref(),
+        Some(hashed.as_str())
+    );
+    assert!(hashed.starts_with("pad_"));
+}
+
+/// Test that pad-name-map names the mapped keys, the others falling back to pad-naming
+#[test]
+#[serial]
+fn test_demux_pad_name_map() {
+    use zenoh::Wait;
+
+    init();
+
+    let base_key = unique_key_expr("demux_pad_name_map");
+    let map = gst::Structure::builder("map")
+        .field(format!("{}/camera/front", base_key).as_str(), "front_cam")
+        .build();
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/**", base_key))
+        .session_group(&format!("test_pad_name_map_{}", std::process::id()))
+        .pad_naming(gstzenoh::PadNaming::LastSegment)
+        .pad_name_map(map.clone())
+        .build();
+    assert_eq!(demux.pad_name_map(), Some(map));
+
+    let pad_names: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let pad_names_clone = pad_names.clone();
+    demux.connect_pad_added(move |_, pad| {
+        pad_names_clone.lock().unwrap().push(pad.name().to_string());
+    });
+
+    let pipeline = gst::Pipeline::new();
+    pipeline.add(&demux).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let start = Instant::now();
+    while pad_names.lock().unwrap().len() < 2 && start.elapsed() < Duration::from_secs(5) {
+        for camera in ["front", "rear"] {
+            session
+                .put(format!("{}/camera/{}", base_key, camera), vec![0u8; 8])
+                .wait()
+                .unwrap();
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+    let mut pad_names = pad_names.lock().unwrap().clone();
+    pad_names.sort();
+    assert_eq!(pad_names, ["front_cam", "rear"]);
+}
+
+/// Test that a pad-name-map with an invalid pad name fails the start
+#[test]
+#[serial]
+fn test_demux_pad_name_map_invalid() {
+    init();
+
+    let demux = gstzenoh::ZenohDemux::builder(&unique_key_expr("demux_pad_name_map_invalid"))
+        .pad_name_map(
+            gst::Structure::builder("map")
+                .field("camera/front", "front cam")
+                .build(),
+        )
+        .build();
+    assert!(
+        demux.set_state(gst::State::Paused).is_err(),
+        "A pad name with a space should be refused"
+    );
+    demux.set_state(gst::State::Null).unwrap();
+}
+
+/// Test that user metadata sent by the publisher reaches downstream consumers
+#[test]
+#[serial]
+fn test_demux_user_metadata_passthrough() {
+    use gstzenoh::metadata::{MetadataBuilder, ZenohUserMeta};
+    use zenoh::Wait;
+
+    init();
+
+    let base_key = unique_key_expr("demux_user_meta");
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/*", base_key))
+        .session_group(&format!("test_user_meta_{}", std::process::id()))
+        .build();
+
+    let received: Arc<Mutex<Option<(Option<String>, Option<Vec<u8>>)>>> =
+        Arc::new(Mutex::new(None));
+    let received_clone = received.clone();
+    demux.connect_pad_added(move |_, pad| {
+        let received = received_clone.clone();
+        pad.add_probe(gst::PadProbeType::BUFFER, move |_, probe_info| {
+            if let Some(gst::PadProbeData::Buffer(ref buffer)) = probe_info.data {
+                *received.lock().unwrap() = Some((
+                    ZenohUserMeta::user_metadata(buffer, "sensor-id"),
+                    ZenohUserMeta::user_metadata_bytes(buffer, "calibration"),
+                ));
+            }
+            gst::PadProbeReturn::Drop
+        });
+    });
+
+    let pipeline = gst::Pipeline::new();
+    pipeline.add(&demux).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let attachment = MetadataBuilder::new()
+        .user_metadata("sensor-id", "imu-42")
+        .user_metadata_bytes("calibration", vec![0xde, 0xad, 0xbe, 0xef])
+        .build()
+        .unwrap();
+    session
+        .put(format!("{}/imu", base_key), vec![1u8, 2, 3])
+        .attachment(attachment)
+        .wait()
+        .unwrap();
+
+    let start = Instant::now();
+    while received.lock().unwrap().is_none() && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(20));
+    }
+    stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+    let (sensor_id, calibration) = received
+        .lock()
+        .unwrap()
+        .take()
+        .expect("No buffer received");
+    assert_eq!(sensor_id.as_deref(), Some("imu-42"));
+    assert_eq!(calibration, Some(vec![0xde, 0xad, 0xbe, 0xef]));
+}
+
+/// Test that with per-pad queues a blocked branch doesn't stall the other pads
+#[test]
+#[serial]
+fn test_demux_per_pad_queue_isolates_blocked_pad() {
+    use std::sync::Condvar;
+    use zenoh::Wait;
+
+    init();
+
+    let base_key = unique_key_expr("demux_pad_queue");
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/*", base_key))
+        .session_group(&format!("test_pad_queue_{}", std::process::id()))
+        .receive_timeout_ms(50)
+        .per_pad_queue_size(4)
+        .build();
+    assert_eq!(demux.per_pad_queue_size(), 4);
+
+    // The "slow" pad blocks on its first buffer until released
+    let release = Arc::new((Mutex::new(false), Condvar::new()));
+    let fast_received = Arc::new(AtomicU64::new(0));
+    let release_clone = release.clone();
+    let fast_clone = fast_received.clone();
+    demux.connect_pad_added(move |_, pad| {
+        if pad.name().ends_with("slow") {
+            let release = release_clone.clone();
+            pad.add_probe(gst::PadProbeType::BUFFER, move |_, _| {
+                let (released, cond) = &*release;
+                let mut released = released.lock().unwrap();
+                while !*released {
+                    released = cond.wait(released).unwrap();
+                }
+                gst::PadProbeReturn::Drop
+            });
+        } else {
+            let fast = fast_clone.clone();
+            pad.add_probe(gst::PadProbeType::BUFFER, move |_, _| {
+                fast.fetch_add(1, Ordering::SeqCst);
+                gst::PadProbeReturn::Drop
+            });
+        }
+    });
+
+    let pipeline = gst::Pipeline::new();
+    pipeline.add(&demux).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let num_samples = 20;
+    for _ in 0..num_samples {
+        for stream in ["slow", "fast"] {
+            session
+                .put(format!("{}/{}", base_key, stream), vec![0u8; 16])
+                .wait()
+                .unwrap();
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    let start = Instant::now();
+    while fast_received.load(Ordering::SeqCst) < num_samples
+        && start.elapsed() < Duration::from_secs(5)
+    {
+        thread::sleep(Duration::from_millis(20));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let dropped_queue_full = demux.dropped_queue_full();
+
+    let (released, cond) = &*release;
+    *released.lock().unwrap() = true;
+    cond.notify_all();
+    stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+    assert_eq!(
+        fast_received.load(Ordering::SeqCst),
+        num_samples,
+        "the fast pad should get every buffer while the slow one is blocked"
+    );
+    assert!(
+        dropped_queue_full > 0,
+        "the blocked pad's queue should have overflowed"
+    );
+}
+
+/// Test that downstream branches receive EOS when the demux stops
+#[test]
+#[serial]
+fn test_demux_sends_eos_on_stop() {
+    use zenoh::Wait;
+
+    init();
+
+    let base_key = unique_key_expr("demux_eos");
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/*", base_key))
+        .session_group(&format!("test_eos_{}", std::process::id()))
+        .receive_timeout_ms(50)
+        .build();
+    let queue = gst::ElementFactory::make("queue").build().unwrap();
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+
+    let pipeline = gst::Pipeline::new();
+    pipeline
+        .add_many([
+            demux.upcast_ref::<gst::Element>(),
+            &queue,
+            appsink.upcast_ref(),
+        ])
+        .unwrap();
+    queue.link(&appsink).unwrap();
+
+    let queue_sink = queue.static_pad("sink").unwrap();
+    demux.connect_pad_added(move |_, pad| {
+        if !queue_sink.is_linked() {
+            pad.link(&queue_sink).unwrap();
+        }
+    });
+
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    session
+        .put(format!("{}/stream", base_key), vec![0u8; 16])
+        .wait()
+        .unwrap();
+
+    assert!(
+        appsink.try_pull_sample(gst::ClockTime::from_seconds(5)).is_some(),
+        "No buffer received before stopping"
+    );
+    assert!(!appsink.is_eos());
+
+    // Only the demux goes to NULL, the downstream branch keeps running
+    demux.set_state(gst::State::Null).unwrap();
+
+    let start = Instant::now();
+    while !appsink.is_eos() && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(20));
+    }
+    let eos = appsink.is_eos();
+    stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+    assert!(eos, "appsink should receive EOS when the demux stops");
+}
+
+/// Test that stopping doesn't wait for a long receive timeout
+#[test]
+#[serial]
+fn test_demux_fast_shutdown_with_long_receive_timeout() {
+    init();
+
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/*", unique_key_expr("demux_shutdown")))
+        .session_group(&format!("test_shutdown_{}", std::process::id()))
+        .receive_timeout_ms(5000)
+        .build();
+
+    let pipeline = gst::Pipeline::new();
+    pipeline.add(&demux).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let start = Instant::now();
+    pipeline.set_state(gst::State::Null).unwrap();
+    let elapsed = start.elapsed();
+    assert!(
+        elapsed < Duration::from_secs(1),
+        "Stopping took {:?} with receive-timeout-ms=5000",
+        elapsed
+    );
+}
+
+/// Test that get-streams reports each pad with its key and own counters
+#[test]
+#[serial]
+fn test_demux_get_streams() {
+    use zenoh::Wait;
+
+    init();
+
+    let base_key = unique_key_expr("demux_streams");
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/*", base_key))
+        .session_group(&format!("test_streams_{}", std::process::id()))
+        .receive_timeout_ms(50)
+        .build();
+    demux.connect_pad_added(|_, pad| {
+        pad.add_probe(gst::PadProbeType::BUFFER, |_, _| gst::PadProbeReturn::Drop);
+    });
+
+    let pipeline = gst::Pipeline::new();
+    pipeline.add(&demux).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    assert!(demux.streams().is_empty());
+    thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let (front, rear) = (format!("{}/front", base_key), format!("{}/rear", base_key));
+    for _ in 0..3 {
+        session.put(&front, vec![0u8; 10]).wait().unwrap();
+    }
+    session.put(&rear, vec![0u8; 25]).wait().unwrap();
+
+    let start = Instant::now();
+    while demux.messages_received() < 4 && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(20));
+    }
+    let streams = demux.streams();
+    stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+    let summary: Vec<(String, String, u64, u64)> = streams
+        .iter()
+        .map(|stream| {
+            (
+                stream.get("pad-name").unwrap(),
+                stream.get("key-expr").unwrap(),
+                stream.get("bytes-received").unwrap(),
+                stream.get("messages-received").unwrap(),
+            )
+        })
+        .collect();
+    let pad_name = |key: &str| key.replace('/', "_");
+    assert_eq!(
+        summary,
+        vec![
+            (pad_name(&front), front.clone(), 30, 3),
+            (pad_name(&rear), rear.clone(), 25, 1),
+        ]
+    );
+}
+
+/// Test that max-pads stops creating pads and counts the samples left without one
+#[test]
+#[serial]
+fn test_demux_max_pads() {
+    use zenoh::Wait;
+
+    init();
+
+    let base_key = unique_key_expr("demux_max_pads");
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/**", base_key))
+        .session_group(&format!("test_max_pads_{}", std::process::id()))
+        .receive_timeout_ms(50)
+        .max_pads(2)
+        .build();
+    assert_eq!(demux.max_pads(), 2);
+    demux.connect_pad_added(|_, pad| {
+        pad.add_probe(gst::PadProbeType::BUFFER, |_, _| gst::PadProbeReturn::Drop);
+    });
+
+    let pipeline = gst::Pipeline::new();
+    pipeline.add(&demux).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    // Each key twice: every rejected sample is counted
+    for _ in 0..2 {
+        for i in 0..5 {
+            let key = format!("{}/sensor{}/data", base_key, i);
+            session.put(&key, vec![i as u8; 8]).wait().unwrap();
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    let start = Instant::now();
+    while (demux.pads_rejected() < 6 || demux.messages_received() < 4)
+        && start.elapsed() < Duration::from_secs(5)
+    {
+        thread::sleep(Duration::from_millis(20));
+    }
+    let (pads_created, pads_rejected) = (demux.pads_created(), demux.pads_rejected());
+    let src_pads = demux.src_pads().len();
+    let messages_received = demux.messages_received();
+    let warning = pipeline
+        .bus()
+        .unwrap()
+        .pop_filtered(&[gst::MessageType::Warning]);
+    stop_pipeline_with_timeout(&pipeline, Duration::from_secs(1));
+
+    assert_eq!(pads_created, 2, "Pad creation should stop at max-pads");
+    assert_eq!(src_pads, 2);
+    assert_eq!(pads_rejected, 6);
+    assert_eq!(
+        messages_received, 4,
+        "Only the samples of the two pads are received"
+    );
+    assert!(
+        warning.is_some(),
+        "A warning should be posted when max-pads is hit"
+    );
+}
@@ -153,3 +153,313 @@ fn test_priority_bounds() {
         "Setting priority outside bounds should panic"
     );
 }
+
+#[test]
+#[serial]
+fn test_zenohsink_malformed_key_in_list() {
+    init();
+
+    // Second entry is empty, third is not a valid key expression
+    for key_expr in ["test/multi/a,", "test/multi/a,test/multi/#bad"] {
+        let sink = gst::ElementFactory::make("zenohsink")
+            .property("key-expr", key_expr)
+            .build()
+            .expect("Failed to create zenohsink");
+
+        // Zenoh resources are created during NULL→READY, so this must fail
+        let result = sink.set_state(gst::State::Ready);
+        assert!(
+            result.is_err(),
+            "Starting with key-expr '{}' should fail",
+            key_expr
+        );
+
+        sink.set_state(gst::State::Null).unwrap();
+    }
+}
+
+#[test]
+#[serial]
+fn test_builders_try_build_validates_key_expr() {
+    init();
+
+    let err = gstzenoh::ZenohSink::builder("bad key#").try_build().unwrap_err();
+    assert!(err.contains("'bad key#'"), "{}", err);
+    assert!(gstzenoh::ZenohSrc::builder("demo//video").try_build().is_err());
+    assert!(gstzenoh::ZenohDemux::builder("").try_build().is_err());
+    assert!(gstzenoh::ZenohQueryableSrc::builder("demo/?x").try_build().is_err());
+    assert!(gstzenoh::ZenohGet::builder("demo/#/snap?x=1").try_build().is_err());
+
+    // Every entry of a sink list is checked, after placeholder expansion
+    assert!(gstzenoh::ZenohSink::builder("demo/a,demo/#b").try_build().is_err());
+    assert!(gstzenoh::ZenohSink::builder("cameras/${id}").try_build().is_err());
+    let sink = gstzenoh::ZenohSink::builder("cameras/${id}/video, cameras/${id}/audio")
+        .key_vars(gst::Structure::builder("vars").field("id", "front").build())
+        .try_build()
+        .expect("Valid key expressions should build");
+    assert_eq!(sink.key_expr(), "cameras/${id}/video, cameras/${id}/audio");
+
+    assert!(gstzenoh::ZenohSrc::builder("demo/**").try_build().is_ok());
+    assert!(gstzenoh::ZenohDemux::builder("demo/*/video").try_build().is_ok());
+    assert!(gstzenoh::ZenohQueryableSrc::builder("demo/latest").try_build().is_ok());
+    assert!(gstzenoh::ZenohGet::builder("demo/snap?quality=high").try_build().is_ok());
+}
+
+#[test]
+#[serial]
+fn test_zenohsrc_rejects_unsupported_compression() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/error/unsupported_compression/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let pipeline = gst::Pipeline::builder().build();
+    let src = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(session.clone())
+        .build();
+    let sink = gst::ElementFactory::make("fakesink").build().unwrap();
+    let src_elem: gst::Element = src.clone().upcast();
+    pipeline.add_many([&src_elem, &sink]).unwrap();
+    src_elem.link(&sink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    std::thread::sleep(Duration::from_millis(200));
+
+    // No build supports "xz", so the receiver must refuse the payload
+    let attachment = gstzenoh::metadata::MetadataBuilder::new()
+        .user_metadata(gstzenoh::metadata::keys::COMPRESSION, "xz")
+        .build()
+        .unwrap();
+    session
+        .put(&key_expr, b"not really compressed".to_vec())
+        .attachment(attachment)
+        .wait()
+        .unwrap();
+
+    let bus = pipeline.bus().unwrap();
+    let msg = bus
+        .timed_pop_filtered(gst::ClockTime::from_seconds(5), &[gst::MessageType::Error])
+        .expect("Expected an error for unsupported compression");
+    let gst::MessageView::Error(err) = msg.view() else {
+        unreachable!()
+    };
+    assert!(err.error().matches(gst::StreamError::Decode));
+    assert!(
+        err.error().message().contains("xz-compressed"),
+        "Unexpected error message: {}",
+        err.error()
+    );
+    assert_eq!(src.messages_received(), 0);
+    assert_eq!(src.errors(), 1);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_zenohsrc_drops_oversized_samples() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/error/max_buffer_size/src/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let pipeline = gst::Pipeline::builder().build();
+    let src = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(session.clone())
+        .max_buffer_size(1024)
+        .build();
+    assert_eq!(src.max_buffer_size(), 1024);
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+    let src_elem: gst::Element = src.clone().upcast();
+    let sink_elem: gst::Element = appsink.clone().upcast();
+    pipeline.add_many([&src_elem, &sink_elem]).unwrap();
+    src_elem.link(&sink_elem).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    std::thread::sleep(Duration::from_millis(200));
+
+    // The oversized sample is dropped, the following small one goes through
+    session.put(&key_expr, vec![0u8; 4096]).wait().unwrap();
+    session.put(&key_expr, vec![1u8; 16]).wait().unwrap();
+
+    let sample = appsink
+        .try_pull_sample(gst::ClockTime::from_seconds(5))
+        .expect("Small sample not received");
+    let buffer = sample.buffer().unwrap();
+    assert_eq!(buffer.size(), 16);
+    assert_eq!(src.errors(), 1);
+    assert_eq!(src.messages_received(), 1);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_zenohsrc_buffer_pool_max_below_size() {
+    init();
+
+    let src = gstzenoh::ZenohSrc::builder("test/error/buffer_pool")
+        .buffer_pool_size(4)
+        .buffer_pool_max(2)
+        .build();
+    assert_eq!(src.buffer_pool_size(), 4);
+    assert_eq!(src.buffer_pool_max(), 2);
+
+    // The element starts during READY→PAUSED
+    src.set_state(gst::State::Ready).unwrap();
+    assert!(
+        src.set_state(gst::State::Paused).is_err(),
+        "Start should fail when buffer-pool-max is below buffer-pool-size"
+    );
+    src.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_zenohdemux_drops_oversized_samples() {
+    use std::time::{Duration, Instant};
+    use zenoh::Wait;
+
+    init();
+
+    let prefix = format!("test/error/max_buffer_size/demux/{}", std::process::id());
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/*", prefix))
+        .max_buffer_size(1024)
+        .build();
+    assert_eq!(demux.max_buffer_size(), 1024);
+
+    let pipeline = gst::Pipeline::builder().build();
+    pipeline.add(&demux).unwrap();
+    demux.connect_pad_added(|_, pad| {
+        // Keep pushes from failing with NOT_LINKED
+        let _ = pad.add_probe(gst::PadProbeType::BUFFER, |_, _| gst::PadProbeReturn::Drop);
+    });
+    pipeline.set_state(gst::State::Playing).unwrap();
+    std::thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    session
+        .put(format!("{}/big", prefix), vec![0u8; 4096])
+        .wait()
+        .unwrap();
+    session
+        .put(format!("{}/small", prefix), vec![1u8; 16])
+        .wait()
+        .unwrap();
+
+    let start = Instant::now();
+    while demux.messages_received() < 1 && start.elapsed() < Duration::from_secs(5) {
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    // No pad is created for the key that only carried an oversized sample
+    assert_eq!(demux.messages_received(), 1);
+    assert_eq!(demux.pads_created(), 1);
+    assert_eq!(demux.errors(), 1);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+/// Metadata attachment whose caps entry can't be parsed
+const MALFORMED_CAPS_ATTACHMENT: &str = "gst.version=1.0\ngst.caps=not_a_valid_caps!!!";
+
+#[test]
+#[serial]
+fn test_zenohsrc_delivers_payload_with_malformed_caps() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/error/malformed_caps/src/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let pipeline = gst::Pipeline::builder().build();
+    let src = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(session.clone())
+        .build();
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+    let src_elem: gst::Element = src.clone().upcast();
+    let sink_elem: gst::Element = appsink.clone().upcast();
+    pipeline.add_many([&src_elem, &sink_elem]).unwrap();
+    src_elem.link(&sink_elem).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    std::thread::sleep(Duration::from_millis(200));
+
+    session
+        .put(&key_expr, vec![7u8; 16])
+        .attachment(MALFORMED_CAPS_ATTACHMENT)
+        .wait()
+        .unwrap();
+
+    let sample = appsink
+        .try_pull_sample(gst::ClockTime::from_seconds(5))
+        .expect("Payload with malformed caps not delivered");
+    let buffer = sample.buffer().unwrap();
+    assert_eq!(buffer.map_readable().unwrap().as_slice(), &[7u8; 16]);
+    assert_eq!(src.metadata_errors(), 1);
+    assert_eq!(src.errors(), 0);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_zenohdemux_delivers_payload_with_malformed_caps() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::{Duration, Instant};
+    use zenoh::Wait;
+
+    init();
+
+    let prefix = format!("test/error/malformed_caps/demux/{}", std::process::id());
+    let demux = gstzenoh::ZenohDemux::builder(&format!("{}/*", prefix)).build();
+
+    let pipeline = gst::Pipeline::builder().build();
+    pipeline.add(&demux).unwrap();
+    let pushed = Arc::new(AtomicU64::new(0));
+    let pushed_clone = pushed.clone();
+    demux.connect_pad_added(move |_, pad| {
+        let pushed = pushed_clone.clone();
+        let _ = pad.add_probe(gst::PadProbeType::BUFFER, move |_, _| {
+            pushed.fetch_add(1, Ordering::SeqCst);
+            gst::PadProbeReturn::Drop
+        });
+    });
+    pipeline.set_state(gst::State::Playing).unwrap();
+    std::thread::sleep(Duration::from_millis(300));
+
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    session
+        .put(format!("{}/cam", prefix), vec![7u8; 16])
+        .attachment(MALFORMED_CAPS_ATTACHMENT)
+        .wait()
+        .unwrap();
+
+    let start = Instant::now();
+    while pushed.load(Ordering::SeqCst) < 1 && start.elapsed() < Duration::from_secs(5) {
+        std::thread::sleep(Duration::from_millis(20));
+    }
+
+    assert_eq!(pushed.load(Ordering::SeqCst), 1, "payload should reach the pad");
+    assert_eq!(demux.pads_created(), 1);
+    assert_eq!(demux.metadata_errors(), 1);
+    assert_eq!(demux.errors(), 0);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
@@ -68,7 +68,11 @@ fn test_pts_preservation() {
         .session(zenoh_session.clone())
         .receive_timeout_ms(50)
         .apply_buffer_meta(true)
+        .timestamp_mode(gstzenoh::TimestampMode::None)
         .build();
+    // Live, but without do-timestamp making up a DTS for the sender's PTS
+    assert!(zenohsrc.is_live());
+    assert!(!zenohsrc.property::<bool>("do-timestamp"));
 
     let fakesink = gst::ElementFactory::make("fakesink")
         .property("sync", false)
@@ -157,6 +161,7 @@ fn test_pts_preservation() {
         Some(expected_pts),
         meta.pts
     );
+    assert_eq!(meta.dts, None, "DTS made up for a buffer sent without one");
 }
 
 /// Test that DTS (Decode Timestamp) is preserved through transmission.
@@ -183,6 +188,7 @@ fn test_dts_preservation() {
         .session(zenoh_session.clone())
         .receive_timeout_ms(50)
         .apply_buffer_meta(true)
+        .timestamp_mode(gstzenoh::TimestampMode::None)
         .build();
 
     let fakesink = gst::ElementFactory::make("fakesink")
@@ -297,6 +303,7 @@ fn test_duration_preservation() {
         .session(zenoh_session.clone())
         .receive_timeout_ms(50)
         .apply_buffer_meta(true)
+        .timestamp_mode(gstzenoh::TimestampMode::None)
         .build();
 
     let fakesink = gst::ElementFactory::make("fakesink")
@@ -410,6 +417,7 @@ fn test_full_metadata_preservation() {
         .session(zenoh_session.clone())
         .receive_timeout_ms(50)
         .apply_buffer_meta(true)
+        .timestamp_mode(gstzenoh::TimestampMode::None)
         .build();
 
     let fakesink = gst::ElementFactory::make("fakesink")
@@ -653,6 +661,7 @@ fn test_sequential_timestamps() {
         .session(zenoh_session.clone())
         .receive_timeout_ms(50)
         .apply_buffer_meta(true)
+        .timestamp_mode(gstzenoh::TimestampMode::None)
         .build();
 
     let fakesink = gst::ElementFactory::make("fakesink")
@@ -748,3 +757,798 @@ fn test_sequential_timestamps() {
         );
     }
 }
+
+/// Test that a subscriber joining a running stream gets the caps from the sink's caps
+/// queryable, without waiting for caps metadata.
+#[test]
+#[serial]
+fn test_late_subscriber_queries_caps() {
+    init();
+
+    let key_expr = unique_key_expr("late-caps");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let stream_caps = gst::Caps::builder("video/x-raw")
+        .field("format", "RGB")
+        .field("width", 4i32)
+        .field("height", 4i32)
+        .field("framerate", gst::Fraction::new(30, 1))
+        .build();
+
+    // Sender with caps sent only with the first buffer
+    let send_pipeline = gst::Pipeline::new();
+
+    let appsrc = gst_app::AppSrc::builder()
+        .caps(&stream_caps)
+        .format(gst::Format::Time)
+        .build();
+
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .send_caps(true)
+        .caps_interval(0)
+        .build();
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    appsrc.push_buffer(gst::Buffer::with_size(48).unwrap()).unwrap();
+    thread::sleep(Duration::from_millis(300));
+
+    // Receiver joins after the caps were transmitted
+    let recv_pipeline = gst::Pipeline::new();
+
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .build();
+
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    let received_caps: Arc<Mutex<Option<gst::Caps>>> = Arc::new(Mutex::new(None));
+    let received_clone = received_caps.clone();
+    let srcpad = zenohsrc.static_pad("src").unwrap();
+    srcpad.add_probe(gst::PadProbeType::BUFFER, move |pad, _| {
+        *received_clone.lock().unwrap() = pad.current_caps();
+        gst::PadProbeReturn::Remove
+    });
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    let stop_clone = stop_flag.clone();
+    let appsrc_sender = appsrc.clone();
+    let sender_thread = thread::spawn(move || {
+        while !stop_clone.load(Ordering::SeqCst) {
+            if appsrc_sender.push_buffer(gst::Buffer::with_size(48).unwrap()).is_err() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    });
+
+    let start = Instant::now();
+    let timeout = Duration::from_secs(5);
+    while received_caps.lock().unwrap().is_none() && start.elapsed() < timeout {
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    stop_flag.store(true, Ordering::SeqCst);
+    sender_thread.join().expect("Sender thread panicked");
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let caps = received_caps.lock().unwrap().clone();
+    assert_eq!(caps, Some(stream_caps), "Late subscriber did not get the stream caps");
+}
+
+/// Test that a resolution switch mid-stream reaches zenohsrc as a caps event
+/// ahead of the first buffer with the new caps, and as a `caps-changed` signal.
+#[test]
+#[serial]
+fn test_caps_change_mid_stream() {
+    init();
+
+    let key_expr = unique_key_expr("caps-change");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let video_caps = |size: i32| {
+        gst::Caps::builder("video/x-raw")
+            .field("format", "RGB")
+            .field("width", size)
+            .field("height", size)
+            .field("framerate", gst::Fraction::new(30, 1))
+            .build()
+    };
+    let (small_caps, large_caps) = (video_caps(4), video_caps(8));
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    let signalled: Arc<Mutex<Vec<gst::Caps>>> = Arc::new(Mutex::new(Vec::new()));
+    let signalled_clone = signalled.clone();
+    zenohsrc.connect_caps_changed(move |_, caps| {
+        signalled_clone.lock().unwrap().push(caps.clone());
+    });
+
+    // Records the size of each buffer with the caps it went out with
+    let received: Arc<Mutex<Vec<(usize, Option<gst::Caps>)>>> = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    let srcpad = zenohsrc.static_pad("src").unwrap();
+    srcpad.add_probe(gst::PadProbeType::BUFFER, move |pad, probe_info| {
+        if let Some(gst::PadProbeData::Buffer(ref buffer)) = probe_info.data {
+            received_clone
+                .lock()
+                .unwrap()
+                .push((buffer.size(), pad.current_caps()));
+        }
+        gst::PadProbeReturn::Ok
+    });
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .caps(&small_caps)
+        .format(gst::Format::Time)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .send_caps(true)
+        .caps_interval(0)
+        .build();
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    // 4x4 RGB frames (48 bytes) until switched, then 8x8 ones (192 bytes)
+    let switch = Arc::new(AtomicBool::new(false));
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    let (switch_clone, stop_clone) = (switch.clone(), stop_flag.clone());
+    let appsrc_sender = appsrc.clone();
+    let sender_caps = large_caps.clone();
+    let sender_thread = thread::spawn(move || {
+        let mut size = 48;
+        while !stop_clone.load(Ordering::SeqCst) {
+            if size == 48 && switch_clone.load(Ordering::SeqCst) {
+                appsrc_sender.set_caps(Some(&sender_caps));
+                size = 192;
+            }
+            if appsrc_sender.push_buffer(gst::Buffer::with_size(size).unwrap()).is_err() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(30));
+        }
+    });
+
+    let wait_for = |size: usize| {
+        let start = Instant::now();
+        while !received.lock().unwrap().iter().any(|(s, _)| *s == size)
+            && start.elapsed() < Duration::from_secs(5)
+        {
+            thread::sleep(Duration::from_millis(20));
+        }
+    };
+    wait_for(48);
+    switch.store(true, Ordering::SeqCst);
+    wait_for(192);
+
+    stop_flag.store(true, Ordering::SeqCst);
+    sender_thread.join().expect("Sender thread panicked");
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let received = received.lock().unwrap();
+    assert!(received.iter().any(|(size, _)| *size == 192), "No buffer after the switch");
+    for (size, caps) in received.iter() {
+        let expected = if *size == 48 { &small_caps } else { &large_caps };
+        assert_eq!(caps.as_ref(), Some(expected), "{} byte buffer with wrong caps", size);
+    }
+    assert_eq!(*signalled.lock().unwrap(), vec![small_caps, large_caps]);
+}
+
+/// Test that the segment rate and base time of the sender reach the receiving
+/// pipeline, as needed when replaying a recorded stream.
+#[test]
+#[serial]
+fn test_segment_rate_preservation() {
+    init();
+
+    let key_expr = unique_key_expr("segment");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .receive_timeout_ms(50)
+        .apply_buffer_meta(true)
+        .timestamp_mode(gstzenoh::TimestampMode::None)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &fakesink]).unwrap();
+    src_elem.link(&fakesink).unwrap();
+
+    // Capture the first segment with a non-default rate
+    let received_segment: Arc<Mutex<Option<gst::FormattedSegment<gst::ClockTime>>>> =
+        Arc::new(Mutex::new(None));
+    let received_clone = received_segment.clone();
+    let srcpad = zenohsrc.static_pad("src").unwrap();
+    srcpad.add_probe(gst::PadProbeType::EVENT_DOWNSTREAM, move |_, probe_info| {
+        if let Some(gst::PadProbeData::Event(ref event)) = probe_info.data
+            && let gst::EventView::Segment(segment) = event.view()
+            && let Ok(segment) = segment.segment().clone().downcast::<gst::ClockTime>()
+            && segment.rate() != 1.0
+        {
+            *received_clone.lock().unwrap() = Some(segment);
+            return gst::PadProbeReturn::Remove;
+        }
+        gst::PadProbeReturn::Ok
+    });
+
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    // The sender plays at twice the speed, with 5s of running time already elapsed
+    let appsrc = gst::ElementFactory::make("appsrc")
+        .property("format", gst::Format::Time)
+        .property("handle-segment-change", true)
+        .build()
+        .unwrap()
+        .downcast::<gst_app::AppSrc>()
+        .unwrap();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .send_buffer_meta(true)
+        .build();
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let mut segment = gst::FormattedSegment::<gst::ClockTime>::new();
+    segment.set_rate(2.0);
+    segment.set_base(gst::ClockTime::from_seconds(5));
+
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    let stop_clone = stop_flag.clone();
+    let appsrc_sender = appsrc.clone();
+    let sender_thread = thread::spawn(move || {
+        while !stop_clone.load(Ordering::SeqCst) {
+            // Untimestamped buffers, so the sink doesn't wait for their running time
+            let sample = gst::Sample::builder()
+                .buffer(&gst::Buffer::with_size(32).unwrap())
+                .segment(&segment)
+                .build();
+            if appsrc_sender.push_sample(&sample).is_err() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+    });
+
+    let start = Instant::now();
+    let timeout = Duration::from_secs(5);
+    while received_segment.lock().unwrap().is_none() && start.elapsed() < timeout {
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    stop_flag.store(true, Ordering::SeqCst);
+    sender_thread.join().expect("Sender thread panicked");
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let segment = received_segment
+        .lock()
+        .unwrap()
+        .clone()
+        .expect("No segment with the sender's rate received");
+    assert_eq!(segment.rate(), 2.0);
+    assert_eq!(segment.base(), Some(gst::ClockTime::from_seconds(5)));
+}
+
+/// zenohsink's user-metadata reaches zenohsrc as a ZenohUserMeta, even with
+/// caps and buffer metadata turned off.
+#[test]
+#[serial]
+fn test_user_metadata_roundtrip() {
+    init();
+
+    let key_expr = unique_key_expr("user_metadata");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build();
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+    let src_elem: gst::Element = zenohsrc.upcast();
+    let appsink_elem: gst::Element = appsink.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &appsink_elem]).unwrap();
+    src_elem.link(&appsink_elem).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let fields = gst::Structure::builder("user")
+        .field("producer", "camera-07")
+        .field("site", 3i32)
+        .build();
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .send_caps(false)
+        .send_buffer_meta(false)
+        .user_metadata(fields.clone())
+        .build();
+    assert_eq!(zenohsink.user_metadata(), Some(fields));
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![0u8; 32]))
+        .expect("Failed to push buffer");
+
+    let sample = appsink.try_pull_sample(gst::ClockTime::from_seconds(5));
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let sample = sample.expect("Buffer not received");
+    let buffer = sample.buffer().unwrap();
+    assert_eq!(
+        gstzenoh::metadata::ZenohUserMeta::user_metadata(buffer, "producer").as_deref(),
+        Some("camera-07")
+    );
+    assert_eq!(
+        gstzenoh::metadata::ZenohUserMeta::user_metadata(buffer, "site").as_deref(),
+        Some("3")
+    );
+}
+
+/// Test that GAP buffers, and gap events turned into GAP buffers by
+/// zenohsink, reach the receiver with the flag and timing intact
+#[test]
+#[serial]
+fn test_gap_roundtrip() {
+    init();
+
+    let key_expr = unique_key_expr("gap");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build();
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+    let src_elem: gst::Element = zenohsrc.upcast();
+    let appsink_elem: gst::Element = appsink.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &appsink_elem]).unwrap();
+    src_elem.link(&appsink_elem).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Time)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .send_caps(false)
+        .build();
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let frame = gst::ClockTime::from_mseconds(20);
+    let mut gap_buffer = gst::Buffer::new();
+    {
+        let gap_buffer = gap_buffer.get_mut().unwrap();
+        gap_buffer.set_pts(gst::ClockTime::ZERO);
+        gap_buffer.set_duration(frame);
+        gap_buffer.set_flags(gst::BufferFlags::GAP | gst::BufferFlags::RESYNC);
+    }
+    appsrc.push_buffer(gap_buffer).expect("Failed to push buffer");
+    // Queued by appsrc and sent after the buffer
+    assert!(appsrc.send_event(gst::event::Gap::builder(frame).duration(frame).build()));
+
+    let first = appsink.try_pull_sample(gst::ClockTime::from_seconds(5));
+    let second = appsink.try_pull_sample(gst::ClockTime::from_seconds(5));
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let first = first.expect("GAP buffer not received");
+    let buffer = first.buffer().unwrap();
+    assert_eq!(buffer.size(), 0);
+    assert!(buffer.flags().contains(gst::BufferFlags::GAP));
+    assert!(buffer.flags().contains(gst::BufferFlags::RESYNC));
+    assert_eq!(buffer.pts(), Some(gst::ClockTime::ZERO));
+    assert_eq!(buffer.duration(), Some(frame));
+
+    let second = second.expect("Gap event not received as a GAP buffer");
+    let buffer = second.buffer().unwrap();
+    assert_eq!(buffer.size(), 0);
+    assert!(buffer.flags().contains(gst::BufferFlags::GAP));
+    assert_eq!(buffer.pts(), Some(frame));
+    assert_eq!(buffer.duration(), Some(frame));
+}
+
+/// Test that tags from upstream reach the receiver as a tag event, without
+/// the tags that aren't transmitted
+#[test]
+#[serial]
+fn test_tags_roundtrip() {
+    init();
+
+    let key_expr = unique_key_expr("tags");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build();
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+    let src_elem: gst::Element = zenohsrc.upcast();
+    let appsink_elem: gst::Element = appsink.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &appsink_elem]).unwrap();
+    src_elem.link(&appsink_elem).unwrap();
+
+    let received_tags: Arc<Mutex<Vec<gst::TagList>>> = Arc::new(Mutex::new(Vec::new()));
+    let received_tags_clone = received_tags.clone();
+    let appsink_pad = appsink_elem.static_pad("sink").unwrap();
+    appsink_pad.add_probe(gst::PadProbeType::EVENT_DOWNSTREAM, move |_, probe_info| {
+        if let Some(gst::PadProbeData::Event(ref event)) = probe_info.data
+            && let gst::EventView::Tag(tag) = event.view()
+        {
+            received_tags_clone.lock().unwrap().push(tag.tag_owned());
+        }
+        gst::PadProbeReturn::Ok
+    });
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .send_caps(false)
+        .build();
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let mut tags = gst::TagList::new();
+    {
+        let tags = tags.get_mut().unwrap();
+        tags.add::<gst::tags::LanguageCode>(&"de", gst::TagMergeMode::Append);
+        tags.add::<gst::tags::Title>(&"Director's commentary", gst::TagMergeMode::Append);
+        tags.add::<gst::tags::AudioCodec>(&"Opus", gst::TagMergeMode::Append);
+        tags.add::<gst::tags::TrackNumber>(&2, gst::TagMergeMode::Append);
+    }
+    // Queued by appsrc and sent ahead of the next buffer
+    assert!(appsrc.send_event(gst::event::Tag::new(tags)));
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![0u8; 32]))
+        .expect("Failed to push buffer");
+
+    let sample = appsink.try_pull_sample(gst::ClockTime::from_seconds(5));
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert!(sample.is_some(), "Buffer not received");
+    let received_tags = received_tags.lock().unwrap();
+    let tags = received_tags.last().expect("No tag event received");
+    assert_eq!(tags.get::<gst::tags::LanguageCode>().unwrap().get(), "de");
+    assert_eq!(tags.get::<gst::tags::Title>().unwrap().get(), "Director's commentary");
+    assert_eq!(tags.get::<gst::tags::AudioCodec>().unwrap().get(), "Opus");
+    assert!(tags.get::<gst::tags::TrackNumber>().is_none());
+}
+
+/// Collects the stream ids and group ids of the stream-start events reaching `pad`
+fn capture_stream_starts(pad: &gst::Pad) -> Arc<Mutex<Vec<(String, Option<gst::GroupId>)>>> {
+    let stream_starts = Arc::new(Mutex::new(Vec::new()));
+    let stream_starts_clone = stream_starts.clone();
+    pad.add_probe(gst::PadProbeType::EVENT_DOWNSTREAM, move |_, probe_info| {
+        if let Some(gst::PadProbeData::Event(ref event)) = probe_info.data
+            && let gst::EventView::StreamStart(stream_start) = event.view()
+        {
+            stream_starts_clone
+                .lock()
+                .unwrap()
+                .push((stream_start.stream_id().to_string(), stream_start.group_id()));
+        }
+        gst::PadProbeReturn::Ok
+    });
+    stream_starts
+}
+
+/// Test that the receiver starts its stream with the stream id of the sender
+#[test]
+#[serial]
+fn test_stream_identity_roundtrip() {
+    init();
+
+    let key_expr = unique_key_expr("stream_identity");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build();
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+    let src_elem: gst::Element = zenohsrc.upcast();
+    let appsink_elem: gst::Element = appsink.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &appsink_elem]).unwrap();
+    src_elem.link(&appsink_elem).unwrap();
+    let received = capture_stream_starts(&appsink_elem.static_pad("sink").unwrap());
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build();
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    let sent = capture_stream_starts(&sink_elem.static_pad("sink").unwrap());
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![0u8; 32]))
+        .expect("Failed to push buffer");
+    let sample = appsink.try_pull_sample(gst::ClockTime::from_seconds(5));
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    assert!(sample.is_some(), "Buffer not received");
+    let (sent_id, sent_group) = sent.lock().unwrap()[0].clone();
+    let received = received.lock().unwrap();
+    // zenohsrc starts with its own stream-start, replaced by the sender's
+    // before the first buffer
+    let (received_id, received_group) = received.last().expect("No stream-start received");
+    assert_eq!(*received_id, sent_id);
+    // The group id is a local one, standing for the sender's
+    assert_eq!(received_group.is_some(), sent_group.is_some());
+}
+
+/// Test that with `caps-channel=sidecar-key` the data samples carry no
+/// attachment, while zenohsrc still gets the caps from the sidecar key
+#[test]
+#[serial]
+fn test_caps_sidecar_key() {
+    init();
+
+    let key_expr = unique_key_expr("caps_sidecar");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    // A plain Zenoh subscriber of the data key, as a non-GStreamer consumer
+    let attachments: Arc<Mutex<Vec<bool>>> = Arc::new(Mutex::new(Vec::new()));
+    let attachments_clone = attachments.clone();
+    let _subscriber = zenoh_session
+        .declare_subscriber(key_expr.as_str())
+        .callback(move |sample| {
+            attachments_clone
+                .lock()
+                .unwrap()
+                .push(sample.attachment().is_some());
+        })
+        .wait()
+        .expect("Failed to declare subscriber");
+
+    let recv_pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .caps_channel(gstzenoh::CapsChannel::SidecarKey)
+        .build();
+    assert_eq!(zenohsrc.caps_channel(), gstzenoh::CapsChannel::SidecarKey);
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+    let src_elem: gst::Element = zenohsrc.upcast();
+    let appsink_elem: gst::Element = appsink.clone().upcast();
+    recv_pipeline.add_many([&src_elem, &appsink_elem]).unwrap();
+    src_elem.link(&appsink_elem).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .caps(&gst::Caps::builder("application/x-sidecar").build())
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .send_buffer_meta(false)
+        .caps_channel(gstzenoh::CapsChannel::SidecarKey)
+        .build();
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![0u8; 32]))
+        .expect("Failed to push buffer");
+
+    let sample = appsink.try_pull_sample(gst::ClockTime::from_seconds(5));
+
+    let _ = send_pipeline.set_state(gst::State::Null);
+    stop_pipeline_with_timeout(&recv_pipeline, Duration::from_secs(1));
+
+    let sample = sample.expect("Buffer not received");
+    let caps = sample.caps().expect("Caps should come from the sidecar key");
+    assert_eq!(caps.structure(0).unwrap().name(), "application/x-sidecar");
+
+    let attachments = attachments.lock().unwrap();
+    assert_eq!(attachments.len(), 1, "Only the data sample is on the data key");
+    assert!(!attachments[0], "Data samples should carry no attachment");
+}
+
+/// Test that with `payload-only` a plain Zenoh subscriber receives exactly
+/// the buffer bytes, without attachment, and that features needing an
+/// attachment fail the start
+#[test]
+#[serial]
+fn test_payload_only() {
+    init();
+
+    let key_expr = unique_key_expr("payload_only");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    // Payloads and whether they had an attachment, as a non-GStreamer consumer sees them
+    let received: Arc<Mutex<Vec<(Vec<u8>, bool)>>> = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    let _subscriber = zenoh_session
+        .declare_subscriber(key_expr.as_str())
+        .callback(move |sample| {
+            received_clone
+                .lock()
+                .unwrap()
+                .push((sample.payload().to_bytes().into_owned(), sample.attachment().is_some()));
+        })
+        .wait()
+        .expect("Failed to declare subscriber");
+
+    let send_pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .caps(&gst::Caps::builder("application/x-payload-only").build())
+        .format(gst::Format::Time)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .payload_only(true)
+        .build();
+    assert!(zenohsink.payload_only());
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.upcast();
+    send_pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    // Tags would otherwise be attached to the next sample
+    let mut tags = gst::TagList::new();
+    tags.get_mut()
+        .unwrap()
+        .add::<gst::tags::Title>(&"not on the wire", gst::TagMergeMode::Replace);
+    assert!(appsrc.send_event(gst::event::Tag::new(tags)));
+
+    let payloads: Vec<Vec<u8>> = (0..3u8).map(|i| vec![i; 8 + i as usize]).collect();
+    for (i, payload) in payloads.iter().enumerate() {
+        let mut buffer = gst::Buffer::from_slice(payload.clone());
+        let buffer_mut = buffer.get_mut().unwrap();
+        buffer_mut.set_pts(gst::ClockTime::from_mseconds(40 * i as u64));
+        buffer_mut.set_duration(gst::ClockTime::from_mseconds(40));
+        appsrc.push_buffer(buffer).expect("Failed to push buffer");
+    }
+
+    let deadline = Instant::now() + Duration::from_secs(3);
+    while received.lock().unwrap().len() < payloads.len() && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(20));
+    }
+    let _ = send_pipeline.set_state(gst::State::Null);
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), payloads.len());
+    for ((payload, has_attachment), sent) in received.iter().zip(&payloads) {
+        assert_eq!(payload, sent, "Payloads should be byte-exact");
+        assert!(!has_attachment, "Samples should carry no attachment");
+    }
+
+    // Sequence numbers need an attachment
+    let conflicting = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .payload_only(true)
+        .sequence_numbers(true)
+        .build();
+    assert!(conflicting.set_state(gst::State::Paused).is_err());
+    let _ = conflicting.set_state(gst::State::Null);
+}
@@ -0,0 +1,120 @@
+//! Prometheus endpoint tests for gst-plugin-zenoh.
+//!
+//! These tests verify that elements started with `metrics-port` serve their
+//! statistics over HTTP and release the port when they stop.
+
+#![cfg(feature = "metrics-http")]
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use gst::prelude::*;
+use serial_test::serial;
+use zenoh::Wait;
+
+mod common;
+#[path = "common/key_expr.rs"]
+mod key_expr;
+use common::init;
+use key_expr::unique_key_expr;
+
+/// Returns a port that is free at the time of the call.
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+/// Sends `GET <path>` and returns the whole response.
+fn http_get(port: u16, path: &str) -> std::io::Result<String> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port))?;
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path)?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    Ok(response)
+}
+
+#[test]
+#[serial]
+fn test_zenohsink_metrics_endpoint() {
+    init();
+
+    let key_expr = unique_key_expr("metrics");
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let port = free_port();
+
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .metrics_port(port)
+        .build();
+    sink.set_property("name", "metrics-sink");
+    assert_eq!(sink.metrics_port(), port);
+    assert_eq!(sink.metrics_address(), "127.0.0.1");
+
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    pipeline
+        .add_many([
+            appsrc.upcast_ref::<gst::Element>(),
+            sink.upcast_ref::<gst::Element>(),
+        ])
+        .unwrap();
+    appsrc.link(&sink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    for i in 0..3u8 {
+        appsrc.push_buffer(gst::Buffer::from_slice(vec![i; 16])).unwrap();
+    }
+    let start = Instant::now();
+    while sink.messages_sent() < 3 && start.elapsed() < Duration::from_secs(5) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(sink.messages_sent(), 3);
+
+    let response = http_get(port, "/metrics").expect("Metrics endpoint not reachable");
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    assert!(response.contains("# TYPE gstzenoh_messages_sent counter"), "{}", response);
+    assert!(response.contains("# TYPE gstzenoh_bitrate gauge"), "{}", response);
+    assert!(
+        response.contains(
+            "gstzenoh_messages_sent{element=\"metrics-sink\",factory=\"zenohsink\"} 3"
+        ),
+        "{}",
+        response
+    );
+    assert!(
+        response.contains(
+            "gstzenoh_bytes_sent{element=\"metrics-sink\",factory=\"zenohsink\"} 48"
+        ),
+        "{}",
+        response
+    );
+
+    let not_found = http_get(port, "/").unwrap();
+    assert!(not_found.starts_with("HTTP/1.1 404"), "{}", not_found);
+
+    // The endpoint goes away with the element's statistics
+    pipeline.set_state(gst::State::Null).unwrap();
+    assert!(http_get(port, "/metrics").is_err());
+}
+
+#[test]
+#[serial]
+fn test_metrics_port_disabled_by_default() {
+    init();
+
+    for factory in ["zenohsink", "zenohsrc", "zenohdemux"] {
+        let element = gst::ElementFactory::make(factory).build().unwrap();
+        assert_eq!(element.property::<u32>("metrics-port"), 0, "{}", factory);
+        // Only reachable from the local host unless widened
+        assert_eq!(element.property::<String>("metrics-address"), "127.0.0.1", "{}", factory);
+    }
+}
@@ -255,3 +255,66 @@ fn test_matching_detection_in_ready_only() {
 
     let _ = pipeline.set_state(gst::State::Null);
 }
+
+/// With lazy-start, no publisher is declared until the first buffer reaches the
+/// sink, and stopping a sink that never received one is a no-op.
+#[test]
+#[serial]
+fn test_lazy_start_declares_publisher_on_first_buffer() {
+    init();
+
+    let key_expr = unique_key_expr("on_demand/lazy");
+
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let received = Arc::new(AtomicU32::new(0));
+    let received_clone = received.clone();
+    let _subscriber = zenoh_session
+        .declare_subscriber(&key_expr)
+        .callback(move |_| {
+            received_clone.fetch_add(1, Ordering::SeqCst);
+        })
+        .wait()
+        .expect("Failed to create subscriber");
+
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder().format(gst::Format::Time).build();
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .lazy_start(true)
+        .build();
+    assert!(sink.lazy_start());
+
+    let src_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = sink.clone().upcast();
+    pipeline.add_many([&src_elem, &sink_elem]).unwrap();
+    src_elem.link(&sink_elem).unwrap();
+
+    pipeline.set_state(gst::State::Playing).unwrap();
+    thread::sleep(Duration::from_millis(500));
+
+    // The subscriber exists, but there is no publisher to match it yet
+    assert!(!sink.has_subscribers(), "no publisher should be declared before the first buffer");
+    assert!(sink.session_zid().is_empty());
+
+    appsrc.push_buffer(gst::Buffer::with_size(64).unwrap()).unwrap();
+
+    assert!(
+        wait_for(Duration::from_secs(5), || received.load(Ordering::SeqCst) > 0),
+        "first buffer should be published"
+    );
+    assert!(wait_for(Duration::from_secs(5), || sink.has_subscribers()));
+    assert!(!sink.session_zid().is_empty());
+    assert_eq!(sink.messages_sent(), 1);
+
+    let _ = pipeline.set_state(gst::State::Null);
+
+    // A lazy sink that never received a buffer goes back to NULL cleanly
+    let (idle_pipeline, idle_sink) = build_pipeline(&key_expr, zenoh_session.clone());
+    idle_sink.set_lazy_start(true);
+    idle_pipeline.set_state(gst::State::Paused).unwrap();
+    assert!(!idle_sink.has_subscribers());
+    idle_pipeline.set_state(gst::State::Null).unwrap();
+}
@@ -0,0 +1,203 @@
+//! Tests for the query-based elements (zenohqueryablesrc, zenohget).
+//!
+//! These tests verify that zenohqueryablesrc answers Zenoh queries with the
+//! latest buffer it received, and that zenohget turns replies into buffers.
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use gst::prelude::*;
+use gstzenoh::ZenohQueryableSrc;
+use gstzenoh::metadata::MetadataParser;
+use serial_test::serial;
+use zenoh::Wait;
+
+mod common;
+#[path = "common/key_expr.rs"]
+mod key_expr;
+use common::init;
+use key_expr::unique_key_expr;
+
+#[test]
+#[serial]
+fn test_queryable_src_properties() {
+    init();
+
+    let queryable = ZenohQueryableSrc::new("test/queryable");
+    assert_eq!(queryable.key_expr(), "test/queryable");
+    assert!(queryable.send_caps());
+    assert!(queryable.send_buffer_meta());
+    assert_eq!(queryable.queries_received(), 0);
+    assert_eq!(queryable.replies_sent(), 0);
+
+    let queryable = ZenohQueryableSrc::builder("test/queryable")
+        .send_caps(false)
+        .send_buffer_meta(false)
+        .session_group("queryable-group")
+        .build();
+    assert!(!queryable.send_caps());
+    assert!(!queryable.send_buffer_meta());
+    assert_eq!(queryable.session_group(), Some("queryable-group".to_string()));
+}
+
+#[test]
+#[serial]
+fn test_queryable_src_replies_with_latest_buffer() {
+    init();
+
+    let key_expr = unique_key_expr("queryable");
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let pipeline = gst::Pipeline::new();
+    let caps = gst::Caps::builder("application/x-test").build();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .caps(&caps)
+        .build();
+    let queryable = ZenohQueryableSrc::builder(&key_expr)
+        .session(session.clone())
+        .build();
+
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let queryable_elem: gst::Element = queryable.clone().upcast();
+    pipeline.add_many([&appsrc_elem, &queryable_elem]).unwrap();
+    appsrc_elem.link(&queryable_elem).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    // Query before any buffer: error reply
+    let replies = session
+        .get(&key_expr)
+        .timeout(Duration::from_secs(2))
+        .wait()
+        .expect("Failed to send query");
+    let reply = replies.recv().expect("Expected a reply");
+    assert!(reply.result().is_err(), "Expected error reply without data");
+
+    for payload in [b"first".as_slice(), b"latest".as_slice()] {
+        appsrc.push_buffer(gst::Buffer::from_slice(payload)).unwrap();
+    }
+    thread::sleep(Duration::from_millis(200));
+
+    let replies = session
+        .get(&key_expr)
+        .timeout(Duration::from_secs(2))
+        .wait()
+        .expect("Failed to send query");
+    let reply = replies.recv().expect("Expected a reply");
+    let sample = reply.result().expect("Expected successful reply");
+    assert_eq!(sample.payload().to_bytes().as_ref(), b"latest");
+
+    let attachment = sample.attachment().expect("Expected caps attachment");
+    let metadata = MetadataParser::parse(attachment).expect("Failed to parse metadata");
+    assert_eq!(metadata.caps(), Some(&caps));
+
+    assert_eq!(queryable.queries_received(), 2);
+    assert_eq!(queryable.replies_sent(), 1);
+    assert_eq!(queryable.bytes_sent(), b"latest".len() as u64);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_zenohget_pushes_replies_then_eos() {
+    init();
+
+    let key_expr = unique_key_expr("get");
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let reply_key = key_expr.clone();
+    let _queryable = session
+        .declare_queryable(&key_expr)
+        .callback(move |query| {
+            query.reply(&reply_key, b"snapshot".as_slice()).wait().unwrap();
+        })
+        .wait()
+        .expect("Failed to declare queryable");
+
+    let pipeline = gst::Pipeline::new();
+    let get = gstzenoh::ZenohGet::builder(&key_expr)
+        .session(session.clone())
+        .timeout_ms(2000)
+        .build();
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+
+    let get_elem: gst::Element = get.clone().upcast();
+    let appsink_elem: gst::Element = appsink.clone().upcast();
+    pipeline.add_many([&get_elem, &appsink_elem]).unwrap();
+    get_elem.link(&appsink_elem).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    let sample = appsink
+        .try_pull_sample(gst::ClockTime::from_seconds(5))
+        .expect("Expected a reply buffer");
+    let buffer = sample.buffer().unwrap();
+    assert_eq!(buffer.map_readable().unwrap().as_slice(), b"snapshot");
+
+    // Single reply, then EOS
+    let bus = pipeline.bus().unwrap();
+    let msg = bus.timed_pop_filtered(
+        gst::ClockTime::from_seconds(5),
+        &[gst::MessageType::Eos, gst::MessageType::Error],
+    );
+    assert!(
+        matches!(msg.map(|m| m.type_()), Some(gst::MessageType::Eos)),
+        "Expected EOS after the replies"
+    );
+    assert_eq!(get.replies_received(), 1);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+/// Test that stopping zenohget doesn't wait for a query nobody answers
+#[test]
+#[serial]
+fn test_zenohget_stops_during_unanswered_query() {
+    init();
+
+    let key_expr = unique_key_expr("get_unanswered");
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    // Queries are kept, unanswered, so the query stays pending until its timeout
+    let pending = Arc::new(Mutex::new(Vec::new()));
+    let pending_clone = pending.clone();
+    let _queryable = session
+        .declare_queryable(&key_expr)
+        .callback(move |query| pending_clone.lock().unwrap().push(query))
+        .wait()
+        .expect("Failed to declare queryable");
+
+    let pipeline = gst::Pipeline::new();
+    let get = gstzenoh::ZenohGet::builder(&key_expr)
+        .session(session.clone())
+        .timeout_ms(10_000)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink").build().unwrap();
+
+    let get_elem: gst::Element = get.clone().upcast();
+    pipeline.add_many([&get_elem, &fakesink]).unwrap();
+    get_elem.link(&fakesink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    let start = Instant::now();
+    while pending.lock().unwrap().is_empty() && start.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(20));
+    }
+    assert!(!pending.lock().unwrap().is_empty(), "The query should be pending");
+
+    let start = Instant::now();
+    pipeline.set_state(gst::State::Null).unwrap();
+    assert!(
+        start.elapsed() < Duration::from_secs(2),
+        "Stopping took {:?}, it should not wait for the query timeout",
+        start.elapsed()
+    );
+    assert_eq!(get.replies_received(), 0);
+}
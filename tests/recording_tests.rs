@@ -0,0 +1,153 @@
+//! Tests for the recording elements (zenohrecord, zenohreplay).
+//!
+//! These tests record buffers from an appsrc and check that zenohreplay pushes
+//! them back with their caps and timing, rebased to start at 0.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use gst::prelude::*;
+use gstzenoh::{ZenohRecord, ZenohReplay};
+use serial_test::serial;
+
+mod common;
+use common::init;
+
+const FRAME: gst::ClockTime = gst::ClockTime::from_mseconds(40);
+
+fn recording_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("gstzenoh-{}-{}.zrec", name, std::process::id()))
+}
+
+/// Records `count` buffers of caps `application/x-test`, starting at PTS 10 s.
+fn record(path: &PathBuf, count: u64) {
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .caps(&gst::Caps::builder("application/x-test").build())
+        .format(gst::Format::Time)
+        .build();
+    let record = ZenohRecord::new(path.to_str().unwrap());
+    pipeline
+        .add_many([appsrc.upcast_ref(), record.upcast_ref::<gst::Element>()])
+        .unwrap();
+    appsrc.link(&record).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    for i in 0..count {
+        let mut buffer = gst::Buffer::from_mut_slice(vec![i as u8; 16]);
+        let buffer_mut = buffer.get_mut().unwrap();
+        buffer_mut.set_pts(gst::ClockTime::from_seconds(10) + FRAME * i);
+        buffer_mut.set_duration(FRAME);
+        appsrc.push_buffer(buffer).unwrap();
+    }
+    appsrc.end_of_stream().unwrap();
+
+    let bus = pipeline.bus().unwrap();
+    let msg = bus.timed_pop_filtered(
+        gst::ClockTime::from_seconds(5),
+        &[gst::MessageType::Eos, gst::MessageType::Error],
+    );
+    assert!(
+        matches!(msg.map(|m| m.type_()), Some(gst::MessageType::Eos)),
+        "Recording should end with EOS"
+    );
+    assert_eq!(record.records_written(), count);
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+/// Replays the recording into an appsink, returning the pipeline and the sink.
+fn replay(replay: &ZenohReplay, sync: bool) -> (gst::Pipeline, gst_app::AppSink) {
+    let pipeline = gst::Pipeline::new();
+    let appsink = gst_app::AppSink::builder().sync(sync).build();
+    pipeline
+        .add_many([replay.upcast_ref::<gst::Element>(), appsink.upcast_ref()])
+        .unwrap();
+    replay.link(&appsink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    (pipeline, appsink)
+}
+
+#[test]
+#[serial]
+fn test_record_then_replay() {
+    init();
+
+    let path = recording_path("replay");
+    record(&path, 3);
+
+    let replay_elem = ZenohReplay::new(path.to_str().unwrap());
+    let (pipeline, appsink) = replay(&replay_elem, true);
+
+    let start = Instant::now();
+    for i in 0..3u64 {
+        let sample = appsink
+            .try_pull_sample(gst::ClockTime::from_seconds(5))
+            .expect("Expected a replayed buffer");
+        let caps = sample.caps().expect("Recorded caps should be set");
+        assert_eq!(caps.structure(0).unwrap().name(), "application/x-test");
+        let buffer = sample.buffer().unwrap();
+        assert_eq!(buffer.map_readable().unwrap().as_slice(), &[i as u8; 16]);
+        assert_eq!(buffer.pts(), Some(FRAME * i), "PTS should be rebased to 0");
+        assert_eq!(buffer.duration(), Some(FRAME));
+    }
+    // The synchronizing sink honors the recorded timing
+    assert!(start.elapsed() >= Duration::from_millis(70));
+
+    assert!(appsink.try_pull_sample(gst::ClockTime::from_seconds(5)).is_none());
+    assert!(appsink.is_eos());
+    assert_eq!(replay_elem.records_read(), 3);
+    assert_eq!(replay_elem.bytes_read(), 48);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_replay_loop() {
+    init();
+
+    let path = recording_path("loop");
+    record(&path, 2);
+
+    let replay_elem = ZenohReplay::builder(path.to_str().unwrap())
+        .loop_(true)
+        .build();
+    assert!(replay_elem.is_loop());
+    let (pipeline, appsink) = replay(&replay_elem, false);
+
+    // Timestamps continue from one pass to the next
+    for i in 0..6u64 {
+        let sample = appsink
+            .try_pull_sample(gst::ClockTime::from_seconds(5))
+            .expect("Expected a replayed buffer");
+        let buffer = sample.buffer().unwrap();
+        assert_eq!(buffer.pts(), Some(FRAME * i));
+        assert_eq!(
+            buffer.flags().contains(gst::BufferFlags::DISCONT),
+            i % 2 == 0,
+            "First buffer of each pass should be DISCONT"
+        );
+    }
+    assert!(replay_elem.loops() >= 2);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_replay_invalid_recording() {
+    init();
+
+    let path = recording_path("invalid");
+    std::fs::write(&path, b"not a recording").unwrap();
+
+    let replay_elem = ZenohReplay::new(path.to_str().unwrap());
+    assert!(
+        replay_elem.set_state(gst::State::Paused).is_err(),
+        "A file without the recording magic should fail the start"
+    );
+    replay_elem.set_state(gst::State::Null).unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
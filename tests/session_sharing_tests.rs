@@ -213,3 +213,243 @@ fn test_zenohdemux_session_group() {
 
     assert_eq!(demux.session_group(), Some("demux-group".to_string()));
 }
+
+#[test]
+#[serial]
+fn test_session_zid_shared_across_elements() {
+    common::init();
+
+    use gstzenoh::zenohdemux::ZenohDemux;
+
+    let sink = ZenohSink::builder("demo/zid/video")
+        .session_group("zid-group")
+        .build();
+    let src = ZenohSrc::builder("demo/zid/data")
+        .session_group("zid-group")
+        .build();
+    let demux = ZenohDemux::builder("demo/zid/**")
+        .session_group("zid-group")
+        .build();
+
+    // Empty until the session exists
+    assert!(sink.session_zid().is_empty());
+    assert!(src.session_zid().is_empty());
+    assert!(demux.session_zid().is_empty());
+    assert!(sink.peers().is_empty());
+
+    sink.set_state(gst::State::Ready).unwrap();
+    src.set_state(gst::State::Paused).unwrap();
+    demux.set_state(gst::State::Paused).unwrap();
+
+    let zid = sink.session_zid();
+    assert!(!zid.is_empty());
+    assert_eq!(src.session_zid(), zid);
+    assert_eq!(demux.session_zid(), zid);
+
+    sink.set_state(gst::State::Null).unwrap();
+    src.set_state(gst::State::Null).unwrap();
+    demux.set_state(gst::State::Null).unwrap();
+    assert!(sink.session_zid().is_empty());
+}
+
+#[test]
+#[serial]
+fn test_peers_lists_connected_sessions() {
+    use std::time::{Duration, Instant};
+
+    common::init();
+
+    let mut listen_config = zenoh::Config::default();
+    listen_config
+        .insert_json5("listen/endpoints", r#"["tcp/127.0.0.1:0"]"#)
+        .unwrap();
+    listen_config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    let session = zenoh::open(listen_config).wait().unwrap();
+    let locator = session.info().locators().wait().remove(0);
+
+    let mut connect_config = zenoh::Config::default();
+    connect_config
+        .insert_json5("connect/endpoints", &format!(r#"["{}"]"#, locator))
+        .unwrap();
+    connect_config
+        .insert_json5("scouting/multicast/enabled", "false")
+        .unwrap();
+    let remote = zenoh::open(connect_config).wait().unwrap();
+
+    let sink = ZenohSink::builder("demo/peers")
+        .session(session.clone())
+        .build();
+    sink.set_state(gst::State::Ready).unwrap();
+    assert_eq!(sink.session_zid(), session.zid().to_string());
+
+    let expected = format!("peer:{}", remote.zid());
+    let start = Instant::now();
+    while !sink.peers().contains(&expected) && start.elapsed() < Duration::from_secs(5) {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(
+        sink.peers().contains(&expected),
+        "{:?} missing from {:?}",
+        expected,
+        sink.peers()
+    );
+
+    sink.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_session_accessor() {
+    common::init();
+
+    use gstzenoh::zenohdemux::ZenohDemux;
+
+    let sink = ZenohSink::builder("demo/accessor/video")
+        .session_group("accessor-group")
+        .build();
+    let src = ZenohSrc::builder("demo/accessor/data")
+        .session_group("accessor-group")
+        .build();
+    let demux = ZenohDemux::builder("demo/accessor/**")
+        .session_group("accessor-group")
+        .build();
+
+    // No session until started
+    assert!(sink.session().is_none());
+    assert!(src.session().is_none());
+    assert!(demux.session().is_none());
+
+    sink.set_state(gst::State::Ready).unwrap();
+    src.set_state(gst::State::Paused).unwrap();
+    demux.set_state(gst::State::Paused).unwrap();
+
+    let session = sink.session().expect("sink session while READY");
+    assert_eq!(session.zid().to_string(), sink.session_zid());
+    assert_eq!(src.session().unwrap().zid(), session.zid());
+    assert_eq!(demux.session().unwrap().zid(), session.zid());
+
+    // The handle can be used to declare extra entities
+    let subscriber = session
+        .declare_subscriber("demo/accessor/extra")
+        .wait()
+        .expect("declare on element session");
+    subscriber.undeclare().wait().unwrap();
+
+    sink.set_state(gst::State::Null).unwrap();
+    src.set_state(gst::State::Null).unwrap();
+    demux.set_state(gst::State::Null).unwrap();
+    assert!(sink.session().is_none());
+    assert!(src.session().is_none());
+    assert!(demux.session().is_none());
+}
+
+#[tokio::test]
+#[serial]
+async fn test_open_session_blocking_in_current_thread_runtime() {
+    common::init();
+
+    // zenoh::open().wait() would panic on this runtime's thread
+    let session = gstzenoh::open_session_blocking(zenoh::Config::default())
+        .expect("Failed to open session from a current-thread runtime");
+
+    // An element opening its own session starts and stops from the runtime's
+    // thread too: its matching status query and the close of the group session
+    // on the last stop block on Zenoh's runtime as well
+    let pipeline = gst::Pipeline::new();
+    let src = gst::ElementFactory::make("fakesrc")
+        .property("num-buffers", 1)
+        .build()
+        .unwrap();
+    let sink = ZenohSink::builder("test/session/tokio")
+        .session_group("tokio-current-thread")
+        .build();
+    pipeline.add_many([&src, sink.upcast_ref()]).unwrap();
+    src.link(&sink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    pipeline.set_state(gst::State::Null).unwrap();
+
+    session.close().await.unwrap();
+}
+
+#[test]
+#[serial]
+fn test_session_group_closed_after_last_element() {
+    use std::time::{Duration, Instant};
+
+    common::init();
+
+    let sink = ZenohSink::builder("demo/linger/video")
+        .session_group("linger-group")
+        .session_group_linger_ms(300)
+        .build();
+    let src = ZenohSrc::builder("demo/linger/data")
+        .session_group("linger-group")
+        .session_group_linger_ms(300)
+        .build();
+    assert_eq!(sink.session_group_linger_ms(), 300);
+
+    sink.set_state(gst::State::Ready).unwrap();
+    src.set_state(gst::State::Paused).unwrap();
+    let session = sink.session().expect("sink session while READY");
+    let zid = session.zid();
+
+    // The src still uses the session
+    sink.set_state(gst::State::Null).unwrap();
+    assert!(!session.is_closed());
+    src.set_state(gst::State::Null).unwrap();
+
+    // Joining again within the linger reuses it
+    sink.set_state(gst::State::Ready).unwrap();
+    assert_eq!(sink.session().unwrap().zid(), zid);
+    sink.set_state(gst::State::Null).unwrap();
+
+    let start = Instant::now();
+    while !session.is_closed() && start.elapsed() < Duration::from_secs(5) {
+        std::thread::sleep(Duration::from_millis(50));
+    }
+    assert!(session.is_closed(), "The session should be closed after the linger");
+
+    // The group then opens a new session
+    sink.set_state(gst::State::Ready).unwrap();
+    assert_ne!(sink.session().unwrap().zid(), zid);
+    sink.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_session_shared_property() {
+    common::init();
+
+    use gstzenoh::zenohdemux::ZenohDemux;
+
+    let sink = ZenohSink::builder("demo/shared/video")
+        .session_group("shared-group")
+        .build();
+    let src = ZenohSrc::builder("demo/shared/data")
+        .session_group("shared-group")
+        .build();
+    let demux = ZenohDemux::builder("demo/shared/**")
+        .session_group("shared-group")
+        .build();
+    let standalone = ZenohSink::new("demo/shared/standalone");
+
+    assert!(!sink.is_session_shared(), "No session while stopped");
+
+    sink.set_state(gst::State::Ready).unwrap();
+    src.set_state(gst::State::Paused).unwrap();
+    demux.set_state(gst::State::Paused).unwrap();
+    standalone.set_state(gst::State::Ready).unwrap();
+
+    assert!(sink.is_session_shared());
+    assert!(src.is_session_shared());
+    assert!(demux.is_session_shared());
+    assert!(!standalone.is_session_shared());
+
+    sink.set_state(gst::State::Null).unwrap();
+    src.set_state(gst::State::Null).unwrap();
+    demux.set_state(gst::State::Null).unwrap();
+    standalone.set_state(gst::State::Null).unwrap();
+    assert!(!sink.is_session_shared());
+}
@@ -0,0 +1,109 @@
+//! Shared-memory publishing tests for gst-plugin-zenoh.
+//!
+//! These tests verify that zenohsink publishes from a shared-memory pool
+//! when `use-shm` is enabled and falls back to regular payloads otherwise.
+
+#![cfg(feature = "shared-memory")]
+
+use std::time::Duration;
+
+use gst::prelude::*;
+use serial_test::serial;
+use zenoh::Wait;
+
+mod common;
+#[path = "common/key_expr.rs"]
+mod key_expr;
+#[path = "common/patterns.rs"]
+mod patterns;
+use common::init;
+use key_expr::unique_key_expr;
+use patterns::{generate_test_pattern, verify_test_pattern};
+
+/// Pushes `sizes.len()` buffers through a zenohsink and returns whether each
+/// received payload was backed by shared memory.
+fn publish_and_check_shm(sink_props: &[(&str, &dyn ToValue)], sizes: &[usize]) -> Vec<bool> {
+    let key_expr = unique_key_expr("shm");
+    let zenoh_session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = zenoh_session
+        .declare_subscriber(key_expr.clone())
+        .wait()
+        .unwrap();
+
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let zenohsink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(zenoh_session.clone())
+        .build();
+    for (name, value) in sink_props {
+        zenohsink.set_property_from_value(name, &value.to_value());
+    }
+    let appsrc_elem: gst::Element = appsrc.clone().upcast();
+    let sink_elem: gst::Element = zenohsink.clone().upcast();
+    pipeline.add_many([&appsrc_elem, &sink_elem]).unwrap();
+    appsrc_elem.link(&sink_elem).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    let mut shm_backed = Vec::new();
+    for (seq, &size) in sizes.iter().enumerate() {
+        let data = generate_test_pattern(seq as u32, size);
+        let mut buffer = gst::Buffer::with_size(data.len()).unwrap();
+        buffer.get_mut().unwrap().copy_from_slice(0, &data).unwrap();
+        appsrc.push_buffer(buffer).unwrap();
+
+        let sample = subscriber
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap()
+            .unwrap_or_else(|| panic!("Buffer {} not received", seq));
+        verify_test_pattern(&sample.payload().to_bytes(), seq as u32).unwrap();
+        shm_backed.push(sample.payload().as_shm().is_some());
+    }
+
+    assert_eq!(zenohsink.property::<u64>("errors"), 0);
+    let _ = pipeline.set_state(gst::State::Null);
+    shm_backed
+}
+
+/// Test that payloads are allocated from shared memory when use-shm is set
+#[test]
+#[serial]
+fn test_shm_publish_roundtrip() {
+    init();
+
+    let shm_backed = publish_and_check_shm(&[("use-shm", &true)], &[1024, 64 * 1024]);
+    assert_eq!(shm_backed, vec![true, true]);
+}
+
+/// Test that buffers larger than the pool fall back to regular payloads
+#[test]
+#[serial]
+fn test_shm_fallback_when_buffer_exceeds_pool() {
+    init();
+
+    let shm_backed = publish_and_check_shm(
+        &[("use-shm", &true), ("shm-size", &8192u64)],
+        &[1024, 64 * 1024],
+    );
+    assert_eq!(shm_backed, vec![true, false]);
+}
+
+/// Test that shared memory is not used unless requested
+#[test]
+#[serial]
+fn test_shm_disabled_by_default() {
+    init();
+
+    let sink = gst::ElementFactory::make("zenohsink")
+        .property("key-expr", "test/shm/default")
+        .build()
+        .unwrap();
+    assert!(!sink.property::<bool>("use-shm"));
+    assert_eq!(sink.property::<u64>("shm-size"), 32 * 1024 * 1024);
+
+    let shm_backed = publish_and_check_shm(&[], &[1024]);
+    assert_eq!(shm_backed, vec![false]);
+}
@@ -199,3 +199,289 @@ fn test_error_conditions() {
 
     println!("Error conditions test passed");
 }
+
+#[test]
+#[serial]
+fn test_rapid_playing_null_toggling() {
+    init();
+
+    let pipeline = gst::parse::launch(
+        "videotestsrc is-live=true ! video/x-raw,width=64,height=48 ! \
+         zenohsink name=sink key-expr=test/state/stress scouting=false",
+    )
+    .expect("Failed to create pipeline")
+    .downcast::<gst::Pipeline>()
+    .unwrap();
+    let sink = pipeline.by_name("sink").unwrap();
+
+    // Every cycle opens and releases a session and a publisher
+    for i in 1..=20 {
+        assert!(
+            pipeline.set_state(gst::State::Playing).is_ok(),
+            "Cycle {}: failed to go to PLAYING",
+            i
+        );
+        if i % 2 == 0 {
+            // Let some buffers through on every other cycle
+            let _ = pipeline.state(gst::ClockTime::from_mseconds(200));
+        }
+        assert!(
+            pipeline.set_state(gst::State::Null).is_ok(),
+            "Cycle {}: failed to go to NULL",
+            i
+        );
+
+        // Nothing stays attached to a session once back in NULL
+        assert_eq!(sink.property::<String>("session-zid"), "");
+        assert!(!sink.property::<bool>("has-subscribers"));
+    }
+
+    // The element is still fully usable afterwards
+    assert!(pipeline.set_state(gst::State::Playing).is_ok());
+    let _ = pipeline.state(gst::ClockTime::from_seconds(2));
+    assert!(!sink.property::<String>("session-zid").is_empty());
+    assert!(pipeline.set_state(gst::State::Null).is_ok());
+
+    println!("Rapid PLAYING/NULL toggling test passed");
+}
+
+#[test]
+#[serial]
+fn test_src_null_transition_with_long_receive_timeout() {
+    use std::time::{Duration, Instant};
+
+    init();
+
+    let pipeline = gst::parse::launch(
+        "zenohsrc key-expr=test/state/long_timeout receive-timeout-ms=5000 scouting=false ! \
+         fakesink",
+    )
+    .expect("Failed to create pipeline")
+    .downcast::<gst::Pipeline>()
+    .unwrap();
+
+    assert!(pipeline.set_state(gst::State::Playing).is_ok());
+    // No data is published, so create() is left waiting for a sample
+    std::thread::sleep(Duration::from_millis(300));
+
+    let start = Instant::now();
+    let pipeline_clone = pipeline.clone();
+    let handle = std::thread::spawn(move || pipeline_clone.set_state(gst::State::Null));
+    while !handle.is_finished() && start.elapsed() < Duration::from_secs(6) {
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    let elapsed = start.elapsed();
+
+    assert!(handle.is_finished(), "NULL transition did not complete");
+    assert!(handle.join().unwrap().is_ok());
+    // Receives are polled every 100ms at most, whatever receive-timeout-ms is
+    assert!(
+        elapsed < Duration::from_millis(300),
+        "NULL transition took {:?}",
+        elapsed
+    );
+}
+
+#[test]
+#[serial]
+fn test_sink_wait_for_connection() {
+    use std::time::{Duration, Instant};
+    use zenoh::Wait;
+
+    init();
+
+    // Without scouting or endpoints the session never reaches anyone
+    for require in [true, false] {
+        let sink = gstzenoh::ZenohSink::builder("test/state/wait_for_connection")
+            .scouting(false)
+            .wait_for_connection_ms(200)
+            .require_connection(require)
+            .build();
+        assert_eq!(sink.wait_for_connection_ms(), 200);
+        assert_eq!(sink.require_connection(), require);
+
+        let start = Instant::now();
+        let result = sink.set_state(gst::State::Paused);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(result.is_ok(), !require, "require-connection={}", require);
+        let _ = sink.set_state(gst::State::Null);
+    }
+
+    // With a peer around, starting goes through well before the timeout
+    let _peer = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let sink = gstzenoh::ZenohSink::builder("test/state/wait_for_connection")
+        .wait_for_connection_ms(5000)
+        .require_connection(true)
+        .build();
+
+    let start = Instant::now();
+    assert!(sink.set_state(gst::State::Paused).is_ok());
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(!sink.property::<Vec<String>>("peers").is_empty());
+    let _ = sink.set_state(gst::State::Null);
+}
+
+#[test]
+#[serial]
+fn test_sink_session_connectivity_signals() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::{Duration, Instant};
+    use zenoh::Wait;
+
+    init();
+
+    let wait_for = |counter: &AtomicU32, expected: u32| {
+        let start = Instant::now();
+        while counter.load(Ordering::SeqCst) < expected
+            && start.elapsed() < Duration::from_secs(5)
+        {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        counter.load(Ordering::SeqCst)
+    };
+
+    let peer = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let sink = gstzenoh::ZenohSink::builder("test/state/connectivity")
+        .wait_for_connection_ms(5000)
+        .require_connection(true)
+        .connectivity_poll_ms(50)
+        .build();
+    assert_eq!(sink.connectivity_poll_ms(), 50);
+
+    let disconnected = Arc::new(AtomicU32::new(0));
+    let reconnected = Arc::new(AtomicU32::new(0));
+    {
+        let disconnected = disconnected.clone();
+        sink.connect_session_disconnected(move |_| {
+            disconnected.fetch_add(1, Ordering::SeqCst);
+        });
+        let reconnected = reconnected.clone();
+        sink.connect_session_reconnected(move |_| {
+            reconnected.fetch_add(1, Ordering::SeqCst);
+        });
+    }
+    assert!(sink.set_state(gst::State::Paused).is_ok());
+
+    // Losing the only peer is reported, and so is a new one showing up
+    peer.close().wait().unwrap();
+    assert_eq!(wait_for(&disconnected, 1), 1, "session-disconnected not emitted");
+    let _peer = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    assert_eq!(wait_for(&reconnected, 1), 1, "session-reconnected not emitted");
+
+    let _ = sink.set_state(gst::State::Null);
+    assert_eq!(gstzenoh::ZenohSink::new("test").connectivity_poll_ms(), 1000);
+}
+
+#[test]
+#[serial]
+fn test_sink_wait_for_subscribers() {
+    use std::time::{Duration, Instant};
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/state/wait_for_subscribers/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    // Nobody subscribes yet
+    for require in [true, false] {
+        let sink = gstzenoh::ZenohSink::builder(&key_expr)
+            .session(session.clone())
+            .wait_for_subscribers_ms(200)
+            .require_subscribers(require)
+            .build();
+        assert_eq!(sink.wait_for_subscribers_ms(), 200);
+        assert_eq!(sink.require_subscribers(), require);
+
+        let start = Instant::now();
+        let result = sink.set_state(gst::State::Paused);
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(result.is_ok(), !require, "require-subscribers={}", require);
+        let _ = sink.set_state(gst::State::Null);
+    }
+
+    // A subscriber showing up during the wait lets the start through early
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .wait_for_subscribers_ms(5000)
+        .require_subscribers(true)
+        .build();
+    let subscriber_session = session.clone();
+    let subscriber_key = key_expr.clone();
+    let subscriber = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(300));
+        subscriber_session
+            .declare_subscriber(subscriber_key)
+            .wait()
+            .expect("Failed to declare subscriber")
+    });
+
+    let start = Instant::now();
+    assert!(sink.set_state(gst::State::Paused).is_ok());
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert!(sink.has_subscribers());
+    let _subscriber = subscriber.join().unwrap();
+    let _ = sink.set_state(gst::State::Null);
+}
+
+#[test]
+#[serial]
+fn test_dump_state_action() {
+    init();
+
+    // Collect the INFO messages of the element categories
+    let dumps = std::sync::Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+    let dumps_clone = dumps.clone();
+    gst::log::set_active(true);
+    let log_function = gst::log::add_log_function(
+        move |category, level, _file, _function, _line, _object, message| {
+            if level == gst::DebugLevel::Info
+                && category.name().starts_with("zenoh")
+                && let Some(message) = message.get()
+                && message.contains("State dump")
+            {
+                dumps_clone.lock().unwrap().push(message.to_string());
+            }
+        },
+    );
+
+    for name in [
+        "zenohsink",
+        "zenohsrc",
+        "zenohdemux",
+        "zenohget",
+        "zenohqueryablesrc",
+    ] {
+        gst::log::set_threshold_for_name(name, gst::DebugLevel::Info);
+        let element = gst::ElementFactory::make(name)
+            .property("key-expr", "test/state/dump")
+            .build()
+            .unwrap_or_else(|_| panic!("Failed to create {}", name));
+        if element.find_property("password").is_some() {
+            element.set_property("password", "secret");
+        }
+        element.emit_by_name::<()>("dump-state", &[]);
+    }
+
+    let sink = gstzenoh::ZenohSink::builder("test/state/dump-sink").build();
+    sink.dump_state();
+
+    gst::log::remove_log_function(log_function);
+
+    let dumps = dumps.lock().unwrap();
+    assert_eq!(dumps.len(), 6, "one dump per emission: {:?}", *dumps);
+    for dump in dumps.iter() {
+        assert!(dump.contains("state: Stopped"), "{}", dump);
+        assert!(dump.contains("key-expr: test/state/dump"), "{}", dump);
+        assert!(!dump.contains("secret"), "password leaked: {}", dump);
+    }
+}
@@ -47,6 +47,63 @@ fn test_zenohsrc_statistics_initial_values() {
     assert_eq!(errors, 0, "Initial errors should be 0");
 }
 
+#[test]
+#[serial]
+fn test_zenohsrc_latency_initial_values() {
+    init();
+
+    let src = gst::ElementFactory::make("zenohsrc")
+        .build()
+        .expect("Failed to create zenohsrc");
+
+    let latency: f64 = src.property("latency-ms");
+    let max_latency: f64 = src.property("max-latency-ms");
+
+    assert_eq!(latency, 0.0, "Initial latency-ms should be 0");
+    assert_eq!(max_latency, 0.0, "Initial max-latency-ms should be 0");
+
+    let pspec = src.find_property("latency-ms").unwrap();
+    assert!(
+        !pspec.flags().contains(gst::glib::ParamFlags::WRITABLE),
+        "latency-ms should be read-only"
+    );
+}
+
+#[test]
+#[serial]
+fn test_rate_statistics_initial_values() {
+    init();
+
+    for factory in ["zenohsink", "zenohsrc", "zenohdemux"] {
+        let element = gst::ElementFactory::make(factory)
+            .build()
+            .unwrap_or_else(|_| panic!("Failed to create {}", factory));
+
+        let bitrate: u64 = element.property("bitrate");
+        let message_rate: f64 = element.property("message-rate");
+
+        assert_eq!(bitrate, 0, "{}: initial bitrate should be 0", factory);
+        assert_eq!(
+            message_rate, 0.0,
+            "{}: initial message-rate should be 0",
+            factory
+        );
+    }
+}
+
+#[test]
+#[serial]
+fn test_zenohsink_compression_ratio_initial_value() {
+    init();
+
+    let sink = gstzenoh::ZenohSink::new("test/stats/ratio");
+
+    // Available in every build; 1.0 until something gets compressed
+    let ratio: f64 = sink.property("compression-ratio");
+    assert_eq!(ratio, 1.0, "Initial compression-ratio should be 1.0");
+    assert_eq!(sink.compression_ratio(), 1.0);
+}
+
 #[test]
 #[serial]
 fn test_zenohsink_statistics_read_only() {
@@ -219,13 +276,28 @@ fn test_statistics_properties_exist() {
         .expect("Failed to create zenohsrc");
 
     // Verify all statistics properties exist and are readable
-    let sink_props = ["bytes-sent", "messages-sent", "errors", "dropped"];
+    let sink_props = [
+        "bytes-sent",
+        "messages-sent",
+        "errors",
+        "dropped",
+        "dropped-congestion",
+        "dropped-compression-error",
+        "dropped-oversize",
+        "dropped-filtered",
+        "attachment-bytes-sent",
+    ];
     for prop in &sink_props {
         let value: u64 = sink.property(prop);
         println!("zenohsink.{} = {}", prop, value);
     }
 
-    let src_props = ["bytes-received", "messages-received", "errors"];
+    let src_props = [
+        "bytes-received",
+        "messages-received",
+        "errors",
+        "attachment-bytes-received",
+    ];
     for prop in &src_props {
         let value: u64 = src.property(prop);
         println!("zenohsrc.{} = {}", prop, value);
@@ -278,3 +350,488 @@ fn test_statistics_types() {
     assert_eq!(errors, 0);
     assert_eq!(dropped, 0);
 }
+
+#[test]
+#[serial]
+fn test_zenohsink_dropped_oversize() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/stats/dropped_oversize/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = session.declare_subscriber(&key_expr).wait().unwrap();
+
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .max_buffer_size(1024)
+        .build();
+    assert_eq!(sink.max_buffer_size(), 1024);
+
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    pipeline
+        .add_many([
+            appsrc.upcast_ref::<gst::Element>(),
+            sink.upcast_ref::<gst::Element>(),
+        ])
+        .unwrap();
+    appsrc.link(&sink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![0u8; 4096]))
+        .unwrap();
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![1u8; 16]))
+        .unwrap();
+
+    // Only the small buffer is published
+    let sample = subscriber
+        .recv_timeout(Duration::from_secs(5))
+        .unwrap()
+        .expect("Small buffer not published");
+    assert_eq!(sample.payload().len(), 16);
+
+    assert_eq!(sink.dropped_oversize(), 1);
+    assert_eq!(sink.dropped_congestion(), 0);
+    assert_eq!(sink.dropped_compression_error(), 0);
+    assert_eq!(sink.dropped(), 1);
+
+    let start = std::time::Instant::now();
+    while sink.messages_sent() < 1 && start.elapsed() < Duration::from_secs(2) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(sink.messages_sent(), 1);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_zenohsink_max_bitrate() {
+    use std::time::{Duration, Instant};
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/stats/max_bitrate/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = session.declare_subscriber(&key_expr).wait().unwrap();
+
+    // 400 kbit/s = 50 kB/s, so the 100 kB burst takes about two seconds
+    const MAX_BITRATE: u64 = 400_000;
+    const BUFFERS: usize = 20;
+    const BUFFER_SIZE: usize = 5000;
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .reliability("reliable")
+        .max_bitrate(MAX_BITRATE)
+        .build();
+    assert_eq!(sink.max_bitrate(), MAX_BITRATE);
+
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    pipeline
+        .add_many([
+            appsrc.upcast_ref::<gst::Element>(),
+            sink.upcast_ref::<gst::Element>(),
+        ])
+        .unwrap();
+    appsrc.link(&sink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    for _ in 0..BUFFERS {
+        appsrc
+            .push_buffer(gst::Buffer::from_slice(vec![0u8; BUFFER_SIZE]))
+            .unwrap();
+    }
+
+    let mut first = None;
+    let mut bytes = 0;
+    for _ in 0..BUFFERS {
+        let sample = subscriber
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap()
+            .expect("Buffer not published");
+        match first {
+            None => first = Some(Instant::now()),
+            Some(_) => bytes += sample.payload().len(),
+        }
+    }
+    let elapsed = first.unwrap().elapsed().as_secs_f64();
+
+    // The first buffer leaves at once; the rest at most at the limit, give or
+    // take the 100 ms burst the bucket allows
+    let measured = (bytes * 8) as f64 / elapsed;
+    assert!(
+        measured < MAX_BITRATE as f64 * 1.15,
+        "egress {:.0} bit/s over the {} bit/s limit",
+        measured,
+        MAX_BITRATE
+    );
+    assert!(elapsed > 1.5, "burst wasn't throttled ({:.2} s)", elapsed);
+    assert!(sink.rate_limited_ms() > 0);
+    assert_eq!(sink.dropped_rate_limit(), 0);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_zenohsink_keyframes_only() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/stats/keyframes_only/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = session.declare_subscriber(&key_expr).wait().unwrap();
+
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .keyframes_only(true)
+        .build();
+    assert!(sink.keyframes_only());
+
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    pipeline
+        .add_many([
+            appsrc.upcast_ref::<gst::Element>(),
+            sink.upcast_ref::<gst::Element>(),
+        ])
+        .unwrap();
+    appsrc.link(&sink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    for (i, delta) in [false, true, true, false].into_iter().enumerate() {
+        let mut buffer = gst::Buffer::from_slice(vec![i as u8; 16]);
+        if delta {
+            buffer
+                .get_mut()
+                .unwrap()
+                .set_flags(gst::BufferFlags::DELTA_UNIT);
+        }
+        appsrc.push_buffer(buffer).unwrap();
+    }
+
+    // Only the two key frames are published
+    for expected in [0u8, 3] {
+        let sample = subscriber
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap()
+            .expect("Key frame not published");
+        assert_eq!(sample.payload().to_bytes()[0], expected);
+    }
+
+    let start = std::time::Instant::now();
+    while sink.messages_sent() < 2 && start.elapsed() < Duration::from_secs(2) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(sink.messages_sent(), 2);
+    assert_eq!(sink.dropped_filtered(), 2);
+    assert_eq!(sink.dropped(), 0);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+/// Test that puts bounded by put-timeout-ms go through the put worker without
+/// losing anything on a healthy network.
+#[test]
+#[serial]
+fn test_zenohsink_put_timeout() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/stats/put_timeout/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+    let subscriber = session.declare_subscriber(&key_expr).wait().unwrap();
+
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .put_timeout_ms(1000)
+        .build();
+    assert_eq!(sink.put_timeout_ms(), 1000);
+
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    pipeline
+        .add_many([
+            appsrc.upcast_ref::<gst::Element>(),
+            sink.upcast_ref::<gst::Element>(),
+        ])
+        .unwrap();
+    appsrc.link(&sink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    for i in 0..5u8 {
+        appsrc.push_buffer(gst::Buffer::from_slice(vec![i; 16])).unwrap();
+    }
+
+    for expected in 0..5u8 {
+        let sample = subscriber
+            .recv_timeout(Duration::from_secs(5))
+            .unwrap()
+            .expect("Buffer not published");
+        assert_eq!(sample.payload().to_bytes()[0], expected);
+    }
+
+    let start = std::time::Instant::now();
+    while sink.messages_sent() < 5 && start.elapsed() < Duration::from_secs(2) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(sink.messages_sent(), 5);
+    assert_eq!(sink.dropped_timeout(), 0);
+
+    let stats: serde_json::Value = serde_json::from_str(&sink.stats_json()).unwrap();
+    assert_eq!(stats["dropped-timeout"], 0);
+
+    pipeline.set_state(gst::State::Null).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_stats_json_initial_values() {
+    init();
+
+    for (factory, keys) in [
+        ("zenohsink", &["bytes-sent", "messages-sent", "errors", "dropped", "bitrate"][..]),
+        ("zenohsrc", &["bytes-received", "messages-received", "errors", "dropped-late"][..]),
+        ("zenohdemux", &["bytes-received", "messages-received", "errors", "pads-created"][..]),
+    ] {
+        let element = gst::ElementFactory::make(factory)
+            .build()
+            .expect("Failed to create element");
+
+        let json: String = element.property("stats-json");
+        let stats: serde_json::Value =
+            serde_json::from_str(&json).expect("stats-json is not valid JSON");
+        for key in keys {
+            assert_eq!(stats[key], 0, "{}: initial {} should be 0", factory, key);
+        }
+        assert_eq!(stats["message-rate"], 0.0, "{}: initial message-rate", factory);
+    }
+}
+
+#[test]
+#[serial]
+fn test_zenohsink_stats_json_matches_properties() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/stats/stats_json/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .max_buffer_size(1024)
+        .build();
+
+    let pipeline = gst::Pipeline::new();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    pipeline
+        .add_many([
+            appsrc.upcast_ref::<gst::Element>(),
+            sink.upcast_ref::<gst::Element>(),
+        ])
+        .unwrap();
+    appsrc.link(&sink).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![0u8; 4096]))
+        .unwrap();
+    for _ in 0..3 {
+        appsrc
+            .push_buffer(gst::Buffer::from_slice(vec![1u8; 16]))
+            .unwrap();
+    }
+
+    let start = std::time::Instant::now();
+    while sink.messages_sent() < 3 && start.elapsed() < Duration::from_secs(2) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let stats: serde_json::Value =
+        serde_json::from_str(&sink.stats_json()).expect("stats-json is not valid JSON");
+    pipeline.set_state(gst::State::Null).unwrap();
+
+    assert_eq!(stats["messages-sent"], 3);
+    assert_eq!(stats["bytes-sent"], 48);
+    assert_eq!(stats["dropped"], 1);
+    assert_eq!(stats["dropped-oversize"], 1);
+    assert_eq!(stats["errors"], 0);
+    assert_eq!(stats["compression-ratio"], 1.0);
+    assert!(stats["bitrate"].as_u64().unwrap() > 0);
+}
+
+#[test]
+#[serial]
+fn test_attachment_bytes_statistics() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/stats/attachment_bytes/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let src = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(session.clone())
+        .receive_timeout_ms(50)
+        .build();
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+    let recv_pipeline = gst::Pipeline::new();
+    recv_pipeline
+        .add_many([src.upcast_ref::<gst::Element>(), &fakesink])
+        .unwrap();
+    src.link(&fakesink).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+
+    // Buffer timing metadata is attached to every buffer by default
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .build();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let send_pipeline = gst::Pipeline::new();
+    send_pipeline
+        .add_many([
+            appsrc.upcast_ref::<gst::Element>(),
+            sink.upcast_ref::<gst::Element>(),
+        ])
+        .unwrap();
+    appsrc.link(&sink).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    std::thread::sleep(Duration::from_millis(200));
+
+    for _ in 0..5 {
+        appsrc
+            .push_buffer(gst::Buffer::from_slice(vec![0u8; 16]))
+            .unwrap();
+    }
+
+    let start = std::time::Instant::now();
+    while src.messages_received() < 5 && start.elapsed() < Duration::from_secs(3) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let sent = sink.attachment_bytes_sent();
+    let received = src.attachment_bytes_received();
+    let stats: serde_json::Value =
+        serde_json::from_str(&sink.stats_json()).expect("stats-json is not valid JSON");
+    send_pipeline.set_state(gst::State::Null).unwrap();
+    recv_pipeline.set_state(gst::State::Null).unwrap();
+
+    assert!(sent > 0, "buffer metadata should be counted");
+    assert_eq!(received, sent);
+    assert_eq!(stats["attachment-bytes-sent"], sent);
+    assert_eq!(stats["bytes-sent"], 80, "attachments are not part of bytes-sent");
+}
+
+#[test]
+#[serial]
+fn test_rx_qos_statistics() {
+    use std::time::Duration;
+    use zenoh::Wait;
+
+    init();
+
+    let key_expr = format!("test/stats/rx_qos/{}", std::process::id());
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let src = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(session.clone())
+        .receive_timeout_ms(50)
+        .build();
+    assert_eq!(src.rx_priority(), 0, "no sample received yet");
+    assert!(!src.rx_express());
+    let fakesink = gst::ElementFactory::make("fakesink")
+        .property("sync", false)
+        .build()
+        .unwrap();
+    let recv_pipeline = gst::Pipeline::new();
+    recv_pipeline
+        .add_many([src.upcast_ref::<gst::Element>(), &fakesink])
+        .unwrap();
+    src.link(&fakesink).unwrap();
+    recv_pipeline.set_state(gst::State::Playing).unwrap();
+
+    let sink = gstzenoh::ZenohSink::builder(&key_expr)
+        .session(session.clone())
+        .priority(2)
+        .express(true)
+        .build();
+    let appsrc = gst_app::AppSrc::builder()
+        .format(gst::Format::Bytes)
+        .build();
+    let send_pipeline = gst::Pipeline::new();
+    send_pipeline
+        .add_many([
+            appsrc.upcast_ref::<gst::Element>(),
+            sink.upcast_ref::<gst::Element>(),
+        ])
+        .unwrap();
+    appsrc.link(&sink).unwrap();
+    send_pipeline.set_state(gst::State::Playing).unwrap();
+    std::thread::sleep(Duration::from_millis(200));
+
+    appsrc
+        .push_buffer(gst::Buffer::from_slice(vec![0u8; 16]))
+        .unwrap();
+
+    let start = std::time::Instant::now();
+    while src.messages_received() < 1 && start.elapsed() < Duration::from_secs(3) {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    // Statistics are reset when stopping, read them first
+    let rx_priority = src.rx_priority();
+    let rx_express = src.rx_express();
+    let stats: serde_json::Value =
+        serde_json::from_str(&src.stats_json()).expect("stats-json is not valid JSON");
+    send_pipeline.set_state(gst::State::Null).unwrap();
+    recv_pipeline.set_state(gst::State::Null).unwrap();
+
+    assert_eq!(rx_priority, 2, "the publisher's priority should be observed");
+    assert!(rx_express, "the publisher's express flag should be observed");
+    assert_eq!(stats["rx-priority"], 2);
+    assert_eq!(stats["rx-express"], true);
+}
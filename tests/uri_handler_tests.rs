@@ -135,6 +135,28 @@ fn test_uri_with_express_mode() {
     assert_eq!(priority, 1);
 }
 
+#[test]
+#[serial]
+fn test_zenohsrc_express_property() {
+    init();
+
+    let src = gstzenoh::ZenohSrc::new("demo/video");
+    assert!(!src.express());
+    src.set_express(true);
+    assert!(src.express());
+
+    let src: gst::Element = src.upcast();
+    let (uri, copy) = roundtrip(&src);
+    assert!(uri.contains("express=true"), "{}", uri);
+    assert!(copy.property::<bool>("express"));
+
+    copy.dynamic_cast_ref::<gst::URIHandler>()
+        .unwrap()
+        .set_uri("zenoh:demo/video?express=false")
+        .unwrap();
+    assert!(!copy.property::<bool>("express"));
+}
+
 #[test]
 #[serial]
 fn test_uri_with_config_file() {
@@ -659,3 +681,183 @@ fn test_zenohsrc_apply_buffer_meta_invalid_uri() {
         "Should reject invalid apply-buffer-meta value"
     );
 }
+
+// ============================================================================
+// Round-trip Tests
+// ============================================================================
+
+/// Copies `from`'s URI onto a fresh element of the same factory.
+fn roundtrip(from: &gst::Element) -> (String, gst::Element) {
+    let uri = from
+        .dynamic_cast_ref::<gst::URIHandler>()
+        .unwrap()
+        .uri()
+        .expect("No URI");
+    let to = gst::ElementFactory::make(&from.factory().unwrap().name())
+        .build()
+        .unwrap();
+    to.dynamic_cast_ref::<gst::URIHandler>()
+        .unwrap()
+        .set_uri(&uri)
+        .expect("Failed to set generated URI");
+    (uri, to)
+}
+
+#[test]
+#[serial]
+fn test_zenohsink_uri_roundtrip() {
+    init();
+
+    let sink = gst::ElementFactory::make("zenohsink")
+        .property("key-expr", "cameras/${device-id}/video")
+        .property(
+            "key-vars",
+            "vars, device-id=front".parse::<gst::Structure>().unwrap(),
+        )
+        .property("session-group", "shared")
+        .property("config-json5", "{ mode: \"client\" }")
+        .property("send-caps", false)
+        .property("caps-interval", 10u32)
+        .property("send-buffer-meta", false)
+        .property("keyframe-priority", 2u32)
+        .property("sequence-numbers", true)
+        .property("max-buffer-size", 65536u64)
+        .property("wait-for-connection-ms", 500u64)
+        .property("scouting", false)
+        .property_from_str("locality", "remote")
+        .property_from_str("express-congestion-control", "drop")
+        .build()
+        .unwrap();
+
+    let (uri, copy) = roundtrip(&sink);
+    assert!(uri.contains("session-group=shared"), "{}", uri);
+    assert!(uri.contains("caps-interval=10"), "{}", uri);
+    assert!(uri.contains("locality=remote"), "{}", uri);
+    assert!(!uri.contains("priority=5"), "Defaults are left out: {}", uri);
+
+    for prop in [
+        "key-expr",
+        "session-group",
+        "config-json5",
+        "send-caps",
+        "caps-interval",
+        "send-buffer-meta",
+        "keyframe-priority",
+        "sequence-numbers",
+        "max-buffer-size",
+        "wait-for-connection-ms",
+        "scouting",
+        "locality",
+        "express-congestion-control",
+    ] {
+        assert_eq!(
+            format!("{:?}", sink.property_value(prop)),
+            format!("{:?}", copy.property_value(prop)),
+            "{} not round-tripped",
+            prop
+        );
+    }
+    let key_vars: Option<gst::Structure> = copy.property("key-vars");
+    assert_eq!(key_vars.unwrap().get::<String>("device-id").unwrap(), "front");
+}
+
+#[test]
+#[serial]
+#[cfg(feature = "compression-zstd")]
+fn test_zenohsink_uri_roundtrip_compression() {
+    init();
+
+    let sink = gst::ElementFactory::make("zenohsink")
+        .property("key-expr", "demo/video")
+        .property_from_str("compression", "zstd")
+        .property("compression-level", 9i32)
+        .build()
+        .unwrap();
+
+    let (uri, copy) = roundtrip(&sink);
+    assert!(uri.contains("compression=zstd"), "{}", uri);
+    assert!(uri.contains("compression-level=9"), "{}", uri);
+    let level: i32 = copy.property("compression-level");
+    assert_eq!(level, 9);
+}
+
+#[test]
+#[serial]
+fn test_zenohsrc_uri_roundtrip() {
+    init();
+
+    let src = gst::ElementFactory::make("zenohsrc")
+        .property("key-expr", "demo/video")
+        .property(
+            "caps",
+            gst::Caps::builder("video/x-h264")
+                .field("stream-format", "byte-stream")
+                .build(),
+        )
+        .property("force-caps", true)
+        .property("zero-copy", false)
+        .property("max-lateness-ms", 200u64)
+        .property("no-data-timeout-ms", 1000u64)
+        .property("reconnect", true)
+        .property("session-group", "shared")
+        .property("timestamp-mode", gstzenoh::TimestampMode::PipelineClock)
+        .build()
+        .unwrap();
+
+    let (uri, copy) = roundtrip(&src);
+    assert!(uri.contains("zero-copy=false"), "{}", uri);
+    assert!(uri.contains("timestamp-mode=pipeline-clock"), "{}", uri);
+
+    for prop in [
+        "key-expr",
+        "force-caps",
+        "zero-copy",
+        "max-lateness-ms",
+        "no-data-timeout-ms",
+        "reconnect",
+        "session-group",
+        "timestamp-mode",
+    ] {
+        assert_eq!(
+            format!("{:?}", src.property_value(prop)),
+            format!("{:?}", copy.property_value(prop)),
+            "{} not round-tripped",
+            prop
+        );
+    }
+    let caps: Option<gst::Caps> = copy.property("caps");
+    assert_eq!(caps, src.property::<Option<gst::Caps>>("caps"));
+}
+
+#[test]
+#[serial]
+fn test_uri_leaves_out_password() {
+    init();
+
+    let src = gst::ElementFactory::make("zenohsrc")
+        .build()
+        .expect("Failed to create zenohsrc");
+    let uri_handler = src.dynamic_cast_ref::<gst::URIHandler>().unwrap();
+
+    uri_handler
+        .set_uri("zenoh:demo/video?username=camera&password=s3cr3t")
+        .expect("Failed to set URI");
+
+    let uri = uri_handler.uri().unwrap();
+    assert!(uri.contains("username=camera"), "{}", uri);
+    assert!(!uri.contains("s3cr3t"), "Password leaked into URI: {}", uri);
+}
+
+#[test]
+#[serial]
+fn test_invalid_enum_in_uri() {
+    init();
+
+    let sink = gst::ElementFactory::make("zenohsink")
+        .build()
+        .expect("Failed to create zenohsink");
+    let uri_handler = sink.dynamic_cast_ref::<gst::URIHandler>().unwrap();
+
+    let result = uri_handler.set_uri("zenoh:demo/video?locality=nowhere");
+    assert!(result.is_err(), "Should reject unknown locality");
+}
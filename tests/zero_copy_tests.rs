@@ -0,0 +1,221 @@
+//! Zero-copy reception tests for gst-plugin-zenoh.
+//!
+//! These tests verify that zenohsrc delivers identical data with and without
+//! `zero-copy`, a `buffer-pool-size` pool or the pool downstream proposes, and
+//! provide a throughput benchmark comparing these modes.
+
+use std::time::{Duration, Instant};
+
+use gst::prelude::*;
+use serial_test::serial;
+use zenoh::Wait;
+
+mod common;
+#[path = "common/key_expr.rs"]
+mod key_expr;
+#[path = "common/patterns.rs"]
+mod patterns;
+use common::init;
+use key_expr::unique_key_expr;
+use patterns::{generate_test_pattern, verify_test_pattern};
+
+/// Publishes `count` payloads of `size` bytes and pulls them through
+/// zenohsrc ! appsink. Returns the elapsed time and the received buffers
+/// (only when `keep` is set, to keep the benchmark lean).
+fn receive_frames(
+    zero_copy: bool,
+    buffer_pool_size: u32,
+    size: usize,
+    count: u32,
+    keep: bool,
+) -> (Duration, Vec<gst::Buffer>) {
+    let key_expr = unique_key_expr("zero_copy");
+    let session = zenoh::open(zenoh::Config::default())
+        .wait()
+        .expect("Failed to open Zenoh session");
+
+    let pipeline = gst::Pipeline::new();
+    let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+        .session(session.clone())
+        .zero_copy(zero_copy)
+        .buffer_pool_size(buffer_pool_size)
+        .build();
+    let appsink = gst_app::AppSink::builder().sync(false).build();
+    let src_elem: gst::Element = zenohsrc.clone().upcast();
+    let sink_elem: gst::Element = appsink.clone().upcast();
+    pipeline.add_many([&src_elem, &sink_elem]).unwrap();
+    src_elem.link(&sink_elem).unwrap();
+    pipeline.set_state(gst::State::Playing).unwrap();
+    // Let the subscriber get declared
+    std::thread::sleep(Duration::from_millis(200));
+
+    let publisher = session.declare_publisher(key_expr).wait().unwrap();
+    let payloads: Vec<Vec<u8>> = (0..count.min(8))
+        .map(|seq| generate_test_pattern(seq, size))
+        .collect();
+
+    let start = Instant::now();
+    let mut received = Vec::new();
+    for seq in 0..count {
+        publisher
+            .put(payloads[seq as usize % payloads.len()].clone())
+            .wait()
+            .unwrap();
+        let sample = appsink
+            .try_pull_sample(gst::ClockTime::from_seconds(5))
+            .unwrap_or_else(|| panic!("Buffer {} not received", seq));
+        if keep {
+            received.push(sample.buffer_owned().unwrap());
+        }
+    }
+    let elapsed = start.elapsed();
+
+    let _ = pipeline.set_state(gst::State::Null);
+    (elapsed, received)
+}
+
+/// Test that zero-copy and copied buffers carry the same data
+#[test]
+#[serial]
+fn test_zero_copy_data_integrity() {
+    init();
+
+    for (zero_copy, buffer_pool_size) in [(true, 0), (false, 0), (false, 2)] {
+        let (_, buffers) = receive_frames(zero_copy, buffer_pool_size, 64 * 1024, 4, true);
+        assert_eq!(buffers.len(), 4);
+        for (seq, buffer) in buffers.iter().enumerate() {
+            let map = buffer.map_readable().unwrap();
+            verify_test_pattern(map.as_slice(), seq as u32).unwrap();
+        }
+    }
+}
+
+/// Test that a zero-copy buffer can still be modified downstream (copy on write)
+#[test]
+#[serial]
+fn test_zero_copy_buffer_writable_map() {
+    init();
+
+    let (_, mut buffers) = receive_frames(true, 0, 1024, 1, true);
+    let mut buffer = buffers.pop().unwrap();
+    {
+        let buffer_mut = buffer.make_mut();
+        let mut map = buffer_mut.map_writable().unwrap();
+        map.as_mut_slice()[0] = 0xFF;
+    }
+    let map = buffer.map_readable().unwrap();
+    assert_eq!(map.as_slice()[0], 0xFF);
+    assert_eq!(map.len(), 1024);
+}
+
+/// Test that with use-downstream-pool, payloads are copied into the buffers
+/// of the pool downstream proposes in the allocation query
+#[test]
+#[serial]
+fn test_use_downstream_pool() {
+    init();
+
+    const POOL_BUFFER_SIZE: usize = 4096;
+
+    for use_downstream_pool in [true, false] {
+        let key_expr = unique_key_expr("downstream_pool");
+        let session = zenoh::open(zenoh::Config::default())
+            .wait()
+            .expect("Failed to open Zenoh session");
+
+        let pipeline = gst::Pipeline::new();
+        let zenohsrc = gstzenoh::ZenohSrc::builder(&key_expr)
+            .session(session.clone())
+            .caps(gst::Caps::builder("application/x-test").build())
+            .use_downstream_pool(use_downstream_pool)
+            .build();
+        assert_eq!(zenohsrc.use_downstream_pool(), use_downstream_pool);
+        let appsink = gst_app::AppSink::builder().sync(false).build();
+
+        // Downstream advertises a pool of 4 KiB buffers
+        let pool = gst::BufferPool::new();
+        appsink
+            .static_pad("sink")
+            .unwrap()
+            .add_probe(gst::PadProbeType::QUERY_DOWNSTREAM, move |_, info| {
+                let Some(gst::PadProbeData::Query(ref mut query)) = info.data else {
+                    return gst::PadProbeReturn::Ok;
+                };
+                let gst::QueryViewMut::Allocation(allocation) = query.view_mut() else {
+                    return gst::PadProbeReturn::Ok;
+                };
+                allocation.add_allocation_pool(Some(&pool), POOL_BUFFER_SIZE as u32, 2, 0);
+                gst::PadProbeReturn::Handled
+            });
+
+        let src_elem: gst::Element = zenohsrc.clone().upcast();
+        let sink_elem: gst::Element = appsink.clone().upcast();
+        pipeline.add_many([&src_elem, &sink_elem]).unwrap();
+        src_elem.link(&sink_elem).unwrap();
+        pipeline.set_state(gst::State::Playing).unwrap();
+        // Let the subscriber get declared
+        std::thread::sleep(Duration::from_millis(200));
+
+        let publisher = session.declare_publisher(key_expr).wait().unwrap();
+        // The larger payload doesn't fit a pool buffer
+        for (seq, size) in [(0u32, 100usize), (1, 200), (2, 2 * POOL_BUFFER_SIZE)] {
+            publisher.put(generate_test_pattern(seq, size)).wait().unwrap();
+            let sample = appsink
+                .try_pull_sample(gst::ClockTime::from_seconds(5))
+                .unwrap_or_else(|| panic!("Buffer {} not received", seq));
+            let buffer = sample.buffer().unwrap();
+            let map = buffer.map_readable().unwrap();
+            verify_test_pattern(map.as_slice(), seq).unwrap();
+
+            // Pool buffers are resized to the payload, keeping their 4 KiB of memory
+            let from_pool = buffer.peek_memory(0).maxsize() > size;
+            assert_eq!(
+                from_pool,
+                use_downstream_pool && size <= POOL_BUFFER_SIZE,
+                "{} byte payload, use-downstream-pool={}",
+                size,
+                use_downstream_pool
+            );
+        }
+
+        let _ = pipeline.set_state(gst::State::Null);
+    }
+}
+
+/// Benchmark: throughput of 4 MiB frames copied, copied into a buffer pool,
+/// and with zero-copy.
+///
+/// Run with `cargo test --release --test zero_copy_tests -- --ignored --nocapture`.
+#[test]
+#[serial]
+#[ignore]
+fn bench_zero_copy_throughput() {
+    init();
+
+    const FRAME_SIZE: usize = 4 * 1024 * 1024;
+    const FRAMES: u32 = 200;
+
+    // Warm up sessions and allocators
+    receive_frames(true, 0, FRAME_SIZE, 10, false);
+
+    let (copy_time, _) = receive_frames(false, 0, FRAME_SIZE, FRAMES, false);
+    let (pool_time, _) = receive_frames(false, 4, FRAME_SIZE, FRAMES, false);
+    let (zero_copy_time, _) = receive_frames(true, 0, FRAME_SIZE, FRAMES, false);
+
+    let mib = (FRAME_SIZE as f64 * FRAMES as f64) / (1024.0 * 1024.0);
+    println!(
+        "copy:      {:>8.1} MiB/s ({:?})",
+        mib / copy_time.as_secs_f64(),
+        copy_time
+    );
+    println!(
+        "pool:      {:>8.1} MiB/s ({:?})",
+        mib / pool_time.as_secs_f64(),
+        pool_time
+    );
+    println!(
+        "zero-copy: {:>8.1} MiB/s ({:?})",
+        mib / zero_copy_time.as_secs_f64(),
+        zero_copy_time
+    );
+}